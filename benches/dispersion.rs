@@ -0,0 +1,101 @@
+//! Benchmarks for the evaluation of the dispersion contribution
+//! of PC-SAFT and SAFT-VRQ Mie for multicomponent mixtures.
+//! The double sums over all pairs of segments scale quadratically
+//! with the number of components and dominate the evaluation of
+//! the Helmholtz energy for mixtures with many components, e.g.,
+//! petroleum fluids described by pseudo-components.
+use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion};
+use feos::pcsaft::{PcSaft, PcSaftParameters, PcSaftRecord};
+use feos::saftvrqmie::{SaftVRQMie, SaftVRQMieParameters, SaftVRQMieRecord};
+use feos_core::joback::JobackRecord;
+use feos_core::parameter::{Parameter, PureRecord};
+use feos_core::{EquationOfState, HelmholtzEnergyDual, StateHD};
+use ndarray::{Array, Array2};
+use num_dual::{Dual64, HyperDual64};
+use std::fs::File;
+use std::sync::Arc;
+
+/// Helper function to create a state with an equimolar composition
+/// and the given total density in units of 1/Å³.
+fn state_hd(n: usize, temperature: f64, density: f64) -> StateHD<f64> {
+    let volume = 1000.0;
+    let moles = Array::from_elem(n, density * volume / n as f64);
+    StateHD::new(temperature, volume, moles)
+}
+
+/// Benchmark the dispersion contribution of an equation of state
+/// for a given number of components using different dual numbers.
+fn bench_dispersion<E: EquationOfState>(
+    c: &mut Criterion,
+    group_name: &str,
+    eos: &[(usize, E)],
+    temperature: f64,
+    density: f64,
+) {
+    let mut group = c.benchmark_group(group_name);
+    for (n, eos) in eos {
+        let disp = eos
+            .residual()
+            .iter()
+            .find(|c| c.to_string() == "Dispersion")
+            .unwrap();
+        let state = state_hd(*n, temperature, density);
+        let state_dual = StateHD::new(
+            Dual64::from(temperature).derive(),
+            Dual64::from(state.volume),
+            state.moles.mapv(Dual64::from),
+        );
+        let state_hyperdual = StateHD::new(
+            HyperDual64::from(temperature),
+            HyperDual64::from(state.volume).derive1().derive2(),
+            state.moles.mapv(HyperDual64::from),
+        );
+        group.bench_with_input(BenchmarkId::new("a_f64", n), &state, |b, s| {
+            b.iter(|| disp.helmholtz_energy(s))
+        });
+        group.bench_with_input(BenchmarkId::new("a_dual", n), &state_dual, |b, s| {
+            b.iter(|| disp.helmholtz_energy(s))
+        });
+        group.bench_with_input(
+            BenchmarkId::new("a_hyperdual", n),
+            &state_hyperdual,
+            |b, s| b.iter(|| disp.helmholtz_energy(s)),
+        );
+    }
+}
+
+/// Benchmark for the PC-SAFT dispersion for up to 75 non-polar components.
+fn pcsaft(c: &mut Criterion) {
+    let file = File::open("./parameters/pcsaft/gross2001.json").unwrap();
+    let records: Vec<PureRecord<PcSaftRecord, JobackRecord>> =
+        serde_json::from_reader(file).unwrap();
+    let eos: Vec<_> = [5, 10, 25, 50, 75]
+        .iter()
+        .map(|&n| {
+            let binary_records = Array2::default((n, n));
+            let parameters = PcSaftParameters::from_records(records[..n].to_vec(), binary_records);
+            (n, PcSaft::new(Arc::new(parameters)))
+        })
+        .collect();
+    bench_dispersion(c, "dispersion_pcsaft", &eos, 300.0, 0.005);
+}
+
+/// Benchmark for the SAFT-VRQ Mie dispersion for mixtures of quantum fluids.
+fn saftvrqmie(c: &mut Criterion) {
+    let file = File::open("./parameters/saftvrqmie/hammer2023.json").unwrap();
+    let records: Vec<PureRecord<SaftVRQMieRecord, JobackRecord>> =
+        serde_json::from_reader(file).unwrap();
+    let eos: Vec<_> = [2, 4, 6]
+        .iter()
+        .map(|&n| {
+            let binary_records = Array2::default((n, n));
+            let parameters =
+                SaftVRQMieParameters::from_records(records[..n].to_vec(), binary_records);
+            (n, SaftVRQMie::new(Arc::new(parameters)))
+        })
+        .collect();
+    bench_dispersion(c, "dispersion_saftvrqmie", &eos, 40.0, 0.02);
+}
+
+criterion_group!(bench, pcsaft, saftvrqmie);
+criterion_main!(bench);
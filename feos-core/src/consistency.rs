@@ -0,0 +1,593 @@
+//! Thermodynamic consistency checks for equations of state.
+//!
+//! The checks are meant to find errors in the implementation of new
+//! Helmholtz energy contributions. Properties that are calculated with
+//! (hyper-) dual numbers are compared to finite differences of lower order
+//! properties, which reveals derivatives that are lost, e.g., because the
+//! real part of a dual number is used in an intermediate result. Additionally,
+//! exact thermodynamic identities (Euler and Gibbs-Duhem relations) and the
+//! ideal gas and pure component limits are verified.
+//!
+//! All checks use the residual (NVT) contributions, so that they do not
+//! depend on the ideal gas model. The physical plausibility of the parameters
+//! of a pure component is checked with [State::parameter_scan].
+use crate::equation_of_state::EquationOfState;
+use crate::errors::{EosError, EosResult};
+use crate::state::{Contributions, State};
+use crate::EosUnit;
+use ndarray::{arr1, Array1};
+use quantity::si::{SIArray1, SINumber, SIUnit, METER, MOL};
+use std::fmt;
+use std::ops::Sub;
+use std::sync::Arc;
+
+const DENSITY_IDEAL_GAS_LIMIT: f64 = 1e-6;
+
+/// Thermodynamic relation that is verified in a [consistency check](check_consistency).
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum ConsistencyCheck {
+    /// Pressure, entropy and chemical potentials compared to finite
+    /// differences of the Helmholtz energy.
+    FirstDerivatives,
+    /// Maxwell relations, i.e., second partial derivatives compared to
+    /// finite differences of the corresponding first partial derivatives.
+    Maxwell,
+    /// Euler relation $A=-pV+\sum_iN_i\mu_i$.
+    Euler,
+    /// Gibbs-Duhem relation $\sum_iN_i\left(\frac{\partial\mu_i}{\partial N_j}\right)_{T,V}=V\left(\frac{\partial p}{\partial N_j}\right)_{T,V}$.
+    GibbsDuhem,
+    /// Vanishing residual properties in the limit of zero density.
+    IdealGasLimit,
+    /// Residual properties of the mixture in the limit of a pure
+    /// component compared to the pure component.
+    PureComponentLimit,
+}
+
+impl fmt::Display for ConsistencyCheck {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::FirstDerivatives => write!(f, "first derivatives"),
+            Self::Maxwell => write!(f, "Maxwell relations"),
+            Self::Euler => write!(f, "Euler relation"),
+            Self::GibbsDuhem => write!(f, "Gibbs-Duhem relation"),
+            Self::IdealGasLimit => write!(f, "ideal gas limit"),
+            Self::PureComponentLimit => write!(f, "pure component limit"),
+        }
+    }
+}
+
+/// Options for [check_consistency].
+#[derive(Clone, Copy, Debug)]
+pub struct ConsistencyOptions {
+    /// Maximum (scaled) deviation of a relation.
+    pub tol: f64,
+    /// Relative step size of the central finite differences.
+    pub step: f64,
+}
+
+impl Default for ConsistencyOptions {
+    fn default() -> Self {
+        Self {
+            tol: 1e-6,
+            step: 1e-5,
+        }
+    }
+}
+
+/// Evaluation of a single relation at a single state point.
+#[derive(Clone, Debug)]
+pub struct ConsistencyResult {
+    /// The group of relations.
+    pub check: ConsistencyCheck,
+    /// The relation that was evaluated, e.g., `(dS/dV)_T = (dp/dT)_V`.
+    pub relation: String,
+    pub temperature: SINumber,
+    pub density: SINumber,
+    pub molefracs: Array1<f64>,
+    /// Deviation between both sides of the relation divided by a
+    /// characteristic (ideal gas) value of the property.
+    pub deviation: f64,
+}
+
+impl ConsistencyResult {
+    /// Return `true` if the deviation is finite and below `tol`.
+    pub fn is_satisfied(&self, tol: f64) -> bool {
+        self.deviation <= tol
+    }
+}
+
+impl fmt::Display for ConsistencyResult {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{}: {} at T = {}, ρ = {}, x = {}: deviation {:e}",
+            self.check,
+            self.relation,
+            self.temperature,
+            self.density,
+            self.molefracs,
+            self.deviation
+        )
+    }
+}
+
+/// Report of a [consistency check](check_consistency).
+#[derive(Clone, Debug)]
+pub struct ConsistencyReport {
+    /// The tolerance used to classify the results.
+    pub tol: f64,
+    /// All evaluated relations.
+    pub results: Vec<ConsistencyResult>,
+}
+
+impl ConsistencyReport {
+    /// Return `true` if all relations are satisfied.
+    pub fn is_consistent(&self) -> bool {
+        self.results.iter().all(|r| r.is_satisfied(self.tol))
+    }
+
+    /// Iterate over all relations that are violated.
+    pub fn failures(&self) -> impl Iterator<Item = &ConsistencyResult> {
+        let tol = self.tol;
+        self.results.iter().filter(move |r| !r.is_satisfied(tol))
+    }
+
+    /// The maximum deviation of all relations in the given group
+    /// or `NaN` if any of the deviations is not finite.
+    pub fn max_deviation(&self, check: ConsistencyCheck) -> f64 {
+        self.results
+            .iter()
+            .filter(|r| r.check == check)
+            .map(|r| r.deviation)
+            .fold(0.0, |max, d| {
+                if max.is_nan() || d.is_nan() {
+                    f64::NAN
+                } else {
+                    max.max(d)
+                }
+            })
+    }
+}
+
+impl fmt::Display for ConsistencyReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for check in [
+            ConsistencyCheck::FirstDerivatives,
+            ConsistencyCheck::Maxwell,
+            ConsistencyCheck::Euler,
+            ConsistencyCheck::GibbsDuhem,
+            ConsistencyCheck::IdealGasLimit,
+            ConsistencyCheck::PureComponentLimit,
+        ] {
+            let n = self.results.iter().filter(|r| r.check == check).count();
+            if n > 0 {
+                let failures = self.failures().filter(|r| r.check == check).count();
+                writeln!(
+                    f,
+                    "{}: {} evaluations, {} failures, max. deviation {:e}",
+                    check,
+                    n,
+                    failures,
+                    self.max_deviation(check)
+                )?;
+            }
+        }
+        for r in self.failures() {
+            writeln!(f, "{}", r)?;
+        }
+        Ok(())
+    }
+}
+
+/// Check the thermodynamic consistency of an equation of state on a grid
+/// of temperatures, (molar) densities and compositions.
+///
+/// Every combination of temperature, density and composition is evaluated.
+/// The ideal gas limit is checked for every temperature and composition and
+/// the pure component limit for every temperature, density and component of
+/// a mixture. For a pure component, `molefracs` has to contain the single
+/// composition `[1.0]`.
+pub fn check_consistency<E: EquationOfState>(
+    eos: &Arc<E>,
+    temperatures: &SIArray1,
+    densities: &SIArray1,
+    molefracs: &[Array1<f64>],
+    options: ConsistencyOptions,
+) -> EosResult<ConsistencyReport> {
+    let mut checks = Checks {
+        eos,
+        h: options.step,
+        results: Vec::new(),
+    };
+    for x in molefracs {
+        if x.len() != eos.components() {
+            return Err(EosError::IncompatibleComponents(eos.components(), x.len()));
+        }
+        let x = x / x.sum();
+        for i in 0..temperatures.len() {
+            let t = temperatures.get(i);
+            for j in 0..densities.len() {
+                checks.state_point(t, densities.get(j), &x)?;
+            }
+            checks.ideal_gas_limit(t, &x)?;
+        }
+    }
+    if eos.components() > 1 {
+        for k in 0..eos.components() {
+            let pure = Arc::new(eos.subset(&[k]));
+            for i in 0..temperatures.len() {
+                for j in 0..densities.len() {
+                    checks.pure_component_limit(&pure, temperatures.get(i), densities.get(j), k)?;
+                }
+            }
+        }
+    }
+    Ok(ConsistencyReport {
+        tol: options.tol,
+        results: checks.results,
+    })
+}
+
+/// Evaluation of the individual relations.
+struct Checks<'a, E> {
+    eos: &'a Arc<E>,
+    h: f64,
+    results: Vec<ConsistencyResult>,
+}
+
+impl<'a, E: EquationOfState> Checks<'a, E> {
+    fn push(
+        &mut self,
+        check: ConsistencyCheck,
+        relation: String,
+        state: &State<E>,
+        deviation: f64,
+    ) {
+        self.results.push(ConsistencyResult {
+            check,
+            relation,
+            temperature: state.temperature,
+            density: state.density,
+            molefracs: state.molefracs.clone(),
+            deviation: deviation.abs(),
+        });
+    }
+
+    /// Central finite difference of a property w.r.t. temperature,
+    /// volume or the amount of substance of a component.
+    fn central_difference<F, T>(&self, state: &State<E>, variable: Variable, f: F) -> EosResult<T>
+    where
+        F: Fn(&State<E>) -> T,
+        T: Sub<Output = T>,
+    {
+        let (t, v) = (state.temperature, state.volume);
+        let (s_p, s_m) = match variable {
+            Variable::Temperature => {
+                let dt = t * self.h;
+                (
+                    State::new_nvt(self.eos, t + dt, v, &state.moles)?,
+                    State::new_nvt(self.eos, t - dt, v, &state.moles)?,
+                )
+            }
+            Variable::Volume => {
+                let dv = v * self.h;
+                (
+                    State::new_nvt(self.eos, t, v + dv, &state.moles)?,
+                    State::new_nvt(self.eos, t, v - dv, &state.moles)?,
+                )
+            }
+            Variable::Moles(i) => {
+                let n = state.moles.to_reduced(MOL)?;
+                let mut dn = Array1::zeros(n.len());
+                dn[i] = n[i] * self.h;
+                (
+                    State::new_nvt(self.eos, t, v, &((&n + &dn) * MOL))?,
+                    State::new_nvt(self.eos, t, v, &((&n - &dn) * MOL))?,
+                )
+            }
+        };
+        Ok(f(&s_p) - f(&s_m))
+    }
+
+    fn state_point(
+        &mut self,
+        temperature: SINumber,
+        density: SINumber,
+        x: &Array1<f64>,
+    ) -> EosResult<()> {
+        let c = Contributions::ResidualNvt;
+        let state = State::new_nvt(self.eos, temperature, MOL / density, &(x * MOL))?;
+        let (t, v, n) = (state.temperature, state.volume, state.total_moles);
+        let r = SIUnit::gas_constant();
+        let rt = r * t;
+        let h2 = 2.0 * self.h;
+
+        // properties from dual numbers divided by ideal gas values
+        let a = (state.helmholtz_energy(c) / (rt * n)).into_value()?;
+        let p = (state.pressure(c) / (rt * n / v)).into_value()?;
+        let s = (state.entropy(c) / (r * n)).into_value()?;
+        let mu = (state.chemical_potential(c) / rt).into_value()?;
+        let dp_dt = (state.dp_dt(c) / (r * n / v)).into_value()?;
+        let dp_dni = (state.dp_dni(c) / (rt / v)).into_value()?;
+        let dmu_dt = (state.dmu_dt(c) / r).into_value()?;
+        let dmu_dni = (state.dmu_dni(c) / (rt / n)).into_value()?;
+
+        // derivatives w.r.t. temperature and volume
+        let helmholtz_energy = |s: &State<E>| s.helmholtz_energy(c);
+        let p_fd = (-self.central_difference(&state, Variable::Volume, helmholtz_energy)?
+            / (h2 * v * rt * n / v))
+            .into_value()?;
+        self.push(
+            ConsistencyCheck::FirstDerivatives,
+            "p = -(dA/dV)_T,N".into(),
+            &state,
+            p_fd - p,
+        );
+        let s_fd = (-self.central_difference(&state, Variable::Temperature, helmholtz_energy)?
+            / (h2 * t * r * n))
+            .into_value()?;
+        self.push(
+            ConsistencyCheck::FirstDerivatives,
+            "S = -(dA/dT)_V,N".into(),
+            &state,
+            s_fd - s,
+        );
+        let ds_dv_fd = (self.central_difference(&state, Variable::Volume, |s| s.entropy(c))?
+            / (h2 * v * r * n / v))
+            .into_value()?;
+        self.push(
+            ConsistencyCheck::Maxwell,
+            "(dS/dV)_T,N = (dp/dT)_V,N".into(),
+            &state,
+            ds_dv_fd - dp_dt,
+        );
+        let dmu_dv_fd = (self
+            .central_difference(&state, Variable::Volume, |s| s.chemical_potential(c))?
+            / (h2 * v * rt / v))
+            .into_value()?;
+        for i in 0..x.len() {
+            self.push(
+                ConsistencyCheck::Maxwell,
+                format!("(dmu_{}/dV)_T,N = -(dp/dN_{})_T,V", i, i),
+                &state,
+                dmu_dv_fd[i] + dp_dni[i],
+            );
+        }
+
+        // derivatives w.r.t. the amount of substance of each component
+        for i in 0..x.len() {
+            if x[i] == 0.0 {
+                continue;
+            }
+            let dn = state.moles.get(i) * h2;
+            let mu_fd = (self.central_difference(&state, Variable::Moles(i), helmholtz_energy)?
+                / (dn * rt))
+                .into_value()?;
+            self.push(
+                ConsistencyCheck::FirstDerivatives,
+                format!("mu_{} = (dA/dN_{})_T,V", i, i),
+                &state,
+                mu_fd - mu[i],
+            );
+            let ds_dni_fd =
+                (self.central_difference(&state, Variable::Moles(i), |s| s.entropy(c))? / (dn * r))
+                    .into_value()?;
+            self.push(
+                ConsistencyCheck::Maxwell,
+                format!("(dS/dN_{})_T,V = -(dmu_{}/dT)_V,N", i, i),
+                &state,
+                ds_dni_fd + dmu_dt[i],
+            );
+            let dmu_dni_fd = (self
+                .central_difference(&state, Variable::Moles(i), |s| s.chemical_potential(c))?
+                / (dn * rt / n))
+                .into_value()?;
+            for j in 0..x.len() {
+                self.push(
+                    ConsistencyCheck::Maxwell,
+                    format!("(dmu_{}/dN_{})_T,V = (dmu_{}/dN_{})_T,V", j, i, i, j),
+                    &state,
+                    dmu_dni_fd[j] - dmu_dni[[i, j]],
+                );
+            }
+        }
+
+        // Euler and Gibbs-Duhem relations
+        self.push(
+            ConsistencyCheck::Euler,
+            "A = -pV + sum_i N_i mu_i".into(),
+            &state,
+            a + p - x.dot(&mu),
+        );
+        for j in 0..x.len() {
+            self.push(
+                ConsistencyCheck::GibbsDuhem,
+                format!("sum_i N_i (dmu_i/dN_{})_T,V = V (dp/dN_{})_T,V", j, j),
+                &state,
+                x.dot(&dmu_dni.column(j)) - dp_dni[j],
+            );
+        }
+        Ok(())
+    }
+
+    fn ideal_gas_limit(&mut self, temperature: SINumber, x: &Array1<f64>) -> EosResult<()> {
+        let c = Contributions::ResidualNvt;
+        let density = DENSITY_IDEAL_GAS_LIMIT * MOL / METER.powi(3);
+        let state = State::new_nvt(self.eos, temperature, MOL / density, &(x * MOL))?;
+        let (v, n) = (state.volume, state.total_moles);
+        let rt = SIUnit::gas_constant() * temperature;
+
+        let a = (state.helmholtz_energy(c) / (rt * n)).into_value()?;
+        self.push(
+            ConsistencyCheck::IdealGasLimit,
+            "A_res -> 0".into(),
+            &state,
+            a,
+        );
+        let p = (state.pressure(c) / (rt * n / v)).into_value()?;
+        self.push(ConsistencyCheck::IdealGasLimit, "Z -> 1".into(), &state, p);
+        let mu = (state.chemical_potential(c) / rt).into_value()?;
+        for i in 0..x.len() {
+            self.push(
+                ConsistencyCheck::IdealGasLimit,
+                format!("mu_res_{} -> 0", i),
+                &state,
+                mu[i],
+            );
+        }
+        Ok(())
+    }
+
+    fn pure_component_limit(
+        &mut self,
+        pure: &Arc<E>,
+        temperature: SINumber,
+        density: SINumber,
+        component: usize,
+    ) -> EosResult<()> {
+        let c = Contributions::ResidualNvt;
+        let mut x = Array1::zeros(self.eos.components());
+        x[component] = 1.0;
+        let state = State::new_nvt(self.eos, temperature, MOL / density, &(x * MOL))?;
+        let state_pure = State::new_nvt(pure, temperature, MOL / density, &(arr1(&[1.0]) * MOL))?;
+        let (v, n) = (state.volume, state.total_moles);
+        let rt = SIUnit::gas_constant() * temperature;
+
+        let a = ((state.helmholtz_energy(c) - state_pure.helmholtz_energy(c)) / (rt * n))
+            .into_value()?;
+        self.push(
+            ConsistencyCheck::PureComponentLimit,
+            format!("A_res(x_{} = 1) = A_res,{}", component, component),
+            &state,
+            a,
+        );
+        let p = ((state.pressure(c) - state_pure.pressure(c)) / (rt * n / v)).into_value()?;
+        self.push(
+            ConsistencyCheck::PureComponentLimit,
+            format!("p_res(x_{} = 1) = p_res,{}", component, component),
+            &state,
+            p,
+        );
+        let mu = ((state.chemical_potential(c).get(component)
+            - state_pure.chemical_potential(c).get(0))
+            / rt)
+            .into_value()?;
+        self.push(
+            ConsistencyCheck::PureComponentLimit,
+            format!(
+                "mu_res_{}(x_{} = 1) = mu_res,{}",
+                component, component, component
+            ),
+            &state,
+            mu,
+        );
+        Ok(())
+    }
+}
+
+/// Independent variable of a finite difference.
+#[derive(Clone, Copy)]
+enum Variable {
+    Temperature,
+    Volume,
+    Moles(usize),
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::cubic::{PengRobinson, PengRobinsonParameters};
+    use crate::equation_of_state::{HelmholtzEnergy, HelmholtzEnergyDual};
+    use crate::StateHD;
+    use num_dual::DualNum;
+    use quantity::si::KELVIN;
+
+    #[test]
+    fn peng_robinson() -> EosResult<()> {
+        let parameters = PengRobinsonParameters::new_simple(
+            &[369.96, 425.2],
+            &[4250000.0, 3800000.0],
+            &[0.153, 0.199],
+            &[44.0962, 58.123],
+        )?;
+        let eos = Arc::new(PengRobinson::new(Arc::new(parameters)));
+        let temperatures = arr1(&[250.0, 350.0, 500.0]) * KELVIN;
+        let densities = arr1(&[100.0, 5000.0, 10000.0]) * MOL / METER.powi(3);
+        let molefracs = [arr1(&[0.3, 0.7]), arr1(&[1.0, 0.0])];
+        let report = check_consistency(
+            &eos,
+            &temperatures,
+            &densities,
+            &molefracs,
+            Default::default(),
+        )?;
+        assert!(report.is_consistent(), "{}", report);
+        assert_eq!(report.failures().count(), 0);
+        assert!(report.max_deviation(ConsistencyCheck::Maxwell) > 0.0);
+        Ok(())
+    }
+
+    /// Second virial coefficient that is inversely proportional to the
+    /// temperature but evaluated with the real part of the temperature,
+    /// so that its temperature derivatives are lost.
+    struct RealTemperatureVirial;
+
+    impl<D: DualNum<f64>> HelmholtzEnergyDual<D> for RealTemperatureVirial {
+        fn helmholtz_energy(&self, state: &StateHD<D>) -> D {
+            let n = state.moles.sum();
+            -n * n / state.volume * (1000.0 / state.temperature.re())
+        }
+    }
+
+    impl fmt::Display for RealTemperatureVirial {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            write!(f, "Real temperature virial")
+        }
+    }
+
+    struct RealTemperatureVirialEos(Vec<Box<dyn HelmholtzEnergy>>);
+
+    impl EquationOfState for RealTemperatureVirialEos {
+        fn components(&self) -> usize {
+            1
+        }
+
+        fn subset(&self, _: &[usize]) -> Self {
+            Self(vec![Box::new(RealTemperatureVirial)])
+        }
+
+        fn compute_max_density(&self, _: &Array1<f64>) -> f64 {
+            0.01
+        }
+
+        fn residual(&self) -> &[Box<dyn HelmholtzEnergy>] {
+            &self.0
+        }
+    }
+
+    #[test]
+    fn lost_temperature_derivative() -> EosResult<()> {
+        let eos = Arc::new(RealTemperatureVirialEos(vec![Box::new(
+            RealTemperatureVirial,
+        )]));
+        let temperatures = arr1(&[250.0, 350.0]) * KELVIN;
+        let densities = arr1(&[100.0, 5000.0]) * MOL / METER.powi(3);
+        let report = check_consistency(
+            &eos,
+            &temperatures,
+            &densities,
+            &[arr1(&[1.0])],
+            Default::default(),
+        )?;
+        assert!(!report.is_consistent());
+
+        // the entropy from dual numbers differs from the finite difference
+        // of the Helmholtz energy, all other relations are satisfied
+        assert_eq!(report.failures().count(), 4);
+        assert!(report.failures().all(|r| {
+            r.check == ConsistencyCheck::FirstDerivatives && r.relation == "S = -(dA/dT)_V,N"
+        }));
+        assert!(report.max_deviation(ConsistencyCheck::FirstDerivatives) > 1e-3);
+        Ok(())
+    }
+}
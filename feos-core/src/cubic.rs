@@ -7,15 +7,17 @@
 use crate::equation_of_state::{
     EquationOfState, HelmholtzEnergy, HelmholtzEnergyDual, IdealGasContribution,
 };
-use crate::joback::{Joback, JobackRecord};
-use crate::parameter::{Identifier, Parameter, ParameterError, PureRecord};
+use crate::ideal_gas::{IdealGasModel, WithIdealGas};
+use crate::joback::JobackRecord;
+use crate::parameter::{Identifier, Parameter, ParameterError, ParameterSet, PureRecord};
 use crate::si::{GRAM, MOL};
 use crate::state::StateHD;
-use crate::MolarWeight;
+use crate::{EosResult, MolarWeight};
 use ndarray::{Array1, Array2};
 use num_dual::DualNum;
 use quantity::si::SIArray1;
-use serde::{Deserialize, Serialize};
+use serde::de::Error;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
 use std::f64::consts::SQRT_2;
 use std::fmt;
 use std::sync::Arc;
@@ -208,7 +210,7 @@ pub struct PengRobinson {
     /// Parameters
     parameters: Arc<PengRobinsonParameters>,
     /// Ideal gas contributions to the Helmholtz energy
-    ideal_gas: Joback,
+    ideal_gas: IdealGasModel,
     /// Non-ideal contributions to the Helmholtz energy
     contributions: Vec<Box<dyn HelmholtzEnergy>>,
 }
@@ -216,9 +218,9 @@ pub struct PengRobinson {
 impl PengRobinson {
     /// Create a new equation of state from a set of parameters.
     pub fn new(parameters: Arc<PengRobinsonParameters>) -> Self {
-        let ideal_gas = parameters.joback_records.as_ref().map_or_else(
-            || Joback::default(parameters.tc.len()),
-            |j| Joback::new(j.clone()),
+        let ideal_gas = parameters.joback_records.clone().map_or_else(
+            || IdealGasModel::default(parameters.tc.len()),
+            IdealGasModel::joback,
         );
         let contributions: Vec<Box<dyn HelmholtzEnergy>> =
             vec![Box::new(PengRobinsonContribution {
@@ -238,7 +240,10 @@ impl EquationOfState for PengRobinson {
     }
 
     fn subset(&self, component_list: &[usize]) -> Self {
-        Self::new(Arc::new(self.parameters.subset(component_list)))
+        Self {
+            ideal_gas: self.ideal_gas.subset(component_list),
+            ..Self::new(Arc::new(self.parameters.subset(component_list)))
+        }
     }
 
     fn compute_max_density(&self, moles: &Array1<f64>) -> f64 {
@@ -255,6 +260,42 @@ impl EquationOfState for PengRobinson {
     }
 }
 
+impl WithIdealGas for PengRobinson {
+    fn with_ideal_gas(mut self, ideal_gas: IdealGasModel) -> EosResult<Self> {
+        ideal_gas.validate_components(self.components())?;
+        self.ideal_gas = ideal_gas;
+        Ok(self)
+    }
+}
+
+// Auxiliary structure used to (de)serialize the equation of state.
+#[derive(Serialize, Deserialize)]
+struct PengRobinsonJSON {
+    parameters: ParameterSet<PengRobinsonRecord, JobackRecord, f64>,
+    ideal_gas: IdealGasModel,
+}
+
+impl Serialize for PengRobinson {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        PengRobinsonJSON {
+            parameters: self.parameters.parameter_set(),
+            ideal_gas: self.ideal_gas.clone(),
+        }
+        .serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for PengRobinson {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let eos = PengRobinsonJSON::deserialize(deserializer)?;
+        let parameters =
+            PengRobinsonParameters::from_parameter_set(eos.parameters).map_err(D::Error::custom)?;
+        Self::new(Arc::new(parameters))
+            .with_ideal_gas(eos.ideal_gas)
+            .map_err(D::Error::custom)
+    }
+}
+
 impl MolarWeight for PengRobinson {
     fn molar_weight(&self) -> SIArray1 {
         self.parameters.molarweight.clone() * GRAM / MOL
@@ -1,5 +1,6 @@
 use crate::equation_of_state::EquationOfState;
 use crate::errors::{EosError, EosResult};
+use crate::solver_log::SolverLog;
 use crate::state::State;
 use crate::EosUnit;
 use quantity::si::{SIArray1, SINumber, SIUnit};
@@ -27,8 +28,10 @@ pub fn density_iteration<E: EquationOfState>(
 
     let maxiter = 50;
     let mut iterations = 0;
+    let mut log = SolverLog::new("density_iteration");
     'iteration: for k in 0..maxiter {
         iterations += 1;
+        let rho_old = rho;
         let (mut p, mut dp_drho) = State::new_nvt(eos, temperature, n / rho, moles)?.p_dpdrho();
 
         // attempt to correct for poor initial density rho_init
@@ -44,8 +47,10 @@ pub fn density_iteration<E: EquationOfState>(
         }
 
         let mut error = p - pressure;
+        let residual = error.to_reduced(SIUnit::reference_pressure())?.abs();
 
-        let mut delta_rho = -error / dp_drho;
+        let newton_step = -error / dp_drho;
+        let mut delta_rho = newton_step;
         if delta_rho.abs() > 0.075 * maxdensity {
             delta_rho = 0.075 * maxdensity * delta_rho.signum();
         };
@@ -124,10 +129,19 @@ pub fn density_iteration<E: EquationOfState>(
                     rho = (rho + 0.1 * maxdensity).min(maxdensity)?
                 }
             }
+            let step_size = (rho - rho_old)
+                .to_reduced(SIUnit::reference_density())?
+                .abs();
+            log.push(residual, step_size, f64::NAN);
             continue 'iteration;
         }
         // Newton step
         rho += delta_rho;
+        log.push(
+            residual,
+            delta_rho.to_reduced(SIUnit::reference_density())?.abs(),
+            delta_rho.to_reduced(newton_step)?,
+        );
         if error.to_reduced(SIUnit::reference_pressure())?.abs()
             < f64::max(
                 abstol,
@@ -140,7 +154,8 @@ pub fn density_iteration<E: EquationOfState>(
     if iterations == maxiter + 1 {
         Err(EosError::NotConverged("density_iteration".to_owned()))
     } else {
-        Ok(State::new_nvt(eos, temperature, n / rho, moles)?)
+        log.converged = true;
+        Ok(State::new_nvt(eos, temperature, n / rho, moles)?.with_solver_log(log))
     }
 }
 
@@ -7,6 +7,7 @@ use num_dual::{
 };
 use num_traits::{One, Zero};
 use quantity::si::{SIArray1, SINumber, SIUnit};
+use serde::{Deserialize, Serialize};
 use std::fmt;
 
 /// Individual Helmholtz energy contribution that can
@@ -132,7 +133,13 @@ impl<T> IdealGasContribution for T where
 {
 }
 
-struct DefaultIdealGasContribution;
+/// Ideal gas contribution in which the de Broglie wavelength is 1
+/// for every component.
+///
+/// It yields the correct ideal gas pressure but no meaningful caloric
+/// properties.
+pub struct DefaultIdealGasContribution;
+
 impl<D: DualNum<f64>> IdealGasContributionDual<D> for DefaultIdealGasContribution {
     fn de_broglie_wavelength(&self, _: D, components: usize) -> Array1<D> {
         Array1::zeros(components)
@@ -153,6 +160,48 @@ pub trait MolarWeight {
     fn molar_weight(&self) -> SIArray1;
 }
 
+/// Strategy for the calculation of the maximum density of an equation of state.
+///
+/// The model specific heuristic, typically based on a maximum packing fraction,
+/// can overshoot into nonphysical regions, e.g., for very asymmetric mixtures.
+/// In that case, the result can be capped or replaced by a user supplied density.
+#[derive(Clone, Copy, Debug, PartialEq, Default, Serialize, Deserialize)]
+pub enum MaxDensity {
+    /// Use the heuristic of the model.
+    #[default]
+    Heuristic,
+    /// Use the heuristic of the model but limit it to the given (reduced) density.
+    Capped(f64),
+    /// Use the given (reduced) density independent of the composition.
+    Fixed(f64),
+}
+
+impl MaxDensity {
+    /// Limit the heuristic of the model to the given density.
+    pub fn capped(density: SINumber) -> EosResult<Self> {
+        Ok(Self::Capped(
+            density.to_reduced(SIUnit::reference_density())?,
+        ))
+    }
+
+    /// Use the given density independent of the composition.
+    pub fn fixed(density: SINumber) -> EosResult<Self> {
+        Ok(Self::Fixed(
+            density.to_reduced(SIUnit::reference_density())?,
+        ))
+    }
+
+    /// Apply the strategy to the (reduced) maximum density obtained from the
+    /// heuristic of the model.
+    pub fn apply(&self, heuristic: f64) -> f64 {
+        match *self {
+            Self::Heuristic => heuristic,
+            Self::Capped(density) => heuristic.min(density),
+            Self::Fixed(density) => density,
+        }
+    }
+}
+
 /// A general equation of state.
 pub trait EquationOfState: Send + Sync {
     /// Return the number of components of the equation of state.
@@ -168,6 +217,9 @@ pub trait EquationOfState: Send + Sync {
     /// equilibria and other iterations. It is not explicitly meant to
     /// be a mathematical limit for the density (if those exist in the
     /// equation of state anyways).
+    ///
+    /// Models that allow a user defined strategy should pass their
+    /// heuristic through [MaxDensity::apply].
     fn compute_max_density(&self, moles: &Array1<f64>) -> f64;
 
     /// Return a slice of the individual contributions (excluding the ideal gas)
@@ -12,6 +12,8 @@ pub enum EosError {
     NotConverged(String),
     #[error("`{0}` encountered illegal values during the iteration.")]
     IterationFailed(String),
+    #[error("`{0}` was cancelled.")]
+    Cancelled(String),
     #[error("Iteration resulted in trivial solution.")]
     TrivialSolution,
     #[error("Equation of state is initialized for {0} components while the input specifies {1} components.")]
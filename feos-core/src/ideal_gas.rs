@@ -0,0 +1,582 @@
+//! Ideal gas heat capacities from NASA polynomials and DIPPR correlations.
+//!
+//! In contrast to the group contribution method of [Joback](crate::joback),
+//! the models in this module are fitted to reference data of individual
+//! substances. The model can be chosen for every component separately
+//! via its [IdealGasRecord].
+//!
+//! Enthalpies and entropies are evaluated relative to the same reference
+//! state as in the Joback model ($T_0=298.15\\,\mathrm{K}$, $p_0=1\\,\mathrm{bar}$),
+//! i.e., the integration constants of the NASA polynomials are not used.
+use crate::joback::{JobackRecord, A3, KB, P0, RGAS, T0};
+use crate::{
+    EosError, EosResult, EosUnit, EquationOfState, HelmholtzEnergy, IdealGasContribution,
+    IdealGasContributionDual,
+};
+use ndarray::Array1;
+use num_dual::DualNum;
+use quantity::si::{SINumber, SIUnit};
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+/// Coefficients of the NASA 7-coefficient polynomials.
+///
+/// The heat capacity is given by
+/// $$\frac{c_p^\mathrm{ig}}{R}=a_1+a_2T+a_3T^2+a_4T^3+a_5T^4$$
+/// with separate coefficients below (`low`) and above (`high`) the
+/// temperature `t_mid` (in K). The coefficients $a_6$ and $a_7$ are
+/// integration constants that are only stored for completeness.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct Nasa7Record {
+    t_mid: f64,
+    low: [f64; 7],
+    high: [f64; 7],
+}
+
+impl Nasa7Record {
+    /// Creates a new `Nasa7Record`
+    pub fn new(t_mid: f64, low: [f64; 7], high: [f64; 7]) -> Self {
+        Self { t_mid, low, high }
+    }
+
+    fn coefs(&self, interval: usize) -> &[f64; 7] {
+        if interval == 0 {
+            &self.low
+        } else {
+            &self.high
+        }
+    }
+
+    fn c_p(&self, t: f64) -> f64 {
+        let a = self.coefs(interval(&[self.t_mid], t));
+        a[0] + t * (a[1] + t * (a[2] + t * (a[3] + t * a[4])))
+    }
+
+    fn enthalpy_entropy<D: DualNum<f64>>(&self, t: D) -> (D, D) {
+        let h = |i, t: D| {
+            let a = self.coefs(i);
+            t * (t * (t * (t * (t * a[4] / 5.0 + a[3] / 4.0) + a[2] / 3.0) + a[1] / 2.0) + a[0])
+        };
+        let s = |i, t: D| {
+            let a = self.coefs(i);
+            t.ln() * a[0] + t * (t * (t * (t * a[4] / 4.0 + a[3] / 3.0) + a[2] / 2.0) + a[1])
+        };
+        let bounds = [self.t_mid];
+        (integrate(&bounds, t, h), integrate(&bounds, t, s))
+    }
+}
+
+impl fmt::Display for Nasa7Record {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "Nasa7Record(t_mid={}, low={:?}, high={:?})",
+            self.t_mid, self.low, self.high
+        )
+    }
+}
+
+/// A single temperature interval of the NASA 9-coefficient polynomials.
+///
+/// The heat capacity is given by
+/// $$\frac{c_p^\mathrm{ig}}{R}=a_1T^{-2}+a_2T^{-1}+a_3+a_4T+a_5T^2+a_6T^3+a_7T^4$$
+/// in the interval from `t_min` to `t_max` (in K). The coefficients $a_8$
+/// and $a_9$ are integration constants that are only stored for completeness.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct Nasa9Interval {
+    t_min: f64,
+    t_max: f64,
+    coefs: [f64; 9],
+}
+
+impl Nasa9Interval {
+    /// Creates a new `Nasa9Interval`
+    pub fn new(t_min: f64, t_max: f64, coefs: [f64; 9]) -> Self {
+        Self {
+            t_min,
+            t_max,
+            coefs,
+        }
+    }
+}
+
+/// Coefficients of the NASA 9-coefficient polynomials.
+///
+/// The intervals have to be sorted by temperature. Outside of the
+/// covered temperature range, the polynomials of the first and the last
+/// interval are extrapolated.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct Nasa9Record {
+    intervals: Vec<Nasa9Interval>,
+}
+
+impl Nasa9Record {
+    /// Creates a new `Nasa9Record`
+    pub fn new(intervals: Vec<Nasa9Interval>) -> Self {
+        Self { intervals }
+    }
+
+    fn bounds(&self) -> Vec<f64> {
+        self.intervals[..self.intervals.len() - 1]
+            .iter()
+            .map(|i| i.t_max)
+            .collect()
+    }
+
+    fn c_p(&self, t: f64) -> f64 {
+        let a = &self.intervals[interval(&self.bounds(), t)].coefs;
+        (a[0] / t + a[1]) / t + a[2] + t * (a[3] + t * (a[4] + t * (a[5] + t * a[6])))
+    }
+
+    fn enthalpy_entropy<D: DualNum<f64>>(&self, t: D) -> (D, D) {
+        let h = |i: usize, t: D| {
+            let a = &self.intervals[i].coefs;
+            -t.recip() * a[0]
+                + t.ln() * a[1]
+                + t * (t * (t * (t * (t * a[6] / 5.0 + a[5] / 4.0) + a[4] / 3.0) + a[3] / 2.0)
+                    + a[2])
+        };
+        let s = |i: usize, t: D| {
+            let a = &self.intervals[i].coefs;
+            -t.powi(-2) * (a[0] / 2.0) - t.recip() * a[1]
+                + t.ln() * a[2]
+                + t * (t * (t * (t * a[6] / 4.0 + a[5] / 3.0) + a[4] / 2.0) + a[3])
+        };
+        let bounds = self.bounds();
+        (integrate(&bounds, t, h), integrate(&bounds, t, s))
+    }
+}
+
+impl fmt::Display for Nasa9Record {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Nasa9Record(intervals=[")?;
+        for (i, interval) in self.intervals.iter().enumerate() {
+            if i > 0 {
+                write!(f, ", ")?;
+            }
+            write!(
+                f,
+                "(t_min={}, t_max={}, coefs={:?})",
+                interval.t_min, interval.t_max, interval.coefs
+            )?;
+        }
+        write!(f, "])")
+    }
+}
+
+/// Ideal gas heat capacity correlations of the DIPPR database.
+///
+/// All coefficients are given for heat capacities in units of
+/// J/(kmol K), as tabulated in the DIPPR database.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(tag = "equation")]
+pub enum DipprRecord {
+    /// DIPPR equation 100:
+    /// $c_p^\mathrm{ig}=A+BT+CT^2+DT^3+ET^4$
+    #[serde(rename = "100")]
+    Eq100 { coefs: [f64; 5] },
+    /// DIPPR equation 107 (Aly and Lee):
+    /// $c_p^\mathrm{ig}=A+B\left(\frac{C/T}{\sinh(C/T)}\right)^2+D\left(\frac{E/T}{\cosh(E/T)}\right)^2$
+    #[serde(rename = "107")]
+    Eq107 { coefs: [f64; 5] },
+}
+
+impl DipprRecord {
+    /// Creates a new `DipprRecord` for DIPPR equation 100
+    pub fn eq100(coefs: [f64; 5]) -> Self {
+        Self::Eq100 { coefs }
+    }
+
+    /// Creates a new `DipprRecord` for DIPPR equation 107
+    pub fn eq107(coefs: [f64; 5]) -> Self {
+        Self::Eq107 { coefs }
+    }
+
+    fn c_p(&self, t: f64) -> f64 {
+        match self {
+            Self::Eq100 { coefs: c } => c[0] + t * (c[1] + t * (c[2] + t * (c[3] + t * c[4]))),
+            Self::Eq107 { coefs: c } => {
+                let x = c[2] / t;
+                let y = c[4] / t;
+                c[0] + c[1] * (x / x.sinh()).powi(2) + c[3] * (y / y.cosh()).powi(2)
+            }
+        }
+    }
+
+    /// Antiderivatives of $c_p^\mathrm{ig}$ and $c_p^\mathrm{ig}/T$.
+    fn antiderivatives<D: DualNum<f64>>(&self, t: D) -> (D, D) {
+        match self {
+            Self::Eq100 { coefs: c } => (
+                t * (t * (t * (t * (t * c[4] / 5.0 + c[3] / 4.0) + c[2] / 3.0) + c[1] / 2.0)
+                    + c[0]),
+                t.ln() * c[0] + t * (t * (t * (t * c[4] / 4.0 + c[3] / 3.0) + c[2] / 2.0) + c[1]),
+            ),
+            Self::Eq107 { coefs: c } => {
+                let x = t.recip() * c[2];
+                let y = t.recip() * c[4];
+                (
+                    t * c[0] + x.tanh().recip() * (c[1] * c[2]) - y.tanh() * (c[3] * c[4]),
+                    t.ln() * c[0] + (x / x.tanh() - x.sinh().ln()) * c[1]
+                        - (y * y.tanh() - y.cosh().ln()) * c[3],
+                )
+            }
+        }
+    }
+
+    fn enthalpy_entropy<D: DualNum<f64>>(&self, t: D) -> (D, D) {
+        let (h, s) = self.antiderivatives(t);
+        let (h0, s0) = self.antiderivatives(D::from(T0));
+        (h - h0, s - s0)
+    }
+}
+
+impl fmt::Display for DipprRecord {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Eq100 { coefs } => write!(f, "DipprRecord(equation=100, coefs={:?})", coefs),
+            Self::Eq107 { coefs } => write!(f, "DipprRecord(equation=107, coefs={:?})", coefs),
+        }
+    }
+}
+
+/// Ideal gas heat capacity model of a single component.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "snake_case")]
+pub enum IdealGasRecord {
+    /// Group contribution method of Joback and Reid
+    Joback(JobackRecord),
+    /// NASA 7-coefficient polynomials
+    Nasa7(Nasa7Record),
+    /// NASA 9-coefficient polynomials
+    Nasa9(Nasa9Record),
+    /// DIPPR correlations
+    Dippr(DipprRecord),
+}
+
+impl IdealGasRecord {
+    /// Ideal gas heat capacity in units of $R$.
+    fn c_p(&self, t: f64) -> f64 {
+        match self {
+            Self::Joback(r) => r.c_p(t) / RGAS,
+            Self::Nasa7(r) => r.c_p(t),
+            Self::Nasa9(r) => r.c_p(t),
+            Self::Dippr(r) => r.c_p(t) * 1e-3 / RGAS,
+        }
+    }
+
+    /// Ideal gas enthalpy (in units of $R$K) and entropy (in units of $R$)
+    /// relative to the reference state.
+    fn enthalpy_entropy<D: DualNum<f64>>(&self, t: D) -> (D, D) {
+        match self {
+            Self::Joback(r) => {
+                let (h, s) = r.enthalpy_entropy(t);
+                (h / RGAS, s / RGAS)
+            }
+            Self::Nasa7(r) => r.enthalpy_entropy(t),
+            Self::Nasa9(r) => r.enthalpy_entropy(t),
+            Self::Dippr(r) => {
+                let (h, s) = r.enthalpy_entropy(t);
+                (h * (1e-3 / RGAS), s * (1e-3 / RGAS))
+            }
+        }
+    }
+}
+
+impl fmt::Display for IdealGasRecord {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Joback(r) => write!(f, "{}", r),
+            Self::Nasa7(r) => write!(f, "{}", r),
+            Self::Nasa9(r) => write!(f, "{}", r),
+            Self::Dippr(r) => write!(f, "{}", r),
+        }
+    }
+}
+
+impl From<JobackRecord> for IdealGasRecord {
+    fn from(record: JobackRecord) -> Self {
+        Self::Joback(record)
+    }
+}
+
+impl From<Nasa7Record> for IdealGasRecord {
+    fn from(record: Nasa7Record) -> Self {
+        Self::Nasa7(record)
+    }
+}
+
+impl From<Nasa9Record> for IdealGasRecord {
+    fn from(record: Nasa9Record) -> Self {
+        Self::Nasa9(record)
+    }
+}
+
+impl From<DipprRecord> for IdealGasRecord {
+    fn from(record: DipprRecord) -> Self {
+        Self::Dippr(record)
+    }
+}
+
+/// Ideal gas contribution with an individual heat capacity model
+/// for every component.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct IdealGasModel {
+    pub records: Vec<IdealGasRecord>,
+}
+
+impl IdealGasModel {
+    /// Creates a new ideal gas contribution.
+    pub fn new(records: Vec<IdealGasRecord>) -> Self {
+        Self { records }
+    }
+
+    /// Creates a Joback contribution for all components.
+    pub fn joback(records: Vec<JobackRecord>) -> Self {
+        Self::new(records.into_iter().map(IdealGasRecord::Joback).collect())
+    }
+
+    /// Creates a default ($c_p^\mathrm{ig}=0$) ideal gas contribution for the
+    /// given number of components.
+    pub fn default(components: usize) -> Self {
+        Self::joback(vec![JobackRecord::default(); components])
+    }
+
+    /// Check whether the number of components matches the given number.
+    pub fn validate_components(&self, components: usize) -> EosResult<()> {
+        if self.records.len() == components {
+            Ok(())
+        } else {
+            Err(EosError::IncompatibleComponents(
+                components,
+                self.records.len(),
+            ))
+        }
+    }
+
+    /// Directly calculates the ideal gas heat capacity.
+    pub fn c_p(&self, temperature: SINumber, molefracs: &Array1<f64>) -> EosResult<SINumber> {
+        let t = temperature.to_reduced(SIUnit::reference_temperature())?;
+        let c_p: f64 = self
+            .records
+            .iter()
+            .zip(molefracs.iter())
+            .map(|(r, &x)| x * r.c_p(t))
+            .sum();
+        Ok(c_p * SIUnit::gas_constant())
+    }
+}
+
+/// Equations of state and Helmholtz energy functionals with an
+/// exchangeable ideal gas contribution.
+pub trait WithIdealGas: Sized {
+    /// Replace the ideal gas contribution by the given model.
+    fn with_ideal_gas(self, ideal_gas: IdealGasModel) -> EosResult<Self>;
+}
+
+impl fmt::Display for IdealGasModel {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Ideal gas (")?;
+        for (i, record) in self.records.iter().enumerate() {
+            if i > 0 {
+                write!(f, ", ")?;
+            }
+            match record {
+                IdealGasRecord::Joback(_) => write!(f, "Joback"),
+                IdealGasRecord::Nasa7(_) => write!(f, "NASA7"),
+                IdealGasRecord::Nasa9(_) => write!(f, "NASA9"),
+                IdealGasRecord::Dippr(_) => write!(f, "DIPPR"),
+            }?;
+        }
+        write!(f, ")")
+    }
+}
+
+impl<D: DualNum<f64>> IdealGasContributionDual<D> for IdealGasModel {
+    fn de_broglie_wavelength(&self, temperature: D, components: usize) -> Array1<D> {
+        let t = temperature;
+        let f = (temperature * KB / (P0 * A3)).ln();
+        Array1::from_shape_fn(components, |i| {
+            let (h, s) = self.records[i].enthalpy_entropy(t);
+            (h - t * s) / t + f
+        })
+    }
+}
+
+impl EquationOfState for IdealGasModel {
+    fn components(&self) -> usize {
+        self.records.len()
+    }
+
+    fn subset(&self, component_list: &[usize]) -> Self {
+        let records = component_list
+            .iter()
+            .map(|&i| self.records[i].clone())
+            .collect();
+        Self::new(records)
+    }
+
+    fn compute_max_density(&self, _moles: &Array1<f64>) -> f64 {
+        1.0
+    }
+
+    fn residual(&self) -> &[Box<dyn HelmholtzEnergy>] {
+        &[]
+    }
+
+    fn ideal_gas(&self) -> &dyn IdealGasContribution {
+        self
+    }
+}
+
+/// Index of the temperature interval that contains `t`.
+fn interval(bounds: &[f64], t: f64) -> usize {
+    bounds.iter().take_while(|&&b| t > b).count()
+}
+
+/// Integral from $T_0$ to `t` of a piecewise defined function,
+/// given the antiderivatives `f` in all temperature intervals.
+fn integrate<D: DualNum<f64>, F: Fn(usize, D) -> D>(bounds: &[f64], t: D, f: F) -> D {
+    let i0 = interval(bounds, T0);
+    let i = interval(bounds, t.re());
+    let jump = |k: usize| f(k, D::from(bounds[k])) - f(k + 1, D::from(bounds[k]));
+    let mut res = f(i, t) - f(i0, D::from(T0));
+    if i >= i0 {
+        for k in i0..i {
+            res = res + jump(k);
+        }
+    } else {
+        for k in i..i0 {
+            res = res - jump(k);
+        }
+    }
+    res
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Contributions, State};
+    use approx::assert_relative_eq;
+    use ndarray::arr1;
+    use quantity::si::*;
+    use std::sync::Arc;
+
+    fn methane() -> Vec<IdealGasRecord> {
+        let records = r#"[
+            {
+                "nasa7": {
+                    "t_mid": 1000.0,
+                    "low": [5.14987613, -1.36709788e-2, 4.91800599e-5, -4.84743026e-8, 1.66693956e-11, -1.02466476e4, -4.64130376],
+                    "high": [7.48514950e-2, 1.33909467e-2, -5.73285809e-6, 1.22292535e-9, -1.01815230e-13, -9.46834459e3, 1.84373180e1]
+                }
+            },
+            {
+                "nasa9": {
+                    "intervals": [
+                        {
+                            "t_min": 200.0,
+                            "t_max": 1000.0,
+                            "coefs": [-1.766850998e5, 2.786181020e3, -1.202577850e1, 3.917619290e-2, -3.619054430e-5, 2.026853043e-8, -4.976705490e-12, -2.331314360e4, 8.904322750e1]
+                        },
+                        {
+                            "t_min": 1000.0,
+                            "t_max": 6000.0,
+                            "coefs": [3.730042760e6, -1.383501485e4, 2.049107091e1, -1.961974759e-3, 4.727313040e-7, -3.728814690e-11, 1.623737207e-15, 7.532066910e4, -1.219124889e2]
+                        }
+                    ]
+                }
+            },
+            {
+                "dippr": {
+                    "equation": "107",
+                    "coefs": [0.33298e5, 0.79933e5, 2.0869e3, 0.41602e5, 991.96]
+                }
+            },
+            {
+                "dippr": {
+                    "equation": "100",
+                    "coefs": [19250.0, 52.13, 0.01197, -1.132e-5, 0.0]
+                }
+            },
+            {
+                "joback": {
+                    "a": 19.25,
+                    "b": 0.05213,
+                    "c": 1.197e-5,
+                    "d": -1.132e-8,
+                    "e": 0.0
+                }
+            }
+        ]"#;
+        serde_json::from_str(records).expect("Unable to parse json.")
+    }
+
+    #[test]
+    fn methane_c_p() -> EosResult<()> {
+        let records = methane();
+        for record in &records[..3] {
+            let ig = IdealGasModel::new(vec![record.clone()]);
+            let c_p = ig.c_p(298.15 * KELVIN, &arr1(&[1.0]))?;
+            assert_relative_eq!(
+                c_p.to_reduced(JOULE / MOL / KELVIN)?,
+                35.69,
+                max_relative = 1e-3
+            );
+        }
+        let dippr = IdealGasModel::new(vec![records[3].clone()]);
+        let joback = IdealGasModel::new(vec![records[4].clone()]);
+        let x = arr1(&[1.0]);
+        assert_relative_eq!(
+            dippr.c_p(500.0 * KELVIN, &x)?,
+            joback.c_p(500.0 * KELVIN, &x)?,
+            max_relative = 1e-10
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn c_p_comparison() -> EosResult<()> {
+        let ig = Arc::new(IdealGasModel::new(methane()));
+        let moles = arr1(&[1.0, 2.0, 3.0, 4.0, 5.0]) * MOL;
+        for &t in &[250.0, 298.15, 600.0, 1500.0] {
+            let temperature = t * KELVIN;
+            let state = State::new_nvt(&ig, temperature, METER.powi(3), &moles)?;
+            assert_relative_eq!(
+                ig.c_p(temperature, &state.molefracs)?,
+                state.c_p(Contributions::IdealGas),
+                max_relative = 1e-10
+            );
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn reference_state() -> EosResult<()> {
+        let ig = Arc::new(IdealGasModel::new(methane()));
+        let moles = arr1(&[1.0, 2.0, 3.0, 4.0, 5.0]) * MOL;
+        let state = State::new_nvt(&ig, 298.15 * KELVIN, METER.powi(3), &moles)?;
+        assert_relative_eq!(
+            state.molar_enthalpy(Contributions::IdealGas),
+            0.0 * JOULE / MOL,
+            epsilon = 1e-8
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn enthalpy_continuity() -> EosResult<()> {
+        let ig = Arc::new(IdealGasModel::new(methane()[..2].to_vec()));
+        let moles = arr1(&[1.0, 1.0]) * MOL;
+        let h = |t: f64| {
+            State::new_nvt(&ig, t * KELVIN, METER.powi(3), &moles)
+                .map(|s| s.molar_enthalpy(Contributions::IdealGas))
+        };
+        let s = |t: f64| {
+            State::new_nvt(&ig, t * KELVIN, METER.powi(3), &moles)
+                .map(|s| s.molar_entropy(Contributions::IdealGas))
+        };
+        assert_relative_eq!(h(1000.0 - 1e-9)?, h(1000.0 + 1e-9)?, max_relative = 1e-8);
+        assert_relative_eq!(s(1000.0 - 1e-9)?, s(1000.0 + 1e-9)?, max_relative = 1e-8);
+        Ok(())
+    }
+}
@@ -89,7 +89,7 @@ impl Joback {
         let t = temperature.to_reduced(SIUnit::reference_temperature())?;
         let mut c_p = 0.0;
         for (j, &x) in self.records.iter().zip(molefracs.iter()) {
-            c_p += x * (j.a + j.b * t + j.c * t.powi(2) + j.d * t.powi(3) + j.e * t.powi(4));
+            c_p += x * j.c_p(t);
         }
         Ok(c_p / RGAS * SIUnit::gas_constant())
     }
@@ -101,29 +101,42 @@ impl fmt::Display for Joback {
     }
 }
 
-const RGAS: f64 = 6.022140857 * 1.38064852;
-const T0: f64 = 298.15;
-const P0: f64 = 1.0e5;
-const A3: f64 = 1e-30;
-const KB: f64 = 1.38064852e-23;
+pub(crate) const RGAS: f64 = 6.022140857 * 1.38064852;
+pub(crate) const T0: f64 = 298.15;
+pub(crate) const P0: f64 = 1.0e5;
+pub(crate) const A3: f64 = 1e-30;
+pub(crate) const KB: f64 = 1.38064852e-23;
+
+impl JobackRecord {
+    /// Ideal gas heat capacity in units of J/mol/K.
+    pub(crate) fn c_p(&self, t: f64) -> f64 {
+        self.a + self.b * t + self.c * t.powi(2) + self.d * t.powi(3) + self.e * t.powi(4)
+    }
+
+    /// Ideal gas enthalpy (in J/mol) and entropy (in J/mol/K) relative to
+    /// the reference state at $T_0$ and $p_0$.
+    pub(crate) fn enthalpy_entropy<D: DualNum<f64>>(&self, t: D) -> (D, D) {
+        let t2 = t * t;
+        let h = (t2 - T0 * T0) * 0.5 * self.b
+            + (t * t2 - T0.powi(3)) * self.c / 3.0
+            + (t2 * t2 - T0.powi(4)) * self.d / 4.0
+            + (t2 * t2 * t - T0.powi(5)) * self.e / 5.0
+            + (t - T0) * self.a;
+        let s = (t - T0) * self.b
+            + (t2 - T0.powi(2)) * 0.5 * self.c
+            + (t2 * t - T0.powi(3)) * self.d / 3.0
+            + (t2 * t2 - T0.powi(4)) * self.e / 4.0
+            + (t / T0).ln() * self.a;
+        (h, s)
+    }
+}
 
 impl<D: DualNum<f64>> IdealGasContributionDual<D> for Joback {
     fn de_broglie_wavelength(&self, temperature: D, components: usize) -> Array1<D> {
         let t = temperature;
-        let t2 = t * t;
         let f = (temperature * KB / (P0 * A3)).ln();
         Array1::from_shape_fn(components, |i| {
-            let j = &self.records[i];
-            let h = (t2 - T0 * T0) * 0.5 * j.b
-                + (t * t2 - T0.powi(3)) * j.c / 3.0
-                + (t2 * t2 - T0.powi(4)) * j.d / 4.0
-                + (t2 * t2 * t - T0.powi(5)) * j.e / 5.0
-                + (t - T0) * j.a;
-            let s = (t - T0) * j.b
-                + (t2 - T0.powi(2)) * 0.5 * j.c
-                + (t2 * t - T0.powi(3)) * j.d / 3.0
-                + (t2 * t2 - T0.powi(4)) * j.e / 4.0
-                + (t / T0).ln() * j.a;
+            let (h, s) = self.records[i].enthalpy_entropy(t);
             (h - t * s) / (t * RGAS) + f
         })
     }
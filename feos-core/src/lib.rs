@@ -26,25 +26,41 @@ macro_rules! log_result {
     }
 }
 
+pub mod consistency;
 pub mod cubic;
 mod density_iteration;
 mod equation_of_state;
 mod errors;
+pub mod ideal_gas;
 pub mod joback;
 pub mod parameter;
 mod phase_equilibria;
+mod process;
+mod progress;
+mod property_provider;
+mod reduced_units;
+mod solver_log;
 mod state;
+mod temperature_cache;
 pub use equation_of_state::{
-    EntropyScaling, EquationOfState, HelmholtzEnergy, HelmholtzEnergyDual, IdealGasContribution,
-    IdealGasContributionDual, MolarWeight,
+    DefaultIdealGasContribution, EntropyScaling, EquationOfState, HelmholtzEnergy,
+    HelmholtzEnergyDual, IdealGasContribution, IdealGasContributionDual, MaxDensity, MolarWeight,
 };
 pub use errors::{EosError, EosResult};
 pub use phase_equilibria::{
-    PhaseDiagram, PhaseDiagramHetero, PhaseEquilibrium, SolverOptions, Verbosity,
+    ChemicalEquilibrium, ChemicalReactions, PhaseDiagram, PhaseDiagramHetero, PhaseDiagramSolid,
+    PhaseEquilibrium, SolidModel, SolverOptions, Verbosity,
 };
+pub use process::{Efficiency, Isoline, Stream};
+pub use progress::{Progress, ProgressCallback};
+pub use property_provider::{FluidProperties, PropertyProvider, TransportPropertyProvider};
+pub use reduced_units::ReducedUnits;
+pub use solver_log::{SolverIteration, SolverLog};
 pub use state::{
-    Contributions, DensityInitialization, Derivative, State, StateBuilder, StateHD, StateVec,
+    Contributions, DensityInitialization, Derivative, ParameterIssue, ParameterScan,
+    ParameterSensitivity, State, StateBuilder, StateHD, StateVec,
 };
+pub use temperature_cache::TemperatureCache;
 
 #[cfg(feature = "python")]
 pub mod python;
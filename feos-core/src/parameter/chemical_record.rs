@@ -93,6 +93,34 @@ impl ChemicalRecord {
         }
         bond_counts
     }
+
+    /// Determine the bonds that close rings in the chemical record.
+    ///
+    /// The bonds are processed in the given order and every bond that
+    /// connects two segments which are already connected by previous
+    /// bonds closes a ring. The remaining bonds form a spanning tree of
+    /// the molecule and the number of ring closures is equal to the
+    /// number of rings.
+    pub fn ring_closures(&self) -> Vec<[usize; 2]> {
+        fn root(parent: &mut [usize], mut i: usize) -> usize {
+            while parent[i] != i {
+                parent[i] = parent[parent[i]];
+                i = parent[i];
+            }
+            i
+        }
+
+        let mut parent: Vec<_> = (0..self.segments.len()).collect();
+        self.bonds
+            .iter()
+            .filter(|&&[i, j]| {
+                let (ri, rj) = (root(&mut parent, i), root(&mut parent, j));
+                parent[ri] = rj;
+                ri == rj
+            })
+            .copied()
+            .collect()
+    }
 }
 
 impl std::fmt::Display for ChemicalRecord {
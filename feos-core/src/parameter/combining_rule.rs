@@ -0,0 +1,118 @@
+use ndarray::{Array1, Array2};
+use num_dual::DualNum;
+use serde::{Deserialize, Serialize};
+
+/// Combining rules for the size and energy parameters of unlike pair interactions.
+///
+/// The binary interaction parameters `k_ij` (and `l_ij`) of the models are
+/// applied as corrections to the combined parameters.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "python", pyo3::pyclass)]
+pub enum CombiningRule {
+    /// Arithmetic mean of the diameters and geometric mean of the energy parameters.
+    LorentzBerthelot,
+    /// Rule of [Kong (1973)](https://doi.org/10.1063/1.1679903) that combines the
+    /// repulsive and attractive parts of the Lennard-Jones potential separately.
+    Kong,
+    /// Rule of [Waldman and Hagler (1993)](https://doi.org/10.1002/jcc.540140909)
+    /// that combines the sixth power of the diameters arithmetically.
+    WaldmanHagler,
+    /// Geometric mean of both the diameters and the energy parameters.
+    GeometricSigma,
+}
+
+impl Default for CombiningRule {
+    fn default() -> Self {
+        Self::LorentzBerthelot
+    }
+}
+
+impl CombiningRule {
+    /// Cross diameter and cross energy parameter of components `i` and `j`.
+    pub fn apply<D: DualNum<f64>>(&self, sigma: [D; 2], epsilon_k: [D; 2]) -> (D, D) {
+        let [s_i, s_j] = sigma;
+        let [e_i, e_j] = epsilon_k;
+        match self {
+            Self::LorentzBerthelot => ((s_i + s_j) * 0.5, (e_i * e_j).sqrt()),
+            Self::Kong => {
+                let s6_e = (e_i * s_i.powi(6) * e_j * s_j.powi(6)).sqrt();
+                let s12_e = ((e_i * s_i.powi(12)).powf(1.0 / 13.0)
+                    + (e_j * s_j.powi(12)).powf(1.0 / 13.0))
+                .powi(13)
+                    / 2f64.powi(13);
+                let s6 = s12_e / s6_e;
+                (s6.powf(1.0 / 6.0), s6_e / s6)
+            }
+            Self::WaldmanHagler => {
+                let s6 = (s_i.powi(6) + s_j.powi(6)) * 0.5;
+                (
+                    s6.powf(1.0 / 6.0),
+                    (e_i * e_j).sqrt() * s_i.powi(3) * s_j.powi(3) / s6,
+                )
+            }
+            Self::GeometricSigma => ((s_i * s_j).sqrt(), (e_i * e_j).sqrt()),
+        }
+    }
+
+    /// Matrices of the cross diameters and cross energy parameters of all pairs
+    /// of components. The diagonals contain the pure component parameters.
+    pub fn cross_parameters(
+        &self,
+        sigma: &Array1<f64>,
+        epsilon_k: &Array1<f64>,
+    ) -> (Array2<f64>, Array2<f64>) {
+        let n = sigma.len();
+        let mut sigma_ij = Array2::from_diag(sigma);
+        let mut epsilon_k_ij = Array2::from_diag(epsilon_k);
+        for i in 0..n {
+            for j in i + 1..n {
+                let (s, e) = self.apply([sigma[i], sigma[j]], [epsilon_k[i], epsilon_k[j]]);
+                sigma_ij[[i, j]] = s;
+                sigma_ij[[j, i]] = s;
+                epsilon_k_ij[[i, j]] = e;
+                epsilon_k_ij[[j, i]] = e;
+            }
+        }
+        (sigma_ij, epsilon_k_ij)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_relative_eq;
+
+    #[test]
+    fn like_interactions() {
+        for rule in [
+            CombiningRule::LorentzBerthelot,
+            CombiningRule::Kong,
+            CombiningRule::WaldmanHagler,
+            CombiningRule::GeometricSigma,
+        ] {
+            let (sigma, epsilon_k) = rule.apply([3.7, 3.7], [150.0, 150.0]);
+            assert_relative_eq!(sigma, 3.7, max_relative = 1e-12);
+            assert_relative_eq!(epsilon_k, 150.0, max_relative = 1e-12);
+        }
+    }
+
+    #[test]
+    fn unlike_interactions() {
+        let (sigma, epsilon_k) = CombiningRule::WaldmanHagler.apply([3.0, 4.0], [100.0, 200.0]);
+        let s6 = 0.5 * (3.0f64.powi(6) + 4.0f64.powi(6));
+        assert_relative_eq!(sigma, s6.powf(1.0 / 6.0), max_relative = 1e-12);
+        assert_relative_eq!(
+            epsilon_k,
+            20000.0f64.sqrt() * 27.0 * 64.0 / s6,
+            max_relative = 1e-12
+        );
+
+        // Kong's rule conserves the combined attractive term of the LJ potential
+        let (sigma, epsilon_k) = CombiningRule::Kong.apply([3.0, 4.0], [100.0, 200.0]);
+        assert_relative_eq!(
+            epsilon_k * sigma.powi(6),
+            (100.0 * 3.0f64.powi(6) * 200.0 * 4.0f64.powi(6)).sqrt(),
+            max_relative = 1e-12
+        );
+    }
+}
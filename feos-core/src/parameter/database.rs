@@ -0,0 +1,420 @@
+use super::identifier::Identifier;
+use super::model_record::{BinaryRecord, PureRecord};
+use super::{Parameter, ParameterError};
+use indexmap::IndexSet;
+use ndarray::Array2;
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::fs::File;
+use std::io::BufReader;
+use std::path::Path;
+
+/// Origin of a record in a [ParameterDatabase].
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+pub struct Provenance {
+    /// Name of the source, e.g., the path of the json file
+    pub source: String,
+    /// Priority of the source
+    pub priority: i32,
+    /// Position of the record within the source
+    pub index: usize,
+}
+
+impl std::fmt::Display for Provenance {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{} (record {}, priority {})",
+            self.source, self.index, self.priority
+        )
+    }
+}
+
+/// An indexed collection of pure and binary records from multiple sources.
+///
+/// Components are resolved by their CAS number, name, IUPAC name,
+/// InChIKey or any of their aliases. The lookup ignores the case and
+/// surrounding whitespace. Records from all sources are kept and every
+/// source is assigned a priority. If multiple records match a query,
+/// the record with the highest priority is used. Multiple matching
+/// records with the same priority result in an error.
+///
+/// # Examples
+///
+/// ```no_run
+/// # use feos_core::parameter::{ParameterDatabase, ParameterError};
+/// # use feos_core::joback::JobackRecord;
+/// # fn main() -> Result<(), ParameterError> {
+/// # type PcSaftRecord = f64;
+/// let mut database: ParameterDatabase<PcSaftRecord, JobackRecord, f64> = ParameterDatabase::new();
+/// database.load_json("parameters/literature.json", 0)?;
+/// database.load_json("parameters/in_house.json", 10)?;
+/// database.load_binary_json("parameters/binary.json", 0)?;
+/// for (record, provenance) in database.resolve(&["74-82-8", "n-butane"])? {
+///     println!("{} from {}", record.identifier, provenance);
+/// }
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone)]
+pub struct ParameterDatabase<M, I, B> {
+    pure_records: Vec<(PureRecord<M, I>, Provenance)>,
+    binary_records: Vec<(BinaryRecord<Identifier, B>, Provenance)>,
+    index: HashMap<String, Vec<usize>>,
+}
+
+impl<M, I, B> Default for ParameterDatabase<M, I, B> {
+    fn default() -> Self {
+        Self {
+            pure_records: Vec::new(),
+            binary_records: Vec::new(),
+            index: HashMap::new(),
+        }
+    }
+}
+
+/// Normalized search keys of an identifier.
+fn keys(identifier: &Identifier) -> IndexSet<String> {
+    vec![
+        &identifier.cas,
+        &identifier.name,
+        &identifier.iupac_name,
+        &identifier.inchikey,
+    ]
+    .into_iter()
+    .flatten()
+    .chain(identifier.aliases.iter())
+    .map(|key| normalize(key))
+    .collect()
+}
+
+fn normalize(key: &str) -> String {
+    key.split_whitespace()
+        .collect::<Vec<_>>()
+        .join(" ")
+        .to_lowercase()
+}
+
+impl<M, I, B> ParameterDatabase<M, I, B> {
+    /// Create an empty database.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Number of pure records in the database.
+    pub fn len(&self) -> usize {
+        self.pure_records.len()
+    }
+
+    /// Returns `true` if the database contains no pure records.
+    pub fn is_empty(&self) -> bool {
+        self.pure_records.is_empty()
+    }
+
+    /// Add pure records from a source with the given priority.
+    pub fn add_records(&mut self, records: Vec<PureRecord<M, I>>, source: &str, priority: i32) {
+        for (index, record) in records.into_iter().enumerate() {
+            let i = self.pure_records.len();
+            for key in keys(&record.identifier) {
+                self.index.entry(key).or_insert_with(Vec::new).push(i);
+            }
+            let provenance = Provenance {
+                source: source.into(),
+                priority,
+                index,
+            };
+            self.pure_records.push((record, provenance));
+        }
+    }
+
+    /// Add binary records from a source with the given priority.
+    pub fn add_binary_records(
+        &mut self,
+        records: Vec<BinaryRecord<Identifier, B>>,
+        source: &str,
+        priority: i32,
+    ) {
+        self.binary_records
+            .extend(records.into_iter().enumerate().map(|(index, record)| {
+                let provenance = Provenance {
+                    source: source.into(),
+                    priority,
+                    index,
+                };
+                (record, provenance)
+            }));
+    }
+
+    /// Find the pure record of a single substance.
+    pub fn find(
+        &self,
+        substance: &str,
+    ) -> Result<(&PureRecord<M, I>, &Provenance), ParameterError> {
+        let (record, provenance) = &self.pure_records[self.find_index(substance)?];
+        Ok((record, provenance))
+    }
+
+    fn find_index(&self, substance: &str) -> Result<usize, ParameterError> {
+        let candidates = self
+            .index
+            .get(&normalize(substance))
+            .ok_or_else(|| ParameterError::ComponentsNotFound(format!("{:?}", [substance])))?;
+        let priority = candidates
+            .iter()
+            .map(|&i| self.pure_records[i].1.priority)
+            .max()
+            .unwrap();
+        let best: Vec<_> = candidates
+            .iter()
+            .copied()
+            .filter(|&i| self.pure_records[i].1.priority == priority)
+            .collect();
+        if best.len() > 1 {
+            let sources: Vec<_> = best
+                .iter()
+                .map(|&i| self.pure_records[i].1.to_string())
+                .collect();
+            return Err(ParameterError::DuplicateRecords(
+                substance.into(),
+                sources.join(", "),
+            ));
+        }
+        Ok(best[0])
+    }
+
+    /// Find the pure records of all substances.
+    ///
+    /// Returns the records together with their provenance in the order
+    /// of the queried substances.
+    pub fn resolve(
+        &self,
+        substances: &[&str],
+    ) -> Result<Vec<(&PureRecord<M, I>, &Provenance)>, ParameterError> {
+        let missing: Vec<_> = substances
+            .iter()
+            .filter(|s| !self.index.contains_key(&normalize(s)))
+            .collect();
+        if !missing.is_empty() {
+            return Err(ParameterError::ComponentsNotFound(format!("{:?}", missing)));
+        }
+
+        let indices = substances
+            .iter()
+            .map(|s| self.find_index(s))
+            .collect::<Result<Vec<_>, _>>()?;
+        for (i, k1) in indices.iter().enumerate() {
+            for (j, k2) in indices.iter().enumerate().skip(i + 1) {
+                if k1 == k2 {
+                    return Err(ParameterError::IncompatibleParameters(format!(
+                        "'{}' and '{}' refer to the same record in {}.",
+                        substances[i], substances[j], self.pure_records[*k1].1
+                    )));
+                }
+            }
+        }
+        Ok(indices
+            .into_iter()
+            .map(|i| {
+                let (record, provenance) = &self.pure_records[i];
+                (record, provenance)
+            })
+            .collect())
+    }
+
+    /// Find the binary record for a pair of pure records.
+    ///
+    /// Binary records are matched to pure records if any of their
+    /// identifiers coincide.
+    pub fn binary_record(
+        &self,
+        record1: &PureRecord<M, I>,
+        record2: &PureRecord<M, I>,
+    ) -> Result<Option<(&B, &Provenance)>, ParameterError> {
+        Ok(self
+            .find_binary_record(record1, record2)?
+            .map(|(br, p)| (&br.model_record, p)))
+    }
+
+    /// Find the full binary record (including its metadata) for a pair of pure records.
+    fn find_binary_record(
+        &self,
+        record1: &PureRecord<M, I>,
+        record2: &PureRecord<M, I>,
+    ) -> Result<Option<&(BinaryRecord<Identifier, B>, Provenance)>, ParameterError> {
+        let keys1 = keys(&record1.identifier);
+        let keys2 = keys(&record2.identifier);
+        let matches = |id: &Identifier, other: &IndexSet<String>| !other.is_disjoint(&keys(id));
+        let candidates: Vec<_> = self
+            .binary_records
+            .iter()
+            .filter(|(br, _)| {
+                (matches(&br.id1, &keys1) && matches(&br.id2, &keys2))
+                    || (matches(&br.id1, &keys2) && matches(&br.id2, &keys1))
+            })
+            .collect();
+        let priority = match candidates.iter().map(|(_, p)| p.priority).max() {
+            Some(priority) => priority,
+            None => return Ok(None),
+        };
+        let best: Vec<_> = candidates
+            .into_iter()
+            .filter(|(_, p)| p.priority == priority)
+            .collect();
+        if best.len() > 1 {
+            let sources: Vec<_> = best.iter().map(|(_, p)| p.to_string()).collect();
+            return Err(ParameterError::DuplicateRecords(
+                format!("{} / {}", record1.identifier, record2.identifier),
+                sources.join(", "),
+            ));
+        }
+        Ok(Some(best[0]))
+    }
+}
+
+impl<M: DeserializeOwned, I: DeserializeOwned, B: DeserializeOwned> ParameterDatabase<M, I, B> {
+    /// Read pure records from a json file and add them with the given priority.
+    pub fn load_json<P: AsRef<Path>>(
+        &mut self,
+        file: P,
+        priority: i32,
+    ) -> Result<(), ParameterError> {
+        let source = file.as_ref().display().to_string();
+        let reader = BufReader::new(File::open(file)?);
+        let records: Vec<PureRecord<M, I>> = serde_json::from_reader(reader)?;
+        self.add_records(records, &source, priority);
+        Ok(())
+    }
+
+    /// Read binary records from a json file and add them with the given priority.
+    pub fn load_binary_json<P: AsRef<Path>>(
+        &mut self,
+        file: P,
+        priority: i32,
+    ) -> Result<(), ParameterError> {
+        let source = file.as_ref().display().to_string();
+        let reader = BufReader::new(File::open(file)?);
+        let records: Vec<BinaryRecord<Identifier, B>> = serde_json::from_reader(reader)?;
+        self.add_binary_records(records, &source, priority);
+        Ok(())
+    }
+}
+
+impl<M: Clone, I: Clone, B: Clone + Default> ParameterDatabase<M, I, B> {
+    /// Creates parameters for the given substances.
+    pub fn parameters<P>(&self, substances: &[&str]) -> Result<P, ParameterError>
+    where
+        P: Parameter<Pure = M, IdealGas = I, Binary = B>,
+    {
+        let records = self.resolve(substances)?;
+        let n = records.len();
+        let mut binary_records = Array2::from_elem([n, n], B::default());
+        let mut binary_metadata = Array2::from_elem([n, n], None);
+        for i in 0..n {
+            for j in i + 1..n {
+                if let Some((br, _)) = self.find_binary_record(records[i].0, records[j].0)? {
+                    binary_records[[i, j]] = br.model_record.clone();
+                    binary_records[[j, i]] = br.model_record.clone();
+                    binary_metadata[[i, j]] = br.metadata.clone();
+                    binary_metadata[[j, i]] = br.metadata.clone();
+                }
+            }
+        }
+        let pure_records = records.into_iter().map(|(r, _)| r.clone()).collect();
+        Ok(P::from_records_with_metadata(
+            pure_records,
+            binary_records,
+            binary_metadata,
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::joback::JobackRecord;
+
+    type Database = ParameterDatabase<f64, JobackRecord, f64>;
+
+    fn record(identifier: Identifier, value: f64) -> PureRecord<f64, JobackRecord> {
+        PureRecord::new(identifier, 1.0, value, None)
+    }
+
+    fn database() -> Database {
+        let methane = Identifier::new(Some("74-82-8"), Some("methane"), None, None, None, None)
+            .with_inchikey("VNWKTOKETHGBQD-UHFFFAOYSA-N");
+        let butane = Identifier::new(Some("106-97-8"), Some("butane"), None, None, None, None)
+            .with_aliases(&["n-butane", "Normal Butane"]);
+        let mut database = Database::new();
+        database.add_records(
+            vec![record(methane.clone(), 1.0), record(butane.clone(), 2.0)],
+            "literature",
+            0,
+        );
+        database.add_records(
+            vec![record(
+                Identifier::new(Some("74-82-8"), None, None, None, None, None),
+                3.0,
+            )],
+            "in-house",
+            10,
+        );
+        database.add_binary_records(
+            vec![BinaryRecord::new(methane, butane, 0.05)],
+            "literature",
+            0,
+        );
+        database
+    }
+
+    #[test]
+    fn lookup() -> Result<(), ParameterError> {
+        let database = database();
+        assert_eq!(database.len(), 3);
+        let (butane, provenance) = database.find("n-butane")?;
+        assert_eq!(butane.model_record, 2.0);
+        assert_eq!(provenance.source, "literature");
+        assert_eq!(database.find(" normal  BUTANE")?.0.model_record, 2.0);
+        assert_eq!(database.find("106-97-8")?.0.model_record, 2.0);
+
+        // the in-house record has the higher priority
+        let (methane, provenance) = database.find("methane")?;
+        assert_eq!(methane.model_record, 3.0);
+        assert_eq!(provenance.source, "in-house");
+        assert_eq!(
+            database.find("VNWKTOKETHGBQD-UHFFFAOYSA-N")?.0.model_record,
+            3.0
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn duplicates() {
+        let mut database = database();
+        let ethane = Identifier::new(Some("74-84-0"), Some("ethane"), None, None, None, None);
+        database.add_records(vec![record(ethane.clone(), 1.0)], "a", 0);
+        database.add_records(vec![record(ethane, 2.0)], "b", 0);
+        assert!(matches!(
+            database.find("ethane"),
+            Err(ParameterError::DuplicateRecords(_, _))
+        ));
+        assert!(matches!(
+            database.resolve(&["butane", "n-butane"]),
+            Err(ParameterError::IncompatibleParameters(_))
+        ));
+        assert!(matches!(
+            database.resolve(&["propane", "butane"]),
+            Err(ParameterError::ComponentsNotFound(_))
+        ));
+    }
+
+    #[test]
+    fn binary_records() -> Result<(), ParameterError> {
+        let database = database();
+        let records = database.resolve(&["butane", "74-82-8"])?;
+        let (kij, provenance) = database.binary_record(records[0].0, records[1].0)?.unwrap();
+        assert_eq!(*kij, 0.05);
+        assert_eq!(provenance.index, 0);
+        Ok(())
+    }
+}
@@ -10,6 +10,7 @@ pub enum IdentifierOption {
     IupacName,
     Smiles,
     Inchi,
+    InchiKey,
     Formula,
 }
 
@@ -36,10 +37,18 @@ pub struct Identifier {
     #[serde(default)]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub inchi: Option<String>,
+    /// InChIKey
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub inchikey: Option<String>,
     /// Chemical formula
     #[serde(default)]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub formula: Option<String>,
+    /// Alternative names
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub aliases: Vec<String>,
 }
 
 impl Identifier {
@@ -72,9 +81,22 @@ impl Identifier {
             smiles: smiles.map(Into::into),
             inchi: inchi.map(Into::into),
             formula: formula.map(Into::into),
+            ..Default::default()
         }
     }
 
+    /// Add an InChIKey to the identifier.
+    pub fn with_inchikey(mut self, inchikey: &str) -> Self {
+        self.inchikey = Some(inchikey.into());
+        self
+    }
+
+    /// Add alternative names to the identifier.
+    pub fn with_aliases(mut self, aliases: &[&str]) -> Self {
+        self.aliases = aliases.iter().map(|&a| a.into()).collect();
+        self
+    }
+
     pub fn as_string(&self, option: IdentifierOption) -> Option<String> {
         match option {
             IdentifierOption::Cas => self.cas.clone(),
@@ -82,6 +104,7 @@ impl Identifier {
             IdentifierOption::IupacName => self.iupac_name.clone(),
             IdentifierOption::Smiles => self.smiles.clone(),
             IdentifierOption::Inchi => self.inchi.clone(),
+            IdentifierOption::InchiKey => self.inchikey.clone(),
             IdentifierOption::Formula => self.formula.clone(),
         }
     }
@@ -105,9 +128,15 @@ impl std::fmt::Display for Identifier {
         if let Some(n) = &self.inchi {
             ids.push(format!("inchi={}", n));
         }
+        if let Some(n) = &self.inchikey {
+            ids.push(format!("inchikey={}", n));
+        }
         if let Some(n) = &self.formula {
             ids.push(format!("formula={}", n));
         }
+        if !self.aliases.is_empty() {
+            ids.push(format!("aliases=[{}]", self.aliases.join(", ")));
+        }
         write!(f, "Identifier({})", ids.join(", "))
     }
 }
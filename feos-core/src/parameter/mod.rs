@@ -3,21 +3,28 @@
 use indexmap::{IndexMap, IndexSet};
 use ndarray::Array2;
 use serde::de::DeserializeOwned;
+use serde::Serialize;
 use std::collections::HashMap;
 use std::fs::File;
 use std::io;
-use std::io::BufReader;
+use std::io::{BufReader, BufWriter};
 use std::path::Path;
 use thiserror::Error;
 
 mod chemical_record;
+mod combining_rule;
+mod database;
 mod identifier;
 mod model_record;
+mod parameter_set;
 mod segment;
 
 pub use chemical_record::{ChemicalRecord, SegmentCount};
+pub use combining_rule::CombiningRule;
+pub use database::{ParameterDatabase, Provenance};
 pub use identifier::{Identifier, IdentifierOption};
-pub use model_record::{BinaryRecord, FromSegments, FromSegmentsBinary, PureRecord};
+pub use model_record::{BinaryRecord, FromSegments, FromSegmentsBinary, Metadata, PureRecord};
+pub use parameter_set::{ParameterSet, ParameterSetHetero};
 pub use segment::SegmentRecord;
 
 /// Constructor methods for parameters.
@@ -39,6 +46,18 @@ where
         binary_records: Array2<Self::Binary>,
     ) -> Self;
 
+    /// Creates parameters from records for pure substances, binary parameters
+    /// and the metadata of the binary records.
+    ///
+    /// The default implementation discards the metadata.
+    fn from_records_with_metadata(
+        pure_records: Vec<PureRecord<Self::Pure, Self::IdealGas>>,
+        binary_records: Array2<Self::Binary>,
+        _binary_metadata: Array2<Option<Metadata>>,
+    ) -> Self {
+        Self::from_records(pure_records, binary_records)
+    }
+
     /// Creates parameters for a pure component from a pure record.
     fn new_pure(pure_record: PureRecord<Self::Pure, Self::IdealGas>) -> Self {
         let binary_record = Array2::from_elem([1, 1], Self::Binary::default());
@@ -70,48 +89,35 @@ where
         &Array2<Self::Binary>,
     );
 
+    /// Return the metadata of the binary records that were used to construct the
+    /// parameters or `None` if the parameters do not store them.
+    fn binary_metadata(&self) -> Option<&Array2<Option<Metadata>>> {
+        None
+    }
+
     /// Helper function to build matrix from list of records in correct order.
     ///
     /// If the identifiers in `binary_records` are not a subset of those in
     /// `pure_records`, the `Default` implementation of Self::Binary is used.
-    #[allow(clippy::expect_fun_call)]
     fn binary_matrix_from_records(
         pure_records: &Vec<PureRecord<Self::Pure, Self::IdealGas>>,
         binary_records: &[BinaryRecord<Identifier, Self::Binary>],
         search_option: IdentifierOption,
     ) -> Array2<Self::Binary> {
-        // Build Hashmap (id, id) -> BinaryRecord
-        let binary_map: HashMap<(String, String), Self::Binary> = {
-            binary_records
-                .iter()
-                .filter_map(|br| {
-                    let id1 = br.id1.as_string(search_option);
-                    let id2 = br.id2.as_string(search_option);
-                    id1.and_then(|id1| id2.map(|id2| ((id1, id2), br.model_record.clone())))
-                })
-                .collect()
-        };
-        let n = pure_records.len();
-        Array2::from_shape_fn([n, n], |(i, j)| {
-            let id1 = pure_records[i]
-                .identifier
-                .as_string(search_option)
-                .expect(&format!(
-                    "No identifier for given search_option for pure record {}.",
-                    i
-                ));
-            let id2 = pure_records[j]
-                .identifier
-                .as_string(search_option)
-                .expect(&format!(
-                    "No identifier for given search_option for pure record {}.",
-                    j
-                ));
-            binary_map
-                .get(&(id1.clone(), id2.clone()))
-                .or_else(|| binary_map.get(&(id2, id1)))
-                .cloned()
-                .unwrap_or_default()
+        matrix_from_binary_records(pure_records, binary_records, search_option, |br| {
+            br.model_record.clone()
+        })
+    }
+
+    /// Helper function to build the matrix of the metadata of binary records
+    /// in the same order as [binary_matrix_from_records](Parameter::binary_matrix_from_records).
+    fn binary_metadata_from_records(
+        pure_records: &Vec<PureRecord<Self::Pure, Self::IdealGas>>,
+        binary_records: &[BinaryRecord<Identifier, Self::Binary>],
+        search_option: IdentifierOption,
+    ) -> Array2<Option<Metadata>> {
+        matrix_from_binary_records(pure_records, binary_records, search_option, |br| {
+            br.metadata.clone()
         })
     }
 
@@ -193,7 +199,13 @@ where
             Vec::new()
         };
         let record_matrix = Self::binary_matrix_from_records(&p, &binary_records, search_option);
-        Ok(Self::from_records(p, record_matrix))
+        let metadata_matrix =
+            Self::binary_metadata_from_records(&p, &binary_records, search_option);
+        Ok(Self::from_records_with_metadata(
+            p,
+            record_matrix,
+            metadata_matrix,
+        ))
     }
 
     /// Creates parameters from the molecular structure and segment information.
@@ -344,11 +356,145 @@ where
         let binary_records = Array2::from_shape_fn([n, n], |(i, j)| {
             binary_records[(component_list[i], component_list[j])].clone()
         });
+        let binary_metadata = Array2::from_shape_fn([n, n], |(i, j)| {
+            self.binary_metadata()
+                .and_then(|m| m[(component_list[i], component_list[j])].clone())
+        });
 
-        Self::from_records(pure_records, binary_records)
+        Self::from_records_with_metadata(pure_records, binary_records, binary_metadata)
+    }
+
+    /// Return all records of the parameters in a serializable form.
+    fn parameter_set(&self) -> ParameterSet<Self::Pure, Self::IdealGas, Self::Binary> {
+        let (pure_records, binary_records) = self.records();
+        let n = pure_records.len();
+        let binary_records = (0..n)
+            .flat_map(|i| (i + 1..n).map(move |j| (i, j)))
+            .map(|(i, j)| BinaryRecord {
+                id1: i,
+                id2: j,
+                model_record: binary_records[(i, j)].clone(),
+                metadata: self.binary_metadata().and_then(|m| m[(i, j)].clone()),
+            })
+            .collect();
+        ParameterSet {
+            pure_records: pure_records.to_vec(),
+            binary_records,
+        }
+    }
+
+    /// Creates parameters from a [ParameterSet].
+    ///
+    /// Binary records that are not contained in the parameter set
+    /// are set to their `Default` implementation.
+    fn from_parameter_set(
+        parameter_set: ParameterSet<Self::Pure, Self::IdealGas, Self::Binary>,
+    ) -> Result<Self, ParameterError> {
+        let n = parameter_set.pure_records.len();
+        let mut binary_records = Array2::from_elem([n, n], Self::Binary::default());
+        let mut binary_metadata = Array2::from_elem([n, n], None);
+        for record in parameter_set.binary_records {
+            let (i, j) = (record.id1, record.id2);
+            if i >= n || j >= n || i == j {
+                return Err(ParameterError::IncompatibleParameters(format!(
+                    "Invalid binary record [{}, {}] for {} components.",
+                    i, j, n
+                )));
+            }
+            binary_records[(i, j)] = record.model_record.clone();
+            binary_records[(j, i)] = record.model_record;
+            binary_metadata[(i, j)] = record.metadata.clone();
+            binary_metadata[(j, i)] = record.metadata;
+        }
+        Ok(Self::from_records_with_metadata(
+            parameter_set.pure_records,
+            binary_records,
+            binary_metadata,
+        ))
+    }
+
+    /// Serialize all records of the parameters to a json string.
+    fn to_json_str(&self) -> Result<String, ParameterError>
+    where
+        Self::Pure: Serialize,
+        Self::IdealGas: Serialize,
+        Self::Binary: Serialize,
+    {
+        Ok(serde_json::to_string_pretty(&self.parameter_set())?)
+    }
+
+    /// Creates parameters from a json string created with `to_json_str`.
+    fn from_json_str(json: &str) -> Result<Self, ParameterError> {
+        Self::from_parameter_set(serde_json::from_str(json)?)
+    }
+
+    /// Write all records of the parameters to a json file.
+    fn to_json_file<P: AsRef<Path>>(&self, file: P) -> Result<(), ParameterError>
+    where
+        Self::Pure: Serialize,
+        Self::IdealGas: Serialize,
+        Self::Binary: Serialize,
+    {
+        let writer = BufWriter::new(File::create(file)?);
+        serde_json::to_writer_pretty(writer, &self.parameter_set())?;
+        Ok(())
+    }
+
+    /// Creates parameters from a json file created with `to_json_file`.
+    fn from_json_file<P: AsRef<Path>>(file: P) -> Result<Self, ParameterError> {
+        let reader = BufReader::new(File::open(file)?);
+        Self::from_parameter_set(serde_json::from_reader(reader)?)
     }
 }
 
+/// Arrange a property of binary records in a matrix in the order of `pure_records`.
+///
+/// Pairs of components without a binary record use the `Default` implementation of `T`.
+#[allow(clippy::expect_fun_call)]
+fn matrix_from_binary_records<M, I, B, T: Clone + Default, F>(
+    pure_records: &[PureRecord<M, I>],
+    binary_records: &[BinaryRecord<Identifier, B>],
+    search_option: IdentifierOption,
+    f: F,
+) -> Array2<T>
+where
+    F: Fn(&BinaryRecord<Identifier, B>) -> T,
+{
+    // Build Hashmap (id, id) -> BinaryRecord
+    let binary_map: HashMap<(String, String), T> = {
+        binary_records
+            .iter()
+            .filter_map(|br| {
+                let id1 = br.id1.as_string(search_option);
+                let id2 = br.id2.as_string(search_option);
+                id1.and_then(|id1| id2.map(|id2| ((id1, id2), f(br))))
+            })
+            .collect()
+    };
+    let n = pure_records.len();
+    Array2::from_shape_fn([n, n], |(i, j)| {
+        let id1 = pure_records[i]
+            .identifier
+            .as_string(search_option)
+            .expect(&format!(
+                "No identifier for given search_option for pure record {}.",
+                i
+            ));
+        let id2 = pure_records[j]
+            .identifier
+            .as_string(search_option)
+            .expect(&format!(
+                "No identifier for given search_option for pure record {}.",
+                j
+            ));
+        binary_map
+            .get(&(id1.clone(), id2.clone()))
+            .or_else(|| binary_map.get(&(id2, id1)))
+            .cloned()
+            .unwrap_or_default()
+    })
+}
+
 /// Constructor methods for parameters for heterosegmented models.
 pub trait ParameterHetero: Sized {
     type Chemical: Clone;
@@ -451,6 +597,71 @@ pub trait ParameterHetero: Sized {
         )
         .unwrap()
     }
+
+    /// Return all records of the parameters in a serializable form.
+    #[allow(clippy::type_complexity)]
+    fn parameter_set(
+        &self,
+    ) -> ParameterSetHetero<Self::Chemical, Self::Pure, Self::IdealGas, Self::Binary> {
+        let (chemical_records, segment_records, binary_segment_records) = self.records();
+        ParameterSetHetero {
+            chemical_records: chemical_records.to_vec(),
+            segment_records: segment_records.to_vec(),
+            binary_segment_records: binary_segment_records.clone(),
+        }
+    }
+
+    /// Creates parameters from a [ParameterSetHetero].
+    fn from_parameter_set(
+        parameter_set: ParameterSetHetero<Self::Chemical, Self::Pure, Self::IdealGas, Self::Binary>,
+    ) -> Result<Self, ParameterError> {
+        Self::from_segments(
+            parameter_set.chemical_records,
+            parameter_set.segment_records,
+            parameter_set.binary_segment_records,
+        )
+    }
+
+    /// Serialize all records of the parameters to a json string.
+    fn to_json_str(&self) -> Result<String, ParameterError>
+    where
+        Self::Chemical: Serialize,
+        Self::Pure: Serialize,
+        Self::IdealGas: Serialize,
+        Self::Binary: Serialize,
+    {
+        Ok(serde_json::to_string_pretty(&self.parameter_set())?)
+    }
+
+    /// Creates parameters from a json string created with `to_json_str`.
+    fn from_json_str(json: &str) -> Result<Self, ParameterError>
+    where
+        Self::Chemical: DeserializeOwned,
+    {
+        Self::from_parameter_set(serde_json::from_str(json)?)
+    }
+
+    /// Write all records of the parameters to a json file.
+    fn to_json_file<P: AsRef<Path>>(&self, file: P) -> Result<(), ParameterError>
+    where
+        Self::Chemical: Serialize,
+        Self::Pure: Serialize,
+        Self::IdealGas: Serialize,
+        Self::Binary: Serialize,
+    {
+        let writer = BufWriter::new(File::create(file)?);
+        serde_json::to_writer_pretty(writer, &self.parameter_set())?;
+        Ok(())
+    }
+
+    /// Creates parameters from a json file created with `to_json_file`.
+    fn from_json_file<P: AsRef<Path>>(file: P) -> Result<Self, ParameterError>
+    where
+        Self::Chemical: DeserializeOwned,
+    {
+        let reader = BufReader::new(File::open(file)?);
+        Self::from_parameter_set(serde_json::from_reader(reader)?)
+    }
 }
 
 /// Error type for incomplete parameter information and IO problems.
@@ -462,12 +673,16 @@ pub enum ParameterError {
     Serde(#[from] serde_json::Error),
     #[error("The following component(s) were not found: {0}")]
     ComponentsNotFound(String),
-    #[error("The identifier '{0}' is not known. ['cas', 'name', 'iupacname', 'smiles', inchi', 'formula']")]
+    #[error("The identifier '{0}' is not known. ['cas', 'name', 'iupacname', 'smiles', inchi', 'inchikey', 'formula']")]
     IdentifierNotFound(String),
     #[error("Information missing.")]
     InsufficientInformation,
     #[error("Incompatible parameters: {0}")]
     IncompatibleParameters(String),
+    #[error("Fragmentation of '{0}' failed: {1}")]
+    Fragmentation(String, String),
+    #[error("Multiple records with the same priority found for '{0}': {1}")]
+    DuplicateRecords(String, String),
 }
 
 #[cfg(test)]
@@ -8,6 +8,41 @@ use std::fs::File;
 use std::io::BufReader;
 use std::path::Path;
 
+/// Optional information on the origin of a record.
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq, Eq)]
+pub struct Metadata {
+    /// DOI of the publication the parameters are taken from
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub doi: Option<String>,
+    /// Date on which the parameters were determined
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub date: Option<String>,
+    /// Free text comment
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub comment: Option<String>,
+}
+
+impl Metadata {
+    /// Creates a new `Metadata` object.
+    pub fn new(doi: Option<String>, date: Option<String>, comment: Option<String>) -> Self {
+        Self { doi, date, comment }
+    }
+}
+
+impl std::fmt::Display for Metadata {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let entries: Vec<_> = [
+            ("doi", &self.doi),
+            ("date", &self.date),
+            ("comment", &self.comment),
+        ]
+        .iter()
+        .filter_map(|(k, v)| v.as_ref().map(|v| format!("{}={}", k, v)))
+        .collect();
+        write!(f, "Metadata({})", entries.join(", "))
+    }
+}
+
 /// A collection of parameters of a pure substance.
 #[derive(Serialize, Deserialize, Debug, Clone)]
 pub struct PureRecord<M, I> {
@@ -17,6 +52,9 @@ pub struct PureRecord<M, I> {
     #[serde(default = "Default::default")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub ideal_gas_record: Option<I>,
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<Metadata>,
 }
 
 impl<M, I> PureRecord<M, I> {
@@ -32,9 +70,16 @@ impl<M, I> PureRecord<M, I> {
             molarweight,
             model_record,
             ideal_gas_record,
+            metadata: None,
         }
     }
 
+    /// Add metadata to the `PureRecord`.
+    pub fn with_metadata(mut self, metadata: Metadata) -> Self {
+        self.metadata = Some(metadata);
+        self
+    }
+
     /// Update the `PureRecord` from segment counts.
     ///
     /// The [FromSegments] trait needs to be implemented for both the model record
@@ -84,6 +129,9 @@ where
         if let Some(i) = self.ideal_gas_record.as_ref() {
             write!(f, "\n\tideal_gas_record={},", i)?;
         }
+        if let Some(m) = self.metadata.as_ref() {
+            write!(f, "\n\tmetadata={},", m)?;
+        }
         write!(f, "\n)")
     }
 }
@@ -114,6 +162,10 @@ pub struct BinaryRecord<I, B> {
     pub id2: I,
     /// Binary interaction parameter(s)
     pub model_record: B,
+    /// Information on the origin of the binary parameters
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<Metadata>,
 }
 
 impl<I, B> BinaryRecord<I, B> {
@@ -123,9 +175,16 @@ impl<I, B> BinaryRecord<I, B> {
             id1,
             id2,
             model_record,
+            metadata: None,
         }
     }
 
+    /// Add metadata to the `BinaryRecord`.
+    pub fn with_metadata(mut self, metadata: Metadata) -> Self {
+        self.metadata = Some(metadata);
+        self
+    }
+
     /// Read a list of `BinaryRecord`s from a JSON file.
     pub fn from_json<P: AsRef<Path>>(file: P) -> Result<Vec<Self>, ParameterError>
     where
@@ -146,6 +205,9 @@ where
         write!(f, "\n\tid1={},", self.id1)?;
         write!(f, "\n\tid2={},", self.id2)?;
         write!(f, "\n\tmodel_record={},", self.model_record)?;
+        if let Some(m) = self.metadata.as_ref() {
+            write!(f, "\n\tmetadata={},", m)?;
+        }
         write!(f, "\n)")
     }
 }
@@ -0,0 +1,25 @@
+use super::{BinaryRecord, PureRecord, SegmentRecord};
+use serde::{Deserialize, Serialize};
+
+/// All records required to reconstruct a parameter set.
+///
+/// Binary records are stored for every pair of components `i < j`
+/// using the indices of the components in `pure_records` as identifiers.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct ParameterSet<M, I, B> {
+    pub pure_records: Vec<PureRecord<M, I>>,
+    #[serde(default = "Vec::new")]
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub binary_records: Vec<BinaryRecord<usize, B>>,
+}
+
+/// All records required to reconstruct a parameter set of a
+/// heterosegmented model.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct ParameterSetHetero<C, M, I, B> {
+    pub chemical_records: Vec<C>,
+    pub segment_records: Vec<SegmentRecord<M, I>>,
+    #[serde(default = "Default::default")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub binary_segment_records: Option<Vec<BinaryRecord<String, B>>>,
+}
@@ -1,4 +1,4 @@
-use super::ParameterError;
+use super::{Metadata, ParameterError};
 use serde::de::DeserializeOwned;
 use serde::{Deserialize, Serialize};
 use std::fs::File;
@@ -13,6 +13,9 @@ pub struct SegmentRecord<M, I> {
     pub molarweight: f64,
     pub model_record: M,
     pub ideal_gas_record: Option<I>,
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<Metadata>,
 }
 
 impl<M, I> SegmentRecord<M, I> {
@@ -28,9 +31,16 @@ impl<M, I> SegmentRecord<M, I> {
             molarweight,
             model_record,
             ideal_gas_record,
+            metadata: None,
         }
     }
 
+    /// Add metadata to the `SegmentRecord`.
+    pub fn with_metadata(mut self, metadata: Metadata) -> Self {
+        self.metadata = Some(metadata);
+        self
+    }
+
     /// Read a list of `SegmentRecord`s from a JSON file.
     pub fn from_json<P: AsRef<Path>>(file: P) -> Result<Vec<Self>, ParameterError>
     where
@@ -62,6 +72,9 @@ impl<M: std::fmt::Display, I: std::fmt::Display> std::fmt::Display for SegmentRe
         if let Some(i) = self.ideal_gas_record.as_ref() {
             write!(f, "\n\tideal_gas_record={},", i)?;
         }
+        if let Some(m) = self.metadata.as_ref() {
+            write!(f, "\n\tmetadata={},", m)?;
+        }
         write!(f, "\n)")
     }
 }
@@ -0,0 +1,257 @@
+use super::{PhaseDiagram, PhaseEquilibrium, SolverOptions, Verbosity};
+use crate::equation_of_state::EquationOfState;
+use crate::errors::{EosError, EosResult};
+use crate::state::{Contributions, TPSpec};
+use ndarray::{arr1, Array1};
+use quantity::si::{SIArray1, SINumber};
+use std::convert::TryFrom;
+use std::sync::Arc;
+
+const MAX_ITER_AZEOTROPE: usize = 50;
+const TOL_AZEOTROPE: f64 = 1e-10;
+const SCAN_POINTS_AZEOTROPE: usize = 21;
+const TRACE_INTERVAL_AZEOTROPE: f64 = 0.05;
+
+/// # Azeotropes
+impl<E: EquationOfState> PhaseEquilibrium<E, 2> {
+    /// Calculate a homogeneous azeotrope of a binary mixture for
+    /// given temperature or pressure.
+    ///
+    /// The azeotrope is determined by solving $y_1\left(x_1\right)=x_1$
+    /// along the bubble point curve. If `x_bounds` are provided, the mole
+    /// fractions of the first component have to bracket the azeotrope.
+    /// Otherwise, the bubble point curve is scanned for an azeotrope.
+    pub fn azeotrope(
+        eos: &Arc<E>,
+        temperature_or_pressure: SINumber,
+        x_bounds: Option<(f64, f64)>,
+        options: SolverOptions,
+        bubble_dew_options: (SolverOptions, SolverOptions),
+    ) -> EosResult<Self> {
+        let tp = TPSpec::try_from(temperature_or_pressure)?;
+        let (a, b) = match x_bounds {
+            Some((x_a, x_b)) => {
+                let a = Self::azeotrope_residual(eos, tp, x_a, None, bubble_dew_options)?;
+                let b = Self::azeotrope_residual(eos, tp, x_b, Some(&a.1), bubble_dew_options)?;
+                if a.0 * b.0 > 0.0 {
+                    return Err(EosError::Error(format!(
+                        "The mole fractions {} and {} do not bracket an azeotrope.",
+                        x_a, x_b
+                    )));
+                }
+                (a, b)
+            }
+            None => Self::scan_azeotrope(eos, tp, bubble_dew_options)?,
+        };
+        Self::converge_azeotrope(eos, tp, a, b, options, bubble_dew_options)
+    }
+
+    /// The deviation $y_1-x_1$ of the bubble point at the given
+    /// liquid mole fraction of the first component.
+    fn azeotrope_residual(
+        eos: &Arc<E>,
+        tp: TPSpec,
+        x: f64,
+        initial_state: Option<&Self>,
+        bubble_dew_options: (SolverOptions, SolverOptions),
+    ) -> EosResult<(f64, Self)> {
+        let (temperature_or_pressure, tp_init) = match tp {
+            TPSpec::Temperature(t) => (
+                t,
+                initial_state.map(|vle| vle.vapor().pressure(Contributions::Total)),
+            ),
+            TPSpec::Pressure(p) => (p, initial_state.map(|vle| vle.vapor().temperature)),
+        };
+        let vle = Self::bubble_point(
+            eos,
+            temperature_or_pressure,
+            &arr1(&[x, 1.0 - x]),
+            tp_init,
+            initial_state.map(|vle| &vle.vapor().molefracs),
+            bubble_dew_options,
+        )?;
+        Ok((vle.vapor().molefracs[0] - x, vle))
+    }
+
+    /// Search the bubble point curve for a sign change of $y_1-x_1$.
+    #[allow(clippy::type_complexity)]
+    fn scan_azeotrope(
+        eos: &Arc<E>,
+        tp: TPSpec,
+        bubble_dew_options: (SolverOptions, SolverOptions),
+    ) -> EosResult<((f64, Self), (f64, Self))> {
+        let x = Array1::linspace(0.0, 1.0, SCAN_POINTS_AZEOTROPE);
+        let mut last: Option<(f64, Self)> = None;
+        for &xi in x.iter().skip(1).take(SCAN_POINTS_AZEOTROPE - 2) {
+            let current = Self::azeotrope_residual(
+                eos,
+                tp,
+                xi,
+                last.as_ref().map(|l| &l.1),
+                bubble_dew_options,
+            );
+            let current = match current {
+                Ok(current) => current,
+                Err(_) => {
+                    last = None;
+                    continue;
+                }
+            };
+            if let Some(last) = last {
+                if last.0 * current.0 < 0.0 {
+                    return Ok((last, current));
+                }
+            }
+            last = Some(current);
+        }
+        Err(EosError::Error(String::from("No azeotrope found.")))
+    }
+
+    /// Converge an azeotrope that is bracketed by two bubble points
+    /// using the Illinois algorithm.
+    fn converge_azeotrope(
+        eos: &Arc<E>,
+        tp: TPSpec,
+        a: (f64, Self),
+        b: (f64, Self),
+        options: SolverOptions,
+        bubble_dew_options: (SolverOptions, SolverOptions),
+    ) -> EosResult<Self> {
+        let (max_iter, tol, verbosity) = options.unwrap_or(MAX_ITER_AZEOTROPE, TOL_AZEOTROPE);
+        let (mut f_a, vle_a) = a;
+        let (mut f_b, mut vle_b) = b;
+        let mut x_a = vle_a.liquid().molefracs[0];
+        let mut x_b = vle_b.liquid().molefracs[0];
+        if f_a.abs() < tol {
+            return Ok(vle_a);
+        }
+
+        log_iter!(verbosity, " iter |       x_1       |    y_1 - x_1    ");
+        log_iter!(verbosity, "{:-<41}", "");
+        for i in 1..=max_iter {
+            if f_b.abs() < tol {
+                log_result!(
+                    verbosity,
+                    "Azeotrope: calculation converged in {} step(s)\n",
+                    i - 1
+                );
+                return Ok(vle_b);
+            }
+            let x_c = x_b - f_b * (x_b - x_a) / (f_b - f_a);
+            let (f_c, vle_c) =
+                Self::azeotrope_residual(eos, tp, x_c, Some(&vle_b), bubble_dew_options)?;
+            log_iter!(verbosity, " {:4} | {:15.12} | {:15.8e}", i, x_c, f_c);
+            if f_c.signum() == f_b.signum() {
+                f_a *= 0.5;
+            } else {
+                x_a = x_b;
+                f_a = f_b;
+            }
+            x_b = x_c;
+            f_b = f_c;
+            vle_b = vle_c;
+        }
+        if f_b.abs() < tol {
+            return Ok(vle_b);
+        }
+        log_result!(
+            verbosity,
+            "Azeotrope: not converged in {} steps\n",
+            max_iter
+        );
+        Err(EosError::NotConverged(String::from(
+            "PhaseEquilibrium::azeotrope",
+        )))
+    }
+}
+
+impl<E: EquationOfState> PhaseDiagram<E, 2> {
+    /// Converge the azeotropes that are bracketed by consecutive states of
+    /// a binary phase diagram and insert them into the diagram.
+    pub(super) fn insert_azeotropes(
+        &mut self,
+        eos: &Arc<E>,
+        temperature_or_pressure: SINumber,
+        bubble_dew_options: (SolverOptions, SolverOptions),
+    ) -> EosResult<()> {
+        let tp = TPSpec::try_from(temperature_or_pressure)?;
+        let residual =
+            |vle: &PhaseEquilibrium<E, 2>| vle.vapor().molefracs[0] - vle.liquid().molefracs[0];
+        for i in (1..self.states.len()).rev() {
+            let (a, b) = (&self.states[i - 1], &self.states[i]);
+            let (f_a, f_b) = (residual(a), residual(b));
+            if f_a * f_b < 0.0 {
+                if let Ok(azeotrope) = PhaseEquilibrium::converge_azeotrope(
+                    eos,
+                    tp,
+                    (f_a, a.clone()),
+                    (f_b, b.clone()),
+                    SolverOptions::default(),
+                    bubble_dew_options,
+                ) {
+                    self.states.insert(i, azeotrope);
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Return the azeotropes contained in a binary phase diagram.
+    ///
+    /// Azeotropes are states at which the compositions of the coexisting
+    /// phases coincide, while their densities differ.
+    pub fn azeotropes(&self) -> Vec<PhaseEquilibrium<E, 2>> {
+        self.states
+            .iter()
+            .filter(|vle| {
+                let x = vle.liquid().molefracs[0];
+                let y = vle.vapor().molefracs[0];
+                let rho_l = vle.liquid().density;
+                let rho_v = vle.vapor().density;
+                x > 0.0
+                    && x < 1.0
+                    && (x - y).abs() < 1e-8
+                    && ((rho_l - rho_v) / rho_l)
+                        .into_value()
+                        .map_or(false, |d| d.abs() > 1e-5)
+            })
+            .cloned()
+            .collect()
+    }
+
+    /// Trace the azeotrope of a binary mixture for the given
+    /// temperatures or pressures.
+    ///
+    /// Each azeotrope is initialized with the composition of the
+    /// previous azeotrope. The calculation stops when the
+    /// azeotrope can not be found anymore.
+    pub fn azeotrope_line(
+        eos: &Arc<E>,
+        temperature_or_pressure: &SIArray1,
+        options: SolverOptions,
+        bubble_dew_options: (SolverOptions, SolverOptions),
+    ) -> EosResult<Self> {
+        let mut states: Vec<PhaseEquilibrium<E, 2>> =
+            Vec::with_capacity(temperature_or_pressure.len());
+        for tp in temperature_or_pressure.into_iter() {
+            let x_bounds = states.last().map(|vle| {
+                let x = vle.liquid().molefracs[0];
+                (
+                    (x - TRACE_INTERVAL_AZEOTROPE).max(1e-3),
+                    (x + TRACE_INTERVAL_AZEOTROPE).min(1.0 - 1e-3),
+                )
+            });
+            let azeotrope =
+                PhaseEquilibrium::azeotrope(eos, tp, x_bounds, options, bubble_dew_options)
+                    .or_else(|_| {
+                        PhaseEquilibrium::azeotrope(eos, tp, None, options, bubble_dew_options)
+                    });
+            match azeotrope {
+                Ok(azeotrope) => states.push(azeotrope),
+                Err(_) if !states.is_empty() => break,
+                Err(e) => return Err(e),
+            }
+        }
+        Ok(Self::new(states))
+    }
+}
@@ -1,6 +1,7 @@
 use super::{PhaseEquilibrium, SolverOptions, Verbosity};
 use crate::equation_of_state::EquationOfState;
 use crate::errors::{EosError, EosResult};
+use crate::solver_log::SolverLog;
 use crate::state::{
     Contributions,
     DensityInitialization::{InitialDensity, Liquid, Vapor},
@@ -368,6 +369,7 @@ where
     // initialize variables
     let mut err_out = 1.0;
     let mut k_out = 0;
+    let mut log = SolverLog::new("bubble_dew");
 
     if PhaseEquilibrium::is_trivial_solution(&state1, &state2) {
         log_iter!(options_outer.verbosity, "Trivial solution encountered!");
@@ -392,7 +394,7 @@ where
     // Outer loop for finding x2
     for ko in 0..options_outer.max_iter.unwrap_or(MAX_ITER_OUTER) {
         // Iso-Fugacity equation
-        err_out = if err_out > NEWTON_TOL {
+        let (err, step_size) = if err_out > NEWTON_TOL {
             // Inner loop for finding T or p
             for _ in 0..options_inner.max_iter.unwrap_or(MAX_ITER_INNER) {
                 // Newton step
@@ -416,6 +418,8 @@ where
                 options_outer.verbosity,
             )
         }?;
+        err_out = err;
+        log.push(err_out, step_size, 1.0);
 
         if PhaseEquilibrium::is_trivial_solution(&state1, &state2) {
             log_iter!(options_outer.verbosity, "Trivial solution encountered!");
@@ -434,7 +438,8 @@ where
             "Bubble/dew point: calculation converged in {} step(s)\n",
             k_out
         );
-        Ok(PhaseEquilibrium::from_states(state1, state2))
+        log.converged = true;
+        Ok(PhaseEquilibrium::from_states(state1, state2).with_solver_log(log))
     } else {
         // not converged, return EosError
         Err(EosError::NotConverged(String::from("bubble-dew-iteration")))
@@ -540,13 +545,14 @@ fn adjust_x2<E: EquationOfState>(
     state1: &State<E>,
     state2: &mut State<E>,
     verbosity: Verbosity,
-) -> EosResult<f64> {
+) -> EosResult<(f64, f64)> {
     let x1 = &state1.molefracs;
     let ln_phi_1 = state1.ln_phi();
     let ln_phi_2 = state2.ln_phi();
     let k = (ln_phi_1 - ln_phi_2).mapv(f64::exp);
     let err_out = (&k * x1 / &state2.molefracs - 1.0).mapv(f64::abs).sum();
     let x2 = (x1 * &k) / (&k * x1).sum();
+    let step_size = norm(&(&x2 - &state2.molefracs));
     log_iter!(verbosity, "{:<14.8e} | {:14} | {:16} |", err_out, "", "");
     *state2 = State::new_npt(
         &state2.eos,
@@ -555,7 +561,7 @@ fn adjust_x2<E: EquationOfState>(
         &(x2 * SIUnit::reference_moles()),
         InitialDensity(state2.density),
     )?;
-    Ok(err_out)
+    Ok((err_out, step_size))
 }
 
 fn newton_step<E: EquationOfState>(
@@ -564,7 +570,7 @@ fn newton_step<E: EquationOfState>(
     state1: &mut State<E>,
     state2: &mut State<E>,
     verbosity: Verbosity,
-) -> EosResult<f64>
+) -> EosResult<(f64, f64)>
 where
     SINumber: std::fmt::Display,
 {
@@ -579,7 +585,7 @@ fn newton_step_t<E: EquationOfState>(
     state1: &mut State<E>,
     state2: &mut State<E>,
     verbosity: Verbosity,
-) -> EosResult<f64>
+) -> EosResult<(f64, f64)>
 where
     SINumber: std::fmt::Display,
 {
@@ -648,7 +654,7 @@ where
         pressure,
         state2.molefracs
     );
-    Ok(error)
+    Ok((error, norm(&dx)))
 }
 
 fn newton_step_p<E: EquationOfState>(
@@ -657,7 +663,7 @@ fn newton_step_p<E: EquationOfState>(
     state1: &mut State<E>,
     state2: &mut State<E>,
     verbosity: Verbosity,
-) -> EosResult<f64>
+) -> EosResult<(f64, f64)>
 where
     SINumber: std::fmt::Display,
 {
@@ -750,5 +756,5 @@ where
         temperature,
         state2.molefracs
     );
-    Ok(error)
+    Ok((error, norm(&dx)))
 }
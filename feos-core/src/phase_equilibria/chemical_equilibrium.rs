@@ -0,0 +1,501 @@
+use super::{PhaseEquilibrium, SolverOptions, Verbosity};
+use crate::equation_of_state::EquationOfState;
+use crate::errors::{EosError, EosResult};
+use crate::solver_log::SolverLog;
+use crate::state::{Contributions, DensityInitialization, State};
+use crate::EosUnit;
+use ndarray::{Array1, Array2, Axis};
+use num_dual::linalg::{norm, LU};
+use num_dual::{Dual64, DualNum};
+use quantity::si::{SIArray1, SINumber, SIUnit, BAR};
+use std::sync::Arc;
+
+const MAX_ITER_CHEM: usize = 50;
+const TOL_CHEM: f64 = 1e-10;
+const MAX_STEP_FRACTION_CHEM: f64 = 0.9;
+/// Reference temperature of the properties of formation in K.
+const REFERENCE_TEMPERATURE: f64 = 298.15;
+
+/// A set of chemical reactions between the components of a system.
+///
+/// The reactions are characterized by their stoichiometric coefficients
+/// $\nu_{ri}$ and the ideal gas enthalpies $\Delta h_{\mathrm{f},i}$ and Gibbs
+/// energies $\Delta g_{\mathrm{f},i}$ of formation of all components at the
+/// reference temperature $T^0=298.15\,\mathrm{K}$ and the standard pressure
+/// $p^0=1\,\mathrm{bar}$.
+///
+/// The ideal gas contribution of an equation of state is only defined up to
+/// a linear function of temperature. The chemical potentials of the equation
+/// of state are therefore shifted to
+/// $$\mu_i^\mathrm{f}=\mu_i+a_i+b_iT$$
+/// with the constants $a_i$ and $b_i$ chosen such that the ideal gas enthalpy
+/// and Gibbs energy of every pure component at $T^0$ and $p^0$ are equal to
+/// the respective properties of formation. The temperature dependence of the
+/// standard Gibbs energies of reaction is then determined by the ideal gas
+/// heat capacities of the equation of state.
+#[derive(Clone, Debug)]
+pub struct ChemicalReactions {
+    /// Stoichiometric coefficients of all reactions (rows) and components (columns).
+    pub stoichiometry: Array2<f64>,
+    /// Ideal gas enthalpies of formation of all components at $T^0$.
+    pub enthalpy_of_formation: SIArray1,
+    /// Ideal gas Gibbs energies of formation of all components at $T^0$ and $p^0$.
+    pub gibbs_energy_of_formation: SIArray1,
+}
+
+impl ChemicalReactions {
+    pub fn new(
+        stoichiometry: Array2<f64>,
+        enthalpy_of_formation: SIArray1,
+        gibbs_energy_of_formation: SIArray1,
+    ) -> EosResult<Self> {
+        let n = stoichiometry.ncols();
+        for len in [enthalpy_of_formation.len(), gibbs_energy_of_formation.len()] {
+            if len != n {
+                return Err(EosError::IncompatibleComponents(n, len));
+            }
+        }
+        for nu in stoichiometry.outer_iter() {
+            if !nu.iter().any(|&nu| nu > 0.0) || !nu.iter().any(|&nu| nu < 0.0) {
+                return Err(EosError::Error(String::from(
+                    "Every reaction requires at least one reactant and one product.",
+                )));
+            }
+        }
+        Ok(Self {
+            stoichiometry,
+            enthalpy_of_formation,
+            gibbs_energy_of_formation,
+        })
+    }
+
+    /// The number of reactions.
+    pub fn reactions(&self) -> usize {
+        self.stoichiometry.nrows()
+    }
+
+    fn check_components<E: EquationOfState>(&self, eos: &Arc<E>) -> EosResult<()> {
+        let n = self.stoichiometry.ncols();
+        if eos.components() != n {
+            return Err(EosError::IncompatibleComponents(eos.components(), n));
+        }
+        Ok(())
+    }
+
+    /// The reduced ideal gas chemical potentials $\beta\mu_i^\mathrm{ig}(T,p^0)$ of the
+    /// pure components according to the equation of state and their temperature derivatives.
+    fn ideal_gas_chemical_potential<E: EquationOfState>(
+        eos: &Arc<E>,
+        temperature: f64,
+    ) -> EosResult<Array1<Dual64>> {
+        let temperature = Dual64::from(temperature).derive();
+        let p0 = BAR.to_reduced(SIUnit::reference_pressure())?;
+        let ln_rho0 = (temperature.recip() * p0).ln();
+        Ok(eos
+            .ideal_gas()
+            .de_broglie_wavelength(temperature, eos.components())
+            .mapv(|ln_lambda3| ln_lambda3 + ln_rho0))
+    }
+
+    /// The reduced shifts $\frac{a_i+b_iT}{RT}$ of the chemical potentials
+    /// of the equation of state.
+    fn chemical_potential_shift<E: EquationOfState>(
+        &self,
+        eos: &Arc<E>,
+        temperature: SINumber,
+    ) -> EosResult<Array1<f64>> {
+        let t0 = REFERENCE_TEMPERATURE;
+        let t = temperature.to_reduced(SIUnit::reference_temperature())?;
+        let beta_mu = Self::ideal_gas_chemical_potential(eos, t0)?;
+        let h_f = self
+            .enthalpy_of_formation
+            .to_reduced(SIUnit::reference_molar_energy())?;
+        let g_f = self
+            .gibbs_energy_of_formation
+            .to_reduced(SIUnit::reference_molar_energy())?;
+        Ok(Array1::from_shape_fn(beta_mu.len(), |i| {
+            let h_ig = -t0 * t0 * beta_mu[i].eps[0];
+            let g_ig = t0 * beta_mu[i].re;
+            let a = h_f[i] - h_ig;
+            let b = (g_f[i] - g_ig - a) / t0;
+            (a + b * t) / t
+        }))
+    }
+
+    /// The standard chemical potentials $\mu_i^0(T)$ of all components, i.e.,
+    /// their Gibbs energies of formation as ideal gases at $T$ and $p^0$.
+    pub fn standard_chemical_potential<E: EquationOfState>(
+        &self,
+        eos: &Arc<E>,
+        temperature: SINumber,
+    ) -> EosResult<SIArray1> {
+        self.check_components(eos)?;
+        let t = temperature.to_reduced(SIUnit::reference_temperature())?;
+        let beta_mu0 = Self::ideal_gas_chemical_potential(eos, t)?.mapv(|mu| mu.re)
+            + self.chemical_potential_shift(eos, temperature)?;
+        Ok(beta_mu0 * SIUnit::gas_constant() * temperature)
+    }
+
+    /// The logarithms of the equilibrium constants
+    /// $\ln K_r=-\sum_i\nu_{ri}\frac{\mu_i^0(T)}{RT}$ of all reactions.
+    pub fn ln_equilibrium_constant<E: EquationOfState>(
+        &self,
+        eos: &Arc<E>,
+        temperature: SINumber,
+    ) -> EosResult<Array1<f64>> {
+        let mu0 = self.standard_chemical_potential(eos, temperature)?;
+        let beta_mu0 = (mu0 / (SIUnit::gas_constant() * temperature)).into_value()?;
+        Ok(-self.stoichiometry.dot(&beta_mu0))
+    }
+
+    /// Initial extents of reaction that result in positive amounts
+    /// of all reacting components.
+    fn initial_extents(&self, moles: &Array1<f64>) -> EosResult<Array1<f64>> {
+        let nu = &self.stoichiometry;
+        let r = self.reactions() as f64;
+        let extents = nu.map_axis(Axis(1), |nu| {
+            let mut min = f64::NEG_INFINITY;
+            let mut max = f64::INFINITY;
+            for (&nu, &n) in nu.iter().zip(moles) {
+                if nu > 0.0 {
+                    min = min.max(-n / nu);
+                } else if nu < 0.0 {
+                    max = max.min(-n / nu);
+                }
+            }
+            0.5 * (min + max) / r
+        });
+        let n = moles + &nu.t().dot(&extents);
+        for (i, nu) in nu.axis_iter(Axis(1)).enumerate() {
+            if nu.iter().any(|&nu| nu != 0.0) && n[i] <= 0.0 {
+                return Err(EosError::Error(String::from(
+                    "The reactions can not proceed from the given amounts of substance.",
+                )));
+            }
+        }
+        Ok(extents)
+    }
+}
+
+/// Simultaneous chemical and phase equilibrium of a reacting system.
+#[derive(Debug)]
+pub struct ChemicalEquilibrium<E> {
+    phases: Vec<State<E>>,
+    extent_of_reaction: SIArray1,
+    solver_log: SolverLog,
+}
+
+impl<E> Clone for ChemicalEquilibrium<E> {
+    fn clone(&self) -> Self {
+        Self {
+            phases: self.phases.clone(),
+            extent_of_reaction: self.extent_of_reaction.clone(),
+            solver_log: self.solver_log.clone(),
+        }
+    }
+}
+
+impl<E: EquationOfState> ChemicalEquilibrium<E> {
+    /// The phases in equilibrium (one or two).
+    pub fn phases(&self) -> &[State<E>] {
+        &self.phases
+    }
+
+    /// The extents of all reactions relative to the initial amounts of substance.
+    pub fn extent_of_reaction(&self) -> &SIArray1 {
+        &self.extent_of_reaction
+    }
+
+    /// The total amounts of substance of all components in equilibrium.
+    pub fn moles(&self) -> SIArray1 {
+        let mut moles = self.phases[0].moles.clone();
+        for s in &self.phases[1..] {
+            moles = &moles + &s.moles;
+        }
+        moles
+    }
+
+    /// The log of the solver that determined the chemical equilibrium.
+    pub fn solver_log(&self) -> &SolverLog {
+        &self.solver_log
+    }
+
+    /// Calculate the chemical equilibrium at given temperature and pressure
+    /// by minimizing the Gibbs energy with respect to the extents of reaction.
+    ///
+    /// The chemical potentials of all components are calculated from the
+    /// equation of state and shifted consistently with the properties of
+    /// formation of the [ChemicalReactions]. If `phase_equilibrium` is `true`,
+    /// the phase split of the reacting mixture is determined in every iteration
+    /// with a stability analysis and a Tp-flash, otherwise the mixture is
+    /// treated as a single (stable) phase.
+    pub fn tp(
+        eos: &Arc<E>,
+        reactions: &ChemicalReactions,
+        temperature: SINumber,
+        pressure: SINumber,
+        moles: &SIArray1,
+        phase_equilibrium: bool,
+        options: SolverOptions,
+    ) -> EosResult<Self> {
+        reactions.check_components(eos)?;
+        if moles.len() != eos.components() {
+            return Err(EosError::IncompatibleComponents(
+                eos.components(),
+                moles.len(),
+            ));
+        }
+        let (max_iter, tol, verbosity) = options.unwrap_or(MAX_ITER_CHEM, TOL_CHEM);
+        let nu = &reactions.stoichiometry;
+        let rt = SIUnit::gas_constant() * temperature;
+        let shift = reactions.chemical_potential_shift(eos, temperature)?;
+        let n0 = moles.to_reduced(SIUnit::reference_moles())?;
+        let mut extents = reactions.initial_extents(&n0)?;
+        let mut vle = None;
+
+        log_iter!(
+            verbosity,
+            " iter |    residual    |  phases  |   damping    |  extents of reaction"
+        );
+        log_iter!(verbosity, "{:-<77}", "");
+
+        let mut log = SolverLog::new("chemical_equilibrium");
+        for i in 1..=max_iter {
+            let n = &n0 + &nu.t().dot(&extents);
+            let phases = Self::phase_split(
+                eos,
+                temperature,
+                pressure,
+                &(n.clone() * SIUnit::reference_moles()),
+                phase_equilibrium,
+                &mut vle,
+            )?;
+
+            // gradient of the reduced Gibbs energy w.r.t. the extents of reaction
+            let beta_mu =
+                (phases[0].chemical_potential(Contributions::Total) / rt).into_value()? + &shift;
+            let gradient = nu.dot(&beta_mu);
+            let res = norm(&gradient);
+            if !res.is_finite() {
+                return Err(EosError::IterationFailed(String::from(
+                    "ChemicalEquilibrium::tp",
+                )));
+            }
+            if res < tol {
+                log.push(res, f64::NAN, f64::NAN);
+                log.converged = true;
+                log_result!(
+                    verbosity,
+                    "Chemical equilibrium: calculation converged in {} step(s)\n",
+                    i
+                );
+                return Ok(Self {
+                    phases,
+                    extent_of_reaction: extents * SIUnit::reference_moles(),
+                    solver_log: log,
+                });
+            }
+
+            // Newton step with the Hessian of the Gibbs energy of the (multiphase) system
+            let dmu_dxi = Self::dmu_dxi(&phases, nu)?;
+            let hessian = nu.dot(&dmu_dxi);
+            let delta = -LU::new(hessian)?.solve(&gradient);
+
+            // limit the step to keep the amounts of all components positive
+            let dn = nu.t().dot(&delta);
+            let mut damping: f64 = 1.0;
+            for (&n, &dn) in n.iter().zip(dn.iter()) {
+                if n > 0.0 && dn < 0.0 {
+                    damping = damping.min(-MAX_STEP_FRACTION_CHEM * n / dn);
+                }
+            }
+            extents += &(&delta * damping);
+            log.push(res, norm(&delta) * damping, damping);
+            log_iter!(
+                verbosity,
+                " {:4} | {:14.8e} | {:8} | {:12.8} | {:.8}",
+                i,
+                res,
+                phases.len(),
+                damping,
+                extents
+            );
+        }
+        Err(EosError::NotConverged(String::from(
+            "ChemicalEquilibrium::tp",
+        )))
+    }
+
+    /// Determine the stable phases for the given amounts of substance.
+    fn phase_split(
+        eos: &Arc<E>,
+        temperature: SINumber,
+        pressure: SINumber,
+        moles: &SIArray1,
+        phase_equilibrium: bool,
+        vle: &mut Option<PhaseEquilibrium<E, 2>>,
+    ) -> EosResult<Vec<State<E>>> {
+        let state = State::new_npt(
+            eos,
+            temperature,
+            pressure,
+            moles,
+            DensityInitialization::None,
+        )?;
+        if !phase_equilibrium {
+            return Ok(vec![state]);
+        }
+        let options = SolverOptions::default();
+        let flash = match vle.as_ref() {
+            Some(init) => state
+                .tp_flash(Some(init), options, None)
+                .or_else(|_| state.tp_flash(None, options, None)),
+            None => state.tp_flash(None, options, None),
+        };
+        match flash {
+            Ok(v) => {
+                let phases = vec![v.vapor().clone(), v.liquid().clone()];
+                *vle = Some(v);
+                Ok(phases)
+            }
+            Err(EosError::NoPhaseSplit) | Err(EosError::TrivialSolution) => {
+                *vle = None;
+                Ok(vec![state])
+            }
+            Err(e) => Err(e),
+        }
+    }
+
+    /// The derivatives of the reduced chemical potentials w.r.t. the extents of
+    /// reaction at constant temperature and pressure, assuming that the phases
+    /// remain in equilibrium.
+    fn dmu_dxi(phases: &[State<E>], nu: &Array2<f64>) -> EosResult<Array2<f64>> {
+        let n = nu.ncols();
+        let mut moles = Array1::zeros(n);
+        for s in phases {
+            moles += &s.moles.to_reduced(SIUnit::reference_moles())?;
+        }
+        // only components that are present in the system
+        let active: Vec<_> = (0..n).filter(|&i| moles[i] > 0.0).collect();
+        let m = active.len();
+
+        // derivatives of the reduced chemical potentials w.r.t. the moles of each phase
+        let hessians = phases
+            .iter()
+            .map(|s| {
+                let dln_phi_dnj = s
+                    .dln_phi_dnj()
+                    .to_reduced(SIUnit::reference_moles().powi(-1))?;
+                let moles = s.moles.to_reduced(SIUnit::reference_moles())?;
+                let total_moles = moles.sum();
+                Ok(Array2::from_shape_fn((m, m), |(i, j)| {
+                    let (i, j) = (active[i], active[j]);
+                    let ideal = if i == j && moles[i] > 0.0 {
+                        1.0 / moles[i]
+                    } else {
+                        0.0
+                    };
+                    dln_phi_dnj[[i, j]] + ideal - 1.0 / total_moles
+                }))
+            })
+            .collect::<EosResult<Vec<_>>>()?;
+
+        let nu_active = nu.select(Axis(1), &active);
+        let mut dmu_dxi = Array2::zeros((n, nu.nrows()));
+        let dmu_dxi_active = match hessians.as_slice() {
+            [h] => h.dot(&nu_active.t()),
+            [h_v, h_l] => {
+                // the changes of the moles in both phases have to satisfy
+                // H_V dn_V = H_L dn_L and dn_V + dn_L = dn
+                let mut a = Array2::zeros((2 * m, 2 * m));
+                for i in 0..m {
+                    for j in 0..m {
+                        a[[i, j]] = h_v[[i, j]];
+                        a[[i, m + j]] = -h_l[[i, j]];
+                    }
+                    a[[m + i, i]] = 1.0;
+                    a[[m + i, m + i]] = 1.0;
+                }
+                let lu = LU::new(a)?;
+                let mut dmu = Array2::zeros((m, nu.nrows()));
+                for (r, nu) in nu_active.outer_iter().enumerate() {
+                    let mut rhs = Array1::zeros(2 * m);
+                    rhs.slice_mut(ndarray::s![m..]).assign(&nu);
+                    let dn = lu.solve(&rhs);
+                    dmu.column_mut(r)
+                        .assign(&h_v.dot(&dn.slice(ndarray::s![..m])));
+                }
+                dmu
+            }
+            _ => unreachable!(),
+        };
+        for (k, &i) in active.iter().enumerate() {
+            dmu_dxi.row_mut(i).assign(&dmu_dxi_active.row(k));
+        }
+        Ok(dmu_dxi)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::cubic::{PengRobinson, PengRobinsonParameters};
+    use approx::assert_relative_eq;
+    use ndarray::{arr1, arr2};
+    use quantity::si::{JOULE, KELVIN, MOL};
+
+    fn methanol_synthesis() -> EosResult<(Arc<PengRobinson>, ChemicalReactions)> {
+        // carbon monoxide, hydrogen, methanol
+        let parameters = PengRobinsonParameters::new_simple(
+            &[132.86, 33.19, 512.6],
+            &[3494000.0, 1313000.0, 8097000.0],
+            &[0.045, -0.216, 0.565],
+            &[28.01, 2.016, 32.04],
+        )?;
+        let eos = Arc::new(PengRobinson::new(Arc::new(parameters)));
+        let reactions = ChemicalReactions::new(
+            arr2(&[[-1.0, -2.0, 1.0]]),
+            arr1(&[-110530.0, 0.0, -200940.0]) * JOULE / MOL,
+            arr1(&[-137170.0, 0.0, -162320.0]) * JOULE / MOL,
+        )?;
+        Ok((eos, reactions))
+    }
+
+    #[test]
+    fn equilibrium_constant() -> EosResult<()> {
+        let (eos, reactions) = methanol_synthesis()?;
+        let ln_k = reactions.ln_equilibrium_constant(&eos, 298.15 * KELVIN)?;
+        assert_relative_eq!(ln_k[0], 10.145395704780512, max_relative = 1e-8);
+        Ok(())
+    }
+
+    #[test]
+    fn methanol_equilibrium() -> EosResult<()> {
+        let (eos, reactions) = methanol_synthesis()?;
+        let t = 523.15 * KELVIN;
+        let p = 50.0 * BAR;
+        let moles = arr1(&[1.0, 2.5, 0.0]) * MOL;
+        let chem =
+            ChemicalEquilibrium::tp(&eos, &reactions, t, p, &moles, false, Default::default())?;
+        assert!(chem.solver_log().converged);
+        assert_eq!(chem.phases().len(), 1);
+
+        // mass action law with fugacities
+        let state = &chem.phases()[0];
+        let ln_f = state.molefracs.mapv(f64::ln) + state.ln_phi() + (p / BAR).into_value()?.ln();
+        let ln_k = reactions.ln_equilibrium_constant(&eos, t)?;
+        assert_relative_eq!(
+            reactions.stoichiometry.dot(&ln_f)[0],
+            ln_k[0],
+            epsilon = 1e-8
+        );
+
+        // conservation of the elements
+        let n = chem.moles().to_reduced(MOL)?;
+        let xi = chem.extent_of_reaction().to_reduced(MOL)?;
+        assert_relative_eq!(n[0] + n[2], 1.0, max_relative = 1e-12);
+        assert_relative_eq!(n[1] + 2.0 * n[2], 2.5, max_relative = 1e-12);
+        assert_relative_eq!(n[2], xi[0], max_relative = 1e-12);
+        Ok(())
+    }
+}
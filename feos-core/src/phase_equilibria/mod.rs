@@ -1,21 +1,30 @@
 use crate::equation_of_state::EquationOfState;
 use crate::errors::{EosError, EosResult};
+use crate::solver_log::SolverLog;
 use crate::state::{Contributions, DensityInitialization, State};
 use crate::EosUnit;
 use quantity::si::{SIArray1, SINumber, SIUnit};
+use serde::de::Error;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use std::convert::TryInto;
 use std::fmt;
 use std::fmt::Write;
 use std::sync::Arc;
 
+mod azeotrope;
 mod bubble_dew;
+mod chemical_equilibrium;
 mod phase_diagram_binary;
 mod phase_diagram_pure;
 mod phase_envelope;
+mod solid;
 mod stability_analysis;
 mod tp_flash;
 mod vle_pure;
+pub use chemical_equilibrium::{ChemicalEquilibrium, ChemicalReactions};
 pub use phase_diagram_binary::PhaseDiagramHetero;
 pub use phase_diagram_pure::PhaseDiagram;
+pub use solid::{PhaseDiagramSolid, SolidModel};
 
 /// Level of detail in the iteration output.
 #[derive(Copy, Clone, PartialOrd, PartialEq, Eq)]
@@ -101,11 +110,30 @@ impl SolverOptions {
 /// + [Pure component phase equilibria](#pure-component-phase-equilibria)
 /// + [Utility functions](#utility-functions)
 #[derive(Debug)]
-pub struct PhaseEquilibrium<E, const N: usize>([State<E>; N]);
+pub struct PhaseEquilibrium<E, const N: usize>([State<E>; N], Option<Arc<SolverLog>>);
 
 impl<E, const N: usize> Clone for PhaseEquilibrium<E, N> {
     fn clone(&self) -> Self {
-        Self(self.0.clone())
+        Self(self.0.clone(), self.1.clone())
+    }
+}
+
+impl<E: Serialize, const N: usize> Serialize for PhaseEquilibrium<E, N> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.0.as_slice().serialize(serializer)
+    }
+}
+
+impl<'de, E: EquationOfState + Deserialize<'de>, const N: usize> Deserialize<'de>
+    for PhaseEquilibrium<E, N>
+{
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let states = Vec::<State<E>>::deserialize(deserializer)?;
+        let n = states.len();
+        let states = states
+            .try_into()
+            .map_err(|_| D::Error::invalid_length(n, &format!("{} states", N).as_str()))?;
+        Ok(Self(states, None))
     }
 }
 
@@ -192,7 +220,7 @@ impl<E: EquationOfState> PhaseEquilibrium<E, 2> {
         } else {
             (state2, state1)
         };
-        Self([vapor, liquid])
+        Self([vapor, liquid], None)
     }
 
     pub(super) fn new_npt(
@@ -216,7 +244,7 @@ impl<E: EquationOfState> PhaseEquilibrium<E, 2> {
             vapor_moles,
             DensityInitialization::Vapor,
         )?;
-        Ok(Self([vapor, liquid]))
+        Ok(Self([vapor, liquid], None))
     }
 
     pub(super) fn vapor_phase_fraction(&self) -> f64 {
@@ -227,6 +255,16 @@ impl<E: EquationOfState> PhaseEquilibrium<E, 2> {
 }
 
 impl<E: EquationOfState, const N: usize> PhaseEquilibrium<E, N> {
+    /// The log of the solver that determined the phase equilibrium.
+    pub fn solver_log(&self) -> Option<&SolverLog> {
+        self.1.as_deref()
+    }
+
+    pub(super) fn with_solver_log(mut self, solver_log: SolverLog) -> Self {
+        self.1 = Some(Arc::new(solver_log));
+        self
+    }
+
     pub(super) fn update_pressure(
         mut self,
         temperature: SINumber,
@@ -3,9 +3,11 @@ use crate::equation_of_state::EquationOfState;
 use crate::errors::{EosError, EosResult};
 use crate::state::{Contributions, DensityInitialization, State, StateBuilder, TPSpec};
 use crate::EosUnit;
-use ndarray::{arr1, arr2, concatenate, s, Array1, Array2, Axis};
+use ndarray::{arr1, arr2, concatenate, s, Array1, Array2, ArrayView1, Axis};
 use num_dual::linalg::{norm, LU};
 use quantity::si::{SIArray1, SINumber, SIUnit};
+#[cfg(feature = "rayon")]
+use rayon::{prelude::*, ThreadPool};
 use std::convert::{TryFrom, TryInto};
 use std::sync::Arc;
 
@@ -18,12 +20,62 @@ impl<E: EquationOfState> PhaseDiagram<E, 2> {
     /// If a heteroazeotrope occurs and the composition of the liquid
     /// phases are known, they can be passed as `x_lle` to avoid
     /// the calculation of unstable branches.
+    ///
+    /// Homogeneous azeotropes are detected along the diagram, converged
+    /// and inserted into the diagram.
     pub fn binary_vle(
         eos: &Arc<E>,
         temperature_or_pressure: SINumber,
         npoints: Option<usize>,
         x_lle: Option<(f64, f64)>,
         bubble_dew_options: (SolverOptions, SolverOptions),
+    ) -> EosResult<Self> {
+        Self::binary_vle_chunked(
+            eos,
+            temperature_or_pressure,
+            npoints,
+            x_lle,
+            None,
+            bubble_dew_options,
+        )
+    }
+
+    /// Create a new binary phase diagram exhibiting a
+    /// vapor/liquid equilibrium, calculating the state points
+    /// in parallel.
+    ///
+    /// The compositions are split into chunks of `chunksize` points
+    /// that are calculated in sequence within a thread, using the
+    /// previous state point as initial value.
+    #[cfg(feature = "rayon")]
+    pub fn par_binary_vle(
+        eos: &Arc<E>,
+        temperature_or_pressure: SINumber,
+        npoints: Option<usize>,
+        x_lle: Option<(f64, f64)>,
+        chunksize: usize,
+        thread_pool: ThreadPool,
+        bubble_dew_options: (SolverOptions, SolverOptions),
+    ) -> EosResult<Self> {
+        thread_pool.install(|| {
+            Self::binary_vle_chunked(
+                eos,
+                temperature_or_pressure,
+                npoints,
+                x_lle,
+                Some(chunksize),
+                bubble_dew_options,
+            )
+        })
+    }
+
+    fn binary_vle_chunked(
+        eos: &Arc<E>,
+        temperature_or_pressure: SINumber,
+        npoints: Option<usize>,
+        x_lle: Option<(f64, f64)>,
+        chunksize: Option<usize>,
+        bubble_dew_options: (SolverOptions, SolverOptions),
     ) -> EosResult<Self> {
         let npoints = npoints.unwrap_or(DEFAULT_POINTS);
         let tp = temperature_or_pressure.try_into()?;
@@ -34,8 +86,15 @@ impl<E: EquationOfState> PhaseDiagram<E, 2> {
 
         // Only calculate up to specified compositions
         if let Some(x_lle) = x_lle {
-            let (states1, states2) =
-                Self::calculate_vlle(eos, tp, npoints, x_lle, vle_sat, bubble_dew_options)?;
+            let (states1, states2) = Self::calculate_vlle(
+                eos,
+                tp,
+                npoints,
+                x_lle,
+                vle_sat,
+                chunksize,
+                bubble_dew_options,
+            )?;
 
             let states = states1
                 .into_iter()
@@ -86,12 +145,15 @@ impl<E: EquationOfState> PhaseDiagram<E, 2> {
             Some(vle_lim.1),
             npoints,
             bubble,
+            chunksize,
             bubble_dew_options,
         );
         if !bubble {
             states = states.into_iter().rev().collect();
         }
-        Ok(Self { states })
+        let mut diagram = Self { states };
+        diagram.insert_azeotropes(eos, temperature_or_pressure, bubble_dew_options)?;
+        Ok(diagram)
     }
 
     #[allow(clippy::type_complexity)]
@@ -101,6 +163,7 @@ impl<E: EquationOfState> PhaseDiagram<E, 2> {
         npoints: usize,
         x_lle: (f64, f64),
         vle_sat: [Option<PhaseEquilibrium<E, 2>>; 2],
+        chunksize: Option<usize>,
         bubble_dew_options: (SolverOptions, SolverOptions),
     ) -> EosResult<(Vec<PhaseEquilibrium<E, 2>>, Vec<PhaseEquilibrium<E, 2>>)> {
         match vle_sat {
@@ -113,6 +176,7 @@ impl<E: EquationOfState> PhaseDiagram<E, 2> {
                     None,
                     npoints / 2,
                     true,
+                    chunksize,
                     bubble_dew_options,
                 );
                 let states2 = iterate_vle(
@@ -123,6 +187,7 @@ impl<E: EquationOfState> PhaseDiagram<E, 2> {
                     None,
                     npoints - npoints / 2,
                     true,
+                    chunksize,
                     bubble_dew_options,
                 );
                 Ok((states1, states2))
@@ -145,29 +210,97 @@ impl<E: EquationOfState> PhaseDiagram<E, 2> {
         max_tp: SINumber,
         npoints: Option<usize>,
     ) -> EosResult<Self> {
-        let npoints = npoints.unwrap_or(DEFAULT_POINTS);
-        let mut states = Vec::with_capacity(npoints);
-        let tp: TPSpec = temperature_or_pressure.try_into()?;
+        Self::lle_chunked(
+            eos,
+            temperature_or_pressure,
+            feed,
+            min_tp,
+            max_tp,
+            npoints,
+            None,
+        )
+    }
 
-        let tp_vec = SIArray1::linspace(min_tp, max_tp, npoints)?;
-        let mut vle = None;
-        for i in 0..npoints {
-            let (_, t, p) = tp.temperature_pressure(tp_vec.get(i));
-            vle = PhaseEquilibrium::tp_flash(
+    /// Create a new phase diagram using Tp flash calculations,
+    /// calculating the state points in parallel.
+    ///
+    /// The temperatures/pressures are split into chunks of `chunksize`
+    /// points that are calculated in sequence within a thread, using the
+    /// previous state point as initial value.
+    #[cfg(feature = "rayon")]
+    pub fn par_lle(
+        eos: &Arc<E>,
+        temperature_or_pressure: SINumber,
+        feed: &SIArray1,
+        min_tp: SINumber,
+        max_tp: SINumber,
+        npoints: Option<usize>,
+        chunksize: usize,
+        thread_pool: ThreadPool,
+    ) -> EosResult<Self> {
+        thread_pool.install(|| {
+            Self::lle_chunked(
                 eos,
-                t,
-                p,
+                temperature_or_pressure,
                 feed,
-                vle.as_ref(),
-                SolverOptions::default(),
-                None,
+                min_tp,
+                max_tp,
+                npoints,
+                Some(chunksize),
             )
-            .ok();
-            if let Some(vle) = &vle {
-                states.push(vle.clone());
-            }
-        }
-        Ok(Self { states })
+        })
+    }
+
+    fn lle_chunked(
+        eos: &Arc<E>,
+        temperature_or_pressure: SINumber,
+        feed: &SIArray1,
+        min_tp: SINumber,
+        max_tp: SINumber,
+        npoints: Option<usize>,
+        chunksize: Option<usize>,
+    ) -> EosResult<Self> {
+        let npoints = npoints.unwrap_or(DEFAULT_POINTS);
+        let tp: TPSpec = temperature_or_pressure.try_into()?;
+        let tp_vec = SIArray1::linspace(min_tp, max_tp, npoints)?;
+
+        // the state points are calculated in sequence within a chunk and the
+        // chunks are calculated in parallel if the `rayon` feature is enabled
+        let chunksize = chunksize.unwrap_or(npoints).max(1);
+        let chunks: Vec<_> = (0..npoints)
+            .step_by(chunksize)
+            .map(|i| i..(i + chunksize).min(npoints))
+            .collect();
+        #[cfg(feature = "rayon")]
+        let chunks = chunks.into_par_iter();
+        #[cfg(not(feature = "rayon"))]
+        let chunks = chunks.into_iter();
+        let states: Vec<Vec<_>> = chunks
+            .map(|chunk| {
+                let mut states = Vec::with_capacity(chunk.len());
+                let mut vle = None;
+                for i in chunk {
+                    let (_, t, p) = tp.temperature_pressure(tp_vec.get(i));
+                    vle = PhaseEquilibrium::tp_flash(
+                        eos,
+                        t,
+                        p,
+                        feed,
+                        vle.as_ref(),
+                        SolverOptions::default(),
+                        None,
+                    )
+                    .ok();
+                    if let Some(vle) = &vle {
+                        states.push(vle.clone());
+                    }
+                }
+                states
+            })
+            .collect();
+        Ok(Self {
+            states: states.into_iter().flatten().collect(),
+        })
     }
 }
 
@@ -179,6 +312,7 @@ fn iterate_vle<E: EquationOfState>(
     vle_1: Option<PhaseEquilibrium<E, 2>>,
     npoints: usize,
     bubble: bool,
+    chunksize: Option<usize>,
     bubble_dew_options: (SolverOptions, SolverOptions),
 ) -> Vec<PhaseEquilibrium<E, 2>>
 where
@@ -193,10 +327,39 @@ where
         x.slice(s![1..])
     };
 
-    let tp_0 = Some(vle_0.vapor().tp(tp));
-    let mut tp_old = tp_0;
-    let mut y_old = None;
+    let tp_0 = vle_0.vapor().tp(tp);
     vle_vec.push(vle_0);
+
+    // the state points are calculated in sequence within a chunk and the
+    // chunks are calculated in parallel if the `rayon` feature is enabled
+    let chunksize = chunksize.unwrap_or(x.len()).max(1);
+    let chunks = x.axis_chunks_iter(Axis(0), chunksize);
+    #[cfg(feature = "rayon")]
+    let chunks = chunks.into_par_iter();
+    let states: Vec<_> = chunks
+        .map(|x| iterate_vle_chunk(eos, tp, x, tp_0, bubble, bubble_dew_options))
+        .collect();
+    vle_vec.extend(states.into_iter().flatten());
+    if let Some(vle_1) = vle_1 {
+        vle_vec.push(vle_1);
+    }
+
+    vle_vec
+}
+
+/// Calculate bubble or dew points for the given compositions in sequence,
+/// using the previous state point as initial value.
+fn iterate_vle_chunk<E: EquationOfState>(
+    eos: &Arc<E>,
+    tp: TPSpec,
+    x: ArrayView1<f64>,
+    tp_0: SINumber,
+    bubble: bool,
+    bubble_dew_options: (SolverOptions, SolverOptions),
+) -> Vec<PhaseEquilibrium<E, 2>> {
+    let mut vle_vec = Vec::with_capacity(x.len());
+    let mut tp_old = Some(tp_0);
+    let mut y_old = None;
     for xi in x {
         let vle = PhaseEquilibrium::bubble_dew_point(
             eos,
@@ -221,13 +384,9 @@ where
             vle_vec.push(vle.clone());
         } else {
             y_old = None;
-            tp_old = tp_0;
+            tp_old = Some(tp_0);
         }
     }
-    if let Some(vle_1) = vle_1 {
-        vle_vec.push(vle_1);
-    }
-
     vle_vec
 }
 
@@ -262,6 +421,65 @@ impl<E: EquationOfState> PhaseDiagram<E, 2> {
         npoints_vle: Option<usize>,
         npoints_lle: Option<usize>,
         bubble_dew_options: (SolverOptions, SolverOptions),
+    ) -> EosResult<PhaseDiagramHetero<E>> {
+        Self::binary_vlle_chunked(
+            eos,
+            temperature_or_pressure,
+            x_lle,
+            tp_lim_lle,
+            tp_init_vlle,
+            npoints_vle,
+            npoints_lle,
+            None,
+            bubble_dew_options,
+        )
+    }
+
+    /// Create a new binary phase diagram exhibiting a
+    /// vapor/liquid/liquid equilibrium, calculating the state points
+    /// of the VLE and LLE in parallel.
+    ///
+    /// The state points are split into chunks of `chunksize` points
+    /// that are calculated in sequence within a thread, using the
+    /// previous state point as initial value.
+    #[cfg(feature = "rayon")]
+    pub fn par_binary_vlle(
+        eos: &Arc<E>,
+        temperature_or_pressure: SINumber,
+        x_lle: (f64, f64),
+        tp_lim_lle: Option<SINumber>,
+        tp_init_vlle: Option<SINumber>,
+        npoints_vle: Option<usize>,
+        npoints_lle: Option<usize>,
+        chunksize: usize,
+        thread_pool: ThreadPool,
+        bubble_dew_options: (SolverOptions, SolverOptions),
+    ) -> EosResult<PhaseDiagramHetero<E>> {
+        thread_pool.install(|| {
+            Self::binary_vlle_chunked(
+                eos,
+                temperature_or_pressure,
+                x_lle,
+                tp_lim_lle,
+                tp_init_vlle,
+                npoints_vle,
+                npoints_lle,
+                Some(chunksize),
+                bubble_dew_options,
+            )
+        })
+    }
+
+    fn binary_vlle_chunked(
+        eos: &Arc<E>,
+        temperature_or_pressure: SINumber,
+        x_lle: (f64, f64),
+        tp_lim_lle: Option<SINumber>,
+        tp_init_vlle: Option<SINumber>,
+        npoints_vle: Option<usize>,
+        npoints_lle: Option<usize>,
+        chunksize: Option<usize>,
+        bubble_dew_options: (SolverOptions, SolverOptions),
     ) -> EosResult<PhaseDiagramHetero<E>> {
         let npoints_vle = npoints_vle.unwrap_or(DEFAULT_POINTS);
         let tp = temperature_or_pressure.try_into()?;
@@ -298,6 +516,7 @@ impl<E: EquationOfState> PhaseDiagram<E, 2> {
             npoints_vle,
             x_hetero,
             vle_sat,
+            chunksize,
             bubble_dew_options,
         )?;
 
@@ -310,13 +529,14 @@ impl<E: EquationOfState> PhaseDiagram<E, 2> {
                 };
                 let x_feed = 0.5 * (x_hetero.0 + x_hetero.1);
                 let feed = arr1(&[x_feed, 1.0 - x_feed]) * SIUnit::reference_moles();
-                PhaseDiagram::lle(
+                PhaseDiagram::lle_chunked(
                     eos,
                     temperature_or_pressure,
                     &feed,
                     tp_lim,
                     tp_hetero,
                     npoints_lle,
+                    chunksize,
                 )
             })
             .transpose()?;
@@ -451,7 +671,7 @@ where
 
             // check for convergence
             if norm(&res) < options.tol.unwrap_or(TOL_HETERO) {
-                return Ok(Self([v, l1, l2]));
+                return Ok(Self([v, l1, l2], None));
             }
 
             // calculate Jacobian
@@ -604,7 +824,7 @@ where
 
             // check for convergence
             if norm(&res) < options.tol.unwrap_or(TOL_HETERO) {
-                return Ok(Self([v, l1, l2]));
+                return Ok(Self([v, l1, l2], None));
             }
 
             // calculate Jacobian
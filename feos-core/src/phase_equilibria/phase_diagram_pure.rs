@@ -1,6 +1,7 @@
 use super::{PhaseEquilibrium, SolverOptions};
 use crate::equation_of_state::EquationOfState;
 use crate::errors::EosResult;
+use crate::progress::ProgressCallback;
 use crate::state::{State, StateVec};
 #[cfg(feature = "rayon")]
 use crate::EosUnit;
@@ -35,12 +36,16 @@ impl<E, const N: usize> PhaseDiagram<E, N> {
 
 impl<E: EquationOfState> PhaseDiagram<E, 2> {
     /// Calculate a phase diagram for a pure component.
+    ///
+    /// If a `callback` is provided, it is invoked before the calculation
+    /// of every temperature and can cancel the calculation.
     pub fn pure(
         eos: &Arc<E>,
         min_temperature: SINumber,
         npoints: usize,
         critical_temperature: Option<SINumber>,
         options: SolverOptions,
+        callback: Option<&dyn ProgressCallback>,
     ) -> EosResult<Self> {
         let mut states = Vec::with_capacity(npoints);
 
@@ -51,7 +56,11 @@ impl<E: EquationOfState> PhaseDiagram<E, 2> {
         let temperatures = SIArray1::linspace(min_temperature, max_temperature, npoints - 1)?;
 
         let mut vle = None;
-        for ti in &temperatures {
+        for i in 0..temperatures.len() {
+            if let Some(callback) = callback {
+                callback.check("PhaseDiagram::pure", i, f64::NAN)?;
+            }
+            let ti = temperatures.get(i);
             vle = PhaseEquilibrium::pure(eos, ti, vle.as_ref(), options).ok();
             if let Some(vle) = vle.as_ref() {
                 states.push(vle.clone());
@@ -146,7 +146,7 @@ impl<E: EquationOfState> PhaseDiagram<E, 2> {
         for ti in &temperatures {
             let spinodal = State::spinodal(eos, ti, Some(moles), options).ok();
             if let Some(spinodal) = spinodal {
-                states.push(PhaseEquilibrium(spinodal));
+                states.push(PhaseEquilibrium(spinodal, None));
             }
         }
         states.push(PhaseEquilibrium::from_states(sc.clone(), sc));
@@ -0,0 +1,314 @@
+use super::{PhaseDiagram, PhaseEquilibrium, SolverOptions, Verbosity};
+use crate::equation_of_state::EquationOfState;
+use crate::errors::{EosError, EosResult};
+use crate::state::{Contributions, DensityInitialization, State, StateVec};
+use crate::EosUnit;
+use ndarray::arr1;
+use quantity::si::{SIArray1, SINumber, SIUnit};
+use std::sync::Arc;
+
+const MAX_ITER_SOLID: usize = 50;
+const TOL_SOLID: f64 = 1e-10;
+const MAX_LNPSTEP_SOLID: f64 = 1.0;
+
+/// Simple model of the solid phase of a pure component.
+///
+/// The molar Gibbs energy of the solid is calculated relative to the
+/// (metastable) liquid of the equation of state at the triple point
+/// pressure $p_\mathrm{t}$:
+/// $$g^\mathrm{s}(T,p)=g^\mathrm{l}(T,p_\mathrm{t})-\Delta h_\mathrm{m}\left(1-\frac{T}{T_\mathrm{t}}\right)-\Delta c_p\left(T-T_\mathrm{t}-T\ln\frac{T}{T_\mathrm{t}}\right)+v^\mathrm{s}(p-p_\mathrm{t})$$
+/// with the melting enthalpy $\Delta h_\mathrm{m}$ at the triple point
+/// temperature $T_\mathrm{t}$, the difference of the heat capacities of the
+/// liquid and the solid $\Delta c_p$ and the (incompressible) molar volume
+/// of the solid $v^\mathrm{s}$. The triple point pressure is the vapor pressure
+/// of the equation of state at the triple point temperature.
+#[derive(Clone, Copy)]
+pub struct SolidModel {
+    /// Triple point temperature.
+    pub triple_temperature: SINumber,
+    /// Molar melting enthalpy at the triple point.
+    pub melting_enthalpy: SINumber,
+    /// Molar volume of the solid.
+    pub molar_volume: SINumber,
+    /// Molar isobaric heat capacity of the liquid minus that of the solid.
+    pub heat_capacity_difference: SINumber,
+}
+
+impl SolidModel {
+    pub fn new(
+        triple_temperature: SINumber,
+        melting_enthalpy: SINumber,
+        molar_volume: SINumber,
+        heat_capacity_difference: Option<SINumber>,
+    ) -> Self {
+        Self {
+            triple_temperature,
+            melting_enthalpy,
+            molar_volume,
+            heat_capacity_difference: heat_capacity_difference
+                .unwrap_or(0.0 * SIUnit::reference_molar_entropy()),
+        }
+    }
+
+    /// Molar Gibbs energy of melting $g^\mathrm{l}-g^\mathrm{s}$ at the triple point pressure.
+    fn melting_gibbs_energy(&self, temperature: SINumber) -> EosResult<SINumber> {
+        let t_t = self.triple_temperature;
+        let ln_t = (temperature / t_t).into_value()?.ln();
+        Ok(
+            self.melting_enthalpy * (1.0 - (temperature / t_t).into_value()?)
+                + self.heat_capacity_difference * (temperature - t_t - temperature * ln_t),
+        )
+    }
+
+    /// Calculate the triple point, i.e., the vapor-liquid equilibrium
+    /// at the triple point temperature.
+    pub fn triple_point<E: EquationOfState>(
+        &self,
+        eos: &Arc<E>,
+        options: SolverOptions,
+    ) -> EosResult<PhaseEquilibrium<E, 2>> {
+        PhaseEquilibrium::pure(eos, self.triple_temperature, None, options)
+    }
+
+    /// Molar Gibbs energy of the solid phase.
+    fn solid_gibbs_energy<E: EquationOfState>(
+        &self,
+        eos: &Arc<E>,
+        temperature: SINumber,
+        pressure: SINumber,
+        triple_pressure: SINumber,
+    ) -> EosResult<SINumber> {
+        let moles = arr1(&[1.0]) * SIUnit::reference_moles();
+        let liquid = State::new_npt(
+            eos,
+            temperature,
+            triple_pressure,
+            &moles,
+            DensityInitialization::Liquid,
+        )?;
+        Ok(liquid.molar_gibbs_energy(Contributions::Total)
+            - self.melting_gibbs_energy(temperature)?
+            + self.molar_volume * (pressure - triple_pressure))
+    }
+
+    /// Calculate the vapor state in equilibrium with the solid
+    /// at the given temperature below the triple point.
+    pub fn sublimation_point<E: EquationOfState>(
+        &self,
+        eos: &Arc<E>,
+        temperature: SINumber,
+        initial_pressure: Option<SINumber>,
+        options: SolverOptions,
+    ) -> EosResult<State<E>> {
+        let vle = self.triple_point(eos, SolverOptions::default())?;
+        self.sublimation_point_(eos, temperature, &vle, initial_pressure, options)
+    }
+
+    fn sublimation_point_<E: EquationOfState>(
+        &self,
+        eos: &Arc<E>,
+        temperature: SINumber,
+        triple_point: &PhaseEquilibrium<E, 2>,
+        initial_pressure: Option<SINumber>,
+        options: SolverOptions,
+    ) -> EosResult<State<E>> {
+        let (max_iter, tol, verbosity) = options.unwrap_or(MAX_ITER_SOLID, TOL_SOLID);
+        let p_t = triple_point.vapor().pressure(Contributions::Total);
+        let rt = SIUnit::gas_constant() * temperature;
+        let moles = arr1(&[1.0]) * SIUnit::reference_moles();
+
+        // the Gibbs energy of the solid at the triple point pressure
+        let g_s_t = self.solid_gibbs_energy(eos, temperature, p_t, p_t)?;
+
+        // initial value from the Clausius-Clapeyron equation
+        let mut p = match initial_pressure {
+            Some(p) => p,
+            None => {
+                let h_sub = triple_point.vapor().molar_enthalpy(Contributions::Total)
+                    - triple_point.liquid().molar_enthalpy(Contributions::Total)
+                    + self.melting_enthalpy;
+                let x = (h_sub / SIUnit::gas_constant()
+                    * (1.0 / temperature - 1.0 / self.triple_temperature))
+                    .into_value()?;
+                p_t * (-x).exp()
+            }
+        };
+
+        log_iter!(verbosity, " iter |    residual    |   pressure   ");
+        log_iter!(verbosity, "{:-<47}", "");
+        for i in 1..=max_iter {
+            let vapor = State::new_npt(eos, temperature, p, &moles, DensityInitialization::Vapor)?;
+            let g_s = g_s_t + self.molar_volume * (p - p_t);
+            let f = ((vapor.molar_gibbs_energy(Contributions::Total) - g_s) / rt).into_value()?;
+            log_iter!(verbosity, " {:4} | {:14.8e} | {:12.8}", i, f, p);
+            if f.abs() < tol {
+                log_result!(
+                    verbosity,
+                    "Sublimation point: calculation converged in {} step(s)\n",
+                    i
+                );
+                return Ok(vapor);
+            }
+            let df = (p * (1.0 / vapor.density - self.molar_volume) / rt).into_value()?;
+            let dlnp = (-f / df).max(-MAX_LNPSTEP_SOLID).min(MAX_LNPSTEP_SOLID);
+            if !dlnp.is_finite() {
+                return Err(EosError::IterationFailed(String::from(
+                    "SolidModel::sublimation_point",
+                )));
+            }
+            p = p * dlnp.exp();
+        }
+        Err(EosError::NotConverged(String::from(
+            "SolidModel::sublimation_point",
+        )))
+    }
+
+    /// Calculate the liquid state in equilibrium with the solid
+    /// at the given temperature above the triple point.
+    pub fn melting_point<E: EquationOfState>(
+        &self,
+        eos: &Arc<E>,
+        temperature: SINumber,
+        initial_pressure: Option<SINumber>,
+        options: SolverOptions,
+    ) -> EosResult<State<E>> {
+        let vle = self.triple_point(eos, SolverOptions::default())?;
+        self.melting_point_(eos, temperature, &vle, initial_pressure, options)
+    }
+
+    fn melting_point_<E: EquationOfState>(
+        &self,
+        eos: &Arc<E>,
+        temperature: SINumber,
+        triple_point: &PhaseEquilibrium<E, 2>,
+        initial_pressure: Option<SINumber>,
+        options: SolverOptions,
+    ) -> EosResult<State<E>> {
+        let (max_iter, tol, verbosity) = options.unwrap_or(MAX_ITER_SOLID, TOL_SOLID);
+        let p_t = triple_point.vapor().pressure(Contributions::Total);
+        let rt = SIUnit::gas_constant() * temperature;
+        let moles = arr1(&[1.0]) * SIUnit::reference_moles();
+
+        // the Gibbs energy of the solid at the triple point pressure
+        let g_s_t = self.solid_gibbs_energy(eos, temperature, p_t, p_t)?;
+
+        let mut p = initial_pressure.unwrap_or(p_t);
+        let mut density = DensityInitialization::Liquid;
+
+        log_iter!(verbosity, " iter |    residual    |   pressure   ");
+        log_iter!(verbosity, "{:-<47}", "");
+        for i in 1..=max_iter {
+            let liquid = State::new_npt(eos, temperature, p, &moles, density)?;
+            let g_s = g_s_t + self.molar_volume * (p - p_t);
+            let f = ((liquid.molar_gibbs_energy(Contributions::Total) - g_s) / rt).into_value()?;
+            log_iter!(verbosity, " {:4} | {:14.8e} | {:12.8}", i, f, p);
+            if f.abs() < tol {
+                log_result!(
+                    verbosity,
+                    "Melting point: calculation converged in {} step(s)\n",
+                    i
+                );
+                return Ok(liquid);
+            }
+            let df = ((1.0 / liquid.density - self.molar_volume) / rt)
+                .to_reduced(1.0 / SIUnit::reference_pressure())?;
+            let dp = -f / df;
+            if !dp.is_finite() {
+                return Err(EosError::IterationFailed(String::from(
+                    "SolidModel::melting_point",
+                )));
+            }
+            // avoid negative pressures
+            let p_new = p.to_reduced(SIUnit::reference_pressure())? + dp;
+            p = if p_new > 0.0 {
+                p_new * SIUnit::reference_pressure()
+            } else {
+                0.5 * p
+            };
+            density = DensityInitialization::InitialDensity(liquid.density);
+        }
+        Err(EosError::NotConverged(String::from(
+            "SolidModel::melting_point",
+        )))
+    }
+}
+
+/// Pure component phase diagram including the solid phase.
+pub struct PhaseDiagramSolid<E> {
+    /// The triple point.
+    pub triple_point: PhaseEquilibrium<E, 2>,
+    /// The vapor-liquid equilibria between the triple point and the critical point.
+    pub vle: PhaseDiagram<E, 2>,
+    /// The vapor states in equilibrium with the solid.
+    pub sublimation: Vec<State<E>>,
+    /// The liquid states in equilibrium with the solid.
+    pub melting: Vec<State<E>>,
+}
+
+impl<E> PhaseDiagramSolid<E> {
+    /// Return the vapor states along the sublimation curve.
+    pub fn sublimation(&self) -> StateVec<'_, E> {
+        self.sublimation.iter().collect()
+    }
+
+    /// Return the liquid states along the melting curve.
+    pub fn melting(&self) -> StateVec<'_, E> {
+        self.melting.iter().collect()
+    }
+}
+
+impl<E: EquationOfState> PhaseDiagram<E, 2> {
+    /// Calculate a pure component phase diagram including the sublimation
+    /// curve from `min_temperature` to the triple point, the vapor pressure
+    /// curve from the triple point to the critical point, and the melting
+    /// curve from the triple point to `max_temperature`.
+    pub fn pure_solid(
+        eos: &Arc<E>,
+        solid: &SolidModel,
+        min_temperature: SINumber,
+        max_temperature: SINumber,
+        npoints: usize,
+        critical_temperature: Option<SINumber>,
+        options: SolverOptions,
+    ) -> EosResult<PhaseDiagramSolid<E>> {
+        let triple_point = solid.triple_point(eos, options)?;
+        let vle = Self::pure(
+            eos,
+            solid.triple_temperature,
+            npoints,
+            critical_temperature,
+            options,
+        )?;
+
+        // sublimation curve starting from the triple point
+        let temperatures = SIArray1::linspace(solid.triple_temperature, min_temperature, npoints)?;
+        let mut sublimation: Vec<State<E>> = Vec::with_capacity(npoints);
+        for t in (&temperatures).into_iter().skip(1) {
+            let p_init = sublimation.last().map(|s| s.pressure(Contributions::Total));
+            match solid.sublimation_point_(eos, t, &triple_point, p_init, options) {
+                Ok(state) => sublimation.push(state),
+                Err(_) => break,
+            }
+        }
+        sublimation.reverse();
+        sublimation.push(triple_point.vapor().clone());
+
+        // melting curve starting from the triple point
+        let temperatures = SIArray1::linspace(solid.triple_temperature, max_temperature, npoints)?;
+        let mut melting = vec![triple_point.liquid().clone()];
+        for t in (&temperatures).into_iter().skip(1) {
+            let p_init = melting.last().map(|s| s.pressure(Contributions::Total));
+            match solid.melting_point_(eos, t, &triple_point, p_init, options) {
+                Ok(state) => melting.push(state),
+                Err(_) => break,
+            }
+        }
+
+        Ok(PhaseDiagramSolid {
+            triple_point,
+            vle,
+            sublimation,
+            melting,
+        })
+    }
+}
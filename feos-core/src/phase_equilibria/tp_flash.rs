@@ -1,6 +1,7 @@
 use super::{PhaseEquilibrium, SolverOptions, Verbosity};
 use crate::equation_of_state::EquationOfState;
 use crate::errors::{EosError, EosResult};
+use crate::solver_log::SolverLog;
 use crate::state::{Contributions, DensityInitialization, State};
 use ndarray::*;
 use num_dual::linalg::norm;
@@ -75,13 +76,13 @@ impl<E: EquationOfState> State<E> {
             new_vle_state.liquid().molefracs,
         );
 
-        let mut iter = 0;
+        let mut log = SolverLog::new("tp_flash");
         if non_volatile_components.is_none() {
             // 3 steps of successive substitution
             new_vle_state.successive_substitution(
                 self,
                 3,
-                &mut iter,
+                &mut log,
                 &mut None,
                 tol,
                 verbosity,
@@ -107,7 +108,7 @@ impl<E: EquationOfState> State<E> {
                 new_vle_state.successive_substitution(
                     self,
                     1,
-                    &mut iter,
+                    &mut log,
                     &mut None,
                     tol,
                     verbosity,
@@ -126,7 +127,7 @@ impl<E: EquationOfState> State<E> {
                 new_vle_state.successive_substitution(
                     self,
                     1,
-                    &mut iter,
+                    &mut log,
                     &mut None,
                     tol,
                     verbosity,
@@ -138,14 +139,15 @@ impl<E: EquationOfState> State<E> {
         //continue with accelerated successive subsitution
         new_vle_state.accelerated_successive_substitution(
             self,
-            &mut iter,
+            &mut log,
             max_iter,
             tol,
             verbosity,
             &non_volatile_components,
         )?;
 
-        Ok(new_vle_state)
+        log.converged = true;
+        Ok(new_vle_state.with_solver_log(log))
     }
 
     fn tangent_plane_distance(&self, trial_state: &State<E>) -> f64 {
@@ -161,7 +163,7 @@ impl<E: EquationOfState> PhaseEquilibrium<E, 2> {
     fn accelerated_successive_substitution(
         &mut self,
         feed_state: &State<E>,
-        iter: &mut usize,
+        log: &mut SolverLog,
         max_iter: usize,
         tol: f64,
         verbosity: Verbosity,
@@ -173,7 +175,7 @@ impl<E: EquationOfState> PhaseEquilibrium<E, 2> {
             if self.successive_substitution(
                 feed_state,
                 5,
-                iter,
+                log,
                 &mut Some(&mut k_vec),
                 tol,
                 verbosity,
@@ -182,7 +184,7 @@ impl<E: EquationOfState> PhaseEquilibrium<E, 2> {
                 log_result!(
                     verbosity,
                     "Tp flash: calculation converged in {} step(s)\n",
-                    iter
+                    log.len()
                 );
                 return Ok(());
             }
@@ -227,7 +229,7 @@ impl<E: EquationOfState> PhaseEquilibrium<E, 2> {
         &mut self,
         feed_state: &State<E>,
         iterations: usize,
-        iter: &mut usize,
+        log: &mut SolverLog,
         k_vec: &mut Option<&mut Array2<f64>>,
         abs_tol: f64,
         verbosity: Verbosity,
@@ -244,7 +246,6 @@ impl<E: EquationOfState> PhaseEquilibrium<E, 2> {
             }
 
             // check for convergence
-            *iter += 1;
             let mut res_vec = ln_phi_l - ln_phi_v
                 + (&self.liquid().molefracs / &self.vapor().molefracs).map(|&i| {
                     if i > 0.0 {
@@ -262,16 +263,19 @@ impl<E: EquationOfState> PhaseEquilibrium<E, 2> {
             log_iter!(
                 verbosity,
                 " {:4} | {:14.8e} | {:.8} | {:.8}",
-                iter,
+                log.len() + 1,
                 res,
                 self.vapor().molefracs,
                 self.liquid().molefracs,
             );
             if res < abs_tol {
+                log.push(res, f64::NAN, f64::NAN);
                 return Ok(true);
             }
 
+            let y_old = self.vapor().molefracs.clone();
             self.update_states(feed_state, &k)?;
+            log.push(res, norm(&(&self.vapor().molefracs - &y_old)), 1.0);
             if let Some(k_vec) = k_vec {
                 if i >= iterations - 3 {
                     k_vec
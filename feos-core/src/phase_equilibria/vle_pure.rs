@@ -1,6 +1,7 @@
 use super::{PhaseEquilibrium, SolverOptions, Verbosity};
 use crate::equation_of_state::EquationOfState;
 use crate::errors::{EosError, EosResult};
+use crate::solver_log::SolverLog;
 use crate::state::{Contributions, DensityInitialization, State, TPSpec};
 use crate::EosUnit;
 use ndarray::{arr1, Array1};
@@ -64,6 +65,7 @@ impl<E: EquationOfState> PhaseEquilibrium<E, 2> {
     fn iterate_pure_t(self, max_iter: usize, tol: f64, verbosity: Verbosity) -> EosResult<Self> {
         let mut p_old = self.vapor().pressure(Contributions::Total);
         let [mut vapor, mut liquid] = self.0;
+        let mut log = SolverLog::new("pure_t");
 
         log_iter!(verbosity,
             " iter |     residual      |     pressure     |    liquid density    |    vapor density     | Newton steps"
@@ -122,8 +124,10 @@ impl<E: EquationOfState> PhaseEquilibrium<E, 2> {
             }
 
             // Calculate Newton steps for the densities and update state.
-            let rho_l = liquid.density + (p_new - p_l) / p_rho_l;
-            let rho_v = vapor.density + (p_new - p_v) / p_rho_v;
+            let delta_rho_l = (p_new - p_l) / p_rho_l;
+            let delta_rho_v = (p_new - p_v) / p_rho_v;
+            let rho_l = liquid.density + delta_rho_l;
+            let rho_v = vapor.density + delta_rho_v;
             liquid = State::new_pure(&liquid.eos, liquid.temperature, rho_l)?;
             vapor = State::new_pure(&vapor.eos, vapor.temperature, rho_v)?;
             if Self::is_trivial_solution(&vapor, &liquid) {
@@ -132,6 +136,10 @@ impl<E: EquationOfState> PhaseEquilibrium<E, 2> {
 
             // Check for convergence
             let res = (p_new - p_old).abs();
+            let step_size = delta_rho_l
+                .to_reduced(SIUnit::reference_density())?
+                .hypot(delta_rho_v.to_reduced(SIUnit::reference_density())?);
+            log.push(res.to_reduced(p_old)?, step_size, 1.0);
             log_iter!(
                 verbosity,
                 " {:4} | {:14.8e} | {:12.8} | {:12.8} | {:12.8} | {}",
@@ -148,7 +156,8 @@ impl<E: EquationOfState> PhaseEquilibrium<E, 2> {
                     "PhaseEquilibrium::pure_t: calculation converged in {} step(s)\n",
                     i
                 );
-                return Ok(Self([vapor, liquid]));
+                log.converged = true;
+                return Ok(Self([vapor, liquid], None).with_solver_log(log));
             }
             p_old = p_new;
         }
@@ -173,6 +182,7 @@ impl<E: EquationOfState> PhaseEquilibrium<E, 2> {
             None => PhaseEquilibrium::init_pure_p(eos, pressure)?,
         };
 
+        let mut log = SolverLog::new("pure_p");
         log_iter!(
             verbosity,
             " iter |     residual     |   temperature   |    liquid density    |    vapor density     "
@@ -213,7 +223,7 @@ impl<E: EquationOfState> PhaseEquilibrium<E, 2> {
             let rho_l = vle.liquid().density + (pressure - p_l - p_t_l * delta_t) / p_rho_l;
             let rho_v = vle.vapor().density + (pressure - p_v - p_t_v * delta_t) / p_rho_v;
 
-            if rho_l.is_sign_negative()
+            let damping = if rho_l.is_sign_negative()
                 || rho_v.is_sign_negative()
                 || delta_t.abs() > SIUnit::reference_temperature()
             {
@@ -221,16 +231,26 @@ impl<E: EquationOfState> PhaseEquilibrium<E, 2> {
                 vle = vle
                     .update_pressure(t_new, pressure)?
                     .check_trivial_solution()?;
+                f64::NAN
             } else {
                 // update state
-                vle = Self([
-                    State::new_pure(eos, t_new, rho_v)?,
-                    State::new_pure(eos, t_new, rho_l)?,
-                ]);
-            }
+                vle = Self(
+                    [
+                        State::new_pure(eos, t_new, rho_v)?,
+                        State::new_pure(eos, t_new, rho_l)?,
+                    ],
+                    None,
+                );
+                1.0
+            };
 
             // check for convergence
             let res = delta_t.abs();
+            log.push(
+                res.to_reduced(vle.vapor().temperature)?,
+                res.to_reduced(SIUnit::reference_temperature())?,
+                damping,
+            );
             log_iter!(
                 verbosity,
                 " {:4} | {:14.8e} | {:13.8} | {:12.8} | {:12.8}",
@@ -246,7 +266,8 @@ impl<E: EquationOfState> PhaseEquilibrium<E, 2> {
                     "PhaseEquilibrium::pure_p: calculation converged in {} step(s)\n",
                     i
                 );
-                return Ok(vle);
+                log.converged = true;
+                return Ok(vle.with_solver_log(log));
             }
         }
         Err(EosError::NotConverged("pure_p".to_owned()))
@@ -255,7 +276,7 @@ impl<E: EquationOfState> PhaseEquilibrium<E, 2> {
     fn init_pure_state(initial_state: &Self, temperature: SINumber) -> EosResult<Self> {
         let vapor = initial_state.vapor().update_temperature(temperature)?;
         let liquid = initial_state.liquid().update_temperature(temperature)?;
-        Ok(Self([vapor, liquid]))
+        Ok(Self([vapor, liquid], None))
     }
 
     fn init_pure_ideal_gas(eos: &Arc<E>, temperature: SINumber) -> EosResult<Self> {
@@ -0,0 +1,141 @@
+use super::Stream;
+use crate::equation_of_state::EquationOfState;
+use crate::errors::EosResult;
+use crate::phase_equilibria::{PhaseEquilibrium, SolverOptions};
+use crate::state::Contributions;
+use crate::EosUnit;
+use ndarray::Array1;
+use quantity::si::{SIArray1, SINumber, SIUnit};
+use std::sync::Arc;
+
+/// Streams along a line of constant pressure or temperature,
+/// e.g., for the generation of T-s, p-h or h-s charts.
+///
+/// For pure components, the saturated liquid and vapor are inserted
+/// into the line if the saturation point is within the given range.
+pub struct Isoline<E> {
+    pub streams: Vec<Stream<E>>,
+}
+
+impl<E> Clone for Isoline<E> {
+    fn clone(&self) -> Self {
+        Self {
+            streams: self.streams.clone(),
+        }
+    }
+}
+
+impl<E: EquationOfState> Isoline<E> {
+    /// Calculate streams at constant pressure for the given temperatures.
+    pub fn isobar(
+        eos: &Arc<E>,
+        pressure: SINumber,
+        temperature: &SIArray1,
+        molefracs: &Array1<f64>,
+        options: SolverOptions,
+    ) -> EosResult<Self> {
+        let mut streams = temperature
+            .into_iter()
+            .map(|t| Stream::new_tp(eos, t, pressure, molefracs, options))
+            .collect::<EosResult<Vec<_>>>()?;
+        if eos.components() == 1 {
+            if let Ok(vle) = PhaseEquilibrium::pure(eos, pressure, None, options) {
+                let t = temperature.to_reduced(SIUnit::reference_temperature())?;
+                let t_sat = vle
+                    .vapor()
+                    .temperature
+                    .to_reduced(SIUnit::reference_temperature())?;
+                insert_saturation(&mut streams, &vle, &t, t_sat, true);
+            }
+        }
+        Ok(Self { streams })
+    }
+
+    /// Calculate streams at constant temperature for the given pressures.
+    pub fn isotherm(
+        eos: &Arc<E>,
+        temperature: SINumber,
+        pressure: &SIArray1,
+        molefracs: &Array1<f64>,
+        options: SolverOptions,
+    ) -> EosResult<Self> {
+        let mut streams = pressure
+            .into_iter()
+            .map(|p| Stream::new_tp(eos, temperature, p, molefracs, options))
+            .collect::<EosResult<Vec<_>>>()?;
+        if eos.components() == 1 {
+            if let Ok(vle) = PhaseEquilibrium::pure(eos, temperature, None, options) {
+                let p = pressure.to_reduced(SIUnit::reference_pressure())?;
+                let p_sat = vle
+                    .vapor()
+                    .pressure(Contributions::Total)
+                    .to_reduced(SIUnit::reference_pressure())?;
+                insert_saturation(&mut streams, &vle, &p, p_sat, false);
+            }
+        }
+        Ok(Self { streams })
+    }
+
+    /// Return the temperatures of the streams.
+    pub fn temperature(&self) -> SIArray1 {
+        SIArray1::from_shape_fn(self.streams.len(), |i| self.streams[i].temperature)
+    }
+
+    /// Return the pressures of the streams.
+    pub fn pressure(&self) -> SIArray1 {
+        SIArray1::from_shape_fn(self.streams.len(), |i| self.streams[i].pressure)
+    }
+
+    /// Return the molar volumes of the streams.
+    pub fn molar_volume(&self) -> SIArray1 {
+        SIArray1::from_shape_fn(self.streams.len(), |i| self.streams[i].molar_volume())
+    }
+
+    /// Return the molar enthalpies of the streams.
+    pub fn molar_enthalpy(&self) -> SIArray1 {
+        SIArray1::from_shape_fn(self.streams.len(), |i| self.streams[i].molar_enthalpy())
+    }
+
+    /// Return the molar entropies of the streams.
+    pub fn molar_entropy(&self) -> SIArray1 {
+        SIArray1::from_shape_fn(self.streams.len(), |i| self.streams[i].molar_entropy())
+    }
+
+    /// Return the vapor fractions of the streams (`NaN` for single-phase streams).
+    pub fn vapor_fraction(&self) -> Array1<f64> {
+        self.streams
+            .iter()
+            .map(|s| s.vapor_fraction().unwrap_or(f64::NAN))
+            .collect()
+    }
+}
+
+/// Insert the saturated phases at the position at which the
+/// line of (reduced) values `x` crosses the saturation point `x_sat`.
+///
+/// Along isobars, the liquid is located at lower temperatures, along
+/// isotherms, the liquid is located at higher pressures.
+fn insert_saturation<E: EquationOfState>(
+    streams: &mut Vec<Stream<E>>,
+    vle: &PhaseEquilibrium<E, 2>,
+    x: &Array1<f64>,
+    x_sat: f64,
+    liquid_at_lower_values: bool,
+) {
+    let position = x
+        .windows(2)
+        .into_iter()
+        .position(|w| (w[0] - x_sat) * (w[1] - x_sat) < 0.0);
+    if let Some(i) = position {
+        let liquid = Stream::from_phase_equilibrium(vle, &Array1::ones(1), 0.0);
+        let vapor = Stream::from_phase_equilibrium(vle, &Array1::ones(1), 1.0);
+        let ascending = x[i + 1] > x[i];
+        let (first, second) = if ascending == liquid_at_lower_values {
+            (liquid, vapor)
+        } else {
+            (vapor, liquid)
+        };
+        streams.insert(i + 1, second);
+        streams.insert(i + 1, first);
+    }
+}
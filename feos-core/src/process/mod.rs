@@ -0,0 +1,445 @@
+//! Material streams and simple process units.
+use crate::equation_of_state::EquationOfState;
+use crate::errors::{EosError, EosResult};
+use crate::phase_equilibria::{PhaseEquilibrium, SolverOptions, Verbosity};
+use crate::state::{Contributions, DensityInitialization, State};
+use crate::EosUnit;
+use ndarray::Array1;
+use quantity::si::{SIArray1, SINumber, SIUnit};
+use std::fmt;
+use std::sync::Arc;
+
+mod isolines;
+mod units;
+pub use isolines::Isoline;
+pub use units::Efficiency;
+
+const MAX_ITER_FLASH: usize = 50;
+const TOL_FLASH: f64 = 1e-8;
+const MAX_STEP_FLASH: f64 = 50.0;
+
+/// A material stream in thermodynamic equilibrium.
+///
+/// The stream consists of one or two phases with the overall composition
+/// `molefracs`. All properties, works and duties are given per amount of
+/// substance of the stream, i.e., multiplying them with a molar flow rate
+/// yields the corresponding flows.
+pub struct Stream<E> {
+    pub eos: Arc<E>,
+    pub temperature: SINumber,
+    pub pressure: SINumber,
+    pub molefracs: Array1<f64>,
+    phases: Vec<State<E>>,
+    phase_fractions: Vec<f64>,
+}
+
+impl<E> Clone for Stream<E> {
+    fn clone(&self) -> Self {
+        Self {
+            eos: self.eos.clone(),
+            temperature: self.temperature,
+            pressure: self.pressure,
+            molefracs: self.molefracs.clone(),
+            phases: self.phases.clone(),
+            phase_fractions: self.phase_fractions.clone(),
+        }
+    }
+}
+
+/// The specification of a flash at constant pressure.
+#[derive(Clone, Copy)]
+enum Specification {
+    Enthalpy(SINumber),
+    Entropy(SINumber),
+}
+
+impl Specification {
+    /// The value of the specified property of a single phase.
+    fn value<E: EquationOfState>(&self, state: &State<E>) -> SINumber {
+        match self {
+            Self::Enthalpy(_) => state.molar_enthalpy(Contributions::Total),
+            Self::Entropy(_) => state.molar_entropy(Contributions::Total),
+        }
+    }
+
+    /// The reduced deviation of the stream from the specification.
+    fn residual<E: EquationOfState>(&self, stream: &Stream<E>) -> EosResult<f64> {
+        Ok(match *self {
+            Self::Enthalpy(h) => {
+                (stream.molar_enthalpy() - h).to_reduced(SIUnit::reference_molar_energy())?
+            }
+            Self::Entropy(s) => {
+                (stream.molar_entropy() - s).to_reduced(SIUnit::reference_molar_entropy())?
+            }
+        })
+    }
+
+    /// The reduced (approximate) temperature derivative of the residual.
+    fn derivative<E: EquationOfState>(&self, stream: &Stream<E>) -> EosResult<f64> {
+        let c_p = stream
+            .phases
+            .iter()
+            .zip(&stream.phase_fractions)
+            .map(|(s, &beta)| beta * s.c_p(Contributions::Total))
+            .fold(0.0 * SIUnit::reference_molar_entropy(), |acc, c_p| {
+                acc + c_p
+            });
+        Ok(match self {
+            Self::Enthalpy(_) => c_p,
+            Self::Entropy(_) => c_p / stream.temperature * SIUnit::reference_temperature(),
+        }
+        .to_reduced(SIUnit::reference_molar_entropy())?)
+    }
+
+    /// Solve the specification for a single phase.
+    fn single_phase<E: EquationOfState>(
+        &self,
+        eos: &Arc<E>,
+        pressure: SINumber,
+        moles: &SIArray1,
+        density_initialization: DensityInitialization,
+        initial_temperature: Option<SINumber>,
+    ) -> EosResult<State<E>> {
+        match *self {
+            Self::Enthalpy(h) => State::new_nph(
+                eos,
+                pressure,
+                h,
+                moles,
+                density_initialization,
+                initial_temperature,
+            ),
+            Self::Entropy(s) => State::new_nps(
+                eos,
+                pressure,
+                s,
+                moles,
+                density_initialization,
+                initial_temperature,
+            ),
+        }
+    }
+
+    fn spec(&self) -> SINumber {
+        match *self {
+            Self::Enthalpy(h) => h,
+            Self::Entropy(s) => s,
+        }
+    }
+}
+
+impl<E: EquationOfState> Stream<E> {
+    /// Create a single-phase stream from a state.
+    pub fn from_state(state: State<E>) -> Self {
+        Self {
+            eos: state.eos.clone(),
+            temperature: state.temperature,
+            pressure: state.pressure(Contributions::Total),
+            molefracs: state.molefracs.clone(),
+            phases: vec![state],
+            phase_fractions: vec![1.0],
+        }
+    }
+
+    /// Create a two-phase stream from a phase equilibrium and the
+    /// molar fraction of the vapor phase.
+    fn from_phase_equilibrium(
+        vle: &PhaseEquilibrium<E, 2>,
+        molefracs: &Array1<f64>,
+        vapor_fraction: f64,
+    ) -> Self {
+        let vapor = vle.vapor();
+        Self {
+            eos: vapor.eos.clone(),
+            temperature: vapor.temperature,
+            pressure: vapor.pressure(Contributions::Total),
+            molefracs: molefracs.clone(),
+            phases: vec![vapor.clone(), vle.liquid().clone()],
+            phase_fractions: vec![vapor_fraction, 1.0 - vapor_fraction],
+        }
+    }
+
+    /// The phases of the stream.
+    pub fn phases(&self) -> &[State<E>] {
+        &self.phases
+    }
+
+    /// The molar fractions of the phases of the stream.
+    pub fn phase_fractions(&self) -> &[f64] {
+        &self.phase_fractions
+    }
+
+    /// The molar vapor fraction of a two-phase stream, `None` for single-phase streams.
+    pub fn vapor_fraction(&self) -> Option<f64> {
+        if self.phases.len() == 2 {
+            Some(self.phase_fractions[0])
+        } else {
+            None
+        }
+    }
+
+    /// Molar enthalpy of the stream: $h=\sum_\alpha\beta^\alpha h^\alpha$
+    pub fn molar_enthalpy(&self) -> SINumber {
+        self.phases
+            .iter()
+            .zip(&self.phase_fractions)
+            .map(|(s, &beta)| beta * s.molar_enthalpy(Contributions::Total))
+            .fold(0.0 * SIUnit::reference_molar_energy(), |acc, h| acc + h)
+    }
+
+    /// Molar entropy of the stream: $s=\sum_\alpha\beta^\alpha s^\alpha$
+    pub fn molar_entropy(&self) -> SINumber {
+        self.phases
+            .iter()
+            .zip(&self.phase_fractions)
+            .map(|(s, &beta)| beta * s.molar_entropy(Contributions::Total))
+            .fold(0.0 * SIUnit::reference_molar_entropy(), |acc, s| acc + s)
+    }
+
+    /// Molar volume of the stream: $v=\sum_\alpha\frac{\beta^\alpha}{\rho^\alpha}$
+    pub fn molar_volume(&self) -> SINumber {
+        self.phases
+            .iter()
+            .zip(&self.phase_fractions)
+            .map(|(s, &beta)| beta / s.density)
+            .fold(0.0 / SIUnit::reference_density(), |acc, v| acc + v)
+    }
+
+    /// Create a stream for given temperature, pressure and composition.
+    ///
+    /// For mixtures, the phase split is determined using a Tp-flash
+    /// that is initialized with a stability analysis.
+    pub fn new_tp(
+        eos: &Arc<E>,
+        temperature: SINumber,
+        pressure: SINumber,
+        molefracs: &Array1<f64>,
+        options: SolverOptions,
+    ) -> EosResult<Self> {
+        let moles = molefracs * SIUnit::reference_moles();
+        let state = State::new_npt(
+            eos,
+            temperature,
+            pressure,
+            &moles,
+            DensityInitialization::None,
+        )?;
+        if eos.components() == 1 {
+            return Ok(Self::from_state(state));
+        }
+        match state.tp_flash(None, options, None) {
+            Ok(vle) => {
+                let vapor_fraction = (vle.vapor().total_moles
+                    / (vle.vapor().total_moles + vle.liquid().total_moles))
+                    .into_value()?;
+                Ok(Self::from_phase_equilibrium(
+                    &vle,
+                    &state.molefracs,
+                    vapor_fraction,
+                ))
+            }
+            Err(EosError::NoPhaseSplit) | Err(EosError::TrivialSolution) => {
+                Ok(Self::from_state(state))
+            }
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Create a stream for given pressure, molar enthalpy and composition.
+    pub fn new_ph(
+        eos: &Arc<E>,
+        pressure: SINumber,
+        molar_enthalpy: SINumber,
+        molefracs: &Array1<f64>,
+        initial_temperature: Option<SINumber>,
+        options: SolverOptions,
+    ) -> EosResult<Self> {
+        Self::new_p_spec(
+            eos,
+            pressure,
+            Specification::Enthalpy(molar_enthalpy),
+            molefracs,
+            initial_temperature,
+            options,
+        )
+    }
+
+    /// Create a stream for given pressure, molar entropy and composition.
+    pub fn new_ps(
+        eos: &Arc<E>,
+        pressure: SINumber,
+        molar_entropy: SINumber,
+        molefracs: &Array1<f64>,
+        initial_temperature: Option<SINumber>,
+        options: SolverOptions,
+    ) -> EosResult<Self> {
+        Self::new_p_spec(
+            eos,
+            pressure,
+            Specification::Entropy(molar_entropy),
+            molefracs,
+            initial_temperature,
+            options,
+        )
+    }
+
+    fn new_p_spec(
+        eos: &Arc<E>,
+        pressure: SINumber,
+        spec: Specification,
+        molefracs: &Array1<f64>,
+        initial_temperature: Option<SINumber>,
+        options: SolverOptions,
+    ) -> EosResult<Self> {
+        if eos.components() == 1 {
+            Self::new_p_spec_pure(eos, pressure, spec, initial_temperature, options)
+        } else {
+            Self::new_p_spec_mixture(eos, pressure, spec, molefracs, initial_temperature, options)
+        }
+    }
+
+    /// For pure components, the specified property is compared to the
+    /// saturated phases to determine the phase of the stream.
+    fn new_p_spec_pure(
+        eos: &Arc<E>,
+        pressure: SINumber,
+        spec: Specification,
+        initial_temperature: Option<SINumber>,
+        options: SolverOptions,
+    ) -> EosResult<Self> {
+        let moles = Array1::ones(1) * SIUnit::reference_moles();
+        let state = match PhaseEquilibrium::pure(eos, pressure, None, options) {
+            Ok(vle) => {
+                let value_v = spec.value(vle.vapor());
+                let value_l = spec.value(vle.liquid());
+                let t_sat = Some(vle.vapor().temperature);
+                if spec.spec() < value_l {
+                    spec.single_phase(eos, pressure, &moles, DensityInitialization::Liquid, t_sat)?
+                } else if spec.spec() > value_v {
+                    spec.single_phase(eos, pressure, &moles, DensityInitialization::Vapor, t_sat)?
+                } else {
+                    let vapor_fraction =
+                        ((spec.spec() - value_l) / (value_v - value_l)).into_value()?;
+                    return Ok(Self::from_phase_equilibrium(
+                        &vle,
+                        &Array1::ones(1),
+                        vapor_fraction,
+                    ));
+                }
+            }
+            // supercritical pressure
+            Err(_) => spec.single_phase(
+                eos,
+                pressure,
+                &moles,
+                DensityInitialization::None,
+                initial_temperature,
+            )?,
+        };
+        Ok(Self::from_state(state))
+    }
+
+    /// For mixtures, the temperature is determined with a bracketed
+    /// secant method (Illinois algorithm) using Tp-flash calculations.
+    fn new_p_spec_mixture(
+        eos: &Arc<E>,
+        pressure: SINumber,
+        spec: Specification,
+        molefracs: &Array1<f64>,
+        initial_temperature: Option<SINumber>,
+        options: SolverOptions,
+    ) -> EosResult<Self> {
+        let (max_iter, tol, verbosity) = options.unwrap_or(MAX_ITER_FLASH, TOL_FLASH);
+        let t_ref = SIUnit::reference_temperature();
+        let flash_options = SolverOptions::default();
+        let f = |t: f64| -> EosResult<(f64, Self)> {
+            let stream = Self::new_tp(eos, t * t_ref, pressure, molefracs, flash_options)?;
+            Ok((spec.residual(&stream)?, stream))
+        };
+
+        log_iter!(verbosity, " iter |  temperature  |    residual    ");
+        log_iter!(verbosity, "{:-<36}", "");
+
+        // bracket the solution
+        let mut t_a = initial_temperature
+            .unwrap_or(298.15 * t_ref)
+            .to_reduced(t_ref)?;
+        let (mut f_a, stream) = f(t_a)?;
+        log_iter!(verbosity, " {:4} | {:13.8} | {:14.8e}", 0, t_a, f_a);
+        if f_a.abs() < tol {
+            return Ok(stream);
+        }
+        let mut dt = (f_a / spec.derivative(&stream)?).abs().min(MAX_STEP_FLASH);
+        let mut t_b = t_a - f_a.signum() * dt;
+        let mut iter = 0;
+        let (mut f_b, mut stream) = loop {
+            iter += 1;
+            if iter > max_iter {
+                return Err(EosError::NotConverged(String::from("Stream::new_ph/ps")));
+            }
+            if t_b <= 0.0 {
+                t_b = 0.5 * t_a;
+            }
+            let (f_b, stream) = f(t_b)?;
+            log_iter!(verbosity, " {:4} | {:13.8} | {:14.8e}", iter, t_b, f_b);
+            if f_b.abs() < tol {
+                return Ok(stream);
+            }
+            if f_b.signum() != f_a.signum() {
+                break (f_b, stream);
+            }
+            t_a = t_b;
+            f_a = f_b;
+            dt *= 2.0;
+            t_b = t_a - f_a.signum() * dt;
+        };
+
+        // Illinois algorithm
+        while iter < max_iter {
+            iter += 1;
+            let t_c = t_b - f_b * (t_b - t_a) / (f_b - f_a);
+            let (f_c, stream_c) = f(t_c)?;
+            log_iter!(verbosity, " {:4} | {:13.8} | {:14.8e}", iter, t_c, f_c);
+            if f_c.abs() < tol || (t_c - t_b).abs() < tol * t_c {
+                log_result!(
+                    verbosity,
+                    "Stream flash: calculation converged in {} step(s)\n",
+                    iter
+                );
+                return Ok(stream_c);
+            }
+            if f_c.signum() == f_b.signum() {
+                f_a *= 0.5;
+            } else {
+                t_a = t_b;
+                f_a = f_b;
+            }
+            t_b = t_c;
+            f_b = f_c;
+            stream = stream_c;
+        }
+        log_result!(
+            verbosity,
+            "Stream flash: not converged, last temperature {}",
+            stream.temperature
+        );
+        Err(EosError::NotConverged(String::from("Stream::new_ph/ps")))
+    }
+}
+
+impl<E> fmt::Display for Stream<E>
+where
+    SINumber: fmt::Display,
+    E: EquationOfState,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "T = {:.5}, p = {:.5}", self.temperature, self.pressure)?;
+        if self.eos.components() > 1 {
+            write!(f, ", x = {:.5}", self.molefracs)?;
+        }
+        if let Some(vapor_fraction) = self.vapor_fraction() {
+            write!(f, ", vapor fraction = {:.5}", vapor_fraction)?;
+        }
+        Ok(())
+    }
+}
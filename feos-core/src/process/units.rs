@@ -0,0 +1,155 @@
+use super::Stream;
+use crate::equation_of_state::EquationOfState;
+use crate::errors::{EosError, EosResult};
+use crate::phase_equilibria::SolverOptions;
+use quantity::si::SINumber;
+
+/// Number of stages used to approximate a polytropic change of state.
+const POLYTROPIC_STAGES: usize = 20;
+
+/// Efficiency of compressors and turbines.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Efficiency {
+    /// Isentropic efficiency of the whole unit.
+    Isentropic(f64),
+    /// Polytropic efficiency, i.e., the isentropic efficiency of
+    /// infinitesimal compression or expansion steps.
+    Polytropic(f64),
+}
+
+/// # Process units
+impl<E: EquationOfState> Stream<E> {
+    /// Compress the stream to the given pressure.
+    ///
+    /// Returns the outlet stream and the molar work $w=h^\mathrm{out}-h^\mathrm{in}$
+    /// that is required for the compression.
+    pub fn compressor(
+        &self,
+        pressure: SINumber,
+        efficiency: Efficiency,
+        options: SolverOptions,
+    ) -> EosResult<(Self, SINumber)> {
+        if pressure < self.pressure {
+            return Err(EosError::Error(String::from(
+                "The outlet pressure of a compressor has to be larger than the inlet pressure.",
+            )));
+        }
+        self.change_pressure(pressure, efficiency, true, options)
+    }
+
+    /// Expand the stream in a turbine to the given pressure.
+    ///
+    /// Returns the outlet stream and the molar work $w=h^\mathrm{out}-h^\mathrm{in}$
+    /// which is negative for the work produced by the turbine.
+    pub fn turbine(
+        &self,
+        pressure: SINumber,
+        efficiency: Efficiency,
+        options: SolverOptions,
+    ) -> EosResult<(Self, SINumber)> {
+        if pressure > self.pressure {
+            return Err(EosError::Error(String::from(
+                "The outlet pressure of a turbine has to be smaller than the inlet pressure.",
+            )));
+        }
+        self.change_pressure(pressure, efficiency, false, options)
+    }
+
+    fn change_pressure(
+        &self,
+        pressure: SINumber,
+        efficiency: Efficiency,
+        compression: bool,
+        options: SolverOptions,
+    ) -> EosResult<(Self, SINumber)> {
+        let (eta, stages) = match efficiency {
+            Efficiency::Isentropic(eta) => (eta, 1),
+            Efficiency::Polytropic(eta) => (eta, POLYTROPIC_STAGES),
+        };
+        if !(eta > 0.0 && eta <= 1.0) {
+            return Err(EosError::Error(format!(
+                "The efficiency has to be in the interval (0, 1], got {}.",
+                eta
+            )));
+        }
+        let ratio = (pressure / self.pressure).into_value()?;
+        let mut stream = self.clone();
+        for k in 1..=stages {
+            let p = self.pressure * ratio.powf(k as f64 / stages as f64);
+            let h_in = stream.molar_enthalpy();
+            let isentropic = Self::new_ps(
+                &self.eos,
+                p,
+                stream.molar_entropy(),
+                &self.molefracs,
+                Some(stream.temperature),
+                options,
+            )?;
+            stream = if eta == 1.0 {
+                isentropic
+            } else {
+                let dh_s = isentropic.molar_enthalpy() - h_in;
+                let dh = if compression { dh_s / eta } else { dh_s * eta };
+                Self::new_ph(
+                    &self.eos,
+                    p,
+                    h_in + dh,
+                    &self.molefracs,
+                    Some(isentropic.temperature),
+                    options,
+                )?
+            };
+        }
+        let work = stream.molar_enthalpy() - self.molar_enthalpy();
+        Ok((stream, work))
+    }
+
+    /// Throttle the stream in an isenthalpic valve to the given pressure.
+    pub fn valve(&self, pressure: SINumber, options: SolverOptions) -> EosResult<Self> {
+        if pressure > self.pressure {
+            return Err(EosError::Error(String::from(
+                "The outlet pressure of a valve has to be smaller than the inlet pressure.",
+            )));
+        }
+        Self::new_ph(
+            &self.eos,
+            pressure,
+            self.molar_enthalpy(),
+            &self.molefracs,
+            Some(self.temperature),
+            options,
+        )
+    }
+
+    /// Heat or cool the stream at constant pressure to the given temperature.
+    ///
+    /// Returns the outlet stream and the molar duty $q=h^\mathrm{out}-h^\mathrm{in}$
+    /// which is negative for coolers.
+    pub fn heater(
+        &self,
+        temperature: SINumber,
+        options: SolverOptions,
+    ) -> EosResult<(Self, SINumber)> {
+        let stream = Self::new_tp(
+            &self.eos,
+            temperature,
+            self.pressure,
+            &self.molefracs,
+            options,
+        )?;
+        let duty = stream.molar_enthalpy() - self.molar_enthalpy();
+        Ok((stream, duty))
+    }
+
+    /// Heat or cool the stream at constant pressure with the given molar duty.
+    pub fn heater_duty(&self, molar_duty: SINumber, options: SolverOptions) -> EosResult<Self> {
+        Self::new_ph(
+            &self.eos,
+            self.pressure,
+            self.molar_enthalpy() + molar_duty,
+            &self.molefracs,
+            Some(self.temperature),
+            options,
+        )
+    }
+}
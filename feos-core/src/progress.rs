@@ -0,0 +1,74 @@
+use crate::errors::{EosError, EosResult};
+use std::fmt;
+
+/// Progress of a long-running calculation that is passed to a [ProgressCallback].
+#[derive(Clone, Copy, Debug)]
+pub struct Progress<'a> {
+    /// The algorithm or calculation that reports its progress.
+    pub task: &'a str,
+    /// The current iteration or state point.
+    pub iteration: usize,
+    /// The current residual (or cost) or `NaN` if not available.
+    pub residual: f64,
+}
+
+impl<'a> fmt::Display for Progress<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{}: iteration {}, residual {:e}",
+            self.task, self.iteration, self.residual
+        )
+    }
+}
+
+/// Callback that is invoked by long-running calculations, like DFT solvers,
+/// adsorption isotherms, phase diagrams or parameter regressions.
+///
+/// Returning `false` cancels the calculation cooperatively, which then
+/// returns [EosError::Cancelled] at the next opportunity.
+pub trait ProgressCallback: Send + Sync {
+    fn report(&self, progress: Progress) -> bool;
+
+    /// Report the progress and return [EosError::Cancelled] if the
+    /// calculation is to be cancelled.
+    fn check(&self, task: &str, iteration: usize, residual: f64) -> EosResult<()> {
+        let progress = Progress {
+            task,
+            iteration,
+            residual,
+        };
+        if self.report(progress) {
+            Ok(())
+        } else {
+            Err(EosError::Cancelled(task.into()))
+        }
+    }
+}
+
+impl<F: Fn(Progress) -> bool + Send + Sync> ProgressCallback for F {
+    fn report(&self, progress: Progress) -> bool {
+        self(progress)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    #[test]
+    fn cancel() {
+        let calls = AtomicUsize::new(0);
+        let callback = |p: Progress| {
+            calls.fetch_add(1, Ordering::SeqCst);
+            p.iteration < 3
+        };
+        assert!(callback.check("test", 2, 1e-3).is_ok());
+        assert!(matches!(
+            callback.check("test", 3, 1e-4),
+            Err(EosError::Cancelled(_))
+        ));
+        assert_eq!(calls.load(Ordering::SeqCst), 2);
+    }
+}
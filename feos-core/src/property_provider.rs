@@ -0,0 +1,263 @@
+//! Interface for the evaluation of fluid properties by external codes.
+use crate::equation_of_state::{EntropyScaling, EquationOfState, MolarWeight};
+use crate::errors::{EosError, EosResult};
+use crate::state::{Contributions, State, StateBuilder};
+use ndarray::{Array1, Array2};
+use quantity::si::{SIArray1, SINumber};
+#[cfg(feature = "rayon")]
+use rayon::prelude::*;
+use std::sync::Arc;
+
+/// Thermodynamic properties of a fluid that are required in flow simulations.
+#[derive(Clone, Copy, Debug)]
+pub struct FluidProperties {
+    /// Mass density
+    pub density: SINumber,
+    /// Specific enthalpy
+    pub enthalpy: SINumber,
+    /// Specific isobaric heat capacity
+    pub c_p: SINumber,
+}
+
+/// Minimal interface for the evaluation of fluid properties as functions of
+/// temperature, pressure and composition.
+///
+/// The trait is intended as a stable coupling surface for external codes, e.g.,
+/// CFD or process simulation tools, that embed `feos` as a library. It is
+/// implemented for every equation of state (wrapped in an [Arc]) that provides
+/// molar weights. Transport properties are provided by the [TransportPropertyProvider]
+/// trait for equations of state that additionally implement entropy scaling.
+///
+/// All properties are evaluated on a mass basis for a homogeneous phase with the
+/// given composition. If a vapor and a liquid density exist at the given conditions,
+/// the phase with the lower Gibbs energy is used. No phase stability analysis is
+/// performed, i.e., within the two-phase region of a mixture, the properties of
+/// a metastable or unstable homogeneous phase are returned.
+///
+/// Implementors have to be [Send] and [Sync], so that a single provider can be
+/// shared by all threads of the calling code.
+pub trait PropertyProvider: Send + Sync {
+    /// Mass density: $\rho^{(m)}$
+    fn density(
+        &self,
+        temperature: SINumber,
+        pressure: SINumber,
+        molefracs: &Array1<f64>,
+    ) -> EosResult<SINumber>;
+
+    /// Specific enthalpy: $h^{(m)}$
+    fn enthalpy(
+        &self,
+        temperature: SINumber,
+        pressure: SINumber,
+        molefracs: &Array1<f64>,
+    ) -> EosResult<SINumber>;
+
+    /// Specific isobaric heat capacity: $c_p^{(m)}$
+    fn c_p(
+        &self,
+        temperature: SINumber,
+        pressure: SINumber,
+        molefracs: &Array1<f64>,
+    ) -> EosResult<SINumber>;
+
+    /// Evaluate all properties at once.
+    fn properties(
+        &self,
+        temperature: SINumber,
+        pressure: SINumber,
+        molefracs: &Array1<f64>,
+    ) -> EosResult<FluidProperties> {
+        Ok(FluidProperties {
+            density: self.density(temperature, pressure, molefracs)?,
+            enthalpy: self.enthalpy(temperature, pressure, molefracs)?,
+            c_p: self.c_p(temperature, pressure, molefracs)?,
+        })
+    }
+
+    /// Evaluate all properties for a batch of state points.
+    ///
+    /// Every row of `molefracs` contains the composition of one state point.
+    /// Errors in individual state points are returned in the corresponding
+    /// entry of the result. If the `rayon` feature is enabled, the state points
+    /// are evaluated in parallel.
+    fn properties_batch(
+        &self,
+        temperature: &SIArray1,
+        pressure: &SIArray1,
+        molefracs: &Array2<f64>,
+    ) -> EosResult<Vec<EosResult<FluidProperties>>> {
+        batch(temperature, pressure, molefracs, |t, p, x| {
+            self.properties(t, p, x)
+        })
+    }
+}
+
+/// Interface for the evaluation of transport properties as functions of
+/// temperature, pressure and composition.
+///
+/// The trait is implemented for every equation of state (wrapped in an [Arc])
+/// that provides molar weights and entropy scaling. The properties are evaluated
+/// for the same homogeneous phase as the properties of the [PropertyProvider].
+pub trait TransportPropertyProvider: PropertyProvider {
+    /// Viscosity: $\eta$
+    fn viscosity(
+        &self,
+        temperature: SINumber,
+        pressure: SINumber,
+        molefracs: &Array1<f64>,
+    ) -> EosResult<SINumber>;
+
+    /// Evaluate the viscosity for a batch of state points.
+    ///
+    /// See [PropertyProvider::properties_batch].
+    fn viscosity_batch(
+        &self,
+        temperature: &SIArray1,
+        pressure: &SIArray1,
+        molefracs: &Array2<f64>,
+    ) -> EosResult<Vec<EosResult<SINumber>>> {
+        batch(temperature, pressure, molefracs, |t, p, x| {
+            self.viscosity(t, p, x)
+        })
+    }
+}
+
+/// Evaluate a property for a batch of state points (in parallel if the
+/// `rayon` feature is enabled).
+fn batch<T: Send, F>(
+    temperature: &SIArray1,
+    pressure: &SIArray1,
+    molefracs: &Array2<f64>,
+    property: F,
+) -> EosResult<Vec<EosResult<T>>>
+where
+    F: Fn(SINumber, SINumber, &Array1<f64>) -> EosResult<T> + Sync,
+{
+    let n = temperature.len();
+    if pressure.len() != n || molefracs.nrows() != n {
+        return Err(EosError::Error(format!(
+            "Inconsistent number of state points: {} temperatures, {} pressures and {} compositions.",
+            n,
+            pressure.len(),
+            molefracs.nrows()
+        )));
+    }
+    let evaluate = |i: usize| {
+        property(
+            temperature.get(i),
+            pressure.get(i),
+            &molefracs.row(i).to_owned(),
+        )
+    };
+    #[cfg(feature = "rayon")]
+    let properties = (0..n).into_par_iter().map(evaluate).collect();
+    #[cfg(not(feature = "rayon"))]
+    let properties = (0..n).map(evaluate).collect();
+    Ok(properties)
+}
+
+/// Return the homogeneous state at the given conditions.
+fn homogeneous_state<E: EquationOfState>(
+    eos: &Arc<E>,
+    temperature: SINumber,
+    pressure: SINumber,
+    molefracs: &Array1<f64>,
+) -> EosResult<State<E>> {
+    if molefracs.len() != eos.components() {
+        return Err(EosError::IncompatibleComponents(
+            eos.components(),
+            molefracs.len(),
+        ));
+    }
+    StateBuilder::new(eos)
+        .temperature(temperature)
+        .pressure(pressure)
+        .molefracs(molefracs)
+        .build()
+}
+
+impl<E: EquationOfState + MolarWeight> PropertyProvider for Arc<E> {
+    fn density(
+        &self,
+        temperature: SINumber,
+        pressure: SINumber,
+        molefracs: &Array1<f64>,
+    ) -> EosResult<SINumber> {
+        Ok(homogeneous_state(self, temperature, pressure, molefracs)?.mass_density())
+    }
+
+    fn enthalpy(
+        &self,
+        temperature: SINumber,
+        pressure: SINumber,
+        molefracs: &Array1<f64>,
+    ) -> EosResult<SINumber> {
+        Ok(homogeneous_state(self, temperature, pressure, molefracs)?
+            .specific_enthalpy(Contributions::Total))
+    }
+
+    fn c_p(
+        &self,
+        temperature: SINumber,
+        pressure: SINumber,
+        molefracs: &Array1<f64>,
+    ) -> EosResult<SINumber> {
+        let state = homogeneous_state(self, temperature, pressure, molefracs)?;
+        Ok(state.c_p(Contributions::Total) / state.total_molar_weight())
+    }
+
+    fn properties(
+        &self,
+        temperature: SINumber,
+        pressure: SINumber,
+        molefracs: &Array1<f64>,
+    ) -> EosResult<FluidProperties> {
+        let state = homogeneous_state(self, temperature, pressure, molefracs)?;
+        Ok(FluidProperties {
+            density: state.mass_density(),
+            enthalpy: state.specific_enthalpy(Contributions::Total),
+            c_p: state.c_p(Contributions::Total) / state.total_molar_weight(),
+        })
+    }
+}
+
+impl<E: EquationOfState + MolarWeight + EntropyScaling> TransportPropertyProvider for Arc<E> {
+    fn viscosity(
+        &self,
+        temperature: SINumber,
+        pressure: SINumber,
+        molefracs: &Array1<f64>,
+    ) -> EosResult<SINumber> {
+        homogeneous_state(self, temperature, pressure, molefracs)?.viscosity()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::cubic::{PengRobinson, PengRobinsonParameters};
+    use approx::assert_relative_eq;
+    use ndarray::arr1;
+    use quantity::si::{BAR, KELVIN};
+
+    #[test]
+    fn property_provider_without_entropy_scaling() -> EosResult<()> {
+        let parameters =
+            PengRobinsonParameters::new_simple(&[369.96], &[4250000.0], &[0.153], &[44.0962])?;
+        let eos = Arc::new(PengRobinson::new(Arc::new(parameters)));
+        let (t, p, x) = (300.0 * KELVIN, BAR, arr1(&[1.0]));
+        let state = StateBuilder::new(&eos)
+            .temperature(t)
+            .pressure(p)
+            .molefracs(&x)
+            .build()?;
+        let properties = eos.properties(t, p, &x)?;
+        assert_relative_eq!(properties.density, state.mass_density());
+        assert_relative_eq!(
+            properties.enthalpy,
+            state.specific_enthalpy(Contributions::Total)
+        );
+        Ok(())
+    }
+}
@@ -0,0 +1,210 @@
+use crate::phase_equilibria::ChemicalReactions;
+use numpy::{PyArray2, PyReadonlyArray2, ToPyArray};
+use pyo3::prelude::*;
+use quantity::python::PySIArray1;
+
+/// A set of chemical reactions between the components of a system.
+///
+/// The standard chemical potentials of all components are determined
+/// from their ideal gas enthalpies and Gibbs energies of formation at
+/// 298.15 K and 1 bar and the ideal gas heat capacities of the
+/// equation of state.
+///
+/// Parameters
+/// ----------
+/// stoichiometry : numpy.ndarray[float]
+///     The stoichiometric coefficients of all reactions (rows) and
+///     components (columns). Negative for reactants and positive for products.
+/// enthalpy_of_formation : SIArray1
+///     The ideal gas enthalpies of formation of all components at 298.15 K.
+/// gibbs_energy_of_formation : SIArray1
+///     The ideal gas Gibbs energies of formation of all components at
+///     298.15 K and 1 bar.
+///
+/// Returns
+/// -------
+/// ChemicalReactions
+#[pyclass(name = "ChemicalReactions")]
+#[derive(Clone)]
+#[pyo3(text_signature = "(stoichiometry, enthalpy_of_formation, gibbs_energy_of_formation)")]
+pub struct PyChemicalReactions(pub ChemicalReactions);
+
+#[pymethods]
+impl PyChemicalReactions {
+    #[new]
+    fn new(
+        stoichiometry: PyReadonlyArray2<f64>,
+        enthalpy_of_formation: PySIArray1,
+        gibbs_energy_of_formation: PySIArray1,
+    ) -> PyResult<Self> {
+        Ok(Self(ChemicalReactions::new(
+            stoichiometry.as_array().to_owned(),
+            enthalpy_of_formation.into(),
+            gibbs_energy_of_formation.into(),
+        )?))
+    }
+
+    #[getter]
+    fn get_stoichiometry<'py>(&self, py: Python<'py>) -> &'py PyArray2<f64> {
+        self.0.stoichiometry.to_pyarray(py)
+    }
+
+    #[getter]
+    fn get_enthalpy_of_formation(&self) -> PySIArray1 {
+        self.0.enthalpy_of_formation.clone().into()
+    }
+
+    #[getter]
+    fn get_gibbs_energy_of_formation(&self) -> PySIArray1 {
+        self.0.gibbs_energy_of_formation.clone().into()
+    }
+}
+
+#[macro_export]
+macro_rules! impl_chemical_equilibrium {
+    ($eos:ty, $py_eos:ty) => {
+        /// Simultaneous chemical and phase equilibrium of a reacting system.
+        #[pyclass(name = "ChemicalEquilibrium")]
+        #[derive(Clone)]
+        pub struct PyChemicalEquilibrium(pub ChemicalEquilibrium<$eos>);
+
+        #[pymethods]
+        impl PyChemicalEquilibrium {
+            /// Calculate the chemical equilibrium at given temperature and pressure
+            /// by minimizing the Gibbs energy with respect to the extents of reaction.
+            ///
+            /// Parameters
+            /// ----------
+            /// eos : EquationOfState
+            ///     The equation of state.
+            /// reactions : ChemicalReactions
+            ///     The chemical reactions.
+            /// temperature : SINumber
+            ///     The temperature.
+            /// pressure : SINumber
+            ///     The pressure.
+            /// moles : SIArray1
+            ///     The initial amounts of substance of all components.
+            /// phase_equilibrium : bool, optional
+            ///     Whether the phase split of the reacting mixture is determined
+            ///     simultaneously. Defaults to True.
+            /// max_iter : int, optional
+            ///     The maximum number of iterations.
+            /// tol: float, optional
+            ///     The solution tolerance.
+            /// verbosity : Verbosity, optional
+            ///     The verbosity.
+            ///
+            /// Returns
+            /// -------
+            /// ChemicalEquilibrium
+            #[staticmethod]
+            #[pyo3(
+                signature = (eos, reactions, temperature, pressure, moles, phase_equilibrium=true, max_iter=None, tol=None, verbosity=None),
+                text_signature = "(eos, reactions, temperature, pressure, moles, phase_equilibrium=True, max_iter=None, tol=None, verbosity=None)"
+            )]
+            pub fn tp(
+                eos: &$py_eos,
+                reactions: &$crate::python::chemical_equilibrium::PyChemicalReactions,
+                temperature: PySINumber,
+                pressure: PySINumber,
+                moles: PySIArray1,
+                phase_equilibrium: bool,
+                max_iter: Option<usize>,
+                tol: Option<f64>,
+                verbosity: Option<Verbosity>,
+            ) -> PyResult<Self> {
+                Ok(Self(ChemicalEquilibrium::tp(
+                    &eos.0,
+                    &reactions.0,
+                    temperature.into(),
+                    pressure.into(),
+                    &moles.into(),
+                    phase_equilibrium,
+                    (max_iter, tol, verbosity).into(),
+                )?))
+            }
+
+            /// The logarithms of the equilibrium constants of all reactions.
+            ///
+            /// Parameters
+            /// ----------
+            /// eos : EquationOfState
+            ///     The equation of state.
+            /// reactions : ChemicalReactions
+            ///     The chemical reactions.
+            /// temperature : SINumber
+            ///     The temperature.
+            ///
+            /// Returns
+            /// -------
+            /// numpy.ndarray[float]
+            #[staticmethod]
+            #[pyo3(text_signature = "(eos, reactions, temperature)")]
+            pub fn ln_equilibrium_constant<'py>(
+                py: Python<'py>,
+                eos: &$py_eos,
+                reactions: &$crate::python::chemical_equilibrium::PyChemicalReactions,
+                temperature: PySINumber,
+            ) -> PyResult<&'py PyArray1<f64>> {
+                Ok(reactions
+                    .0
+                    .ln_equilibrium_constant(&eos.0, temperature.into())?
+                    .to_pyarray(py))
+            }
+
+            /// The standard chemical potentials of all components, i.e.,
+            /// their Gibbs energies of formation as ideal gases at the
+            /// given temperature and 1 bar.
+            ///
+            /// Parameters
+            /// ----------
+            /// eos : EquationOfState
+            ///     The equation of state.
+            /// reactions : ChemicalReactions
+            ///     The chemical reactions.
+            /// temperature : SINumber
+            ///     The temperature.
+            ///
+            /// Returns
+            /// -------
+            /// SIArray1
+            #[staticmethod]
+            #[pyo3(text_signature = "(eos, reactions, temperature)")]
+            pub fn standard_chemical_potential(
+                eos: &$py_eos,
+                reactions: &$crate::python::chemical_equilibrium::PyChemicalReactions,
+                temperature: PySINumber,
+            ) -> PyResult<PySIArray1> {
+                Ok(reactions
+                    .0
+                    .standard_chemical_potential(&eos.0, temperature.into())?
+                    .into())
+            }
+
+            /// The phases in equilibrium.
+            #[getter]
+            fn get_phases(&self) -> Vec<PyState> {
+                self.0.phases().iter().cloned().map(PyState).collect()
+            }
+
+            /// The extents of all reactions.
+            #[getter]
+            fn get_extent_of_reaction(&self) -> PySIArray1 {
+                self.0.extent_of_reaction().clone().into()
+            }
+
+            /// The total amounts of substance of all components.
+            #[getter]
+            fn get_moles(&self) -> PySIArray1 {
+                self.0.moles().into()
+            }
+
+            /// The log of the solver that determined the chemical equilibrium.
+            #[getter]
+            fn get_solver_log(&self) -> $crate::python::solver_log::PySolverLog {
+                $crate::python::solver_log::PySolverLog(self.0.solver_log().clone())
+            }
+        }
+    };
+}
@@ -4,7 +4,7 @@ use crate::parameter::{
     BinaryRecord, Identifier, IdentifierOption, Parameter, ParameterError, PureRecord,
 };
 use crate::python::joback::PyJobackRecord;
-use crate::python::parameter::PyIdentifier;
+use crate::python::parameter::{PyIdentifier, PyMetadata};
 use crate::*;
 use ndarray::Array2;
 use numpy::{PyArray2, PyReadonlyArray2, ToPyArray};
@@ -53,7 +53,7 @@ impl_binary_record!();
 ///     The substances to use. Filters substances from `pure_records` according to
 ///     `search_option`.
 ///     When not provided, all entries of `pure_records` are used.
-/// search_option : {'Name', 'Cas', 'Inchi', 'IupacName', 'Formula', 'Smiles'}, optional, defaults to 'Name'.
+/// search_option : {'Name', 'Cas', 'Inchi', 'InchiKey', 'IupacName', 'Formula', 'Smiles'}, optional, defaults to 'Name'.
 ///     Identifier that is used to search substance.
 ///
 /// Returns
@@ -18,6 +18,24 @@ macro_rules! impl_equation_of_state {
                 let m = moles.as_deref();
                 Ok(self.0.max_density(m)?.into())
             }
+
+            /// Return a copy with a different ideal gas contribution.
+            ///
+            /// Parameters
+            /// ----------
+            /// ideal_gas : IdealGasModel
+            ///     The ideal gas model with a record for every component.
+            ///
+            /// Returns
+            /// -------
+            /// Self
+            #[pyo3(text_signature = "(ideal_gas)")]
+            fn with_ideal_gas(&self, ideal_gas: PyIdealGasModel) -> PyResult<Self> {
+                let components: Vec<usize> = (0..self.0.components()).collect();
+                Ok(Self(Arc::new(
+                    self.0.subset(&components).with_ideal_gas(ideal_gas.0)?,
+                )))
+            }
         }
     };
 }
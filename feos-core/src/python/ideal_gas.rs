@@ -0,0 +1,161 @@
+use crate::ideal_gas::{
+    DipprRecord, IdealGasModel, IdealGasRecord, Nasa7Record, Nasa9Interval, Nasa9Record,
+};
+use crate::impl_json_handling;
+use crate::parameter::ParameterError;
+use crate::python::joback::PyJobackRecord;
+use numpy::PyArray1;
+use pyo3::exceptions::PyValueError;
+use pyo3::prelude::*;
+use quantity::python::PySINumber;
+
+/// Ideal gas heat capacity model of a single component.
+///
+/// Use one of the static methods to create a record.
+#[pyclass(name = "IdealGasRecord")]
+#[derive(Clone)]
+pub struct PyIdealGasRecord(pub IdealGasRecord);
+
+#[pymethods]
+impl PyIdealGasRecord {
+    /// Ideal gas heat capacity according to Joback and Reid.
+    ///
+    /// Parameters
+    /// ----------
+    /// record : JobackRecord
+    ///     The Joback parameters of the component.
+    ///
+    /// Returns
+    /// -------
+    /// IdealGasRecord
+    #[staticmethod]
+    #[pyo3(text_signature = "(record)")]
+    fn joback(record: PyJobackRecord) -> Self {
+        Self(IdealGasRecord::Joback(record.0))
+    }
+
+    /// Ideal gas heat capacity from NASA 7-coefficient polynomials.
+    ///
+    /// Parameters
+    /// ----------
+    /// t_mid : float
+    ///     Temperature in K that separates the two intervals.
+    /// low : [float]
+    ///     The 7 coefficients below `t_mid`.
+    /// high : [float]
+    ///     The 7 coefficients above `t_mid`.
+    ///
+    /// Returns
+    /// -------
+    /// IdealGasRecord
+    #[staticmethod]
+    #[pyo3(text_signature = "(t_mid, low, high)")]
+    fn nasa7(t_mid: f64, low: [f64; 7], high: [f64; 7]) -> Self {
+        Self(IdealGasRecord::Nasa7(Nasa7Record::new(t_mid, low, high)))
+    }
+
+    /// Ideal gas heat capacity from NASA 9-coefficient polynomials.
+    ///
+    /// Parameters
+    /// ----------
+    /// intervals : [(float, float, [float])]
+    ///     The lower and upper temperature in K and the 9
+    ///     coefficients of every temperature interval.
+    ///
+    /// Returns
+    /// -------
+    /// IdealGasRecord
+    #[staticmethod]
+    #[pyo3(text_signature = "(intervals)")]
+    fn nasa9(intervals: Vec<(f64, f64, [f64; 9])>) -> Self {
+        let intervals = intervals
+            .into_iter()
+            .map(|(t_min, t_max, coefs)| Nasa9Interval::new(t_min, t_max, coefs))
+            .collect();
+        Self(IdealGasRecord::Nasa9(Nasa9Record::new(intervals)))
+    }
+
+    /// Ideal gas heat capacity from a DIPPR correlation.
+    ///
+    /// Parameters
+    /// ----------
+    /// equation : int
+    ///     The number of the DIPPR equation (100 or 107).
+    /// coefs : [float]
+    ///     The 5 coefficients for the heat capacity in J/(kmol K).
+    ///
+    /// Returns
+    /// -------
+    /// IdealGasRecord
+    #[staticmethod]
+    #[pyo3(text_signature = "(equation, coefs)")]
+    fn dippr(equation: usize, coefs: [f64; 5]) -> PyResult<Self> {
+        let record = match equation {
+            100 => DipprRecord::eq100(coefs),
+            107 => DipprRecord::eq107(coefs),
+            _ => {
+                return Err(PyValueError::new_err(format!(
+                    "DIPPR equation {} is not supported!",
+                    equation
+                )))
+            }
+        };
+        Ok(Self(IdealGasRecord::Dippr(record)))
+    }
+
+    fn __repr__(&self) -> PyResult<String> {
+        Ok(self.0.to_string())
+    }
+}
+
+impl_json_handling!(PyIdealGasRecord);
+
+/// Ideal gas contribution with an individual heat capacity
+/// model for every component.
+///
+/// Parameters
+/// ----------
+/// records : [IdealGasRecord]
+///     The ideal gas records of all components.
+///
+/// Returns
+/// -------
+/// IdealGasModel
+#[pyclass(name = "IdealGasModel")]
+#[pyo3(text_signature = "(records)")]
+#[derive(Clone)]
+pub struct PyIdealGasModel(pub IdealGasModel);
+
+#[pymethods]
+impl PyIdealGasModel {
+    #[new]
+    fn new(records: Vec<PyIdealGasRecord>) -> Self {
+        Self(IdealGasModel::new(
+            records.into_iter().map(|r| r.0).collect(),
+        ))
+    }
+
+    /// Calculate the ideal gas heat capacity of a mixture.
+    ///
+    /// Parameters
+    /// ----------
+    /// temperature : SINumber
+    ///     The temperature.
+    /// molefracs : numpy.ndarray[float]
+    ///     The mole fractions of all components.
+    ///
+    /// Returns
+    /// -------
+    /// SINumber
+    #[pyo3(text_signature = "(temperature, molefracs)")]
+    fn c_p(&self, temperature: PySINumber, molefracs: &PyArray1<f64>) -> PyResult<PySINumber> {
+        Ok(self
+            .0
+            .c_p(temperature.into(), &molefracs.to_owned_array())?
+            .into())
+    }
+
+    fn __repr__(&self) -> PyResult<String> {
+        Ok(self.0.to_string())
+    }
+}
@@ -2,11 +2,18 @@ use crate::EosError;
 use pyo3::exceptions::PyRuntimeError;
 use pyo3::PyErr;
 
+pub mod chemical_equilibrium;
 pub mod cubic;
 mod equation_of_state;
+pub mod ideal_gas;
 pub mod joback;
 pub mod parameter;
 mod phase_equilibria;
+mod process;
+pub mod progress;
+pub mod reduced_units;
+pub mod solid;
+pub mod solver_log;
 mod state;
 pub mod user_defined;
 
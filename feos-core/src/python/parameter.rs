@@ -1,5 +1,5 @@
 use crate::impl_json_handling;
-use crate::parameter::{BinaryRecord, ChemicalRecord, Identifier, ParameterError};
+use crate::parameter::{BinaryRecord, ChemicalRecord, Identifier, Metadata, ParameterError};
 use pyo3::exceptions::PyRuntimeError;
 use pyo3::prelude::*;
 
@@ -25,6 +25,10 @@ impl From<ParameterError> for PyErr {
 ///     Inchi number
 /// formula : str, optional
 ///     Molecular formula.
+/// inchikey : str, optional
+///     InChIKey.
+/// aliases : [str], optional
+///     Alternative names.
 ///
 /// Returns
 /// -------
@@ -32,7 +36,7 @@ impl From<ParameterError> for PyErr {
 #[pyclass(name = "Identifier")]
 #[derive(Clone)]
 #[pyo3(
-    text_signature = "(cas=None, name=None, iupac_name=None, smiles=None, inchi=None, formula=None)"
+    text_signature = "(cas=None, name=None, iupac_name=None, smiles=None, inchi=None, formula=None, inchikey=None, aliases=None)"
 )]
 pub struct PyIdentifier(pub Identifier);
 
@@ -46,10 +50,14 @@ impl PyIdentifier {
         smiles: Option<&str>,
         inchi: Option<&str>,
         formula: Option<&str>,
+        inchikey: Option<&str>,
+        aliases: Option<Vec<&str>>,
     ) -> Self {
-        Self(Identifier::new(
-            cas, name, iupac_name, smiles, inchi, formula,
-        ))
+        let mut identifier = Identifier::new(cas, name, iupac_name, smiles, inchi, formula);
+        identifier.inchikey = inchikey.map(Into::into);
+        identifier.aliases =
+            aliases.map_or_else(Vec::new, |a| a.into_iter().map(Into::into).collect());
+        Self(identifier)
     }
 
     #[getter]
@@ -112,6 +120,26 @@ impl PyIdentifier {
         self.0.formula = Some(formula.to_string());
     }
 
+    #[getter]
+    fn get_inchikey(&self) -> Option<String> {
+        self.0.inchikey.clone()
+    }
+
+    #[setter]
+    fn set_inchikey(&mut self, inchikey: &str) {
+        self.0.inchikey = Some(inchikey.to_string());
+    }
+
+    #[getter]
+    fn get_aliases(&self) -> Vec<String> {
+        self.0.aliases.clone()
+    }
+
+    #[setter]
+    fn set_aliases(&mut self, aliases: Vec<String>) {
+        self.0.aliases = aliases;
+    }
+
     fn __repr__(&self) -> PyResult<String> {
         Ok(self.0.to_string())
     }
@@ -119,6 +147,69 @@ impl PyIdentifier {
 
 impl_json_handling!(PyIdentifier);
 
+/// Create metadata describing the origin of a record.
+///
+/// Parameters
+/// ----------
+/// doi : str, optional
+///     DOI of the publication the parameters are taken from.
+/// date : str, optional
+///     Date on which the parameters were determined.
+/// comment : str, optional
+///     Free text comment.
+///
+/// Returns
+/// -------
+/// Metadata
+#[pyclass(name = "Metadata")]
+#[derive(Clone)]
+#[pyo3(text_signature = "(doi=None, date=None, comment=None)")]
+pub struct PyMetadata(pub Metadata);
+
+#[pymethods]
+impl PyMetadata {
+    #[new]
+    fn new(doi: Option<String>, date: Option<String>, comment: Option<String>) -> Self {
+        Self(Metadata::new(doi, date, comment))
+    }
+
+    #[getter]
+    fn get_doi(&self) -> Option<String> {
+        self.0.doi.clone()
+    }
+
+    #[setter]
+    fn set_doi(&mut self, doi: &str) {
+        self.0.doi = Some(doi.to_string());
+    }
+
+    #[getter]
+    fn get_date(&self) -> Option<String> {
+        self.0.date.clone()
+    }
+
+    #[setter]
+    fn set_date(&mut self, date: &str) {
+        self.0.date = Some(date.to_string());
+    }
+
+    #[getter]
+    fn get_comment(&self) -> Option<String> {
+        self.0.comment.clone()
+    }
+
+    #[setter]
+    fn set_comment(&mut self, comment: &str) {
+        self.0.comment = Some(comment.to_string());
+    }
+
+    fn __repr__(&self) -> PyResult<String> {
+        Ok(self.0.to_string())
+    }
+}
+
+impl_json_handling!(PyMetadata);
+
 /// Create a chemical record for a pure substance.
 ///
 /// Parameters
@@ -278,6 +369,16 @@ macro_rules! impl_binary_record {
                 Ok(())
             }
 
+            #[getter]
+            fn get_metadata(&self) -> Option<PyMetadata> {
+                self.0.metadata.clone().map(PyMetadata)
+            }
+
+            #[setter]
+            fn set_metadata(&mut self, metadata: PyMetadata) {
+                self.0.metadata = Some(metadata.0);
+            }
+
             fn __repr__(&self) -> PyResult<String> {
                 Ok(self.0.to_string())
             }
@@ -361,6 +462,16 @@ impl PyBinarySegmentRecord {
         self.0.model_record = model_record;
     }
 
+    #[getter]
+    fn get_metadata(&self) -> Option<PyMetadata> {
+        self.0.metadata.clone().map(PyMetadata)
+    }
+
+    #[setter]
+    fn set_metadata(&mut self, metadata: PyMetadata) {
+        self.0.metadata = Some(metadata.0);
+    }
+
     fn __repr__(&self) -> PyResult<String> {
         Ok(self.0.to_string())
     }
@@ -449,6 +560,16 @@ macro_rules! impl_pure_record {
                 self.0.ideal_gas_record = Some(ideal_gas_record.0);
             }
 
+            #[getter]
+            fn get_metadata(&self) -> Option<PyMetadata> {
+                self.0.metadata.clone().map(PyMetadata)
+            }
+
+            #[setter]
+            fn set_metadata(&mut self, metadata: PyMetadata) {
+                self.0.metadata = Some(metadata.0);
+            }
+
             fn __repr__(&self) -> PyResult<String> {
                 Ok(self.0.to_string())
             }
@@ -557,6 +678,16 @@ macro_rules! impl_segment_record {
                 self.0.ideal_gas_record = Some(ideal_gas_record.0);
             }
 
+            #[getter]
+            fn get_metadata(&self) -> Option<PyMetadata> {
+                self.0.metadata.clone().map(PyMetadata)
+            }
+
+            #[setter]
+            fn set_metadata(&mut self, metadata: PyMetadata) {
+                self.0.metadata = Some(metadata.0);
+            }
+
             fn __repr__(&self) -> PyResult<String> {
                 Ok(self.0.to_string())
             }
@@ -594,23 +725,30 @@ macro_rules! impl_parameter {
             ) -> PyResult<Self> {
                 let prs = pure_records.into_iter().map(|pr| pr.0).collect();
                 if let Some(binary_records) = binary_records {
+                    let n = prs.len();
                     let brs = if let Ok(br) = binary_records.extract::<PyReadonlyArray2<f64>>() {
-                        Ok(br.to_owned_array().mapv(|r| r.try_into().unwrap()))
+                        Ok((
+                            br.to_owned_array().mapv(|r| r.try_into().unwrap()),
+                            Array2::from_elem([n, n], None),
+                        ))
                     } else if let Ok(br) = binary_records.extract::<Vec<PyBinaryRecord>>() {
                         let brs: Vec<_> = br.into_iter().map(|br| br.0).collect();
-                        Ok(<$parameter>::binary_matrix_from_records(
-                            &prs,
-                            &brs,
-                            search_option,
+                        Ok((
+                            <$parameter>::binary_matrix_from_records(&prs, &brs, search_option),
+                            <$parameter>::binary_metadata_from_records(
+                                &prs,
+                                &brs,
+                                search_option,
+                            ),
                         ))
                     } else {
                         Err(PyErr::new::<PyTypeError, _>(format!(
                             "Could not parse binary input!"
                         )))
                     };
-                    Ok(Self(Arc::new(<$parameter>::from_records(
-                        prs,
-                        brs.unwrap(),
+                    let (brs, metadata) = brs.unwrap();
+                    Ok(Self(Arc::new(<$parameter>::from_records_with_metadata(
+                        prs, brs, metadata,
                     ))))
                 } else {
                     let n = prs.len();
@@ -743,6 +881,8 @@ macro_rules! impl_parameter {
                     .to_pyarray(py)
             }
         }
+
+        $crate::impl_parameter_json!($parameter, $py_parameter);
     };
 }
 
@@ -772,7 +912,7 @@ macro_rules! impl_parameter_from_segments {
                 Ok(Self(Arc::new(<$parameter>::from_segments(
                     chemical_records.into_iter().map(|cr| cr.0).collect(),
                     segment_records.into_iter().map(|sr| sr.0).collect(),
-                    binary_segment_records.map(|r| r.into_iter().map(|r| BinaryRecord{id1:r.0.id1,id2:r.0.id2,model_record:r.0.model_record.into()}).collect()),
+                    binary_segment_records.map(|r| r.into_iter().map(|r| BinaryRecord{id1:r.0.id1,id2:r.0.id2,model_record:r.0.model_record.into(),metadata:r.0.metadata}).collect()),
                 )?)))
             }
 
@@ -814,6 +954,64 @@ macro_rules! impl_parameter_from_segments {
     };
 }
 
+#[macro_export]
+macro_rules! impl_parameter_json {
+    ($parameter:ty, $py_parameter:ty) => {
+        #[pymethods]
+        impl $py_parameter {
+            /// Creates parameters from a json string created with `to_json_str`.
+            ///
+            /// Parameters
+            /// ----------
+            /// json : str
+            ///     The json string containing all records.
+            #[staticmethod]
+            fn from_json_str(json: &str) -> Result<Self, ParameterError> {
+                Ok(Self(Arc::new(<$parameter>::from_json_str(json)?)))
+            }
+
+            /// Creates a json string containing all records of the parameters.
+            ///
+            /// Metadata of the records is preserved.
+            ///
+            /// Returns
+            /// -------
+            /// str
+            fn to_json_str(&self) -> Result<String, ParameterError> {
+                self.0.to_json_str()
+            }
+
+            /// Creates parameters from a json file created with `to_json_file`.
+            ///
+            /// Parameters
+            /// ----------
+            /// path : str
+            ///     Path to the json file.
+            #[staticmethod]
+            fn from_json_file(path: &str) -> Result<Self, ParameterError> {
+                Ok(Self(Arc::new(<$parameter>::from_json_file(path)?)))
+            }
+
+            /// Writes all records of the parameters to a json file.
+            ///
+            /// Metadata of the records is preserved.
+            ///
+            /// Parameters
+            /// ----------
+            /// path : str
+            ///     Path to the json file.
+            fn to_json_file(&self, path: &str) -> Result<(), ParameterError> {
+                self.0.to_json_file(path)
+            }
+
+            fn __reduce__(&self, py: Python<'_>) -> PyResult<(PyObject, (String,))> {
+                let from_json_str = py.get_type::<Self>().getattr("from_json_str")?;
+                Ok((from_json_str.into(), (self.0.to_json_str()?,)))
+            }
+        }
+    };
+}
+
 #[macro_export]
 macro_rules! impl_json_handling {
     ($py_parameter:ty) => {
@@ -829,6 +1027,12 @@ macro_rules! impl_json_handling {
             fn to_json_str(&self) -> Result<String, ParameterError> {
                 Ok(serde_json::to_string(&self.0)?)
             }
+
+            fn __reduce__(&self, py: Python<'_>) -> PyResult<(PyObject, (String,))> {
+                let from_json_str = py.get_type::<Self>().getattr("from_json_str")?;
+                let json = serde_json::to_string(&self.0).map_err(ParameterError::from)?;
+                Ok((from_json_str.into(), (json,)))
+            }
         }
     };
 }
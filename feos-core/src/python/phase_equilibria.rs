@@ -237,6 +237,13 @@ macro_rules! impl_phase_equilibrium {
                 PyState(self.0.liquid().clone())
             }
 
+            /// The log of the solver that determined the phase
+            /// equilibrium, if available.
+            #[getter]
+            fn get_solver_log(&self) -> Option<$crate::python::solver_log::PySolverLog> {
+                self.0.solver_log().cloned().map($crate::python::solver_log::PySolverLog)
+            }
+
             /// Calculate a new PhaseEquilibrium with the given chemical potential.
             /// The temperature remains constant, but the states are not in
             /// a mechanical equilibrium anymore.
@@ -321,6 +328,36 @@ macro_rules! impl_phase_equilibrium {
             fn __repr__(&self) -> PyResult<String> {
                 Ok(self.0.to_string())
             }
+
+            /// Creates a phase equilibrium from a json string created with `to_json_str`.
+            ///
+            /// Parameters
+            /// ----------
+            /// json : str
+            ///     The json string containing the phase equilibrium.
+            ///
+            /// Returns
+            /// -------
+            /// PhaseEquilibrium
+            #[staticmethod]
+            #[pyo3(text_signature = "(json)")]
+            fn from_json_str(json: &str) -> Result<Self, $crate::parameter::ParameterError> {
+                Ok(Self(serde_json::from_str(json)?))
+            }
+
+            /// Creates a json string containing both phases of the phase equilibrium.
+            ///
+            /// Returns
+            /// -------
+            /// str
+            fn to_json_str(&self) -> Result<String, $crate::parameter::ParameterError> {
+                Ok(serde_json::to_string(&self.0)?)
+            }
+
+            fn __reduce__(&self, py: Python<'_>) -> PyResult<(PyObject, (String,))> {
+                let from_json_str = py.get_type::<Self>().getattr("from_json_str")?;
+                Ok((from_json_str.into(), (self.to_json_str()?,)))
+            }
         }
 
         /// A thermodynamic three phase equilibrium state.
@@ -389,6 +426,108 @@ macro_rules! impl_phase_equilibrium {
                     )
                 )?))
             }
+
+            /// Calculate a homogeneous azeotrope in a binary mixture for a given
+            /// temperature or pressure.
+            ///
+            /// Parameters
+            /// ----------
+            /// eos : EquationOfState
+            ///     The equation of state.
+            /// temperature_or_pressure : SINumber
+            ///     The system temperature or pressure.
+            /// x_bounds : (float, float), optional
+            ///     Liquid molefracs of component 1 that bracket the azeotrope.
+            ///     If not provided, the bubble point curve is scanned for an azeotrope.
+            /// max_iter : int, optional
+            ///     The maximum number of iterations.
+            /// tol: float, optional
+            ///     The solution tolerance.
+            /// verbosity : Verbosity, optional
+            ///     The verbosity.
+            /// max_iter_bd_inner : int, optional
+            ///     The maximum number of inner iterations in the bubble/dew point iteration.
+            /// max_iter_bd_outer : int, optional
+            ///     The maximum number of outer iterations in the bubble/dew point iteration.
+            /// tol_bd_inner : float, optional
+            ///     The solution tolerance in the inner loop of the bubble/dew point iteration.
+            /// tol_bd_outer : float, optional
+            ///     The solution tolerance in the outer loop of the bubble/dew point iteration.
+            /// verbosity_bd : Verbosity, optional
+            ///     The verbosity of the bubble/dew point iteration.
+            ///
+            /// Returns
+            /// -------
+            /// PhaseEquilibrium
+            #[staticmethod]
+            #[pyo3(text_signature = "(eos, temperature_or_pressure, x_bounds=None, max_iter=None, tol=None, verbosity=None, max_iter_bd_inner=None, max_iter_bd_outer=None, tol_bd_inner=None, tol_bd_outer=None, verbosity_bd=None)")]
+            fn azeotrope(
+                eos: $py_eos,
+                temperature_or_pressure: PySINumber,
+                x_bounds: Option<(f64, f64)>,
+                max_iter: Option<usize>,
+                tol: Option<f64>,
+                verbosity: Option<Verbosity>,
+                max_iter_bd_inner: Option<usize>,
+                max_iter_bd_outer: Option<usize>,
+                tol_bd_inner: Option<f64>,
+                tol_bd_outer: Option<f64>,
+                verbosity_bd: Option<Verbosity>,
+            ) -> PyResult<Self> {
+                Ok(Self(PhaseEquilibrium::azeotrope(
+                    &eos.0,
+                    temperature_or_pressure.into(),
+                    x_bounds,
+                    (max_iter, tol, verbosity).into(),
+                    (
+                        (max_iter_bd_inner, tol_bd_inner, verbosity_bd).into(),
+                        (max_iter_bd_outer, tol_bd_outer, verbosity_bd).into(),
+                    )
+                )?))
+            }
+
+            /// Calculate Henry's law constant of a solute in the
+            /// saturated liquid solvent at given temperature.
+            ///
+            /// Parameters
+            /// ----------
+            /// eos : EquationOfState
+            ///     The equation of state.
+            /// temperature : SINumber
+            ///     The temperature.
+            /// solute : int
+            ///     The index of the solute.
+            /// molefracs : numpy.ndarray
+            ///     The composition of the solvent. The entry of the solute is ignored.
+            /// max_iter : int, optional
+            ///     The maximum number of iterations.
+            /// tol: float, optional
+            ///     The solution tolerance.
+            /// verbosity : Verbosity, optional
+            ///     The verbosity.
+            ///
+            /// Returns
+            /// -------
+            /// SINumber
+            #[staticmethod]
+            #[pyo3(text_signature = "(eos, temperature, solute, molefracs, max_iter=None, tol=None, verbosity=None)")]
+            fn henry_constant(
+                eos: $py_eos,
+                temperature: PySINumber,
+                solute: usize,
+                molefracs: &PyArray1<f64>,
+                max_iter: Option<usize>,
+                tol: Option<f64>,
+                verbosity: Option<Verbosity>,
+            ) -> PyResult<PySINumber> {
+                Ok(PySINumber::from(PhaseEquilibrium::henry_constant(
+                    &eos.0,
+                    temperature.into(),
+                    solute,
+                    &molefracs.to_owned_array(),
+                    (max_iter, tol, verbosity).into(),
+                )?))
+            }
         }
 
         #[pymethods]
@@ -498,12 +637,16 @@ macro_rules! impl_phase_equilibrium {
             ///     The solution tolerance.
             /// verbosity : Verbosity, optional
             ///     The verbosity.
+            /// callback : Callable[[str, int, float], bool], optional
+            ///     Called with the arguments `(task, iteration, residual)`
+            ///     before every temperature. Returning `False` cancels
+            ///     the calculation.
             ///
             /// Returns
             /// -------
             /// PhaseDiagram
             #[staticmethod]
-            #[pyo3(text_signature = "(eos, min_temperature, npoints, critical_temperature=None, max_iter=None, tol=None, verbosity=None)")]
+            #[pyo3(text_signature = "(eos, min_temperature, npoints, critical_temperature=None, max_iter=None, tol=None, verbosity=None, callback=None)")]
             pub fn pure(
                 eos: &$py_eos,
                 min_temperature: PySINumber,
@@ -512,6 +655,7 @@ macro_rules! impl_phase_equilibrium {
                 max_iter: Option<usize>,
                 tol: Option<f64>,
                 verbosity: Option<Verbosity>,
+                callback: Option<$crate::python::progress::PyProgressCallback>,
             ) -> PyResult<Self> {
                 let dia = PhaseDiagram::pure(
                     &eos.0,
@@ -519,6 +663,7 @@ macro_rules! impl_phase_equilibrium {
                     npoints,
                     critical_temperature.map(|t| t.into()),
                     (max_iter, tol, verbosity).into(),
+                    callback.as_ref().map(|c| c as &dyn $crate::ProgressCallback),
                 )?;
                 Ok(Self(dia))
             }
@@ -773,6 +918,16 @@ macro_rules! impl_phase_equilibrium {
                 self.0.liquid().into()
             }
 
+            /// The azeotropes contained in the phase diagram.
+            #[getter]
+            pub fn get_azeotropes(&self) -> Vec<PyPhaseEquilibrium> {
+                self.0
+                    .azeotropes()
+                    .into_iter()
+                    .map(PyPhaseEquilibrium)
+                    .collect()
+            }
+
             /// Returns the phase diagram as dictionary.
             ///
             /// Units
@@ -782,6 +937,7 @@ macro_rules! impl_phase_equilibrium {
             /// densities : mol / m³
             /// molar enthalpies : kJ / mol
             /// molar entropies : kJ / mol / K
+            /// molar isobaric heat capacities : kJ / mol / K
             ///
             /// Returns
             /// -------
@@ -812,6 +968,8 @@ macro_rules! impl_phase_equilibrium {
                 dict.insert(String::from("molar enthalpy vapor"), (self.0.vapor().molar_enthalpy() / (KILO*JOULE / MOL)).into_value()?.into_raw_vec());
                 dict.insert(String::from("molar entropy liquid"), (self.0.liquid().molar_entropy() / (KILO*JOULE / KELVIN / MOL)).into_value()?.into_raw_vec());
                 dict.insert(String::from("molar entropy vapor"), (self.0.vapor().molar_entropy() / (KILO*JOULE / KELVIN / MOL)).into_value()?.into_raw_vec());
+                dict.insert(String::from("molar isobaric heat capacity liquid"), (self.0.liquid().c_p() / (KILO*JOULE / KELVIN / MOL)).into_value()?.into_raw_vec());
+                dict.insert(String::from("molar isobaric heat capacity vapor"), (self.0.vapor().c_p() / (KILO*JOULE / KELVIN / MOL)).into_value()?.into_raw_vec());
                 Ok(dict)
             }
 
@@ -868,6 +1026,130 @@ macro_rules! impl_phase_equilibrium {
                 Ok(Self(dia))
             }
 
+            /// Binary phase diagram calculated using bubble/dew point iterations
+            /// in parallel.
+            ///
+            /// Parameters
+            /// ----------
+            /// eos : EquationOfState
+            ///     The equation of state.
+            /// temperature_or_pressure: SINumber
+            ///     The constant temperature or pressure.
+            /// chunksize : int
+            ///     The number of points that are calculated in sequence
+            ///     within a thread.
+            /// nthreads : int
+            ///     Number of threads.
+            /// npoints: int, optional
+            ///     The number of points (default 51).
+            /// x_lle: (float, float), optional
+            ///     An estimate for the molefractions of component 1
+            ///     at the heteroazeotrop
+            /// max_iter_inner : int, optional
+            ///     The maximum number of inner iterations in the bubble/dew point iteration.
+            /// max_iter_outer : int, optional
+            ///     The maximum number of outer iterations in the bubble/dew point iteration.
+            /// tol_inner : float, optional
+            ///     The solution tolerance in the inner loop of the bubble/dew point iteration.
+            /// tol_outer : float, optional
+            ///     The solution tolerance in the outer loop of the bubble/dew point iteration.
+            /// verbosity : Verbosity, optional
+            ///     The verbosity of the bubble/dew point iteration.
+            ///
+            /// Returns
+            /// -------
+            /// PhaseDiagram
+            #[cfg(feature = "rayon")]
+            #[staticmethod]
+            #[pyo3(text_signature = "(eos, temperature_or_pressure, chunksize, nthreads, npoints=None, x_lle=None, max_iter_inner=None, max_iter_outer=None, tol_inner=None, tol_outer=None, verbosity=None)")]
+            pub fn par_binary_vle(
+                eos: $py_eos,
+                temperature_or_pressure: PySINumber,
+                chunksize: usize,
+                nthreads: usize,
+                npoints: Option<usize>,
+                x_lle: Option<(f64, f64)>,
+                max_iter_inner: Option<usize>,
+                max_iter_outer: Option<usize>,
+                tol_inner: Option<f64>,
+                tol_outer: Option<f64>,
+                verbosity: Option<Verbosity>,
+            ) -> EosResult<Self> {
+                let thread_pool = rayon::ThreadPoolBuilder::new()
+                    .num_threads(nthreads)
+                    .build()?;
+                let dia = PhaseDiagram::par_binary_vle(
+                    &eos.0,
+                    temperature_or_pressure.into(),
+                    npoints,
+                    x_lle,
+                    chunksize,
+                    thread_pool,
+                    (
+                        (max_iter_inner, tol_inner, verbosity).into(),
+                        (max_iter_outer, tol_outer, verbosity).into(),
+                    )
+                )?;
+                Ok(Self(dia))
+            }
+
+            /// Trace the homogeneous azeotrope of a binary mixture
+            /// for the given temperatures or pressures.
+            ///
+            /// The calculation stops when the azeotrope vanishes.
+            ///
+            /// Parameters
+            /// ----------
+            /// eos : EquationOfState
+            ///     The equation of state.
+            /// temperature_or_pressure: SIArray1
+            ///     The temperatures or pressures.
+            /// max_iter : int, optional
+            ///     The maximum number of iterations.
+            /// tol: float, optional
+            ///     The solution tolerance.
+            /// verbosity : Verbosity, optional
+            ///     The verbosity.
+            /// max_iter_bd_inner : int, optional
+            ///     The maximum number of inner iterations in the bubble/dew point iteration.
+            /// max_iter_bd_outer : int, optional
+            ///     The maximum number of outer iterations in the bubble/dew point iteration.
+            /// tol_bd_inner : float, optional
+            ///     The solution tolerance in the inner loop of the bubble/dew point iteration.
+            /// tol_bd_outer : float, optional
+            ///     The solution tolerance in the outer loop of the bubble/dew point iteration.
+            /// verbosity_bd : Verbosity, optional
+            ///     The verbosity of the bubble/dew point iteration.
+            ///
+            /// Returns
+            /// -------
+            /// PhaseDiagram
+            #[staticmethod]
+            #[pyo3(text_signature = "(eos, temperature_or_pressure, max_iter=None, tol=None, verbosity=None, max_iter_bd_inner=None, max_iter_bd_outer=None, tol_bd_inner=None, tol_bd_outer=None, verbosity_bd=None)")]
+            pub fn azeotrope_line(
+                eos: $py_eos,
+                temperature_or_pressure: PySIArray1,
+                max_iter: Option<usize>,
+                tol: Option<f64>,
+                verbosity: Option<Verbosity>,
+                max_iter_bd_inner: Option<usize>,
+                max_iter_bd_outer: Option<usize>,
+                tol_bd_inner: Option<f64>,
+                tol_bd_outer: Option<f64>,
+                verbosity_bd: Option<Verbosity>,
+            ) -> PyResult<Self> {
+                let dia = PhaseDiagram::azeotrope_line(
+                    &eos.0,
+                    &temperature_or_pressure.into(),
+                    (max_iter, tol, verbosity).into(),
+                    (
+                        (max_iter_bd_inner, tol_bd_inner, verbosity_bd).into(),
+                        (max_iter_bd_outer, tol_bd_outer, verbosity_bd).into(),
+                    )
+                )?;
+                Ok(Self(dia))
+            }
+
             /// Create a new phase diagram using Tp flash calculations.
             ///
             /// The usual use case for this function is the calculation of
@@ -913,6 +1195,61 @@ macro_rules! impl_phase_equilibrium {
                 )?;
                 Ok(Self(dia))
             }
+
+            /// Create a new phase diagram using Tp flash calculations
+            /// in parallel.
+            ///
+            /// Parameters
+            /// ----------
+            /// eos : EquationOfState
+            ///     The equation of state.
+            /// temperature_or_pressure: SINumber
+            ///     The consant temperature or pressure.
+            /// feed: SIArray1
+            ///     Mole numbers in the (unstable) feed state.
+            /// min_tp:
+            ///     The lower limit of the temperature/pressure range.
+            /// max_tp:
+            ///     The upper limit of the temperature/pressure range.
+            /// chunksize : int
+            ///     The number of points that are calculated in sequence
+            ///     within a thread.
+            /// nthreads : int
+            ///     Number of threads.
+            /// npoints: int, optional
+            ///     The number of points (default 51).
+            ///
+            /// Returns
+            /// -------
+            /// PhaseDiagram
+            #[cfg(feature = "rayon")]
+            #[staticmethod]
+            #[pyo3(text_signature = "(eos, temperature_or_pressure, feed, min_tp, max_tp, chunksize, nthreads, npoints=None)")]
+            pub fn par_lle(
+                eos: $py_eos,
+                temperature_or_pressure: PySINumber,
+                feed: PySIArray1,
+                min_tp: PySINumber,
+                max_tp: PySINumber,
+                chunksize: usize,
+                nthreads: usize,
+                npoints: Option<usize>,
+            ) -> EosResult<Self> {
+                let thread_pool = rayon::ThreadPoolBuilder::new()
+                    .num_threads(nthreads)
+                    .build()?;
+                let dia = PhaseDiagram::par_lle(
+                    &eos.0,
+                    temperature_or_pressure.into(),
+                    &feed,
+                    min_tp.into(),
+                    max_tp.into(),
+                    npoints,
+                    chunksize,
+                    thread_pool,
+                )?;
+                Ok(Self(dia))
+            }
         }
 
         /// Phase diagram for a binary mixture exhibiting a heteroazeotrope.
@@ -986,6 +1323,86 @@ macro_rules! impl_phase_equilibrium {
                 )?;
                 Ok(PyPhaseDiagramHetero(dia))
             }
+
+            /// Phase diagram for a binary mixture exhibiting a heteroazeotrope
+            /// calculated in parallel.
+            ///
+            /// Parameters
+            /// ----------
+            /// eos: SaftFunctional
+            ///     The SAFT Helmholtz energy functional.
+            /// temperature_or_pressure: SINumber
+            ///     The temperature_or_pressure.
+            /// x_lle: SINumber
+            ///     Initial values for the molefractions of component 1
+            ///     at the heteroazeotrop.
+            /// chunksize : int
+            ///     The number of points that are calculated in sequence
+            ///     within a thread.
+            /// nthreads : int
+            ///     Number of threads.
+            /// tp_lim_lle: SINumber, optional
+            ///     The minimum temperature up to which the LLE is calculated.
+            ///     If it is not provided, no LLE is calcualted.
+            /// tp_init_vlle: SINumber, optional
+            ///     Initial value for the calculation of the VLLE.
+            /// npoints_vle: int, optional
+            ///     The number of points for the VLE (default 51).
+            /// npoints_lle: int, optional
+            ///     The number of points for the LLE (default 51).
+            /// max_iter_inner : int, optional
+            ///     The maximum number of inner iterations in the bubble/dew point iteration.
+            /// max_iter_outer : int, optional
+            ///     The maximum number of outer iterations in the bubble/dew point iteration.
+            /// tol_inner : float, optional
+            ///     The solution tolerance in the inner loop of the bubble/dew point iteration.
+            /// tol_outer : float, optional
+            ///     The solution tolerance in the outer loop of the bubble/dew point iteration.
+            /// verbosity : Verbosity, optional
+            ///     The verbosity of the bubble/dew point iteration.
+            ///
+            /// Returns
+            /// -------
+            /// PhaseDiagramHetero
+            #[cfg(feature = "rayon")]
+            #[staticmethod]
+            #[pyo3(text_signature = "(eos, temperature_or_pressure, x_lle, chunksize, nthreads, tp_lim_lle=None, tp_init_vlle=None, npoints_vle=None, npoints_lle=None, max_iter_inner=None, max_iter_outer=None, tol_inner=None, tol_outer=None, verbosity=None)")]
+            pub fn par_binary_vlle(
+                eos: $py_eos,
+                temperature_or_pressure: PySINumber,
+                x_lle: (f64, f64),
+                chunksize: usize,
+                nthreads: usize,
+                tp_lim_lle: Option<PySINumber>,
+                tp_init_vlle: Option<PySINumber>,
+                npoints_vle: Option<usize>,
+                npoints_lle: Option<usize>,
+                max_iter_inner: Option<usize>,
+                max_iter_outer: Option<usize>,
+                tol_inner: Option<f64>,
+                tol_outer: Option<f64>,
+                verbosity: Option<Verbosity>,
+            ) -> EosResult<PyPhaseDiagramHetero> {
+                let thread_pool = rayon::ThreadPoolBuilder::new()
+                    .num_threads(nthreads)
+                    .build()?;
+                let dia = PhaseDiagram::par_binary_vlle(
+                    &eos.0,
+                    temperature_or_pressure.into(),
+                    x_lle,
+                    tp_lim_lle.map(|t| t.into()),
+                    tp_init_vlle.map(|t| t.into()),
+                    npoints_vle,
+                    npoints_lle,
+                    chunksize,
+                    thread_pool,
+                    (
+                        (max_iter_inner, tol_inner, verbosity).into(),
+                        (max_iter_outer, tol_outer, verbosity).into(),
+                    )
+                )?;
+                Ok(PyPhaseDiagramHetero(dia))
+            }
         }
 
         #[pymethods]
@@ -1013,5 +1430,89 @@ macro_rules! impl_phase_equilibrium {
                     .map(|d| PyPhaseDiagram(d.clone()))
             }
         }
+
+        /// Pure component phase diagram including the solid phase.
+        #[pyclass(name = "PhaseDiagramSolid")]
+        pub struct PyPhaseDiagramSolid(PhaseDiagramSolid<$eos>);
+
+        #[pymethods]
+        impl PyPhaseDiagram {
+            /// Calculate a pure component phase diagram including the
+            /// sublimation and melting curves.
+            ///
+            /// Parameters
+            /// ----------
+            /// eos: Eos
+            ///     The equation of state.
+            /// solid: SolidModel
+            ///     The model of the solid phase.
+            /// min_temperature: SINumber
+            ///     The lower limit for the temperature of the sublimation curve.
+            /// max_temperature: SINumber
+            ///     The upper limit for the temperature of the melting curve.
+            /// npoints: int
+            ///     The number of points of each curve.
+            /// critical_temperature: SINumber, optional
+            ///     An estimate for the critical temperature to initialize
+            ///     the calculation if necessary. For most components not necessary.
+            ///     Defaults to `None`.
+            /// max_iter : int, optional
+            ///     The maximum number of iterations.
+            /// tol: float, optional
+            ///     The solution tolerance.
+            /// verbosity : Verbosity, optional
+            ///     The verbosity.
+            ///
+            /// Returns
+            /// -------
+            /// PhaseDiagramSolid
+            #[staticmethod]
+            #[pyo3(text_signature = "(eos, solid, min_temperature, max_temperature, npoints, critical_temperature=None, max_iter=None, tol=None, verbosity=None)")]
+            pub fn pure_solid(
+                eos: &$py_eos,
+                solid: $crate::python::solid::PySolidModel,
+                min_temperature: PySINumber,
+                max_temperature: PySINumber,
+                npoints: usize,
+                critical_temperature: Option<PySINumber>,
+                max_iter: Option<usize>,
+                tol: Option<f64>,
+                verbosity: Option<Verbosity>,
+            ) -> PyResult<PyPhaseDiagramSolid> {
+                let dia = PhaseDiagram::pure_solid(
+                    &eos.0,
+                    &solid.0,
+                    min_temperature.into(),
+                    max_temperature.into(),
+                    npoints,
+                    critical_temperature.map(|t| t.into()),
+                    (max_iter, tol, verbosity).into(),
+                )?;
+                Ok(PyPhaseDiagramSolid(dia))
+            }
+        }
+
+        #[pymethods]
+        impl PyPhaseDiagramSolid {
+            #[getter]
+            pub fn get_triple_point(&self) -> PyPhaseEquilibrium {
+                PyPhaseEquilibrium(self.0.triple_point.clone())
+            }
+
+            #[getter]
+            pub fn get_vle(&self) -> PyPhaseDiagram {
+                PyPhaseDiagram(self.0.vle.clone())
+            }
+
+            #[getter]
+            pub fn get_sublimation(&self) -> PyStateVec {
+                self.0.sublimation().into()
+            }
+
+            #[getter]
+            pub fn get_melting(&self) -> PyStateVec {
+                self.0.melting().into()
+            }
+        }
     }
 }
@@ -0,0 +1,577 @@
+#[macro_export]
+macro_rules! impl_stream {
+    ($eos:ty, $py_eos:ty) => {
+        /// A material stream in thermodynamic equilibrium.
+        ///
+        /// A stream consists of one or two phases. All properties,
+        /// works and duties are given per amount of substance of the stream.
+        #[pyclass(name = "Stream")]
+        #[derive(Clone)]
+        pub struct PyStream(pub Stream<$eos>);
+
+        impl PyStream {
+            fn molefracs(
+                eos: &$py_eos,
+                molefracs: Option<&PyArray1<f64>>,
+            ) -> PyResult<ndarray::Array1<f64>> {
+                match molefracs {
+                    Some(x) => Ok(x.to_owned_array()),
+                    None if eos.0.components() == 1 => Ok(ndarray::Array1::ones(1)),
+                    None => Err(pyo3::exceptions::PyValueError::new_err(
+                        "Mole fractions have to be provided for mixtures.",
+                    )),
+                }
+            }
+        }
+
+        #[pymethods]
+        impl PyStream {
+            /// Create a stream for given temperature, pressure and composition.
+            ///
+            /// Parameters
+            /// ----------
+            /// eos : EquationOfState
+            ///     The equation of state.
+            /// temperature : SINumber
+            ///     The temperature.
+            /// pressure : SINumber
+            ///     The pressure.
+            /// molefracs : numpy.ndarray, optional
+            ///     The overall mole fractions. Can be omitted for pure components.
+            /// max_iter : int, optional
+            ///     The maximum number of iterations of the Tp-flash.
+            /// tol: float, optional
+            ///     The solution tolerance of the Tp-flash.
+            /// verbosity : Verbosity, optional
+            ///     The verbosity of the Tp-flash.
+            ///
+            /// Returns
+            /// -------
+            /// Stream
+            #[staticmethod]
+            #[pyo3(text_signature = "(eos, temperature, pressure, molefracs=None, max_iter=None, tol=None, verbosity=None)")]
+            pub fn tp(
+                eos: $py_eos,
+                temperature: PySINumber,
+                pressure: PySINumber,
+                molefracs: Option<&PyArray1<f64>>,
+                max_iter: Option<usize>,
+                tol: Option<f64>,
+                verbosity: Option<Verbosity>,
+            ) -> PyResult<Self> {
+                let x = Self::molefracs(&eos, molefracs)?;
+                Ok(Self(Stream::new_tp(
+                    &eos.0,
+                    temperature.into(),
+                    pressure.into(),
+                    &x,
+                    (max_iter, tol, verbosity).into(),
+                )?))
+            }
+
+            /// Create a stream for given pressure, molar enthalpy and composition.
+            ///
+            /// Parameters
+            /// ----------
+            /// eos : EquationOfState
+            ///     The equation of state.
+            /// pressure : SINumber
+            ///     The pressure.
+            /// molar_enthalpy : SINumber
+            ///     The molar enthalpy of the stream.
+            /// molefracs : numpy.ndarray, optional
+            ///     The overall mole fractions. Can be omitted for pure components.
+            /// initial_temperature : SINumber, optional
+            ///     The initial temperature.
+            /// max_iter : int, optional
+            ///     The maximum number of iterations.
+            /// tol: float, optional
+            ///     The solution tolerance.
+            /// verbosity : Verbosity, optional
+            ///     The verbosity.
+            ///
+            /// Returns
+            /// -------
+            /// Stream
+            #[staticmethod]
+            #[pyo3(text_signature = "(eos, pressure, molar_enthalpy, molefracs=None, initial_temperature=None, max_iter=None, tol=None, verbosity=None)")]
+            pub fn ph(
+                eos: $py_eos,
+                pressure: PySINumber,
+                molar_enthalpy: PySINumber,
+                molefracs: Option<&PyArray1<f64>>,
+                initial_temperature: Option<PySINumber>,
+                max_iter: Option<usize>,
+                tol: Option<f64>,
+                verbosity: Option<Verbosity>,
+            ) -> PyResult<Self> {
+                let x = Self::molefracs(&eos, molefracs)?;
+                Ok(Self(Stream::new_ph(
+                    &eos.0,
+                    pressure.into(),
+                    molar_enthalpy.into(),
+                    &x,
+                    initial_temperature.map(|t| t.into()),
+                    (max_iter, tol, verbosity).into(),
+                )?))
+            }
+
+            /// Create a stream for given pressure, molar entropy and composition.
+            ///
+            /// Parameters
+            /// ----------
+            /// eos : EquationOfState
+            ///     The equation of state.
+            /// pressure : SINumber
+            ///     The pressure.
+            /// molar_entropy : SINumber
+            ///     The molar entropy of the stream.
+            /// molefracs : numpy.ndarray, optional
+            ///     The overall mole fractions. Can be omitted for pure components.
+            /// initial_temperature : SINumber, optional
+            ///     The initial temperature.
+            /// max_iter : int, optional
+            ///     The maximum number of iterations.
+            /// tol: float, optional
+            ///     The solution tolerance.
+            /// verbosity : Verbosity, optional
+            ///     The verbosity.
+            ///
+            /// Returns
+            /// -------
+            /// Stream
+            #[staticmethod]
+            #[pyo3(text_signature = "(eos, pressure, molar_entropy, molefracs=None, initial_temperature=None, max_iter=None, tol=None, verbosity=None)")]
+            pub fn ps(
+                eos: $py_eos,
+                pressure: PySINumber,
+                molar_entropy: PySINumber,
+                molefracs: Option<&PyArray1<f64>>,
+                initial_temperature: Option<PySINumber>,
+                max_iter: Option<usize>,
+                tol: Option<f64>,
+                verbosity: Option<Verbosity>,
+            ) -> PyResult<Self> {
+                let x = Self::molefracs(&eos, molefracs)?;
+                Ok(Self(Stream::new_ps(
+                    &eos.0,
+                    pressure.into(),
+                    molar_entropy.into(),
+                    &x,
+                    initial_temperature.map(|t| t.into()),
+                    (max_iter, tol, verbosity).into(),
+                )?))
+            }
+
+            #[getter]
+            fn get_temperature(&self) -> PySINumber {
+                PySINumber::from(self.0.temperature)
+            }
+
+            #[getter]
+            fn get_pressure(&self) -> PySINumber {
+                PySINumber::from(self.0.pressure)
+            }
+
+            #[getter]
+            fn get_molefracs<'py>(&self, py: Python<'py>) -> &'py PyArray1<f64> {
+                self.0.molefracs.to_pyarray(py)
+            }
+
+            #[getter]
+            fn get_vapor_fraction(&self) -> Option<f64> {
+                self.0.vapor_fraction()
+            }
+
+            #[getter]
+            fn get_phases(&self) -> Vec<PyState> {
+                self.0.phases().iter().map(|s| PyState(s.clone())).collect()
+            }
+
+            #[getter]
+            fn get_phase_fractions(&self) -> Vec<f64> {
+                self.0.phase_fractions().to_vec()
+            }
+
+            /// Return molar enthalpy of the stream.
+            ///
+            /// Returns
+            /// -------
+            /// SINumber
+            fn molar_enthalpy(&self) -> PySINumber {
+                PySINumber::from(self.0.molar_enthalpy())
+            }
+
+            /// Return molar entropy of the stream.
+            ///
+            /// Returns
+            /// -------
+            /// SINumber
+            fn molar_entropy(&self) -> PySINumber {
+                PySINumber::from(self.0.molar_entropy())
+            }
+
+            /// Return molar volume of the stream.
+            ///
+            /// Returns
+            /// -------
+            /// SINumber
+            fn molar_volume(&self) -> PySINumber {
+                PySINumber::from(self.0.molar_volume())
+            }
+
+            /// Compress the stream to the given pressure.
+            ///
+            /// Parameters
+            /// ----------
+            /// pressure : SINumber
+            ///     The outlet pressure.
+            /// efficiency : float
+            ///     The efficiency of the compressor.
+            /// polytropic : bool, optional
+            ///     Whether `efficiency` is a polytropic (True)
+            ///     or an isentropic (False) efficiency. Defaults to False.
+            /// max_iter : int, optional
+            ///     The maximum number of iterations.
+            /// tol: float, optional
+            ///     The solution tolerance.
+            /// verbosity : Verbosity, optional
+            ///     The verbosity.
+            ///
+            /// Returns
+            /// -------
+            /// (Stream, SINumber)
+            ///     The outlet stream and the molar work.
+            #[pyo3(
+                signature = (pressure, efficiency, polytropic=false, max_iter=None, tol=None, verbosity=None),
+                text_signature = "($self, pressure, efficiency, polytropic=False, max_iter=None, tol=None, verbosity=None)"
+            )]
+            fn compressor(
+                &self,
+                pressure: PySINumber,
+                efficiency: f64,
+                polytropic: bool,
+                max_iter: Option<usize>,
+                tol: Option<f64>,
+                verbosity: Option<Verbosity>,
+            ) -> PyResult<(Self, PySINumber)> {
+                let efficiency = if polytropic {
+                    Efficiency::Polytropic(efficiency)
+                } else {
+                    Efficiency::Isentropic(efficiency)
+                };
+                let (stream, work) = self.0.compressor(
+                    pressure.into(),
+                    efficiency,
+                    (max_iter, tol, verbosity).into(),
+                )?;
+                Ok((Self(stream), PySINumber::from(work)))
+            }
+
+            /// Expand the stream in a turbine to the given pressure.
+            ///
+            /// Parameters
+            /// ----------
+            /// pressure : SINumber
+            ///     The outlet pressure.
+            /// efficiency : float
+            ///     The efficiency of the turbine.
+            /// polytropic : bool, optional
+            ///     Whether `efficiency` is a polytropic (True)
+            ///     or an isentropic (False) efficiency. Defaults to False.
+            /// max_iter : int, optional
+            ///     The maximum number of iterations.
+            /// tol: float, optional
+            ///     The solution tolerance.
+            /// verbosity : Verbosity, optional
+            ///     The verbosity.
+            ///
+            /// Returns
+            /// -------
+            /// (Stream, SINumber)
+            ///     The outlet stream and the (negative) molar work.
+            #[pyo3(
+                signature = (pressure, efficiency, polytropic=false, max_iter=None, tol=None, verbosity=None),
+                text_signature = "($self, pressure, efficiency, polytropic=False, max_iter=None, tol=None, verbosity=None)"
+            )]
+            fn turbine(
+                &self,
+                pressure: PySINumber,
+                efficiency: f64,
+                polytropic: bool,
+                max_iter: Option<usize>,
+                tol: Option<f64>,
+                verbosity: Option<Verbosity>,
+            ) -> PyResult<(Self, PySINumber)> {
+                let efficiency = if polytropic {
+                    Efficiency::Polytropic(efficiency)
+                } else {
+                    Efficiency::Isentropic(efficiency)
+                };
+                let (stream, work) = self.0.turbine(
+                    pressure.into(),
+                    efficiency,
+                    (max_iter, tol, verbosity).into(),
+                )?;
+                Ok((Self(stream), PySINumber::from(work)))
+            }
+
+            /// Throttle the stream in an isenthalpic valve to the given pressure.
+            ///
+            /// Parameters
+            /// ----------
+            /// pressure : SINumber
+            ///     The outlet pressure.
+            /// max_iter : int, optional
+            ///     The maximum number of iterations.
+            /// tol: float, optional
+            ///     The solution tolerance.
+            /// verbosity : Verbosity, optional
+            ///     The verbosity.
+            ///
+            /// Returns
+            /// -------
+            /// Stream
+            #[pyo3(text_signature = "($self, pressure, max_iter=None, tol=None, verbosity=None)")]
+            fn valve(
+                &self,
+                pressure: PySINumber,
+                max_iter: Option<usize>,
+                tol: Option<f64>,
+                verbosity: Option<Verbosity>,
+            ) -> PyResult<Self> {
+                Ok(Self(self.0.valve(
+                    pressure.into(),
+                    (max_iter, tol, verbosity).into(),
+                )?))
+            }
+
+            /// Heat or cool the stream at constant pressure to the given temperature.
+            ///
+            /// Parameters
+            /// ----------
+            /// temperature : SINumber
+            ///     The outlet temperature.
+            /// max_iter : int, optional
+            ///     The maximum number of iterations.
+            /// tol: float, optional
+            ///     The solution tolerance.
+            /// verbosity : Verbosity, optional
+            ///     The verbosity.
+            ///
+            /// Returns
+            /// -------
+            /// (Stream, SINumber)
+            ///     The outlet stream and the molar duty.
+            #[pyo3(text_signature = "($self, temperature, max_iter=None, tol=None, verbosity=None)")]
+            fn heater(
+                &self,
+                temperature: PySINumber,
+                max_iter: Option<usize>,
+                tol: Option<f64>,
+                verbosity: Option<Verbosity>,
+            ) -> PyResult<(Self, PySINumber)> {
+                let (stream, duty) = self
+                    .0
+                    .heater(temperature.into(), (max_iter, tol, verbosity).into())?;
+                Ok((Self(stream), PySINumber::from(duty)))
+            }
+
+            /// Heat or cool the stream at constant pressure with the given molar duty.
+            ///
+            /// Parameters
+            /// ----------
+            /// molar_duty : SINumber
+            ///     The molar duty (negative for coolers).
+            /// max_iter : int, optional
+            ///     The maximum number of iterations.
+            /// tol: float, optional
+            ///     The solution tolerance.
+            /// verbosity : Verbosity, optional
+            ///     The verbosity.
+            ///
+            /// Returns
+            /// -------
+            /// Stream
+            #[pyo3(text_signature = "($self, molar_duty, max_iter=None, tol=None, verbosity=None)")]
+            fn heater_duty(
+                &self,
+                molar_duty: PySINumber,
+                max_iter: Option<usize>,
+                tol: Option<f64>,
+                verbosity: Option<Verbosity>,
+            ) -> PyResult<Self> {
+                Ok(Self(self.0.heater_duty(
+                    molar_duty.into(),
+                    (max_iter, tol, verbosity).into(),
+                )?))
+            }
+
+            fn __repr__(&self) -> PyResult<String> {
+                Ok(self.0.to_string())
+            }
+        }
+
+        /// Streams along a line of constant pressure or temperature.
+        ///
+        /// For pure components, the saturated liquid and vapor are
+        /// inserted into the line if the saturation point is within
+        /// the given range.
+        #[pyclass(name = "Isoline")]
+        #[derive(Clone)]
+        pub struct PyIsoline(pub Isoline<$eos>);
+
+        #[pymethods]
+        impl PyIsoline {
+            /// Calculate streams at constant pressure for the given temperatures.
+            ///
+            /// Parameters
+            /// ----------
+            /// eos : EquationOfState
+            ///     The equation of state.
+            /// pressure : SINumber
+            ///     The pressure.
+            /// temperature : SIArray1
+            ///     The temperatures.
+            /// molefracs : numpy.ndarray, optional
+            ///     The overall mole fractions. Can be omitted for pure components.
+            /// max_iter : int, optional
+            ///     The maximum number of iterations.
+            /// tol: float, optional
+            ///     The solution tolerance.
+            /// verbosity : Verbosity, optional
+            ///     The verbosity.
+            ///
+            /// Returns
+            /// -------
+            /// Isoline
+            #[staticmethod]
+            #[pyo3(text_signature = "(eos, pressure, temperature, molefracs=None, max_iter=None, tol=None, verbosity=None)")]
+            pub fn isobar(
+                eos: $py_eos,
+                pressure: PySINumber,
+                temperature: PySIArray1,
+                molefracs: Option<&PyArray1<f64>>,
+                max_iter: Option<usize>,
+                tol: Option<f64>,
+                verbosity: Option<Verbosity>,
+            ) -> PyResult<Self> {
+                let x = PyStream::molefracs(&eos, molefracs)?;
+                Ok(Self(Isoline::isobar(
+                    &eos.0,
+                    pressure.into(),
+                    &temperature.into(),
+                    &x,
+                    (max_iter, tol, verbosity).into(),
+                )?))
+            }
+
+            /// Calculate streams at constant temperature for the given pressures.
+            ///
+            /// Parameters
+            /// ----------
+            /// eos : EquationOfState
+            ///     The equation of state.
+            /// temperature : SINumber
+            ///     The temperature.
+            /// pressure : SIArray1
+            ///     The pressures.
+            /// molefracs : numpy.ndarray, optional
+            ///     The overall mole fractions. Can be omitted for pure components.
+            /// max_iter : int, optional
+            ///     The maximum number of iterations.
+            /// tol: float, optional
+            ///     The solution tolerance.
+            /// verbosity : Verbosity, optional
+            ///     The verbosity.
+            ///
+            /// Returns
+            /// -------
+            /// Isoline
+            #[staticmethod]
+            #[pyo3(text_signature = "(eos, temperature, pressure, molefracs=None, max_iter=None, tol=None, verbosity=None)")]
+            pub fn isotherm(
+                eos: $py_eos,
+                temperature: PySINumber,
+                pressure: PySIArray1,
+                molefracs: Option<&PyArray1<f64>>,
+                max_iter: Option<usize>,
+                tol: Option<f64>,
+                verbosity: Option<Verbosity>,
+            ) -> PyResult<Self> {
+                let x = PyStream::molefracs(&eos, molefracs)?;
+                Ok(Self(Isoline::isotherm(
+                    &eos.0,
+                    temperature.into(),
+                    &pressure.into(),
+                    &x,
+                    (max_iter, tol, verbosity).into(),
+                )?))
+            }
+
+            #[getter]
+            fn get_streams(&self) -> Vec<PyStream> {
+                self.0.streams.iter().map(|s| PyStream(s.clone())).collect()
+            }
+
+            #[getter]
+            fn get_temperature(&self) -> PySIArray1 {
+                PySIArray1::from(self.0.temperature())
+            }
+
+            #[getter]
+            fn get_pressure(&self) -> PySIArray1 {
+                PySIArray1::from(self.0.pressure())
+            }
+
+            #[getter]
+            fn get_molar_volume(&self) -> PySIArray1 {
+                PySIArray1::from(self.0.molar_volume())
+            }
+
+            #[getter]
+            fn get_molar_enthalpy(&self) -> PySIArray1 {
+                PySIArray1::from(self.0.molar_enthalpy())
+            }
+
+            #[getter]
+            fn get_molar_entropy(&self) -> PySIArray1 {
+                PySIArray1::from(self.0.molar_entropy())
+            }
+
+            #[getter]
+            fn get_vapor_fraction<'py>(&self, py: Python<'py>) -> &'py PyArray1<f64> {
+                self.0.vapor_fraction().to_pyarray(py)
+            }
+
+            /// Returns the isoline as dictionary.
+            ///
+            /// Units
+            /// -----
+            /// temperature : K
+            /// pressure : Pa
+            /// molar volume : m³ / mol
+            /// molar enthalpy : kJ / mol
+            /// molar entropy : kJ / mol / K
+            ///
+            /// Returns
+            /// -------
+            /// dict[str, list[float]]
+            ///     Keys: property names. Values: property for each stream.
+            ///
+            /// Notes
+            /// -----
+            /// The vapor fraction of single-phase streams is NaN.
+            pub fn to_dict(&self) -> PyResult<HashMap<String, Vec<f64>>> {
+                let mut dict = HashMap::with_capacity(6);
+                dict.insert(String::from("temperature"), (self.0.temperature() / KELVIN).into_value()?.into_raw_vec());
+                dict.insert(String::from("pressure"), (self.0.pressure() / PASCAL).into_value()?.into_raw_vec());
+                dict.insert(String::from("molar volume"), (self.0.molar_volume() / (METER.powi(3) / MOL)).into_value()?.into_raw_vec());
+                dict.insert(String::from("molar enthalpy"), (self.0.molar_enthalpy() / (KILO*JOULE / MOL)).into_value()?.into_raw_vec());
+                dict.insert(String::from("molar entropy"), (self.0.molar_entropy() / (KILO*JOULE / KELVIN / MOL)).into_value()?.into_raw_vec());
+                dict.insert(String::from("vapor fraction"), self.0.vapor_fraction().into_raw_vec());
+                Ok(dict)
+            }
+        }
+    };
+}
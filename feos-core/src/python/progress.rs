@@ -0,0 +1,38 @@
+use crate::{Progress, ProgressCallback};
+use pyo3::exceptions::PyTypeError;
+use pyo3::prelude::*;
+
+/// Python callable that is used as [ProgressCallback].
+///
+/// The callable is called with the arguments `(task, iteration, residual)`.
+/// The calculation is cancelled if it returns `False` or raises an exception.
+#[derive(Clone)]
+pub struct PyProgressCallback(pub PyObject);
+
+impl<'source> FromPyObject<'source> for PyProgressCallback {
+    fn extract(ob: &'source PyAny) -> PyResult<Self> {
+        if !ob.is_callable() {
+            return Err(PyTypeError::new_err(
+                "The progress callback has to be callable with the signature\n\tdef callback(task: str, iteration: int, residual: float) -> bool",
+            ));
+        }
+        Ok(Self(ob.into()))
+    }
+}
+
+impl ProgressCallback for PyProgressCallback {
+    fn report(&self, progress: Progress) -> bool {
+        Python::with_gil(|py| {
+            match self
+                .0
+                .call1(py, (progress.task, progress.iteration, progress.residual))
+            {
+                Ok(result) => !matches!(result.extract::<bool>(py), Ok(false)),
+                Err(e) => {
+                    e.print(py);
+                    false
+                }
+            }
+        })
+    }
+}
@@ -0,0 +1,115 @@
+use crate::ReducedUnits;
+use pyo3::prelude::*;
+use quantity::python::PySINumber;
+
+/// Reduced (Lennard-Jones) units with k_B = 1.
+///
+/// Reduced properties are obtained by dividing SI quantities by
+/// the respective reference values, e.g.,
+/// `state.pressure() / units.pressure`.
+///
+/// Parameters
+/// ----------
+/// sigma : SINumber
+///     The reference length.
+/// epsilon_k : SINumber
+///     The reference energy divided by the Boltzmann constant.
+/// molarweight : SINumber, optional
+///     The molar mass, only required for the reduced time and
+///     transport properties.
+///
+/// Returns
+/// -------
+/// ReducedUnits
+#[pyclass(name = "ReducedUnits")]
+#[derive(Clone)]
+#[pyo3(text_signature = "(sigma, epsilon_k, molarweight=None)")]
+pub struct PyReducedUnits(pub ReducedUnits);
+
+#[pymethods]
+impl PyReducedUnits {
+    #[new]
+    fn new(sigma: PySINumber, epsilon_k: PySINumber, molarweight: Option<PySINumber>) -> Self {
+        let units = ReducedUnits::new(sigma.into(), epsilon_k.into());
+        Self(match molarweight {
+            Some(m) => units.molarweight(m.into()),
+            None => units,
+        })
+    }
+
+    /// Reference temperature.
+    #[getter]
+    fn get_temperature(&self) -> PySINumber {
+        self.0.temperature().into()
+    }
+
+    /// Reference length.
+    #[getter]
+    fn get_length(&self) -> PySINumber {
+        self.0.length().into()
+    }
+
+    /// Reference (molar) density.
+    #[getter]
+    fn get_density(&self) -> PySINumber {
+        self.0.density().into()
+    }
+
+    /// Reference pressure.
+    #[getter]
+    fn get_pressure(&self) -> PySINumber {
+        self.0.pressure().into()
+    }
+
+    /// Reference molar energy.
+    #[getter]
+    fn get_molar_energy(&self) -> PySINumber {
+        self.0.molar_energy().into()
+    }
+
+    /// Reference molar entropy.
+    #[getter]
+    fn get_molar_entropy(&self) -> PySINumber {
+        self.0.molar_entropy().into()
+    }
+
+    /// Reference surface tension.
+    #[getter]
+    fn get_surface_tension(&self) -> PySINumber {
+        self.0.surface_tension().into()
+    }
+
+    /// Reference time.
+    #[getter]
+    fn get_time(&self) -> PyResult<PySINumber> {
+        Ok(self.0.time()?.into())
+    }
+
+    /// Reference velocity.
+    #[getter]
+    fn get_velocity(&self) -> PyResult<PySINumber> {
+        Ok(self.0.velocity()?.into())
+    }
+
+    /// Reference viscosity.
+    #[getter]
+    fn get_viscosity(&self) -> PyResult<PySINumber> {
+        Ok(self.0.viscosity()?.into())
+    }
+
+    /// Reference self-diffusion coefficient.
+    #[getter]
+    fn get_diffusion(&self) -> PyResult<PySINumber> {
+        Ok(self.0.diffusion()?.into())
+    }
+
+    /// Reference thermal conductivity.
+    #[getter]
+    fn get_thermal_conductivity(&self) -> PyResult<PySINumber> {
+        Ok(self.0.thermal_conductivity()?.into())
+    }
+
+    fn __repr__(&self) -> PyResult<String> {
+        Ok(self.0.to_string())
+    }
+}
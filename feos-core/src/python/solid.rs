@@ -0,0 +1,69 @@
+use crate::phase_equilibria::SolidModel;
+use pyo3::prelude::*;
+use quantity::python::PySINumber;
+
+/// Simple model of the solid phase of a pure component.
+///
+/// The molar Gibbs energy of the solid is calculated relative to the
+/// (metastable) liquid of the equation of state at the triple point
+/// pressure.
+///
+/// Parameters
+/// ----------
+/// triple_temperature : SINumber
+///     The triple point temperature.
+/// melting_enthalpy : SINumber
+///     The molar melting enthalpy at the triple point.
+/// molar_volume : SINumber
+///     The molar volume of the solid.
+/// heat_capacity_difference : SINumber, optional
+///     The molar isobaric heat capacity of the liquid minus that of
+///     the solid. Defaults to 0.
+///
+/// Returns
+/// -------
+/// SolidModel
+#[pyclass(name = "SolidModel")]
+#[derive(Clone)]
+#[pyo3(
+    text_signature = "(triple_temperature, melting_enthalpy, molar_volume, heat_capacity_difference=None)"
+)]
+pub struct PySolidModel(pub SolidModel);
+
+#[pymethods]
+impl PySolidModel {
+    #[new]
+    fn new(
+        triple_temperature: PySINumber,
+        melting_enthalpy: PySINumber,
+        molar_volume: PySINumber,
+        heat_capacity_difference: Option<PySINumber>,
+    ) -> Self {
+        Self(SolidModel::new(
+            triple_temperature.into(),
+            melting_enthalpy.into(),
+            molar_volume.into(),
+            heat_capacity_difference.map(|c| c.into()),
+        ))
+    }
+
+    #[getter]
+    fn get_triple_temperature(&self) -> PySINumber {
+        self.0.triple_temperature.into()
+    }
+
+    #[getter]
+    fn get_melting_enthalpy(&self) -> PySINumber {
+        self.0.melting_enthalpy.into()
+    }
+
+    #[getter]
+    fn get_molar_volume(&self) -> PySINumber {
+        self.0.molar_volume.into()
+    }
+
+    #[getter]
+    fn get_heat_capacity_difference(&self) -> PySINumber {
+        self.0.heat_capacity_difference.into()
+    }
+}
@@ -0,0 +1,54 @@
+use crate::SolverLog;
+use numpy::{IntoPyArray, PyArray1};
+use pyo3::prelude::*;
+
+/// Structured history of an iterative solver.
+///
+/// The log is attached to the results of calculations,
+/// e.g., `State.solver_log` or `PhaseEquilibrium.solver_log`.
+#[pyclass(name = "SolverLog")]
+#[derive(Clone)]
+pub struct PySolverLog(pub SolverLog);
+
+#[pymethods]
+impl PySolverLog {
+    /// The name of the solver.
+    #[getter]
+    fn get_solver(&self) -> String {
+        self.0.solver.clone()
+    }
+
+    /// Whether the solver converged.
+    #[getter]
+    fn get_converged(&self) -> bool {
+        self.0.converged
+    }
+
+    /// The number of iterations.
+    #[getter]
+    fn get_iterations(&self) -> usize {
+        self.0.len()
+    }
+
+    /// The residuals of all iterations.
+    #[getter]
+    fn get_residual<'py>(&self, py: Python<'py>) -> &'py PyArray1<f64> {
+        self.0.residual().into_pyarray(py)
+    }
+
+    /// The step sizes of all iterations (NaN if not available).
+    #[getter]
+    fn get_step_size<'py>(&self, py: Python<'py>) -> &'py PyArray1<f64> {
+        self.0.step_size().into_pyarray(py)
+    }
+
+    /// The damping factors of all iterations (NaN if not available).
+    #[getter]
+    fn get_damping<'py>(&self, py: Python<'py>) -> &'py PyArray1<f64> {
+        self.0.damping().into_pyarray(py)
+    }
+
+    fn __repr__(&self) -> PyResult<String> {
+        Ok(self.0.to_string())
+    }
+}
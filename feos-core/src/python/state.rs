@@ -37,6 +37,31 @@ macro_rules! impl_state {
         /// initial_temperature : SINumber, optional
         ///     Initial temperature for temperature iteration. Can improve convergence
         ///     when the state is specified with pressure and molar entropy or enthalpy.
+        /// volume_flow : SINumber, optional
+        ///     Volume flow.
+        /// molar_flow : SIArray1, optional
+        ///     Molar flow of each component.
+        /// mass_density : SINumber, optional
+        ///     Mass density.
+        /// total_mass : SINumber, optional
+        ///     Total mass (of a mixture).
+        /// mass : SIArray1, optional
+        ///     Mass of each component.
+        /// massfracs : numpy.ndarray[float], optional
+        ///     Mass fraction of each component.
+        /// total_mass_flow : SINumber, optional
+        ///     Total mass flow (of a mixture).
+        /// mass_flow : SIArray1, optional
+        ///     Mass flow of each component.
+        /// specific_enthalpy : SINumber, optional
+        ///     Specific enthalpy.
+        /// specific_entropy : SINumber, optional
+        ///     Specific entropy.
+        /// specific_internal_energy : SINumber, optional
+        ///     Specific internal energy.
+        ///
+        /// Mass-based properties are converted using the molar weights of the components.
+        /// Flows are converted to the amount of material that passes in one second.
         ///
         /// Returns
         /// -------
@@ -48,7 +73,7 @@ macro_rules! impl_state {
         ///     When the state cannot be created using the combination of input.
         #[pyclass(name = "State")]
         #[derive(Clone)]
-        #[pyo3(text_signature = "(eos, temperature=None, volume=None, density=None, partial_density=None, total_moles=None, moles=None, molefracs=None, pressure=None, molar_enthalpy=None, molar_entropy=None, molar_internal_energy=None, density_initialization=None, initial_temperature=None)")]
+        #[pyo3(text_signature = "(eos, temperature=None, volume=None, density=None, partial_density=None, total_moles=None, moles=None, molefracs=None, pressure=None, molar_enthalpy=None, molar_entropy=None, molar_internal_energy=None, density_initialization=None, initial_temperature=None, volume_flow=None, molar_flow=None, mass_density=None, total_mass=None, mass=None, massfracs=None, total_mass_flow=None, mass_flow=None, specific_enthalpy=None, specific_entropy=None, specific_internal_energy=None)")]
         pub struct PyState(pub State<$eos>);
 
         #[pymethods]
@@ -69,8 +94,20 @@ macro_rules! impl_state {
                 molar_internal_energy: Option<PySINumber>,
                 density_initialization: Option<&PyAny>,
                 initial_temperature: Option<PySINumber>,
+                volume_flow: Option<PySINumber>,
+                molar_flow: Option<PySIArray1>,
+                mass_density: Option<PySINumber>,
+                total_mass: Option<PySINumber>,
+                mass: Option<PySIArray1>,
+                massfracs: Option<&PyArray1<f64>>,
+                total_mass_flow: Option<PySINumber>,
+                mass_flow: Option<PySIArray1>,
+                specific_enthalpy: Option<PySINumber>,
+                specific_entropy: Option<PySINumber>,
+                specific_internal_energy: Option<PySINumber>,
             ) -> PyResult<Self> {
                 let x = molefracs.and_then(|m| Some(m.to_owned_array()));
+                let w = massfracs.map(|m| m.to_owned_array());
                 let density_init = if let Some(di) = density_initialization {
                     if let Ok(d) = di.extract::<&str>() {
                         match d {
@@ -90,23 +127,121 @@ macro_rules! impl_state {
                 } else {
                     Ok(DensityInitialization::None)
                 };
-                let s = State::new(
+                let mut builder = StateBuilder::new(&eos.0);
+                builder = match density_init? {
+                    DensityInitialization::Vapor => builder.vapor(),
+                    DensityInitialization::Liquid => builder.liquid(),
+                    DensityInitialization::InitialDensity(d) => builder.initial_density(d),
+                    DensityInitialization::None => builder,
+                };
+                if let Some(temperature) = temperature {
+                    builder = builder.temperature(temperature.into());
+                }
+                if let Some(volume) = volume {
+                    builder = builder.volume(volume.into());
+                }
+                if let Some(density) = density {
+                    builder = builder.density(density.into());
+                }
+                if let Some(partial_density) = partial_density.as_deref() {
+                    builder = builder.partial_density(partial_density);
+                }
+                if let Some(total_moles) = total_moles {
+                    builder = builder.total_moles(total_moles.into());
+                }
+                if let Some(moles) = moles.as_deref() {
+                    builder = builder.moles(moles);
+                }
+                if let Some(molefracs) = x.as_ref() {
+                    builder = builder.molefracs(molefracs);
+                }
+                if let Some(pressure) = pressure {
+                    builder = builder.pressure(pressure.into());
+                }
+                if let Some(molar_enthalpy) = molar_enthalpy {
+                    builder = builder.molar_enthalpy(molar_enthalpy.into());
+                }
+                if let Some(molar_entropy) = molar_entropy {
+                    builder = builder.molar_entropy(molar_entropy.into());
+                }
+                if let Some(molar_internal_energy) = molar_internal_energy {
+                    builder = builder.molar_internal_energy(molar_internal_energy.into());
+                }
+                if let Some(initial_temperature) = initial_temperature {
+                    builder = builder.initial_temperature(initial_temperature.into());
+                }
+                if let Some(volume_flow) = volume_flow {
+                    builder = builder.volume_flow(volume_flow.into());
+                }
+                if let Some(molar_flow) = molar_flow.as_deref() {
+                    builder = builder.molar_flow(molar_flow);
+                }
+                if let Some(mass_density) = mass_density {
+                    builder = builder.mass_density(mass_density.into());
+                }
+                if let Some(total_mass) = total_mass {
+                    builder = builder.total_mass(total_mass.into());
+                }
+                if let Some(mass) = mass.as_deref() {
+                    builder = builder.mass(mass);
+                }
+                if let Some(massfracs) = w.as_ref() {
+                    builder = builder.massfracs(massfracs);
+                }
+                if let Some(total_mass_flow) = total_mass_flow {
+                    builder = builder.total_mass_flow(total_mass_flow.into());
+                }
+                if let Some(mass_flow) = mass_flow.as_deref() {
+                    builder = builder.mass_flow(mass_flow);
+                }
+                if let Some(specific_enthalpy) = specific_enthalpy {
+                    builder = builder.specific_enthalpy(specific_enthalpy.into());
+                }
+                if let Some(specific_entropy) = specific_entropy {
+                    builder = builder.specific_entropy(specific_entropy.into());
+                }
+                if let Some(specific_internal_energy) = specific_internal_energy {
+                    builder = builder.specific_internal_energy(specific_internal_energy.into());
+                }
+                Ok(Self(builder.build()?))
+            }
+
+            /// Create a thermodynamic state from a reduced temperature and
+            /// a reduced density.
+            ///
+            /// Parameters
+            /// ----------
+            /// eos: EquationOfState
+            ///     The equation of state to use.
+            /// units: ReducedUnits
+            ///     The reduced units.
+            /// temperature: float
+            ///     The reduced temperature.
+            /// density: float
+            ///     The reduced density.
+            /// molefracs: numpy.ndarray[float], optional
+            ///     The mole fractions. Only optional for a pure component.
+            ///
+            /// Returns
+            /// -------
+            /// State
+            #[staticmethod]
+            #[pyo3(text_signature = "(eos, units, temperature, density, molefracs=None)")]
+            fn new_reduced(
+                eos: $py_eos,
+                units: $crate::python::reduced_units::PyReducedUnits,
+                temperature: f64,
+                density: f64,
+                molefracs: Option<&PyArray1<f64>>,
+            ) -> PyResult<Self> {
+                let x = molefracs.map(|m| m.to_owned_array());
+                Ok(Self(State::new_reduced(
                     &eos.0,
-                    temperature.map(|t| t.into()),
-                    volume.map(|t| t.into()),
-                    density.map(|s| s.into()),
-                    partial_density.as_deref(),
-                    total_moles.map(|s| s.into()),
-                    moles.as_deref(),
+                    &units.0,
+                    temperature,
+                    density,
                     x.as_ref(),
-                    pressure.map(|s| s.into()),
-                    molar_enthalpy.map(|s| s.into()),
-                    molar_entropy.map(|s| s.into()),
-                    molar_internal_energy.map(|s| s.into()),
-                    density_init?,
-                    initial_temperature.map(|s| s.into()),
-                )?;
-                Ok(Self(s))
+                )?))
             }
 
             /// Return a list of thermodynamic state at critical conditions
@@ -181,6 +316,52 @@ macro_rules! impl_state {
                 )?))
             }
 
+            /// Create a thermodynamic state at critical conditions using a
+            /// globalized solver that does not require an initial guess.
+            ///
+            /// The solver is initialized with the pseudo-critical point of
+            /// the ideal mixture of the pure components. For a pure component,
+            /// the initial point is estimated from the mechanically unstable
+            /// region of the equation of state.
+            ///
+            /// Parameters
+            /// ----------
+            /// eos: EquationOfState
+            ///     The equation of state to use.
+            /// moles: SIArray1, optional
+            ///     Amount of substance of each component.
+            ///     Only optional for a pure component.
+            /// homotopy: bool, optional
+            ///     Use a Newton homotopy starting from the pseudo-critical
+            ///     point if the trust-region solver fails. Defaults to False.
+            /// max_iter : int, optional
+            ///     The maximum number of iterations.
+            /// tol: float, optional
+            ///     The solution tolerance.
+            /// verbosity : Verbosity, optional
+            ///     The verbosity.
+            ///
+            /// Returns
+            /// -------
+            /// State : State at critical conditions.
+            #[staticmethod]
+            #[pyo3(text_signature = "(eos, moles=None, homotopy=False, max_iter=None, tol=None, verbosity=None)")]
+            fn critical_point_global(
+                eos: $py_eos,
+                moles: Option<PySIArray1>,
+                homotopy: Option<bool>,
+                max_iter: Option<usize>,
+                tol: Option<f64>,
+                verbosity: Option<Verbosity>,
+            ) -> PyResult<Self> {
+                Ok(PyState(State::critical_point_global(
+                    &eos.0,
+                    moles.as_deref(),
+                    homotopy.unwrap_or(false),
+                    (max_iter, tol, verbosity).into(),
+                )?))
+            }
+
             /// Create a thermodynamic state at critical conditions for a binary system.
             ///
             /// Parameters
@@ -568,6 +749,71 @@ macro_rules! impl_state {
                 Ok(self.0.ln_symmetric_activity_coefficient()?.view().to_pyarray(py))
             }
 
+            /// Return logarithmic fugacity coefficient of a solute at infinite dilution.
+            ///
+            /// The solute is removed from the state at constant temperature and pressure.
+            ///
+            /// Parameters
+            /// ----------
+            /// solute : int
+            ///     The index of the solute.
+            ///
+            /// Returns
+            /// -------
+            /// float
+            #[pyo3(text_signature = "($self, solute)")]
+            fn ln_phi_infinite_dilution(&self, solute: usize) -> PyResult<f64> {
+                Ok(self.0.ln_phi_infinite_dilution(solute)?)
+            }
+
+            /// Return Henry's law constant of a solute.
+            ///
+            /// The solute is removed from the state at constant temperature and pressure.
+            ///
+            /// Parameters
+            /// ----------
+            /// solute : int
+            ///     The index of the solute.
+            ///
+            /// Returns
+            /// -------
+            /// SINumber
+            #[pyo3(text_signature = "($self, solute)")]
+            fn henry_constant(&self, solute: usize) -> PyResult<PySINumber> {
+                Ok(PySINumber::from(self.0.henry_constant(solute)?))
+            }
+
+            /// Return logarithmic activity coefficient of a solute at infinite dilution
+            /// with the pure liquid solute as reference state.
+            ///
+            /// Parameters
+            /// ----------
+            /// solute : int
+            ///     The index of the solute.
+            ///
+            /// Returns
+            /// -------
+            /// float
+            #[pyo3(text_signature = "($self, solute)")]
+            fn ln_activity_coefficient_infinite_dilution(&self, solute: usize) -> PyResult<f64> {
+                Ok(self.0.ln_activity_coefficient_infinite_dilution(solute)?)
+            }
+
+            /// Return partial molar volume of a solute at infinite dilution.
+            ///
+            /// Parameters
+            /// ----------
+            /// solute : int
+            ///     The index of the solute.
+            ///
+            /// Returns
+            /// -------
+            /// SINumber
+            #[pyo3(text_signature = "($self, solute)")]
+            fn partial_molar_volume_infinite_dilution(&self, solute: usize) -> PyResult<PySINumber> {
+                Ok(PySINumber::from(self.0.partial_molar_volume_infinite_dilution(solute)?))
+            }
+
             /// Return derivative of logarithmic fugacity coefficient w.r.t. temperature.
             ///
             /// Returns
@@ -930,6 +1176,34 @@ macro_rules! impl_state {
                 self.0.grueneisen_parameter()
             }
 
+            /// Return molar flow of each component, assuming the state
+            /// contains the material that passes in one second.
+            ///
+            /// Returns
+            /// -------
+            /// SIArray1
+            fn molar_flow(&self) -> PySIArray1 {
+                PySIArray1::from(self.0.molar_flow())
+            }
+
+            /// Return total molar flow.
+            ///
+            /// Returns
+            /// -------
+            /// SINumber
+            fn total_molar_flow(&self) -> PySINumber {
+                PySINumber::from(self.0.total_molar_flow())
+            }
+
+            /// Return volume flow.
+            ///
+            /// Returns
+            /// -------
+            /// SINumber
+            fn volume_flow(&self) -> PySINumber {
+                PySINumber::from(self.0.volume_flow())
+            }
+
             /// Return structure factor.
             ///
             /// Returns
@@ -939,6 +1213,13 @@ macro_rules! impl_state {
                 self.0.structure_factor()
             }
 
+            /// The log of the solver that determined the state,
+            /// e.g., the density iteration, if available.
+            #[getter]
+            fn get_solver_log(&self) -> Option<$crate::python::solver_log::PySolverLog> {
+                self.0.solver_log().cloned().map($crate::python::solver_log::PySolverLog)
+            }
+
             #[getter]
             fn get_total_moles(&self) -> PySINumber {
                 PySINumber::from(self.0.total_moles)
@@ -991,6 +1272,37 @@ macro_rules! impl_state {
             fn __repr__(&self) -> PyResult<String> {
                 Ok(self.0.to_string())
             }
+
+            /// Creates a state from a json string created with `to_json_str`.
+            ///
+            /// Parameters
+            /// ----------
+            /// json : str
+            ///     The json string containing the state.
+            ///
+            /// Returns
+            /// -------
+            /// State
+            #[staticmethod]
+            #[pyo3(text_signature = "(json)")]
+            fn from_json_str(json: &str) -> Result<Self, $crate::parameter::ParameterError> {
+                Ok(Self(serde_json::from_str(json)?))
+            }
+
+            /// Creates a json string containing the equation of state,
+            /// temperature, volume and amount of substance of the state.
+            ///
+            /// Returns
+            /// -------
+            /// str
+            fn to_json_str(&self) -> Result<String, $crate::parameter::ParameterError> {
+                Ok(serde_json::to_string(&self.0)?)
+            }
+
+            fn __reduce__(&self, py: Python<'_>) -> PyResult<(PyObject, (String,))> {
+                let from_json_str = py.get_type::<Self>().getattr("from_json_str")?;
+                Ok((from_json_str.into(), (self.to_json_str()?,)))
+            }
         }
 
 
@@ -1027,6 +1339,70 @@ macro_rules! impl_state {
                 Self(states.into_iter().map(|s| s.0).collect())
             }
 
+            /// Create states for every pair of temperature and pressure
+            /// using density iterations.
+            ///
+            /// Parameters
+            /// ----------
+            /// eos : Eos
+            ///     The equation of state to use.
+            /// temperature : SIArray1
+            ///     The temperatures of the states.
+            /// pressure : SIArray1
+            ///     The pressures of the states.
+            /// moles : SIArray1, optional
+            ///     Amount of substance for each component. Only optional
+            ///     for pure components.
+            /// density_initialization : {'vapor', 'liquid', None}, optional
+            ///     Method used to initialize density for density iteration.
+            ///     If no keyword is provided, the vapor and liquid phase is tested and, if
+            ///     different, the result with the lower free energy is returned.
+            ///
+            /// Returns
+            /// -------
+            /// StateVec
+            ///
+            /// Raises
+            /// ------
+            /// Error
+            ///     When any of the states cannot be created.
+            #[staticmethod]
+            #[pyo3(text_signature = "(eos, temperature, pressure, moles=None, density_initialization=None)")]
+            fn from_npt(
+                eos: $py_eos,
+                temperature: PySIArray1,
+                pressure: PySIArray1,
+                moles: Option<PySIArray1>,
+                density_initialization: Option<&str>,
+            ) -> PyResult<Self> {
+                let density_initialization = match density_initialization {
+                    Some("vapor") => DensityInitialization::Vapor,
+                    Some("liquid") => DensityInitialization::Liquid,
+                    None => DensityInitialization::None,
+                    _ => {
+                        return Err(PyErr::new::<PyValueError, _>(
+                            "`density_initialization` must be 'vapor' or 'liquid'.",
+                        ))
+                    }
+                };
+                let moles: SIArray1 = match moles {
+                    Some(moles) => moles.into(),
+                    None if eos.0.components() == 1 => ndarray::arr1(&[1.0]) * MOL,
+                    None => {
+                        return Err(PyErr::new::<PyValueError, _>(
+                            "`moles` must be provided for mixtures.",
+                        ))
+                    }
+                };
+                Ok(Self(State::new_npt_vec(
+                    &eos.0,
+                    &temperature,
+                    &pressure,
+                    &moles,
+                    density_initialization,
+                )?))
+            }
+
             fn __len__(&self) -> PyResult<usize> {
                 Ok(self.0.len())
             }
@@ -1083,6 +1459,46 @@ macro_rules! impl_state {
             fn get_molar_entropy(&self) -> PySIArray1 {
                 StateVec::from(self).molar_entropy().into()
             }
+
+            #[getter]
+            fn get_molar_internal_energy(&self) -> PySIArray1 {
+                StateVec::from(self).molar_internal_energy().into()
+            }
+
+            #[getter]
+            fn get_molar_helmholtz_energy(&self) -> PySIArray1 {
+                StateVec::from(self).molar_helmholtz_energy().into()
+            }
+
+            #[getter]
+            fn get_molar_gibbs_energy(&self) -> PySIArray1 {
+                StateVec::from(self).molar_gibbs_energy().into()
+            }
+
+            #[getter]
+            fn get_c_v(&self) -> PySIArray1 {
+                StateVec::from(self).c_v().into()
+            }
+
+            #[getter]
+            fn get_c_p(&self) -> PySIArray1 {
+                StateVec::from(self).c_p().into()
+            }
+
+            #[getter]
+            fn get_joule_thomson(&self) -> PySIArray1 {
+                StateVec::from(self).joule_thomson().into()
+            }
+
+            #[getter]
+            fn get_isentropic_compressibility(&self) -> PySIArray1 {
+                StateVec::from(self).isentropic_compressibility().into()
+            }
+
+            #[getter]
+            fn get_isothermal_compressibility(&self) -> PySIArray1 {
+                StateVec::from(self).isothermal_compressibility().into()
+            }
         }
     };
 }
@@ -1128,6 +1544,24 @@ macro_rules! impl_state_molarweight {
                 PySINumber::from(self.0.total_mass())
             }
 
+            /// Returns mass flow of each component.
+            ///
+            /// Returns
+            /// -------
+            /// SIArray1
+            fn mass_flow(&self) -> PySIArray1 {
+                PySIArray1::from(self.0.mass_flow())
+            }
+
+            /// Returns system's total mass flow.
+            ///
+            /// Returns
+            /// -------
+            /// SINumber
+            fn total_mass_flow(&self) -> PySINumber {
+                PySINumber::from(self.0.total_mass_flow())
+            }
+
             /// Returns system's mass density.
             ///
             /// Returns
@@ -1248,6 +1682,11 @@ macro_rules! impl_state_molarweight {
             fn get_specific_entropy(&self) -> PySIArray1 {
                 StateVec::from(self).specific_entropy().into()
             }
+
+            #[getter]
+            fn get_speed_of_sound(&self) -> PySIArray1 {
+                StateVec::from(self).speed_of_sound().into()
+            }
         }
     };
 }
@@ -1347,5 +1786,18 @@ macro_rules! impl_state_entropy_scaling {
                 Ok(self.0.ln_thermal_conductivity_reduced()?)
             }
         }
+
+        #[pymethods]
+        impl PyStateVec {
+            #[getter]
+            fn get_viscosity(&self) -> PyResult<PySIArray1> {
+                Ok(StateVec::from(self).viscosity()?.into())
+            }
+
+            #[getter]
+            fn get_thermal_conductivity(&self) -> PyResult<PySIArray1> {
+                Ok(StateVec::from(self).thermal_conductivity()?.into())
+            }
+        }
     };
 }
@@ -0,0 +1,188 @@
+//! Reduced (Lennard-Jones) units for the comparison with molecular simulations.
+use crate::equation_of_state::EquationOfState;
+use crate::errors::{EosError, EosResult};
+use crate::state::State;
+use ndarray::{arr1, Array1};
+use quantity::si::{SINumber, KB, MOL, NAV, RGAS};
+use std::fmt;
+use std::sync::Arc;
+
+/// Reduced units based on a length $\sigma$, an energy $\varepsilon$ and,
+/// optionally, a molar mass $M$ with $k_\mathrm{B}=1$.
+///
+/// The methods return the reference values of the respective properties,
+/// i.e., a reduced property is obtained by dividing the SI quantity by the
+/// reference value and vice versa, e.g.,
+/// $T^*=\frac{k_\mathrm{B}T}{\varepsilon}$, $\rho^*=\rho N_\mathrm{A}\sigma^3$ or
+/// $p^*=\frac{p\sigma^3}{\varepsilon}$. Molar properties are reduced per particle.
+///
+/// Because equations of state and Helmholtz energy functionals are always
+/// evaluated in SI units, the reference values can be used for the in- and
+/// output of all calculations, including DFT calculations, e.g.,
+/// $\gamma^*=\frac{\gamma\sigma^2}{\varepsilon}$ for surface tensions.
+#[derive(Clone, Copy, Debug)]
+pub struct ReducedUnits {
+    /// The length $\sigma$.
+    pub sigma: SINumber,
+    /// The energy $\varepsilon/k_\mathrm{B}$ in units of temperature.
+    pub epsilon_k: SINumber,
+    /// The molar mass $M$, only required for transport properties.
+    pub molarweight: Option<SINumber>,
+}
+
+impl ReducedUnits {
+    /// Reduced units for the given $\sigma$ and $\varepsilon/k_\mathrm{B}$.
+    pub fn new(sigma: SINumber, epsilon_k: SINumber) -> Self {
+        Self {
+            sigma,
+            epsilon_k,
+            molarweight: None,
+        }
+    }
+
+    /// Add the molar mass that is required for the reduced time
+    /// and transport properties.
+    pub fn molarweight(mut self, molarweight: SINumber) -> Self {
+        self.molarweight = Some(molarweight);
+        self
+    }
+
+    /// Reference temperature $\varepsilon/k_\mathrm{B}$.
+    pub fn temperature(&self) -> SINumber {
+        self.epsilon_k
+    }
+
+    /// Reference length $\sigma$.
+    pub fn length(&self) -> SINumber {
+        self.sigma
+    }
+
+    /// Reference (molar) density $\frac{1}{N_\mathrm{A}\sigma^3}$.
+    pub fn density(&self) -> SINumber {
+        1.0 / (NAV * self.sigma.powi(3))
+    }
+
+    /// Reference pressure $\frac{\varepsilon}{\sigma^3}$.
+    pub fn pressure(&self) -> SINumber {
+        KB * self.epsilon_k / self.sigma.powi(3)
+    }
+
+    /// Reference molar energy $N_\mathrm{A}\varepsilon$, e.g., for
+    /// chemical potentials or molar enthalpies.
+    pub fn molar_energy(&self) -> SINumber {
+        RGAS * self.epsilon_k
+    }
+
+    /// Reference molar entropy $N_\mathrm{A}k_\mathrm{B}$, e.g., for
+    /// molar entropies or heat capacities.
+    pub fn molar_entropy(&self) -> SINumber {
+        RGAS
+    }
+
+    /// Reference surface tension $\frac{\varepsilon}{\sigma^2}$.
+    pub fn surface_tension(&self) -> SINumber {
+        KB * self.epsilon_k / self.sigma.powi(2)
+    }
+
+    /// Reference time $\sigma\sqrt{\frac{m}{\varepsilon}}$.
+    pub fn time(&self) -> EosResult<SINumber> {
+        let molarweight = self.molarweight.ok_or_else(|| {
+            EosError::Error(String::from(
+                "The reduced time requires the molar weight of the reduced units.",
+            ))
+        })?;
+        Ok(self.sigma * (molarweight / (RGAS * self.epsilon_k)).sqrt()?)
+    }
+
+    /// Reference velocity $\sqrt{\frac{\varepsilon}{m}}$, e.g., for speeds of sound.
+    pub fn velocity(&self) -> EosResult<SINumber> {
+        Ok(self.sigma / self.time()?)
+    }
+
+    /// Reference viscosity $\frac{\sqrt{m\varepsilon}}{\sigma^2}$.
+    pub fn viscosity(&self) -> EosResult<SINumber> {
+        Ok(KB * self.epsilon_k * self.time()? / self.sigma.powi(3))
+    }
+
+    /// Reference self-diffusion coefficient $\sigma\sqrt{\frac{\varepsilon}{m}}$.
+    pub fn diffusion(&self) -> EosResult<SINumber> {
+        Ok(self.sigma.powi(2) / self.time()?)
+    }
+
+    /// Reference thermal conductivity $\frac{k_\mathrm{B}}{\sigma^2}\sqrt{\frac{\varepsilon}{m}}$.
+    pub fn thermal_conductivity(&self) -> EosResult<SINumber> {
+        Ok(KB / (self.sigma * self.time()?))
+    }
+}
+
+impl fmt::Display for ReducedUnits {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "ReducedUnits(sigma={}, epsilon_k={}",
+            self.sigma, self.epsilon_k
+        )?;
+        if let Some(molarweight) = self.molarweight {
+            write!(f, ", molarweight={}", molarweight)?;
+        }
+        write!(f, ")")
+    }
+}
+
+impl<E: EquationOfState> State<E> {
+    /// Return a new `State` for the given reduced temperature $T^*$,
+    /// reduced density $\rho^*$ and mole fractions.
+    ///
+    /// The mole fractions are only optional for a pure component.
+    pub fn new_reduced(
+        eos: &Arc<E>,
+        units: &ReducedUnits,
+        temperature: f64,
+        density: f64,
+        molefracs: Option<&Array1<f64>>,
+    ) -> EosResult<Self> {
+        let x = molefracs.map_or_else(|| arr1(&[1.0]), |x| x / x.sum());
+        let moles = eos.validate_moles(Some(&(x * MOL)))?;
+        State::new_nvt(
+            eos,
+            temperature * units.temperature(),
+            moles.sum() / (density * units.density()),
+            &moles,
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_relative_eq;
+    use quantity::si::{ANGSTROM, GRAM, KELVIN};
+
+    #[test]
+    fn argon() -> EosResult<()> {
+        let units =
+            ReducedUnits::new(3.405 * ANGSTROM, 119.8 * KELVIN).molarweight(39.948 * GRAM / MOL);
+        assert_relative_eq!(
+            units.pressure(),
+            KB * units.temperature() * units.density() * NAV,
+            max_relative = 1e-12
+        );
+        assert_relative_eq!(
+            units.molar_energy() / units.molar_entropy(),
+            units.temperature(),
+            max_relative = 1e-12
+        );
+        // reduced time of argon: 2.16 ps
+        assert_relative_eq!(
+            units.time()?.to_reduced(1e-12 * quantity::si::SECOND)?,
+            2.156,
+            max_relative = 1e-3
+        );
+        assert_relative_eq!(
+            units.velocity()? * units.time()?,
+            units.length(),
+            max_relative = 1e-12
+        );
+        Ok(())
+    }
+}
@@ -0,0 +1,87 @@
+use ndarray::Array1;
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+/// A single iteration of an iterative solver.
+#[derive(Clone, Copy, Debug, PartialEq, Serialize, Deserialize)]
+pub struct SolverIteration {
+    /// The residual that is used in the convergence criterion of the solver.
+    pub residual: f64,
+    /// The norm of the step in reduced units or `NaN` if not available.
+    pub step_size: f64,
+    /// The damping factor (relative step length) or `NaN` if not available.
+    pub damping: f64,
+}
+
+/// Structured history of an iterative solver.
+///
+/// In contrast to the output controlled by [Verbosity](crate::Verbosity), the log
+/// is always recorded and attached to the result of the calculation, e.g.,
+/// [State::solver_log](crate::State::solver_log), so that convergence problems
+/// can be analyzed programmatically.
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct SolverLog {
+    /// The name of the solver.
+    pub solver: String,
+    /// The individual iterations of the solver.
+    pub iterations: Vec<SolverIteration>,
+    /// Whether the solver converged.
+    pub converged: bool,
+}
+
+impl SolverLog {
+    /// Create an empty log for the given solver.
+    pub fn new(solver: &str) -> Self {
+        Self {
+            solver: solver.into(),
+            iterations: Vec::new(),
+            converged: false,
+        }
+    }
+
+    /// Append an iteration to the log.
+    pub fn push(&mut self, residual: f64, step_size: f64, damping: f64) {
+        self.iterations.push(SolverIteration {
+            residual,
+            step_size,
+            damping,
+        });
+    }
+
+    /// The number of iterations.
+    pub fn len(&self) -> usize {
+        self.iterations.len()
+    }
+
+    /// Returns `true` if no iterations were recorded.
+    pub fn is_empty(&self) -> bool {
+        self.iterations.is_empty()
+    }
+
+    /// The residuals of all iterations.
+    pub fn residual(&self) -> Array1<f64> {
+        self.iterations.iter().map(|i| i.residual).collect()
+    }
+
+    /// The step sizes of all iterations.
+    pub fn step_size(&self) -> Array1<f64> {
+        self.iterations.iter().map(|i| i.step_size).collect()
+    }
+
+    /// The damping factors of all iterations.
+    pub fn damping(&self) -> Array1<f64> {
+        self.iterations.iter().map(|i| i.damping).collect()
+    }
+}
+
+impl fmt::Display for SolverLog {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "SolverLog(solver={}, iterations={}, converged={})",
+            self.solver,
+            self.len(),
+            self.converged
+        )
+    }
+}
@@ -1,8 +1,8 @@
 use super::{DensityInitialization, State};
-use crate::equation_of_state::EquationOfState;
-use crate::errors::EosResult;
-use ndarray::Array1;
-use quantity::si::{SIArray1, SINumber};
+use crate::equation_of_state::{EquationOfState, MolarWeight};
+use crate::errors::{EosError, EosResult};
+use ndarray::{arr1, Array1};
+use quantity::si::{SIArray1, SINumber, KILOGRAM, METER, MOL, SECOND};
 use std::sync::Arc;
 
 /// A simple tool to construct [State]s with arbitrary input parameters.
@@ -52,6 +52,18 @@ use std::sync::Arc;
 /// # Ok(())
 /// # }
 /// ```
+///
+/// # Mass-based and flow-based specifications
+///
+/// For equations of state that implement the [MolarWeight] trait, the state
+/// can also be specified using mass-based properties (mass, mass fractions,
+/// mass density, specific enthalpy, ...) which are converted to the corresponding
+/// molar properties using the molar weights of the components.
+///
+/// Flows (molar flows, mass flows and volume flows) are converted to the
+/// extensive properties of the amount of material that passes in one second,
+/// i.e., the flows of the state are recovered with [State::molar_flow],
+/// [State::mass_flow] and [State::volume_flow].
 pub struct StateBuilder<'a, E: EquationOfState> {
     eos: Arc<E>,
     temperature: Option<SINumber>,
@@ -67,6 +79,18 @@ pub struct StateBuilder<'a, E: EquationOfState> {
     molar_internal_energy: Option<SINumber>,
     density_initialization: DensityInitialization,
     initial_temperature: Option<SINumber>,
+    volume_flow: Option<SINumber>,
+    molar_flow: Option<&'a SIArray1>,
+    molar_weight: Option<SIArray1>,
+    mass_density: Option<SINumber>,
+    total_mass: Option<SINumber>,
+    mass: Option<&'a SIArray1>,
+    massfracs: Option<&'a Array1<f64>>,
+    total_mass_flow: Option<SINumber>,
+    mass_flow: Option<&'a SIArray1>,
+    specific_enthalpy: Option<SINumber>,
+    specific_entropy: Option<SINumber>,
+    specific_internal_energy: Option<SINumber>,
 }
 
 impl<'a, E: EquationOfState> StateBuilder<'a, E> {
@@ -87,6 +111,18 @@ impl<'a, E: EquationOfState> StateBuilder<'a, E> {
             molar_internal_energy: None,
             density_initialization: DensityInitialization::None,
             initial_temperature: None,
+            volume_flow: None,
+            molar_flow: None,
+            molar_weight: None,
+            mass_density: None,
+            total_mass: None,
+            mass: None,
+            massfracs: None,
+            total_mass_flow: None,
+            mass_flow: None,
+            specific_enthalpy: None,
+            specific_entropy: None,
+            specific_internal_energy: None,
         }
     }
 
@@ -156,6 +192,18 @@ impl<'a, E: EquationOfState> StateBuilder<'a, E> {
         self
     }
 
+    /// Provide the volume flow for the new state.
+    pub fn volume_flow(mut self, volume_flow: SINumber) -> Self {
+        self.volume_flow = Some(volume_flow);
+        self
+    }
+
+    /// Provide the molar flows for the new state.
+    pub fn molar_flow(mut self, molar_flow: &'a SIArray1) -> Self {
+        self.molar_flow = Some(molar_flow);
+        self
+    }
+
     /// Specify a vapor state.
     pub fn vapor(mut self) -> Self {
         self.density_initialization = DensityInitialization::Vapor;
@@ -182,25 +230,201 @@ impl<'a, E: EquationOfState> StateBuilder<'a, E> {
 
     /// Try to build the state with the given inputs.
     pub fn build(self) -> EosResult<State<E>> {
+        // The molar weights are only required (and set) if mass-based properties are given.
+        let mw = match &self.molar_weight {
+            Some(mw) => mw.to_reduced(KILOGRAM / MOL)?,
+            None => Array1::zeros(0),
+        };
+        let mass_to_moles =
+            |mass: SIArray1| -> EosResult<SIArray1> { Ok(mass.to_reduced(KILOGRAM)? / &mw * MOL) };
+
+        let volume = unique(
+            vec![self.volume, self.volume_flow.map(|v| v * SECOND)],
+            "volume",
+        )?;
+        let moles = unique(
+            vec![
+                self.moles.cloned(),
+                self.molar_flow.map(|n| n.clone() * SECOND),
+                self.mass.map(|m| mass_to_moles(m.clone())).transpose()?,
+                self.mass_flow
+                    .map(|m| mass_to_moles(m.clone() * SECOND))
+                    .transpose()?,
+            ],
+            "amount of substance",
+        )?;
+        let molefracs = unique(
+            vec![
+                self.molefracs.cloned(),
+                self.massfracs.map(|w| {
+                    let x = w / &mw;
+                    let sum = x.sum();
+                    x / sum
+                }),
+            ],
+            "composition",
+        )?;
+
+        let total_molar_weight = || -> EosResult<SINumber> {
+            let x = if let Some(x) = &molefracs {
+                x.clone()
+            } else if let Some(n) = &moles {
+                let n = n.to_reduced(MOL)?;
+                &n / n.sum()
+            } else if let Some(rho) = self.partial_density {
+                let rho = rho.to_reduced(MOL / METER.powi(3))?;
+                &rho / rho.sum()
+            } else if mw.len() == 1 {
+                arr1(&[1.0])
+            } else {
+                return Err(EosError::UndeterminedState(String::from(
+                    "Missing composition.",
+                )));
+            };
+            Ok((&mw * &x).sum() * KILOGRAM / MOL)
+        };
+        let total_moles = unique(
+            vec![
+                self.total_moles,
+                self.total_mass
+                    .map(|m| total_molar_weight().map(|mw_mix| m / mw_mix))
+                    .transpose()?,
+                self.total_mass_flow
+                    .map(|m| total_molar_weight().map(|mw_mix| m * SECOND / mw_mix))
+                    .transpose()?,
+            ],
+            "total amount of substance",
+        )?;
+        let density = unique(
+            vec![
+                self.density,
+                self.mass_density
+                    .map(|rho| total_molar_weight().map(|mw_mix| rho / mw_mix))
+                    .transpose()?,
+            ],
+            "density",
+        )?;
+        let molar_enthalpy = unique(
+            vec![
+                self.molar_enthalpy,
+                self.specific_enthalpy
+                    .map(|h| total_molar_weight().map(|mw_mix| h * mw_mix))
+                    .transpose()?,
+            ],
+            "enthalpy",
+        )?;
+        let molar_entropy = unique(
+            vec![
+                self.molar_entropy,
+                self.specific_entropy
+                    .map(|s| total_molar_weight().map(|mw_mix| s * mw_mix))
+                    .transpose()?,
+            ],
+            "entropy",
+        )?;
+        let molar_internal_energy = unique(
+            vec![
+                self.molar_internal_energy,
+                self.specific_internal_energy
+                    .map(|u| total_molar_weight().map(|mw_mix| u * mw_mix))
+                    .transpose()?,
+            ],
+            "internal energy",
+        )?;
+
         State::new(
             &self.eos,
             self.temperature,
-            self.volume,
-            self.density,
+            volume,
+            density,
             self.partial_density,
-            self.total_moles,
-            self.moles,
-            self.molefracs,
+            total_moles,
+            moles.as_ref(),
+            molefracs.as_ref(),
             self.pressure,
-            self.molar_enthalpy,
-            self.molar_entropy,
-            self.molar_internal_energy,
+            molar_enthalpy,
+            molar_entropy,
+            molar_internal_energy,
             self.density_initialization,
             self.initial_temperature,
         )
     }
 }
 
+impl<'a, E: EquationOfState + MolarWeight> StateBuilder<'a, E> {
+    fn with_molar_weight(mut self) -> Self {
+        self.molar_weight = Some(self.eos.molar_weight());
+        self
+    }
+
+    /// Provide the mass density for the new state.
+    pub fn mass_density(mut self, mass_density: SINumber) -> Self {
+        self.mass_density = Some(mass_density);
+        self.with_molar_weight()
+    }
+
+    /// Provide the total mass for the new state.
+    pub fn total_mass(mut self, total_mass: SINumber) -> Self {
+        self.total_mass = Some(total_mass);
+        self.with_molar_weight()
+    }
+
+    /// Provide the mass of each component for the new state.
+    pub fn mass(mut self, mass: &'a SIArray1) -> Self {
+        self.mass = Some(mass);
+        self.with_molar_weight()
+    }
+
+    /// Provide the mass fractions for the new state.
+    pub fn massfracs(mut self, massfracs: &'a Array1<f64>) -> Self {
+        self.massfracs = Some(massfracs);
+        self.with_molar_weight()
+    }
+
+    /// Provide the total mass flow for the new state.
+    pub fn total_mass_flow(mut self, total_mass_flow: SINumber) -> Self {
+        self.total_mass_flow = Some(total_mass_flow);
+        self.with_molar_weight()
+    }
+
+    /// Provide the mass flow of each component for the new state.
+    pub fn mass_flow(mut self, mass_flow: &'a SIArray1) -> Self {
+        self.mass_flow = Some(mass_flow);
+        self.with_molar_weight()
+    }
+
+    /// Provide the specific enthalpy for the new state.
+    pub fn specific_enthalpy(mut self, specific_enthalpy: SINumber) -> Self {
+        self.specific_enthalpy = Some(specific_enthalpy);
+        self.with_molar_weight()
+    }
+
+    /// Provide the specific entropy for the new state.
+    pub fn specific_entropy(mut self, specific_entropy: SINumber) -> Self {
+        self.specific_entropy = Some(specific_entropy);
+        self.with_molar_weight()
+    }
+
+    /// Provide the specific internal energy for the new state.
+    pub fn specific_internal_energy(mut self, specific_internal_energy: SINumber) -> Self {
+        self.specific_internal_energy = Some(specific_internal_energy);
+        self.with_molar_weight()
+    }
+}
+
+/// Return the single value of a property that can be specified in different ways.
+fn unique<T>(values: Vec<Option<T>>, property: &str) -> EosResult<Option<T>> {
+    let mut values = values.into_iter().flatten();
+    let value = values.next();
+    if values.next().is_some() {
+        return Err(EosError::UndeterminedState(format!(
+            "The {} is overdetermined.",
+            property
+        )));
+    }
+    Ok(value)
+}
+
 impl<'a, E: EquationOfState> Clone for StateBuilder<'a, E> {
     fn clone(&self) -> Self {
         Self {
@@ -218,6 +442,18 @@ impl<'a, E: EquationOfState> Clone for StateBuilder<'a, E> {
             molar_internal_energy: self.molar_internal_energy,
             density_initialization: self.density_initialization,
             initial_temperature: self.initial_temperature,
+            volume_flow: self.volume_flow,
+            molar_flow: self.molar_flow,
+            molar_weight: self.molar_weight.clone(),
+            mass_density: self.mass_density,
+            total_mass: self.total_mass,
+            mass: self.mass,
+            massfracs: self.massfracs,
+            total_mass_flow: self.total_mass_flow,
+            mass_flow: self.mass_flow,
+            specific_enthalpy: self.specific_enthalpy,
+            specific_entropy: self.specific_entropy,
+            specific_internal_energy: self.specific_internal_energy,
         }
     }
 }
@@ -2,10 +2,10 @@ use super::{State, StateHD, TPSpec};
 use crate::equation_of_state::EquationOfState;
 use crate::errors::{EosError, EosResult};
 use crate::phase_equilibria::{SolverOptions, Verbosity};
-use crate::{DensityInitialization, EosUnit};
+use crate::{DensityInitialization, EosUnit, SolverLog};
 use ndarray::{arr1, arr2, Array1, Array2};
 use num_dual::linalg::{norm, smallest_ev, LU};
-use num_dual::{Dual, Dual3, Dual64, DualNum, DualVec64, HyperDual, StaticVec};
+use num_dual::{Dual, Dual2_64, Dual3, Dual64, DualNum, DualVec64, HyperDual, StaticVec};
 use num_traits::{One, Zero};
 use quantity::si::{SIArray1, SINumber, SIUnit};
 use std::convert::TryFrom;
@@ -14,6 +14,14 @@ use std::sync::Arc;
 const MAX_ITER_CRIT_POINT: usize = 50;
 const MAX_ITER_CRIT_POINT_BINARY: usize = 200;
 const TOL_CRIT_POINT: f64 = 1e-8;
+const MAX_ITER_CRIT_POINT_GLOBAL: usize = 200;
+const HOMOTOPY_STEPS: usize = 10;
+const TRUST_RADIUS_INIT: f64 = 0.1;
+const TRUST_RADIUS_MAX: f64 = 1.0;
+const TRUST_RADIUS_MIN: f64 = 1e-12;
+const PSEUDO_CRITICAL_DENSITY_POINTS: usize = 50;
+const PSEUDO_CRITICAL_BRACKET_STEPS: usize = 20;
+const PSEUDO_CRITICAL_BISECTION_STEPS: usize = 20;
 
 /// # Critical points
 impl<E: EquationOfState> State<E> {
@@ -63,6 +71,11 @@ impl<E: EquationOfState> State<E> {
     }
 
     /// Calculate the critical point of a system for given moles.
+    ///
+    /// If no initial temperature is given, the Newton iteration is started
+    /// from several trial temperatures. If all of them fail for a mixture,
+    /// the critical point is calculated with [State::critical_point_global]
+    /// using the same options.
     pub fn critical_point(
         eos: &Arc<E>,
         moles: Option<&SIArray1>,
@@ -87,9 +100,85 @@ impl<E: EquationOfState> State<E> {
                 return s;
             }
         }
+        if eos.components() > 1 {
+            return Self::critical_point_global(eos, Some(&moles), true, options);
+        }
         Err(EosError::NotConverged(String::from("Critical point")))
     }
 
+    /// Calculate the critical point of a system for given moles using
+    /// a globalized (trust-region) solver that does not require an
+    /// initial guess.
+    ///
+    /// The initial temperature and density are the pseudo-critical
+    /// properties of an ideal mixture of the pure components, i.e., the
+    /// mole fraction weighted critical temperatures and volumes (Kay's rule).
+    /// For a pure component, they are estimated from the mechanically
+    /// unstable region of the equation of state.
+    /// If `homotopy` is `true` and the trust-region solver fails, the
+    /// critical point is traced with the Newton homotopy
+    /// $H(x,\lambda)=F(x)-(1-\lambda)F(x_0)$, where $F$ are the criticality
+    /// conditions of the mixture and $x_0$ is the pseudo-critical point, from
+    /// the trivial solution $x_0$ at $\lambda=0$ to the critical point at
+    /// $\lambda=1$.
+    pub fn critical_point_global(
+        eos: &Arc<E>,
+        moles: Option<&SIArray1>,
+        homotopy: bool,
+        options: SolverOptions,
+    ) -> EosResult<Self>
+    where
+        SINumber: std::fmt::Display,
+    {
+        let moles = eos.validate_moles(moles)?;
+        let n = moles.to_reduced(SIUnit::reference_moles())?;
+        let max_density = eos
+            .max_density(Some(&moles))?
+            .to_reduced(SIUnit::reference_density())?;
+
+        let x0 = pseudo_critical_point(eos, &n, max_density, options)?;
+
+        let (max_iter, tol, verbosity) =
+            options.unwrap_or(MAX_ITER_CRIT_POINT_GLOBAL, TOL_CRIT_POINT);
+        let mut log = SolverLog::new("critical_point_trust_region");
+        let res = critical_point_trust_region(
+            eos,
+            &n,
+            x0,
+            max_density,
+            &Array1::zeros(2),
+            (max_iter, tol, verbosity),
+            &mut log,
+        );
+        let [t, rho] = match res {
+            Ok(x) => x,
+            Err(e) if !homotopy => return Err(e),
+            Err(_) => {
+                log_result!(
+                    verbosity,
+                    "Trust-region solver failed, switching to homotopy\n"
+                );
+                log = SolverLog::new("critical_point_homotopy");
+                critical_point_homotopy(
+                    eos,
+                    &n,
+                    x0,
+                    max_density,
+                    (max_iter, tol, verbosity),
+                    &mut log,
+                )?
+            }
+        };
+        log.converged = true;
+        Ok(State::new_nvt(
+            eos,
+            t * SIUnit::reference_temperature(),
+            moles.sum() / (rho * SIUnit::reference_density()),
+            &moles,
+        )?
+        .with_solver_log(log))
+    }
+
     fn critical_point_hkm(
         eos: &Arc<E>,
         moles: &SIArray1,
@@ -508,6 +597,283 @@ fn critical_point_objective<E: EquationOfState>(
     Ok(StaticVec::new_vec([eval, res.v3]))
 }
 
+/// Residuals of the criticality conditions and their Jacobian
+/// w.r.t. temperature and density.
+fn critical_point_residual<E: EquationOfState>(
+    eos: &Arc<E>,
+    temperature: f64,
+    density: f64,
+    moles: &Array1<f64>,
+) -> EosResult<(Array1<f64>, Array2<f64>)> {
+    let [t_dual, rho_dual] = *StaticVec::new_vec([temperature, density])
+        .map(DualVec64::<2>::from_re)
+        .derive()
+        .raw_array();
+    let res = critical_point_objective(eos, t_dual, rho_dual, moles)?;
+    let jacobian = arr2(res.jacobian().raw_data());
+    let res = arr1(res.map(|r| r.re()).raw_array());
+    if res.iter().chain(jacobian.iter()).any(|r| !r.is_finite()) {
+        return Err(EosError::IterationFailed(String::from("Critical point")));
+    }
+    Ok((res, jacobian))
+}
+
+/// The pseudo-critical temperature and density of a mixture from the
+/// pure component critical points, i.e., the mole fraction weighted
+/// critical temperatures and volumes (Kay's rule).
+///
+/// For a pure component, the critical temperature is estimated as the
+/// highest temperature at which the equation of state is mechanically
+/// unstable on an equidistant density grid, and the critical density as
+/// the density at which the stability criterion is smallest.
+fn pseudo_critical_point<E: EquationOfState>(
+    eos: &Arc<E>,
+    moles: &Array1<f64>,
+    max_density: f64,
+    options: SolverOptions,
+) -> EosResult<[f64; 2]> {
+    if eos.components() == 1 {
+        return pseudo_critical_point_pure(eos, moles, max_density);
+    }
+    let x = moles / moles.sum();
+    let mut t = 0.0;
+    let mut v = 0.0;
+    for (i, xi) in x.iter().enumerate() {
+        let cp = State::critical_point(&Arc::new(eos.subset(&[i])), None, None, options)?;
+        t += xi * cp.temperature.to_reduced(SIUnit::reference_temperature())?;
+        v += xi / cp.density.to_reduced(SIUnit::reference_density())?;
+    }
+    Ok([t, (1.0 / v).min(0.9 * max_density)])
+}
+
+/// Estimate of the critical point of a pure component from a bisection of
+/// the temperature between a mechanically unstable and a stable isotherm.
+fn pseudo_critical_point_pure<E: EquationOfState>(
+    eos: &Arc<E>,
+    moles: &Array1<f64>,
+    max_density: f64,
+) -> EosResult<[f64; 2]> {
+    let error = || EosError::NotConverged(String::from("Pseudo-critical point"));
+
+    // bracket the critical temperature
+    let mut t_stable = 500.0;
+    let mut t_unstable = 500.0;
+    if mechanical_stability(eos, t_unstable, moles, max_density).0 < 0.0 {
+        for _ in 0..PSEUDO_CRITICAL_BRACKET_STEPS {
+            t_stable *= 2.0;
+            if mechanical_stability(eos, t_stable, moles, max_density).0 > 0.0 {
+                break;
+            }
+            t_unstable = t_stable;
+        }
+    } else {
+        for _ in 0..PSEUDO_CRITICAL_BRACKET_STEPS {
+            t_unstable *= 0.5;
+            if mechanical_stability(eos, t_unstable, moles, max_density).0 < 0.0 {
+                break;
+            }
+            t_stable = t_unstable;
+        }
+    }
+    if t_stable == t_unstable {
+        return Err(error());
+    }
+
+    // bisection
+    let mut density = mechanical_stability(eos, t_unstable, moles, max_density).1;
+    for _ in 0..PSEUDO_CRITICAL_BISECTION_STEPS {
+        let t = 0.5 * (t_stable + t_unstable);
+        let (stability, rho) = mechanical_stability(eos, t, moles, max_density);
+        if stability < 0.0 {
+            t_unstable = t;
+            density = rho;
+        } else {
+            t_stable = t;
+        }
+    }
+    Ok([0.5 * (t_stable + t_unstable), density])
+}
+
+/// The smallest second derivative of the reduced Helmholtz energy with
+/// respect to the volume (negative for mechanically unstable states) on an
+/// equidistant density grid and the corresponding density.
+fn mechanical_stability<E: EquationOfState>(
+    eos: &Arc<E>,
+    temperature: f64,
+    moles: &Array1<f64>,
+    max_density: f64,
+) -> (f64, f64) {
+    (1..PSEUDO_CRITICAL_DENSITY_POINTS)
+        .map(|i| {
+            let rho = max_density * i as f64 / PSEUDO_CRITICAL_DENSITY_POINTS as f64;
+            let state = StateHD::new(
+                Dual2_64::from(temperature),
+                Dual2_64::from(moles.sum() / rho).derive(),
+                moles.mapv(Dual2_64::from),
+            );
+            let a = eos.evaluate_residual(&state) + eos.ideal_gas().evaluate(&state);
+            (a.v2[0], rho)
+        })
+        .fold(
+            (f64::INFINITY, f64::NAN),
+            |min, x| if x.0 < min.0 { x } else { min },
+        )
+}
+
+/// Trace the solutions of the Newton homotopy $H(x,\lambda)=F(x)-(1-\lambda)F(x_0)$
+/// from the initial point $x_0$ to the solution of the criticality conditions
+/// $F(x)=0$ in `HOMOTOPY_STEPS` equidistant steps of $\lambda$.
+fn critical_point_homotopy<E: EquationOfState>(
+    eos: &Arc<E>,
+    moles: &Array1<f64>,
+    initial: [f64; 2],
+    max_density: f64,
+    options: (usize, f64, Verbosity),
+    log: &mut SolverLog,
+) -> EosResult<[f64; 2]> {
+    let (r0, _) = critical_point_residual(eos, initial[0], initial[1], moles)?;
+    let mut x = initial;
+    for k in 1..=HOMOTOPY_STEPS {
+        let lambda = k as f64 / HOMOTOPY_STEPS as f64;
+        x = critical_point_trust_region(
+            eos,
+            moles,
+            x,
+            max_density,
+            &(&r0 * (1.0 - lambda)),
+            options,
+            log,
+        )?;
+    }
+    Ok(x)
+}
+
+/// Solve the criticality conditions `F(T, rho) = shift` with a dogleg
+/// trust-region method in scaled temperature and density.
+fn critical_point_trust_region<E: EquationOfState>(
+    eos: &Arc<E>,
+    moles: &Array1<f64>,
+    initial: [f64; 2],
+    max_density: f64,
+    shift: &Array1<f64>,
+    (max_iter, tol, verbosity): (usize, f64, Verbosity),
+    log: &mut SolverLog,
+) -> EosResult<[f64; 2]> {
+    let scale = arr1(&[initial[0], max_density]);
+    let mut x = arr1(&initial);
+    let (res, mut jac) = critical_point_residual(eos, x[0], x[1], moles)?;
+    let mut res = res - shift;
+    let mut delta = TRUST_RADIUS_INIT;
+
+    log_iter!(
+        verbosity,
+        " iter |    residual    |  trust radius  |   temperature   |       density        "
+    );
+    log_iter!(verbosity, "{:-<81}", "");
+
+    for i in 1..=max_iter {
+        // check convergence
+        let f = res.dot(&res).sqrt();
+        if f < tol {
+            log_result!(
+                verbosity,
+                "Critical point calculation converged in {} step(s)\n",
+                i - 1
+            );
+            return Ok([x[0], x[1]]);
+        }
+
+        // Newton and Cauchy steps in scaled variables
+        let jac_s = &jac * &scale;
+        let grad = jac_s.t().dot(&res);
+        let jg = jac_s.dot(&grad);
+        let cauchy = &grad * (-grad.dot(&grad) / jg.dot(&jg));
+        let newton = LU::new(jac_s.clone()).ok().map(|lu| -lu.solve(&res));
+        let norm_newton = newton.as_ref().map_or(f64::NAN, |n| n.dot(n).sqrt());
+
+        // dogleg step
+        let step = match newton {
+            Some(newton) if norm_newton <= delta => newton,
+            Some(newton) if cauchy.dot(&cauchy).sqrt() < delta => {
+                let d = &newton - &cauchy;
+                let a = d.dot(&d);
+                let b = 2.0 * cauchy.dot(&d);
+                let c = cauchy.dot(&cauchy) - delta * delta;
+                let tau = (-b + (b * b - 4.0 * a * c).sqrt()) / (2.0 * a);
+                &cauchy + &(d * tau)
+            }
+            _ => {
+                let norm_cauchy = cauchy.dot(&cauchy).sqrt();
+                &cauchy * (delta / norm_cauchy).min(1.0)
+            }
+        };
+        let step_size = step.dot(&step).sqrt();
+        if !step_size.is_finite() {
+            break;
+        }
+        let predicted = res.dot(&res) - (&res + &jac_s.dot(&step)).map(|r| r * r).sum();
+
+        // evaluate trial point
+        let x_new = &x + &(&step * &scale);
+        let trial = if x_new[0] > 0.0 && x_new[1] > 0.0 && x_new[1] < max_density {
+            critical_point_residual(eos, x_new[0], x_new[1], moles).ok()
+        } else {
+            None
+        };
+        let (ratio, trial) = match trial {
+            Some((res_new, jac_new)) => {
+                let res_new = res_new - shift;
+                let actual = res.dot(&res) - res_new.dot(&res_new);
+                let ratio = if predicted > 0.0 {
+                    actual / predicted
+                } else {
+                    -1.0
+                };
+                (ratio, Some((res_new, jac_new)))
+            }
+            None => (-1.0, None),
+        };
+
+        // update trust radius
+        if ratio < 0.25 {
+            delta = 0.25 * step_size;
+        } else if ratio > 0.75 && step_size > 0.99 * delta {
+            delta = (2.0 * delta).min(TRUST_RADIUS_MAX);
+        }
+
+        // accept step
+        if ratio > 1e-4 {
+            if let Some((res_new, jac_new)) = trial {
+                x = x_new;
+                res = res_new;
+                jac = jac_new;
+                // step length relative to the Newton step (1 without a Newton step)
+                let damping = if norm_newton > 0.0 {
+                    step_size / norm_newton
+                } else {
+                    1.0
+                };
+                log.push(f, step_size, damping);
+            }
+        }
+
+        log_iter!(
+            verbosity,
+            " {:4} | {:14.8e} | {:14.8e} | {:13.8} | {:12.8}",
+            i,
+            res.dot(&res).sqrt(),
+            delta,
+            x[0] * SIUnit::reference_temperature(),
+            x[1] * SIUnit::reference_density(),
+        );
+
+        if delta < TRUST_RADIUS_MIN {
+            break;
+        }
+    }
+    Err(EosError::NotConverged(String::from("Critical point")))
+}
+
 fn critical_point_objective_t<E: EquationOfState>(
     eos: &Arc<E>,
     temperature: f64,
@@ -614,3 +980,92 @@ fn spinodal_objective<E: EquationOfState>(
 
     Ok(eval)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::cubic::{PengRobinson, PengRobinsonParameters};
+    use approx::assert_relative_eq;
+    use quantity::si::MOL;
+
+    #[test]
+    fn homotopy_asymmetric_mixture() -> EosResult<()> {
+        // methane and n-decane
+        let parameters = PengRobinsonParameters::new_simple(
+            &[190.56, 617.7],
+            &[4599000.0, 2110000.0],
+            &[0.011, 0.49],
+            &[16.043, 142.285],
+        )?;
+        let eos = Arc::new(PengRobinson::new(Arc::new(parameters)));
+        let moles = arr1(&[0.5, 0.5]) * MOL;
+        let n = moles.to_reduced(SIUnit::reference_moles())?;
+        let max_density = eos
+            .max_density(Some(&moles))?
+            .to_reduced(SIUnit::reference_density())?;
+        let x0 = pseudo_critical_point(&eos, &n, max_density, SolverOptions::default())?;
+
+        let mut log = SolverLog::new("critical_point_homotopy");
+        let options = (MAX_ITER_CRIT_POINT_GLOBAL, TOL_CRIT_POINT, Verbosity::None);
+        let [t, rho] = critical_point_homotopy(&eos, &n, x0, max_density, options, &mut log)?;
+
+        // the end point of the homotopy is a solution of the criticality conditions
+        let (res, _) = critical_point_residual(&eos, t, rho, &n)?;
+        assert!(norm(&res) < TOL_CRIT_POINT);
+        assert!(t > 190.56 && t < 617.7);
+        assert!(!log.iterations.is_empty());
+        Ok(())
+    }
+
+    #[test]
+    fn critical_point_fallback() -> EosResult<()> {
+        // propane and n-butane
+        let parameters = PengRobinsonParameters::new_simple(
+            &[369.96, 425.2],
+            &[4250000.0, 3800000.0],
+            &[0.153, 0.199],
+            &[44.0962, 58.123],
+        )?;
+        let eos = Arc::new(PengRobinson::new(Arc::new(parameters)));
+        let moles = arr1(&[1.5, 1.5]) * MOL;
+
+        // a single Newton iteration from the trial temperatures can not
+        // converge, the options are forwarded to the global solver, which
+        // can not converge in a single iteration either
+        let options = SolverOptions::new().max_iter(1);
+        assert!(State::critical_point(&eos, Some(&moles), None, options).is_err());
+
+        let cp = State::critical_point(&eos, Some(&moles), None, Default::default())?;
+        let cp_global =
+            State::critical_point_global(&eos, Some(&moles), false, Default::default())?;
+        assert_relative_eq!(cp.temperature, cp_global.temperature, max_relative = 1e-8);
+        assert_relative_eq!(cp.density, cp_global.density, max_relative = 1e-8);
+        Ok(())
+    }
+
+    #[test]
+    fn pseudo_critical_point_pure_component() -> EosResult<()> {
+        // propane
+        let parameters =
+            PengRobinsonParameters::new_simple(&[369.96], &[4250000.0], &[0.153], &[44.0962])?;
+        let eos = Arc::new(PengRobinson::new(Arc::new(parameters)));
+        let moles = arr1(&[1.0]) * MOL;
+        let n = moles.to_reduced(SIUnit::reference_moles())?;
+        let max_density = eos
+            .max_density(Some(&moles))?
+            .to_reduced(SIUnit::reference_density())?;
+
+        // the estimate is close to the critical point of the equation of state
+        let cp = State::critical_point(&eos, None, None, Default::default())?;
+        let tc = cp.temperature.to_reduced(SIUnit::reference_temperature())?;
+        let rhoc = cp.density.to_reduced(SIUnit::reference_density())?;
+        let [t, rho] = pseudo_critical_point_pure(&eos, &n, max_density)?;
+        assert_relative_eq!(t, tc, max_relative = 2e-2);
+        assert_relative_eq!(rho, rhoc, max_relative = 2e-1);
+
+        let cp_global = State::critical_point_global(&eos, None, false, Default::default())?;
+        assert_relative_eq!(cp.temperature, cp_global.temperature, max_relative = 1e-8);
+        assert_relative_eq!(cp.density, cp_global.density, max_relative = 1e-8);
+        Ok(())
+    }
+}
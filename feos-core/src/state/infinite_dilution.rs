@@ -0,0 +1,149 @@
+use super::{Contributions, DensityInitialization, State, StateBuilder};
+use crate::equation_of_state::EquationOfState;
+use crate::errors::{EosError, EosResult};
+use crate::phase_equilibria::{PhaseEquilibrium, SolverOptions};
+use crate::EosUnit;
+use ndarray::{arr1, Array1};
+use quantity::si::{SINumber, SIUnit};
+use std::sync::Arc;
+
+/// # Infinite dilution
+///
+/// The properties are evaluated in the solvent, i.e., in the state at the
+/// same temperature and pressure from which the solute is removed. Because
+/// the derivatives of the Helmholtz energy are calculated exactly, the
+/// limit of infinite dilution is evaluated directly at vanishing amount
+/// of the solute.
+impl<E: EquationOfState> State<E> {
+    /// The state of the solvent at the temperature and pressure of `self`.
+    fn solvent(&self, solute: usize) -> EosResult<Self> {
+        let n = self.eos.components();
+        if solute >= n {
+            return Err(EosError::Error(format!(
+                "Invalid solute index {} for {} components.",
+                solute, n
+            )));
+        }
+        if self.molefracs[solute] == 0.0 {
+            return Ok(self.clone());
+        }
+        if self.molefracs[solute] == 1.0 {
+            return Err(EosError::Error(String::from(
+                "The state does not contain a solvent.",
+            )));
+        }
+        let mut moles = self.moles.to_reduced(SIUnit::reference_moles())?;
+        moles[solute] = 0.0;
+        State::new_npt(
+            &self.eos,
+            self.temperature,
+            self.pressure(Contributions::Total),
+            &(moles * SIUnit::reference_moles()),
+            DensityInitialization::InitialDensity(self.density),
+        )
+    }
+
+    /// Logarithm of the fugacity coefficient of `solute` at infinite dilution:
+    /// $\ln\varphi_i^\infty=\lim\limits_{x_i\to 0}\ln\varphi_i$
+    pub fn ln_phi_infinite_dilution(&self, solute: usize) -> EosResult<f64> {
+        Ok(self.solvent(solute)?.ln_phi()[solute])
+    }
+
+    /// Henry's law constant of `solute`: $H_i=\lim\limits_{x_i\to 0}\frac{f_i}{x_i}=\varphi_i^\infty p$
+    pub fn henry_constant(&self, solute: usize) -> EosResult<SINumber> {
+        let solvent = self.solvent(solute)?;
+        Ok(solvent.ln_phi()[solute].exp() * solvent.pressure(Contributions::Total))
+    }
+
+    /// Logarithm of the activity coefficient of `solute` at infinite dilution
+    /// with the pure liquid solute at the same temperature and pressure as
+    /// reference state: $\ln\gamma_i^\infty=\ln\varphi_i^\infty-\ln\varphi_{0i}\left(T,p\right)$
+    pub fn ln_activity_coefficient_infinite_dilution(&self, solute: usize) -> EosResult<f64> {
+        let solvent = self.solvent(solute)?;
+        let eos = Arc::new(self.eos.subset(&[solute]));
+        let pure = State::new_npt(
+            &eos,
+            self.temperature,
+            solvent.pressure(Contributions::Total),
+            &(arr1(&[1.0]) * SIUnit::reference_moles()),
+            DensityInitialization::Liquid,
+        )?;
+        Ok(solvent.ln_phi()[solute] - pure.ln_phi()[0])
+    }
+
+    /// Partial molar volume of `solute` at infinite dilution:
+    /// $v_i^\infty=\lim\limits_{x_i\to 0}\left(\frac{\partial V}{\partial N_i}\right)_{T,p,N_j}$
+    pub fn partial_molar_volume_infinite_dilution(&self, solute: usize) -> EosResult<SINumber> {
+        Ok(self
+            .solvent(solute)?
+            .partial_molar_volume(Contributions::Total)
+            .get(solute))
+    }
+}
+
+/// # Infinite dilution
+impl<E: EquationOfState> PhaseEquilibrium<E, 2> {
+    /// Henry's law constant of `solute` in the saturated liquid solvent at
+    /// the given temperature.
+    ///
+    /// The composition of the solvent is given by `molefracs`, the entry of
+    /// the solute is ignored. For a single solvent component, the vapor
+    /// pressure of the solvent is used, otherwise the bubble point of the
+    /// solvent mixture.
+    pub fn henry_constant(
+        eos: &Arc<E>,
+        temperature: SINumber,
+        solute: usize,
+        molefracs: &Array1<f64>,
+        options: SolverOptions,
+    ) -> EosResult<SINumber> {
+        let n = eos.components();
+        if molefracs.len() != n {
+            return Err(EosError::IncompatibleComponents(n, molefracs.len()));
+        }
+        if solute >= n {
+            return Err(EosError::Error(format!(
+                "Invalid solute index {} for {} components.",
+                solute, n
+            )));
+        }
+        let solvent: Vec<_> = (0..n)
+            .filter(|&i| i != solute && molefracs[i] > 0.0)
+            .collect();
+        if solvent.is_empty() {
+            return Err(EosError::Error(String::from(
+                "The composition does not contain a solvent.",
+            )));
+        }
+        let solvent_eos = Arc::new(eos.subset(&solvent));
+        let vle = if solvent.len() == 1 {
+            PhaseEquilibrium::pure(&solvent_eos, temperature, None, options)?
+        } else {
+            let x = Array1::from_shape_fn(solvent.len(), |i| molefracs[solvent[i]]);
+            let x = &x / x.sum();
+            PhaseEquilibrium::bubble_point(
+                &solvent_eos,
+                temperature,
+                &x,
+                None,
+                None,
+                (options, options),
+            )?
+        };
+
+        // the saturated liquid without the solute
+        let rho_solvent = vle
+            .liquid()
+            .partial_density
+            .to_reduced(SIUnit::reference_density())?;
+        let mut partial_density = Array1::zeros(n);
+        for (k, &i) in solvent.iter().enumerate() {
+            partial_density[i] = rho_solvent[k];
+        }
+        let liquid = StateBuilder::new(eos)
+            .temperature(temperature)
+            .partial_density(&(partial_density * SIUnit::reference_density()))
+            .build()?;
+        liquid.henry_constant(solute)
+    }
+}
@@ -9,21 +9,28 @@
 use crate::density_iteration::density_iteration;
 use crate::equation_of_state::EquationOfState;
 use crate::errors::{EosError, EosResult};
+use crate::solver_log::SolverLog;
 use crate::EosUnit;
 use cache::Cache;
 use ndarray::prelude::*;
 use num_dual::linalg::{norm, LU};
 use num_dual::*;
 use quantity::si::{SIArray1, SINumber, SIUnit};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
 use std::convert::TryFrom;
 use std::fmt;
 use std::sync::{Arc, Mutex};
 
 mod builder;
 mod cache;
+mod infinite_dilution;
+mod parameter_scan;
 mod properties;
+mod sensitivities;
 pub use builder::StateBuilder;
+pub use parameter_scan::{ParameterIssue, ParameterScan};
 pub use properties::{Contributions, StateVec};
+pub use sensitivities::ParameterSensitivity;
 
 /// Initial values in a density iteration.
 #[derive(Clone, Copy)]
@@ -116,6 +123,7 @@ impl<D: DualNum<f64>> StateHD<D> {
 /// + [Mass specific state properties](#mass-specific-state-properties)
 /// + [Transport properties](#transport-properties)
 /// + [Critical points](#critical-points)
+/// + [Parameter checks](#parameter-checks)
 /// + [State constructors](#state-constructors)
 /// + [Stability analysis](#stability-analysis)
 /// + [Flash calculations](#flash-calculations)
@@ -145,6 +153,8 @@ pub struct State<E> {
     reduced_moles: Array1<f64>,
     /// Cache
     cache: Mutex<Cache>,
+    /// Log of the solver that determined the state
+    solver_log: Option<Arc<SolverLog>>,
 }
 
 impl<E> Clone for State<E> {
@@ -162,10 +172,52 @@ impl<E> Clone for State<E> {
             reduced_volume: self.reduced_volume,
             reduced_moles: self.reduced_moles.clone(),
             cache: Mutex::new(self.cache.lock().unwrap().clone()),
+            solver_log: self.solver_log.clone(),
         }
     }
 }
 
+// Auxiliary structures used to (de)serialize states in reduced units.
+#[derive(Serialize)]
+struct StateRef<'a, E> {
+    eos: &'a E,
+    temperature: f64,
+    volume: f64,
+    moles: &'a Array1<f64>,
+}
+
+#[derive(Deserialize)]
+struct StateJSON<E> {
+    eos: E,
+    temperature: f64,
+    volume: f64,
+    moles: Array1<f64>,
+}
+
+impl<E: Serialize> Serialize for State<E> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        StateRef {
+            eos: self.eos.as_ref(),
+            temperature: self.reduced_temperature,
+            volume: self.reduced_volume,
+            moles: &self.reduced_moles,
+        }
+        .serialize(serializer)
+    }
+}
+
+impl<'de, E: EquationOfState + Deserialize<'de>> Deserialize<'de> for State<E> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let state = StateJSON::<E>::deserialize(deserializer)?;
+        Ok(Self::new_nvt_unchecked(
+            &Arc::new(state.eos),
+            state.temperature * SIUnit::reference_temperature(),
+            state.volume * SIUnit::reference_volume(),
+            &(state.moles * SIUnit::reference_moles()),
+        ))
+    }
+}
+
 impl<E> fmt::Display for State<E>
 where
     SINumber: fmt::Display,
@@ -265,6 +317,7 @@ impl<E: EquationOfState> State<E> {
             reduced_volume: v,
             reduced_moles: m,
             cache: Mutex::new(Cache::with_capacity(eos.components())),
+            solver_log: None,
         }
     }
 
@@ -496,6 +549,38 @@ impl<E: EquationOfState> State<E> {
         }
     }
 
+    /// Return a list of states for the given temperatures and pressures
+    /// using density iterations with the same composition and [DensityInitialization].
+    ///
+    /// Fails if the number of temperatures and pressures differs or if
+    /// any of the density iterations fails.
+    pub fn new_npt_vec(
+        eos: &Arc<E>,
+        temperature: &SIArray1,
+        pressure: &SIArray1,
+        moles: &SIArray1,
+        density_initialization: DensityInitialization,
+    ) -> EosResult<Vec<Self>> {
+        if temperature.len() != pressure.len() {
+            return Err(EosError::Error(format!(
+                "Inconsistent number of state points: {} temperatures and {} pressures.",
+                temperature.len(),
+                pressure.len()
+            )));
+        }
+        (0..temperature.len())
+            .map(|i| {
+                Self::new_npt(
+                    eos,
+                    temperature.get(i),
+                    pressure.get(i),
+                    moles,
+                    density_initialization,
+                )
+            })
+            .collect()
+    }
+
     /// Return a new `State` for given pressure $p$, volume $V$, temperature $T$ and composition $x_i$.
     pub fn new_npvx(
         eos: &Arc<E>,
@@ -622,6 +707,17 @@ impl<E: EquationOfState> State<E> {
         newton(t0, f, 1.0e-8 * SIUnit::reference_temperature())
     }
 
+    /// The log of the solver that determined the state, e.g., the density
+    /// iteration for given temperature and pressure.
+    pub fn solver_log(&self) -> Option<&SolverLog> {
+        self.solver_log.as_deref()
+    }
+
+    pub(crate) fn with_solver_log(mut self, solver_log: SolverLog) -> Self {
+        self.solver_log = Some(Arc::new(solver_log));
+        self
+    }
+
     /// Update the state with the given temperature
     pub fn update_temperature(&self, temperature: SINumber) -> EosResult<Self> {
         Self::new_nvt(&self.eos, temperature, self.volume, &self.moles)
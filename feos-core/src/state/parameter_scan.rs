@@ -0,0 +1,406 @@
+use super::{Contributions, State};
+use crate::equation_of_state::EquationOfState;
+use crate::errors::{EosError, EosResult};
+use crate::phase_equilibria::{PhaseEquilibrium, SolverOptions};
+use crate::EosUnit;
+use quantity::si::{SIArray1, SINumber, SIUnit};
+use std::cmp::Ordering;
+use std::fmt;
+use std::sync::Arc;
+
+const REL_TOL_CRITICAL_POINTS: f64 = 1e-5;
+
+/// Thermodynamic inconsistency detected during a [parameter scan](State::parameter_scan).
+#[derive(Clone, Debug)]
+pub enum ParameterIssue {
+    /// The Helmholtz energy or one of its derivatives is not finite, e.g.,
+    /// because the association solver did not converge.
+    NonFiniteProperties {
+        temperature: SINumber,
+        density: SINumber,
+    },
+    /// The state could not be evaluated.
+    EvaluationFailed {
+        temperature: SINumber,
+        density: SINumber,
+        error: String,
+    },
+    /// The isochoric or isobaric heat capacity of a mechanically stable state is negative.
+    NegativeHeatCapacity {
+        temperature: SINumber,
+        density: SINumber,
+        c_v: SINumber,
+        c_p: SINumber,
+    },
+    /// The speed of sound decreases with increasing density along a compressed liquid isotherm.
+    NonMonotonicSpeedOfSound {
+        temperature: SINumber,
+        density: SINumber,
+    },
+    /// More than one critical point was found.
+    MultipleCriticalPoints(Vec<SINumber>),
+    /// No critical point was found.
+    NoCriticalPoint,
+    /// The pure component phase equilibrium could not be converged below the critical temperature.
+    PhaseEquilibriumFailed { temperature: SINumber },
+}
+
+impl fmt::Display for ParameterIssue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::NonFiniteProperties {
+                temperature,
+                density,
+            } => write!(
+                f,
+                "non-finite properties at T = {}, ρ = {}",
+                temperature, density
+            ),
+            Self::EvaluationFailed {
+                temperature,
+                density,
+                error,
+            } => write!(
+                f,
+                "evaluation failed at T = {}, ρ = {}: {}",
+                temperature, density, error
+            ),
+            Self::NegativeHeatCapacity {
+                temperature,
+                density,
+                c_v,
+                c_p,
+            } => write!(
+                f,
+                "negative heat capacity at T = {}, ρ = {}: c_v = {}, c_p = {}",
+                temperature, density, c_v, c_p
+            ),
+            Self::NonMonotonicSpeedOfSound {
+                temperature,
+                density,
+            } => write!(
+                f,
+                "speed of sound decreases with density at T = {}, ρ = {}",
+                temperature, density
+            ),
+            Self::MultipleCriticalPoints(temperatures) => {
+                write!(f, "multiple critical points at T = [")?;
+                for (i, t) in temperatures.iter().enumerate() {
+                    if i > 0 {
+                        write!(f, ", ")?;
+                    }
+                    write!(f, "{}", t)?;
+                }
+                write!(f, "]")
+            }
+            Self::NoCriticalPoint => write!(f, "no critical point found"),
+            Self::PhaseEquilibriumFailed { temperature } => write!(
+                f,
+                "phase equilibrium did not converge at T = {}",
+                temperature
+            ),
+        }
+    }
+}
+
+/// Result of a [parameter scan](State::parameter_scan) of a pure component.
+pub struct ParameterScan<E> {
+    /// All distinct critical points that were found, ordered by increasing density.
+    pub critical_points: Vec<State<E>>,
+    /// The inconsistencies detected during the scan.
+    pub issues: Vec<ParameterIssue>,
+}
+
+impl<E> ParameterScan<E> {
+    /// Return `true` if no inconsistencies were detected.
+    pub fn is_consistent(&self) -> bool {
+        self.issues.is_empty()
+    }
+}
+
+impl<E> fmt::Display for ParameterScan<E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.is_consistent() {
+            return write!(f, "no inconsistencies detected");
+        }
+        for issue in &self.issues {
+            writeln!(f, "{}", issue)?;
+        }
+        Ok(())
+    }
+}
+
+/// # Parameter checks
+impl<E: EquationOfState> State<E> {
+    /// Scan the parameters of a pure component for thermodynamic inconsistencies.
+    ///
+    /// For `temperature_points` temperatures between `min_temperature` and
+    /// `max_temperature`, the routine
+    /// * evaluates `density_points` states between 0 and the maximum density
+    /// and checks for states that can not be evaluated, non-finite properties
+    /// (e.g. due to failures of the association solver) and negative heat capacities,
+    /// * calculates the phase equilibrium (below the highest critical temperature) and checks
+    /// that the speed of sound increases monotonically along the compressed liquid isotherm,
+    /// * tries to converge the critical point starting from every temperature in the
+    /// range and reports if more than one distinct critical point is found.
+    ///
+    /// Caloric properties are evaluated including the ideal gas contribution of the
+    /// equation of state. The checks of heat capacities and the speed of sound are,
+    /// therefore, only meaningful if an ideal gas model is provided.
+    ///
+    /// Speed of sound checks are based on $\left(\frac{\partial p}{\partial\rho}\right)_{S}=\frac{1}{\rho\kappa_S}$,
+    /// which is, for a pure component, proportional to the square of the speed of sound.
+    /// Hence, the scan does not require the [MolarWeight](crate::MolarWeight) trait.
+    ///
+    /// The scan checks the physical plausibility of parameters. To verify the
+    /// implementation of an equation of state, e.g., its derivatives, use the
+    /// [consistency](crate::consistency) module instead.
+    pub fn parameter_scan(
+        eos: &Arc<E>,
+        min_temperature: SINumber,
+        max_temperature: SINumber,
+        temperature_points: usize,
+        density_points: usize,
+        options: SolverOptions,
+    ) -> EosResult<ParameterScan<E>>
+    where
+        SINumber: std::fmt::Display,
+    {
+        if eos.components() != 1 {
+            return Err(EosError::IncompatibleComponents(eos.components(), 1));
+        }
+        let temperatures =
+            SIArray1::linspace(min_temperature, max_temperature, temperature_points)?;
+        let max_density = eos.max_density(None)?;
+        let mut issues = Vec::new();
+
+        // critical points
+        let critical_points = Self::distinct_critical_points(eos, &temperatures, options);
+        match critical_points.len() {
+            0 => issues.push(ParameterIssue::NoCriticalPoint),
+            1 => (),
+            _ => issues.push(ParameterIssue::MultipleCriticalPoints(
+                critical_points.iter().map(|s| s.temperature).collect(),
+            )),
+        }
+        // phase equilibria can exist up to the highest critical temperature
+        let critical_temperature =
+            critical_points
+                .iter()
+                .map(|s| s.temperature)
+                .fold(None, |tc: Option<SINumber>, t| match tc {
+                    Some(tc) if tc >= t => Some(tc),
+                    _ => Some(t),
+                });
+
+        let mut vle: Option<PhaseEquilibrium<E, 2>> = None;
+        for temperature in &temperatures {
+            // homogeneous states
+            for i in 1..=density_points {
+                let density = max_density * (i as f64 / density_points as f64);
+                match Self::new_pure(eos, temperature, density) {
+                    Ok(state) => {
+                        if let Some(issue) = state.check_homogeneous() {
+                            issues.push(issue);
+                        }
+                    }
+                    Err(e) => issues.push(ParameterIssue::EvaluationFailed {
+                        temperature,
+                        density,
+                        error: e.to_string(),
+                    }),
+                }
+            }
+
+            // compressed liquid
+            if critical_temperature.map_or(true, |tc| temperature >= tc) {
+                vle = None;
+                continue;
+            }
+            vle = PhaseEquilibrium::pure(eos, temperature, vle.as_ref(), options).ok();
+            match &vle {
+                None => issues.push(ParameterIssue::PhaseEquilibriumFailed { temperature }),
+                Some(vle) => {
+                    let rho_l = vle.liquid().density;
+                    let mut c2_old = None;
+                    for i in 0..=density_points {
+                        let density =
+                            rho_l + (max_density - rho_l) * (i as f64 / density_points as f64);
+                        let state = match Self::new_pure(eos, temperature, density) {
+                            Ok(state) => state,
+                            Err(e) => {
+                                issues.push(ParameterIssue::EvaluationFailed {
+                                    temperature,
+                                    density,
+                                    error: e.to_string(),
+                                });
+                                break;
+                            }
+                        };
+                        let c2 = (1.0 / (state.density * state.isentropic_compressibility()))
+                            .to_reduced(SIUnit::reference_molar_energy())?;
+                        if !c2.is_finite() {
+                            break;
+                        }
+                        if c2_old.map_or(false, |c2_old| c2 < c2_old) {
+                            issues.push(ParameterIssue::NonMonotonicSpeedOfSound {
+                                temperature,
+                                density,
+                            });
+                            break;
+                        }
+                        c2_old = Some(c2);
+                    }
+                }
+            }
+        }
+
+        Ok(ParameterScan {
+            critical_points,
+            issues,
+        })
+    }
+
+    fn distinct_critical_points(
+        eos: &Arc<E>,
+        temperatures: &SIArray1,
+        options: SolverOptions,
+    ) -> Vec<Self>
+    where
+        SINumber: std::fmt::Display,
+    {
+        let mut critical_points: Vec<Self> = Vec::new();
+        for t0 in temperatures {
+            if let Ok(cp) = Self::critical_point(eos, None, Some(t0), options) {
+                let p = cp
+                    .pressure(Contributions::Total)
+                    .to_reduced(SIUnit::reference_pressure())
+                    .unwrap_or(f64::NAN);
+                if !p.is_finite() {
+                    continue;
+                }
+                let is_new = critical_points.iter().all(|s| {
+                    (cp.temperature - s.temperature).abs() > s.temperature * REL_TOL_CRITICAL_POINTS
+                        || (cp.density - s.density).abs() > s.density * REL_TOL_CRITICAL_POINTS
+                });
+                if is_new {
+                    critical_points.push(cp);
+                }
+            }
+        }
+        critical_points.sort_by(|s1, s2| {
+            s1.density
+                .partial_cmp(&s2.density)
+                .unwrap_or(Ordering::Equal)
+        });
+        critical_points
+    }
+
+    fn check_homogeneous(&self) -> Option<ParameterIssue> {
+        let is_finite = |x: SINumber, reference: SINumber| {
+            x.to_reduced(reference).map_or(false, |x| x.is_finite())
+        };
+        let a = self.helmholtz_energy(Contributions::Total);
+        let p = self.pressure(Contributions::Total);
+        let dp_dv = self.dp_dv(Contributions::Total);
+        let c_v = self.c_v(Contributions::Total);
+        if !(is_finite(a, SIUnit::reference_energy())
+            && is_finite(p, SIUnit::reference_pressure())
+            && is_finite(
+                dp_dv,
+                SIUnit::reference_pressure() / SIUnit::reference_volume(),
+            )
+            && is_finite(c_v, SIUnit::reference_molar_entropy()))
+        {
+            return Some(ParameterIssue::NonFiniteProperties {
+                temperature: self.temperature,
+                density: self.density,
+            });
+        }
+
+        // only mechanically stable states are considered
+        if !dp_dv.is_sign_negative() {
+            return None;
+        }
+        let c_p = self.c_p(Contributions::Total);
+        if c_v.is_sign_negative() || c_p.is_sign_negative() {
+            return Some(ParameterIssue::NegativeHeatCapacity {
+                temperature: self.temperature,
+                density: self.density,
+                c_v,
+                c_p,
+            });
+        }
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::equation_of_state::{HelmholtzEnergy, HelmholtzEnergyDual};
+    use crate::StateHD;
+    use ndarray::Array1;
+    use num_dual::DualNum;
+    use quantity::si::KELVIN;
+
+    /// Second virial coefficient that increases linearly with temperature,
+    /// which results in a negative isochoric heat capacity.
+    struct LinearVirial;
+
+    impl<D: DualNum<f64>> HelmholtzEnergyDual<D> for LinearVirial {
+        fn helmholtz_energy(&self, state: &StateHD<D>) -> D {
+            let n = state.moles.sum();
+            n * n / state.volume * state.temperature
+        }
+    }
+
+    impl fmt::Display for LinearVirial {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            write!(f, "Linear virial")
+        }
+    }
+
+    struct LinearVirialEos(Vec<Box<dyn HelmholtzEnergy>>);
+
+    impl EquationOfState for LinearVirialEos {
+        fn components(&self) -> usize {
+            1
+        }
+
+        fn subset(&self, _: &[usize]) -> Self {
+            Self(vec![Box::new(LinearVirial)])
+        }
+
+        fn compute_max_density(&self, _: &Array1<f64>) -> f64 {
+            0.01
+        }
+
+        fn residual(&self) -> &[Box<dyn HelmholtzEnergy>] {
+            &self.0
+        }
+    }
+
+    #[test]
+    fn negative_heat_capacity() -> EosResult<()> {
+        let eos = Arc::new(LinearVirialEos(vec![Box::new(LinearVirial)]));
+        let scan = State::parameter_scan(
+            &eos,
+            200.0 * KELVIN,
+            400.0 * KELVIN,
+            3,
+            5,
+            Default::default(),
+        )?;
+        assert!(!scan.is_consistent());
+        assert!(scan
+            .issues
+            .iter()
+            .any(|i| matches!(i, ParameterIssue::NegativeHeatCapacity { .. })));
+        assert!(scan
+            .issues
+            .iter()
+            .all(|i| !matches!(i, ParameterIssue::EvaluationFailed { .. })));
+        Ok(())
+    }
+}
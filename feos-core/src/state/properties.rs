@@ -536,6 +536,21 @@ impl<E: EquationOfState> State<E> {
             .unwrap()
     }
 
+    /// Molar flow of each component, assuming the state contains the material that passes in one second: $\dot N_i=\frac{N_i}{1\,\mathrm{s}}$
+    pub fn molar_flow(&self) -> SIArray1 {
+        &self.moles / SECOND
+    }
+
+    /// Total molar flow: $\dot N=\sum_i\dot N_i$
+    pub fn total_molar_flow(&self) -> SINumber {
+        self.total_moles / SECOND
+    }
+
+    /// Volume flow: $\dot V=\frac{V}{1\,\mathrm{s}}$
+    pub fn volume_flow(&self) -> SINumber {
+        self.volume / SECOND
+    }
+
     /// Helmholtz energy $A$ evaluated for each contribution of the equation of state.
     pub fn helmholtz_energy_contributions(&self) -> Vec<(String, SINumber)> {
         let new_state = self.derive0();
@@ -613,6 +628,16 @@ impl<E: EquationOfState + MolarWeight> State<E> {
         self.total_moles * self.total_molar_weight()
     }
 
+    /// Mass flow of each component: $\dot m_i=\dot N_iMW_i$
+    pub fn mass_flow(&self) -> SIArray1 {
+        self.molar_flow() * self.eos.molar_weight()
+    }
+
+    /// Total mass flow: $\dot m=\sum_i\dot m_i$
+    pub fn total_mass_flow(&self) -> SINumber {
+        self.total_mass() / SECOND
+    }
+
     /// Mass density: $\rho^{(m)}=\frac{m}{V}$
     pub fn mass_density(&self) -> SINumber {
         self.density * self.total_molar_weight()
@@ -841,6 +866,44 @@ impl<'a, E: EquationOfState> StateVec<'a, E> {
             self.0[i].molar_entropy(Contributions::Total)
         })
     }
+
+    pub fn molar_internal_energy(&self) -> SIArray1 {
+        SIArray1::from_shape_fn(self.0.len(), |i| {
+            self.0[i].molar_internal_energy(Contributions::Total)
+        })
+    }
+
+    pub fn molar_helmholtz_energy(&self) -> SIArray1 {
+        SIArray1::from_shape_fn(self.0.len(), |i| {
+            self.0[i].molar_helmholtz_energy(Contributions::Total)
+        })
+    }
+
+    pub fn molar_gibbs_energy(&self) -> SIArray1 {
+        SIArray1::from_shape_fn(self.0.len(), |i| {
+            self.0[i].molar_gibbs_energy(Contributions::Total)
+        })
+    }
+
+    pub fn c_v(&self) -> SIArray1 {
+        SIArray1::from_shape_fn(self.0.len(), |i| self.0[i].c_v(Contributions::Total))
+    }
+
+    pub fn c_p(&self) -> SIArray1 {
+        SIArray1::from_shape_fn(self.0.len(), |i| self.0[i].c_p(Contributions::Total))
+    }
+
+    pub fn joule_thomson(&self) -> SIArray1 {
+        SIArray1::from_shape_fn(self.0.len(), |i| self.0[i].joule_thomson())
+    }
+
+    pub fn isentropic_compressibility(&self) -> SIArray1 {
+        SIArray1::from_shape_fn(self.0.len(), |i| self.0[i].isentropic_compressibility())
+    }
+
+    pub fn isothermal_compressibility(&self) -> SIArray1 {
+        SIArray1::from_shape_fn(self.0.len(), |i| self.0[i].isothermal_compressibility())
+    }
 }
 
 impl<'a, E: EquationOfState + MolarWeight> StateVec<'a, E> {
@@ -865,4 +928,30 @@ impl<'a, E: EquationOfState + MolarWeight> StateVec<'a, E> {
             self.0[i].specific_entropy(Contributions::Total)
         })
     }
+
+    pub fn speed_of_sound(&self) -> SIArray1 {
+        SIArray1::from_shape_fn(self.0.len(), |i| self.0[i].speed_of_sound())
+    }
+}
+
+impl<'a, E: EquationOfState + EntropyScaling> StateVec<'a, E> {
+    pub fn viscosity(&self) -> EosResult<SIArray1> {
+        let viscosity = self
+            .0
+            .iter()
+            .map(|s| s.viscosity())
+            .collect::<EosResult<Vec<_>>>()?;
+        Ok(SIArray1::from_shape_fn(viscosity.len(), |i| viscosity[i]))
+    }
+
+    pub fn thermal_conductivity(&self) -> EosResult<SIArray1> {
+        let thermal_conductivity = self
+            .0
+            .iter()
+            .map(|s| s.thermal_conductivity())
+            .collect::<EosResult<Vec<_>>>()?;
+        Ok(SIArray1::from_shape_fn(thermal_conductivity.len(), |i| {
+            thermal_conductivity[i]
+        }))
+    }
 }
@@ -0,0 +1,106 @@
+use super::{Contributions, State, StateHD};
+use crate::equation_of_state::EquationOfState;
+use crate::errors::{EosError, EosResult};
+use crate::phase_equilibria::PhaseEquilibrium;
+use crate::EosUnit;
+use ndarray::Array1;
+use num_dual::{Dual64, DualNum, HyperDual64};
+use quantity::si::{SIArray1, SIUnit};
+
+/// Equation of state with a residual Helmholtz energy that can be
+/// evaluated for model parameters given as (generalized) dual numbers.
+///
+/// The derivatives of properties with respect to the model parameters
+/// are then calculated exactly and without additional iterations, e.g.,
+/// for gradient-based parameter regressions or the propagation of
+/// parameter uncertainties. All sensitivities are returned as
+/// derivatives with respect to the parameters in the order (and units)
+/// of [parameter_names](ParameterSensitivity::parameter_names).
+///
+/// Implementations can be restricted to a subset of their model, e.g.,
+/// the PC-SAFT equation of state in `feos` returns an error for polar
+/// or associating components. Sensitivities of surface tensions are
+/// calculated by `PlanarInterface::surface_tension_sensitivities` in
+/// `feos-dft`.
+pub trait ParameterSensitivity: EquationOfState {
+    /// The names of the model parameters, e.g., `sigma_0` or `k_01`.
+    fn parameter_names(&self) -> Vec<String>;
+
+    /// The values of the model parameters.
+    fn parameter_values(&self) -> Array1<f64>;
+
+    /// The reduced residual Helmholtz energy $\beta A^\mathrm{res}$
+    /// evaluated with the given model parameters.
+    fn parametric_residual<D: DualNum<f64>>(
+        &self,
+        parameters: &[D],
+        state: &StateHD<D>,
+    ) -> EosResult<D>;
+}
+
+/// # Parameter sensitivities
+impl<E: ParameterSensitivity> State<E> {
+    /// Derivatives of the residual Helmholtz energy w.r.t. the model parameters
+    /// at constant temperature, volume and moles.
+    pub fn helmholtz_energy_sensitivities(&self) -> EosResult<SIArray1> {
+        let parameters = self.eos.parameter_values();
+        let state = StateHD::new(
+            Dual64::from(self.reduced_temperature),
+            Dual64::from(self.reduced_volume),
+            self.reduced_moles.mapv(Dual64::from),
+        );
+        let mut a = Array1::zeros(parameters.len());
+        for k in 0..parameters.len() {
+            let mut p: Vec<_> = parameters.iter().map(|&p| Dual64::from(p)).collect();
+            p[k] = p[k].derive();
+            a[k] = self.eos.parametric_residual(&p, &state)?.eps[0];
+        }
+        Ok(a * self.reduced_temperature * SIUnit::reference_energy())
+    }
+
+    /// Derivatives of the pressure w.r.t. the model parameters at constant
+    /// temperature, volume and moles.
+    pub fn pressure_sensitivities(&self) -> EosResult<SIArray1> {
+        let parameters = self.eos.parameter_values();
+        let state = StateHD::new(
+            HyperDual64::from(self.reduced_temperature),
+            HyperDual64::from(self.reduced_volume).derive1(),
+            self.reduced_moles.mapv(HyperDual64::from),
+        );
+        let mut p = Array1::zeros(parameters.len());
+        for k in 0..parameters.len() {
+            let mut theta: Vec<_> = parameters.iter().map(|&p| HyperDual64::from(p)).collect();
+            theta[k] = theta[k].derive2();
+            p[k] = -self.eos.parametric_residual(&theta, &state)?.eps1eps2[(0, 0)];
+        }
+        Ok(p * self.reduced_temperature * SIUnit::reference_pressure())
+    }
+
+    /// Derivatives of the density w.r.t. the model parameters at constant
+    /// temperature, pressure and moles.
+    pub fn density_sensitivities(&self) -> EosResult<SIArray1> {
+        Ok(-self.pressure_sensitivities()? / self.dp_drho(Contributions::Total))
+    }
+}
+
+impl<E: ParameterSensitivity> PhaseEquilibrium<E, 2> {
+    /// Derivatives of the vapor pressure of a pure component w.r.t. the
+    /// model parameters at constant temperature.
+    ///
+    /// The derivatives follow from the equality of the molar Gibbs energies
+    /// of both phases as
+    /// $$\frac{\mathrm{d}p^\mathrm{sat}}{\mathrm{d}\theta}=\frac{a_\theta^\mathrm{L}-a_\theta^\mathrm{V}}{v^\mathrm{V}-v^\mathrm{L}}$$
+    /// with the derivatives of the molar Helmholtz energies $a_\theta$
+    /// at constant temperature and molar volume, so that no additional
+    /// phase equilibrium calculation is required.
+    pub fn vapor_pressure_sensitivities(&self) -> EosResult<SIArray1> {
+        let (vapor, liquid) = (self.vapor(), self.liquid());
+        let n = vapor.eos.components();
+        if n != 1 {
+            return Err(EosError::IncompatibleComponents(1, n));
+        }
+        let a_l = liquid.helmholtz_energy_sensitivities()? / liquid.total_moles;
+        let a_v = vapor.helmholtz_energy_sensitivities()? / vapor.total_moles;
+        Ok((&a_l - &a_v) / (1.0 / vapor.density - 1.0 / liquid.density))
+    }
+}
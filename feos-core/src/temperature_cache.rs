@@ -0,0 +1,203 @@
+use ndarray::{Array, ArrayD, Dimension};
+use num_dual::{Dual3_64, DualNum};
+use std::collections::{HashMap, VecDeque};
+use std::sync::Mutex;
+
+const CAPACITY: usize = 32;
+
+#[derive(Default)]
+struct CacheEntries {
+    /// Cached quantities of all stored temperatures, the most recently used temperature last
+    temperatures: VecDeque<(f64, HashMap<&'static str, ArrayD<Dual3_64>>)>,
+    hit: u64,
+    miss: u64,
+}
+
+impl CacheEntries {
+    /// Return the quantities at the given temperature and mark the temperature
+    /// as most recently used. If all slots are occupied, the least recently
+    /// used temperature is removed.
+    fn get_mut(&mut self, temperature: f64) -> &mut HashMap<&'static str, ArrayD<Dual3_64>> {
+        match self
+            .temperatures
+            .iter()
+            .position(|(t, _)| *t == temperature)
+        {
+            Some(i) => {
+                let entry = self.temperatures.remove(i).unwrap();
+                self.temperatures.push_back(entry);
+            }
+            None => {
+                if self.temperatures.len() == CAPACITY {
+                    self.temperatures.pop_front();
+                }
+                self.temperatures.push_back((temperature, HashMap::new()));
+            }
+        }
+        &mut self.temperatures.back_mut().unwrap().1
+    }
+}
+
+/// Cache for temperature-dependent model quantities, like (effective)
+/// segment diameters, that is shared between all contributions of
+/// an equation of state or Helmholtz energy functional.
+///
+/// Every quantity is evaluated only once per temperature and stored as
+/// Taylor expansion up to third order around the (real) temperature.
+/// From the expansion, the quantity is reconstructed for arbitrary dual
+/// numbers, which is exact for derivatives with respect to temperature of
+/// up to third order. The quantities of the 32 most recently used
+/// temperatures are stored, so that parallel evaluations at different
+/// temperatures (e.g., of a phase diagram with rayon) do not invalidate
+/// each other.
+#[derive(Default)]
+pub struct TemperatureCache {
+    entries: Mutex<CacheEntries>,
+}
+
+impl TemperatureCache {
+    /// Create a new, empty cache.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Return the quantity `key` at the given temperature, evaluating
+    /// `f` if the quantity is not yet cached for this temperature.
+    ///
+    /// The closure is evaluated without holding the lock of the cache
+    /// and can therefore depend on other cached quantities.
+    pub fn get_or_insert_with<D, Dim, F>(
+        &self,
+        key: &'static str,
+        temperature: D,
+        f: F,
+    ) -> Array<D, Dim>
+    where
+        D: DualNum<f64>,
+        Dim: Dimension,
+        F: FnOnce(Dual3_64) -> Array<Dual3_64, Dim>,
+    {
+        let t = temperature.re();
+        let cached = {
+            let mut entries = self.entries.lock().unwrap();
+            let cached = entries.get_mut(t).get(key).cloned();
+            if cached.is_some() {
+                entries.hit += 1;
+            } else {
+                entries.miss += 1;
+            }
+            cached
+        };
+        let values = match cached {
+            Some(values) => values,
+            None => {
+                let values = f(Dual3_64::from(t).derive()).into_dyn();
+                let mut entries = self.entries.lock().unwrap();
+                entries.get_mut(t).insert(key, values.clone());
+                values
+            }
+        };
+
+        // Taylor expansion around the real part of the temperature
+        let dt = temperature - t;
+        values
+            .into_dimensionality()
+            .unwrap()
+            .mapv(|v| ((dt * (v.v3 / 6.0) + v.v2 * 0.5) * dt + v.v1) * dt + v.re)
+    }
+
+    /// Remove all cached quantities.
+    pub fn clear(&self) {
+        self.entries.lock().unwrap().temperatures.clear();
+    }
+
+    /// The number of cache hits and misses.
+    pub fn statistics(&self) -> (u64, u64) {
+        let entries = self.entries.lock().unwrap();
+        (entries.hit, entries.miss)
+    }
+}
+
+impl Clone for TemperatureCache {
+    fn clone(&self) -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_relative_eq;
+    use ndarray::{arr1, Ix1};
+    use num_dual::{Dual2_64, HyperDual64};
+
+    fn diameter<D: DualNum<f64>>(temperature: D) -> Array<D, Ix1> {
+        arr1(&[
+            -((-temperature.recip() * 3.0).exp() * 0.12 - 1.0) * 3.5,
+            temperature.sqrt(),
+        ])
+    }
+
+    #[test]
+    fn taylor_expansion() {
+        let cache = TemperatureCache::new();
+        let t = 300.0;
+
+        let d: Array<f64, _> = cache.get_or_insert_with("d", t, diameter);
+        assert_eq!(d, diameter(t));
+
+        let t_d2 = Dual2_64::from(t).derive();
+        let d_d2 = cache.get_or_insert_with("d", t_d2, diameter);
+        for (d, d_ref) in d_d2.iter().zip(diameter(t_d2).iter()) {
+            assert_relative_eq!(d.re, d_ref.re, max_relative = 1e-12);
+            assert_relative_eq!(d.v1[0], d_ref.v1[0], max_relative = 1e-12);
+            assert_relative_eq!(d.v2[0], d_ref.v2[0], max_relative = 1e-12);
+        }
+
+        let mut t_hd = HyperDual64::from(t);
+        t_hd.eps1[0] = 2.0;
+        t_hd.eps2[0] = 0.5;
+        let d_hd = cache.get_or_insert_with("d", t_hd, diameter);
+        for (d, d_ref) in d_hd.iter().zip(diameter(t_hd).iter()) {
+            assert_relative_eq!(d.eps1[0], d_ref.eps1[0], max_relative = 1e-12);
+            assert_relative_eq!(d.eps2[0], d_ref.eps2[0], max_relative = 1e-12);
+            assert_relative_eq!(
+                d.eps1eps2[(0, 0)],
+                d_ref.eps1eps2[(0, 0)],
+                max_relative = 1e-12
+            );
+        }
+        assert_eq!(cache.statistics(), (2, 1));
+
+        // a new temperature does not invalidate the cache
+        cache.get_or_insert_with("d", t + 1.0, diameter);
+        cache.get_or_insert_with("d", t, diameter);
+        assert_eq!(cache.statistics(), (3, 2));
+
+        // the least recently used temperature is removed
+        for i in 0..CAPACITY {
+            cache.get_or_insert_with("d", t + 2.0 + i as f64, diameter);
+        }
+        cache.get_or_insert_with("d", t, diameter);
+        assert_eq!(cache.statistics(), (3, 3 + CAPACITY as u64));
+    }
+
+    #[test]
+    fn parallel_temperatures() {
+        // every thread evaluates the quantity repeatedly at its own temperature
+        let cache = TemperatureCache::new();
+        let threads = 8;
+        let evaluations = 100;
+        std::thread::scope(|s| {
+            for i in 0..threads {
+                let cache = &cache;
+                s.spawn(move || {
+                    for _ in 0..evaluations {
+                        cache.get_or_insert_with("d", 300.0 + i as f64, diameter);
+                    }
+                });
+            }
+        });
+        assert_eq!(cache.statistics(), (threads * (evaluations - 1), threads));
+    }
+}
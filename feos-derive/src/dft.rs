@@ -3,11 +3,12 @@ use syn::DeriveInput;
 
 use crate::implement;
 
-const OPT_IMPLS: [&str; 4] = [
+const OPT_IMPLS: [&str; 5] = [
     "bond_lengths",
     "molar_weight",
     "fluid_parameters",
     "pair_potential",
+    "ideal_gas",
 ];
 
 pub(crate) fn expand_helmholtz_energy_functional(
@@ -23,12 +24,14 @@ pub(crate) fn expand_helmholtz_energy_functional(
     let molar_weight = impl_molar_weight(variants)?;
     let fluid_parameters = impl_fluid_parameters(variants)?;
     let pair_potential = impl_pair_potential(variants)?;
+    let ideal_gas = impl_ideal_gas(variants)?;
     Ok(quote! {
         #from
         #functional
         #molar_weight
         #fluid_parameters
         #pair_potential
+        #ideal_gas
     })
 }
 
@@ -245,3 +248,30 @@ fn impl_pair_potential(
         }
     })
 }
+
+fn impl_ideal_gas(
+    variants: &syn::punctuated::Punctuated<syn::Variant, syn::token::Comma>,
+) -> syn::Result<proc_macro2::TokenStream> {
+    let mut ideal_gas = Vec::new();
+
+    for v in variants.iter() {
+        if implement("ideal_gas", v, &OPT_IMPLS)? {
+            let name = &v.ident;
+            ideal_gas.push(quote! {
+                Self::#name(functional) => Ok(Self::#name(functional.with_ideal_gas(model)?))
+            });
+        }
+    }
+    Ok(quote! {
+        impl WithIdealGas for FunctionalVariant {
+            fn with_ideal_gas(self, model: IdealGasModel) -> EosResult<Self> {
+                match self {
+                    #(#ideal_gas,)*
+                    _ => Err(EosError::Error(String::from(
+                        "The ideal gas contribution of this functional cannot be replaced.",
+                    ))),
+                }
+            }
+        }
+    })
+}
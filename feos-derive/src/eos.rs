@@ -3,7 +3,7 @@ use quote::quote;
 use syn::DeriveInput;
 
 // possible additional traits to implement
-const OPT_IMPLS: [&str; 2] = ["molar_weight", "entropy_scaling"];
+const OPT_IMPLS: [&str; 3] = ["molar_weight", "entropy_scaling", "ideal_gas"];
 
 pub(crate) fn expand_equation_of_state(
     input: DeriveInput,
@@ -16,10 +16,12 @@ pub(crate) fn expand_equation_of_state(
     let eos = impl_equation_of_state(variants);
     let molar_weight = impl_molar_weight(variants)?;
     let entropy_scaling = impl_entropy_scaling(variants)?;
+    let ideal_gas = impl_ideal_gas(variants)?;
     Ok(quote! {
         #eos
         #molar_weight
         #entropy_scaling
+        #ideal_gas
     })
 }
 
@@ -208,3 +210,30 @@ fn impl_entropy_scaling(
         }
     })
 }
+
+fn impl_ideal_gas(
+    variants: &syn::punctuated::Punctuated<syn::Variant, syn::token::Comma>,
+) -> syn::Result<proc_macro2::TokenStream> {
+    let mut ideal_gas = Vec::new();
+
+    for v in variants.iter() {
+        if implement("ideal_gas", v, &OPT_IMPLS)? {
+            let name = &v.ident;
+            ideal_gas.push(quote! {
+                Self::#name(eos) => Ok(Self::#name(eos.with_ideal_gas(model)?))
+            });
+        }
+    }
+    Ok(quote! {
+        impl WithIdealGas for EosVariant {
+            fn with_ideal_gas(self, model: IdealGasModel) -> EosResult<Self> {
+                match self {
+                    #(#ideal_gas,)*
+                    _ => Err(EosError::Error(String::from(
+                        "The ideal gas contribution of this equation of state cannot be replaced.",
+                    ))),
+                }
+            }
+        }
+    })
+}
@@ -0,0 +1,252 @@
+//! Transient simulation of fixed-bed adsorption columns.
+use super::IdealAdsorbedSolution;
+use feos_core::{EosError, EosResult, SolverOptions};
+use ndarray::{s, Array1, Array2, ArrayView1, Axis};
+use quantity::si::{SIArray1, SIArray2, SINumber, METER, MOL, RGAS, SECOND};
+
+const SAFETY_FACTOR: f64 = 0.4;
+const HENRY_FRACTION: f64 = 1e-6;
+
+/// One-dimensional fixed-bed adsorption column.
+///
+/// The column is isothermal and isobaric and the adsorbing components
+/// are diluted in an inert carrier gas, so that the interstitial velocity
+/// $v$ is constant. The concentrations $c_i$ in the gas phase follow from
+/// the axially dispersed plug flow model
+/// $$\frac{\partial c_i}{\partial t}=D_\mathrm{ax}\frac{\partial^2c_i}{\partial z^2}-v\frac{\partial c_i}{\partial z}-\frac{\rho_\mathrm{b}}{\varepsilon}\frac{\partial q_i}{\partial t}$$
+/// and the adsorbed amounts $q_i$ from the linear driving force (LDF) model
+/// $$\frac{\partial q_i}{\partial t}=k_i\left(q_i^*-q_i\right)$$
+/// with Danckwerts boundary conditions at the inlet and the outlet. The
+/// equilibrium loadings $q_i^*$ are calculated from an [IdealAdsorbedSolution]
+/// that can be built from analytic isotherms or from DFT results (via
+/// [Adsorption::pure_component_isotherm](super::Adsorption::pure_component_isotherm)).
+#[derive(Clone)]
+pub struct FixedBed {
+    isotherm: IdealAdsorbedSolution,
+    temperature: SINumber,
+    length: SINumber,
+    porosity: f64,
+    bed_density: SINumber,
+    velocity: SINumber,
+    axial_dispersion: SINumber,
+    mass_transfer_coefficients: SIArray1,
+}
+
+/// Outlet concentrations of a fixed-bed adsorption column over time.
+pub struct BreakthroughCurve {
+    /// Times at which the outlet concentrations are evaluated.
+    pub time: SIArray1,
+    /// Concentrations of all components at the outlet of the column
+    /// (components x time).
+    pub outlet_concentration: SIArray2,
+    /// Concentrations of all components in the feed.
+    pub feed_concentration: SIArray1,
+}
+
+impl FixedBed {
+    /// Create a new fixed-bed adsorption column.
+    ///
+    /// The `bed_density` is the amount of adsorbent per volume of the
+    /// column and has to be chosen such that its product with the adsorbed
+    /// amounts of the isotherms is a concentration, e.g., a mass density
+    /// for isotherms in mol/kg.
+    pub fn new(
+        isotherm: IdealAdsorbedSolution,
+        temperature: SINumber,
+        length: SINumber,
+        porosity: f64,
+        bed_density: SINumber,
+        velocity: SINumber,
+        axial_dispersion: SINumber,
+        mass_transfer_coefficients: SIArray1,
+    ) -> EosResult<Self> {
+        if mass_transfer_coefficients.len() != isotherm.components() {
+            return Err(EosError::IncompatibleComponents(
+                isotherm.components(),
+                mass_transfer_coefficients.len(),
+            ));
+        }
+        if porosity <= 0.0 || porosity >= 1.0 {
+            return Err(EosError::Error(String::from(
+                "The porosity of the bed has to be between 0 and 1.",
+            )));
+        }
+        Ok(Self {
+            isotherm,
+            temperature,
+            length,
+            porosity,
+            bed_density,
+            velocity,
+            axial_dispersion,
+            mass_transfer_coefficients,
+        })
+    }
+
+    /// Return the number of components.
+    pub fn components(&self) -> usize {
+        self.isotherm.components()
+    }
+
+    /// Calculate the equilibrium loadings (per volume of the column)
+    /// for the given (reduced) concentrations in the gas phase.
+    fn equilibrium_loading(
+        &self,
+        concentration: ArrayView1<f64>,
+        options: SolverOptions,
+    ) -> EosResult<Array1<f64>> {
+        let density = MOL / METER.powi(3);
+        let concentration = concentration.mapv(|c| c.max(0.0));
+        let total = concentration.sum();
+        if total <= 0.0 {
+            return Ok(Array1::zeros(concentration.len()));
+        }
+        let pressure = total * density * RGAS * self.temperature;
+        let adsorption = self
+            .isotherm
+            .adsorption(pressure, &(concentration / total), options)?;
+        Ok((adsorption * self.bed_density).to_reduced(density)?)
+    }
+
+    /// Simulate the loading of an initially clean column and return the
+    /// concentrations at the outlet.
+    ///
+    /// The feed is specified by the total pressure and the mole fractions of
+    /// the adsorbing components; the remainder is the inert carrier gas.
+    /// The column is discretized with `n_grid` finite volumes and the outlet
+    /// concentrations are stored at `n_time` equidistant times up to
+    /// `duration`. The time step is chosen automatically to keep the explicit
+    /// (second order strong stability preserving Runge-Kutta) integration stable.
+    pub fn breakthrough_curve(
+        &self,
+        pressure: SINumber,
+        molefracs: &Array1<f64>,
+        duration: SINumber,
+        n_grid: usize,
+        n_time: usize,
+        options: SolverOptions,
+    ) -> EosResult<BreakthroughCurve> {
+        let components = self.components();
+        if molefracs.len() != components {
+            return Err(EosError::IncompatibleComponents(
+                components,
+                molefracs.len(),
+            ));
+        }
+        if molefracs.iter().any(|&y| y < 0.0) || molefracs.sum() > 1.0 {
+            return Err(EosError::Error(String::from(
+                "The mole fractions of the feed have to be positive and sum up to at most 1.",
+            )));
+        }
+        if n_grid < 2 || n_time < 2 {
+            return Err(EosError::Error(String::from(
+                "At least two grid points and two times are required.",
+            )));
+        }
+
+        // reduced properties of the column
+        let density = MOL / METER.powi(3);
+        let c_feed = molefracs * (pressure / (RGAS * self.temperature)).to_reduced(density)?;
+        let dz = self.length.to_reduced(METER)? / n_grid as f64;
+        let v = self.velocity.to_reduced(METER / SECOND)?;
+        let d_ax = self.axial_dispersion.to_reduced(METER.powi(2) / SECOND)?;
+        let k = self
+            .mass_transfer_coefficients
+            .to_reduced(SECOND.powi(-1))?;
+        let eps = self.porosity;
+
+        // maximum stable time step from convection, dispersion and
+        // mass transfer (estimated with the Henry coefficients)
+        let c_henry = &c_feed * HENRY_FRACTION;
+        let henry = self.equilibrium_loading(c_henry.view(), options)? / &c_henry;
+        let dt_max = k
+            .iter()
+            .zip(henry.iter())
+            .filter(|(_, h)| h.is_finite())
+            .map(|(&k, &h)| 1.0 / (k * (1.0 + h / eps)))
+            .fold((dz / v).min(0.5 * dz * dz / d_ax), f64::min)
+            * SAFETY_FACTOR;
+        let output_interval = duration.to_reduced(SECOND)? / (n_time - 1) as f64;
+        let steps = (output_interval / dt_max).ceil().max(1.0) as usize;
+        let dt = output_interval / steps as f64;
+
+        // time derivatives of concentrations and loadings
+        let rhs = |c: &Array2<f64>, q: &Array2<f64>| -> EosResult<(Array2<f64>, Array2<f64>)> {
+            let mut q_eq = Array2::zeros(q.raw_dim());
+            for (j, c) in c.axis_iter(Axis(1)).enumerate() {
+                q_eq.column_mut(j)
+                    .assign(&self.equilibrium_loading(c, options)?);
+            }
+            let dq = (q_eq - q) * &k.view().insert_axis(Axis(1));
+
+            let mut flux = Array2::zeros((components, n_grid + 1));
+            flux.column_mut(0).assign(&(&c_feed * v));
+            let c_left = c.slice(s![.., ..-1]);
+            let c_right = c.slice(s![.., 1..]);
+            flux.slice_mut(s![.., 1..-1])
+                .assign(&(&c_left * v - (&c_right - &c_left) * (d_ax / dz)));
+            flux.column_mut(n_grid).assign(&(&c.column(n_grid - 1) * v));
+            let dc = (&flux.slice(s![.., ..-1]) - &flux.slice(s![.., 1..])) / dz - &dq / eps;
+            Ok((dc, dq))
+        };
+
+        let mut c = Array2::zeros((components, n_grid));
+        let mut q = Array2::zeros((components, n_grid));
+        let mut outlet = Array2::zeros((components, n_time));
+        for t in 1..n_time {
+            for _ in 0..steps {
+                let (dc, dq) = rhs(&c, &q)?;
+                let c1 = &c + &(dc * dt);
+                let q1 = &q + &(dq * dt);
+                let (dc, dq) = rhs(&c1, &q1)?;
+                c = (c + c1 + dc * dt) * 0.5;
+                q = (q + q1 + dq * dt) * 0.5;
+            }
+            outlet.column_mut(t).assign(&c.column(n_grid - 1));
+        }
+
+        Ok(BreakthroughCurve {
+            time: Array1::linspace(0.0, duration.to_reduced(SECOND)?, n_time) * SECOND,
+            outlet_concentration: outlet * density,
+            feed_concentration: c_feed * density,
+        })
+    }
+}
+
+impl BreakthroughCurve {
+    /// Return the outlet concentrations relative to the feed concentrations.
+    ///
+    /// Components that are not part of the feed are marked with `NaN`.
+    pub fn relative_concentration(&self) -> EosResult<Array2<f64>> {
+        let density = MOL / METER.powi(3);
+        let c_feed = self.feed_concentration.to_reduced(density)?;
+        Ok(self.outlet_concentration.to_reduced(density)?
+            / &c_feed
+                .mapv(|c| if c > 0.0 { c } else { f64::NAN })
+                .insert_axis(Axis(1)))
+    }
+
+    /// Return the time at which the relative outlet concentration of the
+    /// given component first exceeds `threshold`, or [None] if the
+    /// component does not break through during the simulation.
+    pub fn breakthrough_time(
+        &self,
+        component: usize,
+        threshold: f64,
+    ) -> EosResult<Option<SINumber>> {
+        if component >= self.feed_concentration.len() {
+            return Err(EosError::Error(format!(
+                "The breakthrough curve does not contain component {component}."
+            )));
+        }
+        let relative = self.relative_concentration()?;
+        let relative = relative.row(component);
+        Ok((1..relative.len())
+            .find(|&i| relative[i] >= threshold)
+            .map(|i| {
+                let (t0, t1) = (self.time.get(i - 1), self.time.get(i));
+                let (r0, r1) = (relative[i - 1], relative[i]);
+                t0 + (t1 - t0) * ((threshold - r0) / (r1 - r0))
+            }))
+    }
+}
@@ -0,0 +1,146 @@
+//! Selectivities, working capacities and isosteric heats of mixture adsorption.
+use super::{Adsorption, FluidParameters};
+use crate::functional::HelmholtzEnergyFunctional;
+use feos_core::{EosError, EosResult, EosUnit};
+use ndarray::{Array1, Array2, Dimension, RemoveAxis};
+use quantity::si::{SIArray1, SIArray2, SINumber, SIUnit};
+
+/// Working capacity of a pressure swing between two pressures of an isotherm.
+#[derive(Clone)]
+pub struct WorkingCapacity {
+    pub adsorption_pressure: SINumber,
+    pub desorption_pressure: SINumber,
+    /// adsorbed amounts at the adsorption pressure
+    pub adsorption: SIArray1,
+    /// adsorbed amounts at the desorption pressure
+    pub desorption: SIArray1,
+    /// composition of the bulk phase at the adsorption pressure
+    pub molefracs: Array1<f64>,
+}
+
+impl WorkingCapacity {
+    /// The working capacity $\Delta N_i=N_i(p_\mathrm{ads})-N_i(p_\mathrm{des})$ of each component.
+    pub fn working_capacity(&self) -> SIArray1 {
+        &self.adsorption - &self.desorption
+    }
+
+    /// The total working capacity $\sum_i\Delta N_i$.
+    pub fn total_working_capacity(&self) -> SINumber {
+        self.working_capacity().sum()
+    }
+
+    /// The selectivity of the working capacity of component `i` over component `j`
+    /// $$S_{ij}=\frac{\Delta N_i/\Delta N_j}{y_i/y_j}$$
+    pub fn selectivity(&self, i: usize, j: usize) -> EosResult<f64> {
+        let dn = self.working_capacity();
+        Ok((dn.get(i) / dn.get(j)).into_value()? / (self.molefracs[i] / self.molefracs[j]))
+    }
+}
+
+impl<D: Dimension + RemoveAxis + 'static, F: HelmholtzEnergyFunctional + FluidParameters>
+    Adsorption<D, F>
+where
+    SINumber: std::fmt::Display,
+    D::Larger: Dimension<Smaller = D>,
+    D::Smaller: Dimension<Larger = D>,
+    <D::Larger as Dimension>::Larger: Dimension<Smaller = D::Larger>,
+{
+    /// The composition of the bulk phase for every point of the isotherm.
+    pub fn molefracs(&self) -> Array2<f64> {
+        Array2::from_shape_fn(
+            (self.components, self.profiles.len()),
+            |(j, i)| match &self.profiles[i] {
+                Ok(p) => p.profile.bulk.molefracs[j],
+                Err(_) => f64::NAN,
+            },
+        )
+    }
+
+    /// The adsorption selectivity of component `i` over component `j`
+    /// $$S_{ij}=\frac{N_i/N_j}{y_i/y_j}$$
+    /// for every point of the isotherm.
+    pub fn selectivity(&self, i: usize, j: usize) -> EosResult<Array1<f64>> {
+        if i >= self.components || j >= self.components {
+            return Err(EosError::Error(format!(
+                "Invalid component indices ({}, {}) for {} components.",
+                i, j, self.components
+            )));
+        }
+        let adsorption = self.adsorption();
+        let molefracs = self.molefracs();
+        (0..self.profiles.len())
+            .map(|k| {
+                Ok(
+                    (adsorption.get((i, k)) / adsorption.get((j, k))).into_value()?
+                        / (molefracs[[i, k]] / molefracs[[j, k]]),
+                )
+            })
+            .collect()
+    }
+
+    /// The isosteric heat of adsorption $q_{\mathrm{st},i}=RT^2\left(\frac{\partial\ln p_i}{\partial T}\right)_{N_k}$
+    /// of each component for every point of the isotherm, calculated from the
+    /// exact temperature derivatives of the density profiles.
+    ///
+    /// See [PoreProfile::isosteric_heat](super::PoreProfile::isosteric_heat).
+    pub fn isosteric_heat(&self) -> SIArray2 {
+        let q_st: Vec<_> = self
+            .profiles
+            .iter()
+            .map(
+                |p| match p.as_ref().ok().and_then(|p| p.isosteric_heat().ok()) {
+                    Some(q) => q,
+                    None => {
+                        f64::NAN * Array1::ones(self.components) * SIUnit::reference_molar_energy()
+                    }
+                },
+            )
+            .collect();
+        SIArray2::from_shape_fn((self.components, self.profiles.len()), |(j, i)| {
+            q_st[i].get(j)
+        })
+    }
+
+    /// The working capacity between an adsorption and a desorption pressure,
+    /// obtained by linear interpolation of the isotherm.
+    ///
+    /// Both pressures have to be within the pressure range of the isotherm.
+    pub fn working_capacity(
+        &self,
+        adsorption_pressure: SINumber,
+        desorption_pressure: SINumber,
+    ) -> EosResult<WorkingCapacity> {
+        let (adsorption, molefracs) = self.interpolate(adsorption_pressure)?;
+        let (desorption, _) = self.interpolate(desorption_pressure)?;
+        Ok(WorkingCapacity {
+            adsorption_pressure,
+            desorption_pressure,
+            adsorption,
+            desorption,
+            molefracs,
+        })
+    }
+
+    fn interpolate(&self, pressure: SINumber) -> EosResult<(SIArray1, Array1<f64>)> {
+        let p = self.pressure().to_reduced(SIUnit::reference_pressure())?;
+        let p_int = pressure.to_reduced(SIUnit::reference_pressure())?;
+        let i = (1..p.len())
+            .find(|&i| p[i - 1] <= p_int && p_int <= p[i])
+            .ok_or_else(|| {
+                EosError::Error(format!(
+                    "The pressure {} is outside the range of the isotherm.",
+                    pressure
+                ))
+            })?;
+        let x = (p_int - p[i - 1]) / (p[i] - p[i - 1]);
+        let adsorption = self.adsorption();
+        let molefracs = self.molefracs();
+        let n = SIArray1::from_shape_fn(self.components, |k| {
+            adsorption.get((k, i - 1)) + (adsorption.get((k, i)) - adsorption.get((k, i - 1))) * x
+        });
+        let y = Array1::from_shape_fn(self.components, |k| {
+            molefracs[[k, i - 1]] + (molefracs[[k, i]] - molefracs[[k, i - 1]]) * x
+        });
+        Ok((n, y))
+    }
+}
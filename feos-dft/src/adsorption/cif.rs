@@ -0,0 +1,320 @@
+//! Reader for solid structures in the crystallographic information file (CIF) format.
+use feos_core::{EosError, EosResult};
+use std::collections::HashMap;
+use std::fs;
+use std::path::Path;
+
+const SYMMETRY_TOLERANCE: f64 = 1e-4;
+
+/// Symmetry operation $\mathbf{x}'=\mathbf{R}\mathbf{x}+\mathbf{t}$ in fractional coordinates.
+type SymmetryOperation = [[f64; 4]; 3];
+
+/// Atom in the unit cell of a solid structure.
+pub(super) struct CifAtom {
+    pub label: String,
+    pub type_symbol: String,
+    pub fractional_coordinates: [f64; 3],
+}
+
+/// Unit cell and atoms of a solid structure read from a CIF file.
+pub(super) struct CifStructure {
+    pub cell_lengths: [f64; 3],
+    pub cell_angles: [f64; 3],
+    pub atoms: Vec<CifAtom>,
+}
+
+/// Data items and loops of a single CIF data block.
+#[derive(Default)]
+struct CifData {
+    items: HashMap<String, String>,
+    loops: Vec<(Vec<String>, Vec<String>)>,
+}
+
+impl CifData {
+    fn parse(content: &str) -> Self {
+        let tokens = tokenize(content);
+        let mut data = Self::default();
+        let mut tokens = tokens.into_iter().peekable();
+        let mut blocks = 0;
+        while let Some(token) = tokens.next() {
+            let lower = token.to_lowercase();
+            if lower.starts_with("data_") {
+                // only the first data block is read
+                blocks += 1;
+                if blocks > 1 {
+                    break;
+                }
+            } else if lower == "loop_" {
+                let mut tags = Vec::new();
+                while let Some(tag) = tokens.next_if(|t| t.starts_with('_')) {
+                    tags.push(tag.to_lowercase());
+                }
+                let mut values = Vec::new();
+                while let Some(value) = tokens.next_if(|t| !is_reserved(t)) {
+                    values.push(value);
+                }
+                data.loops.push((tags, values));
+            } else if lower.starts_with('_') {
+                if let Some(value) = tokens.next_if(|t| !is_reserved(t)) {
+                    data.items.insert(lower, value);
+                }
+            }
+        }
+        data
+    }
+
+    /// Return the columns of the loop that contains the given tag.
+    fn find_loop(&self, tag: &str) -> Option<HashMap<&str, Vec<&str>>> {
+        let (tags, values) = self
+            .loops
+            .iter()
+            .find(|(tags, _)| tags.iter().any(|t| t == tag))?;
+        Some(
+            tags.iter()
+                .enumerate()
+                .map(|(i, t)| {
+                    let column = values
+                        .iter()
+                        .skip(i)
+                        .step_by(tags.len())
+                        .map(|v| v.as_str())
+                        .collect();
+                    (t.as_str(), column)
+                })
+                .collect(),
+        )
+    }
+
+    fn number(&self, tag: &str) -> EosResult<Option<f64>> {
+        self.items.get(tag).map(|v| parse_number(v)).transpose()
+    }
+}
+
+impl CifStructure {
+    /// Read the first data block of a CIF file and apply all symmetry
+    /// operations to obtain the full unit cell.
+    pub fn from_file<P: AsRef<Path>>(file: P) -> EosResult<Self> {
+        let content = fs::read_to_string(file).map_err(|e| EosError::Error(e.to_string()))?;
+        Self::parse(&content)
+    }
+
+    pub fn parse(content: &str) -> EosResult<Self> {
+        let data = CifData::parse(content);
+
+        let length = |tag: &str| {
+            data.number(tag)?
+                .ok_or_else(|| EosError::Error(format!("The CIF file does not specify `{tag}`.")))
+        };
+        let angle = |tag: &str| data.number(tag).map(|a| a.unwrap_or(90.0));
+        let cell_lengths = [
+            length("_cell_length_a")?,
+            length("_cell_length_b")?,
+            length("_cell_length_c")?,
+        ];
+        let cell_angles = [
+            angle("_cell_angle_alpha")?,
+            angle("_cell_angle_beta")?,
+            angle("_cell_angle_gamma")?,
+        ];
+
+        // symmetry operations (only the identity if none are given)
+        let symmetry_operations = match data
+            .find_loop("_symmetry_equiv_pos_as_xyz")
+            .map(|l| l["_symmetry_equiv_pos_as_xyz"].clone())
+            .or_else(|| {
+                data.find_loop("_space_group_symop_operation_xyz")
+                    .map(|l| l["_space_group_symop_operation_xyz"].clone())
+            }) {
+            Some(operations) => operations
+                .into_iter()
+                .map(parse_symmetry_operation)
+                .collect::<EosResult<Vec<_>>>()?,
+            None => vec![[
+                [1.0, 0.0, 0.0, 0.0],
+                [0.0, 1.0, 0.0, 0.0],
+                [0.0, 0.0, 1.0, 0.0],
+            ]],
+        };
+
+        // atoms in the asymmetric unit
+        let sites = data.find_loop("_atom_site_fract_x").ok_or_else(|| {
+            EosError::Error(String::from(
+                "The CIF file does not contain fractional coordinates of atoms.",
+            ))
+        })?;
+        let column = |tag: &str| {
+            sites
+                .get(tag)
+                .ok_or_else(|| EosError::Error(format!("The CIF file does not specify `{tag}`.")))
+        };
+        let labels = column("_atom_site_label")?;
+        let coordinates = [
+            column("_atom_site_fract_x")?,
+            column("_atom_site_fract_y")?,
+            column("_atom_site_fract_z")?,
+        ];
+        let type_symbols = sites.get("_atom_site_type_symbol");
+
+        // apply the symmetry operations and remove duplicates
+        let mut atoms: Vec<CifAtom> = Vec::new();
+        for (i, &label) in labels.iter().enumerate() {
+            let type_symbol = match type_symbols {
+                Some(t) => t[i].to_string(),
+                None => label.chars().take_while(|c| c.is_alphabetic()).collect(),
+            };
+            let x = [
+                parse_number(coordinates[0][i])?,
+                parse_number(coordinates[1][i])?,
+                parse_number(coordinates[2][i])?,
+            ];
+            for operation in &symmetry_operations {
+                let fractional_coordinates = operation.map(|r| {
+                    let x = r[0] * x[0] + r[1] * x[1] + r[2] * x[2] + r[3];
+                    x - x.floor()
+                });
+                let duplicate = atoms.iter().any(|a| {
+                    (0..3).all(|k| {
+                        let d = a.fractional_coordinates[k] - fractional_coordinates[k];
+                        (d - d.round()).abs() < SYMMETRY_TOLERANCE
+                    })
+                });
+                if !duplicate {
+                    atoms.push(CifAtom {
+                        label: label.to_string(),
+                        type_symbol: type_symbol.clone(),
+                        fractional_coordinates,
+                    });
+                }
+            }
+        }
+
+        Ok(Self {
+            cell_lengths,
+            cell_angles,
+            atoms,
+        })
+    }
+}
+
+/// Tokens that terminate a list of values.
+fn is_reserved(token: &str) -> bool {
+    let token = token.to_lowercase();
+    token.starts_with('_')
+        || token == "loop_"
+        || token.starts_with("data_")
+        || token.starts_with("save_")
+        || token == "global_"
+        || token == "stop_"
+}
+
+/// Split the content of a CIF file into tokens, removing comments
+/// and quotes and combining semicolon delimited text fields.
+fn tokenize(content: &str) -> Vec<String> {
+    let mut tokens = Vec::new();
+    let mut text_field: Option<String> = None;
+    for line in content.lines() {
+        if let Some(text) = text_field.as_mut() {
+            if line.starts_with(';') {
+                tokens.push(text_field.take().unwrap());
+            } else {
+                text.push_str(line);
+                text.push('\n');
+            }
+            continue;
+        }
+        if let Some(text) = line.strip_prefix(';') {
+            text_field = Some(format!("{text}\n"));
+            continue;
+        }
+
+        let mut chars = line.chars().peekable();
+        while let Some(&c) = chars.peek() {
+            if c.is_whitespace() {
+                chars.next();
+            } else if c == '#' {
+                break;
+            } else if c == '\'' || c == '"' {
+                chars.next();
+                let mut token = String::new();
+                // a quote only terminates the string if it is followed by whitespace
+                while let Some(d) = chars.next() {
+                    if d == c && chars.peek().map_or(true, |e| e.is_whitespace()) {
+                        break;
+                    }
+                    token.push(d);
+                }
+                tokens.push(token);
+            } else {
+                let mut token = String::new();
+                while let Some(d) = chars.next_if(|d| !d.is_whitespace()) {
+                    token.push(d);
+                }
+                tokens.push(token);
+            }
+        }
+    }
+    tokens
+}
+
+/// Parse a number and strip the standard uncertainty, e.g., `25.832(3)`.
+fn parse_number(value: &str) -> EosResult<f64> {
+    value
+        .split('(')
+        .next()
+        .and_then(|v| v.parse().ok())
+        .ok_or_else(|| EosError::Error(format!("Unable to parse `{value}` as a number.")))
+}
+
+/// Parse a symmetry operation, e.g., `-x+1/2,y,-z`.
+fn parse_symmetry_operation(operation: &str) -> EosResult<SymmetryOperation> {
+    let error = || EosError::Error(format!("Unable to parse symmetry operation `{operation}`."));
+    let expressions: Vec<_> = operation.split(',').collect();
+    if expressions.len() != 3 {
+        return Err(error());
+    }
+    let mut result = [[0.0; 4]; 3];
+    for (row, expression) in result.iter_mut().zip(expressions) {
+        let expression: String = expression
+            .chars()
+            .filter(|c| !c.is_whitespace())
+            .collect::<String>()
+            .to_lowercase();
+
+        // split the expression into signed terms
+        let mut terms = Vec::new();
+        let mut term = String::new();
+        for c in expression.chars() {
+            if (c == '+' || c == '-') && !term.is_empty() {
+                terms.push(term);
+                term = String::new();
+            }
+            term.push(c);
+        }
+        terms.push(term);
+
+        for term in terms {
+            let (sign, term) = match term.strip_prefix('-') {
+                Some(t) => (-1.0, t),
+                None => (1.0, term.trim_start_matches('+')),
+            };
+            let (index, coefficient) = match term.chars().last() {
+                Some('x') => (0, &term[..term.len() - 1]),
+                Some('y') => (1, &term[..term.len() - 1]),
+                Some('z') => (2, &term[..term.len() - 1]),
+                _ => (3, term),
+            };
+            let coefficient = coefficient.trim_end_matches('*');
+            let value = if coefficient.is_empty() && index < 3 {
+                1.0
+            } else if let Some((numerator, denominator)) = coefficient.split_once('/') {
+                let numerator: f64 = numerator.parse().map_err(|_| error())?;
+                let denominator: f64 = denominator.parse().map_err(|_| error())?;
+                numerator / denominator
+            } else {
+                coefficient.parse().map_err(|_| error())?
+            };
+            row[index] += sign * value;
+        }
+    }
+    Ok(result)
+}
@@ -3,8 +3,9 @@ use crate::adsorption::fea_potential::calculate_fea_potential;
 use crate::functional::HelmholtzEnergyFunctional;
 #[cfg(feature = "rayon")]
 use crate::geometry::Geometry;
+use feos_core::{EosError, EosResult};
 use libm::tgamma;
-use ndarray::{Array1, Array2, Axis as Axis_nd};
+use ndarray::{Array1, Array2, ArrayView1, Axis as Axis_nd};
 use quantity::si::SIUnit;
 #[cfg(feature = "rayon")]
 use quantity::si::{SIArray2, SINumber};
@@ -63,6 +64,17 @@ pub enum ExternalPotential {
         cutoff_radius: Option<f64>,
     },
 
+    /// Tabulated potential that is linearly interpolated between the given distances (in Angstrom).
+    ///
+    /// The potential (in Kelvin) has to be given for every segment, i.e., `potential` has one row
+    /// per segment and one column per distance. In all geometries, the distance is measured from
+    /// the wall of the pore. In Cartesian pores, the contributions of both walls are added.
+    /// Outside of the tabulated range, the potential is kept constant.
+    Tabulated {
+        distance: Array1<f64>,
+        potential: Array2<f64>,
+    },
+
     /// Custom potential
     Custom(Array2<f64>),
 
@@ -79,6 +91,41 @@ pub trait FluidParameters: HelmholtzEnergyFunctional {
 
 #[allow(unused_variables)]
 impl ExternalPotential {
+    /// Check that a tabulated potential is consistent with the number of segments of the fluid.
+    pub(crate) fn validate(&self, segments: usize) -> EosResult<()> {
+        if let Self::Tabulated {
+            distance,
+            potential,
+        } = self
+        {
+            if distance.len() < 2
+                || distance
+                    .iter()
+                    .zip(distance.iter().skip(1))
+                    .any(|(a, b)| b <= a)
+            {
+                return Err(EosError::Error(String::from(
+                    "The distances of a tabulated potential have to be strictly increasing.",
+                )));
+            }
+            if potential.ncols() != distance.len() {
+                return Err(EosError::Error(format!(
+                    "The tabulated potential contains {} values for {} distances.",
+                    potential.ncols(),
+                    distance.len()
+                )));
+            }
+            if potential.nrows() != segments {
+                return Err(EosError::Error(format!(
+                    "The tabulated potential is given for {} segments, but the fluid consists of {} segments.",
+                    potential.nrows(),
+                    segments
+                )));
+            }
+        }
+        Ok(())
+    }
+
     // Evaluate the external potential in cartesian coordinates for a given grid and fluid parameters.
     pub fn calculate_cartesian_potential<P: FluidParameters>(
         &self,
@@ -192,6 +239,10 @@ impl ExternalPotential {
                             * (2.0 * (sigma_sf[i] / z_grid).mapv(|x| x.powi(9))
                                 - 15.0 * (sigma_sf[i] / z_grid).mapv(|x| x.powi(3))))
                 }
+                Self::Tabulated {
+                    distance,
+                    potential,
+                } => interpolate(distance, potential.row(i), z_grid),
                 #[cfg(feature = "rayon")]
                 Self::FreeEnergyAveraged {
                     coordinates,
@@ -358,6 +409,10 @@ impl ExternalPotential {
                             * sigma_sf[i].powi(3)
                             * *rho_s)
                 }
+                Self::Tabulated {
+                    distance,
+                    potential,
+                } => interpolate(distance, potential.row(i), &(pore_size - r_grid)),
                 #[cfg(feature = "rayon")]
                 Self::FreeEnergyAveraged {
                     coordinates,
@@ -548,6 +603,10 @@ impl ExternalPotential {
                             * (2.0 / 5.0 * sum_n(10, r_grid, sigma_sf[i], pore_size)
                                 - sum_n(4, r_grid, sigma_sf[i], pore_size)))
                 }
+                Self::Tabulated {
+                    distance,
+                    potential,
+                } => interpolate(distance, potential.row(i), &(pore_size - r_grid)),
                 #[cfg(feature = "rayon")]
                 Self::FreeEnergyAveraged {
                     coordinates,
@@ -649,3 +708,19 @@ fn taylor_2f1_psi(x: &Array1<f64>, n: i32) -> Array1<f64> {
         _ => unreachable!(),
     }
 }
+
+/// Linear interpolation of tabulated values. Outside of the tabulated range,
+/// the values are kept constant.
+fn interpolate(x: &Array1<f64>, y: ArrayView1<f64>, grid: &Array1<f64>) -> Array1<f64> {
+    let n = x.len();
+    grid.mapv(|g| {
+        if g <= x[0] {
+            y[0]
+        } else if g >= x[n - 1] {
+            y[n - 1]
+        } else {
+            let i = x.iter().position(|&x| x > g).unwrap();
+            y[i - 1] + (y[i] - y[i - 1]) * (g - x[i - 1]) / (x[i] - x[i - 1])
+        }
+    })
+}
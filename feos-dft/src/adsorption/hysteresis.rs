@@ -0,0 +1,182 @@
+//! Hysteresis of adsorption isotherms and capillary condensation transitions.
+use super::{Adsorption, FluidParameters, PoreSpecification};
+use crate::functional::{HelmholtzEnergyFunctional, DFT};
+use crate::solver::DFTSolver;
+use feos_core::{EosResult, EosUnit};
+use ndarray::{Array1, Dimension, Ix1, Ix2, Ix3, RemoveAxis};
+use quantity::si::{SIArray1, SINumber, SIUnit};
+use std::sync::Arc;
+
+const TOL_HYSTERESIS: f64 = 1e-4;
+
+/// Adsorption and desorption branch of an isotherm evaluated at the same pressures.
+///
+/// Pressures at which the total adsorption of both branches differs form
+/// hysteresis loops. At the upper end of a loop, the adsorption branch jumps to
+/// the desorption branch (capillary condensation), at the lower end, the desorption
+/// branch jumps to the adsorption branch (evaporation). The equilibrium transition
+/// within a loop is located where the grand potentials of both branches are equal.
+pub struct AdsorptionHysteresis<D: Dimension, F> {
+    pub adsorption: Adsorption<D, F>,
+    pub desorption: Adsorption<D, F>,
+}
+
+/// Adsorption hysteresis in 1D pores.
+pub type AdsorptionHysteresis1D<F> = AdsorptionHysteresis<Ix1, F>;
+/// Adsorption hysteresis in 2D pores.
+pub type AdsorptionHysteresis2D<F> = AdsorptionHysteresis<Ix2, F>;
+/// Adsorption hysteresis in 3D pores.
+pub type AdsorptionHysteresis3D<F> = AdsorptionHysteresis<Ix3, F>;
+
+impl<D: Dimension + RemoveAxis + 'static, F: HelmholtzEnergyFunctional + FluidParameters>
+    AdsorptionHysteresis<D, F>
+where
+    SINumber: std::fmt::Display,
+    D::Larger: Dimension<Smaller = D>,
+    D::Smaller: Dimension<Larger = D>,
+    <D::Larger as Dimension>::Larger: Dimension<Smaller = D::Larger>,
+{
+    /// Calculate the adsorption and the desorption branch of an isotherm.
+    pub fn new<S: PoreSpecification<D>>(
+        functional: &Arc<DFT<F>>,
+        temperature: SINumber,
+        pressure: &SIArray1,
+        pore: &S,
+        molefracs: Option<&Array1<f64>>,
+        solver: Option<&DFTSolver>,
+    ) -> EosResult<Self> {
+        let adsorption = Adsorption::adsorption_isotherm(
+            functional,
+            temperature,
+            pressure,
+            pore,
+            molefracs,
+            solver,
+        )?;
+        let desorption = Adsorption::desorption_isotherm(
+            functional,
+            temperature,
+            pressure,
+            pore,
+            molefracs,
+            solver,
+        )?;
+        Ok(Self {
+            adsorption,
+            desorption,
+        })
+    }
+
+    /// Index ranges (inclusive) of all hysteresis loops.
+    fn loops(&self) -> Vec<(usize, usize)> {
+        let n_ads = self.adsorption.total_adsorption();
+        let n_des = self.desorption.total_adsorption();
+        let unit = SIUnit::reference_density()
+            * SIUnit::reference_length().powi(self.adsorption.dimension);
+        let n_ads = n_ads.to_reduced(unit).unwrap();
+        let n_des = n_des.to_reduced(unit).unwrap();
+        let differs: Vec<_> = n_ads
+            .iter()
+            .zip(n_des.iter())
+            .map(|(&a, &d)| (a - d).abs() > TOL_HYSTERESIS * a.abs().max(d.abs()))
+            .collect();
+
+        let mut loops = Vec::new();
+        let mut start = None;
+        for (i, &differs) in differs.iter().enumerate() {
+            match (start, differs) {
+                (None, true) => start = Some(i),
+                (Some(s), false) => {
+                    loops.push((s, i - 1));
+                    start = None;
+                }
+                _ => (),
+            }
+        }
+        if let Some(s) = start {
+            loops.push((s, differs.len() - 1));
+        }
+        loops
+    }
+
+    /// Pressure ranges (lowest and highest pressure) of all hysteresis loops.
+    pub fn hysteresis_loops(&self) -> Vec<(SINumber, SINumber)> {
+        let pressure = self.adsorption.pressure();
+        self.loops()
+            .into_iter()
+            .map(|(s, e)| (pressure.get(s), pressure.get(e)))
+            .collect()
+    }
+
+    /// Pressure intervals in which the adsorption branch jumps to the
+    /// desorption branch, i.e., the (spinodal-like) capillary condensation.
+    ///
+    /// Loops that are not closed within the pressure range are omitted.
+    pub fn adsorption_jumps(&self) -> Vec<(SINumber, SINumber)> {
+        let pressure = self.adsorption.pressure();
+        self.loops()
+            .into_iter()
+            .filter(|&(_, e)| e + 1 < pressure.len())
+            .map(|(_, e)| (pressure.get(e), pressure.get(e + 1)))
+            .collect()
+    }
+
+    /// Pressure intervals in which the desorption branch jumps to the
+    /// adsorption branch, i.e., the (spinodal-like) evaporation.
+    ///
+    /// Loops that are not closed within the pressure range are omitted.
+    pub fn desorption_jumps(&self) -> Vec<(SINumber, SINumber)> {
+        let pressure = self.adsorption.pressure();
+        self.loops()
+            .into_iter()
+            .filter(|&(s, _)| s > 0)
+            .map(|(s, _)| (pressure.get(s - 1), pressure.get(s)))
+            .collect()
+    }
+
+    /// Pressures of the equilibrium transitions within all hysteresis loops,
+    /// obtained by linear interpolation of the difference between the grand
+    /// potentials of both branches.
+    pub fn equilibrium_pressures(&self) -> Vec<SINumber> {
+        let pressure = self.adsorption.pressure();
+        let omega_ads = self.adsorption.grand_potential();
+        let omega_des = self.desorption.grand_potential();
+        let unit = SIUnit::reference_pressure()
+            * SIUnit::reference_length().powi(self.adsorption.dimension);
+        let delta_omega = (omega_ads - omega_des).to_reduced(unit).unwrap();
+        self.loops()
+            .into_iter()
+            .filter_map(|(s, e)| {
+                (s + 1..=e)
+                    .find(|&i| delta_omega[i - 1] <= 0.0 && delta_omega[i] > 0.0)
+                    .map(|i| {
+                        let (p0, p1) = (pressure.get(i - 1), pressure.get(i));
+                        let (o0, o1) = (delta_omega[i - 1], delta_omega[i]);
+                        p0 - (p1 - p0) * (o0 / (o1 - o0))
+                    })
+            })
+            .collect()
+    }
+
+    /// Combine both branches to the equilibrium isotherm, i.e., select the profile
+    /// with the lower grand potential at every pressure.
+    pub fn into_equilibrium_isotherm(self) -> Adsorption<D, F> {
+        let (components, dimension) = (self.adsorption.components, self.adsorption.dimension);
+        let omega_a = self.adsorption.grand_potential();
+        let omega_d = self.desorption.grand_potential();
+        let is_ads = Array1::from_shape_fn(self.adsorption.profiles.len(), |i| {
+            omega_d.get(i).is_nan() || omega_a.get(i) < omega_d.get(i)
+        });
+        let profiles = is_ads
+            .into_iter()
+            .zip(self.adsorption.profiles.into_iter())
+            .zip(self.desorption.profiles.into_iter())
+            .map(|((is_ads, a), d)| if is_ads { a } else { d })
+            .collect();
+        Adsorption {
+            components,
+            dimension,
+            profiles,
+        }
+    }
+}
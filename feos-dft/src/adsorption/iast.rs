@@ -0,0 +1,321 @@
+//! Ideal adsorbed solution theory (IAST) for the prediction of mixture adsorption.
+use feos_core::{EosError, EosResult, SolverOptions};
+use ndarray::Array1;
+use quantity::si::{SIArray1, SIArray2, SINumber};
+use std::sync::Arc;
+
+const MAX_ITER_IAST: usize = 50;
+const TOL_IAST: f64 = 1e-10;
+const MAX_ITER_INVERSION: usize = 50;
+const TOL_INVERSION: f64 = 1e-12;
+const MAX_STEP_IAST: f64 = 1.0;
+
+/// Pure component adsorption isotherm that can be used in
+/// the [IdealAdsorbedSolution].
+pub trait PureComponentIsotherm: Send + Sync {
+    /// Return the adsorbed amount $n(p)$ at the given pressure.
+    fn adsorption(&self, pressure: SINumber) -> EosResult<SINumber>;
+
+    /// Return the reduced spreading pressure $\psi(p)=\int_0^p\frac{n(p')}{p'}\mathrm{d}p'$
+    /// at the given pressure.
+    fn reduced_spreading_pressure(&self, pressure: SINumber) -> EosResult<SINumber>;
+
+    /// Return the pressure at which the reduced spreading pressure equals the given value.
+    ///
+    /// The default implementation uses a Newton iteration in $\ln p$ starting
+    /// from `initial_pressure`.
+    fn pressure_from_reduced_spreading_pressure(
+        &self,
+        reduced_spreading_pressure: SINumber,
+        initial_pressure: SINumber,
+    ) -> EosResult<SINumber> {
+        let mut pressure = initial_pressure;
+        for _ in 0..MAX_ITER_INVERSION {
+            let n = self.adsorption(pressure)?;
+            let psi = self.reduced_spreading_pressure(pressure)?;
+            let delta_ln_p = (reduced_spreading_pressure - psi)
+                .to_reduced(n)?
+                .clamp(-MAX_STEP_IAST, MAX_STEP_IAST);
+            if !delta_ln_p.is_finite() {
+                return Err(EosError::IterationFailed(String::from(
+                    "PureComponentIsotherm::pressure_from_reduced_spreading_pressure",
+                )));
+            }
+            pressure = pressure * delta_ln_p.exp();
+            if delta_ln_p.abs() < TOL_INVERSION {
+                return Ok(pressure);
+            }
+        }
+        Err(EosError::NotConverged(String::from(
+            "PureComponentIsotherm::pressure_from_reduced_spreading_pressure",
+        )))
+    }
+}
+
+/// Langmuir isotherm $n(p)=n_\mathrm{s}\frac{bp}{1+bp}$.
+#[derive(Clone, Copy)]
+pub struct LangmuirIsotherm {
+    saturation_capacity: SINumber,
+    affinity: SINumber,
+}
+
+impl LangmuirIsotherm {
+    /// Create a new Langmuir isotherm from the saturation capacity $n_\mathrm{s}$
+    /// and the affinity constant $b$.
+    pub fn new(saturation_capacity: SINumber, affinity: SINumber) -> Self {
+        Self {
+            saturation_capacity,
+            affinity,
+        }
+    }
+}
+
+impl PureComponentIsotherm for LangmuirIsotherm {
+    fn adsorption(&self, pressure: SINumber) -> EosResult<SINumber> {
+        let bp = (self.affinity * pressure).into_value()?;
+        Ok(self.saturation_capacity * (bp / (1.0 + bp)))
+    }
+
+    fn reduced_spreading_pressure(&self, pressure: SINumber) -> EosResult<SINumber> {
+        let bp = (self.affinity * pressure).into_value()?;
+        Ok(self.saturation_capacity * bp.ln_1p())
+    }
+
+    fn pressure_from_reduced_spreading_pressure(
+        &self,
+        reduced_spreading_pressure: SINumber,
+        _: SINumber,
+    ) -> EosResult<SINumber> {
+        let x = reduced_spreading_pressure.to_reduced(self.saturation_capacity)?;
+        Ok(x.exp_m1() / self.affinity)
+    }
+}
+
+/// Isotherm that is linearly interpolated between tabulated values.
+///
+/// Below the lowest tabulated pressure, Henry's law is assumed, above the
+/// highest tabulated pressure, the adsorbed amount is kept constant.
+#[derive(Clone)]
+pub struct TabulatedIsotherm {
+    pressure: SIArray1,
+    adsorption: SIArray1,
+    reduced_spreading_pressure: SIArray1,
+}
+
+impl TabulatedIsotherm {
+    /// Create a new tabulated isotherm from pressures (in ascending order) and the
+    /// corresponding adsorbed amounts.
+    pub fn new(pressure: SIArray1, adsorption: SIArray1) -> EosResult<Self> {
+        if pressure.len() != adsorption.len() || pressure.len() < 2 {
+            return Err(EosError::Error(String::from(
+                "A tabulated isotherm requires at least two pressures and adsorbed amounts of equal length.",
+            )));
+        }
+        if (1..pressure.len()).any(|i| pressure.get(i) <= pressure.get(i - 1))
+            || pressure.get(0) <= 0.0 * pressure.get(0)
+        {
+            return Err(EosError::Error(String::from(
+                "The pressures of a tabulated isotherm have to be positive and in ascending order.",
+            )));
+        }
+
+        // integrate the piecewise linear isotherm analytically
+        let mut psi = adsorption.get(0);
+        let mut reduced_spreading_pressure = vec![psi];
+        for i in 1..pressure.len() {
+            let (p0, p1) = (pressure.get(i - 1), pressure.get(i));
+            let (n0, n1) = (adsorption.get(i - 1), adsorption.get(i));
+            let b = (n1 - n0) / (p1 - p0);
+            let a = n0 - b * p0;
+            psi += a * p1.to_reduced(p0)?.ln() + b * (p1 - p0);
+            reduced_spreading_pressure.push(psi);
+        }
+
+        Ok(Self {
+            pressure,
+            adsorption,
+            reduced_spreading_pressure: reduced_spreading_pressure.into_iter().collect(),
+        })
+    }
+
+    /// Return the index `i` of the interval for which $p_i\leq p<p_{i+1}$.
+    fn interval(&self, pressure: SINumber) -> Option<usize> {
+        let n = self.pressure.len();
+        if pressure < self.pressure.get(0) || pressure >= self.pressure.get(n - 1) {
+            return None;
+        }
+        (0..n - 1).find(|&i| pressure < self.pressure.get(i + 1))
+    }
+}
+
+impl PureComponentIsotherm for TabulatedIsotherm {
+    fn adsorption(&self, pressure: SINumber) -> EosResult<SINumber> {
+        let n = self.pressure.len();
+        if pressure < self.pressure.get(0) {
+            return Ok(self.adsorption.get(0) * pressure.to_reduced(self.pressure.get(0))?);
+        }
+        Ok(match self.interval(pressure) {
+            Some(i) => {
+                let (p0, p1) = (self.pressure.get(i), self.pressure.get(i + 1));
+                let (n0, n1) = (self.adsorption.get(i), self.adsorption.get(i + 1));
+                n0 + (n1 - n0) * (pressure - p0).to_reduced(p1 - p0)?
+            }
+            None => self.adsorption.get(n - 1),
+        })
+    }
+
+    fn reduced_spreading_pressure(&self, pressure: SINumber) -> EosResult<SINumber> {
+        let n = self.pressure.len();
+        if pressure < self.pressure.get(0) {
+            return self.adsorption(pressure);
+        }
+        let i = self.interval(pressure).unwrap_or(n - 1);
+        let (p0, n0) = (self.pressure.get(i), self.adsorption.get(i));
+        let b = if i < n - 1 {
+            (self.adsorption.get(i + 1) - n0) / (self.pressure.get(i + 1) - p0)
+        } else {
+            0.0 * n0 / p0
+        };
+        let a = n0 - b * p0;
+        Ok(self.reduced_spreading_pressure.get(i)
+            + a * pressure.to_reduced(p0)?.ln()
+            + b * (pressure - p0))
+    }
+}
+
+/// Ideal adsorbed solution theory (IAST) for the prediction of
+/// mixture adsorption from pure component isotherms.
+///
+/// For a gas phase at pressure $p$ and composition $y_i$, the
+/// composition of the adsorbed phase $x_i$ follows from
+/// $$py_i=x_ip_i^0,~~~~\psi_i(p_i^0)=\psi,~~~~\sum_ix_i=1$$
+/// and the total adsorbed amount from $\frac{1}{n}=\sum_i\frac{x_i}{n_i(p_i^0)}$.
+#[derive(Clone)]
+pub struct IdealAdsorbedSolution {
+    isotherms: Vec<Arc<dyn PureComponentIsotherm>>,
+}
+
+impl IdealAdsorbedSolution {
+    /// Create a new ideal adsorbed solution from the pure component isotherms.
+    pub fn new(isotherms: Vec<Arc<dyn PureComponentIsotherm>>) -> Self {
+        Self { isotherms }
+    }
+
+    /// Return the number of components.
+    pub fn components(&self) -> usize {
+        self.isotherms.len()
+    }
+
+    /// Calculate the adsorbed amount of every component for the given
+    /// pressure and composition of the gas phase.
+    pub fn adsorption(
+        &self,
+        pressure: SINumber,
+        molefracs: &Array1<f64>,
+        options: SolverOptions,
+    ) -> EosResult<SIArray1> {
+        let components = self.components();
+        if molefracs.len() != components {
+            return Err(EosError::IncompatibleComponents(
+                components,
+                molefracs.len(),
+            ));
+        }
+
+        // only components that are present in the gas phase are adsorbed
+        let isotherms: Vec<_> = self
+            .isotherms
+            .iter()
+            .zip(molefracs.iter().copied())
+            .filter(|&(_, y)| y > 0.0)
+            .collect();
+
+        // initial guess for the reduced spreading pressure
+        let mut psi = isotherms
+            .iter()
+            .map(|&(i, y)| Ok(i.reduced_spreading_pressure(pressure)? * y))
+            .reduce(|a: EosResult<SINumber>, b| Ok(a? + b?))
+            .ok_or(EosError::TrivialSolution)??;
+        let mut p0 = vec![pressure; isotherms.len()];
+
+        for _ in 0..options.max_iter.unwrap_or(MAX_ITER_IAST) {
+            // pure component pressures at the current spreading pressure
+            for (&(i, _), p) in isotherms.iter().zip(p0.iter_mut()) {
+                *p = i.pressure_from_reduced_spreading_pressure(psi, *p)?;
+            }
+
+            // sum of the molefractions in the adsorbed phase and its derivative
+            let mut x_sum = 0.0;
+            let mut dx_sum = 0.0;
+            for (&(i, y), &p) in isotherms.iter().zip(p0.iter()) {
+                let x = pressure.to_reduced(p)? * y;
+                x_sum += x;
+                dx_sum -= x * psi.to_reduced(i.adsorption(p)?)?;
+            }
+
+            // Newton step in the logarithm of the spreading pressure
+            let res = x_sum - 1.0;
+            if !res.is_finite() {
+                return Err(EosError::IterationFailed(String::from(
+                    "IdealAdsorbedSolution::adsorption",
+                )));
+            }
+            if res.abs() < options.tol.unwrap_or(TOL_IAST) {
+                let n_inv = isotherms
+                    .iter()
+                    .zip(p0.iter())
+                    .map(|(&(i, y), &p)| Ok(pressure.to_reduced(p)? * y / i.adsorption(p)?))
+                    .reduce(|a: EosResult<SINumber>, b| Ok(a? + b?))
+                    .unwrap()?;
+                let mut p0 = p0.into_iter();
+                return Ok(molefracs
+                    .iter()
+                    .map(|&y| {
+                        if y > 0.0 {
+                            pressure.to_reduced(p0.next().unwrap()).unwrap() * y / n_inv
+                        } else {
+                            0.0 / n_inv
+                        }
+                    })
+                    .collect());
+            }
+            psi = psi * (-res / dx_sum).clamp(-MAX_STEP_IAST, MAX_STEP_IAST).exp();
+        }
+        Err(EosError::NotConverged(String::from(
+            "IdealAdsorbedSolution::adsorption",
+        )))
+    }
+
+    /// Calculate the adsorbed amount of every component for every given
+    /// pressure at constant composition of the gas phase.
+    ///
+    /// Pressures for which the calculation fails are marked with `NaN`.
+    pub fn adsorption_isotherm(
+        &self,
+        pressure: &SIArray1,
+        molefracs: &Array1<f64>,
+        options: SolverOptions,
+    ) -> EosResult<SIArray2> {
+        let adsorption: Vec<_> = pressure
+            .into_iter()
+            .map(|p| self.adsorption(p, molefracs, options).ok())
+            .collect();
+        let unit = adsorption
+            .iter()
+            .flatten()
+            .next()
+            .map(|n| n.get(0))
+            .ok_or_else(|| {
+                EosError::IterationFailed(String::from(
+                    "IdealAdsorbedSolution::adsorption_isotherm",
+                ))
+            })?;
+        Ok(SIArray2::from_shape_fn(
+            (self.components(), pressure.len()),
+            |(j, i)| match &adsorption[i] {
+                Some(n) => n.get(j),
+                None => f64::NAN * unit,
+            },
+        ))
+    }
+}
@@ -5,18 +5,36 @@ use feos_core::{
     Contributions, DensityInitialization, EosError, EosResult, EosUnit, EquationOfState,
     SolverOptions, State, StateBuilder,
 };
-use ndarray::{Array1, Dimension, Ix1, Ix3, RemoveAxis};
+use ndarray::{Array1, Dimension, Ix1, Ix2, Ix3, RemoveAxis};
 use quantity::si::{SIArray1, SIArray2, SINumber, SIUnit};
 use std::iter;
 use std::sync::Arc;
 
+mod breakthrough;
+mod capacity;
 mod external_potential;
 #[cfg(feature = "rayon")]
 mod fea_potential;
+mod hysteresis;
+mod iast;
 mod pore;
+mod pore2d;
+mod thin_film;
+mod wetting;
+pub use breakthrough::{BreakthroughCurve, FixedBed};
+pub use capacity::WorkingCapacity;
 pub use external_potential::{ExternalPotential, FluidParameters};
+pub use hysteresis::{
+    AdsorptionHysteresis, AdsorptionHysteresis1D, AdsorptionHysteresis2D, AdsorptionHysteresis3D,
+};
+pub use iast::{IdealAdsorbedSolution, LangmuirIsotherm, PureComponentIsotherm, TabulatedIsotherm};
 pub use pore::{Pore1D, PoreProfile, PoreProfile1D, PoreSpecification};
+pub use pore2d::{Pore2D, PoreProfile2D};
+pub use thin_film::ThinFilm;
+pub use wetting::Wetting;
 
+#[cfg(feature = "rayon")]
+mod cif;
 #[cfg(feature = "rayon")]
 mod pore3d;
 #[cfg(feature = "rayon")]
@@ -34,6 +52,8 @@ pub struct Adsorption<D: Dimension, F> {
 
 /// Container structure for adsorption isotherms in 1D pores.
 pub type Adsorption1D<F> = Adsorption<Ix1, F>;
+/// Container structure for adsorption isotherms in 2D pores.
+pub type Adsorption2D<F> = Adsorption<Ix2, F>;
 /// Container structure for adsorption isotherms in 3D pores.
 pub type Adsorption3D<F> = Adsorption<Ix3, F>;
 
@@ -160,34 +180,15 @@ where
                 dimension: pore.dimension(),
             })
         } else {
-            let adsorption = Self::adsorption_isotherm(
+            Ok(AdsorptionHysteresis::new(
                 functional,
                 temperature,
                 pressure,
                 pore,
                 molefracs,
                 solver,
-            )?;
-            let desorption = Self::desorption_isotherm(
-                functional,
-                temperature,
-                pressure,
-                pore,
-                molefracs,
-                solver,
-            )?;
-            let omega_a = adsorption.grand_potential();
-            let omega_d = desorption.grand_potential();
-            let is_ads = Array1::from_shape_fn(adsorption.profiles.len(), |i| {
-                omega_d.get(i).is_nan() || omega_a.get(i) < omega_d.get(i)
-            });
-            let profiles = is_ads
-                .into_iter()
-                .zip(adsorption.profiles.into_iter())
-                .zip(desorption.profiles.into_iter())
-                .map(|((is_ads, a), d)| if is_ads { a } else { d })
-                .collect();
-            Ok(Adsorption::new(functional, pore, profiles))
+            )?
+            .into_equilibrium_isotherm())
         }
     }
 
@@ -226,6 +227,9 @@ where
         let mut old_density = Some(&profile.density);
 
         for i in 0..pressure.len() {
+            if let Some(solver) = solver {
+                solver.check_progress("Adsorption isotherm", i)?;
+            }
             let mut bulk = StateBuilder::new(functional)
                 .temperature(temperature)
                 .pressure(pressure.get(i))
@@ -240,7 +244,14 @@ where
 
             let p = pore.initialize(&bulk, old_density, external_potential)?;
             let p2 = pore.initialize(&bulk, None, external_potential)?;
-            profiles.push(p.solve(solver).or_else(|_| p2.solve(solver)));
+            let profile = p.solve(solver).or_else(|e| match e {
+                EosError::Cancelled(_) => Err(e),
+                _ => p2.solve(solver),
+            });
+            if let Err(EosError::Cancelled(task)) = profile {
+                return Err(EosError::Cancelled(task));
+            }
+            profiles.push(profile);
 
             old_density = if let Some(Ok(l)) = profiles.last() {
                 Some(&l.profile.density)
@@ -445,6 +456,26 @@ where
         })
     }
 
+    /// Return the isotherm of a pure component as [TabulatedIsotherm], e.g., for
+    /// the use in an [IdealAdsorbedSolution].
+    ///
+    /// Failed calculations are skipped.
+    pub fn pure_component_isotherm(&self) -> EosResult<TabulatedIsotherm> {
+        if self.components != 1 {
+            return Err(EosError::IncompatibleComponents(self.components, 1));
+        }
+        let pressure = self.pressure();
+        let adsorption = self.total_adsorption();
+        let (pressure, adsorption): (Vec<_>, Vec<_>) = (0..self.profiles.len())
+            .map(|i| (pressure.get(i), adsorption.get(i)))
+            .filter(|(p, n)| !(p.is_nan() || n.is_nan()))
+            .unzip();
+        TabulatedIsotherm::new(
+            pressure.into_iter().collect(),
+            adsorption.into_iter().collect(),
+        )
+    }
+
     pub fn partial_molar_enthalpy_of_adsorption(&self) -> SIArray2 {
         let h_ads: Vec<_> = self
             .profiles
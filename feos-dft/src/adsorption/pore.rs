@@ -13,7 +13,7 @@ use num_dual::linalg::LU;
 use quantity::si::{SIArray, SIArray1, SIArray2, SINumber, SIUnit};
 use std::sync::Arc;
 
-const POTENTIAL_OFFSET: f64 = 2.0;
+pub(super) const POTENTIAL_OFFSET: f64 = 2.0;
 const DEFAULT_GRID_POINTS: usize = 2048;
 
 /// Parameters required to specify a 1D pore.
@@ -143,6 +143,37 @@ where
     pub fn enthalpy_of_adsorption(&self) -> EosResult<SINumber> {
         Ok((self.partial_molar_enthalpy_of_adsorption()? * &self.profile.bulk.molefracs).sum())
     }
+
+    /// Isosteric heats of adsorption $q_{\mathrm{st},i}=RT^2\left(\frac{\partial\ln p_i}{\partial T}\right)_{N_k}$
+    /// with the partial pressures $p_i=x_ip$ of the bulk phase.
+    ///
+    /// The derivatives at constant adsorbed amounts are calculated exactly from the
+    /// derivatives of the adsorbed amounts with respect to temperature, pressure and
+    /// bulk composition.
+    pub fn isosteric_heat(&self) -> EosResult<SIArray1> {
+        let t = self.profile.temperature;
+        let p = self.profile.bulk.pressure(Contributions::Total);
+        let x = &self.profile.bulk.molefracs;
+        let n_unit = self.profile.total_moles();
+        let dn_dt = self.profile.dn_dt()?.to_reduced(n_unit / t)?;
+        let dn_dp = self.profile.dn_dp()?.to_reduced(n_unit / p)?;
+        let n = dn_dt.len();
+
+        // T(dln p/dT) and T(dx_j/dT) at constant adsorbed amounts
+        let mut a = Array2::zeros((n + 1, n + 1));
+        let mut b = Array1::zeros(n + 1);
+        a.slice_mut(s![..n, 0]).assign(&dn_dp);
+        b.slice_mut(s![..n]).assign(&(-&dn_dt));
+        if n > 1 {
+            let dn_dx = self.profile.dn_dx()?.to_reduced(n_unit)?;
+            a.slice_mut(s![..n, 1..]).assign(&dn_dx);
+        }
+        a.slice_mut(s![n, 1..]).fill(1.0);
+        let d = LU::new(a)?.solve(&b);
+
+        let dln_p_dt = Array1::from_shape_fn(n, |i| d[0] + d[i + 1] / x[i]);
+        Ok(dln_p_dt * (SIUnit::gas_constant() * t))
+    }
 }
 
 impl PoreSpecification<Ix1> for Pore1D {
@@ -213,6 +244,7 @@ fn external_potential_1d<P: FluidParameters>(
     axis: &Axis,
     potential_cutoff: Option<f64>,
 ) -> EosResult<Array2<f64>> {
+    potential.validate(fluid_parameters.m().len())?;
     let potential_cutoff = potential_cutoff.unwrap_or(MAX_POTENTIAL);
     let effective_pore_size = match axis.geometry {
         Geometry::Spherical => pore_width.to_reduced(SIUnit::reference_length())?,
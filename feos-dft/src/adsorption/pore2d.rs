@@ -0,0 +1,222 @@
+use super::pore::{PoreProfile, PoreSpecification, POTENTIAL_OFFSET};
+use crate::adsorption::{ExternalPotential, FluidParameters};
+use crate::convolver::ConvolverFFT;
+use crate::functional::{HelmholtzEnergyFunctional, DFT};
+use crate::geometry::{Axis, Grid};
+use crate::profile::{DFTProfile, MAX_POTENTIAL};
+use feos_core::{EosError, EosResult, EosUnit, State};
+use ndarray::prelude::*;
+use ndarray::Axis as Axis_nd;
+use quantity::si::{SIArray3, SINumber, SIUnit};
+
+/// Parameters required to specify a 2D pore.
+///
+/// The pore is confined by two parallel walls perpendicular to the x direction.
+/// In the y direction, the pore is either periodic or, if `potential_y` is
+/// specified, confined by a second pair of walls, which results in a rectangular
+/// channel. Chemically patterned walls are modeled with a `pattern`, i.e., a stripe
+/// of the given width in the center of the y direction, in which the walls
+/// perpendicular to the x direction interact with the fluid via a different potential.
+pub struct Pore2D {
+    pub pore_size: [SINumber; 2],
+    pub n_grid: [usize; 2],
+    pub potential: ExternalPotential,
+    pub potential_y: Option<ExternalPotential>,
+    pub pattern: Option<(ExternalPotential, SINumber)>,
+    pub potential_cutoff: Option<f64>,
+}
+
+impl Pore2D {
+    pub fn new(
+        pore_size: [SINumber; 2],
+        n_grid: [usize; 2],
+        potential: ExternalPotential,
+        potential_y: Option<ExternalPotential>,
+        pattern: Option<(ExternalPotential, SINumber)>,
+        potential_cutoff: Option<f64>,
+    ) -> Self {
+        Self {
+            pore_size,
+            n_grid,
+            potential,
+            potential_y,
+            pattern,
+            potential_cutoff,
+        }
+    }
+}
+
+/// Density profile and properties of a 2D confined system.
+pub type PoreProfile2D<F> = PoreProfile<Ix2, F>;
+
+impl PoreSpecification<Ix2> for Pore2D {
+    fn initialize<F: HelmholtzEnergyFunctional + FluidParameters>(
+        &self,
+        bulk: &State<DFT<F>>,
+        density: Option<&SIArray3>,
+        external_potential: Option<&Array3<f64>>,
+    ) -> EosResult<PoreProfile2D<F>> {
+        let dft: &F = &bulk.eos;
+        let potential_offset = POTENTIAL_OFFSET
+            * bulk
+                .eos
+                .sigma_ff()
+                .iter()
+                .max_by(|a, b| a.total_cmp(b))
+                .unwrap();
+
+        // generate grid (the x direction is symmetric with respect to the center of the pore)
+        let x = Axis::new_cartesian(
+            self.n_grid[0],
+            0.5 * self.pore_size[0],
+            Some(potential_offset),
+        )?;
+        let y = match self.potential_y {
+            Some(_) => Axis::new_cartesian(
+                self.n_grid[1],
+                self.pore_size[1],
+                Some(2.0 * potential_offset),
+            )?,
+            None => Axis::new_cartesian(self.n_grid[1], self.pore_size[1], None)?,
+        };
+
+        // temperature
+        let t = bulk
+            .temperature
+            .to_reduced(SIUnit::reference_temperature())?;
+
+        // calculate external potential
+        let external_potential = external_potential.map_or_else(
+            || self.external_potential_2d(dft, [&x, &y], potential_offset, t),
+            |e| Ok(e.clone()),
+        )?;
+
+        // initialize convolver
+        let grid = Grid::Cartesian2(x, y);
+        let weight_functions = dft.weight_functions(t);
+        let convolver = ConvolverFFT::plan(&grid, &weight_functions, Some(1));
+
+        Ok(PoreProfile {
+            profile: DFTProfile::new(grid, convolver, bulk, Some(external_potential), density)?,
+            grand_potential: None,
+            interfacial_tension: None,
+        })
+    }
+
+    fn dimension(&self) -> i32 {
+        2
+    }
+}
+
+impl Pore2D {
+    fn external_potential_2d<P: FluidParameters>(
+        &self,
+        fluid_parameters: &P,
+        axis: [&Axis; 2],
+        potential_offset: f64,
+        temperature: f64,
+    ) -> EosResult<Array3<f64>> {
+        let [x, y] = axis;
+        let potentials = [
+            Some(&self.potential),
+            self.potential_y.as_ref(),
+            self.pattern.as_ref().map(|(p, _)| p),
+        ];
+        if potentials
+            .into_iter()
+            .flatten()
+            .any(|p| matches!(p, ExternalPotential::Custom(_)))
+        {
+            return Err(EosError::Error(String::from(
+                "Custom potentials are not supported in 2D pores. Provide the full external potential to `initialize` instead.",
+            )));
+        }
+
+        for potential in potentials.into_iter().flatten() {
+            potential.validate(fluid_parameters.m().len())?;
+        }
+
+        let potential_cutoff = self.potential_cutoff.unwrap_or(MAX_POTENTIAL);
+        let h_x = 0.5 * self.pore_size[0].to_reduced(SIUnit::reference_length())?;
+        let l_y = self.pore_size[1].to_reduced(SIUnit::reference_length())?;
+
+        // potential of the walls perpendicular to the x direction
+        let wall_potential = |potential: &ExternalPotential| {
+            (potential.calculate_cartesian_potential(
+                &(h_x + &x.grid),
+                fluid_parameters,
+                temperature,
+            ) + &potential.calculate_cartesian_potential(
+                &(h_x - &x.grid),
+                fluid_parameters,
+                temperature,
+            )) / temperature
+        };
+        let potential_x = wall_potential(&self.potential);
+        let pattern = match &self.pattern {
+            Some((potential, width)) => Some((
+                wall_potential(potential),
+                width.to_reduced(SIUnit::reference_length())?,
+            )),
+            None => None,
+        };
+
+        // potential of the walls perpendicular to the y direction
+        let (offset_y, potential_y) = match &self.potential_y {
+            Some(potential) => {
+                let potential_y = (potential.calculate_cartesian_potential(
+                    &(&y.grid - potential_offset),
+                    fluid_parameters,
+                    temperature,
+                ) + &potential.calculate_cartesian_potential(
+                    &(l_y + potential_offset - &y.grid),
+                    fluid_parameters,
+                    temperature,
+                )) / temperature;
+                (potential_offset, Some(potential_y))
+            }
+            None => (0.0, None),
+        };
+
+        // combine the contributions of all walls
+        let y_center = offset_y + 0.5 * l_y;
+        let mut external_potential =
+            Array3::zeros((potential_x.nrows(), x.grid.len(), y.grid.len()));
+        for (k, &y_k) in y.grid.iter().enumerate() {
+            let mut potential = external_potential.index_axis_mut(Axis_nd(2), k);
+            match &pattern {
+                Some((potential_pattern, width)) if (y_k - y_center).abs() < 0.5 * width => {
+                    potential.assign(potential_pattern)
+                }
+                _ => potential.assign(&potential_x),
+            }
+            if let Some(potential_y) = &potential_y {
+                potential += &potential_y.column(k).insert_axis(Axis_nd(1));
+            }
+        }
+
+        // fill the regions outside of the pore
+        for (i, &x_i) in x.grid.iter().enumerate() {
+            if x_i > h_x {
+                external_potential
+                    .index_axis_mut(Axis_nd(1), i)
+                    .fill(potential_cutoff);
+            }
+        }
+        if self.potential_y.is_some() {
+            for (k, &y_k) in y.grid.iter().enumerate() {
+                if y_k < offset_y || y_k > offset_y + l_y {
+                    external_potential
+                        .index_axis_mut(Axis_nd(2), k)
+                        .fill(potential_cutoff);
+                }
+            }
+        }
+        external_potential.map_inplace(|x| {
+            if *x > potential_cutoff {
+                *x = potential_cutoff
+            }
+        });
+        Ok(external_potential)
+    }
+}
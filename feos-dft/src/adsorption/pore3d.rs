@@ -1,13 +1,16 @@
+use super::cif::CifStructure;
 use super::pore::{PoreProfile, PoreSpecification};
 use crate::adsorption::FluidParameters;
 use crate::convolver::ConvolverFFT;
 use crate::functional::{HelmholtzEnergyFunctional, DFT};
 use crate::geometry::{Axis, Grid};
 use crate::profile::{DFTProfile, CUTOFF_RADIUS, MAX_POTENTIAL};
-use feos_core::{EosResult, EosUnit, State};
+use feos_core::{EosError, EosResult, EosUnit, State};
 use ndarray::prelude::*;
 use ndarray::Zip;
-use quantity::si::{SIArray2, SIArray4, SINumber, SIUnit};
+use quantity::si::{SIArray2, SIArray4, SINumber, SIUnit, ANGSTROM};
+use std::collections::HashMap;
+use std::path::Path;
 
 /// Parameters required to specify a 3D pore.
 pub struct Pore3D {
@@ -40,6 +43,63 @@ impl Pore3D {
             cutoff_radius,
         }
     }
+
+    /// Create a 3D pore from the unit cell of a solid structure in a CIF file.
+    ///
+    /// The Lennard-Jones parameters (`sigma` in Angstrom, `epsilon_k` in Kelvin)
+    /// of every atom are taken from the `force_field` using the atom label or,
+    /// if the label is not found, the type symbol of the atom. Symmetry operations
+    /// in the file are applied to obtain all atoms in the unit cell. Only
+    /// orthogonal unit cells are supported.
+    pub fn from_cif<P: AsRef<Path>>(
+        file: P,
+        force_field: &HashMap<String, (f64, f64)>,
+        n_grid: [usize; 3],
+        potential_cutoff: Option<f64>,
+        cutoff_radius: Option<SINumber>,
+    ) -> EosResult<Self> {
+        let structure = CifStructure::from_file(file)?;
+        if structure
+            .cell_angles
+            .iter()
+            .any(|a| (a - 90.0).abs() > 1e-6)
+        {
+            return Err(EosError::Error(String::from(
+                "Only orthogonal unit cells are supported for 3D pores.",
+            )));
+        }
+
+        let n_atoms = structure.atoms.len();
+        let mut coordinates = Array2::zeros((3, n_atoms));
+        let mut sigma_ss = Array1::zeros(n_atoms);
+        let mut epsilon_k_ss = Array1::zeros(n_atoms);
+        for (i, atom) in structure.atoms.iter().enumerate() {
+            let &(sigma, epsilon_k) = force_field
+                .get(&atom.label)
+                .or_else(|| force_field.get(&atom.type_symbol))
+                .ok_or_else(|| {
+                    EosError::Error(format!(
+                        "No force field parameters for atom {} ({}).",
+                        atom.label, atom.type_symbol
+                    ))
+                })?;
+            for k in 0..3 {
+                coordinates[[k, i]] = atom.fractional_coordinates[k] * structure.cell_lengths[k];
+            }
+            sigma_ss[i] = sigma;
+            epsilon_k_ss[i] = epsilon_k;
+        }
+
+        Ok(Self::new(
+            structure.cell_lengths.map(|l| l * ANGSTROM),
+            n_grid,
+            coordinates * ANGSTROM,
+            sigma_ss,
+            epsilon_k_ss,
+            potential_cutoff,
+            cutoff_radius,
+        ))
+    }
 }
 
 /// Density profile and properties of a 3D confined system.
@@ -139,26 +199,31 @@ pub fn external_potential_3d<F: FluidParameters>(
     // square cut-off radius
     let cutoff_radius2 = cutoff_radius.powi(2);
 
+    // periodic images of the unit cell with atoms within the cut-off radius
+    let images = periodic_images(system_size, cutoff_radius);
+
     // calculate external potential
     let sigma_ff = functional.sigma_ff();
     let epsilon_k_ff = functional.epsilon_k_ff();
 
     Zip::indexed(&mut external_potential).par_for_each(|(i, ix, iy, iz), u| {
-        let distance2 = calculate_distance2(
-            [axis[0].grid[ix], axis[1].grid[iy], axis[2].grid[iz]],
-            &coordinates,
-            system_size,
-        );
+        let point = [axis[0].grid[ix], axis[1].grid[iy], axis[2].grid[iz]];
         let sigma_sf = sigma_ss.mapv(|s| (s + sigma_ff[i]) / 2.0);
         let epsilon_sf = epsilon_ss.mapv(|e| (e * epsilon_k_ff[i]).sqrt());
-        *u = (0..sigma_ss.len())
-            .map(|alpha| {
-                m[i] * evaluate_lj_potential(
-                    distance2[alpha],
-                    sigma_sf[alpha],
-                    epsilon_sf[alpha],
-                    cutoff_radius2,
-                )
+        *u = images
+            .iter()
+            .map(|&image| {
+                let distance2 = calculate_distance2_image(point, &coordinates, system_size, image);
+                (0..sigma_ss.len())
+                    .map(|alpha| {
+                        m[i] * evaluate_lj_potential(
+                            distance2[alpha],
+                            sigma_sf[alpha],
+                            epsilon_sf[alpha],
+                            cutoff_radius2,
+                        )
+                    })
+                    .sum::<f64>()
             })
             .sum::<f64>()
             / reduced_temperature
@@ -212,3 +277,40 @@ pub(super) fn calculate_distance2(
         rx.powi(2) + ry.powi(2) + rz.powi(2)
     })
 }
+
+/// Return the shifts (in multiples of the system size) of all periodic images
+/// of the unit cell that can contain solid atoms within the cut-off radius.
+///
+/// For a cut-off radius smaller than half the system size, only the nearest
+/// image is required.
+fn periodic_images(system_size: [f64; 3], cutoff_radius: f64) -> Vec<[f64; 3]> {
+    let n = system_size.map(|l| (cutoff_radius / l - 0.5).ceil().max(0.0) as i32);
+    let mut images = Vec::new();
+    for x in -n[0]..=n[0] {
+        for y in -n[1]..=n[1] {
+            for z in -n[2]..=n[2] {
+                images.push([x as f64, y as f64, z as f64]);
+            }
+        }
+    }
+    images
+}
+
+/// Evaluate the squared euclidian distance between a point and the coordinates of all solid atoms
+/// in the periodic image that is shifted by `image` with respect to the nearest image.
+fn calculate_distance2_image(
+    point: [f64; 3],
+    coordinates: &Array2<f64>,
+    system_size: [f64; 3],
+    image: [f64; 3],
+) -> Array1<f64> {
+    Array1::from_shape_fn(coordinates.ncols(), |i| {
+        (0..3)
+            .map(|k| {
+                let mut r = coordinates[[k, i]] - point[k];
+                r -= system_size[k] * (r / system_size[k]).round();
+                (r + image[k] * system_size[k]).powi(2)
+            })
+            .sum()
+    })
+}
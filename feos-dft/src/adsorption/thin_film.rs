@@ -0,0 +1,131 @@
+//! Thin liquid films on planar walls and disjoining pressure isotherms.
+use super::{Adsorption1D, FluidParameters, Pore1D, PoreProfile1D};
+use crate::functional::{HelmholtzEnergyFunctional, DFT};
+use crate::geometry::Geometry;
+use crate::solver::DFTSolver;
+use feos_core::{Contributions, EosError, EosResult, EosUnit, EquationOfState, PhaseEquilibrium};
+use ndarray::Axis;
+use quantity::si::{SIArray1, SINumber, SIUnit};
+
+/// Liquid films on a planar wall in equilibrium with an undersaturated vapor reservoir.
+///
+/// The density profiles are obtained along the adsorption branch of a (wide)
+/// slit pore, in which each half of the pore contains a single wall. The film
+/// thickness is calculated from the surface excess adsorption $\Gamma$ per wall as
+/// $$l=\frac{\Gamma}{\rho_\mathrm{l}-\rho_\mathrm{v}}$$
+/// with the coexisting densities $\rho_\mathrm{l}$ and $\rho_\mathrm{v}$. The disjoining
+/// pressure of a film in equilibrium with a vapor at pressure $p$ and chemical potential
+/// $\mu$ is
+/// $$\Pi=p-p_\mathrm{l}(\mu)$$
+/// where $p_\mathrm{l}(\mu)$ is the pressure of the (metastable) bulk liquid at the
+/// same temperature and chemical potential.
+///
+/// Only pure components are supported.
+pub struct ThinFilm<F> {
+    pub vle: PhaseEquilibrium<DFT<F>, 2>,
+    pub profiles: Vec<EosResult<PoreProfile1D<F>>>,
+    pub pressure: SIArray1,
+    pub film_thickness: SIArray1,
+    pub disjoining_pressure: SIArray1,
+}
+
+impl<F: HelmholtzEnergyFunctional + FluidParameters> ThinFilm<F> {
+    /// Calculate the film thicknesses and the disjoining pressures for all
+    /// given pressures of the vapor reservoir.
+    ///
+    /// The `pore` has to be a cartesian slit pore that is wide enough for
+    /// the vapor in its center to be unaffected by the films. All pressures
+    /// have to be below the vapor pressure of the phase equilibrium `vle`.
+    pub fn new(
+        vle: &PhaseEquilibrium<DFT<F>, 2>,
+        pore: &Pore1D,
+        pressure: &SIArray1,
+        solver: Option<&DFTSolver>,
+    ) -> EosResult<Self> {
+        let functional = &vle.vapor().eos;
+        if functional.components() > 1 {
+            return Err(EosError::Error(String::from(
+                "Thin films can only be calculated for pure components.",
+            )));
+        }
+        if !matches!(pore.geometry, Geometry::Cartesian) {
+            return Err(EosError::Error(String::from(
+                "Thin films can only be calculated for planar walls.",
+            )));
+        }
+        let p_sat = vle.vapor().pressure(Contributions::Total);
+        if pressure.into_iter().any(|p| p >= p_sat) {
+            return Err(EosError::Error(String::from(
+                "The pressure of the vapor reservoir has to be below the vapor pressure.",
+            )));
+        }
+
+        let temperature = vle.vapor().temperature;
+        let profiles = Adsorption1D::adsorption_isotherm(
+            functional,
+            temperature,
+            pressure,
+            pore,
+            None,
+            solver,
+        )?
+        .profiles;
+
+        let delta_rho = vle.liquid().density - vle.vapor().density;
+        let nan_length = f64::NAN * SIUnit::reference_length();
+        let nan_pressure = f64::NAN * SIUnit::reference_pressure();
+        let mut film_thickness = Vec::with_capacity(profiles.len());
+        let mut disjoining_pressure = Vec::with_capacity(profiles.len());
+        for profile in &profiles {
+            let (l, pi) = match profile {
+                Ok(p) => {
+                    let bulk = &p.profile.bulk;
+                    let rho = p.profile.density.sum_axis(Axis(0));
+                    let adsorption = p.profile.integrate(&(rho - bulk.density));
+
+                    // pressure of the liquid at the chemical potential of the reservoir
+                    let g = bulk.molar_gibbs_energy(Contributions::Total);
+                    let pi = vle.liquid().clone().update_gibbs_energy(g).map_or(
+                        nan_pressure,
+                        |liquid| {
+                            bulk.pressure(Contributions::Total)
+                                - liquid.pressure(Contributions::Total)
+                        },
+                    );
+                    (adsorption / delta_rho, pi)
+                }
+                Err(_) => (nan_length, nan_pressure),
+            };
+            film_thickness.push(l);
+            disjoining_pressure.push(pi);
+        }
+
+        Ok(Self {
+            vle: vle.clone(),
+            profiles,
+            pressure: pressure.clone(),
+            film_thickness: SIArray1::from_vec(film_thickness),
+            disjoining_pressure: SIArray1::from_vec(disjoining_pressure),
+        })
+    }
+
+    /// The equilibrium film thickness for a given disjoining pressure, obtained
+    /// by linear interpolation of the disjoining pressure isotherm.
+    ///
+    /// Returns `None` if the disjoining pressure is outside the calculated range.
+    pub fn equilibrium_film_thickness(&self, disjoining_pressure: SINumber) -> Option<SINumber> {
+        let pi = self
+            .disjoining_pressure
+            .to_reduced(SIUnit::reference_pressure())
+            .ok()?;
+        let pi_eq = disjoining_pressure
+            .to_reduced(SIUnit::reference_pressure())
+            .ok()?;
+        (1..pi.len())
+            .find(|&i| (pi[i - 1] - pi_eq) * (pi[i] - pi_eq) <= 0.0 && pi[i - 1] != pi[i])
+            .map(|i| {
+                let (l0, l1) = (self.film_thickness.get(i - 1), self.film_thickness.get(i));
+                l0 + (l1 - l0) * ((pi_eq - pi[i - 1]) / (pi[i] - pi[i - 1]))
+            })
+    }
+}
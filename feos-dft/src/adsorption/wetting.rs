@@ -0,0 +1,126 @@
+//! Wetting behavior of fluids on planar walls.
+use super::{FluidParameters, Pore1D, PoreSpecification};
+use crate::functional::{HelmholtzEnergyFunctional, DFT};
+use crate::geometry::Geometry;
+use crate::interface::PlanarInterface;
+use crate::solver::DFTSolver;
+use feos_core::{EosError, EosResult, EosUnit, PhaseEquilibrium};
+use ndarray::Array1;
+use quantity::si::{SIArray1, SINumber, SIUnit};
+
+const DEFAULT_GRID_POINTS: usize = 2048;
+
+/// Interfacial tensions and contact angles of a fluid on a planar wall.
+///
+/// For every phase equilibrium, the solid-liquid and solid-vapor
+/// interfacial tensions are obtained from the grand potential of the
+/// liquid and the vapor phase in a (wide) slit pore, the liquid-vapor
+/// interfacial tension from a [PlanarInterface]. The contact angle
+/// $\theta$ follows from Young's equation
+/// $$\cos\theta=\frac{\gamma_\mathrm{sv}-\gamma_\mathrm{sl}}{\gamma_\mathrm{lv}}$$
+pub struct Wetting {
+    pub temperature: SIArray1,
+    pub liquid_vapor_tension: SIArray1,
+    pub solid_liquid_tension: SIArray1,
+    pub solid_vapor_tension: SIArray1,
+    cos_theta: Array1<f64>,
+}
+
+impl Wetting {
+    /// Calculate the wetting behavior for all given phase equilibria.
+    ///
+    /// The `pore` has to be a cartesian slit pore that is wide enough for
+    /// the fluid in its center to be unaffected by the walls. `n_grid` is
+    /// the number of grid points of the liquid-vapor interface.
+    pub fn new<F: HelmholtzEnergyFunctional + FluidParameters>(
+        vle: &[PhaseEquilibrium<DFT<F>, 2>],
+        pore: &Pore1D,
+        n_grid: Option<usize>,
+        solver: Option<&DFTSolver>,
+    ) -> EosResult<Self> {
+        if !matches!(pore.geometry, Geometry::Cartesian) {
+            return Err(EosError::Error(String::from(
+                "Contact angles can only be calculated for planar walls.",
+            )));
+        }
+        let n_grid = n_grid.unwrap_or(DEFAULT_GRID_POINTS);
+
+        let mut gamma_lv = Vec::with_capacity(vle.len());
+        let mut gamma_sl = Vec::with_capacity(vle.len());
+        let mut gamma_sv = Vec::with_capacity(vle.len());
+        for vle in vle {
+            // initialize with pDGT for single segments and tanh for mixtures and segment DFT
+            let interface = if vle.vapor().eos.component_index().len() == 1 {
+                PlanarInterface::from_pdgt(vle, n_grid, false)
+            } else {
+                PlanarInterface::from_tanh(
+                    vle,
+                    n_grid,
+                    100.0 * SIUnit::reference_length(),
+                    500.0 * SIUnit::reference_temperature(),
+                    false,
+                )
+            }?
+            .solve(solver)?;
+            gamma_lv.push(interface.surface_tension.unwrap());
+
+            // each half of the slit pore contains a single wall
+            let liquid = pore.initialize(vle.liquid(), None, None)?.solve(solver)?;
+            gamma_sl.push(liquid.interfacial_tension.unwrap());
+            let vapor = pore.initialize(vle.vapor(), None, None)?.solve(solver)?;
+            gamma_sv.push(vapor.interfacial_tension.unwrap());
+        }
+
+        let cos_theta = gamma_lv
+            .iter()
+            .zip(gamma_sl.iter().zip(gamma_sv.iter()))
+            .map(|(&lv, (&sl, &sv))| ((sv - sl) / lv).into_value())
+            .collect::<Result<Array1<f64>, _>>()?;
+        Ok(Self {
+            temperature: SIArray1::from_vec(
+                vle.iter().map(|vle| vle.vapor().temperature).collect(),
+            ),
+            liquid_vapor_tension: SIArray1::from_vec(gamma_lv),
+            solid_liquid_tension: SIArray1::from_vec(gamma_sl),
+            solid_vapor_tension: SIArray1::from_vec(gamma_sv),
+            cos_theta,
+        })
+    }
+
+    /// The cosine of the contact angle from Young's equation.
+    ///
+    /// Values larger than 1 (smaller than -1) indicate complete wetting (drying).
+    pub fn cos_theta(&self) -> &Array1<f64> {
+        &self.cos_theta
+    }
+
+    /// The contact angle in degrees.
+    pub fn contact_angle(&self) -> Array1<f64> {
+        self.cos_theta
+            .mapv(|c| c.clamp(-1.0, 1.0).acos().to_degrees())
+    }
+
+    /// Temperature of the wetting transition, i.e., the temperature at which
+    /// the contact angle vanishes, obtained by linear interpolation of
+    /// $\cos\theta$ between the given phase equilibria.
+    pub fn wetting_temperature(&self) -> Option<SINumber> {
+        self.transition_temperature(1.0)
+    }
+
+    /// Temperature of the drying transition, i.e., the temperature at which
+    /// the contact angle reaches 180°, obtained by linear interpolation of
+    /// $\cos\theta$ between the given phase equilibria.
+    pub fn drying_temperature(&self) -> Option<SINumber> {
+        self.transition_temperature(-1.0)
+    }
+
+    fn transition_temperature(&self, cos_theta: f64) -> Option<SINumber> {
+        let c = &self.cos_theta;
+        (1..c.len())
+            .find(|&i| (c[i - 1] - cos_theta) * (c[i] - cos_theta) <= 0.0 && c[i - 1] != c[i])
+            .map(|i| {
+                let (t0, t1) = (self.temperature.get(i - 1), self.temperature.get(i));
+                t0 + (t1 - t0) * ((cos_theta - c[i - 1]) / (c[i] - c[i - 1]))
+            })
+    }
+}
@@ -0,0 +1,246 @@
+//! Checkpoints of density profiles that can be stored to resume DFT calculations.
+use crate::functional::HelmholtzEnergyFunctional;
+use crate::profile::DFTProfile;
+use feos_core::{EosError, EosResult, EosUnit};
+use ndarray::{Array, Array1, ArrayD, Dimension};
+use quantity::si::{SIArray, SIUnit, ANGSTROM, JOULE, KELVIN, METER, MOL, RGAS};
+use serde::{Deserialize, Serialize};
+use std::fs::File;
+use std::io::{BufReader, BufWriter};
+use std::path::Path;
+
+const CHECKPOINT_TOLERANCE: f64 = 1e-10;
+const CHEMICAL_POTENTIAL_TOLERANCE: f64 = 1e-8;
+
+/// Converged or partially converged density profile together with the
+/// information that is required to resume a DFT calculation.
+///
+/// Checkpoints are stored as JSON files; HDF5 and netCDF are not supported
+/// because they would require the native libraries at build time. All
+/// quantities are stored in units of K, J/mol, Å and mol/m³ respectively.
+///
+/// If the last solver of the calculation was an Anderson mixing, its history
+/// is stored as well, so that the mixing continues where it stopped instead
+/// of starting from a single iterate.
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct DFTCheckpoint {
+    /// Temperature in K
+    pub temperature: f64,
+    /// Chemical potentials of all components in J/mol
+    pub chemical_potential: Array1<f64>,
+    /// Grid points of all axes in Å
+    pub grid: Vec<Array1<f64>>,
+    /// Density profiles of all segments in mol/m³
+    pub density: ArrayD<f64>,
+    /// Residuals of all iterations of the last solver run
+    pub residual: Vec<f64>,
+    /// History of the Anderson mixing, if it was the last solver
+    #[serde(default)]
+    pub anderson_history: Option<AndersonHistory>,
+}
+
+/// Previous iterates and residuals of an Anderson mixing.
+///
+/// The density profiles and residuals are stored in reduced units and as
+/// logarithms if the mixing was carried out for the logarithmic densities.
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct AndersonHistory {
+    /// Whether the logarithm of the density was mixed
+    pub log: bool,
+    /// Density profiles of all segments in every stored iteration
+    pub density: Vec<ArrayD<f64>>,
+    /// Bulk densities of all segments in every stored iteration
+    pub bulk_density: Vec<Array1<f64>>,
+    /// Residuals of the density profiles in every stored iteration
+    pub residual: Vec<ArrayD<f64>>,
+    /// Residuals of the bulk densities in every stored iteration
+    pub bulk_residual: Vec<Array1<f64>>,
+}
+
+/// Density profile and bulk densities of a single iteration.
+pub(crate) type Iterate<D> = (Array<f64, D>, Array1<f64>);
+
+impl AndersonHistory {
+    pub(crate) fn new<'a, D: Dimension + 'a>(
+        log: bool,
+        density: impl Iterator<Item = &'a Iterate<D>>,
+        residual: impl Iterator<Item = &'a Iterate<D>>,
+    ) -> Self {
+        let (density, bulk_density) = density
+            .map(|(rho, rho_bulk)| (rho.clone().into_dyn(), rho_bulk.clone()))
+            .unzip();
+        let (residual, bulk_residual) = residual
+            .map(|(res, res_bulk)| (res.clone().into_dyn(), res_bulk.clone()))
+            .unzip();
+        Self {
+            log,
+            density,
+            bulk_density,
+            residual,
+            bulk_residual,
+        }
+    }
+
+    /// Return the stored iterates and residuals with the dimension of the profile.
+    pub(crate) fn iterates<D: Dimension>(&self) -> EosResult<(Vec<Iterate<D>>, Vec<Iterate<D>>)> {
+        let convert = |x: &[ArrayD<f64>], x_bulk: &[Array1<f64>]| {
+            x.iter()
+                .zip(x_bulk)
+                .map(|(x, x_bulk)| {
+                    let x = x.clone().into_dimensionality().map_err(|_| {
+                        EosError::Error(String::from(
+                            "The Anderson history does not match the dimension of the profile.",
+                        ))
+                    })?;
+                    Ok((x, x_bulk.clone()))
+                })
+                .collect::<EosResult<Vec<_>>>()
+        };
+        Ok((
+            convert(&self.density, &self.bulk_density)?,
+            convert(&self.residual, &self.bulk_residual)?,
+        ))
+    }
+
+    /// Check that all stored iterations match the given shapes.
+    fn check_shape(&self, shape: &[usize], bulk_len: usize) -> EosResult<()> {
+        let n = self.density.len();
+        let matches = self.bulk_density.len() == n
+            && self.residual.len() == n
+            && self.bulk_residual.len() == n
+            && self
+                .density
+                .iter()
+                .chain(&self.residual)
+                .all(|x| x.shape() == shape)
+            && self
+                .bulk_density
+                .iter()
+                .chain(&self.bulk_residual)
+                .all(|x| x.len() == bulk_len);
+        if matches {
+            Ok(())
+        } else {
+            Err(EosError::Error(String::from(
+                "The Anderson history of the checkpoint does not match the profile.",
+            )))
+        }
+    }
+}
+
+impl DFTCheckpoint {
+    /// Read a checkpoint from a JSON file.
+    pub fn from_file<P: AsRef<Path>>(file: P) -> EosResult<Self> {
+        let file = File::open(file).map_err(|e| EosError::Error(e.to_string()))?;
+        serde_json::from_reader(BufReader::new(file)).map_err(|e| EosError::Error(e.to_string()))
+    }
+
+    /// Write the checkpoint to a JSON file.
+    pub fn to_file<P: AsRef<Path>>(&self, file: P) -> EosResult<()> {
+        let file = File::create(file).map_err(|e| EosError::Error(e.to_string()))?;
+        serde_json::to_writer(BufWriter::new(file), self)
+            .map_err(|e| EosError::Error(e.to_string()))
+    }
+
+    /// Return the stored density profile.
+    pub fn density<D: Dimension>(&self) -> EosResult<SIArray<D>> {
+        let density = self.density.clone().into_dimensionality().map_err(|_| {
+            EosError::Error(format!(
+                "The checkpoint contains a {}-dimensional density profile.",
+                self.grid.len()
+            ))
+        })?;
+        Ok(density * (MOL / METER.powi(3)))
+    }
+}
+
+impl<D: Dimension, F: HelmholtzEnergyFunctional> DFTProfile<D, F>
+where
+    D::Larger: Dimension<Smaller = D>,
+{
+    /// Store the current state of the profile in a checkpoint.
+    pub fn checkpoint(&self) -> EosResult<DFTCheckpoint> {
+        let grid: Vec<_> = self
+            .grid
+            .grids()
+            .into_iter()
+            .map(|g| (g * SIUnit::reference_length()).to_reduced(ANGSTROM))
+            .collect::<Result<_, _>>()?;
+        Ok(DFTCheckpoint {
+            temperature: self.temperature.to_reduced(KELVIN)?,
+            chemical_potential: self.chemical_potential().to_reduced(JOULE / MOL)?,
+            grid,
+            density: self.density.to_reduced(MOL / METER.powi(3))?.into_dyn(),
+            residual: self
+                .solver_log
+                .as_ref()
+                .map_or_else(Vec::new, |log| log.residual().to_vec()),
+            anderson_history: self
+                .solver_log
+                .as_ref()
+                .and_then(|log| log.anderson_history().cloned()),
+        })
+    }
+
+    /// Initialize the density profile from a checkpoint.
+    ///
+    /// The temperature, the chemical potentials, the grid and the number of
+    /// segments of the checkpoint have to match the profile, i.e., the checkpoint
+    /// has to be in equilibrium with the same bulk state. The bulk state and the
+    /// specification of the profile are not changed. A stored Anderson history
+    /// is used by the first Anderson mixing (with the same `log` setting) of
+    /// the next solver run.
+    pub fn load_checkpoint(&mut self, checkpoint: &DFTCheckpoint) -> EosResult<()> {
+        let temperature = self.temperature.to_reduced(KELVIN)?;
+        if (temperature - checkpoint.temperature).abs() > CHECKPOINT_TOLERANCE * temperature {
+            return Err(EosError::Error(format!(
+                "The temperature of the checkpoint ({} K) does not match the temperature of the profile ({} K).",
+                checkpoint.temperature, temperature
+            )));
+        }
+
+        let rt = (RGAS * self.temperature).to_reduced(JOULE / MOL)?;
+        let chemical_potential = self.chemical_potential().to_reduced(JOULE / MOL)?;
+        let chemical_potential_matches = chemical_potential.len()
+            == checkpoint.chemical_potential.len()
+            && chemical_potential
+                .iter()
+                .zip(&checkpoint.chemical_potential)
+                .all(|(&mu, &mu_cp)| (mu - mu_cp).abs() < CHEMICAL_POTENTIAL_TOLERANCE * rt);
+        if !chemical_potential_matches {
+            return Err(EosError::Error(format!(
+                "The chemical potentials of the checkpoint ({} J/mol) do not match the bulk state of the profile ({} J/mol).",
+                checkpoint.chemical_potential, chemical_potential
+            )));
+        }
+
+        let angstrom = SIUnit::reference_length().to_reduced(ANGSTROM)?;
+        let grids = self.grid.grids();
+        let grid_matches = grids.len() == checkpoint.grid.len()
+            && grids.iter().zip(&checkpoint.grid).all(|(g, g_cp)| {
+                g.len() == g_cp.len()
+                    && g.iter().zip(g_cp).all(|(&g, &g_cp)| {
+                        (g * angstrom - g_cp).abs() < CHECKPOINT_TOLERANCE * (1.0 + g_cp.abs())
+                    })
+            });
+        if !grid_matches {
+            return Err(EosError::Error(String::from(
+                "The grid of the checkpoint does not match the grid of the profile.",
+            )));
+        }
+
+        if checkpoint.density.shape() != self.external_potential.shape() {
+            return Err(EosError::Error(format!(
+                "The checkpoint contains {} segments, the profile {}.",
+                checkpoint.density.shape()[0],
+                self.external_potential.shape()[0]
+            )));
+        }
+        if let Some(history) = &checkpoint.anderson_history {
+            history.check_shape(checkpoint.density.shape(), self.dft.component_index().len())?;
+        }
+        self.density = checkpoint.density()?;
+        self.anderson_history = checkpoint.anderson_history.clone();
+        Ok(())
+    }
+}
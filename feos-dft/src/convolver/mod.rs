@@ -21,6 +21,11 @@ use transform::*;
 /// Helmholtz energy functional.
 ///
 /// Parametrized over data types `T` and dimension of the problem `D`.
+///
+/// Alternative implementations, e.g., convolvers that evaluate the Fourier
+/// transforms on a GPU, can be used by replacing the `convolver` field of a
+/// [DFTProfile](crate::DFTProfile) before solving it. No GPU implementation
+/// is included in this crate.
 pub trait Convolver<T, D: Dimension>: Send + Sync {
     /// Convolve the profile with the given weight function.
     fn convolve(&self, profile: Array<T, D>, weight_function: &WeightFunction<T>) -> Array<T, D>;
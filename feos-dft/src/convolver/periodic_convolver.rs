@@ -1,11 +1,14 @@
 use super::{Convolver, FFTWeightFunctions};
 use crate::geometry::Axis;
 use crate::weight_functions::{WeightFunction, WeightFunctionInfo};
+#[cfg(feature = "rayon")]
+use ndarray::parallel::prelude::*;
 use ndarray::Axis as Axis_nd;
 use ndarray::*;
 use num_dual::DualNum;
 use rustfft::num_complex::Complex;
-use rustfft::{Fft, FftDirection, FftNum, FftPlanner};
+use rustfft::num_traits::Zero;
+use rustfft::{Fft, FftNum, FftPlanner};
 use std::f64::consts::PI;
 use std::ops::AddAssign;
 use std::sync::Arc;
@@ -140,39 +143,68 @@ where
 }
 
 impl<T: FftNum, D: Dimension> PeriodicConvolver<T, D> {
-    fn transform(&self, transform: &Arc<dyn Fft<T>>, mut f: ArrayViewMut1<Complex<T>>) {
-        if let Some(f) = f.as_slice_mut() {
-            transform.process(f);
-        } else {
-            let mut f_cont = f.to_owned();
-            transform.process(f_cont.as_slice_mut().unwrap());
-            f.assign(&f_cont);
-        }
-        if let FftDirection::Inverse = transform.fft_direction() {
-            let points = T::from_usize(transform.len()).unwrap();
-            f.mapv_inplace(|x| x / points);
+    /// Apply the one-dimensional Fourier transform to all lanes of `f` along `axis`.
+    ///
+    /// The buffers for non-contiguous lanes and the scratch space of the transform
+    /// are allocated only once (per thread) and reused for all lanes.
+    fn transform_axis<D2: Dimension>(
+        transform: &Arc<dyn Fft<T>>,
+        f: &mut Array<Complex<T>, D2>,
+        axis: usize,
+    ) {
+        let init = || {
+            (
+                vec![Complex::zero(); transform.len()],
+                vec![Complex::zero(); transform.get_inplace_scratch_len()],
+            )
+        };
+        let process = |(buffer, scratch): &mut (Vec<Complex<T>>, Vec<Complex<T>>),
+                       mut lane: ArrayViewMut1<Complex<T>>| {
+            if let Some(lane) = lane.as_slice_mut() {
+                transform.process_with_scratch(lane, scratch);
+            } else {
+                ArrayViewMut1::from(&mut buffer[..]).assign(&lane);
+                transform.process_with_scratch(buffer, scratch);
+                lane.assign(&ArrayView1::from(&buffer[..]));
+            }
+        };
+
+        #[cfg(feature = "rayon")]
+        Zip::from(f.lanes_mut(Axis_nd(axis)))
+            .into_par_iter()
+            .for_each_init(init, |buffers, (lane,)| process(buffers, lane));
+        #[cfg(not(feature = "rayon"))]
+        {
+            let mut buffers = init();
+            for lane in f.lanes_mut(Axis_nd(axis)) {
+                process(&mut buffers, lane);
+            }
         }
     }
 
+    /// Fourier transform of all profiles contained in `f` (the leading axes
+    /// are interpreted as different profiles).
     fn forward_transform<D2: Dimension>(&self, f: ArrayView<T, D2>) -> Array<Complex<T>, D2> {
         let offset = D2::NDIM.unwrap() - D::NDIM.unwrap();
         let mut result = f.mapv(Complex::from);
         for (i, transform) in self.forward_transforms.iter().enumerate() {
-            for r in result.lanes_mut(Axis_nd(i + offset)).into_iter() {
-                self.transform(transform, r);
-            }
+            Self::transform_axis(transform, &mut result, i + offset);
         }
         result
     }
 
+    /// Inverse Fourier transform of all profiles contained in `f` (the leading
+    /// axes are interpreted as different profiles).
     fn inverse_transform<D2: Dimension>(&self, mut f: Array<Complex<T>, D2>) -> Array<T, D2> {
         let offset = D2::NDIM.unwrap() - D::NDIM.unwrap();
+        let mut points = 1;
         for (i, transform) in self.inverse_transforms.iter().enumerate() {
-            for r in f.lanes_mut(Axis_nd(i + offset)).into_iter() {
-                self.transform(transform, r);
-            }
+            Self::transform_axis(transform, &mut f, i + offset);
+            points *= transform.len();
         }
-        f.mapv(|x| x.re)
+        // normalization of all transforms at once
+        let points = T::from_usize(points).unwrap();
+        f.mapv(|x| x.re / points)
     }
 }
 
@@ -204,65 +236,65 @@ where
         for wf in &self.weight_functions {
             // number of weighted densities
             let n_wd = wf.n_weighted_densities(density.ndim() - 1);
+            let n_local = if wf.local_density { wf.segments } else { 0 };
 
-            // Allocating new array for intended weighted densities
-            let mut dim = vec![n_wd];
+            // Allocating new array for the non-local weighted densities in Fourier space
+            let mut dim = vec![n_wd - n_local];
             density.shape().iter().skip(1).for_each(|&d| dim.push(d));
-            let mut weighted_densities = Array::zeros(dim).into_dimensionality().unwrap();
+            let mut weighted_densities_k: Array<Complex<T>, D::Larger> =
+                Array::zeros(dim).into_dimensionality().unwrap();
 
             // Initilaizing row index for non-local weighted densities
             let mut k = 0;
 
-            // Assigning possible local densities to the front of the array
-            if wf.local_density {
-                weighted_densities
-                    .slice_axis_mut(Axis_nd(0), Slice::from(0..wf.segments))
-                    .assign(density);
-                k += wf.segments;
-            }
-
             // Calculating weighted densities {scalar, component}
             for wf_i in &wf.scalar_component_weighted_densities {
-                weighted_densities
+                weighted_densities_k
                     .slice_axis_mut(Axis_nd(0), Slice::from(k..k + wf.segments))
-                    .assign(&self.inverse_transform(&rho_k * wf_i));
+                    .assign(&(&rho_k * wf_i));
                 k += wf.segments;
             }
 
             // Calculating weighted densities {vector, component}
             for wf_i in &wf.vector_component_weighted_densities {
                 for wf_i in wf_i.outer_iter() {
-                    weighted_densities
+                    weighted_densities_k
                         .slice_axis_mut(Axis_nd(0), Slice::from(k..k + wf.segments))
-                        .assign(
-                            &self.inverse_transform((&rho_k * &wf_i).mapv(|x| x * Complex::i())),
-                        );
+                        .assign(&(&rho_k * &wf_i).mapv(|x| x * Complex::i()));
                     k += wf.segments;
                 }
             }
 
             // Calculating weighted densities {scalar, FMT}
             for wf_i in &wf.scalar_fmt_weighted_densities {
-                weighted_densities
+                weighted_densities_k
                     .index_axis_mut(Axis_nd(0), k)
-                    .assign(&self.inverse_transform((&rho_k * wf_i).sum_axis(Axis_nd(0))));
+                    .assign(&(&rho_k * wf_i).sum_axis(Axis_nd(0)));
                 k += 1;
             }
 
             // Calculating weighted densities {vector, FMT}
             for wf_i in &wf.vector_fmt_weighted_densities {
                 for wf_i in wf_i.outer_iter() {
-                    weighted_densities.index_axis_mut(Axis_nd(0), k).assign(
-                        &self.inverse_transform(
-                            (&rho_k * &wf_i)
-                                .sum_axis(Axis_nd(0))
-                                .mapv(|x| x * Complex::i()),
-                        ),
+                    weighted_densities_k.index_axis_mut(Axis_nd(0), k).assign(
+                        &(&rho_k * &wf_i)
+                            .sum_axis(Axis_nd(0))
+                            .mapv(|x| x * Complex::i()),
                     );
                     k += 1;
                 }
             }
 
+            // Inverse transform of all non-local weighted densities at once
+            let weighted_densities = self.inverse_transform(weighted_densities_k);
+
+            // Assigning possible local densities to the front of the array
+            let weighted_densities = if wf.local_density {
+                concatenate(Axis_nd(0), &[density.view(), weighted_densities.view()]).unwrap()
+            } else {
+                weighted_densities
+            };
+
             // add weighted densities for this contribution to the result
             weighted_densities_vec.push(weighted_densities);
         }
@@ -288,6 +320,17 @@ where
 
         // Iterate over all contributions
         for (pd, wf) in partial_derivatives.iter().zip(&self.weight_functions) {
+            // If local densities are present, their contributions are added directly
+            let n_local = if wf.local_density {
+                functional_deriv_local += &pd.slice_axis(Axis_nd(0), Slice::from(..wf.segments));
+                wf.segments
+            } else {
+                0
+            };
+
+            // Fourier transform of all non-local partial derivatives at once
+            let pd_k = self.forward_transform(pd.slice_axis(Axis_nd(0), Slice::from(n_local..)));
+
             // Multiplication of `partial_derivatives` with the weight functions in
             // Fourier space (convolution in real space); summation leads to
             // functional derivative: the rows in the array are selected from the
@@ -295,16 +338,9 @@ where
             // particular contribution
             let mut k = 0;
 
-            // If local densities are present, their contributions are added directly
-            if wf.local_density {
-                functional_deriv_local += &pd.slice_axis(Axis_nd(0), Slice::from(..wf.segments));
-                k += wf.segments;
-            }
-
             // Convolution of functional derivatives {scalar, component}
             for wf_i in &wf.scalar_component_weighted_densities {
-                let pd_k = self
-                    .forward_transform(pd.slice_axis(Axis_nd(0), Slice::from(k..k + wf.segments)));
+                let pd_k = pd_k.slice_axis(Axis_nd(0), Slice::from(k..k + wf.segments));
                 functional_deriv_k += &(&pd_k * wf_i);
                 k += wf.segments;
             }
@@ -312,26 +348,24 @@ where
             // Convolution of functional derivatives {vector, component}
             for wf_i in &wf.vector_component_weighted_densities {
                 for wf_i in wf_i.outer_iter() {
-                    let pd_k = self.forward_transform(
-                        pd.slice_axis(Axis_nd(0), Slice::from(k..k + wf.segments)),
-                    );
-                    functional_deriv_k -= &(pd_k * &wf_i).mapv(|x| x * Complex::i());
+                    let pd_k = pd_k.slice_axis(Axis_nd(0), Slice::from(k..k + wf.segments));
+                    functional_deriv_k -= &(&pd_k * &wf_i).mapv(|x| x * Complex::i());
                     k += wf.segments;
                 }
             }
 
             // Convolution of functional derivatives {scalar, FMT}
             for wf_i in &wf.scalar_fmt_weighted_densities {
-                let pd_k = self.forward_transform(pd.index_axis(Axis_nd(0), k));
-                functional_deriv_k += &(pd_k * wf_i);
+                let pd_k = pd_k.index_axis(Axis_nd(0), k);
+                functional_deriv_k += &(&pd_k * wf_i);
                 k += 1;
             }
 
             // Convolution of functional derivatives {vector, FMT}
             for wf_i in &wf.vector_fmt_weighted_densities {
                 for wf_i in wf_i.outer_iter() {
-                    let pd_k = self.forward_transform(pd.index_axis(Axis_nd(0), k));
-                    functional_deriv_k -= &(pd_k * wf_i).mapv(|x| x * Complex::i());
+                    let pd_k = pd_k.index_axis(Axis_nd(0), k);
+                    functional_deriv_k -= &(&pd_k * &wf_i).mapv(|x| x * Complex::i());
                     k += 1;
                 }
             }
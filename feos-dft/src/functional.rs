@@ -2,9 +2,11 @@ use crate::convolver::Convolver;
 use crate::functional_contribution::*;
 use crate::ideal_chain_contribution::IdealChainContribution;
 use crate::weight_functions::{WeightFunction, WeightFunctionInfo, WeightFunctionShape};
+use feos_core::ideal_gas::{IdealGasModel, WithIdealGas};
 use feos_core::{
-    Contributions, EosResult, EosUnit, EquationOfState, HelmholtzEnergy, HelmholtzEnergyDual,
-    IdealGasContribution, IdealGasContributionDual, MolarWeight, StateHD,
+    Contributions, DefaultIdealGasContribution, EosResult, EosUnit, EquationOfState,
+    HelmholtzEnergy, HelmholtzEnergyDual, IdealGasContribution, IdealGasContributionDual,
+    MolarWeight, StateHD,
 };
 use ndarray::*;
 use num_dual::*;
@@ -13,8 +15,8 @@ use petgraph::visit::EdgeRef;
 use petgraph::Directed;
 // use quantity::{QuantityArray, SIArray1, SINumber};
 use quantity::si::{SIArray, SIArray1, SINumber, SIUnit};
+use serde::{Deserialize, Serialize};
 use std::borrow::Cow;
-use std::fmt;
 use std::ops::{AddAssign, Deref, MulAssign};
 use std::sync::Arc;
 
@@ -22,7 +24,8 @@ use std::sync::Arc;
 ///
 /// Needed (for now) to generically implement the `EquationOfState`
 /// trait for Helmholtz energy functionals.
-#[derive(Clone)]
+#[derive(Clone, Serialize, Deserialize)]
+#[serde(transparent)]
 pub struct DFT<F>(F);
 
 impl<F> From<F> for DFT<F> {
@@ -35,6 +38,11 @@ impl<F> DFT<F> {
     pub fn into<F2: From<F>>(self) -> DFT<F2> {
         DFT(self.0.into())
     }
+
+    /// Return the wrapped Helmholtz energy functional.
+    pub fn into_inner(self) -> F {
+        self.0
+    }
 }
 
 impl<F> Deref for DFT<F> {
@@ -44,22 +52,15 @@ impl<F> Deref for DFT<F> {
     }
 }
 
-impl<T: MolarWeight> MolarWeight for DFT<T> {
-    fn molar_weight(&self) -> SIArray1 {
-        (self as &T).molar_weight()
+impl<F: WithIdealGas> WithIdealGas for DFT<F> {
+    fn with_ideal_gas(self, ideal_gas: IdealGasModel) -> EosResult<Self> {
+        Ok(Self(self.0.with_ideal_gas(ideal_gas)?))
     }
 }
 
-struct DefaultIdealGasContribution();
-impl<D: DualNum<f64>> IdealGasContributionDual<D> for DefaultIdealGasContribution {
-    fn de_broglie_wavelength(&self, _: D, components: usize) -> Array1<D> {
-        Array1::zeros(components)
-    }
-}
-
-impl fmt::Display for DefaultIdealGasContribution {
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(f, "Ideal gas (default)")
+impl<T: MolarWeight> MolarWeight for DFT<T> {
+    fn molar_weight(&self) -> SIArray1 {
+        (self as &T).molar_weight()
     }
 }
 
@@ -160,7 +161,7 @@ pub trait HelmholtzEnergyFunctional: Sized + Send + Sync {
     /// required (e.g. for the calculation of internal energies) this
     /// function has to be overwritten.
     fn ideal_gas(&self) -> &dyn IdealGasContribution {
-        &DefaultIdealGasContribution()
+        &DefaultIdealGasContribution
     }
 
     /// Overwrite this, if the functional consists of heterosegmented chains.
@@ -271,7 +272,7 @@ impl<T: HelmholtzEnergyFunctional> DFT<T> {
         phi * temperature
     }
 
-    fn intrinsic_helmholtz_energy_density<D, N>(
+    pub(crate) fn intrinsic_helmholtz_energy_density<D, N>(
         &self,
         temperature: N,
         density: &Array<f64, D::Larger>,
@@ -374,6 +375,54 @@ impl<T: HelmholtzEnergyFunctional> DFT<T> {
             .collect())
     }
 
+    /// Calculate the individual contributions to the Helmholtz energy density
+    /// (including the ideal gas contribution) together with their temperature
+    /// derivatives.
+    ///
+    /// Untested with heterosegmented functionals.
+    pub fn helmholtz_energy_density_contributions<D>(
+        &self,
+        temperature: f64,
+        density: &Array<f64, D::Larger>,
+        convolver: &Arc<dyn Convolver<Dual64, D>>,
+    ) -> EosResult<Vec<(String, Array<Dual64, D>)>>
+    where
+        D: Dimension,
+        D::Larger: Dimension<Smaller = D>,
+    {
+        let density_dual = density.mapv(Dual64::from);
+        let temperature_dual = Dual64::from(temperature).derive();
+        let weighted_densities = convolver.weighted_densities(&density_dual);
+        let functional_contributions = self.contributions();
+        let mut helmholtz_energy_density = Vec::with_capacity(functional_contributions.len() + 2);
+        helmholtz_energy_density.push((
+            self.ideal_gas().to_string(),
+            self.ideal_gas_contribution_dual::<D>(temperature_dual, density),
+        ));
+        helmholtz_energy_density.push((
+            self.ideal_chain_contribution().to_string(),
+            self.ideal_chain_contribution()
+                .calculate_helmholtz_energy_density(&density_dual)?
+                * temperature_dual,
+        ));
+
+        for (c, wd) in functional_contributions.iter().zip(weighted_densities) {
+            let nwd = wd.shape()[0];
+            let ngrid = wd.len() / nwd;
+            helmholtz_energy_density.push((
+                c.to_string(),
+                c.calculate_helmholtz_energy_density(
+                    temperature_dual,
+                    wd.into_shape((nwd, ngrid)).unwrap().view(),
+                )?
+                .into_shape(density.raw_dim().remove_axis(Axis(0)))
+                .unwrap()
+                    * temperature_dual,
+            ));
+        }
+        Ok(helmholtz_energy_density)
+    }
+
     /// Calculate the internal energy density $u$.
     ///
     /// Untested with heterosegmented functionals.
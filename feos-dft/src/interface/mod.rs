@@ -4,15 +4,22 @@ use crate::functional::{HelmholtzEnergyFunctional, DFT};
 use crate::geometry::{Axis, Grid};
 use crate::profile::{DFTProfile, DFTSpecifications};
 use crate::solver::DFTSolver;
-use feos_core::{Contributions, EosError, EosResult, EosUnit, PhaseEquilibrium};
+use crate::weight_functions::WeightFunctionInfo;
+use feos_core::{
+    Contributions, EosError, EosResult, EosUnit, IdealGasContributionDual, PhaseEquilibrium,
+};
 use ndarray::{s, Array, Array1, Array2, Axis as Axis_nd, Ix1};
+use num_dual::Dual64;
 use quantity::si::{SIArray1, SIArray2, SINumber, SIUnit};
 
+mod sensitivities;
 mod surface_tension_diagram;
+pub use sensitivities::ParametricFunctional;
 pub use surface_tension_diagram::SurfaceTensionDiagram;
 
 const RELATIVE_WIDTH: f64 = 6.0;
 const MIN_WIDTH: f64 = 100.0;
+const MAX_ENRICHMENT: f64 = 10.0;
 
 /// Density profile and properties of a planar interface.
 pub struct PlanarInterface<F: HelmholtzEnergyFunctional> {
@@ -122,6 +129,50 @@ impl<F: HelmholtzEnergyFunctional> PlanarInterface<F> {
         Ok(profile)
     }
 
+    /// Initialize a planar interface with a hyperbolic tangent that is
+    /// enriched in surface-active components.
+    ///
+    /// The composition at the center of the interface is estimated from
+    /// the activities in the liquid phase, $x_i^\sigma\propto x_i\gamma_i$,
+    /// which improves the convergence for mixtures with strongly
+    /// segregating components (e.g., water + alcohols). For pure components
+    /// the result is identical to [PlanarInterface::from_tanh].
+    pub fn from_tanh_enriched(
+        vle: &PhaseEquilibrium<DFT<F>, 2>,
+        n_grid: usize,
+        l_grid: SINumber,
+        critical_temperature: SINumber,
+        fix_equimolar_surface: bool,
+    ) -> EosResult<Self> {
+        let mut profile = Self::from_tanh(vle, n_grid, l_grid, critical_temperature, false)?;
+
+        // enrichment factors from the activity coefficients in the liquid phase
+        let liquid = vle.liquid();
+        let gamma = liquid.ln_symmetric_activity_coefficient()?.mapv(f64::exp);
+        let enrichment = (&gamma / (&gamma * &liquid.molefracs).sum())
+            .mapv(|e| e.clamp(1.0 / MAX_ENRICHMENT, MAX_ENRICHMENT));
+
+        // add a peak with the shape of the derivative of the hyperbolic tangent
+        let indices = &profile.profile.dft.component_index();
+        let z0 = 0.5 * l_grid.to_reduced(SIUnit::reference_length())?;
+        let reduced_temperature = vle.vapor().temperature.to_reduced(critical_temperature)?;
+        let width = 3.0 / (2.4728 - 2.3625 * reduced_temperature);
+        let z = profile.profile.grid.grids()[0];
+        profile.profile.density =
+            SIArray2::from_shape_fn(profile.profile.density.raw_dim(), |(i, k)| {
+                let peak = ((z[k] - z0) / width).cosh().powi(-2);
+                profile.profile.density.get((i, k)) * (1.0 + (enrichment[indices[i]] - 1.0) * peak)
+            });
+
+        // specify specification
+        if fix_equimolar_surface {
+            profile.profile.specification =
+                DFTSpecifications::total_moles_from_profile(&profile.profile)?;
+        }
+
+        Ok(profile)
+    }
+
     pub fn from_pdgt(
         vle: &PhaseEquilibrium<DFT<F>, 2>,
         n_grid: usize,
@@ -342,6 +393,187 @@ impl<F: HelmholtzEnergyFunctional> PlanarInterface<F> {
         Ok((z_lower - z_upper) * SIUnit::reference_length())
     }
 
+    /// Decomposition of the surface tension into the individual contributions of the functional.
+    ///
+    /// For every contribution $\alpha$ (including the ideal gas), returns the name, the energetic
+    /// part $\gamma_\alpha^U=f_\alpha^\sigma+Ts_\alpha^\sigma$ and the entropic part
+    /// $\gamma_\alpha^S=-Ts_\alpha^\sigma$, where $f_\alpha^\sigma$ and $s_\alpha^\sigma$
+    /// are the surface excess Helmholtz energy and entropy with respect to the equimolar
+    /// dividing surface. The last entry is the chemical potential term $-\sum_i\mu_i\Gamma_i$
+    /// with the adsorptions $\Gamma_i$ at the equimolar dividing surface, which vanishes
+    /// for pure components and is counted as energetic.
+    ///
+    /// At equilibrium, all entries sum up to the surface tension.
+    pub fn surface_tension_contributions(&self) -> EosResult<Vec<(String, SINumber, SINumber)>> {
+        let t = self
+            .profile
+            .temperature
+            .to_reduced(SIUnit::reference_temperature())?;
+        let density = self
+            .profile
+            .density
+            .to_reduced(SIUnit::reference_density())?;
+
+        // surface excess of a reduced energy density w.r.t. the equimolar dividing surface
+        let excess = |x: Array1<f64>| self.equimolar_excess(&(x * SIUnit::reference_pressure()));
+
+        // initialize convolver
+        let weight_functions: Vec<WeightFunctionInfo<Dual64>> = self
+            .profile
+            .dft
+            .contributions()
+            .iter()
+            .map(|c| c.weight_functions(Dual64::from(t).derive()))
+            .collect();
+        let convolver = ConvolverFFT::plan(&self.profile.grid, &weight_functions, None);
+
+        let mut contributions = Vec::new();
+        for (name, f) in self
+            .profile
+            .dft
+            .helmholtz_energy_density_contributions(t, &density, &convolver)?
+        {
+            let energy = excess(f.mapv(|f| f.re - t * f.eps[0]));
+            let entropy = excess(f.mapv(|f| t * f.eps[0]));
+            contributions.push((name, energy, entropy));
+        }
+
+        // chemical potential term
+        let mu = self
+            .vle
+            .vapor()
+            .chemical_potential(Contributions::Total)
+            .to_reduced(SIUnit::reference_molar_energy())?;
+        let mut mu_rho = Array1::zeros(density.shape()[1]);
+        for (rho_i, &mu_i) in density.outer_iter().zip(mu.iter()) {
+            mu_rho += &(&rho_i * mu_i);
+        }
+        contributions.push((
+            String::from("Chemical potential"),
+            -excess(mu_rho),
+            0.0 * SIUnit::reference_surface_tension(),
+        ));
+        Ok(contributions)
+    }
+
+    /// Decomposition of the surface tension into an energetic part $\gamma^U$ and
+    /// an entropic part $\gamma^S=-Ts^\sigma$, with $\gamma=\gamma^U+\gamma^S$.
+    ///
+    /// The surface excess entropy $s^\sigma$ is calculated with respect to the
+    /// equimolar dividing surface.
+    pub fn surface_tension_energy_entropy(&self) -> EosResult<(SINumber, SINumber)> {
+        let zero = 0.0 * SIUnit::reference_surface_tension();
+        Ok(self
+            .surface_tension_contributions()?
+            .into_iter()
+            .fold((zero, zero), |(u, s), (_, du, ds)| (u + du, s + ds)))
+    }
+
+    /// Surface excess of a density profile with respect to the equimolar dividing surface.
+    fn equimolar_excess(&self, x: &SIArray1) -> SINumber {
+        let rho = self.profile.density.sum_axis(Axis_nd(0));
+        let n_grid = rho.shape()[0];
+        let ze = self.profile.integrate(&(&rho - rho.get(n_grid - 1)))
+            / (rho.get(0) - rho.get(n_grid - 1));
+        let (x_l, x_v) = (x.get(0), x.get(n_grid - 1));
+        self.profile.integrate(&(x - x_v)) - (x_l - x_v) * ze
+    }
+
+    /// Local entropy density $s(z)$ across the interface.
+    pub fn entropy_density(&self) -> EosResult<SIArray1> {
+        self.profile.entropy_density(Contributions::Total)
+    }
+
+    /// Local enthalpy density $h(z)=Ts(z)+\sum_i\mu_i\rho_i(z)$ across the interface.
+    ///
+    /// In the bulk phases, $h(z)$ reduces to the enthalpy density $u+p$.
+    pub fn enthalpy_density(&self) -> EosResult<SIArray1> {
+        let mu = self.vle.vapor().chemical_potential(Contributions::Total);
+        let mut h = self.entropy_density()? * self.profile.temperature;
+        for i in 0..self.profile.density.shape()[0] {
+            h = h + self.profile.density.index_axis(Axis_nd(0), i) * mu.get(i);
+        }
+        Ok(h)
+    }
+
+    /// Local partial molar entropies $s_i(z)=\frac{\delta S}{\delta\rho_i(z)}=-\left(\frac{\partial}{\partial T}\frac{\delta F}{\delta\rho_i(z)}\right)_{\rho}$
+    /// across the interface.
+    ///
+    /// In the bulk phases, $s_i$ reduces to $\bar{s}_i-\bar{v}_i\left(\frac{\partial p}{\partial T}\right)_{V,N_k}$.
+    ///
+    /// Not compatible with heterosegmented DFT.
+    pub fn partial_molar_entropy(&self) -> EosResult<SIArray2> {
+        let density = self
+            .profile
+            .density
+            .to_reduced(SIUnit::reference_density())?;
+        let t = self
+            .profile
+            .temperature
+            .to_reduced(SIUnit::reference_temperature())?;
+        let dfdrho = self.functional_derivative_dual(t, &density)?;
+        Ok(dfdrho.mapv(|d| -d.eps[0]) * SIUnit::reference_molar_entropy())
+    }
+
+    /// Local partial molar enthalpies $h_i(z)=\frac{\delta H}{\delta\rho_i(z)}=\mu_i+Ts_i(z)$
+    /// across the interface.
+    ///
+    /// Not compatible with heterosegmented DFT.
+    pub fn partial_molar_enthalpy(&self) -> EosResult<SIArray2> {
+        let density = self
+            .profile
+            .density
+            .to_reduced(SIUnit::reference_density())?;
+        let t = self
+            .profile
+            .temperature
+            .to_reduced(SIUnit::reference_temperature())?;
+        let dfdrho = self.functional_derivative_dual(t, &density)?;
+        Ok(dfdrho.mapv(|d| d.re - t * d.eps[0]) * SIUnit::reference_molar_energy())
+    }
+
+    /// Full functional derivative $\frac{\delta F}{\delta\rho_i(z)}$ including the ideal gas
+    /// and the ideal chain contribution, differentiated with respect to the temperature.
+    fn functional_derivative_dual(
+        &self,
+        t: f64,
+        density: &Array2<f64>,
+    ) -> EosResult<Array2<Dual64>> {
+        let dft = &self.profile.dft;
+        let weight_functions: Vec<WeightFunctionInfo<Dual64>> = dft
+            .contributions()
+            .iter()
+            .map(|c| c.weight_functions(Dual64::from(t).derive()))
+            .collect();
+        let convolver = ConvolverFFT::plan(&self.profile.grid, &weight_functions, None);
+        let (_, mut dfdrho) = dft.functional_derivative_dual(t, density, &convolver)?;
+
+        let t_dual = Dual64::from(t).derive();
+        let lambda = dft
+            .ideal_gas()
+            .de_broglie_wavelength(t_dual, density.shape()[0]);
+        dfdrho
+            .outer_iter_mut()
+            .zip(density.outer_iter())
+            .zip(lambda.into_iter())
+            .zip(dft.m().iter())
+            .for_each(|(((mut df, rho), l), &m)| {
+                df += &rho.mapv(|r| (l + r.ln() * m) * t_dual);
+            });
+        Ok(dfdrho)
+    }
+
+    /// Surface excess entropy $s^\sigma$ with respect to the equimolar dividing surface.
+    pub fn interfacial_excess_entropy(&self) -> EosResult<SINumber> {
+        Ok(self.equimolar_excess(&self.entropy_density()?))
+    }
+
+    /// Surface excess enthalpy $h^\sigma=Ts^\sigma+\sum_i\mu_i\Gamma_i$ with respect to the
+    /// equimolar dividing surface.
+    pub fn interfacial_excess_enthalpy(&self) -> EosResult<SINumber> {
+        Ok(self.equimolar_excess(&self.enthalpy_density()?))
+    }
+
     fn set_density_scale(&mut self, init: &SIArray2) {
         assert_eq!(self.profile.density.shape(), init.shape());
         let n_grid = self.profile.density.shape()[1];
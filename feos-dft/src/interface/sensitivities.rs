@@ -0,0 +1,86 @@
+use super::PlanarInterface;
+use crate::convolver::ConvolverFFT;
+use crate::functional::{HelmholtzEnergyFunctional, DFT};
+use feos_core::{EosError, EosResult, EosUnit, EquationOfState};
+use ndarray::{Array1, Ix1};
+use quantity::si::{SIArray1, SIUnit};
+
+/// Step size of the central differences relative to the magnitude of a model parameter.
+const RELATIVE_STEP: f64 = 1e-5;
+
+/// Helmholtz energy functional that can be rebuilt with different values
+/// of its model parameters.
+///
+/// Required to calculate derivatives of interfacial properties with respect
+/// to the model parameters, e.g., in
+/// [PlanarInterface::surface_tension_sensitivities].
+pub trait ParametricFunctional: HelmholtzEnergyFunctional + Sized {
+    /// The names of the model parameters, e.g., `sigma_0` or `k_01`.
+    fn parameter_names(&self) -> Vec<String>;
+
+    /// The values of the model parameters.
+    fn parameter_values(&self) -> Array1<f64>;
+
+    /// The functional with the model parameters replaced by the given values.
+    fn with_parameter_values(&self, parameters: &[f64]) -> EosResult<DFT<Self>>;
+}
+
+impl<F: ParametricFunctional> PlanarInterface<F> {
+    /// Derivatives of the surface tension of a pure component w.r.t. the
+    /// model parameters at constant temperature.
+    ///
+    /// Because the grand potential is stationary w.r.t. the density profile
+    /// and the bulk phases are at coexistence, the derivatives are the surface
+    /// excesses
+    /// $$\frac{\mathrm{d}\gamma}{\mathrm{d}\theta}=\int\left(f_\theta(z)-f_\theta^\mathrm{V}\right)\mathrm{d}z-\left(f_\theta^\mathrm{L}-f_\theta^\mathrm{V}\right)z_\mathrm{e}$$
+    /// of the derivatives $f_\theta$ of the Helmholtz energy density at constant
+    /// density profile with respect to the equimolar dividing surface $z_\mathrm{e}$.
+    /// The density profile is not solved again. The derivatives $f_\theta$ are
+    /// approximated with central differences of the functional rebuilt with
+    /// perturbed parameters, because weight functions are not available for
+    /// parameters given as dual numbers.
+    ///
+    /// The results are in the order of
+    /// [parameter_names](ParametricFunctional::parameter_names). For mixtures,
+    /// the changes of the chemical potentials at coexistence would be required
+    /// in addition and an error is returned.
+    pub fn surface_tension_sensitivities(&self) -> EosResult<SIArray1> {
+        let n = self.profile.dft.components();
+        if n != 1 {
+            return Err(EosError::IncompatibleComponents(1, n));
+        }
+        let t = self
+            .profile
+            .temperature
+            .to_reduced(SIUnit::reference_temperature())?;
+        let density = self
+            .profile
+            .density
+            .to_reduced(SIUnit::reference_density())?;
+
+        // Helmholtz energy density of the fixed density profile
+        let helmholtz_energy_density = |parameters: &[f64]| -> EosResult<Array1<f64>> {
+            let dft = self.profile.dft.with_parameter_values(parameters)?;
+            let convolver =
+                ConvolverFFT::<_, Ix1>::plan(&self.profile.grid, &dft.weight_functions(t), None);
+            Ok(dft.ideal_gas_contribution::<Ix1>(t, &density)
+                + dft.intrinsic_helmholtz_energy_density(t, &density, &convolver)?)
+        };
+
+        let parameters = self.profile.dft.parameter_values();
+        let mut sensitivities = Array1::zeros(parameters.len());
+        for (k, &theta) in parameters.iter().enumerate() {
+            let h = RELATIVE_STEP * theta.abs().max(1.0);
+            let mut p = parameters.to_vec();
+            p[k] = theta + h;
+            let f_plus = helmholtz_energy_density(&p)?;
+            p[k] = theta - h;
+            let f_minus = helmholtz_energy_density(&p)?;
+            let f_theta = (f_plus - f_minus) / (2.0 * h) * SIUnit::reference_pressure();
+            sensitivities[k] = self
+                .equimolar_excess(&f_theta)
+                .to_reduced(SIUnit::reference_surface_tension())?;
+        }
+        Ok(sensitivities * SIUnit::reference_surface_tension())
+    }
+}
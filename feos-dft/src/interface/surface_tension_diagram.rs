@@ -1,9 +1,14 @@
 use super::PlanarInterface;
 use crate::functional::{HelmholtzEnergyFunctional, DFT};
 use crate::solver::DFTSolver;
-use feos_core::{EosUnit, PhaseEquilibrium, StateVec};
+use feos_core::{
+    EosError, EosResult, EosUnit, EquationOfState, PhaseDiagram, PhaseEquilibrium, StateVec,
+};
 use ndarray::Array1;
 use quantity::si::{SIArray1, SIArray2, SINumber, SIUnit};
+#[cfg(feature = "rayon")]
+use rayon::{prelude::*, ThreadPool};
+use std::sync::Arc;
 
 const DEFAULT_GRID_POINTS: usize = 2048;
 
@@ -23,6 +28,62 @@ impl<F: HelmholtzEnergyFunctional> SurfaceTensionDiagram<F> {
         fix_equimolar_surface: Option<bool>,
         solver: Option<&DFTSolver>,
     ) -> Self {
+        let profiles = Self::solve_chunk(
+            dia,
+            init_densities,
+            n_grid,
+            l_grid,
+            critical_temperature,
+            fix_equimolar_surface,
+            solver,
+        );
+        Self { profiles }
+    }
+
+    /// Calculate the interfacial profiles for all phase equilibria in parallel.
+    ///
+    /// The phase equilibria are split into chunks of `chunksize` points
+    /// that are calculated in sequence within a thread, so that the
+    /// density profiles can be initialized with the previous result.
+    #[cfg(feature = "rayon")]
+    pub fn par_new(
+        dia: &Vec<PhaseEquilibrium<DFT<F>, 2>>,
+        chunksize: usize,
+        thread_pool: ThreadPool,
+        init_densities: Option<bool>,
+        n_grid: Option<usize>,
+        l_grid: Option<SINumber>,
+        critical_temperature: Option<SINumber>,
+        fix_equimolar_surface: Option<bool>,
+        solver: Option<&DFTSolver>,
+    ) -> Self {
+        let profiles = thread_pool.install(|| {
+            dia.par_chunks(chunksize.max(1))
+                .flat_map_iter(|dia| {
+                    Self::solve_chunk(
+                        dia,
+                        init_densities,
+                        n_grid,
+                        l_grid,
+                        critical_temperature,
+                        fix_equimolar_surface,
+                        solver,
+                    )
+                })
+                .collect()
+        });
+        Self { profiles }
+    }
+
+    fn solve_chunk(
+        dia: &[PhaseEquilibrium<DFT<F>, 2>],
+        init_densities: Option<bool>,
+        n_grid: Option<usize>,
+        l_grid: Option<SINumber>,
+        critical_temperature: Option<SINumber>,
+        fix_equimolar_surface: Option<bool>,
+        solver: Option<&DFTSolver>,
+    ) -> Vec<PlanarInterface<F>> {
         let n_grid = n_grid.unwrap_or(DEFAULT_GRID_POINTS);
         let mut profiles: Vec<PlanarInterface<F>> = Vec::with_capacity(dia.len());
         for vle in dia.iter() {
@@ -64,7 +125,51 @@ impl<F: HelmholtzEnergyFunctional> SurfaceTensionDiagram<F> {
                 profiles.push(profile);
             }
         }
-        Self { profiles }
+        profiles
+    }
+
+    /// Surface tension diagram of a binary mixture along an isotherm.
+    ///
+    /// The phase equilibria are calculated over the entire composition range
+    /// and the interfacial profiles are solved independently of each other,
+    /// in parallel if the `rayon` feature is enabled. State points for which
+    /// the DFT calculation fails are omitted from the diagram.
+    pub fn binary_isotherm(
+        functional: &Arc<DFT<F>>,
+        temperature: SINumber,
+        npoints: Option<usize>,
+        n_grid: Option<usize>,
+        l_grid: Option<SINumber>,
+        critical_temperature: Option<SINumber>,
+        fix_equimolar_surface: Option<bool>,
+        solver: Option<&DFTSolver>,
+    ) -> EosResult<Self> {
+        if functional.components() != 2 {
+            return Err(EosError::Error(String::from(
+                "Binary surface tension diagrams require a functional with two components.",
+            )));
+        }
+        let dia =
+            PhaseDiagram::binary_vle(functional, temperature, npoints, None, Default::default())?
+                .states;
+
+        let n_grid = n_grid.unwrap_or(DEFAULT_GRID_POINTS);
+        let solve = |vle: &PhaseEquilibrium<DFT<F>, 2>| {
+            PlanarInterface::from_tanh(
+                vle,
+                n_grid,
+                l_grid.unwrap_or(100.0 * SIUnit::reference_length()),
+                critical_temperature.unwrap_or(500.0 * SIUnit::reference_temperature()),
+                fix_equimolar_surface.unwrap_or(false),
+            )
+            .and_then(|profile| profile.solve(solver))
+            .ok()
+        };
+        #[cfg(feature = "rayon")]
+        let profiles = dia.par_iter().filter_map(solve).collect();
+        #[cfg(not(feature = "rayon"))]
+        let profiles = dia.iter().filter_map(solve).collect();
+        Ok(Self { profiles })
     }
 
     pub fn vapor(&self) -> StateVec<'_, DFT<F>> {
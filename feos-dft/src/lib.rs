@@ -4,6 +4,7 @@
 #![allow(clippy::new_ret_no_self)]
 
 pub mod adsorption;
+mod checkpoint;
 mod convolver;
 mod functional;
 mod functional_contribution;
@@ -12,10 +13,12 @@ mod ideal_chain_contribution;
 pub mod interface;
 mod pdgt;
 mod profile;
+pub mod simulation;
 pub mod solvation;
 mod solver;
 mod weight_functions;
 
+pub use checkpoint::{AndersonHistory, DFTCheckpoint};
 pub use convolver::{Convolver, ConvolverFFT};
 pub use functional::{HelmholtzEnergyFunctional, MoleculeShape, DFT};
 pub use functional_contribution::{FunctionalContribution, FunctionalContributionDual};
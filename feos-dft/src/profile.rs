@@ -1,3 +1,4 @@
+use crate::checkpoint::AndersonHistory;
 use crate::convolver::{BulkConvolver, Convolver, ConvolverFFT};
 use crate::functional::{HelmholtzEnergyFunctional, DFT};
 use crate::geometry::Grid;
@@ -5,7 +6,7 @@ use crate::solver::{DFTSolver, DFTSolverLog};
 use crate::weight_functions::WeightFunctionInfo;
 use feos_core::{Contributions, EosError, EosResult, EosUnit, EquationOfState, State, Verbosity};
 use ndarray::{
-    Array, Array1, ArrayBase, Axis as Axis_nd, Data, Dimension, Ix1, Ix2, Ix3, RemoveAxis,
+    Array, Array1, Array2, ArrayBase, Axis as Axis_nd, Data, Dimension, Ix1, Ix2, Ix3, RemoveAxis,
 };
 use num_dual::Dual64;
 use quantity::si::{SIArray, SIArray1, SIArray2, SINumber, SIUnit};
@@ -106,6 +107,8 @@ pub struct DFTProfile<D: Dimension, F> {
     pub external_potential: Array<f64, D::Larger>,
     pub bulk: State<DFT<F>>,
     pub solver_log: Option<DFTSolverLog>,
+    /// Anderson history loaded from a checkpoint that is used by the next solver run
+    pub(crate) anderson_history: Option<AndersonHistory>,
 }
 
 impl<F> DFTProfile<Ix1, F> {
@@ -133,6 +136,14 @@ impl<F> DFTProfile<Ix2, F> {
     pub fn z(&self) -> SIArray1 {
         self.grid.grids()[1] * SIUnit::reference_length()
     }
+
+    pub fn x(&self) -> SIArray1 {
+        self.grid.grids()[0] * SIUnit::reference_length()
+    }
+
+    pub fn y(&self) -> SIArray1 {
+        self.grid.grids()[1] * SIUnit::reference_length()
+    }
 }
 
 impl<F> DFTProfile<Ix3, F> {
@@ -217,6 +228,7 @@ where
             external_potential,
             bulk: bulk.clone(),
             solver_log: None,
+            anderson_history: None,
         })
     }
 
@@ -309,6 +321,7 @@ impl<D: Dimension, F> Clone for DFTProfile<D, F> {
             external_potential: self.external_potential.clone(),
             bulk: self.bulk.clone(),
             solver_log: self.solver_log.clone(),
+            anderson_history: self.anderson_history.clone(),
         }
     }
 }
@@ -553,7 +566,7 @@ where
             let delta_i = self.delta_bond_integrals(&exp_dfdrho, &delta_functional_derivative);
             xm + (delta_functional_derivative - delta_i) * &rho
         };
-        let mut log = DFTSolverLog::new(Verbosity::None);
+        let mut log = DFTSolverLog::new(Verbosity::None, None);
         Self::gmres(rhs, lhs, 200, 1e-13, &mut log)
     }
 
@@ -660,4 +673,54 @@ where
     pub fn dn_dt(&self) -> EosResult<SIArray1> {
         Ok(self.integrate_segments(&self.drho_dt()?))
     }
+
+    /// Return the partial derivatives of the density profiles w.r.t. the bulk composition at constant temperature and pressure $\left(\frac{\partial\rho_i(\mathbf{r})}{\partial x_j}\right)_{T,p}$
+    ///
+    /// The derivatives are evaluated as $N\left(\frac{\partial\rho_i(\mathbf{r})}{\partial N_j}\right)_{T,p}$
+    /// in the bulk phase, so that $\mathrm{d}\rho_i(\mathbf{r})=\sum_j\frac{\partial\rho_i(\mathbf{r})}{\partial x_j}\mathrm{d}x_j$
+    /// for every change in composition with $\sum_j\mathrm{d}x_j=0$. The first axis of the result
+    /// corresponds to $x_j$.
+    ///
+    /// Not compatible with heterosegmented DFT.
+    pub fn drho_dx(&self) -> EosResult<SIArray<<D::Larger as Dimension>::Larger>> {
+        let drho_dmu = self
+            .drho_dmu()?
+            .to_reduced(SIUnit::reference_density() / SIUnit::reference_molar_energy())?;
+
+        // derivatives of the bulk chemical potentials at constant temperature and pressure
+        let n = self.dft.components();
+        if drho_dmu.shape()[0] != n {
+            return Err(EosError::Error(String::from(
+                "Derivatives w.r.t. the composition are not available for heterosegmented DFT.",
+            )));
+        }
+        let dmu_dn = self.bulk.dmu_dni(Contributions::Total);
+        let dp_dn = self.bulk.dp_dni(Contributions::Total);
+        let dp_dv = self.bulk.dp_dv(Contributions::Total);
+        let dmu_dx = Array2::from_shape_fn((n, n), |(k, j)| {
+            ((dmu_dn.get((k, j)) + dp_dn.get(k) * dp_dn.get(j) / dp_dv) * self.bulk.total_moles)
+                .to_reduced(SIUnit::reference_molar_energy())
+                .unwrap()
+        });
+
+        let mut drho_dx = Array::zeros(drho_dmu.raw_dim());
+        for (j, mut d) in drho_dx.outer_iter_mut().enumerate() {
+            for (k, drho_dmu) in drho_dmu.outer_iter().enumerate() {
+                d.scaled_add(dmu_dx[[k, j]], &drho_dmu);
+            }
+        }
+        Ok(drho_dx * SIUnit::reference_density())
+    }
+
+    /// Return the partial derivatives of the number of moles w.r.t. the bulk composition at constant temperature and pressure $\left(\frac{\partial N_i}{\partial x_j}\right)_{T,p}$
+    ///
+    /// Not compatible with heterosegmented DFT.
+    pub fn dn_dx(&self) -> EosResult<SIArray2> {
+        let drho_dx = self.drho_dx()?;
+        let n = drho_dx.shape()[0];
+        let dn_dx = SIArray2::from_shape_fn([n; 2], |(i, j)| {
+            self.integrate(&drho_dx.index_axis(Axis_nd(0), j).index_axis(Axis_nd(0), i))
+        });
+        Ok(dn_dx)
+    }
 }
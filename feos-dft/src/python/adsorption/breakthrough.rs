@@ -0,0 +1,169 @@
+use super::PyIdealAdsorbedSolution;
+use crate::adsorption::{BreakthroughCurve, FixedBed};
+use feos_core::Verbosity;
+use numpy::{PyArray1, PyArray2, ToPyArray};
+use pyo3::prelude::*;
+use quantity::python::{PySIArray1, PySIArray2, PySINumber};
+
+/// One-dimensional fixed-bed adsorption column with axial
+/// dispersion and linear driving force mass transfer.
+///
+/// Parameters
+/// ----------
+/// isotherm : IdealAdsorbedSolution
+///     The equilibrium model of the adsorbent.
+/// temperature : SINumber
+///     The temperature of the column.
+/// length : SINumber
+///     The length of the column.
+/// porosity : float
+///     The void fraction of the bed.
+/// bed_density : SINumber
+///     The amount of adsorbent per volume of the column.
+/// velocity : SINumber
+///     The interstitial velocity of the gas.
+/// axial_dispersion : SINumber
+///     The axial dispersion coefficient.
+/// mass_transfer_coefficients : SIArray1
+///     The LDF mass transfer coefficients of all components.
+///
+/// Returns
+/// -------
+/// FixedBed
+///
+#[pyclass(name = "FixedBed")]
+#[derive(Clone)]
+#[pyo3(
+    text_signature = "(isotherm, temperature, length, porosity, bed_density, velocity, axial_dispersion, mass_transfer_coefficients)"
+)]
+pub struct PyFixedBed(pub FixedBed);
+
+#[pymethods]
+impl PyFixedBed {
+    #[new]
+    fn new(
+        isotherm: PyIdealAdsorbedSolution,
+        temperature: PySINumber,
+        length: PySINumber,
+        porosity: f64,
+        bed_density: PySINumber,
+        velocity: PySINumber,
+        axial_dispersion: PySINumber,
+        mass_transfer_coefficients: PySIArray1,
+    ) -> PyResult<Self> {
+        Ok(Self(FixedBed::new(
+            isotherm.0,
+            temperature.into(),
+            length.into(),
+            porosity,
+            bed_density.into(),
+            velocity.into(),
+            axial_dispersion.into(),
+            mass_transfer_coefficients.into(),
+        )?))
+    }
+
+    /// Simulate the loading of an initially clean column.
+    ///
+    /// Parameters
+    /// ----------
+    /// pressure : SINumber
+    ///     The total pressure of the feed.
+    /// molefracs : numpy.ndarray[float]
+    ///     The mole fractions of the adsorbing components in the feed.
+    ///     The remainder is an inert carrier gas.
+    /// duration : SINumber
+    ///     The simulated time.
+    /// n_grid : int
+    ///     The number of grid points of the column.
+    /// n_time : int
+    ///     The number of times at which the outlet concentrations are stored.
+    /// max_iter : int, optional
+    ///     The maximum number of iterations of the IAST calculations.
+    /// tol: float, optional
+    ///     The solution tolerance of the IAST calculations.
+    /// verbosity : Verbosity, optional
+    ///     The verbosity of the IAST calculations.
+    ///
+    /// Returns
+    /// -------
+    /// BreakthroughCurve
+    ///
+    #[pyo3(
+        text_signature = "($self, pressure, molefracs, duration, n_grid, n_time, max_iter=None, tol=None, verbosity=None)"
+    )]
+    fn breakthrough_curve(
+        &self,
+        pressure: PySINumber,
+        molefracs: &PyArray1<f64>,
+        duration: PySINumber,
+        n_grid: usize,
+        n_time: usize,
+        max_iter: Option<usize>,
+        tol: Option<f64>,
+        verbosity: Option<Verbosity>,
+    ) -> PyResult<PyBreakthroughCurve> {
+        Ok(PyBreakthroughCurve(self.0.breakthrough_curve(
+            pressure.into(),
+            &molefracs.to_owned_array(),
+            duration.into(),
+            n_grid,
+            n_time,
+            (max_iter, tol, verbosity).into(),
+        )?))
+    }
+}
+
+/// Outlet concentrations of a fixed-bed adsorption column over time.
+#[pyclass(name = "BreakthroughCurve")]
+pub struct PyBreakthroughCurve(pub BreakthroughCurve);
+
+#[pymethods]
+impl PyBreakthroughCurve {
+    #[getter]
+    fn get_time(&self) -> PySIArray1 {
+        self.0.time.clone().into()
+    }
+
+    #[getter]
+    fn get_outlet_concentration(&self) -> PySIArray2 {
+        self.0.outlet_concentration.clone().into()
+    }
+
+    #[getter]
+    fn get_feed_concentration(&self) -> PySIArray1 {
+        self.0.feed_concentration.clone().into()
+    }
+
+    #[getter]
+    fn get_relative_concentration<'py>(&self, py: Python<'py>) -> PyResult<&'py PyArray2<f64>> {
+        Ok(self.0.relative_concentration()?.to_pyarray(py))
+    }
+
+    /// Return the time at which the relative outlet concentration
+    /// of a component first exceeds the given threshold.
+    ///
+    /// Parameters
+    /// ----------
+    /// component : int
+    ///     The index of the component.
+    /// threshold : float, optional
+    ///     The relative concentration that defines the breakthrough.
+    ///     Defaults to 0.05.
+    ///
+    /// Returns
+    /// -------
+    /// SINumber, optional
+    ///
+    #[pyo3(text_signature = "($self, component, threshold=0.05)")]
+    fn breakthrough_time(
+        &self,
+        component: usize,
+        threshold: Option<f64>,
+    ) -> PyResult<Option<PySINumber>> {
+        Ok(self
+            .0
+            .breakthrough_time(component, threshold.unwrap_or(0.05))?
+            .map(PySINumber::from))
+    }
+}
@@ -0,0 +1,70 @@
+use crate::adsorption::WorkingCapacity;
+use numpy::{PyArray1, ToPyArray};
+use pyo3::prelude::*;
+use quantity::python::{PySIArray1, PySINumber};
+
+/// Working capacity of a pressure swing between two pressures of an isotherm.
+#[pyclass(name = "WorkingCapacity")]
+#[derive(Clone)]
+pub struct PyWorkingCapacity(pub WorkingCapacity);
+
+#[pymethods]
+impl PyWorkingCapacity {
+    #[getter]
+    fn get_adsorption_pressure(&self) -> PySINumber {
+        self.0.adsorption_pressure.into()
+    }
+
+    #[getter]
+    fn get_desorption_pressure(&self) -> PySINumber {
+        self.0.desorption_pressure.into()
+    }
+
+    /// The adsorbed amounts at the adsorption pressure.
+    #[getter]
+    fn get_adsorption(&self) -> PySIArray1 {
+        self.0.adsorption.clone().into()
+    }
+
+    /// The adsorbed amounts at the desorption pressure.
+    #[getter]
+    fn get_desorption(&self) -> PySIArray1 {
+        self.0.desorption.clone().into()
+    }
+
+    /// The composition of the bulk phase at the adsorption pressure.
+    #[getter]
+    fn get_molefracs<'py>(&self, py: Python<'py>) -> &'py PyArray1<f64> {
+        self.0.molefracs.to_pyarray(py)
+    }
+
+    /// The working capacity of each component.
+    #[getter]
+    fn get_working_capacity(&self) -> PySIArray1 {
+        self.0.working_capacity().into()
+    }
+
+    /// The total working capacity.
+    #[getter]
+    fn get_total_working_capacity(&self) -> PySINumber {
+        self.0.total_working_capacity().into()
+    }
+
+    /// The selectivity of the working capacity of component `i`
+    /// over component `j`.
+    ///
+    /// Parameters
+    /// ----------
+    /// i : int
+    ///     The index of the first component.
+    /// j : int
+    ///     The index of the second component.
+    ///
+    /// Returns
+    /// -------
+    /// float
+    #[pyo3(text_signature = "($self, i, j)")]
+    fn selectivity(&self, i: usize, j: usize) -> PyResult<f64> {
+        Ok(self.0.selectivity(i, j)?)
+    }
+}
@@ -1,5 +1,6 @@
 use crate::adsorption::ExternalPotential;
-use numpy::PyArray1;
+use numpy::{PyArray1, PyArray2};
+use pyo3::exceptions::PyValueError;
 use pyo3::prelude::*;
 use quantity::python::{PySIArray2, PySINumber};
 
@@ -247,4 +248,39 @@ impl PyExternalPotential {
             cutoff_radius,
         })
     }
+
+    /// Tabulated potential that is linearly interpolated.
+    ///
+    /// In all geometries, the distance is measured from the wall of
+    /// the pore. In Cartesian pores, the contributions of both walls
+    /// are added. Outside of the tabulated range, the potential is
+    /// kept constant.
+    ///
+    /// Parameters
+    /// ----------
+    /// distance : numpy.ndarray[float]
+    ///     The distances (in units of Angstrom) in ascending order.
+    /// potential : numpy.ndarray[float]
+    ///     The potential (in units of Kelvin) with one row for
+    ///     every segment and one column for every distance.
+    ///
+    /// Returns
+    /// -------
+    /// ExternalPotential
+    ///
+    #[staticmethod]
+    #[pyo3(text_signature = "(distance, potential)")]
+    pub fn Tabulated(distance: &PyArray1<f64>, potential: &PyArray2<f64>) -> PyResult<Self> {
+        let distance = distance.to_owned_array();
+        let potential = potential.to_owned_array();
+        if potential.ncols() != distance.len() || distance.len() < 2 {
+            return Err(PyErr::new::<PyValueError, _>(
+                "The potential has to be given for at least two distances.",
+            ));
+        }
+        Ok(Self(ExternalPotential::Tabulated {
+            distance,
+            potential,
+        }))
+    }
 }
@@ -0,0 +1,202 @@
+use crate::adsorption::{
+    IdealAdsorbedSolution, LangmuirIsotherm, PureComponentIsotherm, TabulatedIsotherm,
+};
+use feos_core::Verbosity;
+use numpy::PyArray1;
+use pyo3::prelude::*;
+use quantity::python::{PySIArray1, PySIArray2, PySINumber};
+use std::sync::Arc;
+
+/// A pure component adsorption isotherm.
+#[pyclass(name = "PureComponentIsotherm")]
+#[derive(Clone)]
+pub struct PyPureComponentIsotherm(pub Arc<dyn PureComponentIsotherm>);
+
+#[pymethods]
+impl PyPureComponentIsotherm {
+    /// Langmuir isotherm
+    ///
+    /// .. math:: n(p)=n_\mathrm{s}\frac{bp}{1+bp}
+    ///
+    /// Parameters
+    /// ----------
+    /// saturation_capacity : SINumber
+    ///     The saturation capacity.
+    /// affinity : SINumber
+    ///     The affinity constant.
+    ///
+    /// Returns
+    /// -------
+    /// PureComponentIsotherm
+    ///
+    #[staticmethod]
+    #[pyo3(text_signature = "(saturation_capacity, affinity)")]
+    fn langmuir(saturation_capacity: PySINumber, affinity: PySINumber) -> Self {
+        Self(Arc::new(LangmuirIsotherm::new(
+            saturation_capacity.into(),
+            affinity.into(),
+        )))
+    }
+
+    /// Isotherm that is linearly interpolated between tabulated values.
+    ///
+    /// Parameters
+    /// ----------
+    /// pressure : SIArray1
+    ///     The pressures in ascending order.
+    /// adsorption : SIArray1
+    ///     The adsorbed amounts at the given pressures.
+    ///
+    /// Returns
+    /// -------
+    /// PureComponentIsotherm
+    ///
+    #[staticmethod]
+    #[pyo3(text_signature = "(pressure, adsorption)")]
+    fn tabulated(pressure: PySIArray1, adsorption: PySIArray1) -> PyResult<Self> {
+        Ok(Self(Arc::new(TabulatedIsotherm::new(
+            pressure.into(),
+            adsorption.into(),
+        )?)))
+    }
+
+    /// Calculate the adsorbed amount at the given pressure.
+    ///
+    /// Parameters
+    /// ----------
+    /// pressure : SINumber
+    ///     The pressure.
+    ///
+    /// Returns
+    /// -------
+    /// SINumber
+    ///
+    #[pyo3(text_signature = "($self, pressure)")]
+    fn adsorption(&self, pressure: PySINumber) -> PyResult<PySINumber> {
+        Ok(self.0.adsorption(pressure.into())?.into())
+    }
+
+    /// Calculate the reduced spreading pressure at the given pressure.
+    ///
+    /// Parameters
+    /// ----------
+    /// pressure : SINumber
+    ///     The pressure.
+    ///
+    /// Returns
+    /// -------
+    /// SINumber
+    ///
+    #[pyo3(text_signature = "($self, pressure)")]
+    fn reduced_spreading_pressure(&self, pressure: PySINumber) -> PyResult<PySINumber> {
+        Ok(self.0.reduced_spreading_pressure(pressure.into())?.into())
+    }
+}
+
+/// Ideal adsorbed solution theory (IAST) for the prediction of
+/// mixture adsorption from pure component isotherms.
+///
+/// Parameters
+/// ----------
+/// isotherms : [PureComponentIsotherm]
+///     The isotherms of all pure components.
+///
+/// Returns
+/// -------
+/// IdealAdsorbedSolution
+///
+#[pyclass(name = "IdealAdsorbedSolution")]
+#[derive(Clone)]
+#[pyo3(text_signature = "(isotherms)")]
+pub struct PyIdealAdsorbedSolution(pub IdealAdsorbedSolution);
+
+#[pymethods]
+impl PyIdealAdsorbedSolution {
+    #[new]
+    fn new(isotherms: Vec<PyPureComponentIsotherm>) -> Self {
+        Self(IdealAdsorbedSolution::new(
+            isotherms.into_iter().map(|i| i.0).collect(),
+        ))
+    }
+
+    /// Calculate the adsorbed amount of every component.
+    ///
+    /// Parameters
+    /// ----------
+    /// pressure : SINumber
+    ///     The pressure of the gas phase.
+    /// molefracs : numpy.ndarray[float]
+    ///     The composition of the gas phase.
+    /// max_iter : int, optional
+    ///     The maximum number of iterations.
+    /// tol: float, optional
+    ///     The solution tolerance.
+    /// verbosity : Verbosity, optional
+    ///     The verbosity.
+    ///
+    /// Returns
+    /// -------
+    /// SIArray1
+    ///
+    #[pyo3(
+        text_signature = "($self, pressure, molefracs, max_iter=None, tol=None, verbosity=None)"
+    )]
+    fn adsorption(
+        &self,
+        pressure: PySINumber,
+        molefracs: &PyArray1<f64>,
+        max_iter: Option<usize>,
+        tol: Option<f64>,
+        verbosity: Option<Verbosity>,
+    ) -> PyResult<PySIArray1> {
+        Ok(self
+            .0
+            .adsorption(
+                pressure.into(),
+                &molefracs.to_owned_array(),
+                (max_iter, tol, verbosity).into(),
+            )?
+            .into())
+    }
+
+    /// Calculate the adsorbed amount of every component for all
+    /// given pressures at constant composition of the gas phase.
+    ///
+    /// Parameters
+    /// ----------
+    /// pressure : SIArray1
+    ///     The pressures of the gas phase.
+    /// molefracs : numpy.ndarray[float]
+    ///     The composition of the gas phase.
+    /// max_iter : int, optional
+    ///     The maximum number of iterations.
+    /// tol: float, optional
+    ///     The solution tolerance.
+    /// verbosity : Verbosity, optional
+    ///     The verbosity.
+    ///
+    /// Returns
+    /// -------
+    /// SIArray2
+    ///
+    #[pyo3(
+        text_signature = "($self, pressure, molefracs, max_iter=None, tol=None, verbosity=None)"
+    )]
+    fn adsorption_isotherm(
+        &self,
+        pressure: PySIArray1,
+        molefracs: &PyArray1<f64>,
+        max_iter: Option<usize>,
+        tol: Option<f64>,
+        verbosity: Option<Verbosity>,
+    ) -> PyResult<PySIArray2> {
+        Ok(self
+            .0
+            .adsorption_isotherm(
+                &pressure.into(),
+                &molefracs.to_owned_array(),
+                (max_iter, tol, verbosity).into(),
+            )?
+            .into())
+    }
+}
@@ -1,7 +1,13 @@
+mod breakthrough;
+mod capacity;
 mod external_potential;
+mod iast;
 mod pore;
 
+pub use breakthrough::{PyBreakthroughCurve, PyFixedBed};
+pub use capacity::PyWorkingCapacity;
 pub use external_potential::PyExternalPotential;
+pub use iast::{PyIdealAdsorbedSolution, PyPureComponentIsotherm};
 
 #[macro_export]
 macro_rules! impl_adsorption {
@@ -10,12 +16,185 @@ macro_rules! impl_adsorption {
         #[pyclass(name = "Adsorption1D")]
         pub struct PyAdsorption1D(Adsorption1D<$func>);
 
+        /// Container structure for adsorption isotherms in 2D pores.
+        #[pyclass(name = "Adsorption2D")]
+        pub struct PyAdsorption2D(Adsorption2D<$func>);
+
         /// Container structure for adsorption isotherms in 3D pores.
         #[pyclass(name = "Adsorption3D")]
         pub struct PyAdsorption3D(Adsorption3D<$func>);
 
         impl_adsorption_isotherm!($func, $py_func, PyAdsorption1D, PyPore1D, PyPoreProfile1D);
+        impl_adsorption_isotherm!($func, $py_func, PyAdsorption2D, PyPore2D, PyPoreProfile2D);
         impl_adsorption_isotherm!($func, $py_func, PyAdsorption3D, PyPore3D, PyPoreProfile3D);
+
+        /// Adsorption and desorption branch of an isotherm in 1D pores.
+        #[pyclass(name = "AdsorptionHysteresis1D")]
+        pub struct PyAdsorptionHysteresis1D(AdsorptionHysteresis1D<$func>);
+
+        /// Adsorption and desorption branch of an isotherm in 2D pores.
+        #[pyclass(name = "AdsorptionHysteresis2D")]
+        pub struct PyAdsorptionHysteresis2D(AdsorptionHysteresis2D<$func>);
+
+        /// Adsorption and desorption branch of an isotherm in 3D pores.
+        #[pyclass(name = "AdsorptionHysteresis3D")]
+        pub struct PyAdsorptionHysteresis3D(AdsorptionHysteresis3D<$func>);
+
+        impl_adsorption_hysteresis!(
+            $func,
+            $py_func,
+            PyAdsorptionHysteresis1D,
+            PyPore1D,
+            PyPoreProfile1D
+        );
+        impl_adsorption_hysteresis!(
+            $func,
+            $py_func,
+            PyAdsorptionHysteresis2D,
+            PyPore2D,
+            PyPoreProfile2D
+        );
+        impl_adsorption_hysteresis!(
+            $func,
+            $py_func,
+            PyAdsorptionHysteresis3D,
+            PyPore3D,
+            PyPoreProfile3D
+        );
+    };
+}
+
+#[macro_export]
+macro_rules! impl_adsorption_hysteresis {
+    ($func:ty, $py_func:ty, $py_hysteresis:ty, $py_pore:ty, $py_pore_profile:ident) => {
+        #[pymethods]
+        impl $py_hysteresis {
+            /// Calculate the adsorption and the desorption branch of an
+            /// isotherm for the given pressure range.
+            ///
+            /// Parameters
+            /// ----------
+            /// functional : HelmholtzEnergyFunctional
+            ///     The Helmholtz energy functional.
+            /// temperature : SINumber
+            ///     The temperature.
+            /// pressure : SIArray1
+            ///     The pressures for which the profiles are calculated.
+            /// pore : Pore
+            ///     The pore parameters.
+            /// molefracs: numpy.ndarray[float], optional
+            ///     For a mixture, the molefracs of the bulk system.
+            /// solver: DFTSolver, optional
+            ///     Custom solver options.
+            ///
+            /// Returns
+            /// -------
+            /// AdsorptionHysteresis
+            ///
+            #[new]
+            #[pyo3(text_signature = "(functional, temperature, pressure, pore, molefracs=None, solver=None)")]
+            fn new(
+                functional: &$py_func,
+                temperature: PySINumber,
+                pressure: &PySIArray1,
+                pore: &$py_pore,
+                molefracs: Option<&PyArray1<f64>>,
+                solver: Option<PyDFTSolver>,
+            ) -> PyResult<Self> {
+                Ok(Self(AdsorptionHysteresis::new(
+                    &functional.0,
+                    temperature.into(),
+                    pressure,
+                    &pore.0,
+                    molefracs.map(|x| x.to_owned_array()).as_ref(),
+                    solver.map(|s| s.0).as_ref(),
+                )?))
+            }
+
+            #[getter]
+            fn get_adsorption_profiles(&self) -> Vec<$py_pore_profile> {
+                self.0
+                    .adsorption
+                    .profiles
+                    .iter()
+                    .filter_map(|p| p.as_ref().ok().map(|p| $py_pore_profile(p.clone())))
+                    .collect()
+            }
+
+            #[getter]
+            fn get_desorption_profiles(&self) -> Vec<$py_pore_profile> {
+                self.0
+                    .desorption
+                    .profiles
+                    .iter()
+                    .filter_map(|p| p.as_ref().ok().map(|p| $py_pore_profile(p.clone())))
+                    .collect()
+            }
+
+            #[getter]
+            fn get_pressure(&self) -> PySIArray1 {
+                self.0.adsorption.pressure().into()
+            }
+
+            #[getter]
+            fn get_total_adsorption(&self) -> (PySIArray1, PySIArray1) {
+                (
+                    self.0.adsorption.total_adsorption().into(),
+                    self.0.desorption.total_adsorption().into(),
+                )
+            }
+
+            #[getter]
+            fn get_grand_potential(&self) -> (PySIArray1, PySIArray1) {
+                (
+                    self.0.adsorption.grand_potential().into(),
+                    self.0.desorption.grand_potential().into(),
+                )
+            }
+
+            /// The pressure ranges of all hysteresis loops.
+            #[getter]
+            fn get_hysteresis_loops(&self) -> Vec<(PySINumber, PySINumber)> {
+                self.0
+                    .hysteresis_loops()
+                    .into_iter()
+                    .map(|(p0, p1)| (p0.into(), p1.into()))
+                    .collect()
+            }
+
+            /// The pressure intervals in which the adsorption branch
+            /// jumps to the desorption branch (capillary condensation).
+            #[getter]
+            fn get_adsorption_jumps(&self) -> Vec<(PySINumber, PySINumber)> {
+                self.0
+                    .adsorption_jumps()
+                    .into_iter()
+                    .map(|(p0, p1)| (p0.into(), p1.into()))
+                    .collect()
+            }
+
+            /// The pressure intervals in which the desorption branch
+            /// jumps to the adsorption branch (evaporation).
+            #[getter]
+            fn get_desorption_jumps(&self) -> Vec<(PySINumber, PySINumber)> {
+                self.0
+                    .desorption_jumps()
+                    .into_iter()
+                    .map(|(p0, p1)| (p0.into(), p1.into()))
+                    .collect()
+            }
+
+            /// The pressures of the equilibrium transitions within
+            /// all hysteresis loops.
+            #[getter]
+            fn get_equilibrium_pressures(&self) -> Vec<PySINumber> {
+                self.0
+                    .equilibrium_pressures()
+                    .into_iter()
+                    .map(PySINumber::from)
+                    .collect()
+            }
+        }
     };
 }
 
@@ -258,6 +437,75 @@ macro_rules! impl_adsorption_isotherm {
             fn get_enthalpy_of_adsorption(&self) -> PySIArray1 {
                 self.0.enthalpy_of_adsorption().into()
             }
+
+            #[getter]
+            fn get_isosteric_heat(&self) -> PySIArray2 {
+                self.0.isosteric_heat().into()
+            }
+
+            #[getter]
+            fn get_molefracs<'py>(&self, py: Python<'py>) -> &'py PyArray2<f64> {
+                self.0.molefracs().to_pyarray(py)
+            }
+
+            /// Calculate the adsorption selectivity of component `i`
+            /// over component `j` for every point of the isotherm.
+            ///
+            /// Parameters
+            /// ----------
+            /// i : int
+            ///     The index of the first component.
+            /// j : int
+            ///     The index of the second component.
+            ///
+            /// Returns
+            /// -------
+            /// numpy.ndarray[float]
+            ///
+            #[pyo3(text_signature = "($self, i, j)")]
+            fn selectivity<'py>(&self, py: Python<'py>, i: usize, j: usize) -> PyResult<&'py PyArray1<f64>> {
+                Ok(self.0.selectivity(i, j)?.to_pyarray(py))
+            }
+
+            /// Calculate the working capacity between an adsorption
+            /// and a desorption pressure by linear interpolation of
+            /// the isotherm.
+            ///
+            /// Parameters
+            /// ----------
+            /// adsorption_pressure : SINumber
+            ///     The pressure of the adsorption step.
+            /// desorption_pressure : SINumber
+            ///     The pressure of the desorption step.
+            ///
+            /// Returns
+            /// -------
+            /// WorkingCapacity
+            ///
+            #[pyo3(text_signature = "($self, adsorption_pressure, desorption_pressure)")]
+            fn working_capacity(
+                &self,
+                adsorption_pressure: PySINumber,
+                desorption_pressure: PySINumber,
+            ) -> PyResult<PyWorkingCapacity> {
+                Ok(PyWorkingCapacity(self.0.working_capacity(
+                    adsorption_pressure.into(),
+                    desorption_pressure.into(),
+                )?))
+            }
+
+            /// Return the isotherm of a pure component, e.g., for the
+            /// use in an IdealAdsorbedSolution.
+            ///
+            /// Returns
+            /// -------
+            /// PureComponentIsotherm
+            ///
+            fn pure_component_isotherm(&self) -> PyResult<PyPureComponentIsotherm> {
+                Ok(PyPureComponentIsotherm(Arc::new(
+                    self.0.pure_component_isotherm()?,
+                )))
+            }
         }
     };
 }
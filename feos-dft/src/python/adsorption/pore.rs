@@ -132,6 +132,330 @@ macro_rules! impl_pore {
             fn get_enthalpy_of_adsorption(&self) -> PyResult<PySINumber> {
                 Ok(self.0.enthalpy_of_adsorption()?.into())
             }
+
+            #[getter]
+            fn get_isosteric_heat(&self) -> PyResult<PySIArray1> {
+                Ok(self.0.isosteric_heat()?.into())
+            }
+        }
+
+        /// Interfacial tensions and contact angles of a fluid on a planar wall.
+        ///
+        /// Parameters
+        /// ----------
+        /// vle : [PhaseEquilibrium]
+        ///     The phase equilibria for which the wetting behavior
+        ///     is calculated.
+        /// pore : Pore1D
+        ///     A cartesian slit pore that is wide enough for the
+        ///     fluid in its center to be unaffected by the walls.
+        /// n_grid : int, optional
+        ///     The number of grid points of the liquid-vapor
+        ///     interface (default: 2048).
+        /// solver : DFTSolver, optional
+        ///     Custom solver options.
+        ///
+        /// Returns
+        /// -------
+        /// Wetting
+        ///
+        #[pyclass(name = "Wetting")]
+        #[pyo3(text_signature = "(vle, pore, n_grid=None, solver=None)")]
+        pub struct PyWetting(Wetting);
+
+        #[pymethods]
+        impl PyWetting {
+            #[new]
+            fn new(
+                vle: Vec<PyPhaseEquilibrium>,
+                pore: &PyPore1D,
+                n_grid: Option<usize>,
+                solver: Option<PyDFTSolver>,
+            ) -> PyResult<Self> {
+                let vle: Vec<_> = vle.into_iter().map(|vle| vle.0).collect();
+                Ok(Self(Wetting::new(
+                    &vle,
+                    &pore.0,
+                    n_grid,
+                    solver.map(|s| s.0).as_ref(),
+                )?))
+            }
+
+            #[getter]
+            fn get_temperature(&self) -> PySIArray1 {
+                self.0.temperature.clone().into()
+            }
+
+            #[getter]
+            fn get_liquid_vapor_tension(&self) -> PySIArray1 {
+                self.0.liquid_vapor_tension.clone().into()
+            }
+
+            #[getter]
+            fn get_solid_liquid_tension(&self) -> PySIArray1 {
+                self.0.solid_liquid_tension.clone().into()
+            }
+
+            #[getter]
+            fn get_solid_vapor_tension(&self) -> PySIArray1 {
+                self.0.solid_vapor_tension.clone().into()
+            }
+
+            /// The cosine of the contact angle from Young's equation.
+            #[getter]
+            fn get_cos_theta<'py>(&self, py: Python<'py>) -> &'py PyArray1<f64> {
+                self.0.cos_theta().view().to_pyarray(py)
+            }
+
+            /// The contact angle in degrees.
+            #[getter]
+            fn get_contact_angle<'py>(&self, py: Python<'py>) -> &'py PyArray1<f64> {
+                self.0.contact_angle().view().to_pyarray(py)
+            }
+
+            /// The temperature of the wetting transition.
+            #[getter]
+            fn get_wetting_temperature(&self) -> Option<PySINumber> {
+                self.0.wetting_temperature().map(PySINumber::from)
+            }
+
+            /// The temperature of the drying transition.
+            #[getter]
+            fn get_drying_temperature(&self) -> Option<PySINumber> {
+                self.0.drying_temperature().map(PySINumber::from)
+            }
+        }
+
+        /// Liquid films on a planar wall in equilibrium with an
+        /// undersaturated vapor reservoir.
+        ///
+        /// Parameters
+        /// ----------
+        /// vle : PhaseEquilibrium
+        ///     The phase equilibrium of the pure component.
+        /// pore : Pore1D
+        ///     A cartesian slit pore that is wide enough for the
+        ///     vapor in its center to be unaffected by the films.
+        /// pressure : SIArray1
+        ///     The pressures of the vapor reservoir (below the
+        ///     vapor pressure).
+        /// solver : DFTSolver, optional
+        ///     Custom solver options.
+        ///
+        /// Returns
+        /// -------
+        /// ThinFilm
+        ///
+        #[pyclass(name = "ThinFilm")]
+        #[pyo3(text_signature = "(vle, pore, pressure, solver=None)")]
+        pub struct PyThinFilm(ThinFilm<$func>);
+
+        #[pymethods]
+        impl PyThinFilm {
+            #[new]
+            fn new(
+                vle: &PyPhaseEquilibrium,
+                pore: &PyPore1D,
+                pressure: PySIArray1,
+                solver: Option<PyDFTSolver>,
+            ) -> PyResult<Self> {
+                Ok(Self(ThinFilm::new(
+                    &vle.0,
+                    &pore.0,
+                    &pressure.into(),
+                    solver.map(|s| s.0).as_ref(),
+                )?))
+            }
+
+            #[getter]
+            fn get_profiles(&self) -> Vec<PyPoreProfile1D> {
+                self.0
+                    .profiles
+                    .iter()
+                    .filter_map(|p| p.as_ref().ok().map(|p| PyPoreProfile1D(p.clone())))
+                    .collect()
+            }
+
+            #[getter]
+            fn get_pressure(&self) -> PySIArray1 {
+                self.0.pressure.clone().into()
+            }
+
+            #[getter]
+            fn get_film_thickness(&self) -> PySIArray1 {
+                self.0.film_thickness.clone().into()
+            }
+
+            #[getter]
+            fn get_disjoining_pressure(&self) -> PySIArray1 {
+                self.0.disjoining_pressure.clone().into()
+            }
+
+            /// The equilibrium film thickness for a given disjoining pressure,
+            /// obtained by linear interpolation of the disjoining pressure isotherm.
+            ///
+            /// Parameters
+            /// ----------
+            /// disjoining_pressure : SINumber
+            ///     The disjoining pressure.
+            ///
+            /// Returns
+            /// -------
+            /// SINumber, optional
+            ///
+            #[pyo3(text_signature = "($self, disjoining_pressure)")]
+            fn equilibrium_film_thickness(&self, disjoining_pressure: PySINumber) -> Option<PySINumber> {
+                self.0
+                    .equilibrium_film_thickness(disjoining_pressure.into())
+                    .map(PySINumber::from)
+            }
+        }
+
+        /// Parameters required to specify a 2D pore.
+        ///
+        /// The pore is confined by walls perpendicular to the x direction.
+        /// In the y direction, the pore is periodic or, if `potential_y`
+        /// is given, confined by a second pair of walls (rectangular channel).
+        ///
+        /// Parameters
+        /// ----------
+        /// pore_size : [SINumber; 2]
+        ///     The width of the pore in x direction and the width of the
+        ///     channel or the period of the pattern in y direction.
+        /// n_grid : [int; 2]
+        ///     The number of grid points in each direction.
+        /// potential : ExternalPotential
+        ///     The potential of the walls perpendicular to the x direction.
+        /// potential_y : ExternalPotential, optional
+        ///     The potential of the walls perpendicular to the y direction.
+        /// pattern : (ExternalPotential, SINumber), optional
+        ///     The potential and the width of a stripe in the center of the
+        ///     y direction on the walls perpendicular to the x direction.
+        /// potential_cutoff : float, optional
+        ///     Maximum value for the external potential.
+        ///
+        /// Returns
+        /// -------
+        /// Pore2D
+        ///
+        #[pyclass(name = "Pore2D")]
+        #[pyo3(text_signature = "(pore_size, n_grid, potential, potential_y=None, pattern=None, potential_cutoff=None)")]
+        pub struct PyPore2D(Pore2D);
+
+        #[pyclass(name = "PoreProfile2D")]
+        pub struct PyPoreProfile2D(PoreProfile2D<$func>);
+
+        impl_2d_profile!(PyPoreProfile2D, get_x, get_y);
+
+        #[pymethods]
+        impl PyPore2D {
+            #[new]
+            fn new(
+                pore_size: [PySINumber; 2],
+                n_grid: [usize; 2],
+                potential: PyExternalPotential,
+                potential_y: Option<PyExternalPotential>,
+                pattern: Option<(PyExternalPotential, PySINumber)>,
+                potential_cutoff: Option<f64>,
+            ) -> Self {
+                Self(Pore2D::new(
+                    [pore_size[0].into(), pore_size[1].into()],
+                    n_grid,
+                    potential.0,
+                    potential_y.map(|p| p.0),
+                    pattern.map(|(p, w)| (p.0, w.into())),
+                    potential_cutoff,
+                ))
+            }
+
+            /// Initialize the pore for the given bulk state.
+            ///
+            /// Parameters
+            /// ----------
+            /// bulk : State
+            ///     The bulk state in equilibrium with the pore.
+            /// density : SIArray3, optional
+            ///     Initial values for the density profile.
+            /// external_potential : numpy.ndarray[float], optional
+            ///     The external potential in the pore. Used to
+            ///     save computation time in the case of costly
+            ///     evaluations of external potentials.
+            ///
+            /// Returns
+            /// -------
+            /// PoreProfile2D
+            #[pyo3(text_signature = "($self, bulk, density=None, external_potential=None)")]
+            fn initialize(
+                &self,
+                bulk: &PyState,
+                density: Option<PySIArray3>,
+                external_potential: Option<&PyArray3<f64>>,
+            ) -> PyResult<PyPoreProfile2D> {
+                Ok(PyPoreProfile2D(self.0.initialize(
+                    &bulk.0,
+                    density.as_deref(),
+                    external_potential.map(|e| e.to_owned_array()).as_ref(),
+                )?))
+            }
+
+            #[getter]
+            fn get_pore_size(&self)-> [PySINumber; 2] {
+                [self.0.pore_size[0].into(), self.0.pore_size[1].into()]
+            }
+
+            #[getter]
+            fn get_n_grid(&self)-> [usize; 2] {
+                self.0.n_grid
+            }
+
+            #[getter]
+            fn get_potential(&self)-> PyExternalPotential {
+                PyExternalPotential(self.0.potential.clone())
+            }
+
+            #[getter]
+            fn get_potential_y(&self)-> Option<PyExternalPotential> {
+                self.0.potential_y.clone().map(PyExternalPotential)
+            }
+
+            #[getter]
+            fn get_potential_cutoff(&self)-> Option<f64> {
+                self.0.potential_cutoff
+            }
+
+            /// The pore volume using Helium at 298 K as reference.
+            #[getter]
+            fn get_pore_volume(&self) -> PyResult<PySINumber> {
+                Ok(self.0.pore_volume()?.into())
+            }
+        }
+
+        #[pymethods]
+        impl PyPoreProfile2D {
+            #[getter]
+            fn get_grand_potential(&self) -> Option<PySINumber> {
+                self.0.grand_potential.map(PySINumber::from)
+            }
+
+            #[getter]
+            fn get_interfacial_tension(&self) -> Option<PySINumber> {
+                self.0.interfacial_tension.map(PySINumber::from)
+            }
+
+            #[getter]
+            fn get_partial_molar_enthalpy_of_adsorption(&self) -> PyResult<PySIArray1> {
+                Ok(self.0.partial_molar_enthalpy_of_adsorption()?.into())
+            }
+
+            #[getter]
+            fn get_enthalpy_of_adsorption(&self) -> PyResult<PySINumber> {
+                Ok(self.0.enthalpy_of_adsorption()?.into())
+            }
+
+            #[getter]
+            fn get_isosteric_heat(&self) -> PyResult<PySIArray1> {
+                Ok(self.0.isosteric_heat()?.into())
+            }
         }
 
         /// Parameters required to specify a 3D pore.
@@ -189,6 +513,44 @@ macro_rules! impl_pore {
                 ))
             }
 
+            /// Create a 3D pore from the unit cell of a solid structure in a CIF file.
+            ///
+            /// Parameters
+            /// ----------
+            /// file : str
+            ///     The path to the CIF file.
+            /// force_field : dict[str, (float, float)]
+            ///     The size (in Angstrom) and energy (in Kelvin) parameters
+            ///     for every atom label or type symbol in the structure.
+            /// n_grid : [int; 3]
+            ///     The number of grid points in each direction.
+            /// potential_cutoff: float, optional
+            ///     Maximum value for the external potential.
+            /// cutoff_radius: SINumber, optional
+            ///     The cutoff radius for the calculation of solid-fluid interactions.
+            ///
+            /// Returns
+            /// -------
+            /// Pore3D
+            ///
+            #[staticmethod]
+            #[pyo3(text_signature = "(file, force_field, n_grid, potential_cutoff=None, cutoff_radius=None)")]
+            fn from_cif(
+                file: &str,
+                force_field: HashMap<String, (f64, f64)>,
+                n_grid: [usize; 3],
+                potential_cutoff: Option<f64>,
+                cutoff_radius: Option<PySINumber>,
+            ) -> PyResult<Self> {
+                Ok(Self(Pore3D::from_cif(
+                    file,
+                    &force_field,
+                    n_grid,
+                    potential_cutoff,
+                    cutoff_radius.map(|c| c.into()),
+                )?))
+            }
+
             /// Initialize the pore for the given bulk state.
             ///
             /// Parameters
@@ -247,6 +609,11 @@ macro_rules! impl_pore {
             fn get_enthalpy_of_adsorption(&self) -> PyResult<PySINumber> {
                 Ok(self.0.enthalpy_of_adsorption()?.into())
             }
+
+            #[getter]
+            fn get_isosteric_heat(&self) -> PyResult<PySIArray1> {
+                Ok(self.0.isosteric_heat()?.into())
+            }
         }
     };
 }
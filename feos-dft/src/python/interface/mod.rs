@@ -52,6 +52,49 @@ macro_rules! impl_planar_interface {
                 Ok(PyPlanarInterface(profile))
             }
 
+            /// Initialize a planar interface with a hyperbolic tangent that is
+            /// enriched in surface-active components based on the activity
+            /// coefficients in the liquid phase.
+            ///
+            /// Parameters
+            /// ----------
+            /// vle : PhaseEquilibrium
+            ///     The bulk phase equilibrium.
+            /// n_grid : int
+            ///     The number of grid points.
+            /// l_grid: SINumber
+            ///     The width of the calculation domain.
+            /// critical_temperature: SINumber
+            ///     An estimate for the critical temperature of the system.
+            ///     Used to guess the width of the interface.
+            /// fix_equimolar_surface: bool, optional
+            ///     If True use additional constraints to fix the
+            ///     equimolar surface of the system.
+            ///     Defaults to False.
+            ///
+            /// Returns
+            /// -------
+            /// PlanarInterface
+            ///
+            #[staticmethod]
+            #[pyo3(text_signature = "(vle, n_grid, l_grid, critical_temperature, fix_equimolar_surface=None)")]
+            fn from_tanh_enriched(
+                vle: &PyPhaseEquilibrium,
+                n_grid: usize,
+                l_grid: PySINumber,
+                critical_temperature: PySINumber,
+                fix_equimolar_surface: Option<bool>,
+            ) -> PyResult<Self> {
+                let profile = PlanarInterface::from_tanh_enriched(
+                    &vle.0,
+                    n_grid,
+                    l_grid.into(),
+                    critical_temperature.into(),
+                    fix_equimolar_surface.unwrap_or(false),
+                )?;
+                Ok(PyPlanarInterface(profile))
+            }
+
             /// Initialize a planar interface with a pDGT calculation.
             ///
             /// Parameters
@@ -156,6 +199,96 @@ macro_rules! impl_planar_interface {
             fn interfacial_thickness(&self) -> PyResult<PySINumber> {
                 Ok(self.0.interfacial_thickness()?.into())
             }
+
+            /// Calculates the energetic and the entropic part of the
+            /// surface tension.
+            ///
+            /// Returns
+            /// -------
+            /// (SINumber, SINumber)
+            ///
+            fn surface_tension_energy_entropy(&self) -> PyResult<(PySINumber, PySINumber)> {
+                let (energy, entropy) = self.0.surface_tension_energy_entropy()?;
+                Ok((energy.into(), entropy.into()))
+            }
+
+            /// Calculates the energetic and the entropic parts of the
+            /// surface tension for every contribution of the functional.
+            ///
+            /// Returns
+            /// -------
+            /// [(str, SINumber, SINumber)]
+            ///
+            fn surface_tension_contributions(&self) -> PyResult<Vec<(String, PySINumber, PySINumber)>> {
+                Ok(self
+                    .0
+                    .surface_tension_contributions()?
+                    .into_iter()
+                    .map(|(name, energy, entropy)| (name, energy.into(), entropy.into()))
+                    .collect())
+            }
+
+            /// Calculates the local entropy density across the interface.
+            ///
+            /// Returns
+            /// -------
+            /// SIArray1
+            ///
+            fn entropy_density(&self) -> PyResult<PySIArray1> {
+                Ok(self.0.entropy_density()?.into())
+            }
+
+            /// Calculates the local enthalpy density across the interface.
+            ///
+            /// Returns
+            /// -------
+            /// SIArray1
+            ///
+            fn enthalpy_density(&self) -> PyResult<PySIArray1> {
+                Ok(self.0.enthalpy_density()?.into())
+            }
+
+            /// Calculates the local partial molar entropies across the interface.
+            ///
+            /// Returns
+            /// -------
+            /// SIArray2
+            ///
+            fn partial_molar_entropy(&self) -> PyResult<PySIArray2> {
+                Ok(self.0.partial_molar_entropy()?.into())
+            }
+
+            /// Calculates the local partial molar enthalpies across the interface.
+            ///
+            /// Returns
+            /// -------
+            /// SIArray2
+            ///
+            fn partial_molar_enthalpy(&self) -> PyResult<PySIArray2> {
+                Ok(self.0.partial_molar_enthalpy()?.into())
+            }
+
+            /// Calculates the surface excess entropy with respect to
+            /// the equimolar dividing surface.
+            ///
+            /// Returns
+            /// -------
+            /// SINumber
+            ///
+            fn interfacial_excess_entropy(&self) -> PyResult<PySINumber> {
+                Ok(self.0.interfacial_excess_entropy()?.into())
+            }
+
+            /// Calculates the surface excess enthalpy with respect to
+            /// the equimolar dividing surface.
+            ///
+            /// Returns
+            /// -------
+            /// SINumber
+            ///
+            fn interfacial_excess_enthalpy(&self) -> PyResult<PySINumber> {
+                Ok(self.0.interfacial_excess_enthalpy()?.into())
+            }
         }
     };
 }
@@ -1,6 +1,6 @@
 #[macro_export]
 macro_rules! impl_surface_tension_diagram {
-    ($func:ty) => {
+    ($func:ty, $py_func:ty) => {
         /// Container structure for the efficient calculation of surface tension diagrams.
         ///
         /// Parameters
@@ -58,6 +58,127 @@ macro_rules! impl_surface_tension_diagram {
                 ))
             }
 
+            /// Calculate the interfacial profiles of a surface tension diagram
+            /// in parallel.
+            ///
+            /// Parameters
+            /// ----------
+            /// dia : [PhaseEquilibrium]
+            ///     The underlying phase diagram given as a list of states
+            ///     for which surface tensions shall be calculated.
+            /// chunksize : int
+            ///     The number of points that are calculated in sequence
+            ///     within a thread.
+            /// nthreads : int
+            ///     Number of threads.
+            /// init_densities : bool, optional
+            ///     None: Do not initialize densities with old results
+            ///     True: Initialize and scale densities
+            ///     False: Initialize without scaling
+            /// n_grid : int, optional
+            ///     The number of grid points (default: 2048).
+            /// l_grid : SINumber, optional
+            ///     The size of the calculation domain (default: 100 A)
+            /// critical_temperature: SINumber, optional
+            ///     An estimate for the critical temperature, used to initialize
+            ///     density profile (default: 500 K)
+            /// fix_equimolar_surface: bool, optional
+            ///     If True use additional constraints to fix the
+            ///     equimolar surface of the system.
+            ///     Defaults to False.
+            /// solver: DFTSolver, optional
+            ///     Custom solver options
+            ///
+            /// Returns
+            /// -------
+            /// SurfaceTensionDiagram
+            ///
+            #[cfg(feature = "rayon")]
+            #[staticmethod]
+            #[pyo3(text_signature = "(dia, chunksize, nthreads, init_densities=None, n_grid=None, l_grid=None, critical_temperature=None, fix_equimolar_surface=None, solver=None)")]
+            pub fn par_isotherm(
+                dia: Vec<PyPhaseEquilibrium>,
+                chunksize: usize,
+                nthreads: usize,
+                init_densities: Option<bool>,
+                n_grid: Option<usize>,
+                l_grid: Option<PySINumber>,
+                critical_temperature: Option<PySINumber>,
+                fix_equimolar_surface: Option<bool>,
+                solver: Option<PyDFTSolver>,
+            ) -> EosResult<Self> {
+                let thread_pool = rayon::ThreadPoolBuilder::new()
+                    .num_threads(nthreads)
+                    .build()?;
+                let x = dia.into_iter().map(|vle| vle.0).collect();
+                Ok(Self(SurfaceTensionDiagram::par_new(
+                    &x,
+                    chunksize,
+                    thread_pool,
+                    init_densities,
+                    n_grid,
+                    l_grid.map(|l| l.into()),
+                    critical_temperature.map(|c| c.into()),
+                    fix_equimolar_surface,
+                    solver.map(|s| s.0).as_ref(),
+                )))
+            }
+
+            /// Surface tension diagram of a binary mixture along an isotherm.
+            ///
+            /// The interfacial profiles are solved in parallel for all
+            /// phase equilibria over the entire composition range.
+            ///
+            /// Parameters
+            /// ----------
+            /// functional : HelmholtzEnergyFunctional
+            ///     The Helmholtz energy functional of the binary mixture.
+            /// temperature : SINumber
+            ///     The temperature.
+            /// npoints : int, optional
+            ///     The number of points of the phase diagram (default: 51).
+            /// n_grid : int, optional
+            ///     The number of grid points (default: 2048).
+            /// l_grid : SINumber, optional
+            ///     The size of the calculation domain (default: 100 A)
+            /// critical_temperature: SINumber, optional
+            ///     An estimate for the critical temperature, used to initialize
+            ///     density profile (default: 500 K)
+            /// fix_equimolar_surface: bool, optional
+            ///     If True use additional constraints to fix the
+            ///     equimolar surface of the system.
+            ///     Defaults to False.
+            /// solver: DFTSolver, optional
+            ///     Custom solver options
+            ///
+            /// Returns
+            /// -------
+            /// SurfaceTensionDiagram
+            ///
+            #[staticmethod]
+            #[pyo3(text_signature = "(functional, temperature, npoints=None, n_grid=None, l_grid=None, critical_temperature=None, fix_equimolar_surface=None, solver=None)")]
+            pub fn binary_isotherm(
+                functional: $py_func,
+                temperature: PySINumber,
+                npoints: Option<usize>,
+                n_grid: Option<usize>,
+                l_grid: Option<PySINumber>,
+                critical_temperature: Option<PySINumber>,
+                fix_equimolar_surface: Option<bool>,
+                solver: Option<PyDFTSolver>,
+            ) -> PyResult<Self> {
+                Ok(Self(SurfaceTensionDiagram::binary_isotherm(
+                    &functional.0,
+                    temperature.into(),
+                    npoints,
+                    n_grid,
+                    l_grid.map(|l| l.into()),
+                    critical_temperature.map(|c| c.into()),
+                    fix_equimolar_surface,
+                    solver.map(|s| s.0).as_ref(),
+                )?))
+            }
+
             #[getter]
             fn get_profiles(&self) -> Vec<PyPlanarInterface> {
                 self.0
@@ -1,8 +1,13 @@
 mod adsorption;
 mod interface;
 mod profile;
+mod simulation;
 mod solvation;
 mod solver;
 
-pub use adsorption::PyExternalPotential;
+pub use adsorption::{
+    PyBreakthroughCurve, PyExternalPotential, PyFixedBed, PyIdealAdsorbedSolution,
+    PyPureComponentIsotherm, PyWorkingCapacity,
+};
+pub use simulation::{PyPotentialTable, PySimulationProfile};
 pub use solver::{PyDFTSolver, PyDFTSolverLog};
@@ -46,6 +46,39 @@ macro_rules! impl_profile {
                 Ok(slf)
             }
 
+            /// Store the current density profile in a checkpoint file.
+            ///
+            /// Parameters
+            /// ----------
+            /// path : str
+            ///     The path of the (JSON) checkpoint file.
+            ///
+            #[pyo3(text_signature = "($self, path)")]
+            fn write_checkpoint(&self, path: &str) -> PyResult<()> {
+                Ok(self.0.profile.checkpoint()?.to_file(path)?)
+            }
+
+            /// Initialize the density profile from a checkpoint file.
+            ///
+            /// The temperature, the chemical potentials and the grid
+            /// of the checkpoint have to match the profile.
+            ///
+            /// Parameters
+            /// ----------
+            /// path : str
+            ///     The path of the (JSON) checkpoint file.
+            ///
+            /// Returns
+            /// -------
+            /// $struct
+            ///
+            #[pyo3(text_signature = "($self, path)")]
+            fn load_checkpoint(slf: &PyCell<Self>, path: &str) -> PyResult<&PyCell<Self>> {
+                let checkpoint = $crate::DFTCheckpoint::from_file(path)?;
+                slf.borrow_mut().0.profile.load_checkpoint(&checkpoint)?;
+                Ok(slf)
+            }
+
             $(
             #[getter]
             fn $ax(&self) -> PySIArray1 {
@@ -209,6 +242,17 @@ macro_rules! impl_profile {
             fn get_dn_dt(&self) -> PyResult<PySIArray1> {
                 Ok((PySIArray1::from(self.0.profile.dn_dt()?)))
             }
+            $(
+                #[getter]
+                fn get_drho_dx(&self) -> PyResult<$si_arr3> {
+                    Ok(($si_arr3::from(self.0.profile.drho_dx()?)))
+                }
+            )?
+
+            #[getter]
+            fn get_dn_dx(&self) -> PyResult<PySIArray2> {
+                Ok((PySIArray2::from(self.0.profile.dn_dx()?)))
+            }
         }
     };
 }
@@ -0,0 +1,134 @@
+use crate::simulation::{PotentialTable, SimulationProfile};
+use numpy::{PyArray1, ToPyArray};
+use pyo3::prelude::*;
+use quantity::python::PySIArray1;
+
+/// Potential energies and forces of a pair of segments or of a segment
+/// and a wall, tabulated on a grid of distances.
+#[pyclass(name = "PotentialTable")]
+#[derive(Clone)]
+pub struct PyPotentialTable(pub PotentialTable);
+
+#[pymethods]
+impl PyPotentialTable {
+    /// Keyword that identifies the table in a file.
+    #[getter]
+    fn get_keyword(&self) -> String {
+        self.0.keyword.clone()
+    }
+
+    /// Distances in Angstrom.
+    #[getter]
+    fn get_distance<'py>(&self, py: Python<'py>) -> &'py PyArray1<f64> {
+        self.0.distance.to_pyarray(py)
+    }
+
+    /// Potential energies divided by the Boltzmann constant in Kelvin.
+    #[getter]
+    fn get_energy<'py>(&self, py: Python<'py>) -> &'py PyArray1<f64> {
+        self.0.energy.to_pyarray(py)
+    }
+
+    /// Forces divided by the Boltzmann constant in Kelvin per Angstrom.
+    #[getter]
+    fn get_force<'py>(&self, py: Python<'py>) -> &'py PyArray1<f64> {
+        self.0.force.to_pyarray(py)
+    }
+
+    /// Write tables to a file in the format of the LAMMPS commands
+    /// `pair_style table` and `fix wall/table` for `units real`.
+    ///
+    /// Parameters
+    /// ----------
+    /// tables : List[PotentialTable]
+    ///     The tabulated potentials.
+    /// file : str
+    ///     The path of the file.
+    #[staticmethod]
+    #[pyo3(text_signature = "(tables, file)")]
+    fn to_lammps_file(tables: Vec<Self>, file: &str) -> PyResult<()> {
+        let tables: Vec<_> = tables.into_iter().map(|t| t.0).collect();
+        Ok(PotentialTable::to_lammps_file(&tables, file)?)
+    }
+}
+
+/// Density profile of a single species obtained from a molecular simulation.
+#[pyclass(name = "SimulationProfile")]
+#[derive(Clone)]
+pub struct PySimulationProfile(pub SimulationProfile);
+
+#[pymethods]
+impl PySimulationProfile {
+    /// Read a density profile from the output of the LAMMPS command
+    /// `fix ave/chunk` with chunks from `compute chunk/atom bin/1d`.
+    ///
+    /// The file has to contain the columns `Coord1` and `density/number`
+    /// in `units real`. Multiple outputs are averaged.
+    ///
+    /// Parameters
+    /// ----------
+    /// file : str
+    ///     The path of the file.
+    ///
+    /// Returns
+    /// -------
+    /// SimulationProfile
+    #[staticmethod]
+    #[pyo3(text_signature = "(file)")]
+    fn from_lammps_chunk(file: &str) -> PyResult<Self> {
+        Ok(Self(SimulationProfile::from_lammps_chunk(file)?))
+    }
+
+    /// Read a density profile from a text file with whitespace
+    /// separated columns, e.g., as exported from RASPA.
+    ///
+    /// The first column contains the coordinates in Angstrom, lines
+    /// starting with `#` are ignored.
+    ///
+    /// Parameters
+    /// ----------
+    /// file : str
+    ///     The path of the file.
+    /// density_column : int
+    ///     The (0-based) index of the column that contains the
+    ///     number densities in 1/Angstrom^3.
+    ///
+    /// Returns
+    /// -------
+    /// SimulationProfile
+    #[staticmethod]
+    #[pyo3(text_signature = "(file, density_column)")]
+    fn from_columns(file: &str, density_column: usize) -> PyResult<Self> {
+        Ok(Self(SimulationProfile::from_columns(file, density_column)?))
+    }
+
+    /// Coordinates of the bins.
+    #[getter]
+    fn get_coordinate(&self) -> PySIArray1 {
+        self.0.coordinate.clone().into()
+    }
+
+    /// Densities in the bins.
+    #[getter]
+    fn get_density(&self) -> PySIArray1 {
+        self.0.density.clone().into()
+    }
+
+    /// Linearly interpolate the density profile to the given coordinates,
+    /// e.g., the grid of a DFT profile.
+    ///
+    /// Outside of the range of the simulation profile, the density is NaN.
+    ///
+    /// Parameters
+    /// ----------
+    /// coordinate : SIArray1
+    ///     The coordinates.
+    ///
+    /// Returns
+    /// -------
+    /// SIArray1
+    #[pyo3(text_signature = "($self, coordinate)")]
+    fn interpolate(&self, coordinate: PySIArray1) -> PyResult<PySIArray1> {
+        Ok(self.0.interpolate(&coordinate.into())?.into())
+    }
+}
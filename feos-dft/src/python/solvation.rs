@@ -68,6 +68,61 @@ macro_rules! impl_solvation_profile {
             fn get_solvation_free_energy(&self) -> Option<PySINumber> {
                 self.0.solvation_free_energy.map(PySINumber::from)
             }
+
+            #[getter]
+            fn get_henry_constant(&self) -> PyResult<PySINumber> {
+                Ok(self.0.henry_constant()?.into())
+            }
+
+            /// Calculate the solvation Gibbs energy for the transfer of the
+            /// solute from an ideal gas at the standard pressure to an ideal
+            /// dilute solution at the standard concentration.
+            ///
+            /// Parameters
+            /// ----------
+            /// standard_pressure : SINumber, optional
+            ///     The standard pressure of the gas (default: 1 bar).
+            /// standard_concentration : SINumber, optional
+            ///     The standard concentration of the solution (default: 1 mol/l).
+            ///
+            /// Returns
+            /// -------
+            /// SINumber
+            ///
+            #[pyo3(text_signature = "($self, standard_pressure=None, standard_concentration=None)")]
+            fn standard_solvation_gibbs_energy(
+                &self,
+                standard_pressure: Option<PySINumber>,
+                standard_concentration: Option<PySINumber>,
+            ) -> PyResult<PySINumber> {
+                Ok(self
+                    .0
+                    .standard_solvation_gibbs_energy(
+                        standard_pressure.map(|p| p.into()),
+                        standard_concentration.map(|c| c.into()),
+                    )?
+                    .into())
+            }
+
+            /// Calculate the partition coefficient of the solute between
+            /// this solvent and the solvent of another solvation profile.
+            ///
+            /// Parameters
+            /// ----------
+            /// other : SolvationProfile
+            ///     The solved profile of the same solute in the other solvent
+            ///     at the same temperature.
+            ///
+            /// Returns
+            /// -------
+            /// float
+            ///     The ratio of the concentrations of the solute in both
+            ///     solvents. The decadic logarithm yields, e.g., log P.
+            ///
+            #[pyo3(text_signature = "($self, other)")]
+            fn partition_coefficient(&self, other: PyRef<PySolvationProfile>) -> PyResult<f64> {
+                Ok(self.0.partition_coefficient(&other.0)?)
+            }
         }
     };
 }
@@ -131,5 +186,97 @@ macro_rules! impl_pair_correlation {
                 self.0.structure_factor
             }
         }
+
+        /// Pair correlation functions of all pairs of components in a mixture
+        /// from separate test particle calculations for every component.
+        ///
+        /// Parameters
+        /// ----------
+        /// bulk : State
+        ///     The bulk state in equilibrium with the profiles.
+        /// n_grid : int
+        ///     The number of grid points.
+        /// width: SINumber
+        ///     The width of the system.
+        ///
+        /// Returns
+        /// -------
+        /// PairCorrelationMatrix
+        ///
+        #[pyclass(name = "PairCorrelationMatrix")]
+        #[pyo3(text_signature = "(bulk, n_grid, width)")]
+        pub struct PyPairCorrelationMatrix(PairCorrelationMatrix<$func>);
+
+        #[pymethods]
+        impl PyPairCorrelationMatrix {
+            #[new]
+            fn new(bulk: PyState, n_grid: usize, width: PySINumber) -> PyResult<Self> {
+                Ok(Self(PairCorrelationMatrix::new(&bulk.0, n_grid, width.into())?))
+            }
+
+            /// Solve the test particle profiles of all components in-place.
+            /// A non-default solver can be provided optionally.
+            ///
+            /// Parameters
+            /// ----------
+            /// solver : DFTSolver, optional
+            ///     The solver used to solve the profiles.
+            /// debug: bool, optional
+            ///     If True, do not check for convergence.
+            ///
+            /// Returns
+            /// -------
+            /// PairCorrelationMatrix
+            ///
+            #[pyo3(signature = (solver=None, debug=false), text_signature = "($self, solver=None, debug=False)")]
+            fn solve(slf: &PyCell<Self>, solver: Option<PyDFTSolver>, debug: bool) -> PyResult<&PyCell<Self>> {
+                slf.borrow_mut()
+                    .0
+                    .solve_inplace(solver.map(|s| s.0).as_ref(), debug)?;
+                Ok(slf)
+            }
+
+            /// Calculate the partial structure factors for the given wave numbers.
+            ///
+            /// Parameters
+            /// ----------
+            /// k : SIArray1
+            ///     The wave numbers.
+            ///
+            /// Returns
+            /// -------
+            /// numpy.ndarray[float]
+            ///     The partial structure factors with the components in the
+            ///     first two and the wave numbers in the third axis.
+            ///
+            #[pyo3(text_signature = "($self, k)")]
+            fn structure_factor<'py>(&self, k: PySIArray1, py: Python<'py>) -> PyResult<&'py PyArray3<f64>> {
+                Ok(self.0.structure_factor(&k.into())?.view().to_pyarray(py))
+            }
+
+            #[getter]
+            fn get_r(&self) -> PySIArray1 {
+                PySIArray1::from(self.0.r())
+            }
+
+            #[getter]
+            fn get_pair_correlations(&self) -> Vec<PyPairCorrelation> {
+                self.0
+                    .pair_correlations
+                    .iter()
+                    .map(|p| PyPairCorrelation(p.clone()))
+                    .collect()
+            }
+
+            #[getter]
+            fn get_pair_correlation_function<'py>(
+                &self,
+                py: Python<'py>,
+            ) -> Option<&'py PyArray3<f64>> {
+                self.0
+                    .pair_correlation_function()
+                    .map(|g| g.view().to_pyarray(py))
+            }
+        }
     };
 }
@@ -1,8 +1,10 @@
 use crate::{DFTSolver, DFTSolverLog};
+use feos_core::python::progress::PyProgressCallback;
 use feos_core::Verbosity;
 use numpy::{PyArray1, ToPyArray};
 use pyo3::prelude::*;
 use quantity::python::PySIArray1;
+use std::sync::Arc;
 
 /// Settings for the DFT solver.
 ///
@@ -11,20 +13,28 @@ use quantity::python::PySIArray1;
 /// verbosity: Verbosity, optional
 ///     The verbosity level of the solver.
 ///     Defaults to Verbosity.None.
+/// callback: Callable[[str, int, float], bool], optional
+///     Called with the arguments `(task, iteration, residual)`
+///     in every iteration of the solver. Returning `False`
+///     cancels the calculation.
 ///
 /// Returns
 /// -------
 /// DFTSolver
 #[pyclass(name = "DFTSolver")]
 #[derive(Clone)]
-#[pyo3(text_signature = "(verbosity=None)")]
+#[pyo3(text_signature = "(verbosity=None, callback=None)")]
 pub struct PyDFTSolver(pub DFTSolver);
 
 #[pymethods]
 impl PyDFTSolver {
     #[new]
-    fn new(verbosity: Option<Verbosity>) -> Self {
-        Self(DFTSolver::new(verbosity))
+    fn new(verbosity: Option<Verbosity>, callback: Option<PyProgressCallback>) -> Self {
+        let solver = DFTSolver::new(verbosity);
+        Self(match callback {
+            Some(callback) => solver.callback(Arc::new(callback)),
+            None => solver,
+        })
     }
 
     /// The default solver.
@@ -37,6 +47,23 @@ impl PyDFTSolver {
         Self(DFTSolver::default())
     }
 
+    /// Report the progress of the solver to a callback.
+    ///
+    /// Parameters
+    /// ----------
+    /// callback: Callable[[str, int, float], bool]
+    ///     Called with the arguments `(task, iteration, residual)`
+    ///     in every iteration of the solver. Returning `False`
+    ///     cancels the calculation.
+    ///
+    /// Returns
+    /// -------
+    /// DFTSolver
+    #[pyo3(text_signature = "($self, callback)")]
+    fn callback(&self, callback: PyProgressCallback) -> Self {
+        Self(self.0.clone().callback(Arc::new(callback)))
+    }
+
     /// Add a picard iteration to the solver object.
     ///
     /// Parameters
@@ -130,19 +157,83 @@ impl PyDFTSolver {
     /// tol: float, optional
     ///     The tolerance.
     ///     Defaults to 1e-11.
+    /// backtracking: bool, optional
+    ///     Halve the Newton step until the residual decreases.
+    ///     Defaults to False.
     ///
     /// Returns
     /// -------
     /// DFTSolver
-    #[pyo3(text_signature = "($self, log=None, max_iter=None, max_iter_gmres=None, tol=None)")]
+    #[pyo3(
+        text_signature = "($self, log=None, max_iter=None, max_iter_gmres=None, tol=None, backtracking=None)"
+    )]
     fn newton(
         &self,
         log: Option<bool>,
         max_iter: Option<usize>,
         max_iter_gmres: Option<usize>,
         tol: Option<f64>,
+        backtracking: Option<bool>,
+    ) -> Self {
+        Self(
+            self.0
+                .clone()
+                .newton(log, max_iter, max_iter_gmres, tol, backtracking),
+        )
+    }
+
+    /// Add a Picard iteration that switches automatically to
+    /// a Newton solver to the solver object.
+    ///
+    /// The Picard iteration is used until the residual falls below
+    /// `switching_tol` or the convergence of the Picard iteration
+    /// stagnates. Afterwards, the Newton solver is used.
+    ///
+    /// Parameters
+    /// ----------
+    /// log: bool, optional
+    ///     Iterate the logarithm of the density profile
+    ///     Defaults to False.
+    /// max_iter: int, optional
+    ///     The maximum number of Newton iterations.
+    ///     Defaults to 50.
+    /// max_iter_gmres: int, optional
+    ///     The maximum number of iterations for the GMRES solver.
+    ///     Defaults to 200.
+    /// tol: float, optional
+    ///     The tolerance.
+    ///     Defaults to 1e-11.
+    /// switching_tol: float, optional
+    ///     The tolerance at which the Picard iteration is switched
+    ///     to the Newton solver.
+    ///     Defaults to 1e-3.
+    /// backtracking: bool, optional
+    ///     Halve the Newton step until the residual decreases.
+    ///     Defaults to False.
+    ///
+    /// Returns
+    /// -------
+    /// DFTSolver
+    #[pyo3(
+        text_signature = "($self, log=None, max_iter=None, max_iter_gmres=None, tol=None, switching_tol=None, backtracking=None)"
+    )]
+    fn picard_newton(
+        &self,
+        log: Option<bool>,
+        max_iter: Option<usize>,
+        max_iter_gmres: Option<usize>,
+        tol: Option<f64>,
+        switching_tol: Option<f64>,
+        backtracking: Option<bool>,
     ) -> Self {
-        Self(self.0.clone().newton(log, max_iter, max_iter_gmres, tol))
+        Self(self.0.clone().picard_newton(
+            log,
+            max_iter,
+            max_iter_gmres,
+            tol,
+            switching_tol,
+            backtracking,
+        ))
     }
 
     fn _repr_markdown_(&self) -> String {
@@ -165,6 +256,16 @@ impl PyDFTSolverLog {
         self.0.residual().to_pyarray(py)
     }
 
+    #[getter]
+    fn get_step_size<'py>(&self, py: Python<'py>) -> &'py PyArray1<f64> {
+        self.0.step_size().to_pyarray(py)
+    }
+
+    #[getter]
+    fn get_damping<'py>(&self, py: Python<'py>) -> &'py PyArray1<f64> {
+        self.0.damping().to_pyarray(py)
+    }
+
     #[getter]
     fn get_time(&self) -> PySIArray1 {
         self.0.time().into()
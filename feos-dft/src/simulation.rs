@@ -0,0 +1,311 @@
+//! Exchange of potentials and density profiles with molecular simulation codes.
+//!
+//! Pair potentials and external wall potentials are tabulated in the formats of
+//! the LAMMPS commands `pair_style table` and `fix wall/table`, so that grand
+//! canonical Monte Carlo (GCMC) or molecular dynamics simulations use exactly the
+//! same potentials as the DFT calculation. Density profiles obtained from the
+//! simulations can be read and interpolated to the grid of a DFT profile.
+use crate::adsorption::{ExternalPotential, FluidParameters};
+use crate::functional::{HelmholtzEnergyFunctional, DFT};
+use crate::solvation::PairPotential;
+use feos_core::{EosError, EosResult, EosUnit};
+use ndarray::Array1;
+use quantity::si::{SIArray1, SINumber, SIUnit, ANGSTROM, JOULE, KELVIN, MOL, NAV, RGAS};
+use std::fmt::Write;
+use std::fs;
+use std::path::Path;
+
+/// Relative step size of the central differences used to calculate forces.
+const FORCE_STEP: f64 = 1e-6;
+/// Thermochemical calorie in J.
+const CALORIE: f64 = 4.184;
+
+/// Potential energies and forces of a pair of segments or of a segment and a
+/// wall, tabulated on a grid of distances.
+#[derive(Clone, Debug)]
+pub struct PotentialTable {
+    /// Keyword that identifies the table in a file
+    pub keyword: String,
+    /// Distances in Å
+    pub distance: Array1<f64>,
+    /// Potential energies divided by the Boltzmann constant in K
+    pub energy: Array1<f64>,
+    /// Forces divided by the Boltzmann constant in K/Å
+    pub force: Array1<f64>,
+}
+
+impl PotentialTable {
+    /// Tabulate a potential and calculate the forces from central differences.
+    fn new<P: Fn(&Array1<f64>) -> Array1<f64>>(
+        keyword: String,
+        distance: Array1<f64>,
+        potential: P,
+    ) -> EosResult<Self> {
+        let energy = potential(&distance);
+        let h = &distance * FORCE_STEP;
+        let force = (potential(&(&distance - &h)) - potential(&(&distance + &h))) / (2.0 * h);
+        if energy.iter().chain(force.iter()).any(|x| !x.is_finite()) {
+            return Err(EosError::Error(format!(
+                "The potential {} is not finite at all distances.",
+                keyword
+            )));
+        }
+        Ok(Self {
+            keyword,
+            distance,
+            energy,
+            force,
+        })
+    }
+
+    /// Write tables to a file in the format of the LAMMPS commands
+    /// `pair_style table` and `fix wall/table`.
+    ///
+    /// The tables are written for `units real`, i.e., distances in Å,
+    /// energies in kcal/mol and forces in kcal/(mol Å).
+    pub fn to_lammps_file<P: AsRef<Path>>(tables: &[Self], file: P) -> EosResult<()> {
+        let kcal_mol = (RGAS * KELVIN).to_reduced(JOULE / MOL)? / (1000.0 * CALORIE);
+        let mut content = String::from("# Tabulated potentials in LAMMPS units real\n");
+        for table in tables {
+            write!(
+                content,
+                "\n{}\nN {}\n\n",
+                table.keyword,
+                table.distance.len()
+            )
+            .unwrap();
+            for (i, ((r, e), f)) in table
+                .distance
+                .iter()
+                .zip(table.energy.iter())
+                .zip(table.force.iter())
+                .enumerate()
+            {
+                writeln!(
+                    content,
+                    "{} {:.10e} {:.10e} {:.10e}",
+                    i + 1,
+                    r,
+                    e * kcal_mol,
+                    f * kcal_mol
+                )
+                .unwrap();
+            }
+        }
+        fs::write(file, content).map_err(|e| EosError::Error(e.to_string()))
+    }
+}
+
+/// Check that distances are positive and strictly increasing.
+fn validate_distance(distance: &SIArray1) -> EosResult<Array1<f64>> {
+    let r = distance.to_reduced(SIUnit::reference_length())?;
+    if r.len() < 2 || r[0] <= 0.0 || r.iter().zip(r.iter().skip(1)).any(|(a, b)| b <= a) {
+        return Err(EosError::Error(String::from(
+            "The distances have to be positive and strictly increasing.",
+        )));
+    }
+    Ok(r)
+}
+
+impl<F: HelmholtzEnergyFunctional + PairPotential> DFT<F> {
+    /// Tabulate the pair potentials of all pairs of segments.
+    ///
+    /// The tables are named `PAIR_i_j` ($i\leq j$) with segment indices
+    /// starting at 1, i.e., the atom types of a LAMMPS simulation.
+    pub fn pair_potential_tables(
+        &self,
+        temperature: SINumber,
+        distance: &SIArray1,
+    ) -> EosResult<Vec<PotentialTable>> {
+        let t = temperature.to_reduced(SIUnit::reference_temperature())?;
+        let r = validate_distance(distance)?;
+        let segments = self.m().len();
+        let mut tables = Vec::with_capacity(segments * (segments + 1) / 2);
+        for i in 0..segments {
+            for j in i..segments {
+                tables.push(PotentialTable::new(
+                    format!("PAIR_{}_{}", i + 1, j + 1),
+                    r.clone(),
+                    |r| self.pair_potential(i, r, t).row(j).to_owned(),
+                )?);
+            }
+        }
+        Ok(tables)
+    }
+}
+
+impl ExternalPotential {
+    /// Tabulate the potential of a single planar wall for all segments of a fluid.
+    ///
+    /// The distance is measured from the wall. The tables are named `WALL_i`
+    /// with segment indices starting at 1. Potentials that are only defined
+    /// on the grid of a pore (`Custom`) or that are not finite (`HardWall`)
+    /// can not be tabulated.
+    pub fn wall_potential_tables<P: FluidParameters>(
+        &self,
+        fluid_parameters: &P,
+        temperature: SINumber,
+        distance: &SIArray1,
+    ) -> EosResult<Vec<PotentialTable>> {
+        if let Self::Custom(_) = self {
+            return Err(EosError::Error(String::from(
+                "Custom external potentials can not be tabulated.",
+            )));
+        }
+        self.validate(fluid_parameters.m().len())?;
+        let t = temperature.to_reduced(SIUnit::reference_temperature())?;
+        let z = validate_distance(distance)?;
+        (0..fluid_parameters.m().len())
+            .map(|i| {
+                PotentialTable::new(format!("WALL_{}", i + 1), z.clone(), |z| {
+                    self.calculate_cartesian_potential(z, fluid_parameters, t)
+                        .row(i)
+                        .to_owned()
+                })
+            })
+            .collect()
+    }
+}
+
+/// Density profile of a single species obtained from a molecular simulation.
+#[derive(Clone, Debug)]
+pub struct SimulationProfile {
+    /// Coordinates of the bins
+    pub coordinate: SIArray1,
+    /// Densities in the bins
+    pub density: SIArray1,
+}
+
+impl SimulationProfile {
+    fn new(coordinate: Vec<f64>, density: Array1<f64>) -> EosResult<Self> {
+        if coordinate.len() < 2 {
+            return Err(EosError::Error(String::from(
+                "The file does not contain a density profile.",
+            )));
+        }
+        Ok(Self {
+            coordinate: Array1::from_vec(coordinate) * ANGSTROM,
+            density: density * (1.0 / (NAV * ANGSTROM.powi(3))),
+        })
+    }
+
+    /// Read a density profile from the output of the LAMMPS command `fix ave/chunk`
+    /// with chunks from `compute chunk/atom bin/1d`.
+    ///
+    /// The file has to contain the columns `Coord1` and `density/number` in
+    /// `units real`. If the file contains multiple outputs, the density profile
+    /// is averaged over all of them.
+    pub fn from_lammps_chunk<P: AsRef<Path>>(file: P) -> EosResult<Self> {
+        let content = fs::read_to_string(file).map_err(|e| EosError::Error(e.to_string()))?;
+        let mut lines = content.lines().map(str::trim).filter(|l| !l.is_empty());
+        let mut columns = None;
+        let mut coordinate = Vec::new();
+        let mut density: Option<Array1<f64>> = None;
+        let mut outputs = 0;
+        while let Some(line) = lines.next() {
+            if let Some(header) = line.strip_prefix('#') {
+                let names: Vec<_> = header.split_whitespace().collect();
+                let z = names.iter().position(|&n| n == "Coord1");
+                let rho = names.iter().position(|&n| n == "density/number");
+                if let (Some(z), Some(rho)) = (z, rho) {
+                    columns = Some((z, rho));
+                }
+                continue;
+            }
+            let (z, rho) = columns.ok_or_else(|| {
+                EosError::Error(String::from(
+                    "The file does not contain the columns `Coord1` and `density/number`.",
+                ))
+            })?;
+            let chunks = match parse_values(line)?[..] {
+                [_, chunks, _] => chunks as usize,
+                _ => {
+                    return Err(EosError::Error(format!(
+                        "Invalid output header `{}`.",
+                        line
+                    )))
+                }
+            };
+            let mut z_output = Vec::with_capacity(chunks);
+            let mut rho_output = Array1::zeros(chunks);
+            for k in 0..chunks {
+                let line = lines.next().ok_or_else(|| {
+                    EosError::Error(String::from("The last output of the file is incomplete."))
+                })?;
+                let values = parse_values(line)?;
+                if values.len() <= z.max(rho) {
+                    return Err(EosError::Error(format!("Invalid line `{}`.", line)));
+                }
+                z_output.push(values[z]);
+                rho_output[k] = values[rho];
+            }
+            density = match density {
+                None => {
+                    coordinate = z_output;
+                    Some(rho_output)
+                }
+                Some(d) if d.len() == chunks => Some(d + rho_output),
+                Some(_) => {
+                    return Err(EosError::Error(String::from(
+                        "All outputs have to contain the same number of chunks.",
+                    )))
+                }
+            };
+            outputs += 1;
+        }
+        let density = density.unwrap_or_else(|| Array1::zeros(0));
+        Self::new(coordinate, density / outputs.max(1) as f64)
+    }
+
+    /// Read a density profile from a text file with whitespace separated columns,
+    /// e.g., as exported from RASPA.
+    ///
+    /// The first column contains the coordinates in Å and the column with the
+    /// (0-based) index `density_column` the number densities in 1/Å³. Lines
+    /// starting with `#` are ignored.
+    pub fn from_columns<P: AsRef<Path>>(file: P, density_column: usize) -> EosResult<Self> {
+        let content = fs::read_to_string(file).map_err(|e| EosError::Error(e.to_string()))?;
+        let mut coordinate = Vec::new();
+        let mut density = Vec::new();
+        for line in content
+            .lines()
+            .map(str::trim)
+            .filter(|l| !l.is_empty() && !l.starts_with('#'))
+        {
+            let values = parse_values(line)?;
+            if values.len() <= density_column {
+                return Err(EosError::Error(format!("Invalid line `{}`.", line)));
+            }
+            coordinate.push(values[0]);
+            density.push(values[density_column]);
+        }
+        Self::new(coordinate, Array1::from_vec(density))
+    }
+
+    /// Linearly interpolate the density profile to the given coordinates,
+    /// e.g., the grid of a DFT profile.
+    ///
+    /// Outside of the range of the simulation profile, the density is NaN.
+    pub fn interpolate(&self, coordinate: &SIArray1) -> EosResult<SIArray1> {
+        let unit = 1.0 / (NAV * ANGSTROM.powi(3));
+        let x = self.coordinate.to_reduced(ANGSTROM)?;
+        let y = self.density.to_reduced(unit)?;
+        let grid = coordinate.to_reduced(ANGSTROM)?;
+        let n = x.len();
+        Ok(
+            grid.mapv(|g| match (1..n).find(|&i| x[i - 1] <= g && g <= x[i]) {
+                Some(i) => y[i - 1] + (y[i] - y[i - 1]) * (g - x[i - 1]) / (x[i] - x[i - 1]),
+                None => f64::NAN,
+            }) * unit,
+        )
+    }
+}
+
+fn parse_values(line: &str) -> EosResult<Vec<f64>> {
+    line.split_whitespace()
+        .map(|v| {
+            v.parse()
+                .map_err(|_| EosError::Error(format!("Invalid line `{}`.", line)))
+        })
+        .collect()
+}
@@ -1,6 +1,6 @@
 //! Solvation free energies and pair correlaion functions.
 mod pair_correlation;
-pub use pair_correlation::{PairCorrelation, PairPotential};
+pub use pair_correlation::{PairCorrelation, PairCorrelationMatrix, PairPotential};
 
 #[cfg(feature = "rayon")]
 mod solvation_profile;
@@ -4,9 +4,11 @@ use crate::functional::{HelmholtzEnergyFunctional, DFT};
 use crate::profile::MAX_POTENTIAL;
 use crate::solver::DFTSolver;
 use crate::{Axis, DFTProfile, Grid};
-use feos_core::{Contributions, EosResult, EosUnit, State};
+use feos_core::{Contributions, EosError, EosResult, EosUnit, State};
 use ndarray::prelude::*;
-use quantity::si::{SINumber, SIUnit};
+use ndarray::stack;
+use num_dual::DualNum;
+use quantity::si::{SIArray1, SINumber, SIUnit};
 
 /// The underlying pair potential, that the Helmholtz energy functional
 /// models.
@@ -107,3 +109,96 @@ impl<F: HelmholtzEnergyFunctional + PairPotential> PairCorrelation<F> {
         Ok(self)
     }
 }
+
+/// Pair correlation functions of all pairs of components in a mixture.
+///
+/// Every row $i$ of the matrix $g_{ij}(r)$ is obtained from a separate test
+/// particle calculation with a particle of component $i$ fixed at the origin.
+pub struct PairCorrelationMatrix<F> {
+    pub pair_correlations: Vec<PairCorrelation<F>>,
+}
+
+impl<F> Clone for PairCorrelationMatrix<F> {
+    fn clone(&self) -> Self {
+        Self {
+            pair_correlations: self.pair_correlations.clone(),
+        }
+    }
+}
+
+impl<F: HelmholtzEnergyFunctional + PairPotential> PairCorrelationMatrix<F> {
+    pub fn new(bulk: &State<DFT<F>>, n_grid: usize, width: SINumber) -> EosResult<Self> {
+        let pair_correlations = (0..bulk.molefracs.len())
+            .map(|i| PairCorrelation::new(bulk, i, n_grid, width))
+            .collect::<EosResult<Vec<_>>>()?;
+        Ok(Self { pair_correlations })
+    }
+
+    pub fn solve_inplace(&mut self, solver: Option<&DFTSolver>, debug: bool) -> EosResult<()> {
+        for pair_correlation in self.pair_correlations.iter_mut() {
+            pair_correlation.solve_inplace(solver, debug)?;
+        }
+        Ok(())
+    }
+
+    pub fn solve(mut self, solver: Option<&DFTSolver>) -> EosResult<Self> {
+        self.solve_inplace(solver, false)?;
+        Ok(self)
+    }
+}
+
+impl<F> PairCorrelationMatrix<F> {
+    /// The radial grid of the pair correlation functions.
+    pub fn r(&self) -> SIArray1 {
+        self.pair_correlations[0].profile.r()
+    }
+
+    /// The pair correlation functions $g_{ij}(r)$ with the test particle $i$ in
+    /// the first, component $j$ in the second and the radius in the third axis.
+    pub fn pair_correlation_function(&self) -> Option<Array3<f64>> {
+        let g = self
+            .pair_correlations
+            .iter()
+            .map(|p| p.pair_correlation_function.as_ref().map(|g| g.view()))
+            .collect::<Option<Vec<_>>>()?;
+        stack(Axis(0), &g).ok()
+    }
+
+    /// Partial structure factors $S_{ij}(k)$ (Ashcroft-Langreth definition)
+    /// for the given wave numbers.
+    ///
+    /// The total correlation functions are symmetrized, i.e.,
+    /// $h_{ij}=\frac{1}{2}\left(g_{ij}+g_{ji}\right)-1$, and transformed
+    /// according to
+    /// $$S_{ij}(k)=\delta_{ij}+\sqrt{\rho_i\rho_j}\int h_{ij}(r)\frac{\sin kr}{kr}4\pi r^2\mathrm{d}r$$
+    pub fn structure_factor(&self, k: &SIArray1) -> EosResult<Array3<f64>> {
+        let g = self.pair_correlation_function().ok_or_else(|| {
+            EosError::Error(String::from(
+                "The pair correlation functions have to be solved first.",
+            ))
+        })?;
+        let profile = &self.pair_correlations[0].profile;
+        let r = profile.grid.grids()[0];
+        let weights = profile.grid.integration_weights()[0];
+        let rho = profile
+            .bulk
+            .partial_density
+            .to_reduced(SIUnit::reference_density())?;
+        let k = k.to_reduced(SIUnit::reference_length().powi(-1))?;
+
+        let n = rho.len();
+        let mut structure_factor = Array3::zeros((n, n, k.len()));
+        for i in 0..n {
+            for j in 0..n {
+                let h = (&g.slice(s![i, j, ..]) + &g.slice(s![j, i, ..])) * 0.5 - 1.0;
+                let h = h * weights;
+                for (l, &k_l) in k.iter().enumerate() {
+                    let h_k = (&h * &r.mapv(|r| (k_l * r).sph_j0())).sum();
+                    let delta = if i == j { 1.0 } else { 0.0 };
+                    structure_factor[(i, j, l)] = delta + (rho[i] * rho[j]).sqrt() * h_k;
+                }
+            }
+        }
+        Ok(structure_factor)
+    }
+}
@@ -4,10 +4,10 @@ use crate::functional::{HelmholtzEnergyFunctional, DFT};
 use crate::geometry::{Axis, Grid};
 use crate::profile::{DFTProfile, CUTOFF_RADIUS, MAX_POTENTIAL};
 use crate::solver::DFTSolver;
-use feos_core::{Contributions, EosResult, EosUnit, State};
+use feos_core::{Contributions, EosError, EosResult, EosUnit, State};
 use ndarray::prelude::*;
 use ndarray::Zip;
-use quantity::si::{SIArray2, SINumber, SIUnit};
+use quantity::si::{SIArray2, SINumber, SIUnit, BAR, METER, MOL, RGAS};
 
 /// Density profile and properties of a solute in a inhomogeneous bulk fluid.
 pub struct SolvationProfile<F: HelmholtzEnergyFunctional> {
@@ -50,6 +50,62 @@ impl<F: HelmholtzEnergyFunctional> SolvationProfile<F> {
     }
 }
 
+impl<F: HelmholtzEnergyFunctional> SolvationProfile<F> {
+    fn solved_solvation_free_energy(&self) -> EosResult<SINumber> {
+        self.solvation_free_energy.ok_or_else(|| {
+            EosError::Error(String::from(
+                "The solvation profile has to be solved first.",
+            ))
+        })
+    }
+
+    /// Henry's law constant $H=\rho RT\exp\left(\frac{\Delta G^*}{RT}\right)$ of the
+    /// solute at infinite dilution in the solvent, with the total density $\rho$ of
+    /// the solvent and the solvation free energy $\Delta G^*$.
+    pub fn henry_constant(&self) -> EosResult<SINumber> {
+        let rt = RGAS * self.profile.temperature;
+        let dg = self.solved_solvation_free_energy()?;
+        Ok(self.profile.bulk.density * rt * (dg / rt).into_value()?.exp())
+    }
+
+    /// Solvation Gibbs energy for the transfer of the solute from an ideal gas at
+    /// the standard pressure $p^\circ$ to an ideal dilute solution at the standard
+    /// concentration $c^\circ$:
+    /// $$\Delta G^\circ=\Delta G^*+RT\ln\frac{c^\circ RT}{p^\circ}$$
+    ///
+    /// Defaults to $p^\circ=1\,\mathrm{bar}$ and $c^\circ=1\,\mathrm{mol/l}$.
+    pub fn standard_solvation_gibbs_energy(
+        &self,
+        standard_pressure: Option<SINumber>,
+        standard_concentration: Option<SINumber>,
+    ) -> EosResult<SINumber> {
+        let rt = RGAS * self.profile.temperature;
+        let p0 = standard_pressure.unwrap_or(BAR);
+        let c0 = standard_concentration.unwrap_or(1000.0 * MOL / METER.powi(3));
+        Ok(self.solved_solvation_free_energy()? + rt * (c0 * rt / p0).into_value()?.ln())
+    }
+
+    /// Partition coefficient $K=\frac{c}{c_\mathrm{other}}=\exp\left(-\frac{\Delta G^*-\Delta G^*_\mathrm{other}}{RT}\right)$
+    /// of the solute between this solvent and the solvent of `other`.
+    ///
+    /// Both profiles have to describe the same solute at the same temperature.
+    /// The logarithmic partition coefficient (e.g., $\log P$ for octanol and
+    /// water) is the decadic logarithm of the result.
+    pub fn partition_coefficient<G: HelmholtzEnergyFunctional>(
+        &self,
+        other: &SolvationProfile<G>,
+    ) -> EosResult<f64> {
+        let t = self.profile.temperature;
+        if ((other.profile.temperature - t) / t).into_value()?.abs() > 1e-10 {
+            return Err(EosError::Error(String::from(
+                "The partition coefficient requires solvation profiles at the same temperature.",
+            )));
+        }
+        let ddg = other.solved_solvation_free_energy()? - self.solved_solvation_free_energy()?;
+        Ok((ddg / (RGAS * t)).into_value()?.exp())
+    }
+}
+
 impl<F: HelmholtzEnergyFunctional + FluidParameters> SolvationProfile<F> {
     pub fn new(
         bulk: &State<DFT<F>>,
@@ -1,5 +1,6 @@
+use crate::checkpoint::{AndersonHistory, Iterate};
 use crate::{DFTProfile, HelmholtzEnergyFunctional, WeightFunction, WeightFunctionShape};
-use feos_core::{log_iter, log_result, EosError, EosResult, EosUnit, Verbosity};
+use feos_core::{log_iter, log_result, EosError, EosResult, EosUnit, ProgressCallback, Verbosity};
 use ndarray::prelude::*;
 use ndarray::RemoveAxis;
 use num_dual::linalg::LU;
@@ -11,6 +12,7 @@ use quantity::si::{SIArray1, SECOND};
 use std::collections::VecDeque;
 use std::fmt;
 use std::ops::AddAssign;
+use std::sync::Arc;
 use std::time::{Duration, Instant};
 
 const DEFAULT_PARAMS_PICARD: PicardIteration = PicardIteration {
@@ -18,6 +20,7 @@ const DEFAULT_PARAMS_PICARD: PicardIteration = PicardIteration {
     max_iter: 500,
     tol: 1e-11,
     damping_coefficient: None,
+    stop_on_stagnation: false,
 };
 const DEFAULT_PARAMS_ANDERSON_LOG: AndersonMixing = AndersonMixing {
     log: true,
@@ -38,7 +41,12 @@ const DEFAULT_PARAMS_NEWTON: Newton = Newton {
     max_iter: 50,
     max_iter_gmres: 200,
     tol: 1e-11,
+    backtracking: false,
 };
+const DEFAULT_SWITCHING_TOL: f64 = 1e-3;
+const STAGNATION_ITERATIONS: usize = 10;
+const STAGNATION_RATIO: f64 = 0.5;
+const MAX_ITER_BACKTRACKING: usize = 8;
 
 #[derive(Clone, Copy, Debug)]
 struct PicardIteration {
@@ -46,6 +54,7 @@ struct PicardIteration {
     max_iter: usize,
     tol: f64,
     damping_coefficient: Option<f64>,
+    stop_on_stagnation: bool,
 }
 
 #[derive(Clone, Copy, Debug)]
@@ -63,6 +72,21 @@ struct Newton {
     max_iter: usize,
     max_iter_gmres: usize,
     tol: f64,
+    backtracking: bool,
+}
+
+impl Newton {
+    /// The enabled options of the solver for the markdown representation.
+    fn flags(&self) -> String {
+        let mut flags = String::new();
+        if self.log {
+            flags += "log, ";
+        }
+        if self.backtracking {
+            flags += "backtracking, ";
+        }
+        flags
+    }
 }
 
 #[derive(Clone, Copy)]
@@ -70,6 +94,7 @@ enum DFTAlgorithm {
     PicardIteration(PicardIteration),
     AndersonMixing(AndersonMixing),
     Newton(Newton),
+    PicardNewton(PicardIteration, Newton),
 }
 
 /// Settings for the DFT solver.
@@ -77,6 +102,7 @@ enum DFTAlgorithm {
 pub struct DFTSolver {
     algorithms: Vec<DFTAlgorithm>,
     pub verbosity: Verbosity,
+    pub callback: Option<Arc<dyn ProgressCallback>>,
 }
 
 impl Default for DFTSolver {
@@ -87,6 +113,7 @@ impl Default for DFTSolver {
                 DFTAlgorithm::AndersonMixing(DEFAULT_PARAMS_ANDERSON),
             ],
             verbosity: Default::default(),
+            callback: None,
         }
     }
 }
@@ -96,6 +123,23 @@ impl DFTSolver {
         Self {
             algorithms: vec![],
             verbosity: verbosity.unwrap_or_default(),
+            callback: None,
+        }
+    }
+
+    /// Report the residual of every iteration to `callback`, which can
+    /// cancel the calculation by returning `false`.
+    pub fn callback(mut self, callback: Arc<dyn ProgressCallback>) -> Self {
+        self.callback = Some(callback);
+        self
+    }
+
+    /// Report the progress of an outer loop (e.g., the state points of an
+    /// isotherm) to the callback, if there is one.
+    pub(crate) fn check_progress(&self, task: &str, iteration: usize) -> EosResult<()> {
+        match &self.callback {
+            Some(callback) => callback.check(task, iteration, f64::NAN),
+            None => Ok(()),
         }
     }
 
@@ -133,54 +177,106 @@ impl DFTSolver {
         self
     }
 
+    /// Add a Newton solver.
+    ///
+    /// With `backtracking`, the Newton step is halved until the residual
+    /// decreases. If no step decreases the residual, the solver stops
+    /// without updating the profile.
     pub fn newton(
         mut self,
         log: Option<bool>,
         max_iter: Option<usize>,
         max_iter_gmres: Option<usize>,
         tol: Option<f64>,
+        backtracking: Option<bool>,
     ) -> Self {
         let mut params = DEFAULT_PARAMS_NEWTON;
         params.log = log.unwrap_or(params.log);
         params.max_iter = max_iter.unwrap_or(params.max_iter);
         params.max_iter_gmres = max_iter_gmres.unwrap_or(params.max_iter_gmres);
         params.tol = tol.unwrap_or(params.tol);
+        params.backtracking = backtracking.unwrap_or(params.backtracking);
         self.algorithms.push(DFTAlgorithm::Newton(params));
         self
     }
+
+    /// Add a Picard iteration that switches automatically to a Newton solver.
+    ///
+    /// The Picard iteration (with line search) is used until the residual falls
+    /// below `switching_tol` or the convergence of the Picard iteration stagnates.
+    /// Afterwards, the profile is converged to `tol` with the Newton solver
+    /// (optionally with `backtracking`, see [DFTSolver::newton]).
+    pub fn picard_newton(
+        mut self,
+        log: Option<bool>,
+        max_iter: Option<usize>,
+        max_iter_gmres: Option<usize>,
+        tol: Option<f64>,
+        switching_tol: Option<f64>,
+        backtracking: Option<bool>,
+    ) -> Self {
+        let mut picard = DEFAULT_PARAMS_PICARD;
+        picard.log = log.unwrap_or(picard.log);
+        picard.tol = switching_tol.unwrap_or(DEFAULT_SWITCHING_TOL);
+        picard.stop_on_stagnation = true;
+        let mut newton = DEFAULT_PARAMS_NEWTON;
+        newton.log = log.unwrap_or(newton.log);
+        newton.max_iter = max_iter.unwrap_or(newton.max_iter);
+        newton.max_iter_gmres = max_iter_gmres.unwrap_or(newton.max_iter_gmres);
+        newton.tol = tol.unwrap_or(newton.tol);
+        newton.backtracking = backtracking.unwrap_or(newton.backtracking);
+        self.algorithms
+            .push(DFTAlgorithm::PicardNewton(picard, newton));
+        self
+    }
 }
 
-/// A log that stores the residuals and execution time of DFT solvers.
+/// A log that stores the residuals, step sizes, damping factors and execution time of DFT solvers.
 #[derive(Clone)]
 pub struct DFTSolverLog {
     verbosity: Verbosity,
+    callback: Option<Arc<dyn ProgressCallback>>,
     start_time: Instant,
     residual: Vec<f64>,
+    step_size: Vec<f64>,
+    damping: Vec<f64>,
     time: Vec<Duration>,
     solver: Vec<&'static str>,
+    anderson_history: Option<AndersonHistory>,
 }
 
 impl DFTSolverLog {
-    pub(crate) fn new(verbosity: Verbosity) -> Self {
+    pub(crate) fn new(verbosity: Verbosity, callback: Option<Arc<dyn ProgressCallback>>) -> Self {
         log_iter!(
             verbosity,
             "solver                 | iter |    time    | residual "
         );
         Self {
             verbosity,
+            callback,
             start_time: Instant::now(),
             residual: Vec::new(),
+            step_size: Vec::new(),
+            damping: Vec::new(),
             time: Vec::new(),
             solver: Vec::new(),
+            anderson_history: None,
         }
     }
 
-    fn add_residual(&mut self, solver: &'static str, iteration: usize, residual: f64) {
+    fn add_residual(
+        &mut self,
+        solver: &'static str,
+        iteration: usize,
+        residual: f64,
+    ) -> EosResult<usize> {
         if iteration == 0 {
             log_iter!(self.verbosity, "{:-<59}", "");
         }
         self.solver.push(solver);
         self.residual.push(residual);
+        self.step_size.push(f64::NAN);
+        self.damping.push(f64::NAN);
         let time = self.start_time.elapsed();
         self.time.push(self.start_time.elapsed());
         log_iter!(
@@ -191,12 +287,33 @@ impl DFTSolverLog {
             time.as_secs_f64() * SECOND,
             residual,
         );
+        if let Some(callback) = &self.callback {
+            callback.check(solver, iteration, residual)?;
+        }
+        Ok(self.residual.len() - 1)
+    }
+
+    /// Store the step size and damping factor of the iteration with the given index.
+    fn add_step(&mut self, index: usize, step_size: f64, damping: f64) {
+        self.step_size[index] = step_size;
+        self.damping[index] = damping;
     }
 
     pub fn residual(&self) -> ArrayView1<f64> {
         (&self.residual).into()
     }
 
+    /// The root mean square of the update of the (logarithmic) density
+    /// profile in every iteration (NaN if not available).
+    pub fn step_size(&self) -> ArrayView1<f64> {
+        (&self.step_size).into()
+    }
+
+    /// The damping factor (relative step length) of every iteration (NaN if not available).
+    pub fn damping(&self) -> ArrayView1<f64> {
+        (&self.damping).into()
+    }
+
     pub fn time(&self) -> SIArray1 {
         self.time.iter().map(|t| t.as_secs_f64() * SECOND).collect()
     }
@@ -204,6 +321,16 @@ impl DFTSolverLog {
     pub fn solver(&self) -> &[&'static str] {
         &self.solver
     }
+
+    /// The history of the Anderson mixing, if it was the last solver.
+    pub(crate) fn anderson_history(&self) -> Option<&AndersonHistory> {
+        self.anderson_history.as_ref()
+    }
+}
+
+/// Root mean square of the elements of an array.
+fn rms<D: Dimension>(x: &Array<f64, D>) -> f64 {
+    (x.mapv(|x| x * x).sum() / x.len() as f64).sqrt()
 }
 
 impl<D: Dimension, F: HelmholtzEnergyFunctional> DFTProfile<D, F>
@@ -220,17 +347,33 @@ where
     ) -> EosResult<()> {
         let mut converged = false;
         let mut iterations = 0;
-        let mut log = DFTSolverLog::new(solver.verbosity);
+        let mut log = DFTSolverLog::new(solver.verbosity, solver.callback.clone());
+        let mut history = self.anderson_history.take();
         for algorithm in &solver.algorithms {
-            let (conv, iter) = match algorithm {
+            log.anderson_history = None;
+            let result = match algorithm {
                 DFTAlgorithm::PicardIteration(picard) => {
                     self.solve_picard(*picard, rho, rho_bulk, &mut log)
                 }
                 DFTAlgorithm::AndersonMixing(anderson) => {
-                    self.solve_anderson(*anderson, rho, rho_bulk, &mut log)
+                    self.solve_anderson(*anderson, rho, rho_bulk, &mut history, &mut log)
                 }
                 DFTAlgorithm::Newton(newton) => self.solve_newton(*newton, rho, rho_bulk, &mut log),
-            }?;
+                DFTAlgorithm::PicardNewton(picard, newton) => {
+                    let (_, iter_picard) = self.solve_picard(*picard, rho, rho_bulk, &mut log)?;
+                    let (conv, iter_newton) =
+                        self.solve_newton(*newton, rho, rho_bulk, &mut log)?;
+                    Ok((conv, iter_picard + iter_newton))
+                }
+            };
+            // keep the log of failed calculations for diagnostics
+            let (conv, iter) = match result {
+                Ok(result) => result,
+                Err(e) => {
+                    self.solver_log = Some(log);
+                    return Err(e);
+                }
+            };
             converged = conv;
             iterations += iter;
         }
@@ -262,22 +405,33 @@ where
             "Picard iteration"
         };
 
+        let mut residuals = Vec::with_capacity(picard.max_iter);
         for k in 0..picard.max_iter {
             // calculate residual
             let (res, res_bulk, res_norm, _, _) =
                 self.euler_lagrange_equation(&*rho, &*rho_bulk, picard.log)?;
-            log.add_residual(solver, k, res_norm);
+            let index = log.add_residual(solver, k, res_norm)?;
 
             // check for convergence
             if res_norm < picard.tol {
                 return Ok((true, k));
             }
 
+            // check for stagnation
+            if picard.stop_on_stagnation
+                && k >= STAGNATION_ITERATIONS
+                && res_norm > STAGNATION_RATIO * residuals[k - STAGNATION_ITERATIONS]
+            {
+                return Ok((false, k));
+            }
+            residuals.push(res_norm);
+
             // apply line search or constant damping
             let damping_coefficient = picard.damping_coefficient.map_or_else(
                 || self.line_search(rho, &res, rho_bulk, res_norm, picard.log),
                 Ok,
             )?;
+            log.add_step(index, damping_coefficient * rms(&res), damping_coefficient);
 
             // update solution
             if picard.log {
@@ -358,6 +512,7 @@ where
         anderson: AndersonMixing,
         rho: &mut Array<f64, D::Larger>,
         rho_bulk: &mut Array1<f64>,
+        history: &mut Option<AndersonHistory>,
         log: &mut DFTSolverLog,
     ) -> EosResult<(bool, usize)> {
         let solver = if anderson.log {
@@ -366,11 +521,19 @@ where
             "Anderson mixing"
         };
 
-        let mut resm = VecDeque::with_capacity(anderson.mmax);
-        let mut rhom = VecDeque::with_capacity(anderson.mmax);
+        let mut resm: VecDeque<Iterate<D::Larger>> = VecDeque::with_capacity(anderson.mmax);
+        let mut rhom: VecDeque<Iterate<D::Larger>> = VecDeque::with_capacity(anderson.mmax);
         let mut r;
         let mut alpha;
 
+        // resume from a stored history
+        if history.as_ref().map_or(false, |h| h.log == anderson.log) {
+            let (rho_history, res_history) = history.take().unwrap().iterates()?;
+            let skip = rho_history.len().saturating_sub(anderson.mmax);
+            rhom.extend(rho_history.into_iter().skip(skip));
+            resm.extend(res_history.into_iter().skip(skip));
+        }
+
         for k in 0..anderson.max_iter {
             // drop old values
             if resm.len() == anderson.mmax {
@@ -382,15 +545,17 @@ where
             // calculate residual
             let (res, res_bulk, res_norm, _, _) =
                 self.euler_lagrange_equation(&*rho, &*rho_bulk, anderson.log)?;
-            log.add_residual(solver, k, res_norm);
+            let index = log.add_residual(solver, k, res_norm)?;
 
             // check for convergence
             if res_norm < anderson.tol {
+                log.anderson_history =
+                    Some(AndersonHistory::new(anderson.log, rhom.iter(), resm.iter()));
                 return Ok((true, k));
             }
 
             // save residual and x value
-            resm.push_back((res, res_bulk, res_norm));
+            resm.push_back((res, res_bulk));
             if anderson.log {
                 rhom.push_back((rho.mapv(f64::ln), rho_bulk.mapv(f64::ln)));
             } else {
@@ -400,8 +565,8 @@ where
             // calculate alpha
             r = Array::from_shape_fn((m + 1, m + 1), |(i, j)| match (i == m, j == m) {
                 (false, false) => {
-                    let (resi, resi_bulk, _) = &resm[i];
-                    let (resj, resj_bulk, _) = &resm[j];
+                    let (resi, resi_bulk) = &resm[i];
+                    let (resj, resj_bulk) = &resm[j];
                     (resi * resj).sum() + (resi_bulk * resj_bulk).sum()
                 }
                 (true, true) => 0.0,
@@ -416,11 +581,14 @@ where
             rho_bulk.fill(0.0);
             for i in 0..m {
                 let (rhoi, rhoi_bulk) = &rhom[i];
-                let (resi, resi_bulk, _) = &resm[i];
+                let (resi, resi_bulk) = &resm[i];
                 *rho += &(alpha[i] * (rhoi + &(anderson.damping_coefficient * resi)));
                 *rho_bulk +=
                     &(alpha[i] * (rhoi_bulk + &(anderson.damping_coefficient * resi_bulk)));
             }
+            let (rho_old, _) = &rhom[m - 1];
+            let step_size = rms(&(&*rho - rho_old));
+            log.add_step(index, step_size, anderson.damping_coefficient);
             if anderson.log {
                 rho.mapv_inplace(f64::exp);
                 rho_bulk.mapv_inplace(f64::exp);
@@ -429,6 +597,7 @@ where
                 rho_bulk.mapv_inplace(f64::abs);
             }
         }
+        log.anderson_history = Some(AndersonHistory::new(anderson.log, rhom.iter(), resm.iter()));
         Ok((false, anderson.max_iter))
     }
 
@@ -440,11 +609,12 @@ where
         log: &mut DFTSolverLog,
     ) -> EosResult<(bool, usize)> {
         let solver = if newton.log { "Newton (log)" } else { "Newton" };
+
+        // calculate initial residual
+        let (mut res, _, mut res_norm, mut exp_dfdrho, mut rho_p) =
+            self.euler_lagrange_equation(rho, rho_bulk, newton.log)?;
         for k in 0..newton.max_iter {
-            // calculate initial residual
-            let (res, _, res_norm, exp_dfdrho, rho_p) =
-                self.euler_lagrange_equation(rho, rho_bulk, newton.log)?;
-            log.add_residual(solver, k, res_norm);
+            let index = log.add_residual(solver, k, res_norm)?;
 
             // check convergence
             if res_norm < newton.tol {
@@ -467,9 +637,43 @@ where
                 delta_rho + (delta_functional_derivative - delta_i) * rho
             };
 
-            // update solution
-            let lhs = if newton.log { &*rho * res } else { res };
-            *rho += &Self::gmres(rhs, &lhs, newton.max_iter_gmres, newton.tol * 1e-2, log)?;
+            // calculate Newton step
+            let lhs = if newton.log {
+                &*rho * &res
+            } else {
+                res.clone()
+            };
+            let delta_rho = Self::gmres(rhs, &lhs, newton.max_iter_gmres, newton.tol * 1e-2, log)?;
+
+            if !newton.backtracking {
+                *rho += &delta_rho;
+                (res, _, res_norm, exp_dfdrho, rho_p) =
+                    self.euler_lagrange_equation(rho, rho_bulk, newton.log)?;
+                log.add_step(index, rms(&delta_rho), 1.0);
+                continue;
+            }
+
+            // reduce the step size until the residual decreases
+            let mut alpha = 1.0;
+            let mut accepted = false;
+            for _ in 0..MAX_ITER_BACKTRACKING {
+                let rho_new = &*rho + &(alpha * &delta_rho);
+                match self.euler_lagrange_equation(&rho_new, rho_bulk, newton.log) {
+                    Ok(eval) if eval.2 < res_norm => {
+                        *rho = rho_new;
+                        (res, _, res_norm, exp_dfdrho, rho_p) = eval;
+                        log.add_step(index, alpha * rms(&delta_rho), alpha);
+                        accepted = true;
+                        break;
+                    }
+                    _ => alpha *= 0.5,
+                }
+            }
+
+            // no step decreases the residual
+            if !accepted {
+                return Ok((false, k));
+            }
         }
 
         Ok((false, newton.max_iter))
@@ -494,7 +698,7 @@ where
 
         gamma[0] = (r0 * r0).sum().sqrt();
         v.push(r0 / gamma[0]);
-        log.add_residual("GMRES", 0, gamma[0]);
+        log.add_residual("GMRES", 0, gamma[0])?;
 
         let mut iter = 0;
         for j in 0..max_iter {
@@ -530,7 +734,7 @@ where
             gamma[j] *= c[j + 1];
 
             // check for convergence
-            log.add_residual("GMRES", j + 1, gamma[j + 1].abs());
+            log.add_residual("GMRES", j + 1, gamma[j + 1].abs())?;
             if gamma[j + 1].abs() >= tol && j + 1 < max_iter {
                 v.push(q / h[(j + 1, j)]);
                 iter += 1;
@@ -719,6 +923,7 @@ impl fmt::Display for DFTAlgorithm {
             Self::PicardIteration(picard) => write!(f, "{picard:?}"),
             Self::AndersonMixing(anderson) => write!(f, "{anderson:?}"),
             Self::Newton(newton) => write!(f, "{newton:?}"),
+            Self::PicardNewton(picard, newton) => write!(f, "{picard:?} -> {newton:?}"),
         }
     }
 }
@@ -764,7 +969,17 @@ impl DFTSolver {
                 DFTAlgorithm::Newton(newton) => (
                     format!(
                         "Newton ({}max_iter_gmres={})",
-                        if newton.log { "log, " } else { "" },
+                        newton.flags(),
+                        newton.max_iter_gmres
+                    ),
+                    newton.max_iter,
+                    newton.tol,
+                ),
+                DFTAlgorithm::PicardNewton(picard, newton) => (
+                    format!(
+                        "Picard iteration + Newton ({}switching_tol={:e}, max_iter_gmres={})",
+                        newton.flags(),
+                        picard.tol,
                         newton.max_iter_gmres
                     ),
                     newton.max_iter,
@@ -123,20 +123,27 @@ where
 
             let na = self.association_parameters.na[0];
             let nb = self.association_parameters.nb[0];
+            let nc = self.association_parameters.nc[0];
             let f = |x: N| x.ln() - x * 0.5 + 0.5;
-            if nb > 0.0 {
+            let mut phi = if nb > 0.0 {
                 // no cross association, two association sites
                 let xa = deltarho.mapv(|d| Self::assoc_site_frac_ab(d, na, nb));
                 let xb = (&xa - 1.0) * (na / nb) + 1.0;
-                Ok((xa.mapv(f) * na + xb.mapv(f) * nb) * rho0.index_axis(Axis(0), 0))
+                xa.mapv(f) * na + xb.mapv(f) * nb
             } else {
                 // no cross association, one association site
                 let xa = deltarho.mapv(|d| Self::assoc_site_frac_a(d, na));
 
-                Ok(xa.mapv(f) * na * rho0.index_axis(Axis(0), 0))
+                xa.mapv(f) * na
+            };
+            if nc > 0.0 {
+                // self-associating sites of type C
+                let xc = deltarho.mapv(|d| Self::assoc_site_frac_a(d, nc));
+                phi = phi + xc.mapv(f) * nc;
             }
+            Ok(phi * rho0.index_axis(Axis(0), 0))
         } else {
-            let mut x: Array1<f64> = Array::from_elem(2 * nassoc, 0.2);
+            let mut x: Array1<f64> = Array::from_elem(3 * nassoc, 0.2);
             Ok(rho0
                 .view()
                 .into_shape([nassoc, rho0.len() / nassoc])
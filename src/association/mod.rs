@@ -30,17 +30,38 @@ pub struct AssociationRecord {
     /// \# of association sites of type B
     #[serde(skip_serializing_if = "Option::is_none")]
     pub nb: Option<f64>,
+    /// \# of association sites of type C
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub nc: Option<f64>,
 }
 
 impl AssociationRecord {
-    pub fn new(kappa_ab: f64, epsilon_k_ab: f64, na: Option<f64>, nb: Option<f64>) -> Self {
+    pub fn new(
+        kappa_ab: f64,
+        epsilon_k_ab: f64,
+        na: Option<f64>,
+        nb: Option<f64>,
+        nc: Option<f64>,
+    ) -> Self {
         Self {
             kappa_ab,
             epsilon_k_ab,
             na,
             nb,
+            nc,
         }
     }
+
+    /// Return a record with all numbers of association sites multiplied by `n`.
+    pub fn scale_sites(&self, n: f64) -> Self {
+        Self::new(
+            self.kappa_ab,
+            self.epsilon_k_ab,
+            Some(self.na.unwrap_or(1.0) * n),
+            Some(self.nb.unwrap_or(1.0) * n),
+            self.nc.map(|nc| nc * n),
+        )
+    }
 }
 
 impl fmt::Display for AssociationRecord {
@@ -48,12 +69,53 @@ impl fmt::Display for AssociationRecord {
         write!(f, "AssociationRecord(kappa_ab={}", self.kappa_ab)?;
         write!(f, ", epsilon_k_ab={}", self.epsilon_k_ab)?;
         write!(f, ", na={}", self.na.unwrap_or(1.0))?;
-        write!(f, ", nb={})", self.nb.unwrap_or(1.0))
+        write!(f, ", nb={}", self.nb.unwrap_or(1.0))?;
+        if let Some(nc) = self.nc {
+            write!(f, ", nc={}", nc)?;
+        }
+        write!(f, ")")
+    }
+}
+
+/// Binary association parameters that replace the combining rules
+/// for the cross-association between two components.
+#[derive(Serialize, Deserialize, Clone, Copy, Default)]
+pub struct BinaryAssociationRecord {
+    /// Cross-association volume parameter
+    pub kappa_ab: f64,
+    /// Cross-association energy parameter in units of Kelvin
+    pub epsilon_k_ab: f64,
+}
+
+impl BinaryAssociationRecord {
+    pub fn new(kappa_ab: f64, epsilon_k_ab: f64) -> Self {
+        Self {
+            kappa_ab,
+            epsilon_k_ab,
+        }
+    }
+}
+
+impl fmt::Display for BinaryAssociationRecord {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "BinaryAssociationRecord(kappa_ab={}", self.kappa_ab)?;
+        write!(f, ", epsilon_k_ab={})", self.epsilon_k_ab)
     }
 }
 
 /// Parameter set required for the SAFT association Helmoltz energy
 /// contribution and functional.
+///
+/// Every segment (or component) can carry an arbitrary number of
+/// association site types, each with individual association parameters.
+/// Sites of type A associate with sites of type B, sites of type C
+/// associate with all other sites of type C.
+///
+/// The cross-association parameters are obtained from combining rules,
+/// unless they are specified explicitly in binary records. A binary record
+/// also allows the induced association of a component that does not
+/// self-associate (i.e., with vanishing pure component association
+/// parameters) but declares association sites.
 #[derive(Clone)]
 pub struct AssociationParameters {
     component_index: Array1<usize>,
@@ -64,12 +126,14 @@ pub struct AssociationParameters {
     pub epsilon_k_aibj: Array2<f64>,
     pub na: Array1<f64>,
     pub nb: Array1<f64>,
+    pub nc: Array1<f64>,
 }
 
 impl AssociationParameters {
     pub fn new(
-        records: &[Option<AssociationRecord>],
+        records: &[Vec<AssociationRecord>],
         sigma: &Array1<f64>,
+        binary_records: &[([usize; 2], BinaryAssociationRecord)],
         component_index: Option<&Array1<usize>>,
     ) -> Self {
         let mut assoc_comp = Vec::new();
@@ -78,27 +142,43 @@ impl AssociationParameters {
         let mut epsilon_k_ab = Vec::new();
         let mut na = Vec::new();
         let mut nb = Vec::new();
+        let mut nc = Vec::new();
 
-        for (i, record) in records.iter().enumerate() {
-            if let Some(record) = record.as_ref() {
-                if record.kappa_ab > 0.0 && record.epsilon_k_ab > 0.0 {
+        for (i, records) in records.iter().enumerate() {
+            let induced = binary_records.iter().any(|([k, l], _)| *k == i || *l == i);
+            for record in records {
+                if (record.kappa_ab > 0.0 && record.epsilon_k_ab > 0.0) || induced {
                     assoc_comp.push(i);
                     sigma_assoc.push(sigma[i]);
                     kappa_ab.push(record.kappa_ab);
                     epsilon_k_ab.push(record.epsilon_k_ab);
                     na.push(record.na.unwrap_or(1.0));
                     nb.push(record.nb.unwrap_or(1.0));
+                    nc.push(record.nc.unwrap_or(0.0));
                 }
             }
         }
 
-        let sigma3_kappa_aibj = Array2::from_shape_fn([kappa_ab.len(); 2], |(i, j)| {
+        let mut sigma3_kappa_aibj = Array2::from_shape_fn([kappa_ab.len(); 2], |(i, j)| {
             (sigma_assoc[i] * sigma_assoc[j]).powf(1.5) * (kappa_ab[i] * kappa_ab[j]).sqrt()
         });
-        let epsilon_k_aibj = Array2::from_shape_fn([epsilon_k_ab.len(); 2], |(i, j)| {
+        let mut epsilon_k_aibj = Array2::from_shape_fn([epsilon_k_ab.len(); 2], |(i, j)| {
             0.5 * (epsilon_k_ab[i] + epsilon_k_ab[j])
         });
 
+        // explicit cross-association parameters
+        for ([k, l], record) in binary_records {
+            for (i, &ci) in assoc_comp.iter().enumerate() {
+                for (j, &cj) in assoc_comp.iter().enumerate() {
+                    if (ci, cj) == (*k, *l) || (ci, cj) == (*l, *k) {
+                        sigma3_kappa_aibj[(i, j)] =
+                            (sigma_assoc[i] * sigma_assoc[j]).powf(1.5) * record.kappa_ab;
+                        epsilon_k_aibj[(i, j)] = record.epsilon_k_ab;
+                    }
+                }
+            }
+        }
+
         Self {
             component_index: component_index
                 .cloned()
@@ -110,6 +190,7 @@ impl AssociationParameters {
             epsilon_k_aibj,
             na: Array1::from_vec(na),
             nb: Array1::from_vec(nb),
+            nc: Array1::from_vec(nc),
         }
     }
 }
@@ -217,18 +298,27 @@ impl<D: DualNum<f64> + ScalarOperand, P: HardSphereProperties> HelmholtzEnergyDu
 
             let na = self.association_parameters.na[0];
             let nb = self.association_parameters.nb[0];
-            if nb > 0.0 {
+            let nc = self.association_parameters.nc[0];
+            let f = |x: D| x.ln() - x * 0.5 + 0.5;
+            let a_ab = if nb > 0.0 {
                 // no cross association, two association sites
                 let xa = Self::assoc_site_frac_ab(deltarho, na, nb);
                 let xb = (xa - 1.0) * (na / nb) + 1.0;
 
-                state.moles[c] * ((xa.ln() - xa * 0.5 + 0.5) * na + (xb.ln() - xb * 0.5 + 0.5) * nb)
+                f(xa) * na + f(xb) * nb
             } else {
                 // no cross association, one association site
                 let xa = Self::assoc_site_frac_a(deltarho, na);
 
-                state.moles[c] * (xa.ln() - xa * 0.5 + 0.5) * na
-            }
+                f(xa) * na
+            };
+            let a_c = if nc > 0.0 {
+                // self-associating sites of type C
+                f(Self::assoc_site_frac_a(deltarho, nc)) * nc
+            } else {
+                D::zero()
+            };
+            state.moles[c] * (a_ab + a_c)
         }
     }
 }
@@ -284,12 +374,13 @@ impl<P: HardSphereProperties> Association<P> {
         // extract parameters of associating components
         let na = &self.association_parameters.na;
         let nb = &self.association_parameters.nb;
+        let nc = &self.association_parameters.nc;
 
         // cross-association according to Michelsen2006
         // initialize monomer fraction
         let mut x = match &x0 {
             Some(x0) => (*x0).clone(),
-            None => Array::from_elem(2 * nassoc, 0.2),
+            None => Array::from_elem(3 * nassoc, 0.2),
         };
 
         for k in 0..max_iter {
@@ -299,6 +390,7 @@ impl<P: HardSphereProperties> Association<P> {
                 &delta.map(D::re),
                 na,
                 nb,
+                nc,
                 &density.map(D::re),
                 tol,
             )? {
@@ -312,7 +404,16 @@ impl<P: HardSphereProperties> Association<P> {
         // calculate derivatives
         let mut x_dual = x.mapv(D::from);
         for _ in 0..D::NDERIV {
-            Self::newton_step_cross_association(nassoc, &mut x_dual, &delta, na, nb, density, tol)?;
+            Self::newton_step_cross_association(
+                nassoc,
+                &mut x_dual,
+                &delta,
+                na,
+                nb,
+                nc,
+                density,
+                tol,
+            )?;
         }
 
         // save monomer fraction
@@ -322,27 +423,31 @@ impl<P: HardSphereProperties> Association<P> {
 
         // Helmholtz energy density
         let xa = x_dual.slice(s![..nassoc]);
-        let xb = x_dual.slice(s![nassoc..]);
+        let xb = x_dual.slice(s![nassoc..2 * nassoc]);
+        let xc = x_dual.slice(s![2 * nassoc..]);
         let f = |x: D| x.ln() - x * 0.5 + 0.5;
-        Ok((density * (xa.mapv(f) * na + xb.mapv(f) * nb)).sum())
+        Ok((density * (xa.mapv(f) * na + xb.mapv(f) * nb + xc.mapv(f) * nc)).sum())
     }
 
+    #[allow(clippy::too_many_arguments)]
     fn newton_step_cross_association<S: Data<Elem = D>, D: DualNum<f64> + ScalarOperand>(
         nassoc: usize,
         x: &mut Array1<D>,
         delta: &Array2<D>,
         na: &Array1<f64>,
         nb: &Array1<f64>,
+        nc: &Array1<f64>,
         rho: &ArrayBase<S, Ix1>,
         tol: f64,
     ) -> Result<bool, EosError> {
         // gradient
         let mut g = x.map(D::recip);
         // Hessian
-        let mut h: Array2<D> = Array::zeros((2 * nassoc, 2 * nassoc));
+        let mut h: Array2<D> = Array::zeros((3 * nassoc, 3 * nassoc));
 
         // split x array
-        let (xa, xb) = x.view().split_at(Axis(0), nassoc);
+        let (xa, xbc) = x.view().split_at(Axis(0), nassoc);
+        let (xb, xc) = xbc.split_at(Axis(0), nassoc);
 
         // calculate gradients and approximate Hessian
         for i in 0..nassoc {
@@ -359,6 +464,14 @@ impl<P: HardSphereProperties> Association<P> {
             let dnx = (&xa * na * &d).sum() + 1.0;
             g[nassoc + i] -= dnx;
             h[(nassoc + i, nassoc + i)] = -dnx / xb[i];
+
+            // sites of type C only associate with other sites of type C
+            let dnx = (&xc * nc * &d).sum() + 1.0;
+            g[2 * nassoc + i] -= dnx;
+            for j in 0..nassoc {
+                h[(2 * nassoc + i, 2 * nassoc + j)] = -d[j] * nc[j];
+            }
+            h[(2 * nassoc + i, 2 * nassoc + i)] -= dnx / xc[i];
         }
 
         // Newton step
@@ -373,8 +486,8 @@ impl<P: HardSphereProperties> Association<P> {
 #[cfg(feature = "pcsaft")]
 mod tests_pcsaft {
     use super::*;
-    use crate::pcsaft::parameters::utils::water_parameters;
-    use crate::pcsaft::PcSaftParameters;
+    use crate::pcsaft::parameters::utils::{carbon_dioxide_parameters, water_parameters};
+    use crate::pcsaft::{PcSaftBinaryRecord, PcSaftParameters};
     use approx::assert_relative_eq;
     use feos_core::parameter::Parameter;
 
@@ -421,6 +534,102 @@ mod tests_pcsaft {
         let a_cross_assoc = cross_assoc.helmholtz_energy(&s) / n;
         assert_relative_eq!(a_assoc, a_cross_assoc, epsilon = 1e-10);
     }
+
+    #[test]
+    fn helmholtz_energy_cross_c_sites() {
+        let mut params = water_parameters();
+        let mut record = params.pure_records.pop().unwrap();
+        let mut association_record = record.model_record.association_record.unwrap();
+        association_record.na = Some(0.0);
+        association_record.nb = Some(0.0);
+        association_record.nc = Some(2.0);
+        record.model_record.association_record = Some(association_record);
+        let params = Arc::new(PcSaftParameters::new_pure(record));
+        let assoc = Association::new(&params, &params.association, 50, 1e-10);
+        let cross_assoc =
+            Association::new_cross_association(&params, &params.association, 50, 1e-10);
+        let t = 350.0;
+        let v = 41.248289328513216;
+        let n = 1.23;
+        let s = StateHD::new(t, v, arr1(&[n]));
+        let a_assoc = assoc.helmholtz_energy(&s) / n;
+        let a_cross_assoc = cross_assoc.helmholtz_energy(&s) / n;
+        assert!(a_assoc < 0.0);
+        assert_relative_eq!(a_assoc, a_cross_assoc, epsilon = 1e-10);
+    }
+
+    #[test]
+    fn helmholtz_energy_association_sites() {
+        // two site types with identical parameters are equivalent to a single 3B site type
+        let mut params = water_parameters();
+        let mut record = params.pure_records.pop().unwrap();
+        let mut association_record = record.model_record.association_record.unwrap();
+        association_record.na = Some(1.0);
+        association_record.nb = Some(2.0);
+        record.model_record.association_record = Some(association_record);
+        let params_3b = Arc::new(PcSaftParameters::new_pure(record.clone()));
+
+        association_record.nb = Some(1.0);
+        let mut association_site = association_record;
+        association_site.na = Some(0.0);
+        record.model_record.association_record = Some(association_record);
+        record.model_record.association_sites = vec![association_site];
+        let params_sites = Arc::new(PcSaftParameters::new_pure(record));
+        assert_eq!(params_sites.association.assoc_comp.len(), 2);
+
+        let assoc_3b = Association::new(&params_3b, &params_3b.association, 50, 1e-10);
+        let assoc_sites = Association::new(&params_sites, &params_sites.association, 50, 1e-10);
+        let t = 350.0;
+        let v = 41.248289328513216;
+        let n = 1.23;
+        let s = StateHD::new(t, v, arr1(&[n]));
+        let a_3b = assoc_3b.helmholtz_energy(&s) / n;
+        let a_sites = assoc_sites.helmholtz_energy(&s) / n;
+        assert_relative_eq!(a_3b, a_sites, epsilon = 1e-10);
+    }
+
+    #[test]
+    fn helmholtz_energy_induced_association() {
+        let water = water_parameters().pure_records.pop().unwrap();
+        let mut co2 = carbon_dioxide_parameters().pure_records.pop().unwrap();
+        let w = water.model_record.association_record.unwrap();
+        co2.model_record.association_record =
+            Some(AssociationRecord::new(0.0, 0.0, Some(1.0), Some(0.0), None));
+        let pure_records = vec![water, co2];
+
+        // without binary record, CO2 does not associate
+        let params = Arc::new(PcSaftParameters::new_binary(pure_records.clone(), None));
+        assert_eq!(params.association.assoc_comp.len(), 1);
+        let assoc = Association::new(&params, &params.association, 50, 1e-10);
+
+        // CO2 as electron acceptor
+        let br = PcSaftBinaryRecord {
+            association: Some(BinaryAssociationRecord::new(
+                w.kappa_ab,
+                0.5 * w.epsilon_k_ab,
+            )),
+            ..Default::default()
+        };
+        let params_induced = Arc::new(PcSaftParameters::new_binary(pure_records, Some(br)));
+        let a = &params_induced.association;
+        assert_eq!(a.assoc_comp.len(), 2);
+        assert_eq!(a.epsilon_k_aibj[(0, 1)], 0.5 * w.epsilon_k_ab);
+        assert_eq!(a.epsilon_k_aibj[(1, 0)], 0.5 * w.epsilon_k_ab);
+        assert_eq!(a.sigma3_kappa_aibj[(1, 1)], 0.0);
+        assert_relative_eq!(
+            a.sigma3_kappa_aibj[(0, 1)],
+            (params_induced.sigma[0] * params_induced.sigma[1]).powf(1.5) * w.kappa_ab,
+            max_relative = 1e-14
+        );
+        let assoc_induced = Association::new(&params_induced, a, 50, 1e-10);
+
+        let t = 350.0;
+        let v = 41.248289328513216;
+        let s = StateHD::new(t, v, arr1(&[0.8, 0.4]));
+        let a_assoc = assoc.helmholtz_energy(&s);
+        let a_induced = assoc_induced.helmholtz_energy(&s);
+        assert!(a_induced < a_assoc);
+    }
 }
 
 #[cfg(test)]
@@ -6,17 +6,23 @@ use pyo3::prelude::*;
 /// Pure component association parameters
 #[pyclass(
     name = "AssociationRecord",
-    text_signature = "(kappa_ab, epsilon_k_ab, na=None, nb=None)"
+    text_signature = "(kappa_ab, epsilon_k_ab, na=None, nb=None, nc=None)"
 )]
 #[derive(Clone)]
 pub struct PyAssociationRecord(pub AssociationRecord);
 
 #[pymethods]
 impl PyAssociationRecord {
-    #[pyo3(signature = (kappa_ab, epsilon_k_ab, na=None, nb=None))]
+    #[pyo3(signature = (kappa_ab, epsilon_k_ab, na=None, nb=None, nc=None))]
     #[new]
-    fn new(kappa_ab: f64, epsilon_k_ab: f64, na: Option<f64>, nb: Option<f64>) -> Self {
-        Self(AssociationRecord::new(kappa_ab, epsilon_k_ab, na, nb))
+    fn new(
+        kappa_ab: f64,
+        epsilon_k_ab: f64,
+        na: Option<f64>,
+        nb: Option<f64>,
+        nc: Option<f64>,
+    ) -> Self {
+        Self(AssociationRecord::new(kappa_ab, epsilon_k_ab, na, nb, nc))
     }
 
     #[getter]
@@ -39,6 +45,11 @@ impl PyAssociationRecord {
         self.0.nb
     }
 
+    #[getter]
+    fn get_nc(&self) -> Option<f64> {
+        self.0.nc
+    }
+
     fn __repr__(&self) -> PyResult<String> {
         Ok(self.0.to_string())
     }
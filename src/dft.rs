@@ -1,12 +1,15 @@
 #[cfg(feature = "gc_pcsaft")]
 use crate::gc_pcsaft::GcPcSaftFunctional;
 use crate::hard_sphere::FMTFunctional;
+#[cfg(feature = "lennard_jones")]
+use crate::lennard_jones::LennardJonesFunctional;
 #[cfg(feature = "pcsaft")]
 use crate::pcsaft::PcSaftFunctional;
 #[cfg(feature = "pets")]
 use crate::pets::PetsFunctional;
 #[cfg(feature = "saftvrqmie")]
 use crate::saftvrqmie::SaftVRQMieFunctional;
+use feos_core::ideal_gas::{IdealGasModel, WithIdealGas};
 use feos_core::*;
 use feos_derive::HelmholtzEnergyFunctional;
 use feos_dft::adsorption::*;
@@ -16,25 +19,29 @@ use ndarray::{Array1, Array2};
 use petgraph::graph::UnGraph;
 use petgraph::Graph;
 use quantity::si::*;
+use serde::{Deserialize, Serialize};
 
 /// Collection of different [HelmholtzEnergyFunctional] implementations.
 ///
 /// Particularly relevant for situations in which generic types
 /// are undesirable (e.g. FFI).
-#[derive(HelmholtzEnergyFunctional)]
+#[derive(HelmholtzEnergyFunctional, Serialize, Deserialize)]
 pub enum FunctionalVariant {
     #[cfg(feature = "pcsaft")]
-    #[implement(fluid_parameters, molar_weight, pair_potential)]
+    #[implement(fluid_parameters, molar_weight, pair_potential, ideal_gas)]
     PcSaft(PcSaftFunctional),
     #[cfg(feature = "gc_pcsaft")]
-    #[implement(fluid_parameters, molar_weight, bond_lengths)]
+    #[implement(fluid_parameters, molar_weight, bond_lengths, ideal_gas)]
     GcPcSaft(GcPcSaftFunctional),
     #[cfg(feature = "pets")]
-    #[implement(fluid_parameters, molar_weight, pair_potential)]
+    #[implement(fluid_parameters, molar_weight, pair_potential, ideal_gas)]
     Pets(PetsFunctional),
     #[implement(fluid_parameters, pair_potential)]
     Fmt(FMTFunctional),
     #[cfg(feature = "saftvrqmie")]
-    #[implement(fluid_parameters, molar_weight, pair_potential)]
+    #[implement(fluid_parameters, molar_weight, pair_potential, ideal_gas)]
     SaftVRQMie(SaftVRQMieFunctional),
+    #[cfg(feature = "lennard_jones")]
+    #[implement(fluid_parameters, molar_weight, pair_potential, ideal_gas)]
+    LennardJones(LennardJonesFunctional),
 }
@@ -1,5 +1,7 @@
 #[cfg(feature = "gc_pcsaft")]
 use crate::gc_pcsaft::GcPcSaft;
+#[cfg(feature = "lennard_jones")]
+use crate::lennard_jones::LennardJones;
 #[cfg(feature = "pcsaft")]
 use crate::pcsaft::PcSaft;
 #[cfg(feature = "pets")]
@@ -9,36 +11,43 @@ use crate::saftvrqmie::SaftVRQMie;
 #[cfg(feature = "uvtheory")]
 use crate::uvtheory::UVTheory;
 use feos_core::cubic::PengRobinson;
+use feos_core::ideal_gas::{IdealGasModel, WithIdealGas};
 #[cfg(feature = "python")]
 use feos_core::python::user_defined::PyEoSObj;
 use feos_core::*;
 use feos_derive::EquationOfState;
 use ndarray::Array1;
 use quantity::si::*;
+use serde::{Deserialize, Serialize};
 
 /// Collection of different [EquationOfState] implementations.
 ///
 /// Particularly relevant for situations in which generic types
 /// are undesirable (e.g. FFI).
-#[derive(EquationOfState)]
+#[derive(EquationOfState, Serialize, Deserialize)]
 pub enum EosVariant {
     #[cfg(feature = "pcsaft")]
-    #[implement(entropy_scaling, molar_weight)]
+    #[implement(entropy_scaling, molar_weight, ideal_gas)]
     PcSaft(PcSaft),
     #[cfg(feature = "gc_pcsaft")]
-    #[implement(molar_weight)]
+    #[implement(molar_weight, ideal_gas)]
     GcPcSaft(GcPcSaft),
-    #[implement(molar_weight)]
+    #[implement(molar_weight, ideal_gas)]
     PengRobinson(PengRobinson),
     #[cfg(feature = "python")]
     #[implement(molar_weight)]
+    #[serde(skip)]
     Python(PyEoSObj),
     #[cfg(feature = "saftvrqmie")]
-    #[implement(molar_weight)]
+    #[implement(molar_weight, ideal_gas)]
     SaftVRQMie(SaftVRQMie),
     #[cfg(feature = "pets")]
-    #[implement(molar_weight)]
+    #[implement(molar_weight, ideal_gas)]
     Pets(Pets),
     #[cfg(feature = "uvtheory")]
+    #[implement(ideal_gas)]
     UVTheory(UVTheory),
+    #[cfg(feature = "lennard_jones")]
+    #[implement(molar_weight, ideal_gas)]
+    LennardJones(LennardJones),
 }
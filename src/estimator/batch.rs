@@ -0,0 +1,110 @@
+//! Evaluation of many data points, in parallel if the `rayon` feature is enabled.
+use super::EstimatorError;
+use feos_core::{EosUnit, EquationOfState, PhaseEquilibrium, SolverOptions};
+use quantity::si::{SIArray1, SIUnit};
+#[cfg(feature = "rayon")]
+use rayon::prelude::*;
+use std::sync::Arc;
+
+/// Evaluate `f` for the indices `0..n`.
+///
+/// If the `rayon` feature is enabled, the evaluations are distributed
+/// over the global thread pool.
+pub(crate) fn map_datapoints<T, F>(n: usize, f: F) -> Vec<T>
+where
+    T: Send,
+    F: Fn(usize) -> T + Send + Sync,
+{
+    #[cfg(feature = "rayon")]
+    {
+        (0..n).into_par_iter().map(f).collect()
+    }
+    #[cfg(not(feature = "rayon"))]
+    {
+        (0..n).map(f).collect()
+    }
+}
+
+/// Solve the pure component phase equilibria at the given temperatures.
+///
+/// The temperatures are sorted and split into chunks of neighboring
+/// temperatures, one for each thread. Within a chunk, the phase equilibria
+/// are solved successively and every solution is used as initial value for
+/// the next temperature. If a warm start fails, the phase equilibrium is
+/// solved without initial value. Phase equilibria that can not be solved
+/// are returned as `None`.
+pub(crate) fn pure_vle_batch<E: EquationOfState>(
+    eos: &Arc<E>,
+    temperature: &SIArray1,
+    options: SolverOptions,
+) -> Result<Vec<Option<PhaseEquilibrium<E, 2>>>, EstimatorError> {
+    let t = temperature.to_reduced(SIUnit::reference_temperature())?;
+    let mut order: Vec<usize> = (0..t.len()).collect();
+    order.sort_by(|&i, &j| t[i].total_cmp(&t[j]));
+
+    #[cfg(feature = "rayon")]
+    let chunksize = (order.len() / rayon::current_num_threads()).max(1);
+    #[cfg(not(feature = "rayon"))]
+    let chunksize = order.len().max(1);
+
+    let solve_chunk = |chunk: &[usize]| {
+        let mut initial_state: Option<PhaseEquilibrium<E, 2>> = None;
+        chunk
+            .iter()
+            .map(|&i| {
+                let t = temperature.get(i);
+                let vle = initial_state
+                    .as_ref()
+                    .and_then(|vle| PhaseEquilibrium::pure(eos, t, Some(vle), options).ok())
+                    .or_else(|| PhaseEquilibrium::pure(eos, t, None, options).ok());
+                if vle.is_some() {
+                    initial_state = vle.clone();
+                }
+                (i, vle)
+            })
+            .collect::<Vec<_>>()
+    };
+    #[cfg(feature = "rayon")]
+    let solutions: Vec<_> = order.par_chunks(chunksize).map(solve_chunk).collect();
+    #[cfg(not(feature = "rayon"))]
+    let solutions: Vec<_> = order.chunks(chunksize).map(solve_chunk).collect();
+
+    let mut result = vec![None; t.len()];
+    for (i, vle) in solutions.into_iter().flatten() {
+        result[i] = vle;
+    }
+    Ok(result)
+}
+
+#[cfg(test)]
+#[cfg(feature = "pcsaft")]
+mod tests {
+    use super::*;
+    use crate::pcsaft::parameters::utils::propane_parameters;
+    use crate::pcsaft::PcSaft;
+    use approx::assert_relative_eq;
+    use feos_core::Contributions;
+    use ndarray::arr1;
+    use quantity::si::KELVIN;
+
+    #[test]
+    fn pure_vle_warm_start() -> Result<(), EstimatorError> {
+        let eos = Arc::new(PcSaft::new(propane_parameters()));
+        let temperature = arr1(&[300.0, 250.0, 350.0, 200.0, 500.0]) * KELVIN;
+        let options = SolverOptions::default();
+        let vle = pure_vle_batch(&eos, &temperature, options)?;
+        assert!(vle[4].is_none());
+        for (i, vle) in vle.iter().take(4).enumerate() {
+            let p = vle.as_ref().unwrap().vapor().pressure(Contributions::Total);
+            let vle_ref = PhaseEquilibrium::pure(&eos, temperature.get(i), None, options)?;
+            let p_ref = vle_ref.vapor().pressure(Contributions::Total);
+            assert_relative_eq!(p, p_ref, max_relative = 1e-8);
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn map_datapoints_order() {
+        assert_eq!(map_datapoints(5, |i| i * i), vec![0, 1, 4, 9, 16]);
+    }
+}
@@ -0,0 +1,110 @@
+use super::batch::map_datapoints;
+use super::{DataSet, EstimatorError};
+use feos_core::{EosUnit, EquationOfState, PhaseEquilibrium, SolverOptions};
+use ndarray::{arr1, concatenate, Array1, Axis};
+use quantity::si::{SIArray1, SIUnit};
+use std::collections::HashMap;
+use std::sync::Arc;
+
+/// Store experimental binary liquid-liquid equilibrium data.
+///
+/// The target are the mole fractions of component 1 in both liquid phases.
+/// The phases are ordered such that the first phase is poorer in
+/// component 1.
+#[derive(Clone)]
+pub struct BinaryLle {
+    temperature: SIArray1,
+    pressure: SIArray1,
+    target: SIArray1,
+    solver_options: SolverOptions,
+}
+
+impl BinaryLle {
+    /// Create a new data set for binary liquid-liquid equilibria.
+    pub fn new(
+        temperature: SIArray1,
+        pressure: SIArray1,
+        molefracs_1: Array1<f64>,
+        molefracs_2: Array1<f64>,
+        solver_options: Option<SolverOptions>,
+    ) -> Result<Self, EstimatorError> {
+        let n = temperature.len();
+        if pressure.len() != n || molefracs_1.len() != n || molefracs_2.len() != n {
+            return Err(EstimatorError::IncompatibleInput);
+        }
+        let x_1 = Array1::from_shape_fn(n, |i| molefracs_1[i].min(molefracs_2[i]));
+        let x_2 = Array1::from_shape_fn(n, |i| molefracs_1[i].max(molefracs_2[i]));
+        let target = concatenate(Axis(0), &[x_1.view(), x_2.view()])? * SIUnit::reference_moles()
+            / SIUnit::reference_moles();
+        Ok(Self {
+            temperature,
+            pressure,
+            target,
+            solver_options: solver_options.unwrap_or_default(),
+        })
+    }
+
+    /// Return temperature.
+    pub fn temperature(&self) -> SIArray1 {
+        self.temperature.clone()
+    }
+
+    /// Return pressure.
+    pub fn pressure(&self) -> SIArray1 {
+        self.pressure.clone()
+    }
+}
+
+impl<E: EquationOfState> DataSet<E> for BinaryLle {
+    fn target(&self) -> &SIArray1 {
+        &self.target
+    }
+
+    fn target_str(&self) -> &str {
+        "liquid molefracs"
+    }
+
+    fn input_str(&self) -> Vec<&str> {
+        vec!["temperature", "pressure"]
+    }
+
+    fn predict(&self, eos: &Arc<E>) -> Result<SIArray1, EstimatorError> {
+        let n = self.temperature.len();
+        let x = self
+            .target
+            .to_reduced(SIUnit::reference_moles() / SIUnit::reference_moles())?;
+        let prediction = map_datapoints(n, |i| {
+            // the feed is located between the experimental compositions
+            let z = 0.5 * (x[i] + x[n + i]);
+            let feed = arr1(&[z, 1.0 - z]) * SIUnit::reference_moles();
+            PhaseEquilibrium::tp_flash(
+                eos,
+                self.temperature.get(i),
+                self.pressure.get(i),
+                &feed,
+                None,
+                self.solver_options,
+                None,
+            )
+            .map(|lle| {
+                let x_1 = lle.vapor().molefracs[0];
+                let x_2 = lle.liquid().molefracs[0];
+                (x_1.min(x_2), x_1.max(x_2))
+            })
+            .unwrap_or((f64::NAN, f64::NAN))
+        });
+        let x_1: Array1<f64> = prediction.iter().map(|x| x.0).collect();
+        let x_2: Array1<f64> = prediction.iter().map(|x| x.1).collect();
+        Ok(
+            concatenate(Axis(0), &[x_1.view(), x_2.view()])? * SIUnit::reference_moles()
+                / SIUnit::reference_moles(),
+        )
+    }
+
+    fn get_input(&self) -> HashMap<String, SIArray1> {
+        let mut m = HashMap::with_capacity(2);
+        m.insert("temperature".to_owned(), self.temperature());
+        m.insert("pressure".to_owned(), self.pressure());
+        m
+    }
+}
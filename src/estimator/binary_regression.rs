@@ -0,0 +1,186 @@
+//! Regression of binary interaction parameters for all binary subsystems of a mixture.
+use super::{Estimator, EstimatorError, Optimizer, RegressionParameters, RegressionResult};
+use feos_core::parameter::{BinaryRecord, Identifier};
+use feos_core::{EquationOfState, ProgressCallback, SolverOptions};
+use serde::Serialize;
+use std::fmt;
+use std::fs::File;
+use std::io::BufWriter;
+use std::path::Path;
+use std::sync::Arc;
+
+/// Experimental data of a binary subsystem.
+pub struct BinarySystem<'a, E: EquationOfState> {
+    /// Indices of the two components in the full parameter set.
+    pub components: [usize; 2],
+    /// The data sets of the binary subsystem.
+    pub estimator: &'a Estimator<E>,
+}
+
+impl<'a, E: EquationOfState> BinarySystem<'a, E> {
+    pub fn new(components: [usize; 2], estimator: &'a Estimator<E>) -> Self {
+        Self {
+            components,
+            estimator,
+        }
+    }
+}
+
+/// The result of the regression of binary interaction parameters.
+pub struct BinaryRegression<P: RegressionParameters> {
+    /// The full parameter set with all fitted binary records.
+    pub parameters: Arc<P>,
+    /// The results of the individual binary subsystems.
+    pub results: Vec<([usize; 2], RegressionResult<P>)>,
+}
+
+impl<P: RegressionParameters> BinaryRegression<P> {
+    /// Adjust the binary interaction parameters of every binary subsystem.
+    ///
+    /// For every binary subsystem, `names` are adjusted using the
+    /// component indices 0 and 1 of the binary subset of `parameters`,
+    /// e.g., `k_ij[0,1]`. The binary records of the fitted subsystems are
+    /// copied to the full parameter set, all other binary records are
+    /// left unchanged.
+    pub fn fit<E, F>(
+        parameters: &P,
+        systems: &[BinarySystem<E>],
+        names: &[P::Name],
+        bounds: Option<&[(f64, f64)]>,
+        eos: F,
+        optimizer: Optimizer,
+        options: SolverOptions,
+        callback: Option<&dyn ProgressCallback>,
+    ) -> Result<Self, EstimatorError>
+    where
+        E: EquationOfState,
+        F: Fn(Arc<P>) -> E,
+    {
+        let (pure_records, binary_records) = parameters.records();
+        let pure_records = pure_records.to_vec();
+        let mut binary_records = binary_records.clone();
+        let n = pure_records.len();
+
+        let mut results = Vec::with_capacity(systems.len());
+        for system in systems {
+            let [i, j] = system.components;
+            if i == j || i >= n || j >= n {
+                return Err(EstimatorError::InvalidParameter(format!(
+                    "Invalid binary system [{}, {}] for {} components.",
+                    i, j, n
+                )));
+            }
+            let subset = parameters.subset(&[i, j]);
+            let result = system
+                .estimator
+                .fit(&subset, names, bounds, &eos, optimizer, options, callback)?;
+            let (_, fitted) = result.parameters.records();
+            binary_records[(i, j)] = fitted[(0, 1)].clone();
+            binary_records[(j, i)] = fitted[(1, 0)].clone();
+            results.push(([i, j], result));
+        }
+        Ok(Self {
+            parameters: Arc::new(P::from_records(pure_records, binary_records)),
+            results,
+        })
+    }
+
+    /// The fitted binary records.
+    pub fn binary_records(&self) -> Vec<BinaryRecord<Identifier, P::Binary>> {
+        let (pure_records, binary_records) = self.parameters.records();
+        self.results
+            .iter()
+            .map(|&([i, j], _)| {
+                BinaryRecord::new(
+                    pure_records[i].identifier.clone(),
+                    pure_records[j].identifier.clone(),
+                    binary_records[(i, j)].clone(),
+                )
+            })
+            .collect()
+    }
+
+    /// Write the fitted binary records to a json file.
+    pub fn to_json<Q: AsRef<Path>>(&self, file: Q) -> Result<(), EstimatorError>
+    where
+        P::Binary: Serialize,
+    {
+        let writer = BufWriter::new(File::create(file)?);
+        serde_json::to_writer_pretty(writer, &self.binary_records())?;
+        Ok(())
+    }
+}
+
+impl<P: RegressionParameters> fmt::Display for BinaryRegression<P> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "BinaryRegression(")?;
+        for ([i, j], result) in &self.results {
+            write!(f, "\n    [{}, {}]: {}", i, j, result)?;
+        }
+        write!(f, "\n)")
+    }
+}
+
+#[cfg(test)]
+#[cfg(feature = "pcsaft")]
+mod tests {
+    use super::*;
+    use crate::estimator::{BinaryVlePressure, DataSet, Loss, Phase};
+    use crate::pcsaft::parameters::utils::propane_butane_parameters;
+    use crate::pcsaft::{PcSaft, PcSaftFitParameter, PcSaftParameters};
+    use approx::assert_relative_eq;
+    use feos_core::{Contributions, PhaseEquilibrium};
+    use ndarray::arr1;
+    use quantity::si::{SIArray1, KELVIN};
+
+    #[test]
+    fn fit_k_ij() -> Result<(), EstimatorError> {
+        let parameters = propane_butane_parameters();
+        let k_ij = [PcSaftFitParameter::Kij(0, 1)];
+        let eos = Arc::new(PcSaft::new(Arc::new(
+            parameters.set_parameters(&k_ij, &[0.02])?,
+        )));
+
+        // synthetic bubble point data
+        let x = arr1(&[0.2, 0.5, 0.8]);
+        let temperature = SIArray1::linspace(300.0 * KELVIN, 300.0 * KELVIN, 3)?;
+        let pressure: SIArray1 = x
+            .iter()
+            .map(|&x| {
+                let vle = PhaseEquilibrium::bubble_point(
+                    &eos,
+                    300.0 * KELVIN,
+                    &arr1(&[x, 1.0 - x]),
+                    None,
+                    None,
+                    Default::default(),
+                )?;
+                Ok(vle.vapor().pressure(Contributions::Total))
+            })
+            .collect::<Result<_, EstimatorError>>()?;
+        let data: Arc<dyn DataSet<PcSaft>> = Arc::new(BinaryVlePressure::new(
+            temperature,
+            pressure,
+            x,
+            Phase::Liquid,
+        ));
+        let estimator = Estimator::new(vec![data], vec![1.0], vec![Loss::Linear]);
+
+        let regression = BinaryRegression::<PcSaftParameters>::fit(
+            &parameters,
+            &[BinarySystem::new([0, 1], &estimator)],
+            &k_ij,
+            Some(&[(-0.1, 0.1)]),
+            PcSaft::new,
+            Optimizer::LevenbergMarquardt,
+            SolverOptions::default(),
+            None,
+        )?;
+        assert_relative_eq!(regression.parameters.k_ij[[0, 1]], 0.02, epsilon = 1e-6);
+        assert_relative_eq!(regression.parameters.k_ij[[1, 0]], 0.02, epsilon = 1e-6);
+        let records = regression.binary_records();
+        assert_eq!(records.len(), 1);
+        assert_relative_eq!(records[0].model_record.k_ij, 0.02, epsilon = 1e-6);
+        Ok(())
+    }
+}
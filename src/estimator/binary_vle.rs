@@ -1,3 +1,4 @@
+use super::batch::map_datapoints;
 use super::{DataSet, EstimatorError};
 use feos_core::{
     Contributions, DensityInitialization, EosUnit, EquationOfState, PhaseDiagram, PhaseEquilibrium,
@@ -149,32 +150,119 @@ impl<E: EquationOfState> DataSet<E> for BinaryVlePressure {
 
     fn predict(&self, eos: &Arc<E>) -> Result<SIArray1, EstimatorError> {
         let options = Default::default();
-        self.molefracs
-            .iter()
-            .enumerate()
-            .map(|(i, &xi)| {
-                let vle = (match self.phase {
-                    Phase::Vapor => PhaseEquilibrium::dew_point(
-                        eos,
-                        self.temperature.get(i),
-                        &arr1(&[xi, 1.0 - xi]),
-                        Some(self.pressure.get(i)),
-                        None,
-                        options,
-                    ),
-                    Phase::Liquid => PhaseEquilibrium::bubble_point(
-                        eos,
-                        self.temperature.get(i),
-                        &arr1(&[xi, 1.0 - xi]),
-                        Some(self.pressure.get(i)),
-                        None,
-                        options,
-                    ),
-                })?;
-
-                Ok(vle.vapor().pressure(Contributions::Total))
+        map_datapoints(self.molefracs.len(), |i| {
+            let xi = self.molefracs[i];
+            let vle = (match self.phase {
+                Phase::Vapor => PhaseEquilibrium::dew_point(
+                    eos,
+                    self.temperature.get(i),
+                    &arr1(&[xi, 1.0 - xi]),
+                    Some(self.pressure.get(i)),
+                    None,
+                    options,
+                ),
+                Phase::Liquid => PhaseEquilibrium::bubble_point(
+                    eos,
+                    self.temperature.get(i),
+                    &arr1(&[xi, 1.0 - xi]),
+                    Some(self.pressure.get(i)),
+                    None,
+                    options,
+                ),
+            })?;
+
+            Ok(vle.vapor().pressure(Contributions::Total))
+        })
+        .into_iter()
+        .collect()
+    }
+
+    fn get_input(&self) -> HashMap<String, SIArray1> {
+        let mut m = HashMap::with_capacity(4);
+        m.insert("temperature".to_owned(), self.temperature.clone());
+        m.insert("pressure".to_owned(), self.pressure.clone());
+        m.insert(
+            (match self.phase {
+                Phase::Vapor => "vapor_molefracs",
+                Phase::Liquid => "liquid_molefracs",
             })
-            .collect()
+            .to_owned(),
+            &self.molefracs * SIUnit::reference_moles() / SIUnit::reference_moles(),
+        );
+        m
+    }
+}
+
+/// Store experimental binary VLE data for the calculation of temperature residuals.
+#[derive(Clone)]
+pub struct BinaryVleTemperature {
+    temperature: SIArray1,
+    pressure: SIArray1,
+    molefracs: Array1<f64>,
+    phase: Phase,
+}
+
+impl BinaryVleTemperature {
+    pub fn new(
+        temperature: SIArray1,
+        pressure: SIArray1,
+        molefracs: Array1<f64>,
+        phase: Phase,
+    ) -> Self {
+        Self {
+            temperature,
+            pressure,
+            molefracs,
+            phase,
+        }
+    }
+}
+
+impl<E: EquationOfState> DataSet<E> for BinaryVleTemperature {
+    fn target(&self) -> &SIArray1 {
+        &self.temperature
+    }
+
+    fn target_str(&self) -> &str {
+        "temperature"
+    }
+
+    fn input_str(&self) -> Vec<&str> {
+        let mut vec = vec!["pressure", "temperature"];
+        vec.push(match self.phase {
+            Phase::Vapor => "vapor molefracs",
+            Phase::Liquid => "liquid molefracs",
+        });
+        vec
+    }
+
+    fn predict(&self, eos: &Arc<E>) -> Result<SIArray1, EstimatorError> {
+        let options = Default::default();
+        map_datapoints(self.molefracs.len(), |i| {
+            let xi = self.molefracs[i];
+            let vle = (match self.phase {
+                Phase::Vapor => PhaseEquilibrium::dew_point(
+                    eos,
+                    self.pressure.get(i),
+                    &arr1(&[xi, 1.0 - xi]),
+                    Some(self.temperature.get(i)),
+                    None,
+                    options,
+                ),
+                Phase::Liquid => PhaseEquilibrium::bubble_point(
+                    eos,
+                    self.pressure.get(i),
+                    &arr1(&[xi, 1.0 - xi]),
+                    Some(self.temperature.get(i)),
+                    None,
+                    options,
+                ),
+            })?;
+
+            Ok(vle.vapor().temperature)
+        })
+        .into_iter()
+        .collect()
     }
 
     fn get_input(&self) -> HashMap<String, SIArray1> {
@@ -1,3 +1,4 @@
+use super::batch::map_datapoints;
 use super::{DataSet, EstimatorError};
 use feos_core::{DensityInitialization, EntropyScaling, EosUnit, EquationOfState, State};
 use ndarray::{arr1, Array1};
@@ -62,22 +63,20 @@ impl<E: EquationOfState + EntropyScaling> DataSet<E> for Diffusion {
             .to_reduced(SIUnit::reference_pressure())
             .unwrap();
 
-        let res = ts
-            .iter()
-            .zip(ps.iter())
-            .map(|(&t, &p)| {
-                State::new_npt(
-                    eos,
-                    t * SIUnit::reference_temperature(),
-                    p * SIUnit::reference_pressure(),
-                    &moles,
-                    DensityInitialization::None,
-                )?
-                .diffusion()?
-                .to_reduced(SIUnit::reference_diffusion())
-                .map_err(EstimatorError::from)
-            })
-            .collect::<Result<Vec<f64>, EstimatorError>>();
+        let res = map_datapoints(ts.len(), |i| {
+            State::new_npt(
+                eos,
+                ts[i] * SIUnit::reference_temperature(),
+                ps[i] * SIUnit::reference_pressure(),
+                &moles,
+                DensityInitialization::None,
+            )?
+            .diffusion()?
+            .to_reduced(SIUnit::reference_diffusion())
+            .map_err(EstimatorError::from)
+        })
+        .into_iter()
+        .collect::<Result<Vec<f64>, EstimatorError>>();
         Ok(Array1::from_vec(res?) * SIUnit::reference_diffusion())
     }
 
@@ -1,5 +1,6 @@
 //! The [`Estimator`] struct can be used to store multiple [`DataSet`]s for convenient parameter
 //! optimization.
+use super::batch::map_datapoints;
 use super::{DataSet, EstimatorError, Loss};
 use feos_core::EquationOfState;
 use ndarray::{arr1, concatenate, Array1, ArrayView1, Axis};
@@ -39,29 +40,30 @@ impl<E: EquationOfState> Estimator<E> {
 
     /// Returns the cost of each `DataSet`.
     ///
-    /// Each cost contains the inverse weight.
+    /// Each cost contains the inverse weight. If the `rayon` feature is
+    /// enabled, the `DataSet`s and their data points are evaluated in parallel.
     pub fn cost(&self, eos: &Arc<E>) -> Result<Array1<f64>, EstimatorError> {
         let w = arr1(&self.weights) / self.weights.iter().sum::<f64>();
-        let predictions = self
-            .data
-            .iter()
-            .enumerate()
-            .map(|(i, d)| Ok(d.cost(eos, self.losses[i])? * w[i]))
-            .collect::<Result<Vec<_>, EstimatorError>>()?;
+        let predictions = map_datapoints(self.data.len(), |i| {
+            Ok(self.data[i].cost(eos, self.losses[i])? * w[i])
+        })
+        .into_iter()
+        .collect::<Result<Vec<_>, EstimatorError>>()?;
         let aview: Vec<ArrayView1<f64>> = predictions.iter().map(|pi| pi.view()).collect();
         Ok(concatenate(Axis(0), &aview)?)
     }
 
     /// Returns the properties as computed by the equation of state for each `DataSet`.
     pub fn predict(&self, eos: &Arc<E>) -> Result<Vec<SIArray1>, EstimatorError> {
-        self.data.iter().map(|d| d.predict(eos)).collect()
+        map_datapoints(self.data.len(), |i| self.data[i].predict(eos))
+            .into_iter()
+            .collect()
     }
 
     /// Returns the relative difference for each `DataSet`.
     pub fn relative_difference(&self, eos: &Arc<E>) -> Result<Vec<Array1<f64>>, EstimatorError> {
-        self.data
-            .iter()
-            .map(|d| d.relative_difference(eos))
+        map_datapoints(self.data.len(), |i| self.data[i].relative_difference(eos))
+            .into_iter()
             .collect()
     }
 
@@ -70,10 +72,11 @@ impl<E: EquationOfState> Estimator<E> {
         &self,
         eos: &Arc<E>,
     ) -> Result<Array1<f64>, EstimatorError> {
-        self.data
-            .iter()
-            .map(|d| d.mean_absolute_relative_difference(eos))
-            .collect()
+        map_datapoints(self.data.len(), |i| {
+            self.data[i].mean_absolute_relative_difference(eos)
+        })
+        .into_iter()
+        .collect()
     }
 
     /// Returns the stored `DataSet`s.
@@ -0,0 +1,87 @@
+use super::batch::map_datapoints;
+use super::{DataSet, EstimatorError, Phase};
+use feos_core::{Contributions, DensityInitialization, EosUnit, EquationOfState, State};
+use ndarray::arr1;
+use quantity::si::{SIArray1, SIUnit};
+use std::collections::HashMap;
+use std::sync::Arc;
+
+/// Molar isobaric heat capacity data as function of pressure and temperature.
+#[derive(Clone)]
+pub struct IsobaricHeatCapacity {
+    /// molar isobaric heat capacity
+    pub target: SIArray1,
+    /// temperature
+    temperature: SIArray1,
+    /// pressure
+    pressure: SIArray1,
+    /// phase of the data points
+    phase: Phase,
+}
+
+impl IsobaricHeatCapacity {
+    /// A new data set for molar isobaric heat capacities with pressures and temperatures as input.
+    pub fn new(
+        target: SIArray1,
+        temperature: SIArray1,
+        pressure: SIArray1,
+        phase: Phase,
+    ) -> Result<Self, EstimatorError> {
+        Ok(Self {
+            target,
+            temperature,
+            pressure,
+            phase,
+        })
+    }
+
+    /// Returns temperature of data points.
+    pub fn temperature(&self) -> SIArray1 {
+        self.temperature.clone()
+    }
+
+    /// Returns pressure of data points.
+    pub fn pressure(&self) -> SIArray1 {
+        self.pressure.clone()
+    }
+}
+
+impl<E: EquationOfState> DataSet<E> for IsobaricHeatCapacity {
+    fn target(&self) -> &SIArray1 {
+        &self.target
+    }
+
+    fn target_str(&self) -> &str {
+        "isobaric heat capacity"
+    }
+
+    fn input_str(&self) -> Vec<&str> {
+        vec!["temperature", "pressure"]
+    }
+
+    fn predict(&self, eos: &Arc<E>) -> Result<SIArray1, EstimatorError> {
+        let moles = arr1(&[1.0]) * SIUnit::reference_moles();
+        let density_initialization = match self.phase {
+            Phase::Liquid => DensityInitialization::Liquid,
+            Phase::Vapor => DensityInitialization::Vapor,
+        };
+        Ok(map_datapoints(self.target.len(), |i| {
+            let (t, p) = (self.temperature.get(i), self.pressure.get(i));
+            let state = State::new_npt(eos, t, p, &moles, density_initialization);
+            if let Ok(s) = state {
+                s.c_p(Contributions::Total)
+            } else {
+                f64::NAN * SIUnit::reference_molar_entropy()
+            }
+        })
+        .into_iter()
+        .collect())
+    }
+
+    fn get_input(&self) -> HashMap<String, SIArray1> {
+        let mut m = HashMap::with_capacity(2);
+        m.insert("temperature".to_owned(), self.temperature());
+        m.insert("pressure".to_owned(), self.pressure());
+        m
+    }
+}
@@ -1,7 +1,7 @@
+use super::batch::{map_datapoints, pure_vle_batch};
 use super::{DataSet, EstimatorError};
 use feos_core::{
-    DensityInitialization, EosUnit, EquationOfState, MolarWeight, PhaseEquilibrium, SolverOptions,
-    State,
+    DensityInitialization, EosUnit, EquationOfState, MolarWeight, SolverOptions, State,
 };
 use ndarray::arr1;
 use quantity::si::{SIArray1, SIUnit};
@@ -59,19 +59,17 @@ impl<E: EquationOfState + MolarWeight> DataSet<E> for LiquidDensity {
 
     fn predict(&self, eos: &Arc<E>) -> Result<SIArray1, EstimatorError> {
         let moles = arr1(&[1.0]) * SIUnit::reference_moles();
-        Ok(self
-            .temperature
-            .into_iter()
-            .zip(self.pressure.into_iter())
-            .map(|(t, p)| {
-                let state = State::new_npt(eos, t, p, &moles, DensityInitialization::Liquid);
-                if let Ok(s) = state {
-                    s.mass_density()
-                } else {
-                    f64::NAN * SIUnit::reference_mass() / SIUnit::reference_volume()
-                }
-            })
-            .collect())
+        Ok(map_datapoints(self.target.len(), |i| {
+            let (t, p) = (self.temperature.get(i), self.pressure.get(i));
+            let state = State::new_npt(eos, t, p, &moles, DensityInitialization::Liquid);
+            if let Ok(s) = state {
+                s.mass_density()
+            } else {
+                f64::NAN * SIUnit::reference_mass() / SIUnit::reference_volume()
+            }
+        })
+        .into_iter()
+        .collect())
     }
 
     fn get_input(&self) -> HashMap<String, SIArray1> {
@@ -124,12 +122,11 @@ impl<E: EquationOfState + MolarWeight> DataSet<E> for EquilibriumLiquidDensity {
     }
 
     fn predict(&self, eos: &Arc<E>) -> Result<SIArray1, EstimatorError> {
-        Ok(self
-            .temperature
+        Ok(pure_vle_batch(eos, &self.temperature, self.solver_options)?
             .into_iter()
-            .map(|t| {
-                if let Ok(state) = PhaseEquilibrium::pure(eos, t, None, self.solver_options) {
-                    state.liquid().mass_density()
+            .map(|vle| {
+                if let Some(vle) = vle {
+                    vle.liquid().mass_density()
                 } else {
                     f64::NAN * SIUnit::reference_mass() / SIUnit::reference_volume()
                 }
@@ -4,12 +4,20 @@ use quantity::QuantityError;
 use std::num::ParseFloatError;
 use thiserror::Error;
 
+mod batch;
 mod dataset;
 pub use dataset::DataSet;
 mod estimator;
 pub use estimator::Estimator;
 mod loss;
 pub use loss::Loss;
+mod regression;
+pub use regression::{Optimizer, RegressionParameters, RegressionResult};
+pub(crate) use regression::levenberg_marquardt;
+mod uncertainty;
+pub use uncertainty::{Posterior, PredictionBand, SamplerOptions};
+mod binary_regression;
+pub use binary_regression::{BinaryRegression, BinarySystem};
 
 // Properties
 mod vapor_pressure;
@@ -17,13 +25,29 @@ pub use vapor_pressure::VaporPressure;
 mod liquid_density;
 pub use liquid_density::{EquilibriumLiquidDensity, LiquidDensity};
 mod binary_vle;
-pub use binary_vle::{BinaryPhaseDiagram, BinaryVleChemicalPotential, BinaryVlePressure, Phase};
+pub use binary_vle::{
+    BinaryPhaseDiagram, BinaryVleChemicalPotential, BinaryVlePressure, BinaryVleTemperature, Phase,
+};
+mod binary_lle;
+pub use binary_lle::BinaryLle;
 mod viscosity;
 pub use viscosity::Viscosity;
 mod thermal_conductivity;
 pub use thermal_conductivity::ThermalConductivity;
 mod diffusion;
 pub use diffusion::Diffusion;
+mod heat_capacity;
+pub use heat_capacity::IsobaricHeatCapacity;
+mod speed_of_sound;
+pub use speed_of_sound::SpeedOfSound;
+#[cfg(feature = "dft")]
+mod surface_tension;
+#[cfg(feature = "dft")]
+pub use surface_tension::SurfaceTension;
+
+// Data import
+mod thermoml;
+pub use thermoml::ThermoML;
 
 #[cfg(feature = "python")]
 pub mod python;
@@ -40,4 +64,14 @@ pub enum EstimatorError {
     QuantityError(#[from] QuantityError),
     #[error(transparent)]
     EosError(#[from] EosError),
+    #[error(transparent)]
+    IOError(#[from] std::io::Error),
+    #[error(transparent)]
+    XmlError(#[from] roxmltree::Error),
+    #[error(transparent)]
+    Serde(#[from] serde_json::Error),
+    #[error("{0}")]
+    ThermoMLError(String),
+    #[error("Invalid regression parameter: {0}")]
+    InvalidParameter(String),
 }
@@ -437,6 +437,147 @@ macro_rules! impl_estimator {
                 )))
             }
 
+            /// Create a DataSet with experimental data for binary
+            /// phase equilibria using the temperature residual.
+            ///
+            /// Parameters
+            /// ----------
+            /// temperature : SIArray1
+            ///     Temperature of the experimental data points.
+            /// pressure : SIArray1
+            ///     Pressure of the experimental data points.
+            /// molefracs : np.array[float]
+            ///     Molar composition of component 1 in the considered phase.
+            /// phase : Phase
+            ///     The phase of the experimental data points.
+            ///
+            /// Returns
+            /// -------
+            /// DataSet
+            #[staticmethod]
+            #[pyo3(text_signature = "(temperature, pressure, molefracs, phase)")]
+            fn binary_vle_temperature(
+                temperature: &PySIArray1,
+                pressure: &PySIArray1,
+                molefracs: &PyArray1<f64>,
+                phase: Phase,
+            ) -> Self {
+                Self(Arc::new(BinaryVleTemperature::new(
+                    temperature.clone().into(),
+                    pressure.clone().into(),
+                    molefracs.to_owned_array(),
+                    phase,
+                )))
+            }
+
+            /// Create a DataSet with experimental data for binary
+            /// liquid-liquid equilibria.
+            ///
+            /// Parameters
+            /// ----------
+            /// temperature : SIArray1
+            ///     Temperature of the experimental data points.
+            /// pressure : SIArray1
+            ///     Pressure of the experimental data points.
+            /// molefracs_1 : np.array[float]
+            ///     Molar composition of component 1 in the first liquid phase.
+            /// molefracs_2 : np.array[float]
+            ///     Molar composition of component 1 in the second liquid phase.
+            /// max_iter : int, optional
+            ///     The maximum number of iterations for the flash algorithm.
+            /// tol: float, optional
+            ///     Solution tolerance for the flash algorithm.
+            /// verbosity : Verbosity, optional
+            ///     Verbosity for the flash algorithm.
+            ///
+            /// Returns
+            /// -------
+            /// DataSet
+            #[staticmethod]
+            #[pyo3(text_signature = "(temperature, pressure, molefracs_1, molefracs_2, max_iter=None, tol=None, verbosity=None)")]
+            fn binary_lle(
+                temperature: &PySIArray1,
+                pressure: &PySIArray1,
+                molefracs_1: &PyArray1<f64>,
+                molefracs_2: &PyArray1<f64>,
+                max_iter: Option<usize>,
+                tol: Option<f64>,
+                verbosity: Option<Verbosity>,
+            ) -> PyResult<Self> {
+                Ok(Self(Arc::new(BinaryLle::new(
+                    temperature.clone().into(),
+                    pressure.clone().into(),
+                    molefracs_1.to_owned_array(),
+                    molefracs_2.to_owned_array(),
+                    Some((max_iter, tol, verbosity).into()),
+                )?)))
+            }
+
+            /// Create a DataSet with experimental data for the speed of sound.
+            ///
+            /// Parameters
+            /// ----------
+            /// target : SIArray1
+            ///     Experimental data for the speed of sound.
+            /// temperature : SIArray1
+            ///     Temperature for experimental data points.
+            /// pressure : SIArray1
+            ///     Pressure for experimental data points.
+            /// phase : Phase
+            ///     The phase of the experimental data points.
+            ///
+            /// Returns
+            /// -------
+            /// DataSet
+            #[staticmethod]
+            #[pyo3(text_signature = "(target, temperature, pressure, phase)")]
+            fn speed_of_sound(
+                target: &PySIArray1,
+                temperature: &PySIArray1,
+                pressure: &PySIArray1,
+                phase: Phase,
+            ) -> PyResult<Self> {
+                Ok(Self(Arc::new(SpeedOfSound::new(
+                    target.clone().into(),
+                    temperature.clone().into(),
+                    pressure.clone().into(),
+                    phase,
+                )?)))
+            }
+
+            /// Create a DataSet with experimental data for the
+            /// molar isobaric heat capacity.
+            ///
+            /// Parameters
+            /// ----------
+            /// target : SIArray1
+            ///     Experimental data for the molar isobaric heat capacity.
+            /// temperature : SIArray1
+            ///     Temperature for experimental data points.
+            /// pressure : SIArray1
+            ///     Pressure for experimental data points.
+            /// phase : Phase
+            ///     The phase of the experimental data points.
+            ///
+            /// Returns
+            /// -------
+            /// DataSet
+            #[staticmethod]
+            #[pyo3(text_signature = "(target, temperature, pressure, phase)")]
+            fn isobaric_heat_capacity(
+                target: &PySIArray1,
+                temperature: &PySIArray1,
+                pressure: &PySIArray1,
+                phase: Phase,
+            ) -> PyResult<Self> {
+                Ok(Self(Arc::new(IsobaricHeatCapacity::new(
+                    target.clone().into(),
+                    temperature.clone().into(),
+                    pressure.clone().into(),
+                    phase,
+                )?)))
+            }
+
             /// Return `input` as ``Dict[str, SIArray1]``.
             #[getter]
             fn get_input(&self) -> HashMap<String, PySIArray1> {
@@ -464,6 +605,197 @@ macro_rules! impl_estimator {
             }
         }
 
+        /// Experimental data read from a ThermoML file.
+        ///
+        /// Compounds are identified by their CAS number, any of their
+        /// names, their InChIKey or their molecular formula.
+        ///
+        /// Parameters
+        /// ----------
+        /// path : str
+        ///     Path to the ThermoML file.
+        ///
+        /// Returns
+        /// -------
+        /// ThermoML
+        #[pyclass(name = "ThermoML")]
+        #[pyo3(text_signature = "(path)")]
+        #[derive(Clone)]
+        pub struct PyThermoML(ThermoML);
+
+        #[pymethods]
+        impl PyThermoML {
+            #[new]
+            fn new(path: &str) -> PyResult<Self> {
+                Ok(Self(ThermoML::from_file(path)?))
+            }
+
+            /// The identifiers of all compounds in the file.
+            #[getter]
+            fn get_compounds(&self) -> Vec<PyIdentifier> {
+                self.0.compounds().into_iter().map(PyIdentifier).collect()
+            }
+
+            /// Create a DataSet with the vapor pressures of a pure compound.
+            ///
+            /// Parameters
+            /// ----------
+            /// compound : str
+            ///     The compound.
+            /// extrapolate : bool, optional
+            ///     Use Antoine type equation to extrapolate vapor
+            ///     pressure if experimental data is above critial
+            ///     point of model. Defaults to False.
+            /// max_iter : int, optional
+            ///     The maximum number of iterations for critical point
+            ///     and VLE algorithms.
+            /// tol: float, optional
+            ///     Solution tolerance for critical point
+            ///     and VLE algorithms.
+            /// verbosity : Verbosity, optional
+            ///     Verbosity for critical point
+            ///     and VLE algorithms.
+            ///
+            /// Returns
+            /// -------
+            /// DataSet
+            #[pyo3(text_signature = "($self, compound, extrapolate=None, max_iter=None, tol=None, verbosity=None)")]
+            fn vapor_pressure(
+                &self,
+                compound: &str,
+                extrapolate: Option<bool>,
+                max_iter: Option<usize>,
+                tol: Option<f64>,
+                verbosity: Option<Verbosity>,
+            ) -> PyResult<PyDataSet> {
+                Ok(PyDataSet(Arc::new(self.0.vapor_pressure(
+                    compound,
+                    extrapolate.unwrap_or(false),
+                    Some((max_iter, tol, verbosity).into()),
+                )?)))
+            }
+
+            /// Create a DataSet with the liquid densities of a pure compound.
+            ///
+            /// Parameters
+            /// ----------
+            /// compound : str
+            ///     The compound.
+            ///
+            /// Returns
+            /// -------
+            /// DataSet
+            #[pyo3(text_signature = "($self, compound)")]
+            fn liquid_density(&self, compound: &str) -> PyResult<PyDataSet> {
+                Ok(PyDataSet(Arc::new(self.0.liquid_density(compound)?)))
+            }
+
+            /// Create a DataSet with the saturated liquid densities
+            /// of a pure compound.
+            ///
+            /// Parameters
+            /// ----------
+            /// compound : str
+            ///     The compound.
+            /// max_iter : int, optional
+            ///     The maximum number of iterations for the VLE algorithm.
+            /// tol: float, optional
+            ///     Solution tolerance for the VLE algorithm.
+            /// verbosity : Verbosity, optional
+            ///     Verbosity for the VLE algorithm.
+            ///
+            /// Returns
+            /// -------
+            /// DataSet
+            #[pyo3(text_signature = "($self, compound, max_iter=None, tol=None, verbosity=None)")]
+            fn equilibrium_liquid_density(
+                &self,
+                compound: &str,
+                max_iter: Option<usize>,
+                tol: Option<f64>,
+                verbosity: Option<Verbosity>,
+            ) -> PyResult<PyDataSet> {
+                Ok(PyDataSet(Arc::new(self.0.equilibrium_liquid_density(
+                    compound,
+                    Some((max_iter, tol, verbosity).into()),
+                )?)))
+            }
+
+            /// Create a DataSet with the molar isobaric heat capacities
+            /// of a pure compound.
+            ///
+            /// Parameters
+            /// ----------
+            /// compound : str
+            ///     The compound.
+            /// phase : Phase
+            ///     The phase of the experimental data points.
+            ///
+            /// Returns
+            /// -------
+            /// DataSet
+            #[pyo3(text_signature = "($self, compound, phase)")]
+            fn isobaric_heat_capacity(&self, compound: &str, phase: Phase) -> PyResult<PyDataSet> {
+                Ok(PyDataSet(Arc::new(
+                    self.0.isobaric_heat_capacity(compound, phase)?,
+                )))
+            }
+
+            /// Create a DataSet with binary VLE data using the
+            /// pressure residual.
+            ///
+            /// Parameters
+            /// ----------
+            /// compounds : [str, str]
+            ///     The two compounds. The mole fractions refer to the first compound.
+            /// phase : Phase
+            ///     The phase of which the composition is used.
+            ///
+            /// Returns
+            /// -------
+            /// DataSet
+            #[pyo3(text_signature = "($self, compounds, phase)")]
+            fn binary_vle_pressure(
+                &self,
+                compounds: [&str; 2],
+                phase: Phase,
+            ) -> PyResult<PyDataSet> {
+                Ok(PyDataSet(Arc::new(
+                    self.0.binary_vle_pressure(compounds, phase)?,
+                )))
+            }
+
+            /// Create a DataSet with binary VLE data using the
+            /// chemical potential residual.
+            ///
+            /// Parameters
+            /// ----------
+            /// compounds : [str, str]
+            ///     The two compounds. The mole fractions refer to the first compound.
+            ///
+            /// Returns
+            /// -------
+            /// DataSet
+            #[pyo3(text_signature = "($self, compounds)")]
+            fn binary_vle_chemical_potential(&self, compounds: [&str; 2]) -> PyResult<PyDataSet> {
+                Ok(PyDataSet(Arc::new(
+                    self.0.binary_vle_chemical_potential(compounds)?,
+                )))
+            }
+
+            fn __repr__(&self) -> PyResult<String> {
+                Ok(format!(
+                    "ThermoML(compounds=[{}])",
+                    self.0
+                        .compounds()
+                        .iter()
+                        .map(|c| c.to_string())
+                        .collect::<Vec<_>>()
+                        .join(", ")
+                ))
+            }
+        }
+
         /// A collection of `DataSet`s that can be used to compute metrics for experimental data.
         ///
         /// Parameters
@@ -715,3 +1047,397 @@ macro_rules! impl_estimator_entropy_scaling {
         }
     };
 }
+
+#[macro_export]
+macro_rules! impl_estimator_surface_tension {
+    ($func:ty, $py_func:ty) => {
+        #[pymethods]
+        impl PyDataSet {
+            /// Create a DataSet with experimental data for the surface
+            /// tension of a pure component.
+            ///
+            /// The surface tension is calculated from the density
+            /// profile of a planar vapor-liquid interface.
+            ///
+            /// Parameters
+            /// ----------
+            /// target : SIArray1
+            ///     Experimental data for the surface tension.
+            /// temperature : SIArray1
+            ///     Temperature for experimental data points.
+            /// n_grid : int, optional
+            ///     The number of grid points of the interface.
+            ///     Defaults to 512.
+            /// max_iter : int, optional
+            ///     The maximum number of iterations for the VLE algorithm.
+            /// tol: float, optional
+            ///     Solution tolerance for the VLE algorithm.
+            /// verbosity : Verbosity, optional
+            ///     Verbosity for the VLE algorithm.
+            /// solver : DFTSolver, optional
+            ///     Custom solver options for the density profile.
+            ///
+            /// Returns
+            /// -------
+            /// DataSet
+            #[staticmethod]
+            #[pyo3(text_signature = "(target, temperature, n_grid=None, max_iter=None, tol=None, verbosity=None, solver=None)")]
+            fn surface_tension(
+                target: &PySIArray1,
+                temperature: &PySIArray1,
+                n_grid: Option<usize>,
+                max_iter: Option<usize>,
+                tol: Option<f64>,
+                verbosity: Option<Verbosity>,
+                solver: Option<PyDFTSolver>,
+            ) -> PyResult<Self> {
+                Ok(Self(Arc::new(SurfaceTension::new(
+                    target.clone().into(),
+                    temperature.clone().into(),
+                    n_grid,
+                    Some((max_iter, tol, verbosity).into()),
+                    solver.map(|s| s.0),
+                )?)))
+            }
+        }
+    };
+}
+
+#[macro_export]
+macro_rules! impl_estimator_pcsaft {
+    ($eos:ty, $py_eos:ty, $build:expr) => {
+        /// Samples of the posterior distribution of PC-SAFT parameters.
+        ///
+        /// Use ``Estimator.sample_pcsaft`` to create the samples.
+        #[pyclass(name = "Posterior")]
+        pub struct PyPosterior(Posterior<PcSaftParameters>);
+
+        #[pymethods]
+        impl PyPosterior {
+            /// The sampled parameters.
+            #[getter]
+            fn get_names(&self) -> Vec<String> {
+                self.0.names.iter().map(|n| n.to_string()).collect()
+            }
+
+            /// The samples of all walkers with one row per sample.
+            #[getter]
+            fn get_samples<'py>(&self, py: Python<'py>) -> &'py PyArray2<f64> {
+                self.0.samples.view().to_pyarray(py)
+            }
+
+            /// The logarithm of the posterior probability of every sample.
+            #[getter]
+            fn get_log_probability<'py>(&self, py: Python<'py>) -> &'py PyArray1<f64> {
+                self.0.log_probability.view().to_pyarray(py)
+            }
+
+            /// The fraction of accepted proposals.
+            #[getter]
+            fn get_acceptance_fraction(&self) -> f64 {
+                self.0.acceptance_fraction
+            }
+
+            /// The mean of every parameter.
+            ///
+            /// Returns
+            /// -------
+            /// numpy.ndarray[Float]
+            fn mean<'py>(&self, py: Python<'py>) -> &'py PyArray1<f64> {
+                self.0.mean().view().to_pyarray(py)
+            }
+
+            /// The standard deviation of every parameter.
+            ///
+            /// Returns
+            /// -------
+            /// numpy.ndarray[Float]
+            fn std<'py>(&self, py: Python<'py>) -> &'py PyArray1<f64> {
+                self.0.std().view().to_pyarray(py)
+            }
+
+            /// The quantile of every parameter.
+            ///
+            /// Parameters
+            /// ----------
+            /// q : float
+            ///     The quantile between 0 and 1.
+            ///
+            /// Returns
+            /// -------
+            /// numpy.ndarray[Float]
+            #[pyo3(text_signature = "($self, q)")]
+            fn quantile<'py>(&self, q: f64, py: Python<'py>) -> &'py PyArray1<f64> {
+                self.0.quantile(q).view().to_pyarray(py)
+            }
+
+            /// The parameter set of the sample with the highest
+            /// posterior probability.
+            ///
+            /// Returns
+            /// -------
+            /// PcSaftParameters
+            fn maximum_a_posteriori(&self) -> PyResult<PyPcSaftParameters> {
+                Ok(PyPcSaftParameters(Arc::new(
+                    self.0.maximum_a_posteriori()?,
+                )))
+            }
+
+            fn __repr__(&self) -> PyResult<String> {
+                Ok(self.0.to_string())
+            }
+        }
+
+        #[pymethods]
+        impl PyEstimator {
+            /// Adjust PC-SAFT parameters to the experimental data.
+            ///
+            /// Parameters
+            /// ----------
+            /// parameters : PcSaftParameters
+            ///     The initial parameter set.
+            /// names : List[str]
+            ///     The parameters that are adjusted, e.g. 'sigma[0]',
+            ///     'epsilon_k[0]', 'm[0]', 'mu[0]', 'kappa_ab[0]',
+            ///     'epsilon_k_ab[0]' or 'k_ij[0,1]'. The association
+            ///     parameters of additional association sites are
+            ///     adjusted with, e.g., 'epsilon_k_ab[0,1]'.
+            /// bounds : List[(float, float)], optional
+            ///     Lower and upper bound of every adjusted parameter.
+            /// optimizer : Optimizer, optional
+            ///     The optimization algorithm.
+            ///     Defaults to Optimizer.LevenbergMarquardt.
+            /// max_iter : int, optional
+            ///     The maximum number of iterations of the optimizer.
+            /// tol: float, optional
+            ///     Convergence tolerance of the optimizer.
+            /// verbosity : Verbosity, optional
+            ///     Verbosity of the optimizer.
+            /// callback : Callable[[str, int, float], bool], optional
+            ///     Called with the arguments `(task, iteration, cost)` in
+            ///     every iteration of the optimizer. Returning `False`
+            ///     cancels the regression.
+            ///
+            /// Returns
+            /// -------
+            /// (PcSaftParameters, float)
+            ///     The fitted parameters and the sum of squared costs.
+            #[pyo3(
+                signature = (parameters, names, bounds=None, optimizer=Optimizer::LevenbergMarquardt, max_iter=None, tol=None, verbosity=None, callback=None),
+                text_signature = "($self, parameters, names, bounds=None, optimizer=None, max_iter=None, tol=None, verbosity=None, callback=None)"
+            )]
+            fn fit_pcsaft(
+                &self,
+                parameters: PyPcSaftParameters,
+                names: Vec<&str>,
+                bounds: Option<Vec<(f64, f64)>>,
+                optimizer: Optimizer,
+                max_iter: Option<usize>,
+                tol: Option<f64>,
+                verbosity: Option<Verbosity>,
+                callback: Option<feos_core::python::progress::PyProgressCallback>,
+            ) -> PyResult<(PyPcSaftParameters, f64)> {
+                let names = names
+                    .iter()
+                    .map(|n| n.parse())
+                    .collect::<Result<Vec<PcSaftFitParameter>, _>>()?;
+                let result = self.0.fit(
+                    parameters.0.as_ref(),
+                    &names,
+                    bounds.as_deref(),
+                    $build,
+                    optimizer,
+                    (max_iter, tol, verbosity).into(),
+                    callback.as_ref().map(|c| c as &dyn feos_core::ProgressCallback),
+                )?;
+                Ok((PyPcSaftParameters(result.parameters), result.cost))
+            }
+
+            /// Adjust the PC-SAFT binary interaction parameters of several
+            /// binary subsystems.
+            ///
+            /// Every binary subsystem is fitted individually and the
+            /// resulting binary records are collected in the full
+            /// parameter set.
+            ///
+            /// Parameters
+            /// ----------
+            /// parameters : PcSaftParameters
+            ///     The initial parameters of all components.
+            /// systems : List[(int, int, Estimator)]
+            ///     The indices of the two components of each binary
+            ///     subsystem and the corresponding experimental data.
+            /// l_ij : bool, optional
+            ///     Whether l_ij is adjusted in addition to k_ij.
+            ///     Defaults to False.
+            /// bounds : List[(float, float)], optional
+            ///     Lower and upper bound of k_ij (and l_ij).
+            /// optimizer : Optimizer, optional
+            ///     The optimization algorithm.
+            ///     Defaults to Optimizer.LevenbergMarquardt.
+            /// max_iter : int, optional
+            ///     The maximum number of iterations of the optimizer.
+            /// tol: float, optional
+            ///     Convergence tolerance of the optimizer.
+            /// verbosity : Verbosity, optional
+            ///     Verbosity of the optimizer.
+            /// path : str, optional
+            ///     Write the fitted binary records to this json file.
+            /// callback : Callable[[str, int, float], bool], optional
+            ///     Called with the arguments `(task, iteration, cost)` in
+            ///     every iteration of the optimizer. Returning `False`
+            ///     cancels the regression.
+            ///
+            /// Returns
+            /// -------
+            /// PcSaftParameters
+            ///     The parameters including all fitted binary records.
+            #[staticmethod]
+            #[pyo3(
+                signature = (parameters, systems, l_ij=false, bounds=None, optimizer=Optimizer::LevenbergMarquardt, max_iter=None, tol=None, verbosity=None, path=None, callback=None),
+                text_signature = "(parameters, systems, l_ij=False, bounds=None, optimizer=None, max_iter=None, tol=None, verbosity=None, path=None, callback=None)"
+            )]
+            fn fit_binary_pcsaft(
+                parameters: PyPcSaftParameters,
+                systems: Vec<(usize, usize, PyRef<PyEstimator>)>,
+                l_ij: bool,
+                bounds: Option<Vec<(f64, f64)>>,
+                optimizer: Optimizer,
+                max_iter: Option<usize>,
+                tol: Option<f64>,
+                verbosity: Option<Verbosity>,
+                path: Option<&str>,
+                callback: Option<feos_core::python::progress::PyProgressCallback>,
+            ) -> PyResult<PyPcSaftParameters> {
+                let systems: Vec<_> = systems
+                    .iter()
+                    .map(|(i, j, estimator)| BinarySystem::new([*i, *j], &estimator.0))
+                    .collect();
+                let mut names = vec![PcSaftFitParameter::Kij(0, 1)];
+                if l_ij {
+                    names.push(PcSaftFitParameter::Lij(0, 1));
+                }
+                let regression = BinaryRegression::fit(
+                    parameters.0.as_ref(),
+                    &systems,
+                    &names,
+                    bounds.as_deref(),
+                    $build,
+                    optimizer,
+                    (max_iter, tol, verbosity).into(),
+                    callback.as_ref().map(|c| c as &dyn feos_core::ProgressCallback),
+                )?;
+                if let Some(path) = path {
+                    regression.to_json(path)?;
+                }
+                Ok(PyPcSaftParameters(regression.parameters))
+            }
+
+            /// Sample the posterior distribution of PC-SAFT parameters with
+            /// an affine invariant ensemble sampler.
+            ///
+            /// The prior is uniform within the bounds. The likelihood assumes
+            /// normally distributed costs with unknown variance.
+            ///
+            /// Parameters
+            /// ----------
+            /// parameters : PcSaftParameters
+            ///     The parameter set around which the walkers are initialized,
+            ///     typically the result of ``Estimator.fit_pcsaft``.
+            /// names : List[str]
+            ///     The sampled parameters, e.g. 'sigma[0]' or 'k_ij[0,1]'.
+            /// bounds : List[(float, float)], optional
+            ///     Lower and upper bound of every sampled parameter.
+            /// n_walkers : int, optional
+            ///     The number of walkers.
+            ///     Defaults to four times the number of parameters (at least 8).
+            /// n_steps : int, optional
+            ///     The number of steps of every walker. Defaults to 1000.
+            /// burn_in : int, optional
+            ///     The number of discarded initial steps.
+            ///     Defaults to a quarter of the steps.
+            /// thin : int, optional
+            ///     Only every `thin`-th step is stored. Defaults to 1.
+            /// seed : int, optional
+            ///     Seed of the random number generator.
+            /// verbosity : Verbosity, optional
+            ///     Verbosity of the sampler.
+            ///
+            /// Returns
+            /// -------
+            /// Posterior
+            #[pyo3(
+                signature = (parameters, names, bounds=None, n_walkers=None, n_steps=None, burn_in=None, thin=None, seed=None, verbosity=None),
+                text_signature = "($self, parameters, names, bounds=None, n_walkers=None, n_steps=None, burn_in=None, thin=None, seed=None, verbosity=None)"
+            )]
+            fn sample_pcsaft(
+                &self,
+                parameters: PyPcSaftParameters,
+                names: Vec<&str>,
+                bounds: Option<Vec<(f64, f64)>>,
+                n_walkers: Option<usize>,
+                n_steps: Option<usize>,
+                burn_in: Option<usize>,
+                thin: Option<usize>,
+                seed: Option<u64>,
+                verbosity: Option<Verbosity>,
+            ) -> PyResult<PyPosterior> {
+                let names = names
+                    .iter()
+                    .map(|n| n.parse())
+                    .collect::<Result<Vec<PcSaftFitParameter>, _>>()?;
+                let options = SamplerOptions {
+                    n_walkers,
+                    n_steps,
+                    burn_in,
+                    thin,
+                    seed,
+                    verbosity: verbosity.unwrap_or(Verbosity::None),
+                };
+                Ok(PyPosterior(self.0.sample(
+                    &parameters.0,
+                    &names,
+                    bounds.as_deref(),
+                    $build,
+                    options,
+                )?))
+            }
+
+            /// Propagate the parameter uncertainty to the predictions
+            /// of every ``DataSet``.
+            ///
+            /// Parameters
+            /// ----------
+            /// posterior : Posterior
+            ///     The samples of the parameters.
+            /// confidence : float, optional
+            ///     The confidence level. Defaults to 0.95.
+            /// n_samples : int, optional
+            ///     The maximum number of (evenly spaced) samples that are
+            ///     evaluated. Defaults to all samples.
+            ///
+            /// Returns
+            /// -------
+            /// List[(SIArray1, SIArray1, SIArray1)]
+            ///     The lower bound, median and upper bound of the
+            ///     predictions for every ``DataSet``.
+            #[pyo3(
+                signature = (posterior, confidence=0.95, n_samples=None),
+                text_signature = "($self, posterior, confidence=0.95, n_samples=None)"
+            )]
+            fn prediction_bands_pcsaft(
+                &self,
+                posterior: &PyPosterior,
+                confidence: f64,
+                n_samples: Option<usize>,
+            ) -> PyResult<Vec<(PySIArray1, PySIArray1, PySIArray1)>> {
+                Ok(self
+                    .0
+                    .prediction_bands(&posterior.0, $build, confidence, n_samples)?
+                    .into_iter()
+                    .map(|b| (b.lower.into(), b.median.into(), b.upper.into()))
+                    .collect())
+            }
+        }
+    };
+}
@@ -0,0 +1,556 @@
+//! Optimization of model parameters to experimental data.
+use super::{Estimator, EstimatorError};
+use feos_core::parameter::Parameter;
+use feos_core::{
+    log_iter, log_result, EquationOfState, ProgressCallback, SolverOptions, Verbosity,
+};
+use ndarray::{Array1, Array2, Axis};
+use num_dual::linalg::LU;
+use std::fmt;
+use std::sync::Arc;
+
+const MAX_ITER_LM: usize = 100;
+const MAX_ITER_NM: usize = 2000;
+const TOL: f64 = 1e-8;
+const MAX_LAMBDA: f64 = 1e10;
+
+/// Parameter sets whose individual parameters can be adjusted in a regression.
+pub trait RegressionParameters: Parameter {
+    /// Identifier of a single adjustable parameter.
+    type Name: Clone + fmt::Display;
+
+    /// Return the value of a single parameter.
+    fn get_parameter(&self, name: &Self::Name) -> Result<f64, EstimatorError>;
+
+    /// Return a copy of the parameter set in which the given parameters are replaced.
+    fn set_parameters(&self, names: &[Self::Name], values: &[f64]) -> Result<Self, EstimatorError>;
+}
+
+/// Algorithms to minimize the cost function of an [Estimator].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "python", pyo3::pyclass)]
+pub enum Optimizer {
+    /// Levenberg-Marquardt algorithm with a finite difference jacobian.
+    LevenbergMarquardt,
+    /// Derivative-free simplex algorithm of Nelder and Mead.
+    NelderMead,
+}
+
+/// The result of a parameter regression.
+pub struct RegressionResult<P: RegressionParameters> {
+    /// The fitted parameter set.
+    pub parameters: Arc<P>,
+    /// The adjusted parameters.
+    pub names: Vec<P::Name>,
+    /// The fitted values of the adjusted parameters.
+    pub values: Array1<f64>,
+    /// The sum of the squared costs of all data points.
+    pub cost: f64,
+    /// The number of iterations of the optimizer.
+    pub iterations: usize,
+    /// Whether the optimizer converged within the maximum number of iterations.
+    pub converged: bool,
+}
+
+impl<P: RegressionParameters> fmt::Display for RegressionResult<P> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "RegressionResult(")?;
+        for (name, value) in self.names.iter().zip(self.values.iter()) {
+            write!(f, "{}={}, ", name, value)?;
+        }
+        write!(
+            f,
+            "cost={}, iterations={}, converged={})",
+            self.cost, self.iterations, self.converged
+        )
+    }
+}
+
+impl<E: EquationOfState> Estimator<E> {
+    /// Adjust the parameters `names` of `parameters` to minimize the cost function.
+    ///
+    /// The equation of state for a given parameter set is created by `eos`. If
+    /// `bounds` are provided, they contain the lower and upper bound of every
+    /// adjusted parameter. The optional `callback` is invoked with the cost
+    /// in every iteration of the optimizer and can cancel the regression.
+    pub fn fit<P, F>(
+        &self,
+        parameters: &P,
+        names: &[P::Name],
+        bounds: Option<&[(f64, f64)]>,
+        eos: F,
+        optimizer: Optimizer,
+        options: SolverOptions,
+        callback: Option<&dyn ProgressCallback>,
+    ) -> Result<RegressionResult<P>, EstimatorError>
+    where
+        P: RegressionParameters,
+        F: Fn(Arc<P>) -> E,
+    {
+        let bounds = check_bounds(bounds, names.len())?;
+        let x0 = names
+            .iter()
+            .map(|n| parameters.get_parameter(n))
+            .collect::<Result<Array1<_>, _>>()?;
+
+        let residuals = |x: &Array1<f64>| -> Result<Array1<f64>, EstimatorError> {
+            let p = parameters.set_parameters(names, x.as_slice().unwrap())?;
+            self.cost(&Arc::new(eos(Arc::new(p))))
+        };
+        let (values, cost, iterations, converged) = match optimizer {
+            Optimizer::LevenbergMarquardt => {
+                levenberg_marquardt(residuals, x0, &bounds, options, callback)?
+            }
+            Optimizer::NelderMead => nelder_mead(
+                |x| {
+                    let r = residuals(x)?;
+                    Ok(r.dot(&r))
+                },
+                x0,
+                &bounds,
+                options,
+                callback,
+            )?,
+        };
+        Ok(RegressionResult {
+            parameters: Arc::new(parameters.set_parameters(names, values.as_slice().unwrap())?),
+            names: names.to_vec(),
+            values,
+            cost,
+            iterations,
+            converged,
+        })
+    }
+}
+
+/// Return the bounds of all parameters, unbounded if none are given.
+pub(crate) fn check_bounds(
+    bounds: Option<&[(f64, f64)]>,
+    n: usize,
+) -> Result<Vec<(f64, f64)>, EstimatorError> {
+    match bounds {
+        Some(bounds) if bounds.len() != n => Err(EstimatorError::InvalidParameter(format!(
+            "{} bounds were given for {} parameters.",
+            bounds.len(),
+            n
+        ))),
+        Some(bounds) => Ok(bounds.to_vec()),
+        None => Ok(vec![(f64::NEG_INFINITY, f64::INFINITY); n]),
+    }
+}
+
+fn clamp(x: &Array1<f64>, bounds: &[(f64, f64)]) -> Array1<f64> {
+    Array1::from_shape_fn(x.len(), |i| x[i].max(bounds[i].0).min(bounds[i].1))
+}
+
+/// Minimize the sum of squared residuals with the Levenberg-Marquardt algorithm.
+///
+/// Steps are projected onto the bounds. Residuals that cannot be evaluated
+/// lead to a rejection of the step. If a finite difference of the jacobian
+/// cannot be evaluated, the step into the opposite direction is used. If no
+/// step reduces the cost, the
+/// optimization is only considered converged if the gradient (projected onto
+/// the bounds) vanishes.
+pub(crate) fn levenberg_marquardt<F>(
+    residuals: F,
+    x0: Array1<f64>,
+    bounds: &[(f64, f64)],
+    options: SolverOptions,
+    callback: Option<&dyn ProgressCallback>,
+) -> Result<(Array1<f64>, f64, usize, bool), EstimatorError>
+where
+    F: Fn(&Array1<f64>) -> Result<Array1<f64>, EstimatorError>,
+{
+    let (max_iter, tol, verbosity) = options.unwrap_or(MAX_ITER_LM, TOL);
+    let evaluate = |x: &Array1<f64>| {
+        residuals(x)
+            .ok()
+            .filter(|r| r.iter().all(|r| r.is_finite()))
+    };
+
+    let mut x = clamp(&x0, bounds);
+    let mut r = evaluate(&x).ok_or_else(|| {
+        EstimatorError::InvalidParameter(
+            "The cost function can not be evaluated for the initial parameters.".into(),
+        )
+    })?;
+    let mut cost = r.dot(&r);
+    let mut lambda = 1e-3;
+
+    log_iter!(
+        verbosity,
+        " iter |    cost      |    lambda    | parameters"
+    );
+    log_iter!(verbosity, "{:-<64}", "");
+    log_iter!(
+        verbosity,
+        " {:4} | {:12.6e} |              | {}",
+        0,
+        cost,
+        x
+    );
+
+    for k in 1..=max_iter {
+        // finite difference jacobian, steps point into the feasible region
+        let mut jacobian = Array2::zeros((r.len(), x.len()));
+        for (i, &(lower, upper)) in bounds.iter().enumerate() {
+            let h = f64::EPSILON.sqrt() * x[i].abs().max(1.0);
+            let steps = if x[i] + h > upper { [-h, h] } else { [h, -h] };
+            let mut column = None;
+            let mut feasible = false;
+            for h in steps {
+                if x[i] + h < lower || x[i] + h > upper {
+                    continue;
+                }
+                feasible = true;
+                let mut x_h = x.clone();
+                x_h[i] += h;
+                if let Some(r_h) = evaluate(&x_h) {
+                    column = Some((r_h - &r) / h);
+                    break;
+                }
+            }
+            match column {
+                Some(column) => jacobian.index_axis_mut(Axis(1), i).assign(&column),
+                // the parameter is fixed by its bounds
+                None if !feasible => (),
+                None => {
+                    return Err(EstimatorError::InvalidParameter(format!(
+                        "The jacobian can not be evaluated for parameter {} at {}.",
+                        i, x[i]
+                    )))
+                }
+            }
+        }
+        let gradient = jacobian.t().dot(&r);
+        let hessian = jacobian.t().dot(&jacobian);
+
+        // increase the damping until the cost decreases
+        let mut step = None;
+        while lambda < MAX_LAMBDA {
+            let mut a = hessian.clone();
+            for i in 0..x.len() {
+                a[(i, i)] += lambda * hessian[(i, i)].max(TOL);
+            }
+            let dx = match LU::new(a) {
+                Ok(lu) => lu.solve(&(-&gradient)),
+                // a singular system is treated like a rejected step
+                Err(_) => {
+                    lambda *= 10.0;
+                    continue;
+                }
+            };
+            let x_new = clamp(&(&x + &dx), bounds);
+            if let Some(r_new) = evaluate(&x_new) {
+                let cost_new = r_new.dot(&r_new);
+                if cost_new < cost {
+                    lambda = (lambda * 0.1).max(1e-12);
+                    step = Some((x_new, r_new, cost_new));
+                    break;
+                }
+            }
+            lambda *= 10.0;
+        }
+
+        let (x_new, r_new, cost_new) = match step {
+            Some(step) => step,
+            None => {
+                // no further improvement possible, check the relative change of
+                // the cost with respect to the parameters at the current point
+                let converged = bounds.iter().enumerate().all(|(i, &(lower, upper))| {
+                    let g = gradient[i];
+                    (x[i] <= lower && g > 0.0)
+                        || (x[i] >= upper && g < 0.0)
+                        || g.abs() * x[i].abs().max(1.0) <= tol.sqrt() * (cost + tol)
+                });
+                if converged {
+                    log_result!(
+                        verbosity,
+                        "Levenberg-Marquardt: converged in {} step(s)\n",
+                        k - 1
+                    );
+                } else {
+                    log_result!(
+                        verbosity,
+                        "Levenberg-Marquardt: not converged in {} step(s), the cost can not be reduced\n",
+                        k - 1
+                    );
+                }
+                return Ok((x, cost, k - 1, converged));
+            }
+        };
+        let dx = (&x_new - &x).mapv(f64::abs).sum();
+        let dcost = cost - cost_new;
+        x = x_new;
+        r = r_new;
+        cost = cost_new;
+        log_iter!(
+            verbosity,
+            " {:4} | {:12.6e} | {:12.6e} | {}",
+            k,
+            cost,
+            lambda,
+            x
+        );
+        if let Some(callback) = callback {
+            callback.check("Levenberg-Marquardt", k, cost)?;
+        }
+
+        if dcost <= tol * cost || dx <= tol * (x.mapv(f64::abs).sum() + tol) {
+            log_result!(
+                verbosity,
+                "Levenberg-Marquardt: converged in {} step(s)\n",
+                k
+            );
+            return Ok((x, cost, k, true));
+        }
+    }
+    log_result!(
+        verbosity,
+        "Levenberg-Marquardt: not converged in {} step(s)\n",
+        max_iter
+    );
+    Ok((x, cost, max_iter, false))
+}
+
+/// Minimize an objective function with the Nelder-Mead simplex algorithm.
+///
+/// Vertices are projected onto the bounds. Points at which the objective
+/// function cannot be evaluated are treated as infinitely bad.
+pub(crate) fn nelder_mead<F>(
+    objective: F,
+    x0: Array1<f64>,
+    bounds: &[(f64, f64)],
+    options: SolverOptions,
+    callback: Option<&dyn ProgressCallback>,
+) -> Result<(Array1<f64>, f64, usize, bool), EstimatorError>
+where
+    F: Fn(&Array1<f64>) -> Result<f64, EstimatorError>,
+{
+    let (max_iter, tol, verbosity) = options.unwrap_or(MAX_ITER_NM, TOL);
+    let evaluate = |x: &Array1<f64>| {
+        objective(x)
+            .ok()
+            .filter(|f| f.is_finite())
+            .unwrap_or(f64::INFINITY)
+    };
+    let n = x0.len();
+
+    // initial simplex
+    let x0 = clamp(&x0, bounds);
+    let mut simplex = vec![x0.clone()];
+    for i in 0..n {
+        let mut x = x0.clone();
+        x[i] = if x[i] != 0.0 { 1.05 * x[i] } else { 2.5e-4 };
+        let x = clamp(&x, bounds);
+        // step into the other direction if the vertex is at the bound
+        simplex.push(if x == x0 {
+            let mut x = x0.clone();
+            x[i] -= 0.05 * x0[i].abs().max(5e-3);
+            clamp(&x, bounds)
+        } else {
+            x
+        });
+    }
+    let mut f: Vec<_> = simplex.iter().map(evaluate).collect();
+    if !f[0].is_finite() {
+        return Err(EstimatorError::InvalidParameter(
+            "The cost function can not be evaluated for the initial parameters.".into(),
+        ));
+    }
+
+    log_iter!(verbosity, " iter |    cost      | parameters");
+    log_iter!(verbosity, "{:-<64}", "");
+
+    for k in 1..=max_iter {
+        // sort vertices
+        let mut order: Vec<_> = (0..=n).collect();
+        order.sort_by(|&a, &b| f[a].partial_cmp(&f[b]).unwrap());
+        simplex = order.iter().map(|&i| simplex[i].clone()).collect();
+        f = order.iter().map(|&i| f[i]).collect();
+        log_iter!(verbosity, " {:4} | {:12.6e} | {}", k, f[0], simplex[0]);
+        if let Some(callback) = callback {
+            callback.check("Nelder-Mead", k, f[0])?;
+        }
+
+        let size = simplex[1..]
+            .iter()
+            .map(|x| (x - &simplex[0]).mapv(f64::abs).sum())
+            .fold(0.0, f64::max);
+        if f[n] - f[0] <= tol * (f[0].abs() + tol)
+            && size <= tol.sqrt() * (simplex[0].mapv(f64::abs).sum() + tol)
+        {
+            log_result!(verbosity, "Nelder-Mead: converged in {} step(s)\n", k);
+            return Ok((simplex.swap_remove(0), f[0], k, true));
+        }
+
+        let centroid = simplex[..n].iter().fold(Array1::zeros(n), |acc, x| acc + x) / n as f64;
+        let reflected = clamp(&(&centroid * 2.0 - &simplex[n]), bounds);
+        let f_reflected = evaluate(&reflected);
+        if f_reflected < f[0] {
+            let expanded = clamp(&(&centroid * 3.0 - &simplex[n] * 2.0), bounds);
+            let f_expanded = evaluate(&expanded);
+            if f_expanded < f_reflected {
+                simplex[n] = expanded;
+                f[n] = f_expanded;
+            } else {
+                simplex[n] = reflected;
+                f[n] = f_reflected;
+            }
+        } else if f_reflected < f[n - 1] {
+            simplex[n] = reflected;
+            f[n] = f_reflected;
+        } else {
+            let contracted = if f_reflected < f[n] {
+                (&centroid + &reflected) * 0.5
+            } else {
+                (&centroid + &simplex[n]) * 0.5
+            };
+            let f_contracted = evaluate(&contracted);
+            if f_contracted < f[n].min(f_reflected) {
+                simplex[n] = contracted;
+                f[n] = f_contracted;
+            } else {
+                // shrink towards the best vertex
+                let best = simplex[0].clone();
+                for (x, fx) in simplex.iter_mut().zip(f.iter_mut()).skip(1) {
+                    *x = (&best + &*x) * 0.5;
+                    *fx = evaluate(x);
+                }
+            }
+        }
+    }
+    let best = (0..=n)
+        .min_by(|&a, &b| f[a].partial_cmp(&f[b]).unwrap())
+        .unwrap();
+    log_result!(
+        verbosity,
+        "Nelder-Mead: not converged in {} step(s)\n",
+        max_iter
+    );
+    Ok((simplex.swap_remove(best), f[best], max_iter, false))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_relative_eq;
+    use ndarray::arr1;
+
+    fn rosenbrock(x: &Array1<f64>) -> Result<Array1<f64>, EstimatorError> {
+        Ok(arr1(&[10.0 * (x[1] - x[0] * x[0]), 1.0 - x[0]]))
+    }
+
+    #[test]
+    fn levenberg_marquardt_rosenbrock() -> Result<(), EstimatorError> {
+        let bounds = [(f64::NEG_INFINITY, f64::INFINITY); 2];
+        let (x, cost, _, converged) = levenberg_marquardt(
+            rosenbrock,
+            arr1(&[-1.2, 1.0]),
+            &bounds,
+            SolverOptions::default(),
+            None,
+        )?;
+        assert!(converged);
+        assert!(cost < 1e-12);
+        assert_relative_eq!(x, arr1(&[1.0, 1.0]), epsilon = 1e-5);
+        Ok(())
+    }
+
+    #[test]
+    fn levenberg_marquardt_stuck() -> Result<(), EstimatorError> {
+        // the cost decreases towards x = 0, but can not be evaluated for x < 1
+        let residuals = |x: &Array1<f64>| {
+            if x[0] < 1.0 {
+                Err(EstimatorError::InvalidParameter("x < 1".into()))
+            } else {
+                Ok(x.clone())
+            }
+        };
+        let bounds = [(f64::NEG_INFINITY, f64::INFINITY)];
+        let (x, _, iterations, converged) = levenberg_marquardt(
+            residuals,
+            arr1(&[1.0]),
+            &bounds,
+            SolverOptions::default(),
+            None,
+        )?;
+        assert!(!converged);
+        assert_eq!(iterations, 0);
+        assert_eq!(x[0], 1.0);
+        Ok(())
+    }
+
+    #[test]
+    fn levenberg_marquardt_one_sided_jacobian() -> Result<(), EstimatorError> {
+        // the cost can not be evaluated for x > 2, the jacobian at the
+        // initial point requires a backward difference
+        let residuals = |x: &Array1<f64>| {
+            if x[0] > 2.0 {
+                Err(EstimatorError::InvalidParameter("x > 2".into()))
+            } else {
+                Ok(x - 1.5)
+            }
+        };
+        let bounds = [(f64::NEG_INFINITY, f64::INFINITY)];
+        let (x, _, _, converged) = levenberg_marquardt(
+            residuals,
+            arr1(&[2.0]),
+            &bounds,
+            SolverOptions::default(),
+            None,
+        )?;
+        assert!(converged);
+        assert_relative_eq!(x[0], 1.5, epsilon = 1e-6);
+
+        // the jacobian can not be evaluated in either direction
+        let residuals = |x: &Array1<f64>| {
+            if x[0] != 2.0 {
+                Err(EstimatorError::InvalidParameter("x != 2".into()))
+            } else {
+                Ok(x - 1.5)
+            }
+        };
+        assert!(levenberg_marquardt(
+            residuals,
+            arr1(&[2.0]),
+            &bounds,
+            SolverOptions::default(),
+            None,
+        )
+        .is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn nelder_mead_rosenbrock() -> Result<(), EstimatorError> {
+        let bounds = [(f64::NEG_INFINITY, f64::INFINITY); 2];
+        let (x, _, _, converged) = nelder_mead(
+            |x| {
+                let r = rosenbrock(x)?;
+                Ok(r.dot(&r))
+            },
+            arr1(&[-1.2, 1.0]),
+            &bounds,
+            SolverOptions::default().tol(1e-12),
+            None,
+        )?;
+        assert!(converged);
+        assert_relative_eq!(x, arr1(&[1.0, 1.0]), epsilon = 1e-4);
+        Ok(())
+    }
+
+    #[test]
+    fn bounds() -> Result<(), EstimatorError> {
+        let bounds = [(f64::NEG_INFINITY, 0.5), (f64::NEG_INFINITY, f64::INFINITY)];
+        let (x, _, _, _) = levenberg_marquardt(
+            rosenbrock,
+            arr1(&[-1.2, 1.0]),
+            &bounds,
+            SolverOptions::default(),
+            None,
+        )?;
+        assert_relative_eq!(x, arr1(&[0.5, 0.25]), epsilon = 1e-5);
+        Ok(())
+    }
+}
@@ -0,0 +1,101 @@
+use super::batch::map_datapoints;
+use super::{DataSet, EstimatorError};
+use feos_core::{EosUnit, PhaseEquilibrium, SolverOptions};
+use feos_dft::interface::PlanarInterface;
+use feos_dft::{DFTSolver, HelmholtzEnergyFunctional, DFT};
+use quantity::si::{SIArray1, SINumber, SIUnit};
+use std::collections::HashMap;
+use std::sync::Arc;
+
+const DEFAULT_GRID_POINTS: usize = 512;
+
+/// Surface tension data of a pure component at vapor-liquid equilibrium.
+///
+/// The surface tension is calculated from the density profile of
+/// a planar interface and therefore requires a Helmholtz energy functional.
+#[derive(Clone)]
+pub struct SurfaceTension {
+    /// surface tension
+    pub target: SIArray1,
+    /// temperature
+    temperature: SIArray1,
+    /// number of grid points of the interface
+    n_grid: usize,
+    vle_options: SolverOptions,
+    solver: Option<DFTSolver>,
+}
+
+impl SurfaceTension {
+    /// A new data set for surface tensions with temperatures as input.
+    pub fn new(
+        target: SIArray1,
+        temperature: SIArray1,
+        n_grid: Option<usize>,
+        vle_options: Option<SolverOptions>,
+        solver: Option<DFTSolver>,
+    ) -> Result<Self, EstimatorError> {
+        Ok(Self {
+            target,
+            temperature,
+            n_grid: n_grid.unwrap_or(DEFAULT_GRID_POINTS),
+            vle_options: vle_options.unwrap_or_default(),
+            solver,
+        })
+    }
+
+    /// Returns temperature of data points.
+    pub fn temperature(&self) -> SIArray1 {
+        self.temperature.clone()
+    }
+
+    fn surface_tension<F: HelmholtzEnergyFunctional>(
+        &self,
+        func: &Arc<DFT<F>>,
+        temperature: SINumber,
+    ) -> Result<SINumber, EstimatorError> {
+        let vle = PhaseEquilibrium::pure(func, temperature, None, self.vle_options)?;
+        // initialize with pDGT for single segments and tanh for segment DFT
+        let interface = if func.component_index().len() == 1 {
+            PlanarInterface::from_pdgt(&vle, self.n_grid, false)
+        } else {
+            PlanarInterface::from_tanh(
+                &vle,
+                self.n_grid,
+                100.0 * SIUnit::reference_length(),
+                500.0 * SIUnit::reference_temperature(),
+                false,
+            )
+        }?
+        .solve(self.solver.as_ref())?;
+        Ok(interface.surface_tension.unwrap())
+    }
+}
+
+impl<F: HelmholtzEnergyFunctional> DataSet<DFT<F>> for SurfaceTension {
+    fn target(&self) -> &SIArray1 {
+        &self.target
+    }
+
+    fn target_str(&self) -> &str {
+        "surface tension"
+    }
+
+    fn input_str(&self) -> Vec<&str> {
+        vec!["temperature"]
+    }
+
+    fn predict(&self, func: &Arc<DFT<F>>) -> Result<SIArray1, EstimatorError> {
+        Ok(map_datapoints(self.temperature.len(), |i| {
+            self.surface_tension(func, self.temperature.get(i))
+                .unwrap_or_else(|_| f64::NAN * SIUnit::reference_surface_tension())
+        })
+        .into_iter()
+        .collect())
+    }
+
+    fn get_input(&self) -> HashMap<String, SIArray1> {
+        let mut m = HashMap::with_capacity(1);
+        m.insert("temperature".to_owned(), self.temperature());
+        m
+    }
+}
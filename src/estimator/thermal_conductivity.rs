@@ -1,3 +1,4 @@
+use super::batch::map_datapoints;
 use super::{DataSet, EstimatorError};
 use feos_core::{DensityInitialization, EntropyScaling, EosUnit, EquationOfState, State};
 use ndarray::{arr1, Array1};
@@ -66,22 +67,20 @@ impl<E: EquationOfState + EntropyScaling> DataSet<E> for ThermalConductivity {
             / SIUnit::reference_temperature()
             / SIUnit::reference_length();
 
-        let res = ts
-            .iter()
-            .zip(ps.iter())
-            .map(|(&t, &p)| {
-                State::new_npt(
-                    eos,
-                    t * SIUnit::reference_temperature(),
-                    p * SIUnit::reference_pressure(),
-                    &moles,
-                    DensityInitialization::None,
-                )?
-                .thermal_conductivity()?
-                .to_reduced(unit)
-                .map_err(EstimatorError::from)
-            })
-            .collect::<Result<Vec<f64>, EstimatorError>>();
+        let res = map_datapoints(ts.len(), |i| {
+            State::new_npt(
+                eos,
+                ts[i] * SIUnit::reference_temperature(),
+                ps[i] * SIUnit::reference_pressure(),
+                &moles,
+                DensityInitialization::None,
+            )?
+            .thermal_conductivity()?
+            .to_reduced(unit)
+            .map_err(EstimatorError::from)
+        })
+        .into_iter()
+        .collect::<Result<Vec<f64>, EstimatorError>>();
         Ok(Array1::from_vec(res?) * unit)
     }
 
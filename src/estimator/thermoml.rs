@@ -0,0 +1,634 @@
+//! Import of experimental data from ThermoML files.
+//!
+//! [ThermoML](https://trc.nist.gov/ThermoML/) is the IUPAC standard XML format
+//! for experimental thermophysical property data that is, e.g., used by the
+//! NIST Thermodynamics Research Center to publish the data of several journals.
+use super::{
+    BinaryVleChemicalPotential, BinaryVlePressure, EquilibriumLiquidDensity, EstimatorError,
+    IsobaricHeatCapacity, LiquidDensity, Phase, VaporPressure,
+};
+use feos_core::parameter::Identifier;
+use feos_core::SolverOptions;
+use ndarray::Array1;
+use quantity::si::{SIArray1, JOULE, KELVIN, KILO, KILOGRAM, METER, MOL, PASCAL};
+use roxmltree::{Document, Node};
+use std::collections::HashMap;
+use std::fs;
+use std::path::Path;
+
+/// Phases as they are specified in ThermoML files.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+enum ThermoMLPhase {
+    Liquid,
+    Gas,
+    Other,
+}
+
+impl From<&str> for ThermoMLPhase {
+    fn from(phase: &str) -> Self {
+        match phase {
+            "Liquid" => Self::Liquid,
+            "Gas" => Self::Gas,
+            _ => Self::Other,
+        }
+    }
+}
+
+impl From<Phase> for ThermoMLPhase {
+    fn from(phase: Phase) -> Self {
+        match phase {
+            Phase::Liquid => Self::Liquid,
+            Phase::Vapor => Self::Gas,
+        }
+    }
+}
+
+/// The supported variables and properties in their ThermoML units.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+enum Quantity {
+    /// Temperature in K
+    Temperature,
+    /// Pressure in kPa
+    Pressure,
+    /// Vapor pressure in kPa
+    VaporPressure,
+    /// Mass density in kg/m³
+    MassDensity(ThermoMLPhase),
+    /// Molar isobaric heat capacity in J/(mol K)
+    HeatCapacity(ThermoMLPhase),
+    /// Mole fraction of a compound
+    MoleFraction(usize, ThermoMLPhase),
+}
+
+impl Quantity {
+    fn new(name: &str, phase: ThermoMLPhase, compound: Option<usize>) -> Option<Self> {
+        match name {
+            "Temperature, K" => Some(Self::Temperature),
+            "Pressure, kPa" => Some(Self::Pressure),
+            "Vapor or sublimation pressure, kPa" => Some(Self::VaporPressure),
+            "Mass density, kg/m3" => Some(Self::MassDensity(phase)),
+            "Molar heat capacity at constant pressure, J/K/mol" => Some(Self::HeatCapacity(phase)),
+            "Mole fraction" => compound.map(|c| Self::MoleFraction(c, phase)),
+            _ => None,
+        }
+    }
+}
+
+/// The data points of a single `PureOrMixtureData` element.
+#[derive(Clone, Debug)]
+struct ThermoMLData {
+    components: Vec<usize>,
+    phases: Vec<ThermoMLPhase>,
+    points: Vec<HashMap<Quantity, f64>>,
+}
+
+/// Experimental data read from a ThermoML file.
+///
+/// The data can be converted into the data sets that are used in
+/// an [Estimator](super::Estimator). Compounds are identified by
+/// their CAS number, any of their names, their InChIKey or their
+/// molecular formula.
+///
+/// Currently, vapor pressures, (saturated) liquid densities,
+/// isobaric heat capacities and binary vapor-liquid equilibria
+/// are supported. All other properties are ignored.
+#[derive(Clone, Debug)]
+pub struct ThermoML {
+    compounds: Vec<(usize, Identifier)>,
+    data: Vec<ThermoMLData>,
+}
+
+/// Iterate over all child elements with the given name.
+fn children<'a, 'input: 'a>(
+    node: Node<'a, 'input>,
+    name: &'a str,
+) -> impl Iterator<Item = Node<'a, 'input>> + 'a {
+    node.children()
+        .filter(move |n| n.is_element() && n.tag_name().name() == name)
+}
+
+/// Text of the first descendant element with the given name.
+fn text<'a>(node: Node<'a, '_>, name: &str) -> Option<&'a str> {
+    node.descendants()
+        .find(|n| n.is_element() && n.tag_name().name() == name)
+        .and_then(|n| n.text())
+        .map(str::trim)
+}
+
+fn number(node: Node, name: &str) -> Result<Option<usize>, EstimatorError> {
+    text(node, name)
+        .map(|n| {
+            n.parse().map_err(|_| {
+                EstimatorError::ThermoMLError(format!("'{}' is not a valid {}.", n, name))
+            })
+        })
+        .transpose()
+}
+
+fn value(node: Node, name: &str) -> Result<Option<f64>, EstimatorError> {
+    Ok(text(node, name).map(str::parse::<f64>).transpose()?)
+}
+
+/// Insert the hyphens into CAS numbers that are stored as integers.
+fn cas(cas: &str) -> String {
+    if cas.contains('-') || cas.len() < 4 {
+        return cas.into();
+    }
+    let n = cas.len();
+    format!("{}-{}-{}", &cas[..n - 3], &cas[n - 3..n - 1], &cas[n - 1..])
+}
+
+fn compound(node: Node) -> Result<Option<(usize, Identifier)>, EstimatorError> {
+    let org_num = match number(node, "nOrgNum")? {
+        Some(n) => n,
+        None => return Ok(None),
+    };
+    let mut names = children(node, "sCommonName").filter_map(|n| n.text());
+    let mut identifier = Identifier::new(
+        text(node, "nCASRN").map(cas).as_deref(),
+        names.next(),
+        text(node, "sIUPACName"),
+        text(node, "sSmiles"),
+        text(node, "sStandardInChI"),
+        text(node, "sFormulaMolec"),
+    );
+    identifier.inchikey = text(node, "sStandardInChIKey").map(Into::into);
+    identifier.aliases = names.map(Into::into).collect();
+    Ok(Some((org_num, identifier)))
+}
+
+fn pure_or_mixture_data(node: Node) -> Result<ThermoMLData, EstimatorError> {
+    let components = children(node, "Component")
+        .map(|c| number(c, "nOrgNum"))
+        .collect::<Result<Option<Vec<_>>, _>>()?
+        .ok_or_else(|| EstimatorError::ThermoMLError("Missing component number.".into()))?;
+    let phases = children(node, "PhaseID")
+        .filter_map(|p| text(p, "ePhase"))
+        .map(ThermoMLPhase::from)
+        .collect();
+
+    // variables and properties are both referenced by their number
+    let mut variables = HashMap::new();
+    for variable in children(node, "Variable") {
+        let id = children(variable, "VariableID").next();
+        let name = id
+            .and_then(|id| children(id, "VariableType").next())
+            .and_then(|t| t.children().find(|n| n.is_element()))
+            .and_then(|n| n.text());
+        let phase = text(variable, "eVarPhase").map_or(ThermoMLPhase::Other, Into::into);
+        let compound = id.map(|id| number(id, "nOrgNum")).transpose()?.flatten();
+        if let (Some(n), Some(q)) = (
+            number(variable, "nVarNumber")?,
+            name.and_then(|name| Quantity::new(name.trim(), phase, compound)),
+        ) {
+            variables.insert(n, q);
+        }
+    }
+    let mut properties = HashMap::new();
+    for property in children(node, "Property") {
+        let compound = children(property, "Property-MethodID")
+            .next()
+            .map(|id| number(id, "nOrgNum"))
+            .transpose()?
+            .flatten();
+        let phase = text(property, "ePropPhase").map_or(ThermoMLPhase::Other, Into::into);
+        if let (Some(n), Some(q)) = (
+            number(property, "nPropNumber")?,
+            text(property, "ePropName").and_then(|name| Quantity::new(name, phase, compound)),
+        ) {
+            properties.insert(n, q);
+        }
+    }
+
+    // constraints apply to all data points
+    let mut constraints = HashMap::new();
+    for constraint in children(node, "Constraint") {
+        let id = children(constraint, "ConstraintID").next();
+        let name = id
+            .and_then(|id| children(id, "ConstraintType").next())
+            .and_then(|t| t.children().find(|n| n.is_element()))
+            .and_then(|n| n.text());
+        let phase = text(constraint, "eConstraintPhase").map_or(ThermoMLPhase::Other, Into::into);
+        let compound = id.map(|id| number(id, "nOrgNum")).transpose()?.flatten();
+        if let (Some(v), Some(q)) = (
+            value(constraint, "nConstraintValue")?,
+            name.and_then(|name| Quantity::new(name.trim(), phase, compound)),
+        ) {
+            constraints.insert(q, v);
+        }
+    }
+
+    let mut points = Vec::new();
+    for values in children(node, "NumValues") {
+        let mut point = constraints.clone();
+        for v in children(values, "VariableValue") {
+            if let (Some(n), Some(v)) = (number(v, "nVarNumber")?, value(v, "nVarValue")?) {
+                if let Some(&q) = variables.get(&n) {
+                    point.insert(q, v);
+                }
+            }
+        }
+        for v in children(values, "PropertyValue") {
+            if let (Some(n), Some(v)) = (number(v, "nPropNumber")?, value(v, "nPropValue")?) {
+                if let Some(&q) = properties.get(&n) {
+                    point.insert(q, v);
+                }
+            }
+        }
+        points.push(point);
+    }
+
+    Ok(ThermoMLData {
+        components,
+        phases,
+        points,
+    })
+}
+
+impl ThermoML {
+    /// Read the experimental data from a ThermoML file.
+    pub fn from_file<P: AsRef<Path>>(file: P) -> Result<Self, EstimatorError> {
+        Self::parse(&fs::read_to_string(file)?)
+    }
+
+    /// Read the experimental data from the content of a ThermoML file.
+    pub fn parse(xml: &str) -> Result<Self, EstimatorError> {
+        let document = Document::parse(xml)?;
+        let root = document.root_element();
+        let compounds = children(root, "Compound")
+            .map(compound)
+            .filter_map(Result::transpose)
+            .collect::<Result<_, _>>()?;
+        let data = children(root, "PureOrMixtureData")
+            .map(pure_or_mixture_data)
+            .collect::<Result<_, _>>()?;
+        Ok(Self { compounds, data })
+    }
+
+    /// Return the identifiers of all compounds in the file.
+    pub fn compounds(&self) -> Vec<Identifier> {
+        self.compounds.iter().map(|(_, c)| c.clone()).collect()
+    }
+
+    fn find(&self, compound: &str) -> Result<usize, EstimatorError> {
+        let query = compound.trim().to_lowercase();
+        let is_match =
+            |id: &Option<String>| id.as_ref().map_or(false, |id| id.to_lowercase() == query);
+        self.compounds
+            .iter()
+            .find(|(_, c)| {
+                is_match(&c.cas)
+                    || is_match(&c.name)
+                    || is_match(&c.iupac_name)
+                    || is_match(&c.inchikey)
+                    || is_match(&c.formula)
+                    || c.aliases.iter().any(|a| a.to_lowercase() == query)
+            })
+            .map(|&(n, _)| n)
+            .ok_or_else(|| {
+                EstimatorError::ThermoMLError(format!(
+                    "The compound '{}' is not contained in the ThermoML file.",
+                    compound
+                ))
+            })
+    }
+
+    /// All data points of the given compounds in data sets with phases that satisfy `phases`.
+    fn points<F: Fn(&[ThermoMLPhase]) -> bool>(
+        &self,
+        components: &[usize],
+        phases: F,
+    ) -> Vec<&HashMap<Quantity, f64>> {
+        let mut components = components.to_vec();
+        components.sort_unstable();
+        self.data
+            .iter()
+            .filter(|d| {
+                let mut c = d.components.clone();
+                c.sort_unstable();
+                c == components && phases(&d.phases)
+            })
+            .flat_map(|d| d.points.iter())
+            .collect()
+    }
+
+    fn pure_data<const N: usize>(
+        &self,
+        compound: &str,
+        quantities: [Quantity; N],
+        phases: impl Fn(&[ThermoMLPhase]) -> bool,
+        property: &str,
+    ) -> Result<[Array1<f64>; N], EstimatorError> {
+        let n = self.find(compound)?;
+        let data: Vec<_> = self
+            .points(&[n], phases)
+            .into_iter()
+            .filter_map(|p| {
+                quantities
+                    .iter()
+                    .map(|q| p.get(q).copied())
+                    .collect::<Option<Vec<_>>>()
+            })
+            .collect();
+        if data.is_empty() {
+            return Err(EstimatorError::ThermoMLError(format!(
+                "No {} data found for '{}'.",
+                property, compound
+            )));
+        }
+        let mut res = [(); N].map(|_| Array1::zeros(0));
+        for (i, r) in res.iter_mut().enumerate() {
+            *r = data.iter().map(|d| d[i]).collect();
+        }
+        Ok(res)
+    }
+
+    /// Vapor pressures of a pure compound.
+    ///
+    /// See [VaporPressure::new] for the meaning of the remaining arguments.
+    pub fn vapor_pressure(
+        &self,
+        compound: &str,
+        extrapolate: bool,
+        solver_options: Option<SolverOptions>,
+    ) -> Result<VaporPressure, EstimatorError> {
+        let [t, p] = self.pure_data(
+            compound,
+            [Quantity::Temperature, Quantity::VaporPressure],
+            |phases| phases.contains(&ThermoMLPhase::Liquid),
+            "vapor pressure",
+        )?;
+        VaporPressure::new(
+            p * KILO * PASCAL,
+            t * KELVIN,
+            extrapolate,
+            None,
+            solver_options,
+        )
+    }
+
+    /// Liquid densities of a pure compound as function of temperature and pressure.
+    pub fn liquid_density(&self, compound: &str) -> Result<LiquidDensity, EstimatorError> {
+        let [t, p, rho] = self.pure_data(
+            compound,
+            [
+                Quantity::Temperature,
+                Quantity::Pressure,
+                Quantity::MassDensity(ThermoMLPhase::Liquid),
+            ],
+            |phases| phases == [ThermoMLPhase::Liquid],
+            "liquid density",
+        )?;
+        LiquidDensity::new(
+            rho * KILOGRAM / METER.powi(3),
+            t * KELVIN,
+            p * KILO * PASCAL,
+        )
+    }
+
+    /// Saturated liquid densities of a pure compound as function of temperature.
+    pub fn equilibrium_liquid_density(
+        &self,
+        compound: &str,
+        solver_options: Option<SolverOptions>,
+    ) -> Result<EquilibriumLiquidDensity, EstimatorError> {
+        let [t, rho] = self.pure_data(
+            compound,
+            [
+                Quantity::Temperature,
+                Quantity::MassDensity(ThermoMLPhase::Liquid),
+            ],
+            |phases| {
+                phases.contains(&ThermoMLPhase::Liquid) && phases.contains(&ThermoMLPhase::Gas)
+            },
+            "equilibrium liquid density",
+        )?;
+        EquilibriumLiquidDensity::new(rho * KILOGRAM / METER.powi(3), t * KELVIN, solver_options)
+    }
+
+    /// Molar isobaric heat capacities of a pure compound in the given phase.
+    pub fn isobaric_heat_capacity(
+        &self,
+        compound: &str,
+        phase: Phase,
+    ) -> Result<IsobaricHeatCapacity, EstimatorError> {
+        let [t, p, c_p] = self.pure_data(
+            compound,
+            [
+                Quantity::Temperature,
+                Quantity::Pressure,
+                Quantity::HeatCapacity(phase.into()),
+            ],
+            |phases| phases == [ThermoMLPhase::from(phase)],
+            "isobaric heat capacity",
+        )?;
+        IsobaricHeatCapacity::new(
+            c_p * JOULE / KELVIN / MOL,
+            t * KELVIN,
+            p * KILO * PASCAL,
+            phase,
+        )
+    }
+
+    /// Temperature, pressure and the mole fraction of the first compound in
+    /// the given phases for all VLE data points of a binary mixture.
+    fn binary_vle(
+        &self,
+        compounds: [&str; 2],
+        phases: &[ThermoMLPhase],
+    ) -> Result<(SIArray1, SIArray1, Vec<Array1<f64>>), EstimatorError> {
+        let n1 = self.find(compounds[0])?;
+        let n2 = self.find(compounds[1])?;
+        let molefrac = |p: &HashMap<Quantity, f64>, phase| {
+            p.get(&Quantity::MoleFraction(n1, phase))
+                .copied()
+                .or_else(|| p.get(&Quantity::MoleFraction(n2, phase)).map(|x| 1.0 - x))
+        };
+        let data: Vec<_> = self
+            .points(&[n1, n2], |p| {
+                p.contains(&ThermoMLPhase::Liquid) && p.contains(&ThermoMLPhase::Gas)
+            })
+            .into_iter()
+            .filter_map(|p| {
+                let t = p.get(&Quantity::Temperature)?;
+                let pressure = p.get(&Quantity::Pressure)?;
+                let x: Option<Vec<_>> = phases.iter().map(|&phase| molefrac(p, phase)).collect();
+                Some((*t, *pressure, x?))
+            })
+            .collect();
+        if data.is_empty() {
+            return Err(EstimatorError::ThermoMLError(format!(
+                "No VLE data found for '{}' and '{}'.",
+                compounds[0], compounds[1]
+            )));
+        }
+        let t: Array1<f64> = data.iter().map(|d| d.0).collect();
+        let p: Array1<f64> = data.iter().map(|d| d.1).collect();
+        let x = (0..phases.len())
+            .map(|i| data.iter().map(|d| d.2[i]).collect())
+            .collect();
+        Ok((t * KELVIN, p * KILO * PASCAL, x))
+    }
+
+    /// Binary VLE data of two compounds in the given phase.
+    ///
+    /// The mole fractions refer to the first compound.
+    pub fn binary_vle_pressure(
+        &self,
+        compounds: [&str; 2],
+        phase: Phase,
+    ) -> Result<BinaryVlePressure, EstimatorError> {
+        let (t, p, mut x) = self.binary_vle(compounds, &[phase.into()])?;
+        Ok(BinaryVlePressure::new(t, p, x.remove(0), phase))
+    }
+
+    /// Binary VLE data of two compounds with the compositions of both phases.
+    ///
+    /// The mole fractions refer to the first compound.
+    pub fn binary_vle_chemical_potential(
+        &self,
+        compounds: [&str; 2],
+    ) -> Result<BinaryVleChemicalPotential, EstimatorError> {
+        let (t, p, mut x) =
+            self.binary_vle(compounds, &[ThermoMLPhase::Liquid, ThermoMLPhase::Gas])?;
+        let y = x.remove(1);
+        Ok(BinaryVleChemicalPotential::new(t, p, x.remove(0), y))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_relative_eq;
+
+    const XML: &str = r#"<?xml version="1.0" encoding="utf-8"?>
+<DataReport xmlns="http://www.iupac.org/namespaces/ThermoML">
+  <Compound>
+    <RegNum><nOrgNum>1</nOrgNum></RegNum>
+    <sStandardInChIKey>ATUOYWHBWRKTHZ-UHFFFAOYSA-N</sStandardInChIKey>
+    <sCommonName>propane</sCommonName>
+    <sCommonName>dimethylmethane</sCommonName>
+    <sFormulaMolec>C3H8</sFormulaMolec>
+  </Compound>
+  <Compound>
+    <RegNum><nOrgNum>2</nOrgNum><nCASRN>106978</nCASRN></RegNum>
+    <sCommonName>butane</sCommonName>
+  </Compound>
+  <PureOrMixtureData>
+    <Component><RegNum><nOrgNum>1</nOrgNum></RegNum></Component>
+    <PhaseID><ePhase>Liquid</ePhase></PhaseID>
+    <PhaseID><ePhase>Gas</ePhase></PhaseID>
+    <Property>
+      <nPropNumber>1</nPropNumber>
+      <Property-MethodID>
+        <PropertyGroup>
+          <VaporPBoilingTAzeotropTandP>
+            <ePropName>Vapor or sublimation pressure, kPa</ePropName>
+          </VaporPBoilingTAzeotropTandP>
+        </PropertyGroup>
+      </Property-MethodID>
+      <PropPhaseID><ePropPhase>Liquid</ePropPhase></PropPhaseID>
+    </Property>
+    <Variable>
+      <nVarNumber>1</nVarNumber>
+      <VariableID><VariableType><eTemperature>Temperature, K</eTemperature></VariableType></VariableID>
+      <VarPhaseID><eVarPhase>Liquid</eVarPhase></VarPhaseID>
+    </Variable>
+    <NumValues>
+      <VariableValue><nVarNumber>1</nVarNumber><nVarValue>250.0</nVarValue></VariableValue>
+      <PropertyValue><nPropNumber>1</nPropNumber><nPropValue>218.5</nPropValue></PropertyValue>
+    </NumValues>
+    <NumValues>
+      <VariableValue><nVarNumber>1</nVarNumber><nVarValue>300.0</nVarValue></VariableValue>
+      <PropertyValue><nPropNumber>1</nPropNumber><nPropValue>997.9</nPropValue></PropertyValue>
+    </NumValues>
+  </PureOrMixtureData>
+  <PureOrMixtureData>
+    <Component><RegNum><nOrgNum>2</nOrgNum></RegNum></Component>
+    <Component><RegNum><nOrgNum>1</nOrgNum></RegNum></Component>
+    <PhaseID><ePhase>Liquid</ePhase></PhaseID>
+    <PhaseID><ePhase>Gas</ePhase></PhaseID>
+    <Property>
+      <nPropNumber>1</nPropNumber>
+      <Property-MethodID>
+        <PropertyGroup>
+          <CompositionAndAssociation><ePropName>Mole fraction</ePropName></CompositionAndAssociation>
+        </PropertyGroup>
+        <RegNum><nOrgNum>1</nOrgNum></RegNum>
+      </Property-MethodID>
+      <PropPhaseID><ePropPhase>Gas</ePropPhase></PropPhaseID>
+    </Property>
+    <Constraint>
+      <ConstraintID><ConstraintType><eTemperature>Temperature, K</eTemperature></ConstraintType></ConstraintID>
+      <nConstraintValue>300.0</nConstraintValue>
+    </Constraint>
+    <Variable>
+      <nVarNumber>1</nVarNumber>
+      <VariableID><VariableType><ePressure>Pressure, kPa</ePressure></VariableType></VariableID>
+    </Variable>
+    <Variable>
+      <nVarNumber>2</nVarNumber>
+      <VariableID>
+        <VariableType><eComponentComposition>Mole fraction</eComponentComposition></VariableType>
+        <RegNum><nOrgNum>2</nOrgNum></RegNum>
+      </VariableID>
+      <VarPhaseID><eVarPhase>Liquid</eVarPhase></VarPhaseID>
+    </Variable>
+    <NumValues>
+      <VariableValue><nVarNumber>1</nVarNumber><nVarValue>600.0</nVarValue></VariableValue>
+      <VariableValue><nVarNumber>2</nVarNumber><nVarValue>0.6</nVarValue></VariableValue>
+      <PropertyValue><nPropNumber>1</nPropNumber><nPropValue>0.7</nPropValue></PropertyValue>
+    </NumValues>
+  </PureOrMixtureData>
+</DataReport>"#;
+
+    #[test]
+    fn compounds() -> Result<(), EstimatorError> {
+        let thermoml = ThermoML::parse(XML)?;
+        let compounds = thermoml.compounds();
+        assert_eq!(compounds[0].name, Some("propane".into()));
+        assert_eq!(compounds[0].aliases, vec!["dimethylmethane".to_string()]);
+        assert_eq!(compounds[1].cas, Some("106-97-8".into()));
+        assert_eq!(thermoml.find("ATUOYWHBWRKTHZ-UHFFFAOYSA-N")?, 1);
+        assert_eq!(thermoml.find("Dimethylmethane")?, 1);
+        assert_eq!(thermoml.find("106-97-8")?, 2);
+        assert!(thermoml.find("pentane").is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn pure_data() -> Result<(), EstimatorError> {
+        let thermoml = ThermoML::parse(XML)?;
+        let vapor_pressure = thermoml.vapor_pressure("propane", false, None)?;
+        assert_relative_eq!(
+            vapor_pressure.temperature().get(1),
+            300.0 * KELVIN,
+            max_relative = 1e-12
+        );
+        assert_relative_eq!(
+            vapor_pressure.target.get(0),
+            218.5 * KILO * PASCAL,
+            max_relative = 1e-12
+        );
+        assert!(thermoml.liquid_density("propane").is_err());
+        assert!(thermoml.vapor_pressure("butane", false, None).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn binary_vle() -> Result<(), EstimatorError> {
+        let thermoml = ThermoML::parse(XML)?;
+        let (t, p, x) = thermoml.binary_vle(
+            ["propane", "butane"],
+            &[ThermoMLPhase::Liquid, ThermoMLPhase::Gas],
+        )?;
+        assert_relative_eq!(t.get(0), 300.0 * KELVIN, max_relative = 1e-12);
+        assert_relative_eq!(p.get(0), 600.0 * KILO * PASCAL, max_relative = 1e-12);
+        assert_relative_eq!(x[0][0], 0.4, max_relative = 1e-12);
+        assert_relative_eq!(x[1][0], 0.7, max_relative = 1e-12);
+        assert!(thermoml
+            .binary_vle_pressure(["butane", "propane"], Phase::Vapor)
+            .is_ok());
+        Ok(())
+    }
+}
@@ -0,0 +1,448 @@
+//! Bayesian uncertainty quantification of model parameters.
+use super::regression::check_bounds;
+use super::{Estimator, EstimatorError, RegressionParameters};
+use feos_core::{log_iter, log_result, EquationOfState, Verbosity};
+use ndarray::{s, Array1, Array2, Axis};
+use quantity::si::SIArray1;
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+use std::fmt;
+use std::sync::Arc;
+
+const STRETCH: f64 = 2.0;
+const N_STEPS: usize = 1000;
+const INITIAL_SPREAD: f64 = 1e-3;
+const MAX_INITIALIZATION: usize = 100;
+
+/// Options for the affine invariant ensemble sampler.
+///
+/// If the values are [None], default values are used.
+#[derive(Clone, Copy, Default)]
+pub struct SamplerOptions {
+    /// Number of walkers. Defaults to four times the number of parameters (at least 8).
+    pub n_walkers: Option<usize>,
+    /// Number of steps of every walker. Defaults to 1000.
+    pub n_steps: Option<usize>,
+    /// Number of discarded initial steps. Defaults to a quarter of the steps.
+    pub burn_in: Option<usize>,
+    /// Only every `thin`-th step is stored. Defaults to 1.
+    pub thin: Option<usize>,
+    /// Seed of the random number generator. Defaults to a random seed.
+    pub seed: Option<u64>,
+    /// Iteration output indicated by the [Verbosity] enum.
+    pub verbosity: Verbosity,
+}
+
+impl SamplerOptions {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn n_walkers(mut self, n_walkers: usize) -> Self {
+        self.n_walkers = Some(n_walkers);
+        self
+    }
+
+    pub fn n_steps(mut self, n_steps: usize) -> Self {
+        self.n_steps = Some(n_steps);
+        self
+    }
+
+    pub fn burn_in(mut self, burn_in: usize) -> Self {
+        self.burn_in = Some(burn_in);
+        self
+    }
+
+    pub fn thin(mut self, thin: usize) -> Self {
+        self.thin = Some(thin);
+        self
+    }
+
+    pub fn seed(mut self, seed: u64) -> Self {
+        self.seed = Some(seed);
+        self
+    }
+
+    pub fn verbosity(mut self, verbosity: Verbosity) -> Self {
+        self.verbosity = verbosity;
+        self
+    }
+}
+
+/// Samples of the posterior distribution of model parameters.
+pub struct Posterior<P: RegressionParameters> {
+    /// The parameter set around which the walkers were initialized.
+    pub parameters: Arc<P>,
+    /// The sampled parameters.
+    pub names: Vec<P::Name>,
+    /// The samples of all walkers with one row per sample.
+    pub samples: Array2<f64>,
+    /// The logarithm of the (unnormalized) posterior probability of every sample.
+    pub log_probability: Array1<f64>,
+    /// The fraction of accepted proposals.
+    pub acceptance_fraction: f64,
+}
+
+impl<P: RegressionParameters> Posterior<P> {
+    /// The mean of every parameter.
+    pub fn mean(&self) -> Array1<f64> {
+        self.samples.mean_axis(Axis(0)).unwrap()
+    }
+
+    /// The standard deviation of every parameter.
+    pub fn std(&self) -> Array1<f64> {
+        self.samples.std_axis(Axis(0), 1.0)
+    }
+
+    /// The quantile `q` (between 0 and 1) of every parameter.
+    pub fn quantile(&self, q: f64) -> Array1<f64> {
+        self.samples
+            .axis_iter(Axis(1))
+            .map(|x| quantile(x.to_vec(), q))
+            .collect()
+    }
+
+    /// The sample with the highest posterior probability.
+    pub fn maximum_a_posteriori(&self) -> Result<P, EstimatorError> {
+        let (best, _) = self.log_probability.iter().enumerate().fold(
+            (0, f64::NEG_INFINITY),
+            |(i_max, max), (i, &lp)| {
+                if lp > max {
+                    (i, lp)
+                } else {
+                    (i_max, max)
+                }
+            },
+        );
+        self.parameters
+            .set_parameters(&self.names, &self.samples.row(best).to_vec())
+    }
+}
+
+impl<P: RegressionParameters> fmt::Display for Posterior<P> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Posterior(")?;
+        for ((name, mean), std) in self.names.iter().zip(self.mean()).zip(self.std()) {
+            write!(f, "{}={}±{}, ", name, mean, std)?;
+        }
+        write!(
+            f,
+            "samples={}, acceptance_fraction={})",
+            self.samples.nrows(),
+            self.acceptance_fraction
+        )
+    }
+}
+
+/// Lower and upper bound of a prediction for a given confidence level.
+#[derive(Clone)]
+pub struct PredictionBand {
+    /// The median of the predictions.
+    pub median: SIArray1,
+    /// The lower bound of the predictions.
+    pub lower: SIArray1,
+    /// The upper bound of the predictions.
+    pub upper: SIArray1,
+}
+
+impl<E: EquationOfState> Estimator<E> {
+    /// Sample the posterior distribution of the parameters `names` of `parameters`.
+    ///
+    /// The parameters are sampled with the affine invariant ensemble sampler of
+    /// [Goodman and Weare (2010)](https://doi.org/10.2140/camcos.2010.5.65). The
+    /// prior is uniform within `bounds`. For the likelihood, the cost function is
+    /// assumed to be normally distributed with an unknown variance that is
+    /// marginalized with a Jeffreys prior, i.e., the log-likelihood is
+    /// $-\frac{N}{2}\ln\left(\sum_i c_i^2\right)$ for $N$ data points.
+    ///
+    /// The walkers are initialized in a small region around `parameters`, which
+    /// should, therefore, be the result of a regression.
+    pub fn sample<P, F>(
+        &self,
+        parameters: &Arc<P>,
+        names: &[P::Name],
+        bounds: Option<&[(f64, f64)]>,
+        eos: F,
+        options: SamplerOptions,
+    ) -> Result<Posterior<P>, EstimatorError>
+    where
+        P: RegressionParameters,
+        F: Fn(Arc<P>) -> E,
+    {
+        let bounds = check_bounds(bounds, names.len())?;
+        let x0 = names
+            .iter()
+            .map(|n| parameters.get_parameter(n))
+            .collect::<Result<Array1<_>, _>>()?;
+
+        let log_probability = |x: &Array1<f64>| -> Result<f64, EstimatorError> {
+            let p = parameters.set_parameters(names, x.as_slice().unwrap())?;
+            let cost = self.cost(&Arc::new(eos(Arc::new(p))))?;
+            Ok(-0.5 * cost.len() as f64 * cost.dot(&cost).ln())
+        };
+        let (samples, log_probability, acceptance_fraction) =
+            ensemble_sampler(log_probability, &x0, &bounds, options)?;
+        Ok(Posterior {
+            parameters: parameters.clone(),
+            names: names.to_vec(),
+            samples,
+            log_probability,
+            acceptance_fraction,
+        })
+    }
+
+    /// Propagate the parameter uncertainty to the predictions of all `DataSet`s.
+    ///
+    /// The predictions are evaluated for (at most `n_samples`, evenly spaced)
+    /// samples of the posterior. The bands contain the central fraction
+    /// `confidence` of the predictions. Samples for which the prediction
+    /// fails are ignored.
+    pub fn prediction_bands<P, F>(
+        &self,
+        posterior: &Posterior<P>,
+        eos: F,
+        confidence: f64,
+        n_samples: Option<usize>,
+    ) -> Result<Vec<PredictionBand>, EstimatorError>
+    where
+        P: RegressionParameters,
+        F: Fn(Arc<P>) -> E,
+    {
+        if !(0.0..1.0).contains(&confidence) {
+            return Err(EstimatorError::InvalidParameter(format!(
+                "The confidence level has to be between 0 and 1, got {}.",
+                confidence
+            )));
+        }
+        let n = posterior.samples.nrows();
+        let step = n_samples.map_or(1, |n_samples| (n / n_samples.max(1)).max(1));
+
+        // predictions relative to the target values for every sample
+        let datasets = self.datasets();
+        let mut ratios: Vec<Vec<Vec<f64>>> = datasets
+            .iter()
+            .map(|d| vec![Vec::new(); d.datapoints()])
+            .collect();
+        for x in posterior.samples.axis_iter(Axis(0)).step_by(step) {
+            let p = posterior
+                .parameters
+                .set_parameters(&posterior.names, &x.to_vec())?;
+            let eos = Arc::new(eos(Arc::new(p)));
+            for (d, r) in datasets.iter().zip(ratios.iter_mut()) {
+                let ratio = d
+                    .predict(&eos)
+                    .and_then(|prediction| Ok((&prediction / d.target()).into_value()?));
+                if let Ok(ratio) = ratio {
+                    for (ri, q) in r.iter_mut().zip(ratio) {
+                        if q.is_finite() {
+                            ri.push(q);
+                        }
+                    }
+                }
+            }
+        }
+
+        let alpha = 0.5 * (1.0 - confidence);
+        Ok(datasets
+            .iter()
+            .zip(ratios)
+            .map(|(d, r)| {
+                let band = |q: f64| -> SIArray1 {
+                    d.target()
+                        .into_iter()
+                        .zip(r.iter())
+                        .map(|(t, ri)| {
+                            if ri.is_empty() {
+                                t * f64::NAN
+                            } else {
+                                t * quantile(ri.clone(), q)
+                            }
+                        })
+                        .collect()
+                };
+                PredictionBand {
+                    median: band(0.5),
+                    lower: band(alpha),
+                    upper: band(1.0 - alpha),
+                }
+            })
+            .collect())
+    }
+}
+
+/// Linearly interpolated quantile of a list of values.
+fn quantile(mut x: Vec<f64>, q: f64) -> f64 {
+    x.sort_by(|a, b| a.partial_cmp(b).unwrap());
+    let position = q * (x.len() - 1) as f64;
+    let i = (position.floor() as usize).min(x.len() - 1);
+    let j = (i + 1).min(x.len() - 1);
+    x[i] + (x[j] - x[i]) * (position - i as f64)
+}
+
+/// Sample a probability distribution with the affine invariant ensemble sampler.
+///
+/// The ensemble is split in two halves that are updated alternately with stretch
+/// moves towards walkers of the other half. The log-probability is `-inf` outside
+/// of the bounds and for parameters that can not be evaluated.
+pub(crate) fn ensemble_sampler<F>(
+    log_probability: F,
+    x0: &Array1<f64>,
+    bounds: &[(f64, f64)],
+    options: SamplerOptions,
+) -> Result<(Array2<f64>, Array1<f64>, f64), EstimatorError>
+where
+    F: Fn(&Array1<f64>) -> Result<f64, EstimatorError>,
+{
+    let n_params = x0.len();
+    let n_walkers = options.n_walkers.unwrap_or(4 * n_params).max(8);
+    let n_walkers = n_walkers + n_walkers % 2;
+    let n_steps = options.n_steps.unwrap_or(N_STEPS);
+    let burn_in = options.burn_in.unwrap_or(n_steps / 4);
+    let thin = options.thin.unwrap_or(1).max(1);
+    let verbosity = options.verbosity;
+    if burn_in >= n_steps {
+        return Err(EstimatorError::InvalidParameter(format!(
+            "The burn-in ({}) has to be smaller than the number of steps ({}).",
+            burn_in, n_steps
+        )));
+    }
+    let mut rng = match options.seed {
+        Some(seed) => StdRng::seed_from_u64(seed),
+        None => StdRng::from_entropy(),
+    };
+    let evaluate = |x: &Array1<f64>| {
+        let in_bounds = x
+            .iter()
+            .zip(bounds)
+            .all(|(&x, &(lower, upper))| x >= lower && x <= upper);
+        if !in_bounds {
+            return f64::NEG_INFINITY;
+        }
+        log_probability(x)
+            .ok()
+            .filter(|lp| !lp.is_nan())
+            .unwrap_or(f64::NEG_INFINITY)
+    };
+
+    // initialize the walkers in a small region around x0
+    let mut walkers = Array2::zeros((n_walkers, n_params));
+    let mut lp = Array1::zeros(n_walkers);
+    for k in 0..n_walkers {
+        let mut initialized = false;
+        for _ in 0..MAX_INITIALIZATION {
+            let x = Array1::from_shape_fn(n_params, |i| {
+                let spread = INITIAL_SPREAD * x0[i].abs().max(1.0);
+                (x0[i] + spread * rng.gen_range(-1.0..1.0)).clamp(bounds[i].0, bounds[i].1)
+            });
+            let lp_x = evaluate(&x);
+            if lp_x.is_finite() {
+                walkers.row_mut(k).assign(&x);
+                lp[k] = lp_x;
+                initialized = true;
+                break;
+            }
+        }
+        if !initialized {
+            return Err(EstimatorError::InvalidParameter(
+                "The posterior can not be evaluated around the initial parameters.".into(),
+            ));
+        }
+    }
+
+    log_iter!(verbosity, " step | accepted | max. log-probability");
+    log_iter!(verbosity, "{:-<40}", "");
+
+    let half = n_walkers / 2;
+    let n_samples = (n_steps - burn_in) / thin;
+    let mut samples = Array2::zeros((n_samples * n_walkers, n_params));
+    let mut log_probabilities = Array1::zeros(n_samples * n_walkers);
+    let mut accepted = 0;
+    let mut proposed = 0;
+    let mut i_sample = 0;
+    for step in 0..n_steps {
+        for (active, other) in [(0, half), (half, 0)] {
+            for k in active..active + half {
+                let j = other + rng.gen_range(0..half);
+                let z = ((STRETCH - 1.0) * rng.gen::<f64>() + 1.0).powi(2) / STRETCH;
+                let x_k = walkers.row(k).to_owned();
+                let y = &walkers.row(j) + &((&x_k - &walkers.row(j)) * z);
+                let lp_y = evaluate(&y);
+                let log_acceptance = (n_params as f64 - 1.0) * z.ln() + lp_y - lp[k];
+                if step >= burn_in {
+                    proposed += 1;
+                }
+                if rng.gen::<f64>().ln() < log_acceptance {
+                    walkers.row_mut(k).assign(&y);
+                    lp[k] = lp_y;
+                    if step >= burn_in {
+                        accepted += 1;
+                    }
+                }
+            }
+        }
+        log_iter!(
+            verbosity,
+            " {:4} | {:8} | {:12.6e}",
+            step + 1,
+            accepted,
+            lp.fold(f64::NEG_INFINITY, |a, &b| a.max(b))
+        );
+        if step >= burn_in && (step - burn_in) % thin == 0 && i_sample < n_samples {
+            let rows = i_sample * n_walkers..(i_sample + 1) * n_walkers;
+            samples.slice_mut(s![rows.clone(), ..]).assign(&walkers);
+            log_probabilities.slice_mut(s![rows]).assign(&lp);
+            i_sample += 1;
+        }
+    }
+    let acceptance_fraction = accepted as f64 / proposed.max(1) as f64;
+    log_result!(
+        verbosity,
+        "Ensemble sampler: {} samples with acceptance fraction {:.3}\n",
+        n_samples * n_walkers,
+        acceptance_fraction
+    );
+    Ok((samples, log_probabilities, acceptance_fraction))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_relative_eq;
+    use ndarray::arr1;
+
+    #[test]
+    fn sample_normal_distribution() -> Result<(), EstimatorError> {
+        let mean = arr1(&[1.0, -2.0]);
+        let std = arr1(&[0.5, 2.0]);
+        let log_probability =
+            |x: &Array1<f64>| Ok(-0.5 * ((x - &mean) / &std).mapv(|x| x * x).sum());
+        let bounds = [(f64::NEG_INFINITY, f64::INFINITY); 2];
+        let options = SamplerOptions::new().n_steps(4000).seed(42);
+        let (samples, _, acceptance_fraction) =
+            ensemble_sampler(log_probability, &arr1(&[0.0, 0.0]), &bounds, options)?;
+        assert_eq!(samples.nrows(), 3000 * 8);
+        assert!(acceptance_fraction > 0.2 && acceptance_fraction < 0.9);
+        assert_relative_eq!(samples.mean_axis(Axis(0)).unwrap(), mean, epsilon = 0.15);
+        assert_relative_eq!(samples.std_axis(Axis(0), 1.0), std, epsilon = 0.2);
+        Ok(())
+    }
+
+    #[test]
+    fn sample_within_bounds() -> Result<(), EstimatorError> {
+        let log_probability = |_: &Array1<f64>| Ok(0.0);
+        let bounds = [(0.0, 1.0)];
+        let options = SamplerOptions::new().n_steps(500).seed(1);
+        let (samples, _, _) = ensemble_sampler(log_probability, &arr1(&[0.5]), &bounds, options)?;
+        assert!(samples.iter().all(|&x| (0.0..=1.0).contains(&x)));
+        Ok(())
+    }
+
+    #[test]
+    fn quantiles() {
+        let x = vec![4.0, 1.0, 3.0, 2.0, 5.0];
+        assert_relative_eq!(quantile(x.clone(), 0.5), 3.0);
+        assert_relative_eq!(quantile(x.clone(), 0.0), 1.0);
+        assert_relative_eq!(quantile(x.clone(), 1.0), 5.0);
+        assert_relative_eq!(quantile(x, 0.125), 1.5);
+    }
+}
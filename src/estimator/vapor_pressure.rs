@@ -1,3 +1,4 @@
+use super::batch::pure_vle_batch;
 use super::{DataSet, EstimatorError};
 use feos_core::{Contributions, EosUnit, EquationOfState, PhaseEquilibrium, SolverOptions, State};
 use ndarray::{arr1, Array1};
@@ -91,10 +92,11 @@ impl<E: EquationOfState> DataSet<E> for VaporPressure {
 
         let unit = self.target.get(0);
         let mut prediction = Array1::zeros(self.datapoints) * unit;
-        for i in 0..self.datapoints {
+        let vle = pure_vle_batch(eos, &self.temperature, self.solver_options)?;
+        for (i, vle) in vle.iter().enumerate() {
             let t = self.temperature.get(i);
-            if let Some(pvap) = PhaseEquilibrium::vapor_pressure(eos, t)[0] {
-                prediction.try_set(i, pvap)?;
+            if let Some(vle) = vle {
+                prediction.try_set(i, vle.vapor().pressure(Contributions::Total))?;
             } else if self.extrapolate {
                 prediction.try_set(
                     i,
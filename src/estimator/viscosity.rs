@@ -1,3 +1,4 @@
+use super::batch::map_datapoints;
 use super::{DataSet, EstimatorError};
 use feos_core::{DensityInitialization, EntropyScaling, EosUnit, EquationOfState, State};
 use ndarray::arr1;
@@ -53,15 +54,14 @@ impl<E: EquationOfState + EntropyScaling> DataSet<E> for Viscosity {
 
     fn predict(&self, eos: &Arc<E>) -> Result<SIArray1, EstimatorError> {
         let moles = arr1(&[1.0]) * SIUnit::reference_moles();
-        self.temperature
-            .into_iter()
-            .zip(self.pressure.into_iter())
-            .map(|(t, p)| {
-                State::new_npt(eos, t, p, &moles, DensityInitialization::None)?
-                    .viscosity()
-                    .map_err(EstimatorError::from)
-            })
-            .collect()
+        map_datapoints(self.target.len(), |i| {
+            let (t, p) = (self.temperature.get(i), self.pressure.get(i));
+            State::new_npt(eos, t, p, &moles, DensityInitialization::None)?
+                .viscosity()
+                .map_err(EstimatorError::from)
+        })
+        .into_iter()
+        .collect()
     }
 
     fn get_input(&self) -> HashMap<String, SIArray1> {
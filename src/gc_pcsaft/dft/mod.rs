@@ -1,14 +1,21 @@
 use super::eos::GcPcSaftOptions;
+use super::record::GcPcSaftRecord;
 use crate::association::Association;
 use crate::hard_sphere::{FMTContribution, FMTVersion, HardSphereProperties, MonomerShape};
-use feos_core::parameter::ParameterHetero;
-use feos_core::MolarWeight;
+use feos_core::ideal_gas::{IdealGasModel, WithIdealGas};
+use feos_core::joback::JobackRecord;
+use feos_core::parameter::{ChemicalRecord, ParameterHetero, ParameterSetHetero};
+use feos_core::{
+    DefaultIdealGasContribution, EosResult, EquationOfState, IdealGasContribution, MolarWeight,
+};
 use feos_dft::adsorption::FluidParameters;
 use feos_dft::{FunctionalContribution, HelmholtzEnergyFunctional, MoleculeShape, DFT};
 use ndarray::Array1;
 use num_dual::DualNum;
 use petgraph::graph::UnGraph;
 use quantity::si::{SIArray1, GRAM, MOL};
+use serde::de::Error;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
 use std::f64::consts::FRAC_PI_6;
 use std::sync::Arc;
 
@@ -25,6 +32,7 @@ pub struct GcPcSaftFunctional {
     fmt_version: FMTVersion,
     options: GcPcSaftOptions,
     contributions: Vec<Box<dyn FunctionalContribution>>,
+    ideal_gas: Option<IdealGasModel>,
 }
 
 impl GcPcSaftFunctional {
@@ -71,6 +79,7 @@ impl GcPcSaftFunctional {
             fmt_version,
             options: saft_options,
             contributions,
+            ideal_gas: None,
         })
         .into()
     }
@@ -82,40 +91,107 @@ impl HelmholtzEnergyFunctional for GcPcSaftFunctional {
     }
 
     fn subset(&self, component_list: &[usize]) -> DFT<Self> {
-        Self::with_options(
+        let functional = Self::with_options(
             Arc::new(self.parameters.subset(component_list)),
             self.fmt_version,
             self.options,
-        )
+        );
+        match &self.ideal_gas {
+            Some(ideal_gas) => functional
+                .with_ideal_gas(ideal_gas.subset(component_list))
+                .unwrap(),
+            None => functional,
+        }
     }
 
     fn compute_max_density(&self, moles: &Array1<f64>) -> f64 {
         let p = &self.parameters;
         let moles_segments: Array1<f64> = p.component_index.iter().map(|&i| moles[i]).collect();
-        self.options.max_eta * moles.sum()
-            / (FRAC_PI_6 * &p.m * p.sigma.mapv(|v| v.powi(3)) * moles_segments).sum()
+        self.options.max_density.apply(
+            self.options.max_eta * moles.sum()
+                / (FRAC_PI_6 * &p.m * p.sigma.mapv(|v| v.powi(3)) * moles_segments).sum(),
+        )
     }
 
     fn contributions(&self) -> &[Box<dyn FunctionalContribution>] {
         &self.contributions
     }
 
+    fn ideal_gas(&self) -> &dyn IdealGasContribution {
+        match &self.ideal_gas {
+            Some(ideal_gas) => ideal_gas,
+            None => &DefaultIdealGasContribution,
+        }
+    }
+
     fn bond_lengths(&self, temperature: f64) -> UnGraph<(), f64> {
         // temperature dependent segment diameter
         let d = self.parameters.hs_diameter(temperature);
 
-        self.parameters.bonds.map(
-            |_, _| (),
+        // ring closures are excluded from the bond integrals so that
+        // cyclic molecules are described by a spanning tree
+        let ring_closures = &self.parameters.ring_closures;
+        self.parameters.bonds.filter_map(
+            |_, _| Some(()),
             |e, _| {
                 let (i, j) = self.parameters.bonds.edge_endpoints(e).unwrap();
-                let di = d[i.index()];
-                let dj = d[j.index()];
-                0.5 * (di + dj)
+                let (i, j) = (i.index(), j.index());
+                if ring_closures.contains(&[i, j]) || ring_closures.contains(&[j, i]) {
+                    return None;
+                }
+                Some(0.5 * (d[i] + d[j]))
             },
         )
     }
 }
 
+impl WithIdealGas for GcPcSaftFunctional {
+    fn with_ideal_gas(mut self, ideal_gas: IdealGasModel) -> EosResult<Self> {
+        ideal_gas.validate_components(self.parameters.molarweight.len())?;
+        self.ideal_gas = Some(ideal_gas);
+        Ok(self)
+    }
+}
+
+// Auxiliary structure used to (de)serialize the Helmholtz energy functional.
+#[derive(Serialize, Deserialize)]
+struct GcPcSaftFunctionalJSON {
+    parameters: ParameterSetHetero<ChemicalRecord, GcPcSaftRecord, JobackRecord, f64>,
+    fmt_version: FMTVersion,
+    options: GcPcSaftOptions,
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    ideal_gas: Option<IdealGasModel>,
+}
+
+impl Serialize for GcPcSaftFunctional {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        GcPcSaftFunctionalJSON {
+            parameters: self.parameters.parameter_set(),
+            fmt_version: self.fmt_version,
+            options: self.options,
+            ideal_gas: self.ideal_gas.clone(),
+        }
+        .serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for GcPcSaftFunctional {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let func = GcPcSaftFunctionalJSON::deserialize(deserializer)?;
+        let parameters = GcPcSaftFunctionalParameters::from_parameter_set(func.parameters)
+            .map_err(D::Error::custom)?;
+        let functional =
+            Self::with_options(Arc::new(parameters), func.fmt_version, func.options).into_inner();
+        match func.ideal_gas {
+            Some(ideal_gas) => functional
+                .with_ideal_gas(ideal_gas)
+                .map_err(D::Error::custom),
+            None => Ok(functional),
+        }
+    }
+}
+
 impl MolarWeight for GcPcSaftFunctional {
     fn molar_weight(&self) -> SIArray1 {
         self.parameters.molarweight.clone() * GRAM / MOL
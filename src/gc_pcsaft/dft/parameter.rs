@@ -21,6 +21,7 @@ pub struct GcPcSaftFunctionalParameters {
     pub sigma: Array1<f64>,
     pub epsilon_k: Array1<f64>,
     pub bonds: UnGraph<(), ()>,
+    pub ring_closures: Vec<[usize; 2]>,
     pub association: AssociationParameters,
     pub psi_dft: Array1<f64>,
     pub k_ij: Array2<f64>,
@@ -56,6 +57,7 @@ impl ParameterHetero for GcPcSaftFunctionalParameters {
         let mut sigma = Vec::new();
         let mut epsilon_k = Vec::new();
         let mut bonds = Graph::default();
+        let mut ring_closures = Vec::new();
         let mut association_records = Vec::new();
         let mut psi_dft = Vec::new();
 
@@ -68,6 +70,12 @@ impl ParameterHetero for GcPcSaftFunctionalParameters {
                     (),
                 )
             }));
+            ring_closures.extend(
+                chemical_record
+                    .ring_closures()
+                    .into_iter()
+                    .map(|[i, j]| [segment_index + i, segment_index + j]),
+            );
 
             for id in &chemical_record.segments {
                 let segment = segment_map
@@ -80,7 +88,13 @@ impl ParameterHetero for GcPcSaftFunctionalParameters {
                 sigma.push(segment.model_record.sigma);
                 epsilon_k.push(segment.model_record.epsilon_k);
 
-                association_records.push(segment.model_record.association_record);
+                association_records.push(
+                    segment
+                        .model_record
+                        .association_record
+                        .into_iter()
+                        .collect::<Vec<_>>(),
+                );
 
                 psi_dft.push(segment.model_record.psi_dft.unwrap_or(PSI_GC_DFT));
 
@@ -124,7 +138,7 @@ impl ParameterHetero for GcPcSaftFunctionalParameters {
         let sigma = Array1::from_vec(sigma);
         let component_index = Array1::from_vec(component_index);
         let association =
-            AssociationParameters::new(&association_records, &sigma, Some(&component_index));
+            AssociationParameters::new(&association_records, &sigma, &[], Some(&component_index));
 
         Ok(Self {
             molarweight,
@@ -134,6 +148,7 @@ impl ParameterHetero for GcPcSaftFunctionalParameters {
             sigma,
             epsilon_k: Array1::from_vec(epsilon_k),
             bonds,
+            ring_closures,
             association,
             psi_dft: Array1::from_vec(psi_dft),
             k_ij,
@@ -223,6 +238,9 @@ impl std::fmt::Display for GcPcSaftFunctionalParameters {
         write!(f, "\n\tsigma={}", self.sigma)?;
         write!(f, "\n\tepsilon_k={}", self.epsilon_k)?;
         write!(f, "\n\tbonds={:?}", self.bonds)?;
+        if !self.ring_closures.is_empty() {
+            write!(f, "\n\tring_closures={:?}", self.ring_closures)?;
+        }
         // if !self.assoc_segment.is_empty() {
         //     write!(f, "\n\tassoc_segment={}", self.assoc_segment)?;
         //     write!(f, "\n\tkappa_ab={}", self.kappa_ab)?;
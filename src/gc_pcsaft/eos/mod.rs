@@ -1,10 +1,16 @@
 use crate::association::Association;
+use crate::gc_pcsaft::record::GcPcSaftRecord;
 use crate::hard_sphere::HardSphere;
-use feos_core::joback::Joback;
-use feos_core::parameter::ParameterHetero;
-use feos_core::{EquationOfState, HelmholtzEnergy, IdealGasContribution, MolarWeight};
+use feos_core::ideal_gas::{IdealGasModel, WithIdealGas};
+use feos_core::joback::JobackRecord;
+use feos_core::parameter::{ParameterHetero, ParameterSetHetero};
+use feos_core::{
+    EosResult, EquationOfState, HelmholtzEnergy, IdealGasContribution, MaxDensity, MolarWeight,
+};
 use ndarray::Array1;
 use quantity::si::*;
+use serde::de::Error;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
 use std::f64::consts::FRAC_PI_6;
 use std::sync::Arc;
 
@@ -18,10 +24,12 @@ pub use parameter::{GcPcSaftChemicalRecord, GcPcSaftEosParameters};
 use polar::Dipole;
 
 /// Customization options for the gc-PC-SAFT equation of state and functional.
-#[derive(Copy, Clone)]
+#[derive(Copy, Clone, Serialize, Deserialize)]
 pub struct GcPcSaftOptions {
     /// maximum packing fraction
     pub max_eta: f64,
+    /// strategy for the calculation of the maximum density
+    pub max_density: MaxDensity,
     /// maximum number of iterations for cross association calculation
     pub max_iter_cross_assoc: usize,
     /// tolerance for cross association calculation
@@ -32,6 +40,7 @@ impl Default for GcPcSaftOptions {
     fn default() -> Self {
         Self {
             max_eta: 0.5,
+            max_density: MaxDensity::Heuristic,
             max_iter_cross_assoc: 50,
             tol_cross_assoc: 1e-10,
         }
@@ -43,7 +52,7 @@ pub struct GcPcSaft {
     pub parameters: Arc<GcPcSaftEosParameters>,
     options: GcPcSaftOptions,
     contributions: Vec<Box<dyn HelmholtzEnergy>>,
-    joback: Joback,
+    ideal_gas: IdealGasModel,
 }
 
 impl GcPcSaft {
@@ -75,9 +84,9 @@ impl GcPcSaft {
             parameters: parameters.clone(),
             options,
             contributions,
-            joback: parameters.joback_records.clone().map_or_else(
-                || Joback::default(parameters.chemical_records.len()),
-                Joback::new,
+            ideal_gas: parameters.joback_records.clone().map_or_else(
+                || IdealGasModel::default(parameters.chemical_records.len()),
+                IdealGasModel::joback,
             ),
         }
     }
@@ -89,17 +98,22 @@ impl EquationOfState for GcPcSaft {
     }
 
     fn subset(&self, component_list: &[usize]) -> Self {
-        Self::with_options(
-            Arc::new(self.parameters.subset(component_list)),
-            self.options,
-        )
+        Self {
+            ideal_gas: self.ideal_gas.subset(component_list),
+            ..Self::with_options(
+                Arc::new(self.parameters.subset(component_list)),
+                self.options,
+            )
+        }
     }
 
     fn compute_max_density(&self, moles: &Array1<f64>) -> f64 {
         let p = &self.parameters;
         let moles_segments: Array1<f64> = p.component_index.iter().map(|&i| moles[i]).collect();
-        self.options.max_eta * moles.sum()
-            / (FRAC_PI_6 * &p.m * p.sigma.mapv(|v| v.powi(3)) * moles_segments).sum()
+        self.options.max_density.apply(
+            self.options.max_eta * moles.sum()
+                / (FRAC_PI_6 * &p.m * p.sigma.mapv(|v| v.powi(3)) * moles_segments).sum(),
+        )
     }
 
     fn residual(&self) -> &[Box<dyn HelmholtzEnergy>] {
@@ -107,7 +121,45 @@ impl EquationOfState for GcPcSaft {
     }
 
     fn ideal_gas(&self) -> &dyn IdealGasContribution {
-        &self.joback
+        &self.ideal_gas
+    }
+}
+
+impl WithIdealGas for GcPcSaft {
+    fn with_ideal_gas(mut self, ideal_gas: IdealGasModel) -> EosResult<Self> {
+        ideal_gas.validate_components(self.components())?;
+        self.ideal_gas = ideal_gas;
+        Ok(self)
+    }
+}
+
+// Auxiliary structure used to (de)serialize the equation of state.
+#[derive(Serialize, Deserialize)]
+struct GcPcSaftJSON {
+    parameters: ParameterSetHetero<GcPcSaftChemicalRecord, GcPcSaftRecord, JobackRecord, f64>,
+    options: GcPcSaftOptions,
+    ideal_gas: IdealGasModel,
+}
+
+impl Serialize for GcPcSaft {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        GcPcSaftJSON {
+            parameters: self.parameters.parameter_set(),
+            options: self.options,
+            ideal_gas: self.ideal_gas.clone(),
+        }
+        .serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for GcPcSaft {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let eos = GcPcSaftJSON::deserialize(deserializer)?;
+        let parameters =
+            GcPcSaftEosParameters::from_parameter_set(eos.parameters).map_err(D::Error::custom)?;
+        Self::with_options(Arc::new(parameters), eos.options)
+            .with_ideal_gas(eos.ideal_gas)
+            .map_err(D::Error::custom)
     }
 }
 
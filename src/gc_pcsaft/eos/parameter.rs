@@ -10,11 +10,22 @@ use indexmap::IndexMap;
 use ndarray::{Array1, Array2};
 use num_dual::DualNum;
 use quantity::si::{JOULE, KB, KELVIN};
+use serde::{Deserialize, Serialize};
 use std::borrow::Cow;
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
 use std::fmt::Write;
 
-#[derive(Clone)]
+// Auxiliary structure used to (de)serialize chemical records with bond counts.
+#[derive(Serialize, Deserialize)]
+struct GcPcSaftChemicalRecordJSON {
+    identifier: Identifier,
+    segments: BTreeMap<String, f64>,
+    bonds: Vec<([String; 2], f64)>,
+}
+
+#[derive(Clone, Serialize, Deserialize)]
+#[serde(from = "GcPcSaftChemicalRecordJSON")]
+#[serde(into = "GcPcSaftChemicalRecordJSON")]
 pub struct GcPcSaftChemicalRecord {
     pub identifier: Identifier,
     pub segments: HashMap<String, f64>,
@@ -35,6 +46,28 @@ impl GcPcSaftChemicalRecord {
     }
 }
 
+impl From<GcPcSaftChemicalRecordJSON> for GcPcSaftChemicalRecord {
+    fn from(record: GcPcSaftChemicalRecordJSON) -> Self {
+        Self::new(
+            record.identifier,
+            record.segments.into_iter().collect(),
+            record.bonds.into_iter().collect(),
+        )
+    }
+}
+
+impl From<GcPcSaftChemicalRecord> for GcPcSaftChemicalRecordJSON {
+    fn from(record: GcPcSaftChemicalRecord) -> Self {
+        let mut bonds: Vec<_> = record.bonds.into_iter().collect();
+        bonds.sort_by(|a, b| a.0.cmp(&b.0));
+        Self {
+            identifier: record.identifier,
+            segments: record.segments.into_iter().collect(),
+            bonds,
+        }
+    }
+}
+
 impl SegmentCount for GcPcSaftChemicalRecord {
     type Count = f64;
 
@@ -138,12 +171,13 @@ impl ParameterHetero for GcPcSaftEosParameters {
                 sigma.push(segment.model_record.sigma);
                 epsilon_k.push(segment.model_record.epsilon_k);
 
-                let mut assoc = segment.model_record.association_record;
-                if let Some(mut assoc) = assoc.as_mut() {
-                    assoc.na = Some(assoc.na.unwrap_or(1.0) * count);
-                    assoc.nb = Some(assoc.nb.unwrap_or(1.0) * count);
-                };
-                association_records.push(assoc);
+                let assoc = segment.model_record.association_record;
+                association_records.push(
+                    assoc
+                        .map(|assoc| assoc.scale_sites(count))
+                        .into_iter()
+                        .collect::<Vec<_>>(),
+                );
 
                 m_i += segment.model_record.m * count;
                 sigma_i += segment.model_record.m * segment.model_record.sigma.powi(3) * count;
@@ -229,7 +263,7 @@ impl ParameterHetero for GcPcSaftEosParameters {
         let sigma = Array1::from_vec(sigma);
         let component_index = Array1::from_vec(component_index);
         let association =
-            AssociationParameters::new(&association_records, &sigma, Some(&component_index));
+            AssociationParameters::new(&association_records, &sigma, &[], Some(&component_index));
 
         Ok(Self {
             molarweight,
@@ -426,7 +460,7 @@ pub mod test {
                 2.7702,
                 334.29,
                 None,
-                Some(AssociationRecord::new(0.009583, 2575.9, None, None)),
+                Some(AssociationRecord::new(0.009583, 2575.9, None, None, None)),
                 None,
             ),
             None,
@@ -503,4 +537,15 @@ pub mod test {
             (181.49f64 * 334.29).sqrt() * 1.0087
         );
     }
+
+    #[test]
+    fn test_json_round_trip() -> Result<(), ParameterError> {
+        let params = ethanol_propanol(true);
+        let json = params.to_json_str()?;
+        let params_json = GcPcSaftEosParameters::from_json_str(&json)?;
+        assert_eq!(params.molarweight, params_json.molarweight);
+        assert_eq!(params.m.sum(), params_json.m.sum());
+        assert_eq!(json, params_json.to_json_str()?);
+        Ok(())
+    }
 }
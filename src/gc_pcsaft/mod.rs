@@ -11,10 +11,12 @@ pub(crate) mod eos;
 #[cfg(feature = "micelles")]
 pub mod micelles;
 mod record;
+mod smiles;
 #[cfg(feature = "dft")]
 pub use dft::{GcPcSaftFunctional, GcPcSaftFunctionalParameters};
 pub use eos::{GcPcSaft, GcPcSaftChemicalRecord, GcPcSaftEosParameters, GcPcSaftOptions};
 pub use record::GcPcSaftRecord;
+pub use smiles::{chemical_record_from_smiles, FromSmiles};
 
 #[cfg(feature = "python")]
 pub mod python;
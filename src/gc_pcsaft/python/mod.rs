@@ -2,14 +2,19 @@
 use super::dft::GcPcSaftFunctionalParameters;
 use super::eos::GcPcSaftEosParameters;
 use super::record::GcPcSaftRecord;
+use super::smiles::FromSmiles;
 use crate::association::PyAssociationRecord;
 use feos_core::joback::JobackRecord;
 use feos_core::parameter::{
     BinaryRecord, IdentifierOption, ParameterError, ParameterHetero, SegmentRecord,
 };
 use feos_core::python::joback::PyJobackRecord;
-use feos_core::python::parameter::{PyBinarySegmentRecord, PyChemicalRecord, PyIdentifier};
-use feos_core::{impl_json_handling, impl_parameter_from_segments, impl_segment_record};
+use feos_core::python::parameter::{
+    PyBinarySegmentRecord, PyChemicalRecord, PyIdentifier, PyMetadata,
+};
+use feos_core::{
+    impl_json_handling, impl_parameter_from_segments, impl_parameter_json, impl_segment_record,
+};
 #[cfg(feature = "dft")]
 use numpy::{PyArray2, ToPyArray};
 use pyo3::prelude::*;
@@ -84,6 +89,78 @@ impl_segment_record!(
     PyJobackRecord
 );
 
+macro_rules! impl_from_smiles {
+    ($parameter:ty, $py_parameter:ty) => {
+        #[pymethods]
+        impl $py_parameter {
+            /// Creates parameters from SMILES strings and segment records.
+            ///
+            /// The segments and bonds of all substances are assigned
+            /// automatically from their molecular structure.
+            ///
+            /// Parameters
+            /// ----------
+            /// smiles : List[str]
+            ///     The SMILES strings of all substances.
+            /// segment_records : [SegmentRecord]
+            ///     A list of records containing the parameters of
+            ///     all individual segments.
+            /// binary_segment_records : [BinarySegmentRecord], optional
+            ///     A list of binary segment-segment parameters.
+            #[staticmethod]
+            #[pyo3(text_signature = "(smiles, segment_records, binary_segment_records=None)")]
+            fn from_smiles(
+                smiles: Vec<&str>,
+                segment_records: Vec<PySegmentRecord>,
+                binary_segment_records: Option<Vec<PyBinarySegmentRecord>>,
+            ) -> Result<Self, ParameterError> {
+                Ok(Self(Arc::new(<$parameter>::from_smiles(
+                    &smiles,
+                    segment_records.into_iter().map(|sr| sr.0).collect(),
+                    binary_segment_records.map(|r| {
+                        r.into_iter()
+                            .map(|r| BinaryRecord {
+                                id1: r.0.id1,
+                                id2: r.0.id2,
+                                model_record: r.0.model_record.into(),
+                                metadata: r.0.metadata,
+                            })
+                            .collect()
+                    }),
+                )?)))
+            }
+
+            /// Creates parameters from SMILES strings and segment records
+            /// stored in json files.
+            ///
+            /// The segments and bonds of all substances are assigned
+            /// automatically from their molecular structure.
+            ///
+            /// Parameters
+            /// ----------
+            /// smiles : List[str]
+            ///     The SMILES strings of all substances.
+            /// segments_path : str
+            ///     Path to file containing segment parameters.
+            /// binary_path : str, optional
+            ///     Path to file containing binary segment-segment parameters.
+            #[staticmethod]
+            #[pyo3(text_signature = "(smiles, segments_path, binary_path=None)")]
+            fn from_json_smiles(
+                smiles: Vec<&str>,
+                segments_path: String,
+                binary_path: Option<String>,
+            ) -> Result<Self, ParameterError> {
+                Ok(Self(Arc::new(<$parameter>::from_json_smiles(
+                    &smiles,
+                    segments_path,
+                    binary_path,
+                )?)))
+            }
+        }
+    };
+}
+
 #[pyclass(name = "GcPcSaftEosParameters")]
 #[pyo3(
     text_signature = "(pure_records, segmentbinary_records=None, substances=None, search_option='Name')"
@@ -92,6 +169,8 @@ impl_segment_record!(
 pub struct PyGcPcSaftEosParameters(pub Arc<GcPcSaftEosParameters>);
 
 impl_parameter_from_segments!(GcPcSaftEosParameters, PyGcPcSaftEosParameters);
+impl_from_smiles!(GcPcSaftEosParameters, PyGcPcSaftEosParameters);
+impl_parameter_json!(GcPcSaftEosParameters, PyGcPcSaftEosParameters);
 
 #[pymethods]
 impl PyGcPcSaftEosParameters {
@@ -114,6 +193,10 @@ pub struct PyGcPcSaftFunctionalParameters(pub Arc<GcPcSaftFunctionalParameters>)
 
 #[cfg(feature = "dft")]
 impl_parameter_from_segments!(GcPcSaftFunctionalParameters, PyGcPcSaftFunctionalParameters);
+#[cfg(feature = "dft")]
+impl_from_smiles!(GcPcSaftFunctionalParameters, PyGcPcSaftFunctionalParameters);
+#[cfg(feature = "dft")]
+impl_parameter_json!(GcPcSaftFunctionalParameters, PyGcPcSaftFunctionalParameters);
 
 #[cfg(feature = "dft")]
 #[pymethods]
@@ -150,6 +233,7 @@ impl PyGcPcSaftFunctionalParameters {
 #[pymodule]
 pub fn gc_pcsaft(_py: Python<'_>, m: &PyModule) -> PyResult<()> {
     m.add_class::<PyIdentifier>()?;
+    m.add_class::<PyMetadata>()?;
     m.add_class::<IdentifierOption>()?;
     m.add_class::<PyChemicalRecord>()?;
     m.add_class::<PyJobackRecord>()?;
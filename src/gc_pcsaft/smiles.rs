@@ -0,0 +1,631 @@
+//! Automatic assignment of gc-PC-SAFT segments from SMILES strings.
+//!
+//! The molecules are fragmented into the groups of
+//! [Sauer et al. (2014)](https://doi.org/10.1021/ie502203w). Because the
+//! Joback parameters are stored in the segment records, the ideal gas
+//! groups are assigned at the same time.
+use feos_core::parameter::{
+    BinaryRecord, ChemicalRecord, Identifier, ParameterError, ParameterHetero, SegmentRecord,
+};
+use std::collections::{HashMap, VecDeque};
+use std::fs::File;
+use std::io::BufReader;
+use std::path::Path;
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Bond {
+    Single,
+    Double,
+    Triple,
+    Aromatic,
+}
+
+impl Bond {
+    fn from_symbol(symbol: char) -> Option<Self> {
+        match symbol {
+            '-' | '/' | '\\' => Some(Self::Single),
+            '=' => Some(Self::Double),
+            '#' => Some(Self::Triple),
+            ':' => Some(Self::Aromatic),
+            _ => None,
+        }
+    }
+
+    fn order(self) -> usize {
+        match self {
+            Self::Single | Self::Aromatic => 1,
+            Self::Double => 2,
+            Self::Triple => 3,
+        }
+    }
+}
+
+struct Atom {
+    element: String,
+    aromatic: bool,
+    hydrogens: usize,
+}
+
+/// Hydrogen-suppressed molecular graph.
+struct Molecule {
+    atoms: Vec<Atom>,
+    bonds: Vec<(usize, usize, Bond)>,
+}
+
+fn standard_valences(element: &str) -> &'static [usize] {
+    match element {
+        "B" => &[3],
+        "C" => &[4],
+        "N" | "P" => &[3, 5],
+        "O" => &[2],
+        "S" => &[2, 4, 6],
+        "F" | "Cl" | "Br" | "I" => &[1],
+        _ => &[],
+    }
+}
+
+/// Parse the content of a bracket atom, e.g., `[nH]` or `[13CH4]`.
+fn parse_bracket_atom(token: &[char]) -> Result<(String, bool, Option<usize>), String> {
+    let text: String = token.iter().collect();
+    let mut chars = token
+        .iter()
+        .copied()
+        .skip_while(char::is_ascii_digit)
+        .peekable();
+    let (element, aromatic) = match chars.next() {
+        Some(c) if c.is_ascii_lowercase() => (c.to_ascii_uppercase().to_string(), true),
+        Some(c) if c.is_ascii_uppercase() => {
+            let mut element = c.to_string();
+            if let Some(&l) = chars.peek() {
+                if l.is_ascii_lowercase() {
+                    element.push(l);
+                    chars.next();
+                }
+            }
+            (element, false)
+        }
+        _ => return Err(format!("invalid bracket atom [{text}]")),
+    };
+    while chars.peek() == Some(&'@') {
+        chars.next();
+    }
+    let mut hydrogens = 0;
+    if chars.peek() == Some(&'H') {
+        chars.next();
+        hydrogens = 1;
+        if let Some(h) = chars.peek().and_then(|c| c.to_digit(10)) {
+            hydrogens = h as usize;
+            chars.next();
+        }
+    }
+    if chars.next().is_some() {
+        return Err(format!("charged atoms are not supported ([{text}])"));
+    }
+    Ok((element, aromatic, Some(hydrogens)))
+}
+
+impl Molecule {
+    fn from_smiles(smiles: &str) -> Result<Self, String> {
+        let chars: Vec<char> = smiles.chars().collect();
+        let mut atoms: Vec<(String, bool, Option<usize>)> = Vec::new();
+        let mut bonds: Vec<(usize, usize, Option<Bond>)> = Vec::new();
+        let mut branches = Vec::new();
+        let mut ring_bonds: HashMap<usize, (usize, Option<Bond>)> = HashMap::new();
+        let mut previous = None;
+        let mut bond = None;
+
+        let mut i = 0;
+        while i < chars.len() {
+            let c = chars[i];
+            i += 1;
+            match c {
+                '(' => branches.push(previous),
+                ')' => previous = branches.pop().ok_or("unmatched parenthesis")?,
+                '.' => return Err("disconnected structures are not supported".into()),
+                '0'..='9' | '%' => {
+                    let label = if c == '%' {
+                        let label: String = chars.iter().skip(i).take(2).collect();
+                        i += 2;
+                        label
+                            .parse()
+                            .map_err(|_| format!("invalid ring bond label %{label}"))?
+                    } else {
+                        c.to_digit(10).unwrap() as usize
+                    };
+                    let atom = previous.ok_or("ring bond label without atom")?;
+                    match ring_bonds.remove(&label) {
+                        Some((other, other_bond)) => bonds.push((other, atom, bond.or(other_bond))),
+                        None => {
+                            ring_bonds.insert(label, (atom, bond));
+                        }
+                    }
+                    bond = None;
+                }
+                c if Bond::from_symbol(c).is_some() => bond = Bond::from_symbol(c),
+                _ => {
+                    let atom = match c {
+                        '[' => {
+                            let end = chars[i..]
+                                .iter()
+                                .position(|&c| c == ']')
+                                .ok_or("unmatched bracket")?
+                                + i;
+                            let atom = parse_bracket_atom(&chars[i..end])?;
+                            i = end + 1;
+                            atom
+                        }
+                        'C' if chars.get(i) == Some(&'l') => {
+                            i += 1;
+                            ("Cl".into(), false, None)
+                        }
+                        'B' if chars.get(i) == Some(&'r') => {
+                            i += 1;
+                            ("Br".into(), false, None)
+                        }
+                        'B' | 'C' | 'N' | 'O' | 'P' | 'S' | 'F' | 'I' => {
+                            (c.to_string(), false, None)
+                        }
+                        'b' | 'c' | 'n' | 'o' | 'p' | 's' => {
+                            (c.to_ascii_uppercase().to_string(), true, None)
+                        }
+                        _ => return Err(format!("unexpected character '{c}'")),
+                    };
+                    atoms.push(atom);
+                    let index = atoms.len() - 1;
+                    if let Some(previous) = previous {
+                        bonds.push((previous, index, bond));
+                    }
+                    previous = Some(index);
+                    bond = None;
+                }
+            }
+        }
+        if !branches.is_empty() {
+            return Err("unmatched parenthesis".into());
+        }
+        if !ring_bonds.is_empty() {
+            return Err("unclosed ring bond".into());
+        }
+        if atoms.is_empty() {
+            return Err("empty SMILES".into());
+        }
+
+        // bonds without explicit symbol are aromatic between aromatic atoms
+        let bonds: Vec<_> = bonds
+            .into_iter()
+            .map(|(a, b, bond)| {
+                let bond = bond.unwrap_or(if atoms[a].1 && atoms[b].1 {
+                    Bond::Aromatic
+                } else {
+                    Bond::Single
+                });
+                (a, b, bond)
+            })
+            .collect();
+
+        // implicit hydrogens from the lowest standard valence
+        let atoms = atoms
+            .into_iter()
+            .enumerate()
+            .map(|(i, (element, aromatic, hydrogens))| {
+                let hydrogens = hydrogens.unwrap_or_else(|| {
+                    let bond_orders = bonds
+                        .iter()
+                        .filter(|&&(a, b, _)| a == i || b == i)
+                        .map(|(_, _, bond)| bond.order())
+                        .sum::<usize>()
+                        + aromatic as usize;
+                    standard_valences(&element)
+                        .iter()
+                        .find(|&&v| v >= bond_orders)
+                        .map_or(0, |v| v - bond_orders)
+                });
+                Atom {
+                    element,
+                    aromatic,
+                    hydrogens,
+                }
+            })
+            .collect();
+
+        let mut molecule = Self { atoms, bonds };
+        molecule.perceive_aromaticity();
+        Ok(molecule)
+    }
+
+    fn neighbors(&self, atom: usize) -> impl Iterator<Item = (usize, Bond)> + '_ {
+        self.bonds.iter().filter_map(move |&(a, b, bond)| {
+            if a == atom {
+                Some((b, bond))
+            } else if b == atom {
+                Some((a, bond))
+            } else {
+                None
+            }
+        })
+    }
+
+    fn bond(&self, a: usize, b: usize) -> Option<Bond> {
+        self.neighbors(a)
+            .find(|&(n, _)| n == b)
+            .map(|(_, bond)| bond)
+    }
+
+    /// The atoms of the smallest ring that contains the atom.
+    fn smallest_ring(&self, atom: usize) -> Option<Vec<usize>> {
+        self.neighbors(atom)
+            .filter_map(|(start, _)| {
+                // shortest path back to the atom without the direct bond
+                let mut previous = vec![None; self.atoms.len()];
+                previous[start] = Some(start);
+                let mut queue = VecDeque::from([start]);
+                while let Some(current) = queue.pop_front() {
+                    if current == atom {
+                        break;
+                    }
+                    for (next, _) in self.neighbors(current) {
+                        if (current == start && next == atom) || previous[next].is_some() {
+                            continue;
+                        }
+                        previous[next] = Some(current);
+                        queue.push_back(next);
+                    }
+                }
+                previous[atom].map(|_| {
+                    let mut ring = vec![atom];
+                    let mut current = atom;
+                    while current != start {
+                        current = previous[current].unwrap();
+                        ring.push(current);
+                    }
+                    ring
+                })
+            })
+            .min_by_key(|ring| ring.len())
+    }
+
+    /// Mark six-membered carbon rings with alternating single and double
+    /// bonds (Kekulé structures) as aromatic.
+    fn perceive_aromaticity(&mut self) {
+        for atom in 0..self.atoms.len() {
+            if self.atoms[atom].aromatic {
+                continue;
+            }
+            let ring = match self.smallest_ring(atom) {
+                Some(ring) if ring.len() == 6 => ring,
+                _ => continue,
+            };
+            if ring.iter().any(|&a| self.atoms[a].element != "C") {
+                continue;
+            }
+            let ring_bonds: Vec<_> = (0..6)
+                .map(|k| self.bond(ring[k], ring[(k + 1) % 6]))
+                .collect();
+            let alternating = (0..6).all(|k| {
+                matches!(
+                    (ring_bonds[k], ring_bonds[(k + 1) % 6]),
+                    (Some(Bond::Single), Some(Bond::Double))
+                        | (Some(Bond::Double), Some(Bond::Single))
+                )
+            });
+            if alternating {
+                for k in 0..6 {
+                    let (a, b) = (ring[k], ring[(k + 1) % 6]);
+                    self.atoms[a].aromatic = true;
+                    for bond in self.bonds.iter_mut() {
+                        if (bond.0, bond.1) == (a, b) || (bond.0, bond.1) == (b, a) {
+                            bond.2 = Bond::Aromatic;
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    fn is_element(&self, atom: usize, element: &str) -> bool {
+        self.atoms[atom].element == element
+    }
+
+    /// Oxygen atom bonded to the atom with the given bond type.
+    fn oxygen(&self, atom: usize, bond: Bond, hydrogens: usize) -> Option<usize> {
+        self.neighbors(atom)
+            .find(|&(n, b)| {
+                b == bond && self.is_element(n, "O") && self.atoms[n].hydrogens == hydrogens
+            })
+            .map(|(n, _)| n)
+    }
+}
+
+/// Assignment of atoms to groups.
+struct Fragments {
+    /// Index of the first atom and name of every group
+    groups: Vec<(usize, &'static str)>,
+    group_index: Vec<Option<usize>>,
+}
+
+impl Fragments {
+    fn new(atoms: usize) -> Self {
+        Self {
+            groups: Vec::new(),
+            group_index: vec![None; atoms],
+        }
+    }
+
+    fn assign(&mut self, name: &'static str, members: &[usize]) {
+        for &a in members {
+            self.group_index[a] = Some(self.groups.len());
+        }
+        self.groups.push((*members.iter().min().unwrap(), name));
+    }
+
+    fn is_assigned(&self, atom: usize) -> bool {
+        self.group_index[atom].is_some()
+    }
+}
+
+/// Fragment a molecule, given as SMILES string, into gc-PC-SAFT segments.
+///
+/// Segments and bonds are assigned according to the groups of Sauer et al. (2014):
+/// alkyl groups (`CH3`, `CH2`, `>CH`, `>C<`), alkenes (`=CH2`, `=CH`, `=C<`),
+/// terminal alkynes (`C≡CH`), five- and six-membered rings (`CH2_pent`, `CH_pent`,
+/// `CH2_hex`, `CH_hex`), aromatic carbons (`CH_arom`, `C_arom`), aldehydes (`CH=O`),
+/// ketones (`>C=O`), ethers (`OCH3`, `OCH2`), esters (`HCOO`, `COO`), alcohols (`OH`)
+/// and primary amines (`NH2`). Kekulé structures of benzene rings are recognized as
+/// aromatic. The SMILES string is stored in the identifier of the chemical record.
+pub fn chemical_record_from_smiles(smiles: &str) -> Result<ChemicalRecord, ParameterError> {
+    let error = |msg: String| ParameterError::Fragmentation(smiles.into(), msg);
+    let molecule = Molecule::from_smiles(smiles).map_err(error)?;
+    let atoms = &molecule.atoms;
+
+    let mut fragments = Fragments::new(atoms.len());
+    let carbons: Vec<_> = (0..atoms.len())
+        .filter(|&a| molecule.is_element(a, "C"))
+        .collect();
+
+    // carbonyl groups: esters, aldehydes and ketones
+    for &c in &carbons {
+        let o_double = match molecule.oxygen(c, Bond::Double, 0) {
+            Some(o) => o,
+            None => continue,
+        };
+        if molecule.oxygen(c, Bond::Single, 1).is_some() {
+            return Err(error("carboxylic acids are not supported".into()));
+        }
+        match (molecule.oxygen(c, Bond::Single, 0), atoms[c].hydrogens) {
+            (Some(o_single), 1) => fragments.assign("HCOO", &[c, o_double, o_single]),
+            (Some(o_single), 0) => fragments.assign("COO", &[c, o_double, o_single]),
+            (None, 1) => fragments.assign("CH=O", &[c, o_double]),
+            (None, 0) => fragments.assign(">C=O", &[c, o_double]),
+            _ => return Err(error(format!("no group for carbonyl atom {c}"))),
+        }
+    }
+
+    // ethers: the oxygen is combined with a methyl or methylene group
+    for o in 0..atoms.len() {
+        if !molecule.is_element(o, "O") || atoms[o].hydrogens > 0 || fragments.is_assigned(o) {
+            continue;
+        }
+        let alkyl = |hydrogens| {
+            molecule.neighbors(o).map(|(n, _)| n).find(|&n| {
+                !fragments.is_assigned(n)
+                    && molecule.is_element(n, "C")
+                    && atoms[n].hydrogens == hydrogens
+                    && molecule.smallest_ring(n).is_none()
+                    && molecule.neighbors(n).all(|(_, b)| b == Bond::Single)
+            })
+        };
+        match (alkyl(3), alkyl(2)) {
+            (Some(c), _) => fragments.assign("OCH3", &[o, c]),
+            (None, Some(c)) => fragments.assign("OCH2", &[o, c]),
+            _ => return Err(error(format!("no group for ether oxygen {o}"))),
+        }
+    }
+
+    // terminal alkynes
+    for &c in &carbons {
+        if fragments.is_assigned(c) || atoms[c].hydrogens != 1 {
+            continue;
+        }
+        if let Some((c2, _)) = molecule.neighbors(c).find(|&(_, b)| b == Bond::Triple) {
+            fragments.assign("C≡CH", &[c, c2]);
+        }
+    }
+
+    // single atom groups
+    for a in 0..atoms.len() {
+        if fragments.is_assigned(a) {
+            continue;
+        }
+        let atom = &atoms[a];
+        let name = match (atom.element.as_str(), atom.hydrogens) {
+            ("O", 1) => Some("OH"),
+            ("N", 2) => Some("NH2"),
+            ("C", h) if atom.aromatic => match h {
+                1 => Some("CH_arom"),
+                0 => Some("C_arom"),
+                _ => None,
+            },
+            ("C", h) => match (molecule.smallest_ring(a).map(|r| r.len()), h) {
+                (Some(5), 2) => Some("CH2_pent"),
+                (Some(5), 1) => Some("CH_pent"),
+                (Some(6), 2) => Some("CH2_hex"),
+                (Some(6), 1) => Some("CH_hex"),
+                (Some(_), _) => None,
+                (None, h) => {
+                    let bonds: Vec<_> = molecule.neighbors(a).map(|(_, b)| b).collect();
+                    if bonds.contains(&Bond::Double) {
+                        match h {
+                            2 => Some("=CH2"),
+                            1 => Some("=CH"),
+                            0 => Some("=C<"),
+                            _ => None,
+                        }
+                    } else if bonds.iter().all(|&b| b == Bond::Single) {
+                        match h {
+                            3 => Some("CH3"),
+                            2 => Some("CH2"),
+                            1 => Some(">CH"),
+                            0 => Some(">C<"),
+                            _ => None,
+                        }
+                    } else {
+                        None
+                    }
+                }
+            },
+            _ => None,
+        };
+        match name {
+            Some(name) => fragments.assign(name, &[a]),
+            None => {
+                return Err(error(format!(
+                    "no group for atom {a} ({} with {} hydrogens)",
+                    atom.element, atom.hydrogens
+                )))
+            }
+        }
+    }
+
+    // order the segments by their position in the SMILES string
+    let Fragments {
+        groups,
+        group_index,
+    } = fragments;
+    let mut order: Vec<_> = (0..groups.len()).collect();
+    order.sort_by_key(|&g| groups[g].0);
+    let mut position = vec![0; groups.len()];
+    for (p, &g) in order.iter().enumerate() {
+        position[g] = p;
+    }
+    let segments = order.iter().map(|&g| groups[g].1.to_string()).collect();
+    let mut bonds = Vec::new();
+    for &(a, b, _) in &molecule.bonds {
+        let (ga, gb) = (group_index[a].unwrap(), group_index[b].unwrap());
+        if ga != gb {
+            let bond = [position[ga], position[gb]];
+            if !bonds.contains(&bond) && !bonds.contains(&[bond[1], bond[0]]) {
+                bonds.push(bond);
+            }
+        }
+    }
+
+    Ok(ChemicalRecord::new(
+        Identifier::new(None, None, None, Some(smiles), None, None),
+        segments,
+        Some(bonds),
+    ))
+}
+
+/// Construction of heterosegmented parameters from SMILES strings.
+pub trait FromSmiles: ParameterHetero
+where
+    ChemicalRecord: Into<Self::Chemical>,
+{
+    /// Creates parameters from SMILES strings and segment records.
+    fn from_smiles(
+        smiles: &[&str],
+        segment_records: Vec<SegmentRecord<Self::Pure, Self::IdealGas>>,
+        binary_segment_records: Option<Vec<BinaryRecord<String, Self::Binary>>>,
+    ) -> Result<Self, ParameterError> {
+        let chemical_records = smiles
+            .iter()
+            .map(|s| chemical_record_from_smiles(s))
+            .collect::<Result<Vec<_>, _>>()?;
+        Self::from_segments(chemical_records, segment_records, binary_segment_records)
+    }
+
+    /// Creates parameters from SMILES strings and segment records stored in json files.
+    fn from_json_smiles<P: AsRef<Path>>(
+        smiles: &[&str],
+        file_segments: P,
+        file_binary: Option<P>,
+    ) -> Result<Self, ParameterError> {
+        let segment_records = SegmentRecord::from_json(file_segments)?;
+        let binary_records = file_binary
+            .map(|file_binary| {
+                let reader = BufReader::new(File::open(file_binary)?);
+                let binary_records: Result<
+                    Vec<BinaryRecord<String, Self::Binary>>,
+                    ParameterError,
+                > = Ok(serde_json::from_reader(reader)?);
+                binary_records
+            })
+            .transpose()?;
+        Self::from_smiles(smiles, segment_records, binary_records)
+    }
+}
+
+impl<T: ParameterHetero> FromSmiles for T where ChemicalRecord: Into<T::Chemical> {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_fragmentation() {
+        let file = File::open("./parameters/pcsaft/gc_substances.json").unwrap();
+        let records: Vec<ChemicalRecord> = serde_json::from_reader(BufReader::new(file)).unwrap();
+        let names = [
+            "1-hexanol",
+            "2-methyl-2-butanol",
+            "2-propylamine",
+            "aniline",
+            "2,3-dimethylbutane",
+            "neopentane",
+            "ethylcyclohexane",
+            "cyclopentene",
+            "1-hexene",
+            "o-xylene",
+            "tetralin",
+            "biphenyl",
+            "acetone",
+            "3-pentanone",
+            "butanal",
+            "methyl methanoate",
+            "propyl ethanoate",
+            "methyl butanoate",
+            "diethyl ether",
+            "methyl n-propyl ether",
+        ];
+        for name in names {
+            let record = records
+                .iter()
+                .find(|r| r.identifier.name.as_deref() == Some(name))
+                .unwrap();
+            let smiles = record.identifier.smiles.as_ref().unwrap();
+            let fragments = chemical_record_from_smiles(smiles).unwrap();
+            assert_eq!(
+                fragments.segment_count::<usize>(),
+                record.segment_count::<usize>(),
+                "{name}"
+            );
+            assert_eq!(
+                fragments.bond_count::<usize>(),
+                record.bond_count::<usize>(),
+                "{name}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_fragmentation_order() {
+        let record = chemical_record_from_smiles("CC(C)OC").unwrap();
+        assert_eq!(record.segments, vec!["CH3", ">CH", "CH3", "OCH3"]);
+        assert_eq!(record.bonds, vec![[0, 1], [1, 2], [1, 3]]);
+        assert_eq!(record.ring_closures(), Vec::<[usize; 2]>::new());
+
+        let record = chemical_record_from_smiles("C#CC1CCCCC1").unwrap();
+        let counts: HashMap<String, usize> = record.segment_count();
+        assert_eq!(counts["C≡CH"], 1);
+        assert_eq!(counts["CH_hex"], 1);
+        assert_eq!(counts["CH2_hex"], 5);
+        assert_eq!(record.ring_closures().len(), 1);
+    }
+
+    #[test]
+    fn test_fragmentation_errors() {
+        for smiles in ["C", "CC(=O)O", "CCCl", "[NH4+]", "CC.O", "CC(C", "C1CC"] {
+            assert!(chemical_record_from_smiles(smiles).is_err(), "{smiles}");
+        }
+    }
+}
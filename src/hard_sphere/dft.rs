@@ -1,4 +1,4 @@
-use feos_core::EosResult;
+use feos_core::{EosError, EosResult};
 use feos_dft::adsorption::FluidParameters;
 use feos_dft::solvation::PairPotential;
 use feos_dft::{
@@ -7,17 +7,18 @@ use feos_dft::{
 };
 use ndarray::*;
 use num_dual::DualNum;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
 use std::f64::consts::PI;
 use std::fmt;
 use std::sync::Arc;
 
-use super::{HardSphereProperties, MonomerShape};
+use super::{HardSphereProperties, MonomerShape, SizeDistribution};
 
 const PI36M1: f64 = 1.0 / (36.0 * PI);
 const N3_CUTOFF: f64 = 1e-5;
 
 /// Different versions of fundamental measure theory.
-#[derive(Clone, Copy)]
+#[derive(Clone, Copy, Serialize, Deserialize)]
 #[cfg_attr(feature = "python", pyo3::pyclass)]
 pub enum FMTVersion {
     /// White Bear ([Roth et al., 2002](https://doi.org/10.1088/0953-8984/14/46/313)) or modified ([Yu and Wu, 2002](https://doi.org/10.1063/1.1520530)) fundamental measure theory
@@ -26,6 +27,8 @@ pub enum FMTVersion {
     KierlikRosinberg,
     /// Anti-symmetric White Bear fundamental measure theory ([Rosenfeld et al., 1997](https://doi.org/10.1103/PhysRevE.55.4245)) and SI of ([Kessler et al., 2021](https://doi.org/10.1016/j.micromeso.2021.111263))
     AntiSymWhiteBear,
+    /// Tensorial White Bear fundamental measure theory ([Tarazona, 2000](https://doi.org/10.1103/PhysRevLett.84.694)) that describes the freezing of hard spheres and strongly confined fluids
+    TensorWhiteBear,
 }
 
 /// The [FunctionalContribution] for the hard sphere functional.
@@ -41,6 +44,7 @@ pub enum FMTVersion {
 /// |WhiteBear|$n_1n_2-\vec n_1\cdot\vec n_2$|$n_2^2-3\vec n_2\cdot\vec n_2$|
 /// |KierlikRosinberg|$n_1n_2$|$n_2^2$|
 /// |AntiSymWhiteBear|$n_1n_2-\vec n_1\cdot\vec n_2$|$n_2^2\left(1-\frac{\vec n_2\cdot\vec n_2}{n_2^2}\right)^3$|
+/// |TensorWhiteBear|$n_1n_2-\vec n_1\cdot\vec n_2$|$\frac{9}{2n_2}\left(\vec n_2\cdot\mathbf{n}_T\cdot\vec n_2-n_2\vec n_2\cdot\vec n_2-\mathrm{tr}\\,\mathbf{n}_T^3+n_2\mathrm{tr}\\,\mathbf{n}_T^2\right)$|
 ///
 /// The value of $f(n_3)$ numerically diverges for small $n_3$. Therefore, it is approximated with a Taylor expansion.
 /// $$f_3=\begin{cases}\frac{n_3+\left(1-n_3\right)^2\ln\left(1-n_3\right)}{n_3^2\left(1-n_3\right)^2}&\text{if }n_3>10^{-5}\\\\
@@ -51,7 +55,7 @@ pub enum FMTVersion {
 ///
 /// The weight functions differ between the different [FMTVersion]s.
 ///
-/// ||WhiteBear/AntiSymWhiteBear|KierlikRosinberg|
+/// ||WhiteBear/AntiSymWhiteBear/TensorWhiteBear|KierlikRosinberg|
 /// |-|:-:|:-:|
 /// |$\omega_0^\alpha(\mathbf{r})$|$\frac{C_{0,\alpha}}{\pi\sigma_\alpha^2}\\,\delta\\!\left(\frac{d_\alpha}{2}-\|\mathbf{r}\|\right)$|$C_{0,\alpha}\left(-\frac{1}{8\pi}\\,\delta\'\'\\!\left(\frac{d_\alpha}{2}-\|\mathbf{r}\|\right)+\frac{1}{2\pi\|\mathbf{r}\|}\\,\delta\'\\!\left(\frac{d_\alpha}{2}-\|\mathbf{r}\|\right)\right)$|
 /// |$\omega_1^\alpha(\mathbf{r})$|$\frac{C_{1,\alpha}}{2\pi\sigma_\alpha}\\,\delta\\!\left(\frac{d_\alpha}{2}-\|\mathbf{r}\|\right)$|$\frac{C_{1,\alpha}}{8\pi}\\,\delta\'\\!\left(\frac{d_\alpha}{2}-\|\mathbf{r}\|\right)$|
@@ -60,6 +64,10 @@ pub enum FMTVersion {
 /// |$\vec\omega_1^\alpha(\mathbf{r})$|$C_{3,\alpha}\frac{\mathbf{r}}{2\pi\sigma_\alpha\|\mathbf{r}\|}\\,\delta\\!\left(\frac{d_\alpha}{2}-\|\mathbf{r}\|\right)$|-|
 /// |$\vec\omega_2^\alpha(\mathbf{r})$|$C_{3,\alpha}\frac{\mathbf{r}}{\|\mathbf{r}\|}\\,\delta\\!\left(\frac{d_\alpha}{2}-\|\mathbf{r}\|\right)$|-|
 ///
+/// The tensorial weighted density $\mathbf{n}_T$ of the [FMTVersion::TensorWhiteBear] is obtained from the weight function $\mathbf{\omega}_T^\alpha(\mathbf{r})=C_{2,\alpha}\frac{\mathbf{r}\mathbf{r}}{\|\mathbf{r}\|^2}\\,\delta\\!\left(\frac{d_\alpha}{2}-\|\mathbf{r}\|\right)$. For planar systems (and in the bulk), the tensor is diagonal and its elements are expressed by the scalar weighted density $n_{T\perp}$
+/// $$\mathbf{n}_T=\mathrm{diag}\left(n_{T\perp},n_{T\perp},n_2-2n_{T\perp}\right)\qquad\omega_{T\perp}^\alpha(\mathbf{r})=\frac{2C_{2,\alpha}}{d_\alpha}\\,\Theta\\!\left(\frac{d_\alpha}{2}-\|\mathbf{r}\|\right)$$
+/// The tensorial version is therefore restricted to one-dimensional Cartesian grids and bulk systems. Multi-dimensional systems result in an error and spherical or cylindrical grids are not supported.
+///
 /// The geometry coefficients $C_{k,\alpha}$ and the segment diameters $d_\alpha$ are specified via the [HardSphereProperties] trait.
 pub struct FMTContribution<P> {
     pub properties: Arc<P>,
@@ -90,8 +98,31 @@ impl<P: HardSphereProperties, N: DualNum<f64>> FunctionalContributionDual<N>
     fn weight_functions(&self, temperature: N) -> WeightFunctionInfo<N> {
         let r = self.properties.hs_diameter(temperature) * 0.5;
         let [c0, c1, c2, c3] = self.properties.geometry_coefficients(temperature);
-        match (self.version, r.len()) {
-            (FMTVersion::WhiteBear | FMTVersion::AntiSymWhiteBear, 1) => {
+        let tensor = matches!(self.version, FMTVersion::TensorWhiteBear)
+            .then(|| tensor_weight_function(&c2, &r));
+        let segments = r.len();
+        let weight_functions = match (self.version, segments) {
+            (FMTVersion::KierlikRosinberg, _) => {
+                WeightFunctionInfo::new(self.properties.component_index().into_owned(), false)
+                    .extend(
+                        vec![
+                            WeightFunctionShape::KR0,
+                            WeightFunctionShape::KR1,
+                            WeightFunctionShape::Delta,
+                            WeightFunctionShape::Theta,
+                        ]
+                        .into_iter()
+                        .zip(self.properties.geometry_coefficients(temperature))
+                        .map(|(s, c)| WeightFunction {
+                            prefactor: c,
+                            kernel_radius: r.clone(),
+                            shape: s,
+                        })
+                        .collect(),
+                        true,
+                    )
+            }
+            (_, 1) => {
                 WeightFunctionInfo::new(self.properties.component_index().into_owned(), false)
                     .extend(
                         vec![
@@ -110,7 +141,7 @@ impl<P: HardSphereProperties, N: DualNum<f64>> FunctionalContributionDual<N>
                         false,
                     )
             }
-            (FMTVersion::WhiteBear | FMTVersion::AntiSymWhiteBear, _) => {
+            (_, _) => {
                 WeightFunctionInfo::new(self.properties.component_index().into_owned(), false)
                     .add(
                         WeightFunction {
@@ -167,26 +198,14 @@ impl<P: HardSphereProperties, N: DualNum<f64>> FunctionalContributionDual<N>
                         true,
                     )
             }
-            (FMTVersion::KierlikRosinberg, _) => {
-                WeightFunctionInfo::new(self.properties.component_index().into_owned(), false)
-                    .extend(
-                        vec![
-                            WeightFunctionShape::KR0,
-                            WeightFunctionShape::KR1,
-                            WeightFunctionShape::Delta,
-                            WeightFunctionShape::Theta,
-                        ]
-                        .into_iter()
-                        .zip(self.properties.geometry_coefficients(temperature))
-                        .map(|(s, c)| WeightFunction {
-                            prefactor: c,
-                            kernel_radius: r.clone(),
-                            shape: s,
-                        })
-                        .collect(),
-                        true,
-                    )
-            }
+        };
+
+        // diagonal elements of the tensorial weighted density (as component
+        // weighted density for pure components, consistent with the other
+        // weighted densities)
+        match tensor {
+            Some(tensor) => weight_functions.add(tensor, segments > 1),
+            None => weight_functions,
         }
     }
 
@@ -197,103 +216,263 @@ impl<P: HardSphereProperties, N: DualNum<f64>> FunctionalContributionDual<N>
     ) -> EosResult<Array1<N>> {
         let pure_component_weighted_densities = matches!(
             self.version,
-            FMTVersion::WhiteBear | FMTVersion::AntiSymWhiteBear
+            FMTVersion::WhiteBear | FMTVersion::AntiSymWhiteBear | FMTVersion::TensorWhiteBear
         ) && self.properties.component_index().len() == 1;
+        let radius = pure_component_weighted_densities
+            .then(|| self.properties.hs_diameter(temperature)[0] * 0.5);
+        fmt_helmholtz_energy_density(self.version, weighted_densities, radius)
+    }
+}
 
-        // scalar weighted densities
-        let (n2, n3) = if pure_component_weighted_densities {
-            (
-                weighted_densities.index_axis(Axis(0), 0),
-                weighted_densities.index_axis(Axis(0), 1),
-            )
-        } else {
-            (
-                weighted_densities.index_axis(Axis(0), 2),
-                weighted_densities.index_axis(Axis(0), 3),
-            )
-        };
+/// The Helmholtz energy density of FMT.
+///
+/// If the `radius` is given, the weighted densities are those of a pure
+/// component, i.e., $n_0$, $n_1$ and $\vec n_1$ are obtained from $n_2$ and
+/// $\vec n_2$. Otherwise, all weighted densities are provided explicitly.
+fn fmt_helmholtz_energy_density<N: DualNum<f64>>(
+    version: FMTVersion,
+    weighted_densities: ArrayView2<N>,
+    radius: Option<N>,
+) -> EosResult<Array1<N>> {
+    let pure_component_weighted_densities = radius.is_some();
 
-        let (n0, n1) = if pure_component_weighted_densities {
-            let r = self.properties.hs_diameter(temperature)[0] * 0.5;
-            (
-                n2.mapv(|n2| n2 / (r * r * 4.0 * PI)),
-                n2.mapv(|n2| n2 / (r * 4.0 * PI)),
-            )
-        } else {
-            (
-                weighted_densities.index_axis(Axis(0), 0).to_owned(),
-                weighted_densities.index_axis(Axis(0), 1).to_owned(),
-            )
-        };
+    // scalar weighted densities
+    let (n2, n3) = if pure_component_weighted_densities {
+        (
+            weighted_densities.index_axis(Axis(0), 0),
+            weighted_densities.index_axis(Axis(0), 1),
+        )
+    } else {
+        (
+            weighted_densities.index_axis(Axis(0), 2),
+            weighted_densities.index_axis(Axis(0), 3),
+        )
+    };
 
-        // vector weighted densities
-        let (n1n2, n2n2) = match self.version {
-            FMTVersion::WhiteBear | FMTVersion::AntiSymWhiteBear => {
-                let (n1v, n2v) = if pure_component_weighted_densities {
-                    let r = self.properties.hs_diameter(temperature)[0] * 0.5;
-                    let n2v = weighted_densities.slice_axis(Axis(0), Slice::new(2, None, 1));
-                    (n2v.mapv(|n2v| n2v / (r * 4.0 * PI)), n2v)
-                } else {
-                    let dim = ((weighted_densities.shape()[0] - 4) / 2) as isize;
-                    (
-                        weighted_densities
-                            .slice_axis(Axis(0), Slice::new(4, Some(4 + dim), 1))
-                            .to_owned(),
-                        weighted_densities
-                            .slice_axis(Axis(0), Slice::new(4 + dim, Some(4 + 2 * dim), 1)),
-                    )
-                };
-                match self.version {
-                    FMTVersion::WhiteBear => (
-                        &n1 * &n2 - (&n1v * &n2v).sum_axis(Axis(0)),
-                        &n2 * &n2 - (&n2v * &n2v).sum_axis(Axis(0)) * 3.0,
-                    ),
-                    FMTVersion::AntiSymWhiteBear => {
-                        let mut xi2 = (&n2v * &n2v).sum_axis(Axis(0)) / n2.map(|n| n.powi(2));
-                        xi2.iter_mut().for_each(|x| {
-                            if x.re() > 1.0 {
-                                *x = N::one()
-                            }
-                        });
-                        (
-                            &n1 * &n2 - (&n1v * &n2v).sum_axis(Axis(0)),
-                            &n2 * &n2 * xi2.mapv(|x| (-x + 1.0).powi(3)),
-                        )
+    let (n0, n1) = if let Some(r) = radius {
+        (
+            n2.mapv(|n2| n2 / (r * r * 4.0 * PI)),
+            n2.mapv(|n2| n2 / (r * 4.0 * PI)),
+        )
+    } else {
+        (
+            weighted_densities.index_axis(Axis(0), 0).to_owned(),
+            weighted_densities.index_axis(Axis(0), 1).to_owned(),
+        )
+    };
+
+    // vector weighted densities
+    let (n1n2, n2n22) = match version {
+        FMTVersion::WhiteBear | FMTVersion::AntiSymWhiteBear | FMTVersion::TensorWhiteBear => {
+            // the tensorial weighted density follows the scalar weighted densities
+            let tensor = usize::from(matches!(version, FMTVersion::TensorWhiteBear));
+            let n_scalar = if pure_component_weighted_densities {
+                2 + tensor
+            } else {
+                4 + tensor
+            };
+            let (n1v, n2v) = if let Some(r) = radius {
+                let n2v =
+                    weighted_densities.slice_axis(Axis(0), Slice::new(n_scalar as isize, None, 1));
+                (n2v.mapv(|n2v| n2v / (r * 4.0 * PI)), n2v)
+            } else {
+                let dim = (weighted_densities.shape()[0] - n_scalar) / 2;
+                (
+                    weighted_densities
+                        .slice_axis(Axis(0), Slice::from(n_scalar..n_scalar + dim))
+                        .to_owned(),
+                    weighted_densities
+                        .slice_axis(Axis(0), Slice::from(n_scalar + dim..n_scalar + 2 * dim)),
+                )
+            };
+            let n1n2 = &n1 * &n2 - (&n1v * &n2v).sum_axis(Axis(0));
+            let n2n2v = (&n2v * &n2v).sum_axis(Axis(0));
+            let n2n22 = match version {
+                FMTVersion::WhiteBear => &n2 * &(&n2 * &n2 - n2n2v * 3.0),
+                FMTVersion::AntiSymWhiteBear => {
+                    let mut xi2 = n2n2v / n2.map(|n| n.powi(2));
+                    xi2.iter_mut().for_each(|x| {
+                        if x.re() > 1.0 {
+                            *x = N::one()
+                        }
+                    });
+                    &n2 * &n2 * &n2 * xi2.mapv(|x| (-x + 1.0).powi(3))
+                }
+                FMTVersion::TensorWhiteBear => {
+                    if n2v.shape()[0] > 1 {
+                        return Err(EosError::Error(String::from(
+                                "The tensorial FMT functional is only available for one-dimensional systems.",
+                            )));
                     }
-                    _ => unreachable!(),
+                    let nt = weighted_densities.index_axis(Axis(0), n_scalar - 1);
+                    Zip::from(&n2)
+                        .and(&nt)
+                        .and(&n2n2v)
+                        .map_collect(|&n2, &nt, &n2n2v| tensor_n2n22(n2, nt, n2n2v))
                 }
-            }
-            FMTVersion::KierlikRosinberg => (&n1 * &n2, &n2 * &n2),
-        };
+                FMTVersion::KierlikRosinberg => unreachable!(),
+            };
+            (n1n2, n2n22)
+        }
+        FMTVersion::KierlikRosinberg => (&n1 * &n2, &n2 * &n2 * &n2),
+    };
 
-        // auxiliary variables
-        let ln31 = n3.mapv(|n3| (-n3).ln_1p());
-        let n3rec = n3.mapv(|n3| n3.recip());
-        let n3m1 = n3.mapv(|n3| -n3 + 1.0);
-        let n3m1rec = n3m1.mapv(|n3m1| n3m1.recip());
-
-        // use Taylor expansion for f3 at low densities to avoid numerical issues
-        let mut f3 = (&n3m1 * &n3m1 * &ln31 + n3) * &n3rec * n3rec * &n3m1rec * &n3m1rec;
-        f3.iter_mut().zip(n3).for_each(|(f3, &n3)| {
-            if n3.re() < N3_CUTOFF {
-                *f3 = (((n3 * 35.0 / 6.0 + 4.8) * n3 + 3.75) * n3 + 8.0 / 3.0) * n3 + 1.5;
-            }
-        });
-        Ok(-(&n0 * &ln31) + n1n2 * &n3m1rec + n2n2 * n2 * PI36M1 * f3)
+    // auxiliary variables
+    let ln31 = n3.mapv(|n3| (-n3).ln_1p());
+    let n3rec = n3.mapv(|n3| n3.recip());
+    let n3m1 = n3.mapv(|n3| -n3 + 1.0);
+    let n3m1rec = n3m1.mapv(|n3m1| n3m1.recip());
+
+    // use Taylor expansion for f3 at low densities to avoid numerical issues
+    let mut f3 = (&n3m1 * &n3m1 * &ln31 + n3) * &n3rec * n3rec * &n3m1rec * &n3m1rec;
+    f3.iter_mut().zip(n3).for_each(|(f3, &n3)| {
+        if n3.re() < N3_CUTOFF {
+            *f3 = (((n3 * 35.0 / 6.0 + 4.8) * n3 + 3.75) * n3 + 8.0 / 3.0) * n3 + 1.5;
+        }
+    });
+    Ok(-(&n0 * &ln31) + n1n2 * &n3m1rec + n2n22 * PI36M1 * f3)
+}
+
+/// Weight function of the diagonal elements $n_{T\perp}$ of the tensorial
+/// weighted density perpendicular to the direction of inhomogeneity.
+fn tensor_weight_function<N: DualNum<f64>>(c2: &Array1<N>, r: &Array1<N>) -> WeightFunction<N> {
+    WeightFunction {
+        prefactor: Zip::from(c2).and(r).map_collect(|&c, &r| c / r),
+        kernel_radius: r.clone(),
+        shape: WeightFunctionShape::Theta,
     }
 }
 
+/// The product $n_2n_{22}$ of the tensorial version for the planar tensor
+/// $\mathbf{n}_T=\mathrm{diag}\left(n_{T\perp},n_{T\perp},n_2-2n_{T\perp}\right)$.
+fn tensor_n2n22<N: DualNum<f64>>(n2: N, nt: N, n2n2v: N) -> N {
+    let nz = n2 - nt * 2.0;
+    ((nz - n2) * n2n2v + n2 * (nt * nt * 2.0 + nz * nz) - nt.powi(3) * 2.0 - nz.powi(3)) * 4.5
+}
+
 impl<P: HardSphereProperties> fmt::Display for FMTContribution<P> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         let ver = match self.version {
             FMTVersion::WhiteBear => "WB",
             FMTVersion::KierlikRosinberg => "KR",
             FMTVersion::AntiSymWhiteBear => "AntiSymWB",
+            FMTVersion::TensorWhiteBear => "TensorWB",
         };
         write!(f, "FMT functional ({})", ver)
     }
 }
 
+/// The [FunctionalContribution] for polydisperse hard spheres.
+///
+/// The fluid is described by a single density profile $\rho(\mathbf{r})$ whose
+/// diameters follow the [SizeDistribution]. The weighted densities are
+/// assembled by quadrature over the nodes of the distribution
+/// $$n_k(\mathbf{r})=\sum_qw_q\int\rho(\mathbf{r}\')\omega_k^{\sigma_q}(\mathbf{r}-\mathbf{r}\')\mathrm{d}\mathbf{r}\'$$
+/// with the weight functions of [FMTContribution] for spheres with diameter
+/// $\sigma_q$. The Helmholtz energy density is then given by the same
+/// expressions as in [FMTContribution]. The size distribution is the same at
+/// every point, i.e., fractionation is not considered.
+struct PolydisperseFMTContribution {
+    distribution: SizeDistribution,
+    version: FMTVersion,
+}
+
+impl PolydisperseFMTContribution {
+    /// The number of scalar and vector weighted densities of every node.
+    fn weighted_densities_per_node(&self) -> (usize, usize) {
+        match self.version {
+            FMTVersion::KierlikRosinberg => (4, 0),
+            FMTVersion::WhiteBear | FMTVersion::AntiSymWhiteBear => (4, 2),
+            FMTVersion::TensorWhiteBear => (5, 2),
+        }
+    }
+}
+
+impl<N: DualNum<f64>> FunctionalContributionDual<N> for PolydisperseFMTContribution {
+    fn weight_functions(&self, _: N) -> WeightFunctionInfo<N> {
+        let mut weight_functions = WeightFunctionInfo::new(arr1(&[0]), false);
+        for (&d, &w) in self
+            .distribution
+            .diameters
+            .iter()
+            .zip(&self.distribution.weights)
+        {
+            let r = d * 0.5;
+            let shapes_and_prefactors = match self.version {
+                FMTVersion::KierlikRosinberg => vec![
+                    (WeightFunctionShape::KR0, w),
+                    (WeightFunctionShape::KR1, w),
+                    (WeightFunctionShape::Delta, w),
+                    (WeightFunctionShape::Theta, w),
+                ],
+                _ => {
+                    let mut wf = vec![
+                        (WeightFunctionShape::Delta, w / (4.0 * PI * r * r)),
+                        (WeightFunctionShape::Delta, w / (4.0 * PI * r)),
+                        (WeightFunctionShape::Delta, w),
+                        (WeightFunctionShape::Theta, w),
+                    ];
+                    if let FMTVersion::TensorWhiteBear = self.version {
+                        wf.push((WeightFunctionShape::Theta, w / r));
+                    }
+                    wf.push((WeightFunctionShape::DeltaVec, w / (4.0 * PI * r)));
+                    wf.push((WeightFunctionShape::DeltaVec, w));
+                    wf
+                }
+            };
+            for (shape, prefactor) in shapes_and_prefactors {
+                weight_functions = weight_functions.add(
+                    WeightFunction {
+                        prefactor: arr1(&[N::from(prefactor)]),
+                        kernel_radius: arr1(&[N::from(r)]),
+                        shape,
+                    },
+                    true,
+                );
+            }
+        }
+        weight_functions
+    }
+
+    fn calculate_helmholtz_energy_density(
+        &self,
+        _: N,
+        weighted_densities: ArrayView2<N>,
+    ) -> EosResult<Array1<N>> {
+        // the scalar weighted densities of all nodes are followed by the
+        // vector weighted densities of all nodes
+        let nodes = self.distribution.len();
+        let (n_scalar, n_vector) = self.weighted_densities_per_node();
+        let scalar = n_scalar * nodes;
+        let dim = if n_vector > 0 {
+            (weighted_densities.shape()[0] - scalar) / (n_vector * nodes)
+        } else {
+            0
+        };
+        let mut n = Array::zeros([n_scalar + n_vector * dim, weighted_densities.shape()[1]]);
+        for q in 0..nodes {
+            let mut n_s = n.slice_mut(s![..n_scalar, ..]);
+            n_s += &weighted_densities.slice(s![q * n_scalar..(q + 1) * n_scalar, ..]);
+            let start = scalar + q * n_vector * dim;
+            let mut n_v = n.slice_mut(s![n_scalar.., ..]);
+            n_v += &weighted_densities.slice(s![start..start + n_vector * dim, ..]);
+        }
+        fmt_helmholtz_energy_density(self.version, n.view(), None)
+    }
+}
+
+impl fmt::Display for PolydisperseFMTContribution {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let ver = match self.version {
+            FMTVersion::WhiteBear => "WB",
+            FMTVersion::KierlikRosinberg => "KR",
+            FMTVersion::AntiSymWhiteBear => "AntiSymWB",
+            FMTVersion::TensorWhiteBear => "TensorWB",
+        };
+        write!(f, "Polydisperse FMT functional ({})", ver)
+    }
+}
+
 struct HardSphereParameters {
     sigma: Array1<f64>,
 }
@@ -309,26 +488,89 @@ impl HardSphereProperties for HardSphereParameters {
 }
 
 /// [HelmholtzEnergyFunctional] for hard sphere systems.
+///
+/// Polydisperse systems are described by a single component with a
+/// [SizeDistribution] of the diameters.
 pub struct FMTFunctional {
     properties: Arc<HardSphereParameters>,
     contributions: Vec<Box<dyn FunctionalContribution>>,
     version: FMTVersion,
+    distribution: Option<SizeDistribution>,
 }
 
 impl FMTFunctional {
     pub fn new(sigma: &Array1<f64>, version: FMTVersion) -> DFT<Self> {
+        Self::new_full(sigma, version, None)
+    }
+
+    /// FMT functional for a polydisperse hard-sphere fluid with the
+    /// given (discretized) size distribution.
+    ///
+    /// The functional has a single component. Its diameter, that is used,
+    /// e.g., for the pair potential and the maximum density, is the
+    /// diameter $\left(\sum_qw_q\sigma_q^3\right)^{1/3}$ of a sphere with the
+    /// mean volume of the distribution.
+    pub fn polydisperse(distribution: &SizeDistribution, version: FMTVersion) -> DFT<Self> {
+        let sigma = arr1(&[distribution.moment(3).cbrt()]);
+        Self::new_full(&sigma, version, Some(distribution.clone()))
+    }
+
+    fn new_full(
+        sigma: &Array1<f64>,
+        version: FMTVersion,
+        distribution: Option<SizeDistribution>,
+    ) -> DFT<Self> {
         let properties = Arc::new(HardSphereParameters {
             sigma: sigma.clone(),
         });
-        let contributions: Vec<Box<dyn FunctionalContribution>> =
-            vec![Box::new(FMTContribution::new(&properties, version))];
+        let contributions: Vec<Box<dyn FunctionalContribution>> = match &distribution {
+            Some(distribution) => vec![Box::new(PolydisperseFMTContribution {
+                distribution: distribution.clone(),
+                version,
+            })],
+            None => vec![Box::new(FMTContribution::new(&properties, version))],
+        };
         (Self {
             properties,
             contributions,
             version,
+            distribution,
         })
         .into()
     }
+
+    /// The size distribution of a polydisperse functional.
+    pub fn size_distribution(&self) -> Option<&SizeDistribution> {
+        self.distribution.as_ref()
+    }
+}
+
+// Auxiliary structure used to (de)serialize the Helmholtz energy functional.
+#[derive(Serialize, Deserialize)]
+struct FMTFunctionalJSON {
+    sigma: Array1<f64>,
+    version: FMTVersion,
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    distribution: Option<SizeDistribution>,
+}
+
+impl Serialize for FMTFunctional {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        FMTFunctionalJSON {
+            sigma: self.properties.sigma.clone(),
+            version: self.version,
+            distribution: self.distribution.clone(),
+        }
+        .serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for FMTFunctional {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let func = FMTFunctionalJSON::deserialize(deserializer)?;
+        Ok(Self::new_full(&func.sigma, func.version, func.distribution).into_inner())
+    }
 }
 
 impl HelmholtzEnergyFunctional for FMTFunctional {
@@ -337,6 +579,9 @@ impl HelmholtzEnergyFunctional for FMTFunctional {
     }
 
     fn subset(&self, component_list: &[usize]) -> DFT<Self> {
+        if let Some(distribution) = &self.distribution {
+            return Self::polydisperse(distribution, self.version);
+        }
         let sigma = component_list
             .iter()
             .map(|&c| self.properties.sigma[c])
@@ -375,3 +620,45 @@ impl FluidParameters for FMTFunctional {
         &self.properties.sigma
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_relative_eq;
+    use feos_core::{Contributions, State};
+    use quantity::si::{KELVIN, METER, MOL};
+
+    #[test]
+    fn polydisperse_bulk() -> EosResult<()> {
+        let distribution = SizeDistribution::gauss_legendre(|_| 1.0, 3.0, 4.0, 5)?;
+        for version in [
+            FMTVersion::WhiteBear,
+            FMTVersion::KierlikRosinberg,
+            FMTVersion::AntiSymWhiteBear,
+            FMTVersion::TensorWhiteBear,
+        ] {
+            let poly = Arc::new(FMTFunctional::polydisperse(&distribution, version));
+            let mix = Arc::new(FMTFunctional::new(&distribution.diameters, version));
+            let t = 300.0 * KELVIN;
+            let v = 5e-5 * METER.powi(3);
+            let n = arr1(&[1.0]) * MOL;
+            let state_poly = State::new_nvt(&poly, t, v, &n)?;
+            let state_mix = State::new_nvt(&mix, t, v, &(distribution.weights.clone() * n.get(0)))?;
+            assert_relative_eq!(
+                state_poly.pressure(Contributions::ResidualNvt),
+                state_mix.pressure(Contributions::ResidualNvt),
+                max_relative = 1e-12
+            );
+            let mu_poly = state_poly.chemical_potential(Contributions::ResidualNvt);
+            let mu_mix = state_mix.chemical_potential(Contributions::ResidualNvt);
+            assert_relative_eq!(
+                mu_poly.get(0),
+                (0..distribution.len())
+                    .map(|k| mu_mix.get(k) * distribution.weights[k])
+                    .fold(0.0 * mu_mix.get(0), |acc, mu| acc + mu),
+                max_relative = 1e-12
+            );
+        }
+        Ok(())
+    }
+}
@@ -9,8 +9,10 @@ use std::{borrow::Cow, sync::Arc};
 
 #[cfg(feature = "dft")]
 mod dft;
+mod polydisperse;
 #[cfg(feature = "dft")]
 pub use dft::{FMTContribution, FMTFunctional, FMTVersion};
+pub use polydisperse::SizeDistribution;
 
 /// Different monomer shapes for FMT and BMCSL.
 pub enum MonomerShape<'a, D> {
@@ -61,37 +63,50 @@ pub trait HardSphereProperties {
         partial_density: &Array1<D>,
         k: [i32; N],
     ) -> [D; N] {
-        let component_index = self.component_index();
-        let geometry_coefficients = self.geometry_coefficients(temperature);
-        let diameter = self.hs_diameter(temperature);
-        let mut zeta = [D::zero(); N];
-        for i in 0..diameter.len() {
-            for (z, &k) in zeta.iter_mut().zip(k.iter()) {
-                *z += partial_density[component_index[i]]
-                    * diameter[i].powi(k)
-                    * (geometry_coefficients[k as usize][i] * FRAC_PI_6);
-            }
-        }
-
-        zeta
+        packing_fractions(
+            &self.hs_diameter(temperature),
+            &self.geometry_coefficients(temperature),
+            &self.component_index(),
+            partial_density,
+            k,
+        )
     }
 
     /// The fraction $\frac{\zeta_2}{\zeta_3}$ evaluated in a way to avoid a division by 0 when the density is 0.
     fn zeta_23<D: DualNum<f64>>(&self, temperature: D, molefracs: &Array1<D>) -> D {
-        let component_index = self.component_index();
-        let geometry_coefficients = self.geometry_coefficients(temperature);
-        let diameter = self.hs_diameter(temperature);
-        let mut zeta: [D; 2] = [D::zero(); 2];
-        for i in 0..diameter.len() {
-            for (k, z) in zeta.iter_mut().enumerate() {
-                *z += molefracs[component_index[i]]
-                    * diameter[i].powi((k + 2) as i32)
-                    * (geometry_coefficients[k + 2][i] * FRAC_PI_6);
-            }
-        }
+        let [zeta2, zeta3] = packing_fractions(
+            &self.hs_diameter(temperature),
+            &self.geometry_coefficients(temperature),
+            &self.component_index(),
+            molefracs,
+            [2, 3],
+        );
+        zeta2 / zeta3
+    }
+}
 
-        zeta[0] / zeta[1]
+/// The packing fractions $\zeta_k$ for given segment diameters and geometry
+/// coefficients. Evaluated with mole fractions instead of partial densities,
+/// the result is $\zeta_k/\rho$.
+///
+/// The diameters and geometry coefficients are not restricted to real numbers
+/// so that the function can also be used with model parameters given as dual numbers.
+pub(crate) fn packing_fractions<D: DualNum<f64>, const N: usize>(
+    diameter: &Array1<D>,
+    geometry_coefficients: &[Array1<D>; 4],
+    component_index: &Array1<usize>,
+    density: &Array1<D>,
+    k: [i32; N],
+) -> [D; N] {
+    let mut zeta = [D::zero(); N];
+    for i in 0..diameter.len() {
+        for (z, &k) in zeta.iter_mut().zip(k.iter()) {
+            *z += density[component_index[i]]
+                * diameter[i].powi(k)
+                * (geometry_coefficients[k as usize][i] * FRAC_PI_6);
+        }
     }
+    zeta
 }
 
 /// Implementation of the BMCSL equation of state for hard-sphere mixtures.
@@ -120,15 +135,22 @@ impl<D: DualNum<f64>, P: HardSphereProperties> HelmholtzEnergyDual<D> for HardSp
     fn helmholtz_energy(&self, state: &StateHD<D>) -> D {
         let p = &self.parameters;
         let zeta = p.zeta(state.temperature, &state.partial_density, [0, 1, 2, 3]);
-        let frac_1mz3 = -(zeta[3] - 1.0).recip();
         let zeta_23 = p.zeta_23(state.temperature, &state.molefracs);
-        state.volume * 6.0 / std::f64::consts::PI
-            * (zeta[1] * zeta[2] * frac_1mz3 * 3.0
-                + zeta[2].powi(2) * frac_1mz3.powi(2) * zeta_23
-                + (zeta[2] * zeta_23.powi(2) - zeta[0]) * (zeta[3] * (-1.0)).ln_1p())
+        bmcsl_helmholtz_energy_density(zeta, zeta_23) * state.volume
     }
 }
 
+/// The reduced Helmholtz energy density $\frac{\beta A}{V}$ of the BMCSL
+/// equation of state for given packing fractions $\zeta_0\ldots\zeta_3$ and
+/// the fraction $\frac{\zeta_2}{\zeta_3}$.
+pub(crate) fn bmcsl_helmholtz_energy_density<D: DualNum<f64>>(zeta: [D; 4], zeta_23: D) -> D {
+    let frac_1mz3 = -(zeta[3] - 1.0).recip();
+    (zeta[1] * zeta[2] * frac_1mz3 * 3.0
+        + zeta[2].powi(2) * frac_1mz3.powi(2) * zeta_23
+        + (zeta[2] * zeta_23.powi(2) - zeta[0]) * (zeta[3] * (-1.0)).ln_1p())
+        * (6.0 / std::f64::consts::PI)
+}
+
 impl<P> fmt::Display for HardSphere<P> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         write!(f, "Hard Sphere")
@@ -0,0 +1,170 @@
+use feos_core::{EosError, EosResult};
+use ndarray::Array1;
+use serde::{Deserialize, Serialize};
+use std::f64::consts::PI;
+use std::fmt;
+
+const MAX_ITER_LEGENDRE: usize = 100;
+const TOL_LEGENDRE: f64 = 1e-14;
+
+/// Discretized size distribution of a polydisperse hard-sphere fluid.
+///
+/// A continuous distribution of diameters $p(\sigma)$ is represented by
+/// quadrature nodes $\sigma_q$ and weights $w_q$, so that moments of the
+/// distribution are approximated as
+/// $$\int\sigma^kp(\sigma)\mathrm{d}\sigma\approx\sum_qw_q\sigma_q^k.$$
+/// In `FMTFunctional::polydisperse`, the fluid is a single component and its
+/// weighted densities are assembled by quadrature over the diameters.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct SizeDistribution {
+    /// Quadrature nodes (diameters) in units of Angstrom
+    pub diameters: Array1<f64>,
+    /// Normalized quadrature weights
+    pub weights: Array1<f64>,
+}
+
+impl SizeDistribution {
+    /// Size distribution from given quadrature nodes and weights.
+    ///
+    /// The weights are normalized to 1.
+    pub fn new(diameters: Array1<f64>, weights: Array1<f64>) -> EosResult<Self> {
+        if diameters.len() != weights.len() {
+            return Err(EosError::IncompatibleComponents(
+                diameters.len(),
+                weights.len(),
+            ));
+        }
+        if diameters.is_empty() {
+            return Err(EosError::Error(String::from(
+                "The size distribution requires at least one node.",
+            )));
+        }
+        if diameters.iter().any(|&d| d <= 0.0) || weights.iter().any(|&w| w < 0.0) {
+            return Err(EosError::Error(String::from(
+                "The diameters have to be positive and the weights non-negative.",
+            )));
+        }
+        let total = weights.sum();
+        if total <= 0.0 {
+            return Err(EosError::Error(String::from(
+                "The weights of the size distribution sum to 0.",
+            )));
+        }
+        Ok(Self {
+            diameters,
+            weights: weights / total,
+        })
+    }
+
+    /// Discretize the (not necessarily normalized) probability density
+    /// `pdf` of the diameters between `sigma_min` and `sigma_max` with
+    /// an `n`-point Gauss-Legendre quadrature.
+    pub fn gauss_legendre<F: Fn(f64) -> f64>(
+        pdf: F,
+        sigma_min: f64,
+        sigma_max: f64,
+        n: usize,
+    ) -> EosResult<Self> {
+        if sigma_min >= sigma_max {
+            return Err(EosError::Error(format!(
+                "Invalid interval of diameters [{}, {}].",
+                sigma_min, sigma_max
+            )));
+        }
+        let (x, w) = gauss_legendre_nodes(n);
+        let diameters = x.mapv(|x| 0.5 * (sigma_max - sigma_min) * (x + 1.0) + sigma_min);
+        let weights = &w * &diameters.mapv(pdf) * (0.5 * (sigma_max - sigma_min));
+        Self::new(diameters, weights)
+    }
+
+    /// The number of nodes of the distribution.
+    pub fn len(&self) -> usize {
+        self.diameters.len()
+    }
+
+    /// Returns `true` if the distribution contains no nodes.
+    pub fn is_empty(&self) -> bool {
+        self.diameters.is_empty()
+    }
+
+    /// The $k$-th moment of the distribution $\sum_qw_q\sigma_q^k$.
+    pub fn moment(&self, k: i32) -> f64 {
+        (&self.weights * &self.diameters.mapv(|d| d.powi(k))).sum()
+    }
+
+    /// The mean diameter.
+    pub fn mean(&self) -> f64 {
+        self.moment(1)
+    }
+
+    /// The polydispersity, i.e., the standard deviation of the
+    /// diameters relative to the mean diameter.
+    pub fn polydispersity(&self) -> f64 {
+        let mean = self.mean();
+        (self.moment(2) - mean * mean).max(0.0).sqrt() / mean
+    }
+}
+
+impl fmt::Display for SizeDistribution {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "SizeDistribution(nodes={}, mean={}, polydispersity={})",
+            self.len(),
+            self.mean(),
+            self.polydispersity()
+        )
+    }
+}
+
+/// Nodes and weights of the Gauss-Legendre quadrature on $[-1,1]$.
+fn gauss_legendre_nodes(n: usize) -> (Array1<f64>, Array1<f64>) {
+    let mut x = Array1::zeros(n);
+    let mut w = Array1::zeros(n);
+    for i in 0..(n + 1) / 2 {
+        // initial guess and Newton iteration for the roots of P_n
+        let mut z = (PI * (i as f64 + 0.75) / (n as f64 + 0.5)).cos();
+        let mut dp = 1.0;
+        for _ in 0..MAX_ITER_LEGENDRE {
+            let (mut p0, mut p1) = (1.0, z);
+            for k in 2..=n {
+                let p2 = ((2 * k - 1) as f64 * z * p1 - (k - 1) as f64 * p0) / k as f64;
+                p0 = p1;
+                p1 = p2;
+            }
+            dp = n as f64 * (z * p1 - p0) / (z * z - 1.0);
+            let dz = p1 / dp;
+            z -= dz;
+            if dz.abs() < TOL_LEGENDRE {
+                break;
+            }
+        }
+        let wi = 2.0 / ((1.0 - z * z) * dp * dp);
+        x[i] = -z;
+        x[n - 1 - i] = z;
+        w[i] = wi;
+        w[n - 1 - i] = wi;
+    }
+    (x, w)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_relative_eq;
+
+    #[test]
+    fn gauss_legendre_moments() -> EosResult<()> {
+        // uniform distribution between 1 and 3
+        let dist = SizeDistribution::gauss_legendre(|_| 1.0, 1.0, 3.0, 5)?;
+        assert_relative_eq!(dist.weights.sum(), 1.0, max_relative = 1e-14);
+        assert_relative_eq!(dist.mean(), 2.0, max_relative = 1e-14);
+        assert_relative_eq!(dist.moment(3), 10.0, max_relative = 1e-14);
+        assert_relative_eq!(
+            dist.polydispersity(),
+            1.0 / 3f64.sqrt() / 2.0,
+            max_relative = 1e-12
+        );
+        Ok(())
+    }
+}
@@ -0,0 +1,232 @@
+use super::eos::{LennardJonesOptions, TholCoefficients};
+use super::parameters::{LennardJonesBinaryRecord, LennardJonesParameters, LennardJonesRecord};
+use crate::hard_sphere::{FMTContribution, FMTVersion};
+use feos_core::ideal_gas::{IdealGasModel, WithIdealGas};
+use feos_core::joback::JobackRecord;
+use feos_core::parameter::{Parameter, ParameterSet};
+use feos_core::{EosResult, IdealGasContribution, MolarWeight};
+use feos_dft::adsorption::FluidParameters;
+use feos_dft::solvation::PairPotential;
+use feos_dft::{FunctionalContribution, HelmholtzEnergyFunctional, MoleculeShape, DFT};
+use ndarray::{Array1, Array2};
+use quantity::si::*;
+use residual::ResidualFunctional;
+use serde::de::Error;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use std::f64::consts::FRAC_PI_6;
+use std::sync::Arc;
+
+mod residual;
+
+/// Lennard-Jones Helmholtz energy functional.
+///
+/// The functional consists of a hard-sphere (FMT) contribution and a
+/// mean-field contribution that is evaluated with weighted densities
+/// and accounts for the difference between the reference equation of
+/// state and the hard-sphere fluid. The functional is only consistent
+/// with the equation of state in the bulk for the White Bear versions of FMT.
+pub struct LennardJonesFunctional {
+    /// Lennard-Jones parameters of all substances in the system
+    pub parameters: Arc<LennardJonesParameters>,
+    coefficients: Arc<TholCoefficients>,
+    fmt_version: FMTVersion,
+    options: LennardJonesOptions,
+    contributions: Vec<Box<dyn FunctionalContribution>>,
+    ideal_gas: IdealGasModel,
+}
+
+impl LennardJonesFunctional {
+    /// Functional of the full Lennard-Jones fluid with default options.
+    ///
+    /// # Defaults
+    /// `FMTVersion`: `FMTVersion::WhiteBear`
+    pub fn new(parameters: Arc<LennardJonesParameters>) -> DFT<Self> {
+        Self::with_options(
+            parameters,
+            Arc::new(TholCoefficients::lennard_jones()),
+            FMTVersion::WhiteBear,
+            LennardJonesOptions::default(),
+        )
+    }
+
+    /// Functional with the provided coefficients, FMT version and options.
+    pub fn with_options(
+        parameters: Arc<LennardJonesParameters>,
+        coefficients: Arc<TholCoefficients>,
+        fmt_version: FMTVersion,
+        options: LennardJonesOptions,
+    ) -> DFT<Self> {
+        let parameters =
+            LennardJonesParameters::apply_combining_rule(parameters, options.combining_rule);
+        let contributions: Vec<Box<dyn FunctionalContribution>> = vec![
+            Box::new(FMTContribution::new(&parameters, fmt_version)),
+            Box::new(ResidualFunctional::new(
+                parameters.clone(),
+                coefficients.clone(),
+            )),
+        ];
+
+        let ideal_gas = parameters.joback_records.clone().map_or_else(
+            || IdealGasModel::default(parameters.sigma.len()),
+            IdealGasModel::joback,
+        );
+
+        Self {
+            parameters,
+            coefficients,
+            fmt_version,
+            options,
+            contributions,
+            ideal_gas,
+        }
+        .into()
+    }
+}
+
+impl HelmholtzEnergyFunctional for LennardJonesFunctional {
+    fn subset(&self, component_list: &[usize]) -> DFT<Self> {
+        Self::with_options(
+            Arc::new(self.parameters.subset(component_list)),
+            self.coefficients.clone(),
+            self.fmt_version,
+            self.options,
+        )
+        .with_ideal_gas(self.ideal_gas.subset(component_list))
+        .unwrap()
+    }
+
+    fn molecule_shape(&self) -> MoleculeShape {
+        MoleculeShape::Spherical(self.parameters.sigma.len())
+    }
+
+    fn compute_max_density(&self, moles: &Array1<f64>) -> f64 {
+        self.options.max_density.apply(
+            self.options.max_eta * moles.sum()
+                / (FRAC_PI_6 * self.parameters.sigma.mapv(|v| v.powi(3)) * moles).sum(),
+        )
+    }
+
+    fn contributions(&self) -> &[Box<dyn FunctionalContribution>] {
+        &self.contributions
+    }
+
+    fn ideal_gas(&self) -> &dyn IdealGasContribution {
+        &self.ideal_gas
+    }
+}
+
+impl WithIdealGas for LennardJonesFunctional {
+    fn with_ideal_gas(mut self, ideal_gas: IdealGasModel) -> EosResult<Self> {
+        ideal_gas.validate_components(self.parameters.sigma.len())?;
+        self.ideal_gas = ideal_gas;
+        Ok(self)
+    }
+}
+
+// Auxiliary structure used to (de)serialize the Helmholtz energy functional.
+#[derive(Serialize, Deserialize)]
+struct LennardJonesFunctionalJSON {
+    parameters: ParameterSet<LennardJonesRecord, JobackRecord, LennardJonesBinaryRecord>,
+    coefficients: TholCoefficients,
+    fmt_version: FMTVersion,
+    options: LennardJonesOptions,
+    ideal_gas: IdealGasModel,
+}
+
+impl Serialize for LennardJonesFunctional {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        LennardJonesFunctionalJSON {
+            parameters: self.parameters.parameter_set(),
+            coefficients: self.coefficients.as_ref().clone(),
+            fmt_version: self.fmt_version,
+            options: self.options,
+            ideal_gas: self.ideal_gas.clone(),
+        }
+        .serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for LennardJonesFunctional {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let func = LennardJonesFunctionalJSON::deserialize(deserializer)?;
+        let parameters = LennardJonesParameters::from_parameter_set(func.parameters)
+            .map_err(D::Error::custom)?;
+        Self::with_options(
+            Arc::new(parameters),
+            Arc::new(func.coefficients),
+            func.fmt_version,
+            func.options,
+        )
+        .into_inner()
+        .with_ideal_gas(func.ideal_gas)
+        .map_err(D::Error::custom)
+    }
+}
+
+impl MolarWeight for LennardJonesFunctional {
+    fn molar_weight(&self) -> SIArray1 {
+        self.parameters.molarweight.clone() * GRAM / MOL
+    }
+}
+
+impl FluidParameters for LennardJonesFunctional {
+    fn epsilon_k_ff(&self) -> Array1<f64> {
+        self.parameters.epsilon_k.clone()
+    }
+
+    fn sigma_ff(&self) -> &Array1<f64> {
+        &self.parameters.sigma
+    }
+}
+
+/// The pair potential is truncated and shifted if the coefficients
+/// specify a cut-off radius.
+impl PairPotential for LennardJonesFunctional {
+    fn pair_potential(&self, i: usize, r: &Array1<f64>, _: f64) -> Array2<f64> {
+        let p = &self.parameters;
+        let rc = self.coefficients.cutoff_radius;
+        let shift = rc.map_or(0.0, |rc| rc.powi(-12) - rc.powi(-6));
+        Array2::from_shape_fn((p.sigma.len(), r.len()), |(j, k)| {
+            let sigma_ij = p.sigma_ij[[i, j]];
+            if rc.map_or(false, |rc| r[k] > rc * sigma_ij) {
+                0.0
+            } else {
+                let att = (sigma_ij / r[k]).powi(6);
+                4.0 * p.epsilon_k_ij[[i, j]] * (att * (att - 1.0) - shift)
+            }
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::lennard_jones::parameters::utils::argon_krypton_parameters;
+    use crate::lennard_jones::LennardJones;
+    use approx::assert_relative_eq;
+    use feos_core::{Contributions, State};
+    use ndarray::arr1;
+
+    #[test]
+    fn bulk_consistency() -> EosResult<()> {
+        let parameters = argon_krypton_parameters();
+        let eos = Arc::new(LennardJones::new(parameters.clone()));
+        let func = Arc::new(LennardJonesFunctional::new(parameters));
+        let t = 150.0 * KELVIN;
+        let v = 1e-4 * METER.powi(3);
+        let n = arr1(&[1.0, 2.0]) * MOL;
+        let s_eos = State::new_nvt(&eos, t, v, &n)?;
+        let s_func = State::new_nvt(&func, t, v, &n)?;
+        assert_relative_eq!(
+            s_eos.pressure(Contributions::Total),
+            s_func.pressure(Contributions::Total),
+            max_relative = 1e-10
+        );
+        assert_relative_eq!(
+            s_eos.molar_helmholtz_energy(Contributions::ResidualNvt),
+            s_func.molar_helmholtz_energy(Contributions::ResidualNvt),
+            max_relative = 1e-10
+        );
+        Ok(())
+    }
+}
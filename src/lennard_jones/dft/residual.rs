@@ -0,0 +1,100 @@
+use crate::hard_sphere::HardSphereProperties;
+use crate::lennard_jones::eos::thol::{helmholtz_energy_density, TholCoefficients};
+use crate::lennard_jones::parameters::LennardJonesParameters;
+use feos_core::EosError;
+use feos_dft::{
+    FunctionalContributionDual, WeightFunction, WeightFunctionInfo, WeightFunctionShape,
+};
+use ndarray::*;
+use num_dual::DualNum;
+use std::f64::consts::PI;
+use std::fmt;
+use std::sync::Arc;
+
+/// psi Parameter for DFT (not adjusted, taken from PeTS)
+const PSI_DFT: f64 = 1.21;
+/// psi Parameter for pDGT (not adjusted, yet)
+const PSI_PDGT: f64 = 1.21;
+
+/// Mean-field contribution of the Lennard-Jones fluid beyond the hard-sphere reference.
+///
+/// The residual Helmholtz energy density of the equation of state minus the
+/// hard-sphere (BMCSL) contribution is evaluated at weighted densities that are
+/// averaged over spheres with the radius $\psi d_i$. In the bulk, the functional
+/// together with a White Bear FMT contribution reduces to the equation of state.
+#[derive(Clone)]
+pub struct ResidualFunctional {
+    parameters: Arc<LennardJonesParameters>,
+    coefficients: Arc<TholCoefficients>,
+}
+
+impl ResidualFunctional {
+    pub fn new(
+        parameters: Arc<LennardJonesParameters>,
+        coefficients: Arc<TholCoefficients>,
+    ) -> Self {
+        Self {
+            parameters,
+            coefficients,
+        }
+    }
+}
+
+fn weight_functions<N: DualNum<f64> + ScalarOperand>(
+    p: &LennardJonesParameters,
+    psi: f64,
+    temperature: N,
+) -> WeightFunctionInfo<N> {
+    let d = p.hs_diameter(temperature);
+    WeightFunctionInfo::new(Array1::from_shape_fn(d.len(), |i| i), false).add(
+        WeightFunction::new_scaled(d * psi, WeightFunctionShape::Theta),
+        false,
+    )
+}
+
+impl<N: DualNum<f64> + ScalarOperand> FunctionalContributionDual<N> for ResidualFunctional {
+    fn weight_functions(&self, temperature: N) -> WeightFunctionInfo<N> {
+        weight_functions(&self.parameters, PSI_DFT, temperature)
+    }
+
+    fn weight_functions_pdgt(&self, temperature: N) -> WeightFunctionInfo<N> {
+        weight_functions(&self.parameters, PSI_PDGT, temperature)
+    }
+
+    fn calculate_helmholtz_energy_density(
+        &self,
+        temperature: N,
+        density: ArrayView2<N>,
+    ) -> Result<Array1<N>, EosError> {
+        let p = &self.parameters;
+        Ok(Array1::from_shape_fn(density.shape()[1], |k| {
+            let rho = density.column(k).to_owned();
+            let rho_total = rho.sum();
+            let a_lj = if rho_total.re() == 0.0 {
+                N::zero()
+            } else {
+                let x = rho.mapv(|r| r / rho_total);
+                helmholtz_energy_density(p, &self.coefficients, temperature, rho_total, x.view())
+            };
+
+            // hard-sphere contribution (BMCSL) at the same weighted densities
+            let [zeta0, zeta1, zeta2, zeta3] = p.zeta(temperature, &rho, [0, 1, 2, 3]);
+            if zeta3.re() == 0.0 {
+                return a_lj;
+            }
+            let frac_1mz3 = -(zeta3 - 1.0).recip();
+            let zeta_23 = zeta2 / zeta3;
+            let a_hs = (zeta1 * zeta2 * frac_1mz3 * 3.0
+                + zeta2.powi(2) * frac_1mz3.powi(2) * zeta_23
+                + (zeta2 * zeta_23.powi(2) - zeta0) * (-zeta3).ln_1p())
+                * (6.0 / PI);
+            a_lj - a_hs
+        }))
+    }
+}
+
+impl fmt::Display for ResidualFunctional {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Lennard-Jones residual functional")
+    }
+}
@@ -0,0 +1,338 @@
+use super::parameters::{LennardJonesBinaryRecord, LennardJonesParameters, LennardJonesRecord};
+use feos_core::ideal_gas::{IdealGasModel, WithIdealGas};
+use feos_core::joback::JobackRecord;
+use feos_core::parameter::{CombiningRule, Parameter, ParameterSet};
+use feos_core::{
+    EosResult, EquationOfState, HelmholtzEnergy, IdealGasContribution, MaxDensity, MolarWeight,
+};
+use ndarray::Array1;
+use quantity::si::*;
+use serde::de::Error;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use std::f64::consts::FRAC_PI_6;
+use std::sync::Arc;
+
+pub(crate) mod thol;
+use thol::Thol;
+pub use thol::{ExponentialTerm, GaussianTerm, PolynomialTerm, TholCoefficients};
+
+/// Configuration options for the Lennard-Jones equation of state and Helmholtz energy functional.
+///
+/// The maximum packing fraction is used to infer initial values
+/// for routines that depend on starting values for the system density.
+#[derive(Copy, Clone, Serialize, Deserialize)]
+pub struct LennardJonesOptions {
+    /// maximum packing fraction
+    pub max_eta: f64,
+    /// strategy for the calculation of the maximum density
+    pub max_density: MaxDensity,
+    /// combining rule for the cross diameters and energy parameters
+    #[serde(default)]
+    pub combining_rule: CombiningRule,
+}
+
+impl Default for LennardJonesOptions {
+    fn default() -> Self {
+        Self {
+            max_eta: 0.5,
+            max_density: MaxDensity::Heuristic,
+            combining_rule: CombiningRule::LorentzBerthelot,
+        }
+    }
+}
+
+/// Lennard-Jones reference equation of state.
+pub struct LennardJones {
+    parameters: Arc<LennardJonesParameters>,
+    coefficients: Arc<TholCoefficients>,
+    options: LennardJonesOptions,
+    contributions: Vec<Box<dyn HelmholtzEnergy>>,
+    ideal_gas: IdealGasModel,
+}
+
+impl LennardJones {
+    /// Equation of state of the full Lennard-Jones fluid with default options.
+    pub fn new(parameters: Arc<LennardJonesParameters>) -> Self {
+        Self::with_options(
+            parameters,
+            Arc::new(TholCoefficients::lennard_jones()),
+            LennardJonesOptions::default(),
+        )
+    }
+
+    /// Equation of state with the provided coefficients and options.
+    pub fn with_options(
+        parameters: Arc<LennardJonesParameters>,
+        coefficients: Arc<TholCoefficients>,
+        options: LennardJonesOptions,
+    ) -> Self {
+        let parameters =
+            LennardJonesParameters::apply_combining_rule(parameters, options.combining_rule);
+        let contributions: Vec<Box<dyn HelmholtzEnergy>> = vec![Box::new(Thol {
+            parameters: parameters.clone(),
+            coefficients: coefficients.clone(),
+        })];
+
+        let ideal_gas = parameters.joback_records.clone().map_or_else(
+            || IdealGasModel::default(parameters.sigma.len()),
+            IdealGasModel::joback,
+        );
+
+        Self {
+            parameters,
+            coefficients,
+            options,
+            contributions,
+            ideal_gas,
+        }
+    }
+}
+
+impl EquationOfState for LennardJones {
+    fn components(&self) -> usize {
+        self.parameters.pure_records.len()
+    }
+
+    fn subset(&self, component_list: &[usize]) -> Self {
+        Self {
+            ideal_gas: self.ideal_gas.subset(component_list),
+            ..Self::with_options(
+                Arc::new(self.parameters.subset(component_list)),
+                self.coefficients.clone(),
+                self.options,
+            )
+        }
+    }
+
+    fn compute_max_density(&self, moles: &Array1<f64>) -> f64 {
+        self.options.max_density.apply(
+            self.options.max_eta * moles.sum()
+                / (FRAC_PI_6 * self.parameters.sigma.mapv(|v| v.powi(3)) * moles).sum(),
+        )
+    }
+
+    fn residual(&self) -> &[Box<dyn HelmholtzEnergy>] {
+        &self.contributions
+    }
+
+    fn ideal_gas(&self) -> &dyn IdealGasContribution {
+        &self.ideal_gas
+    }
+}
+
+impl WithIdealGas for LennardJones {
+    fn with_ideal_gas(mut self, ideal_gas: IdealGasModel) -> EosResult<Self> {
+        ideal_gas.validate_components(self.components())?;
+        self.ideal_gas = ideal_gas;
+        Ok(self)
+    }
+}
+
+// Auxiliary structure used to (de)serialize the equation of state.
+#[derive(Serialize, Deserialize)]
+struct LennardJonesJSON {
+    parameters: ParameterSet<LennardJonesRecord, JobackRecord, LennardJonesBinaryRecord>,
+    coefficients: TholCoefficients,
+    options: LennardJonesOptions,
+    ideal_gas: IdealGasModel,
+}
+
+impl Serialize for LennardJones {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        LennardJonesJSON {
+            parameters: self.parameters.parameter_set(),
+            coefficients: self.coefficients.as_ref().clone(),
+            options: self.options,
+            ideal_gas: self.ideal_gas.clone(),
+        }
+        .serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for LennardJones {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let eos = LennardJonesJSON::deserialize(deserializer)?;
+        let parameters =
+            LennardJonesParameters::from_parameter_set(eos.parameters).map_err(D::Error::custom)?;
+        Self::with_options(
+            Arc::new(parameters),
+            Arc::new(eos.coefficients),
+            eos.options,
+        )
+        .with_ideal_gas(eos.ideal_gas)
+        .map_err(D::Error::custom)
+    }
+}
+
+impl MolarWeight for LennardJones {
+    fn molar_weight(&self) -> SIArray1 {
+        self.parameters.molarweight.clone() * GRAM / MOL
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::lennard_jones::parameters::utils::{argon_krypton_parameters, argon_parameters};
+    use approx::assert_relative_eq;
+    use feos_core::{Contributions, PhaseEquilibrium, ReducedUnits, State};
+    use ndarray::arr1;
+    use std::f64::consts::PI;
+
+    #[test]
+    fn critical_point() -> EosResult<()> {
+        let parameters = argon_parameters();
+        let units = ReducedUnits::new(
+            parameters.sigma[0] * ANGSTROM,
+            parameters.epsilon_k[0] * KELVIN,
+        );
+        let eos = Arc::new(LennardJones::new(parameters));
+        let cp = State::critical_point(&eos, None, None, Default::default())?;
+        assert_relative_eq!(
+            cp.temperature.to_reduced(units.temperature())?,
+            1.3203755,
+            max_relative = 1e-6
+        );
+        assert_relative_eq!(
+            cp.density.to_reduced(units.density())?,
+            0.3142894,
+            max_relative = 1e-5
+        );
+        assert_relative_eq!(
+            cp.pressure(Contributions::Total)
+                .to_reduced(units.pressure())?,
+            0.1302218,
+            max_relative = 1e-5
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn vapor_liquid_equilibrium() -> EosResult<()> {
+        let parameters = argon_parameters();
+        let units = ReducedUnits::new(
+            parameters.sigma[0] * ANGSTROM,
+            parameters.epsilon_k[0] * KELVIN,
+        );
+        let eos = Arc::new(LennardJones::new(parameters));
+        let vle =
+            PhaseEquilibrium::pure(&eos, 1.0 * units.temperature(), None, Default::default())?;
+        assert_relative_eq!(
+            vle.liquid().density.to_reduced(units.density())?,
+            0.7018976,
+            max_relative = 1e-6
+        );
+        assert_relative_eq!(
+            vle.vapor().density.to_reduced(units.density())?,
+            0.0294304,
+            max_relative = 1e-5
+        );
+        assert_relative_eq!(
+            vle.vapor()
+                .pressure(Contributions::Total)
+                .to_reduced(units.pressure())?,
+            0.0248702,
+            max_relative = 1e-5
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn second_virial_coefficient() -> EosResult<()> {
+        let parameters = argon_parameters();
+        let units = ReducedUnits::new(
+            parameters.sigma[0] * ANGSTROM,
+            parameters.epsilon_k[0] * KELVIN,
+        );
+        let e1 = LennardJones::new(parameters);
+        let e12 = LennardJones::new(argon_krypton_parameters());
+
+        // B* = -2pi int (exp(-u*/T*) - 1) r*^2 dr* from the Mayer function of the
+        // pair potential, the tail beyond r_max is integrated with exp(-u*/T*) - 1 = -u*/T*
+        let mayer = |t: f64| {
+            let (r_max, n) = (20.0, 200_000);
+            let dr = r_max / n as f64;
+            let integral: f64 = (0..n)
+                .map(|i| {
+                    let r = (i as f64 + 0.5) * dr;
+                    (-4.0 * (r.powi(-12) - r.powi(-6)) / t).exp_m1() * r * r * dr
+                })
+                .sum();
+            -2.0 * PI * (integral + 4.0 / (3.0 * t * r_max.powi(3)))
+        };
+        for t in [1.0, 2.0, 6.0] {
+            let b = e1.second_virial_coefficient(t * units.temperature(), None)?;
+            assert_relative_eq!(
+                (b * units.density()).into_value()?,
+                mayer(t),
+                max_relative = 2e-2
+            );
+
+            // the virial coefficient of a mixture is evaluated at the given mole fractions
+            let n = arr1(&[1.0, 0.0]) * MOL;
+            let b12 = e12.second_virial_coefficient(t * units.temperature(), Some(&n))?;
+            assert_relative_eq!(b12, b, max_relative = 1e-12);
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn mix_single() -> EosResult<()> {
+        let e1 = Arc::new(LennardJones::new(argon_parameters()));
+        let e12 = Arc::new(LennardJones::new(argon_krypton_parameters()));
+        let t = 150.0 * KELVIN;
+        let v = 1e-4 * METER.powi(3);
+        let s1 = State::new_nvt(&e1, t, v, &(arr1(&[2.0]) * MOL))?;
+        let s1m = State::new_nvt(&e12, t, v, &(arr1(&[2.0, 0.0]) * MOL))?;
+        assert_relative_eq!(
+            s1.pressure(Contributions::Total),
+            s1m.pressure(Contributions::Total),
+            max_relative = 1e-12
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn json_round_trip() -> EosResult<()> {
+        let eos = LennardJones::new(argon_krypton_parameters());
+        let json = serde_json::to_string(&eos).unwrap();
+        let eos2: LennardJones = serde_json::from_str(&json).unwrap();
+        let t = 150.0 * KELVIN;
+        let v = 1e-4 * METER.powi(3);
+        let n = arr1(&[1.0, 2.0]) * MOL;
+        let s = State::new_nvt(&Arc::new(eos), t, v, &n)?;
+        let s2 = State::new_nvt(&Arc::new(eos2), t, v, &n)?;
+        assert_relative_eq!(
+            s.pressure(Contributions::Total),
+            s2.pressure(Contributions::Total),
+            max_relative = 1e-14
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn coefficients_from_json() -> EosResult<()> {
+        let coefficients = TholCoefficients {
+            cutoff_radius: Some(2.5),
+            ..TholCoefficients::lennard_jones()
+        };
+        let path = std::env::temp_dir().join("feos_thol_coefficients.json");
+        std::fs::write(&path, serde_json::to_string(&coefficients).unwrap()).unwrap();
+        let coefficients = TholCoefficients::from_json(&path)?;
+        std::fs::remove_file(&path).unwrap();
+        assert_eq!(coefficients.cutoff_radius, Some(2.5));
+
+        let parameters = argon_parameters();
+        let e1 = Arc::new(LennardJones::new(parameters.clone()));
+        let e2 = Arc::new(LennardJones::with_options(
+            parameters,
+            Arc::new(coefficients),
+            Default::default(),
+        ));
+        let cp1 = State::critical_point(&e1, None, None, Default::default())?;
+        let cp2 = State::critical_point(&e2, None, None, Default::default())?;
+        assert_relative_eq!(cp1.temperature, cp2.temperature, max_relative = 1e-12);
+        assert_relative_eq!(cp1.density, cp2.density, max_relative = 1e-12);
+        Ok(())
+    }
+}
@@ -0,0 +1,220 @@
+use crate::lennard_jones::parameters::LennardJonesParameters;
+use feos_core::parameter::ParameterError;
+use feos_core::{HelmholtzEnergyDual, StateHD};
+use ndarray::ArrayView1;
+use num_dual::DualNum;
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::fs::File;
+use std::io::BufReader;
+use std::path::Path;
+use std::sync::Arc;
+
+/// Polynomial term $n\tau^t\delta^d$.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy)]
+pub struct PolynomialTerm {
+    pub n: f64,
+    pub t: f64,
+    pub d: i32,
+}
+
+/// Exponential term $n\tau^t\delta^d\exp\left(-\delta^l\right)$.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy)]
+pub struct ExponentialTerm {
+    pub n: f64,
+    pub t: f64,
+    pub d: i32,
+    pub l: i32,
+}
+
+/// Gaussian bell-shaped term
+/// $n\tau^t\delta^d\exp\left(-\eta(\delta-\varepsilon)^2-\beta(\tau-\gamma)^2\right)$.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy)]
+pub struct GaussianTerm {
+    pub n: f64,
+    pub t: f64,
+    pub d: i32,
+    pub eta: f64,
+    pub beta: f64,
+    pub gamma: f64,
+    pub epsilon: f64,
+}
+
+/// Coefficients of an empirical equation of state for a Lennard-Jones
+/// model fluid in the functional form of [Thol et al. (2016)](https://doi.org/10.1063/1.4945000).
+///
+/// The reduced residual Helmholtz energy
+/// $$\alpha^\mathrm{r}(\tau,\delta)=\sum_i n_i\tau^{t_i}\delta^{d_i}+\sum_i n_i\tau^{t_i}\delta^{d_i}e^{-\delta^{l_i}}+\sum_i n_i\tau^{t_i}\delta^{d_i}e^{-\eta_i(\delta-\varepsilon_i)^2-\beta_i(\tau-\gamma_i)^2}$$
+/// is a function of the inverse reduced temperature $\tau=\frac{T_\mathrm{c}^*}{T^*}$
+/// and the reduced density $\delta=\frac{\rho^*}{\rho_\mathrm{c}^*}$ with the
+/// reducing parameters $T_\mathrm{c}^*$ and $\rho_\mathrm{c}^*$ in Lennard-Jones units.
+///
+/// Only the coefficients of the full Lennard-Jones fluid
+/// ([TholCoefficients::lennard_jones]) are provided. Coefficients of the
+/// truncated and shifted Lennard-Jones fluid are not included in this crate and
+/// have to be taken from the literature and read from a JSON file with
+/// [TholCoefficients::from_json], together with the corresponding `cutoff_radius`.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct TholCoefficients {
+    /// reducing temperature $T_\mathrm{c}^*$
+    pub reducing_temperature: f64,
+    /// reducing density $\rho_\mathrm{c}^*$
+    pub reducing_density: f64,
+    /// cut-off radius of the truncated and shifted potential in units of $\sigma$
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cutoff_radius: Option<f64>,
+    pub polynomial: Vec<PolynomialTerm>,
+    pub exponential: Vec<ExponentialTerm>,
+    pub gaussian: Vec<GaussianTerm>,
+}
+
+impl Default for TholCoefficients {
+    fn default() -> Self {
+        Self::lennard_jones()
+    }
+}
+
+impl TholCoefficients {
+    /// Coefficients of the full (untruncated) Lennard-Jones fluid
+    /// by [Thol et al. (2016)](https://doi.org/10.1063/1.4945000).
+    pub fn lennard_jones() -> Self {
+        let polynomial = [
+            (0.005208073, 1.000, 4),
+            (2.186252000, 0.320, 1),
+            (-2.161016000, 0.505, 1),
+            (1.452700000, 0.672, 2),
+            (-2.041792000, 0.843, 2),
+            (0.186952860, 0.898, 3),
+        ];
+        let exponential = [
+            (-0.090988445, 1.294, 5, 1),
+            (-0.497456100, 2.590, 2, 2),
+            (0.109014310, 1.786, 2, 1),
+            (-0.800559220, 2.770, 3, 2),
+            (-0.568839000, 1.786, 2, 2),
+            (-0.620862500, 1.205, 1, 1),
+        ];
+        let gaussian = [
+            (-1.466717700, 2.830, 1, 2.067, 0.625, 0.710, 0.2053),
+            (1.891469000, 2.548, 1, 1.522, 0.638, 0.860, 0.4090),
+            (-0.138370100, 4.650, 2, 8.820, 3.910, 1.940, 0.6000),
+            (-0.386964500, 1.385, 3, 1.722, 0.156, 1.480, 1.2030),
+            (0.126570200, 1.460, 3, 0.679, 0.157, 1.490, 1.8290),
+            (0.605781000, 1.351, 2, 1.883, 0.153, 1.945, 1.3970),
+            (1.179189000, 0.660, 1, 3.925, 1.160, 3.020, 1.3900),
+            (-0.477326790, 1.496, 2, 2.461, 1.730, 1.110, 0.5390),
+            (-9.921857500, 1.830, 3, 28.200, 383.000, 1.170, 0.9340),
+            (-0.574793200, 1.616, 1, 0.753, 0.112, 1.330, 2.3690),
+            (0.003772923, 4.970, 1, 0.820, 0.119, 0.240, 2.4300),
+        ];
+        Self {
+            reducing_temperature: 1.32,
+            reducing_density: 0.31,
+            cutoff_radius: None,
+            polynomial: polynomial
+                .iter()
+                .map(|&(n, t, d)| PolynomialTerm { n, t, d })
+                .collect(),
+            exponential: exponential
+                .iter()
+                .map(|&(n, t, d, l)| ExponentialTerm { n, t, d, l })
+                .collect(),
+            gaussian: gaussian
+                .iter()
+                .map(|&(n, t, d, eta, beta, gamma, epsilon)| GaussianTerm {
+                    n,
+                    t,
+                    d,
+                    eta,
+                    beta,
+                    gamma,
+                    epsilon,
+                })
+                .collect(),
+        }
+    }
+
+    /// Read coefficients from a JSON file.
+    pub fn from_json<P: AsRef<Path>>(file: P) -> Result<Self, ParameterError> {
+        let reader = BufReader::new(File::open(file)?);
+        Ok(serde_json::from_reader(reader)?)
+    }
+
+    /// The reduced residual Helmholtz energy per particle $\alpha^\mathrm{r}$
+    /// for the given reduced temperature $T^*$ and reduced density $\rho^*$.
+    pub fn alpha_r<D: DualNum<f64>>(&self, temperature: D, density: D) -> D {
+        let tau = temperature.recip() * self.reducing_temperature;
+        let delta = density / self.reducing_density;
+        let ln_tau = tau.ln();
+        let mut alpha = D::zero();
+        for c in &self.polynomial {
+            alpha += (ln_tau * c.t).exp() * delta.powi(c.d) * c.n;
+        }
+        for c in &self.exponential {
+            alpha += (ln_tau * c.t - delta.powi(c.l)).exp() * delta.powi(c.d) * c.n;
+        }
+        for c in &self.gaussian {
+            alpha += (ln_tau * c.t
+                - (delta - c.epsilon).powi(2) * c.eta
+                - (tau - c.gamma).powi(2) * c.beta)
+                .exp()
+                * delta.powi(c.d)
+                * c.n;
+        }
+        alpha
+    }
+}
+
+/// Residual Helmholtz energy density of the Lennard-Jones fluid for the
+/// given total density and mole fractions.
+///
+/// Mixtures are described as a single Lennard-Jones fluid with the
+/// van der Waals one-fluid mixing rules
+/// $$\sigma_x^3=\sum_{ij}x_ix_j\sigma_{ij}^3,~~~~~~~~\varepsilon_x\sigma_x^3=\sum_{ij}x_ix_j\varepsilon_{ij}\sigma_{ij}^3.$$
+/// The mole fractions are passed separately, so that the density can vanish,
+/// e.g., for the calculation of virial coefficients.
+pub(crate) fn helmholtz_energy_density<D: DualNum<f64>>(
+    parameters: &LennardJonesParameters,
+    coefficients: &TholCoefficients,
+    temperature: D,
+    density: D,
+    molefracs: ArrayView1<D>,
+) -> D {
+    let n = parameters.sigma.len();
+    let mut sigma3 = D::zero();
+    let mut epsilon_sigma3 = D::zero();
+    for i in 0..n {
+        for j in 0..n {
+            let x2_sigma3 = molefracs[i] * molefracs[j] * parameters.sigma_ij[[i, j]].powi(3);
+            sigma3 += x2_sigma3;
+            epsilon_sigma3 += x2_sigma3 * parameters.epsilon_k_ij[[i, j]];
+        }
+    }
+    let reduced_temperature = temperature * sigma3 / epsilon_sigma3;
+    let reduced_density = density * sigma3;
+    density * coefficients.alpha_r(reduced_temperature, reduced_density)
+}
+
+pub struct Thol {
+    pub parameters: Arc<LennardJonesParameters>,
+    pub coefficients: Arc<TholCoefficients>,
+}
+
+impl<D: DualNum<f64>> HelmholtzEnergyDual<D> for Thol {
+    fn helmholtz_energy(&self, state: &StateHD<D>) -> D {
+        helmholtz_energy_density(
+            &self.parameters,
+            &self.coefficients,
+            state.temperature,
+            state.partial_density.sum(),
+            state.molefracs.view(),
+        ) * state.volume
+    }
+}
+
+impl fmt::Display for Thol {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Lennard-Jones (Thol et al.)")
+    }
+}
@@ -0,0 +1,31 @@
+//! Reference equation of state for the Lennard-Jones fluid
+//!
+//! [Thol et al. (2016)](https://doi.org/10.1063/1.4945000)
+//!
+//! The empirical equation of state is explicit in the Helmholtz energy and
+//! accurately reproduces molecular simulation data of the Lennard-Jones fluid.
+//! It can be used to benchmark other models of Lennard-Jones fluids (e.g.,
+//! PeTS or uv-theory) within the same framework. Only the coefficients of the
+//! full Lennard-Jones fluid are included, the truncated and shifted
+//! Lennard-Jones fluid is not parametrized in this crate. Other model fluids
+//! with the same functional form can be described with coefficients from the
+//! literature read from JSON files ([TholCoefficients]).
+//! Mixtures are described with the van der Waals one-fluid theory.
+#![warn(clippy::all)]
+#![allow(clippy::too_many_arguments)]
+
+#[cfg(feature = "dft")]
+mod dft;
+mod eos;
+mod parameters;
+
+#[cfg(feature = "dft")]
+pub use dft::LennardJonesFunctional;
+pub use eos::{
+    ExponentialTerm, GaussianTerm, LennardJones, LennardJonesOptions, PolynomialTerm,
+    TholCoefficients,
+};
+pub use parameters::{LennardJonesBinaryRecord, LennardJonesParameters, LennardJonesRecord};
+
+#[cfg(feature = "python")]
+pub mod python;
@@ -0,0 +1,283 @@
+use crate::hard_sphere::{HardSphereProperties, MonomerShape};
+use feos_core::joback::JobackRecord;
+use feos_core::parameter::{CombiningRule, Metadata, Parameter, PureRecord};
+use ndarray::{Array, Array1, Array2};
+use num_dual::DualNum;
+use num_traits::Zero;
+use serde::{Deserialize, Serialize};
+use std::fmt::Write;
+use std::sync::Arc;
+
+/// Lennard-Jones parameters for a pure substance.
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct LennardJonesRecord {
+    /// Lennard-Jones diameter in units of Angstrom
+    pub sigma: f64,
+    /// Lennard-Jones energy parameter in units of Kelvin
+    pub epsilon_k: f64,
+}
+
+impl std::fmt::Display for LennardJonesRecord {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "LennardJonesRecord(sigma={}", self.sigma)?;
+        write!(f, ", epsilon_k={})", self.epsilon_k)
+    }
+}
+
+impl LennardJonesRecord {
+    /// New Lennard-Jones parameters for a pure substance.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use feos::lennard_jones::LennardJonesRecord;
+    /// let record = LennardJonesRecord::new(3.405, 119.8);
+    /// ```
+    pub fn new(sigma: f64, epsilon_k: f64) -> LennardJonesRecord {
+        LennardJonesRecord { sigma, epsilon_k }
+    }
+}
+
+/// Parameters that modify binary interactions.
+///
+/// $\varepsilon_{k,ij} = (1 - k_{ij})\sqrt{\varepsilon_{k,i} \varepsilon_{k,j}}$
+#[derive(Serialize, Deserialize, Clone, Default, Debug)]
+pub struct LennardJonesBinaryRecord {
+    k_ij: f64,
+}
+
+impl From<f64> for LennardJonesBinaryRecord {
+    fn from(k_ij: f64) -> Self {
+        Self { k_ij }
+    }
+}
+
+impl From<LennardJonesBinaryRecord> for f64 {
+    fn from(binary_record: LennardJonesBinaryRecord) -> Self {
+        binary_record.k_ij
+    }
+}
+
+impl std::fmt::Display for LennardJonesBinaryRecord {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "LennardJonesBinaryRecord(k_ij={})", self.k_ij)
+    }
+}
+
+/// Parameter set for the Lennard-Jones equation of state and Helmholtz energy functional.
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct LennardJonesParameters {
+    /// molar weight in gram per mole
+    pub molarweight: Array1<f64>,
+    /// Lennard-Jones diameter in Angstrom
+    pub sigma: Array1<f64>,
+    /// Lennard-Jones energy parameter in Kelvin
+    pub epsilon_k: Array1<f64>,
+    /// binary interaction parameter
+    pub k_ij: Array2<f64>,
+    /// diameter matrix
+    pub sigma_ij: Array2<f64>,
+    /// energy parameter matrix including k_ij
+    pub epsilon_k_ij: Array2<f64>,
+    /// combining rule for the cross diameters and energy parameters
+    #[serde(default)]
+    pub combining_rule: CombiningRule,
+    /// records of all pure substances of the system
+    pub pure_records: Vec<PureRecord<LennardJonesRecord, JobackRecord>>,
+    /// records of parameters for Joback method
+    pub joback_records: Option<Vec<JobackRecord>>,
+    /// records of all binary interaction parameters
+    pub binary_records: Array2<LennardJonesBinaryRecord>,
+    pub binary_metadata: Array2<Option<Metadata>>,
+}
+
+impl Parameter for LennardJonesParameters {
+    type Pure = LennardJonesRecord;
+    type IdealGas = JobackRecord;
+    type Binary = LennardJonesBinaryRecord;
+
+    fn from_records(
+        pure_records: Vec<PureRecord<Self::Pure, Self::IdealGas>>,
+        binary_records: Array2<LennardJonesBinaryRecord>,
+    ) -> Self {
+        let n = pure_records.len();
+
+        let mut molarweight = Array::zeros(n);
+        let mut sigma = Array::zeros(n);
+        let mut epsilon_k = Array::zeros(n);
+        for (i, record) in pure_records.iter().enumerate() {
+            sigma[i] = record.model_record.sigma;
+            epsilon_k[i] = record.model_record.epsilon_k;
+            molarweight[i] = record.molarweight;
+        }
+
+        let k_ij = binary_records.map(|br| br.k_ij);
+        let mut epsilon_k_ij = Array::zeros((n, n));
+        let mut sigma_ij = Array::zeros((n, n));
+        for i in 0..n {
+            for j in 0..n {
+                epsilon_k_ij[[i, j]] = (1.0 - k_ij[[i, j]]) * (epsilon_k[i] * epsilon_k[j]).sqrt();
+                sigma_ij[[i, j]] = 0.5 * (sigma[i] + sigma[j]);
+            }
+        }
+
+        let joback_records = pure_records
+            .iter()
+            .map(|r| r.ideal_gas_record.clone())
+            .collect();
+
+        Self {
+            molarweight,
+            sigma,
+            epsilon_k,
+            k_ij,
+            sigma_ij,
+            epsilon_k_ij,
+            combining_rule: CombiningRule::LorentzBerthelot,
+            pure_records,
+            joback_records,
+            binary_metadata: Array2::from_elem(binary_records.raw_dim(), None),
+            binary_records,
+        }
+    }
+
+    fn records(
+        &self,
+    ) -> (
+        &[PureRecord<LennardJonesRecord, JobackRecord>],
+        &Array2<LennardJonesBinaryRecord>,
+    ) {
+        (&self.pure_records, &self.binary_records)
+    }
+
+    fn from_records_with_metadata(
+        pure_records: Vec<PureRecord<Self::Pure, Self::IdealGas>>,
+        binary_records: Array2<Self::Binary>,
+        binary_metadata: Array2<Option<Metadata>>,
+    ) -> Self {
+        let mut parameters = Self::from_records(pure_records, binary_records);
+        parameters.binary_metadata = binary_metadata;
+        parameters
+    }
+
+    fn binary_metadata(&self) -> Option<&Array2<Option<Metadata>>> {
+        Some(&self.binary_metadata)
+    }
+}
+
+/// The hard-sphere diameters are calculated from the correlation of
+/// [Cotterman et al. (1986)](https://doi.org/10.1016/0378-3812(86)80002-6)
+/// for the Barker-Henderson diameter of the Lennard-Jones fluid.
+impl HardSphereProperties for LennardJonesParameters {
+    fn monomer_shape<N: DualNum<f64>>(&self, _: N) -> MonomerShape<N> {
+        MonomerShape::Spherical(self.sigma.len())
+    }
+
+    fn hs_diameter<D: DualNum<f64>>(&self, temperature: D) -> Array1<D> {
+        Array::from_shape_fn(self.sigma.len(), |i| {
+            let t = temperature / self.epsilon_k[i];
+            (t * 0.2977 + 1.0) / (t * 0.33163 + t * t * 0.0010477 + 1.0) * self.sigma[i]
+        })
+    }
+}
+
+impl LennardJonesParameters {
+    /// Replace the combining rule for the cross diameters and energy parameters.
+    ///
+    /// The binary interaction parameters `k_ij` are applied as corrections
+    /// to the combined energy parameters.
+    pub fn with_combining_rule(&self, combining_rule: CombiningRule) -> Self {
+        let (sigma_ij, epsilon_k_ij) =
+            combining_rule.cross_parameters(&self.sigma, &self.epsilon_k);
+        Self {
+            sigma_ij,
+            epsilon_k_ij: (1.0 - &self.k_ij) * epsilon_k_ij,
+            combining_rule,
+            ..self.clone()
+        }
+    }
+
+    /// Rebuild shared parameters if they do not use the given combining rule.
+    pub(crate) fn apply_combining_rule(
+        parameters: Arc<Self>,
+        combining_rule: CombiningRule,
+    ) -> Arc<Self> {
+        if parameters.combining_rule == combining_rule {
+            parameters
+        } else {
+            Arc::new(parameters.with_combining_rule(combining_rule))
+        }
+    }
+
+    pub fn to_markdown(&self) -> String {
+        let mut output = String::new();
+        let o = &mut output;
+        write!(
+            o,
+            "|component|molarweight|$\\sigma$|$\\varepsilon$|\n|-|-|-|-|"
+        )
+        .unwrap();
+        for i in 0..self.sigma.len() {
+            let component = self.pure_records[i].identifier.name.clone();
+            let component = component.unwrap_or(format!("Component {}", i + 1));
+            write!(
+                o,
+                "\n|{}|{}|{}|{}|",
+                component, self.molarweight[i], self.sigma[i], self.epsilon_k[i],
+            )
+            .unwrap();
+        }
+
+        output
+    }
+}
+
+impl std::fmt::Display for LennardJonesParameters {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "LennardJonesParameters(")?;
+        write!(f, "\n\tmolarweight={}", self.molarweight)?;
+        write!(f, "\n\tsigma={}", self.sigma)?;
+        write!(f, "\n\tepsilon_k={}", self.epsilon_k)?;
+        if !self.k_ij.iter().all(|k| k.is_zero()) {
+            write!(f, "\n\tk_ij=\n{}", self.k_ij)?;
+        }
+        write!(f, "\n)")
+    }
+}
+
+#[cfg(test)]
+pub mod utils {
+    use super::*;
+    use feos_core::parameter::Identifier;
+    use std::sync::Arc;
+
+    pub fn argon_parameters() -> Arc<LennardJonesParameters> {
+        let identifier = Identifier::new(None, Some("argon"), None, None, None, None);
+        let record = PureRecord::new(
+            identifier,
+            39.948,
+            LennardJonesRecord::new(3.405, 119.8),
+            None,
+        );
+        Arc::new(LennardJonesParameters::new_pure(record))
+    }
+
+    pub fn argon_krypton_parameters() -> Arc<LennardJonesParameters> {
+        let argon = PureRecord::new(
+            Identifier::new(None, Some("argon"), None, None, None, None),
+            39.948,
+            LennardJonesRecord::new(3.405, 119.8),
+            None,
+        );
+        let krypton = PureRecord::new(
+            Identifier::new(None, Some("krypton"), None, None, None, None),
+            83.798,
+            LennardJonesRecord::new(3.63, 163.1),
+            None,
+        );
+        Arc::new(LennardJonesParameters::new_binary(
+            vec![argon, krypton],
+            None,
+        ))
+    }
+}
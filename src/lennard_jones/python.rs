@@ -0,0 +1,322 @@
+use super::parameters::*;
+use super::TholCoefficients;
+use feos_core::joback::JobackRecord;
+use feos_core::parameter::*;
+use feos_core::python::joback::PyJobackRecord;
+use feos_core::python::parameter::*;
+use feos_core::{impl_binary_record, impl_json_handling, impl_parameter, impl_pure_record};
+use ndarray::Array2;
+use numpy::{PyArray2, PyReadonlyArray2, ToPyArray};
+use pyo3::exceptions::{PyTypeError, PyValueError};
+use pyo3::prelude::*;
+use std::convert::{TryFrom, TryInto};
+use std::sync::Arc;
+
+/// Create a set of Lennard-Jones parameters for a pure substance.
+///
+/// Parameters
+/// ----------
+/// sigma : float
+///     Lennard-Jones diameter in units of Angstrom.
+/// epsilon_k : float
+///     Lennard-Jones energy parameter in units of Kelvin.
+///
+/// Returns
+/// -------
+/// LennardJonesRecord
+#[pyclass(name = "LennardJonesRecord")]
+#[pyo3(text_signature = "(sigma, epsilon_k)")]
+#[derive(Clone)]
+pub struct PyLennardJonesRecord(LennardJonesRecord);
+
+#[pymethods]
+impl PyLennardJonesRecord {
+    #[new]
+    fn new(sigma: f64, epsilon_k: f64) -> Self {
+        Self(LennardJonesRecord::new(sigma, epsilon_k))
+    }
+
+    #[getter]
+    fn get_sigma(&self) -> f64 {
+        self.0.sigma
+    }
+
+    #[getter]
+    fn get_epsilon_k(&self) -> f64 {
+        self.0.epsilon_k
+    }
+
+    fn __repr__(&self) -> PyResult<String> {
+        Ok(self.0.to_string())
+    }
+}
+
+impl_json_handling!(PyLennardJonesRecord);
+impl_pure_record!(
+    LennardJonesRecord,
+    PyLennardJonesRecord,
+    JobackRecord,
+    PyJobackRecord
+);
+
+#[pyclass(name = "LennardJonesBinaryRecord")]
+#[derive(Clone)]
+pub struct PyLennardJonesBinaryRecord(LennardJonesBinaryRecord);
+impl_binary_record!(LennardJonesBinaryRecord, PyLennardJonesBinaryRecord);
+
+/// Create a set of Lennard-Jones parameters from records.
+///
+/// Parameters
+/// ----------
+/// pure_records : List[PureRecord]
+///     pure substance records.
+/// binary_records : List[BinarySubstanceRecord], optional
+///     binary Lennard-Jones parameter records
+/// substances : List[str], optional
+///     The substances to use. Filters substances from `pure_records` according to
+///     `search_option`.
+///     When not provided, all entries of `pure_records` are used.
+/// search_option : {'Name', 'Cas', 'Inchi', 'InchiKey', 'IupacName', 'Formula', 'Smiles'}, optional, defaults to 'Name'.
+///     Identifier that is used to search substance.
+#[pyclass(name = "LennardJonesParameters")]
+#[pyo3(
+    text_signature = "(pure_records, binary_records=None, substances=None, search_option='Name')"
+)]
+#[derive(Clone)]
+pub struct PyLennardJonesParameters(pub Arc<LennardJonesParameters>);
+
+#[pymethods]
+impl PyLennardJonesParameters {
+    /// Create a set of Lennard-Jones parameters from lists.
+    ///
+    /// Parameters
+    /// ----------
+    /// sigma : List[float]
+    ///     Lennard-Jones diameter in units of Angstrom.
+    /// epsilon_k : List[float]
+    ///     Lennard-Jones energy parameter in units of Kelvin.
+    /// k_ij: numpy.ndarray[float], optional
+    ///     matrix of binary interaction parameters.
+    /// molarweight: List[float], optional
+    ///     molar weight in units of Gram per Mol.
+    ///
+    /// Returns
+    /// -------
+    /// LennardJonesParameters
+    #[pyo3(text_signature = "(sigma, epsilon_k, k_ij=None, molarweight=None)")]
+    #[staticmethod]
+    fn from_lists(
+        sigma: Vec<f64>,
+        epsilon_k: Vec<f64>,
+        k_ij: Option<&PyArray2<f64>>,
+        molarweight: Option<Vec<f64>>,
+    ) -> PyResult<Self> {
+        // Check if all inputs have the same length
+        let n = sigma.len();
+        let input_length = vec![
+            Some(sigma.len()),
+            Some(epsilon_k.len()),
+            k_ij.as_ref().map(|v| v.shape()[0]),
+            k_ij.as_ref().map(|v| v.shape()[1]),
+            molarweight.as_ref().map(|v| v.len()),
+        ]
+        .iter()
+        .filter_map(|&v| v)
+        .all(|v| v == n);
+
+        if !input_length {
+            return Err(PyValueError::new_err(
+                "shape of arguments could not be used together.",
+            ));
+        }
+
+        // Define `PureRecord`s
+        let pure_records = (0..n)
+            .map(|i| {
+                let identifier = Identifier::new(
+                    Some(format!("{}", i).as_str()),
+                    None,
+                    None,
+                    None,
+                    None,
+                    None,
+                );
+                PureRecord::new(
+                    identifier,
+                    molarweight.as_ref().map_or(1.0, |v| v[i]),
+                    LennardJonesRecord::new(sigma[i], epsilon_k[i]),
+                    None,
+                )
+            })
+            .collect();
+
+        let binary = match k_ij {
+            Some(v) => v.to_owned_array().mapv(f64::into),
+            None => Array2::from_shape_fn((n, n), |(_, _)| LennardJonesBinaryRecord::from(0.0)),
+        };
+
+        Ok(Self(Arc::new(LennardJonesParameters::from_records(
+            pure_records,
+            binary,
+        ))))
+    }
+
+    /// Create a set of Lennard-Jones parameters from values.
+    ///
+    /// Parameters
+    /// ----------
+    /// sigma : float
+    ///     Lennard-Jones diameter in units of Angstrom.
+    /// epsilon_k : float
+    ///     Lennard-Jones energy parameter in units of Kelvin.
+    /// molarweight: float, optional
+    ///     molar weight in units of Gram per Mol.
+    ///
+    /// Returns
+    /// -------
+    /// LennardJonesParameters
+    #[pyo3(text_signature = "(sigma, epsilon_k, molarweight=None)")]
+    #[staticmethod]
+    fn from_values(sigma: f64, epsilon_k: f64, molarweight: Option<f64>) -> Self {
+        let pure_record = PureRecord::new(
+            Identifier::new(
+                Some(format!("{}", 1).as_str()),
+                None,
+                None,
+                None,
+                None,
+                None,
+            ),
+            molarweight.unwrap_or(1.0),
+            LennardJonesRecord::new(sigma, epsilon_k),
+            None,
+        );
+        Self(Arc::new(LennardJonesParameters::new_pure(pure_record)))
+    }
+
+    #[getter]
+    fn get_k_ij<'py>(&self, py: Python<'py>) -> &'py PyArray2<f64> {
+        self.0.k_ij.view().to_pyarray(py)
+    }
+
+    fn _repr_markdown_(&self) -> String {
+        self.0.to_markdown()
+    }
+
+    fn __repr__(&self) -> PyResult<String> {
+        Ok(self.0.to_string())
+    }
+}
+
+impl_parameter!(LennardJonesParameters, PyLennardJonesParameters);
+
+/// Coefficients of an empirical equation of state for a
+/// Lennard-Jones model fluid in the functional form of
+/// Thol et al. (2016).
+///
+/// Use `TholCoefficients.lennard_jones()` for the full
+/// Lennard-Jones fluid and `TholCoefficients.from_json` to
+/// read coefficients of other model fluids, e.g., the truncated
+/// and shifted Lennard-Jones fluid. Coefficients of the truncated
+/// and shifted fluid are not included and have to be taken from
+/// the literature.
+#[pyclass(name = "TholCoefficients")]
+#[derive(Clone)]
+pub struct PyTholCoefficients(pub Arc<TholCoefficients>);
+
+#[pymethods]
+impl PyTholCoefficients {
+    /// Coefficients of the full Lennard-Jones fluid.
+    ///
+    /// Returns
+    /// -------
+    /// TholCoefficients
+    #[staticmethod]
+    fn lennard_jones() -> Self {
+        Self(Arc::new(TholCoefficients::lennard_jones()))
+    }
+
+    /// Read coefficients from a JSON file.
+    ///
+    /// Parameters
+    /// ----------
+    /// path : str
+    ///     Path to the file containing the coefficients.
+    ///
+    /// Returns
+    /// -------
+    /// TholCoefficients
+    #[staticmethod]
+    #[pyo3(text_signature = "(path)")]
+    fn from_json(path: &str) -> Result<Self, ParameterError> {
+        Ok(Self(Arc::new(TholCoefficients::from_json(path)?)))
+    }
+
+    /// Creates coefficients from a json string.
+    #[staticmethod]
+    fn from_json_str(json: &str) -> Result<Self, ParameterError> {
+        Ok(Self(Arc::new(serde_json::from_str(json)?)))
+    }
+
+    /// Creates a json string from the coefficients.
+    fn to_json_str(&self) -> Result<String, ParameterError> {
+        Ok(serde_json::to_string(self.0.as_ref())?)
+    }
+
+    fn __reduce__(&self, py: Python<'_>) -> PyResult<(PyObject, (String,))> {
+        let from_json_str = py.get_type::<Self>().getattr("from_json_str")?;
+        Ok((from_json_str.into(), (self.to_json_str()?,)))
+    }
+
+    /// The reducing temperature in Lennard-Jones units.
+    #[getter]
+    fn get_reducing_temperature(&self) -> f64 {
+        self.0.reducing_temperature
+    }
+
+    /// The reducing density in Lennard-Jones units.
+    #[getter]
+    fn get_reducing_density(&self) -> f64 {
+        self.0.reducing_density
+    }
+
+    /// The cut-off radius of the truncated and shifted potential.
+    #[getter]
+    fn get_cutoff_radius(&self) -> Option<f64> {
+        self.0.cutoff_radius
+    }
+
+    /// The reduced residual Helmholtz energy per particle.
+    ///
+    /// Parameters
+    /// ----------
+    /// temperature : float
+    ///     The reduced temperature.
+    /// density : float
+    ///     The reduced density.
+    ///
+    /// Returns
+    /// -------
+    /// float
+    #[pyo3(text_signature = "(temperature, density)")]
+    fn alpha_r(&self, temperature: f64, density: f64) -> f64 {
+        self.0.alpha_r(temperature, density)
+    }
+}
+
+#[pymodule]
+pub fn lennard_jones(_py: Python<'_>, m: &PyModule) -> PyResult<()> {
+    m.add_class::<PyIdentifier>()?;
+    m.add_class::<PyMetadata>()?;
+    m.add_class::<IdentifierOption>()?;
+    m.add_class::<CombiningRule>()?;
+    m.add_class::<PyChemicalRecord>()?;
+    m.add_class::<PyJobackRecord>()?;
+
+    m.add_class::<PyLennardJonesRecord>()?;
+    m.add_class::<PyPureRecord>()?;
+    m.add_class::<PyBinaryRecord>()?;
+    m.add_class::<PyLennardJonesParameters>()?;
+    m.add_class::<PyTholCoefficients>()?;
+    Ok(())
+}
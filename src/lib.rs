@@ -33,6 +33,8 @@
 
 #![warn(clippy::all)]
 #![allow(clippy::too_many_arguments)]
+#[cfg(feature = "openblas")]
+extern crate blas_src;
 #[cfg(feature = "dft")]
 mod dft;
 #[cfg(feature = "dft")]
@@ -50,6 +52,8 @@ pub mod hard_sphere;
 // models
 #[cfg(feature = "gc_pcsaft")]
 pub mod gc_pcsaft;
+#[cfg(feature = "lennard_jones")]
+pub mod lennard_jones;
 #[cfg(feature = "pcsaft")]
 pub mod pcsaft;
 #[cfg(feature = "pets")]
@@ -56,7 +56,6 @@ impl<N: DualNum<f64> + ScalarOperand> FunctionalContributionDual<N> for Attracti
     ) -> Result<Array1<N>, EosError> {
         // auxiliary variables
         let p = &self.parameters;
-        let n = p.m.len();
 
         // temperature dependent segment radius
         let r = p.hs_diameter(temperature) * 0.5;
@@ -86,20 +85,9 @@ impl<N: DualNum<f64> + ScalarOperand> FunctionalContributionDual<N> for Attracti
         });
 
         // mixture densities, crosswise interactions of all segments on all chains
-        let mut rho1mix: Array1<N> = Array::zeros(eta.raw_dim());
-        let mut rho2mix: Array1<N> = Array::zeros(eta.raw_dim());
-        for i in 0..n {
-            for j in 0..n {
-                let eps_ij_t = temperature.recip() * p.epsilon_k_ij[(i, j)];
-                let sigma_ij_3 = p.sigma_ij[(i, j)].powi(3);
-                rho1mix = rho1mix
-                    + (&density.index_axis(Axis(0), i) * &density.index_axis(Axis(0), j))
-                        .mapv(|x| x * (eps_ij_t * sigma_ij_3 * p.m[i] * p.m[j]));
-                rho2mix = rho2mix
-                    + (&density.index_axis(Axis(0), i) * &density.index_axis(Axis(0), j))
-                        .mapv(|x| x * (eps_ij_t * eps_ij_t * sigma_ij_3 * p.m[i] * p.m[j]));
-            }
-        }
+        let t_inv = temperature.recip();
+        let rho1mix = mixture_density(&p.m_sigma3_epsilon_k_ij, density) * t_inv;
+        let rho2mix = mixture_density(&p.m_sigma3_epsilon_k2_ij, density) * (t_inv * t_inv);
 
         // I1, I2 and C1
         let mut i1: Array1<N> = Array::zeros(eta.raw_dim());
@@ -127,6 +115,19 @@ impl<N: DualNum<f64> + ScalarOperand> FunctionalContributionDual<N> for Attracti
     }
 }
 
+/// Double sum $\sum_i\sum_j\rho_i\rho_jc_{ij}$ at every grid point.
+///
+/// The inner sum is evaluated as matrix product of the coefficient matrix
+/// with the density profiles, which uses the optimized (SIMD) matrix
+/// multiplication of `ndarray` for real numbers.
+fn mixture_density<N: DualNum<f64> + ScalarOperand>(
+    c: &Array2<f64>,
+    density: ArrayView2<N>,
+) -> Array1<N> {
+    let c_rho = c.mapv(N::from).dot(&density);
+    (&density * &c_rho).sum_axis(Axis(0))
+}
+
 impl fmt::Display for AttractiveFunctional {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         write!(f, "Attractive functional")
@@ -1,16 +1,20 @@
-use super::PcSaftParameters;
+use super::{PcSaftBinaryRecord, PcSaftParameters, PcSaftRecord};
 use crate::association::Association;
 use crate::hard_sphere::{FMTContribution, FMTVersion};
 use crate::pcsaft::eos::PcSaftOptions;
-use feos_core::joback::Joback;
-use feos_core::parameter::Parameter;
-use feos_core::{IdealGasContribution, MolarWeight};
+use feos_core::ideal_gas::{IdealGasModel, WithIdealGas};
+use feos_core::joback::JobackRecord;
+use feos_core::parameter::{Parameter, ParameterSet};
+use feos_core::{EosResult, EquationOfState, IdealGasContribution, MolarWeight};
 use feos_dft::adsorption::FluidParameters;
+use feos_dft::interface::ParametricFunctional;
 use feos_dft::solvation::PairPotential;
 use feos_dft::{FunctionalContribution, HelmholtzEnergyFunctional, MoleculeShape, DFT};
 use ndarray::{Array1, Array2};
 use num_traits::One;
 use quantity::si::*;
+use serde::de::Error;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
 use std::f64::consts::FRAC_PI_6;
 use std::sync::Arc;
 
@@ -28,7 +32,7 @@ pub struct PcSaftFunctional {
     fmt_version: FMTVersion,
     options: PcSaftOptions,
     contributions: Vec<Box<dyn FunctionalContribution>>,
-    joback: Joback,
+    ideal_gas: IdealGasModel,
 }
 
 impl PcSaftFunctional {
@@ -45,12 +49,15 @@ impl PcSaftFunctional {
         fmt_version: FMTVersion,
         saft_options: PcSaftOptions,
     ) -> DFT<Self> {
+        let parameters =
+            PcSaftParameters::apply_combining_rule(parameters, saft_options.combining_rule);
         let mut contributions: Vec<Box<dyn FunctionalContribution>> = Vec::with_capacity(4);
 
         if matches!(
             fmt_version,
             FMTVersion::WhiteBear | FMTVersion::AntiSymWhiteBear
         ) && parameters.m.len() == 1
+            && parameters.association.assoc_comp.len() <= 1
         {
             let fmt_assoc = PureFMTAssocFunctional::new(parameters.clone(), fmt_version);
             contributions.push(Box::new(fmt_assoc));
@@ -87,17 +94,17 @@ impl PcSaftFunctional {
             }
         }
 
-        let joback = match &parameters.joback_records {
-            Some(joback_records) => Joback::new(joback_records.clone()),
-            None => Joback::default(parameters.m.len()),
-        };
+        let ideal_gas = parameters.joback_records.clone().map_or_else(
+            || IdealGasModel::default(parameters.m.len()),
+            IdealGasModel::joback,
+        );
 
         (Self {
             parameters,
             fmt_version,
             options: saft_options,
             contributions,
-            joback,
+            ideal_gas,
         })
         .into()
     }
@@ -110,12 +117,19 @@ impl HelmholtzEnergyFunctional for PcSaftFunctional {
             self.fmt_version,
             self.options,
         )
+        .with_ideal_gas(self.ideal_gas.subset(component_list))
+        .unwrap()
     }
 
     fn compute_max_density(&self, moles: &Array1<f64>) -> f64 {
-        self.options.max_eta * moles.sum()
-            / (FRAC_PI_6 * &self.parameters.m * self.parameters.sigma.mapv(|v| v.powi(3)) * moles)
-                .sum()
+        self.options.max_density.apply(
+            self.options.max_eta * moles.sum()
+                / (FRAC_PI_6
+                    * &self.parameters.m
+                    * self.parameters.sigma.mapv(|v| v.powi(3))
+                    * moles)
+                    .sum(),
+        )
     }
 
     fn contributions(&self) -> &[Box<dyn FunctionalContribution>] {
@@ -123,7 +137,7 @@ impl HelmholtzEnergyFunctional for PcSaftFunctional {
     }
 
     fn ideal_gas(&self) -> &dyn IdealGasContribution {
-        &self.joback
+        &self.ideal_gas
     }
 
     fn molecule_shape(&self) -> MoleculeShape {
@@ -131,6 +145,70 @@ impl HelmholtzEnergyFunctional for PcSaftFunctional {
     }
 }
 
+impl WithIdealGas for PcSaftFunctional {
+    fn with_ideal_gas(mut self, ideal_gas: IdealGasModel) -> EosResult<Self> {
+        ideal_gas.validate_components(self.parameters.m.len())?;
+        self.ideal_gas = ideal_gas;
+        Ok(self)
+    }
+}
+
+/// The parameters are ordered as in the [ParameterSensitivity](feos_core::ParameterSensitivity)
+/// implementation of [PcSaft](crate::pcsaft::PcSaft), i.e., $m_i$, $\sigma_i$,
+/// $\varepsilon_i/k_\mathrm{B}$ for all components, followed by the binary interaction
+/// parameters $k_{ij}$ with $i<j$. The remaining parameters, e.g., of associating
+/// components, are kept.
+impl ParametricFunctional for PcSaftFunctional {
+    fn parameter_names(&self) -> Vec<String> {
+        self.parameters.sensitivity_parameter_names()
+    }
+
+    fn parameter_values(&self) -> Array1<f64> {
+        self.parameters.sensitivity_parameter_values()
+    }
+
+    fn with_parameter_values(&self, parameters: &[f64]) -> EosResult<DFT<Self>> {
+        let parameters = self
+            .parameters
+            .with_sensitivity_parameter_values(parameters)?;
+        Self::with_options(Arc::new(parameters), self.fmt_version, self.options)
+            .with_ideal_gas(self.ideal_gas.clone())
+    }
+}
+
+// Auxiliary structure used to (de)serialize the Helmholtz energy functional.
+#[derive(Serialize, Deserialize)]
+struct PcSaftFunctionalJSON {
+    parameters: ParameterSet<PcSaftRecord, JobackRecord, PcSaftBinaryRecord>,
+    fmt_version: FMTVersion,
+    options: PcSaftOptions,
+    ideal_gas: IdealGasModel,
+}
+
+impl Serialize for PcSaftFunctional {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        PcSaftFunctionalJSON {
+            parameters: self.parameters.parameter_set(),
+            fmt_version: self.fmt_version,
+            options: self.options,
+            ideal_gas: self.ideal_gas.clone(),
+        }
+        .serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for PcSaftFunctional {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let func = PcSaftFunctionalJSON::deserialize(deserializer)?;
+        let parameters =
+            PcSaftParameters::from_parameter_set(func.parameters).map_err(D::Error::custom)?;
+        Self::with_options(Arc::new(parameters), func.fmt_version, func.options)
+            .into_inner()
+            .with_ideal_gas(func.ideal_gas)
+            .map_err(D::Error::custom)
+    }
+}
+
 impl MolarWeight for PcSaftFunctional {
     fn molar_weight(&self) -> SIArray1 {
         self.parameters.molarweight.clone() * GRAM / MOL
@@ -134,12 +134,18 @@ impl<N: DualNum<f64> + ScalarOperand> FunctionalContributionDual<N> for PureFMTA
                         Association::<PcSaftParameters>::assoc_site_frac_ab(d, a.na[0], a.nb[0])
                     });
                     let xb = (xa.clone() - 1.0) * a.na[0] / a.nb[0] + 1.0;
-                    (n0 / p.m[0] * xi) * (xa.mapv(f) * a.na[0] + xb.mapv(f) * a.nb[0])
+                    (&n0 / p.m[0] * &xi) * (xa.mapv(f) * a.na[0] + xb.mapv(f) * a.nb[0])
                 } else {
                     let xa = deltarho
                         .mapv(|d| Association::<PcSaftParameters>::assoc_site_frac_a(d, a.na[0]));
-                    n0 / p.m[0] * xi * (xa.mapv(f) * a.na[0])
+                    &n0 / p.m[0] * &xi * (xa.mapv(f) * a.na[0])
                 };
+            if a.nc[0] > 0.0 {
+                // self-associating sites of type C
+                let xc = deltarho
+                    .mapv(|d| Association::<PcSaftParameters>::assoc_site_frac_a(d, a.nc[0]));
+                phi = phi + n0 / p.m[0] * xi * (xc.mapv(f) * a.nc[0]);
+            }
         }
 
         Ok(phi)
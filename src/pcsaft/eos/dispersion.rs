@@ -1,6 +1,7 @@
 use super::PcSaftParameters;
 use crate::hard_sphere::HardSphereProperties;
 use feos_core::{HelmholtzEnergyDual, StateHD};
+use ndarray::{Array1, Array2};
 use num_dual::DualNum;
 use std::f64::consts::{FRAC_PI_3, PI};
 use std::fmt;
@@ -68,7 +69,6 @@ pub struct Dispersion {
 impl<D: DualNum<f64>> HelmholtzEnergyDual<D> for Dispersion {
     fn helmholtz_energy(&self, state: &StateHD<D>) -> D {
         // auxiliary variables
-        let n = self.parameters.m.len();
         let p = &self.parameters;
         let rho = &state.partial_density;
 
@@ -82,38 +82,65 @@ impl<D: DualNum<f64>> HelmholtzEnergyDual<D> for Dispersion {
         let m = (&state.molefracs * &p.m).sum();
 
         // mixture densities, crosswise interactions of all segments on all chains
-        let mut rho1mix = D::zero();
-        let mut rho2mix = D::zero();
-        for i in 0..n {
-            for j in 0..n {
-                let eps_ij = state.temperature.recip() * p.epsilon_k_ij[(i, j)];
-                let sigma_ij = p.sigma_ij[[i, j]].powi(3);
-                rho1mix += rho[i] * rho[j] * p.m[i] * p.m[j] * eps_ij * sigma_ij;
-                rho2mix += rho[i] * rho[j] * p.m[i] * p.m[j] * eps_ij * eps_ij * sigma_ij;
-            }
-        }
-
-        // I1, I2 and C1
-        let mut i1 = D::zero();
-        let mut i2 = D::zero();
-        let mut eta_i = D::one();
-        for i in 0..=6 {
-            i1 += ((m - 1.0) / m * ((m - 2.0) / m * A2[i] + A1[i]) + A0[i]) * eta_i;
-            i2 += ((m - 1.0) / m * ((m - 2.0) / m * B2[i] + B1[i]) + B0[i]) * eta_i;
-            eta_i *= eta;
-        }
-        let c1 = (m * (eta * 8.0 - eta.powi(2) * 2.0) / (eta - 1.0).powi(4)
-            + (D::one() - m)
-                * (eta * 20.0 - eta.powi(2) * 27.0 + eta.powi(3) * 12.0 - eta.powi(4) * 2.0)
-                / ((eta - 1.0) * (eta - 2.0)).powi(2)
-            + 1.0)
-            .recip();
+        let t_inv = state.temperature.recip();
+        let rho1mix = quadratic_form(&p.m_sigma3_epsilon_k_ij, rho) * t_inv;
+        let rho2mix = quadratic_form(&p.m_sigma3_epsilon_k2_ij, rho) * t_inv * t_inv;
 
         // Helmholtz energy
-        (-rho1mix * i1 * 2.0 - rho2mix * m * c1 * i2) * PI * state.volume
+        dispersion_helmholtz_energy_density(m, eta, rho1mix, rho2mix) * state.volume
     }
 }
 
+/// The reduced Helmholtz energy density $\frac{\beta A}{V}$ of the dispersion
+/// contribution for given mean segment number, packing fraction and the
+/// mixture densities $\sum_i\sum_j\rho_i\rho_jm_im_j\frac{\varepsilon_{ij}}{kT}\sigma_{ij}^3$
+/// and $\sum_i\sum_j\rho_i\rho_jm_im_j\left(\frac{\varepsilon_{ij}}{kT}\right)^2\sigma_{ij}^3$.
+pub(crate) fn dispersion_helmholtz_energy_density<D: DualNum<f64>>(
+    m: D,
+    eta: D,
+    rho1mix: D,
+    rho2mix: D,
+) -> D {
+    // I1, I2 and C1
+    let mut i1 = D::zero();
+    let mut i2 = D::zero();
+    let mut eta_i = D::one();
+    for i in 0..=6 {
+        i1 += ((m - 1.0) / m * ((m - 2.0) / m * A2[i] + A1[i]) + A0[i]) * eta_i;
+        i2 += ((m - 1.0) / m * ((m - 2.0) / m * B2[i] + B1[i]) + B0[i]) * eta_i;
+        eta_i *= eta;
+    }
+    let c1 = (m * (eta * 8.0 - eta.powi(2) * 2.0) / (eta - 1.0).powi(4)
+        + (D::one() - m)
+            * (eta * 20.0 - eta.powi(2) * 27.0 + eta.powi(3) * 12.0 - eta.powi(4) * 2.0)
+            / ((eta - 1.0) * (eta - 2.0)).powi(2)
+        + 1.0)
+        .recip();
+
+    (-rho1mix * i1 * 2.0 - rho2mix * m * c1 * i2) * PI
+}
+
+/// Double sum $\sum_i\sum_j\rho_i\rho_jc_{ij}$ evaluated as product of the
+/// density vector with the matrix-vector product $\sum_jc_{ij}\rho_j$.
+///
+/// Because the coefficients are real numbers, only $N$ of the $N^2$
+/// multiplications involve two dual numbers. Real densities (without
+/// derivatives) are multiplied with the matrix-vector product of `ndarray`,
+/// which uses BLAS with the `blas` feature.
+pub(crate) fn quadratic_form<D: DualNum<f64>>(c: &Array2<f64>, rho: &Array1<D>) -> D {
+    if D::NDERIV == 0 {
+        let rho = rho.mapv(|r| r.re());
+        return D::from(rho.dot(&c.dot(&rho)));
+    }
+    c.outer_iter()
+        .zip(rho.iter())
+        .map(|(c_i, &rho_i)| {
+            let c_rho_i: D = c_i.iter().zip(rho.iter()).map(|(&c, &r)| r * c).sum();
+            rho_i * c_rho_i
+        })
+        .sum()
+}
+
 impl fmt::Display for Dispersion {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         write!(f, "Dispersion")
@@ -128,6 +155,7 @@ mod tests {
     };
     use approx::assert_relative_eq;
     use ndarray::arr1;
+    use num_dual::Dual64;
 
     #[test]
     fn helmholtz_energy() {
@@ -166,4 +194,19 @@ mod tests {
         assert_relative_eq!(a1, a1m, epsilon = 1e-14);
         assert_relative_eq!(a2, a2m, epsilon = 1e-14);
     }
+
+    #[test]
+    fn quadratic_form_real_and_dual() {
+        let p = propane_butane_parameters();
+        let rho = arr1(&[0.002, 0.003]);
+        let c = &p.m_sigma3_epsilon_k_ij;
+        let pairs = || (0..2).flat_map(|i| (0..2).map(move |j| (i, j)));
+        let sum: f64 = pairs().map(|(i, j)| rho[i] * rho[j] * c[[i, j]]).sum();
+        let dsum: f64 = pairs().map(|(i, j)| 2.0 * rho[j] * c[[i, j]]).sum();
+        assert_relative_eq!(quadratic_form(c, &rho), sum, max_relative = 1e-14);
+        let rho_dual = rho.mapv(|r| Dual64::from_re(r).derive());
+        let q = quadratic_form(c, &rho_dual);
+        assert_relative_eq!(q.re, sum, max_relative = 1e-14);
+        assert_relative_eq!(q.eps[0], dsum, max_relative = 1e-14);
+    }
 }
@@ -1,7 +1,7 @@
 use super::PcSaftParameters;
 use crate::hard_sphere::HardSphereProperties;
 use feos_core::{HelmholtzEnergyDual, StateHD};
-use ndarray::Array;
+use ndarray::Array1;
 use num_dual::*;
 use std::fmt;
 use std::sync::Arc;
@@ -14,19 +14,30 @@ impl<D: DualNum<f64>> HelmholtzEnergyDual<D> for HardChain {
     fn helmholtz_energy(&self, state: &StateHD<D>) -> D {
         let p = &self.parameters;
         let d = self.parameters.hs_diameter(state.temperature);
-        let [zeta2, zeta3] = p.zeta(state.temperature, &state.partial_density, [2, 3]);
-        let frac_1mz3 = -(zeta3 - 1.0).recip();
-        let c = zeta2 * frac_1mz3 * frac_1mz3;
-        let g_hs =
-            d.mapv(|d| frac_1mz3 + d * c * 1.5 - d.powi(2) * c.powi(2) * (zeta3 - 1.0) * 0.5);
-        Array::from_shape_fn(self.parameters.m.len(), |i| {
-            state.partial_density[i] * (1.0 - self.parameters.m[i]) * g_hs[i].ln()
-        })
-        .sum()
+        let zeta = p.zeta(state.temperature, &state.partial_density, [2, 3]);
+        hard_chain_helmholtz_energy_density(&p.m.mapv(D::from), &d, &state.partial_density, zeta)
             * state.volume
     }
 }
 
+/// The reduced Helmholtz energy density $\frac{\beta A}{V}$ of the hard-chain
+/// contribution for given chain lengths, segment diameters, partial densities
+/// and packing fractions $\zeta_2$ and $\zeta_3$.
+pub(crate) fn hard_chain_helmholtz_energy_density<D: DualNum<f64>>(
+    m: &Array1<D>,
+    diameter: &Array1<D>,
+    partial_density: &Array1<D>,
+    [zeta2, zeta3]: [D; 2],
+) -> D {
+    let frac_1mz3 = -(zeta3 - 1.0).recip();
+    let c = zeta2 * frac_1mz3 * frac_1mz3;
+    let g_hs =
+        diameter.mapv(|d| frac_1mz3 + d * c * 1.5 - d.powi(2) * c.powi(2) * (zeta3 - 1.0) * 0.5);
+    (0..m.len())
+        .map(|i| partial_density[i] * (-m[i] + 1.0) * g_hs[i].ln())
+        .sum()
+}
+
 impl fmt::Display for HardChain {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         write!(f, "Hard Chain")
@@ -1,14 +1,17 @@
-use super::parameters::PcSaftParameters;
+use super::parameters::{PcSaftBinaryRecord, PcSaftParameters, PcSaftRecord};
 use crate::association::Association;
 use crate::hard_sphere::HardSphere;
-use feos_core::joback::Joback;
-use feos_core::parameter::Parameter;
+use feos_core::ideal_gas::{IdealGasModel, WithIdealGas};
+use feos_core::joback::JobackRecord;
+use feos_core::parameter::{CombiningRule, Parameter, ParameterSet};
 use feos_core::{
     Contributions, EntropyScaling, EosError, EosResult, EquationOfState, HelmholtzEnergy,
-    IdealGasContribution, MolarWeight, State,
+    IdealGasContribution, MaxDensity, MolarWeight, State,
 };
 use ndarray::Array1;
 use quantity::si::*;
+use serde::de::Error;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
 use std::f64::consts::{FRAC_PI_6, PI};
 use std::sync::Arc;
 
@@ -16,34 +19,45 @@ pub(crate) mod dispersion;
 pub(crate) mod hard_chain;
 pub(crate) mod polar;
 mod qspr;
+mod sensitivities;
 use dispersion::Dispersion;
 use hard_chain::HardChain;
 pub use polar::DQVariants;
-use polar::{Dipole, DipoleQuadrupole, Quadrupole};
+use polar::{Dipole, DipoleQuadrupole, PolarizableDipole, Quadrupole};
 use qspr::QSPR;
 
 #[allow(clippy::upper_case_acronyms)]
 enum IdealGasContributions {
     QSPR(QSPR),
-    Joback(Joback),
+    IdealGasModel(IdealGasModel),
 }
 
 /// Customization options for the PC-SAFT equation of state and functional.
-#[derive(Copy, Clone)]
+#[derive(Copy, Clone, Serialize, Deserialize)]
 pub struct PcSaftOptions {
     pub max_eta: f64,
+    pub max_density: MaxDensity,
     pub max_iter_cross_assoc: usize,
     pub tol_cross_assoc: f64,
     pub dq_variant: DQVariants,
+    /// Account for induced dipoles of components with a polarizability
+    /// (only available in the equation of state)
+    pub polarizable: bool,
+    /// Combining rule for the cross diameters and energy parameters
+    #[serde(default)]
+    pub combining_rule: CombiningRule,
 }
 
 impl Default for PcSaftOptions {
     fn default() -> Self {
         Self {
             max_eta: 0.5,
+            max_density: MaxDensity::Heuristic,
             max_iter_cross_assoc: 50,
             tol_cross_assoc: 1e-10,
             dq_variant: DQVariants::DQ35,
+            polarizable: false,
+            combining_rule: CombiningRule::LorentzBerthelot,
         }
     }
 }
@@ -62,6 +76,7 @@ impl PcSaft {
     }
 
     pub fn with_options(parameters: Arc<PcSaftParameters>, options: PcSaftOptions) -> Self {
+        let parameters = PcSaftParameters::apply_combining_rule(parameters, options.combining_rule);
         let mut contributions: Vec<Box<dyn HelmholtzEnergy>> = Vec::with_capacity(7);
         contributions.push(Box::new(HardSphere::new(&parameters)));
         contributions.push(Box::new(HardChain {
@@ -71,9 +86,13 @@ impl PcSaft {
             parameters: parameters.clone(),
         }));
         if parameters.ndipole > 0 {
-            contributions.push(Box::new(Dipole {
-                parameters: parameters.clone(),
-            }));
+            if options.polarizable && parameters.alpha.iter().any(|&a| a > 0.0) {
+                contributions.push(Box::new(PolarizableDipole::new(&parameters)));
+            } else {
+                contributions.push(Box::new(Dipole {
+                    parameters: parameters.clone(),
+                }));
+            }
         };
         if parameters.nquadpole > 0 {
             contributions.push(Box::new(Quadrupole {
@@ -103,7 +122,9 @@ impl PcSaft {
             contributions,
             ideal_gas: joback_records.map_or(
                 IdealGasContributions::QSPR(QSPR { parameters }),
-                |joback_records| IdealGasContributions::Joback(Joback::new(joback_records)),
+                |joback_records| {
+                    IdealGasContributions::IdealGasModel(IdealGasModel::joback(joback_records))
+                },
             ),
         }
     }
@@ -115,16 +136,25 @@ impl EquationOfState for PcSaft {
     }
 
     fn subset(&self, component_list: &[usize]) -> Self {
-        Self::with_options(
+        let mut eos = Self::with_options(
             Arc::new(self.parameters.subset(component_list)),
             self.options,
-        )
+        );
+        if let IdealGasContributions::IdealGasModel(ideal_gas) = &self.ideal_gas {
+            eos.ideal_gas = IdealGasContributions::IdealGasModel(ideal_gas.subset(component_list));
+        }
+        eos
     }
 
     fn compute_max_density(&self, moles: &Array1<f64>) -> f64 {
-        self.options.max_eta * moles.sum()
-            / (FRAC_PI_6 * &self.parameters.m * self.parameters.sigma.mapv(|v| v.powi(3)) * moles)
-                .sum()
+        self.options.max_density.apply(
+            self.options.max_eta * moles.sum()
+                / (FRAC_PI_6
+                    * &self.parameters.m
+                    * self.parameters.sigma.mapv(|v| v.powi(3))
+                    * moles)
+                    .sum(),
+        )
     }
 
     fn residual(&self) -> &[Box<dyn HelmholtzEnergy>] {
@@ -134,7 +164,53 @@ impl EquationOfState for PcSaft {
     fn ideal_gas(&self) -> &dyn IdealGasContribution {
         match &self.ideal_gas {
             IdealGasContributions::QSPR(qspr) => qspr,
-            IdealGasContributions::Joback(joback) => joback,
+            IdealGasContributions::IdealGasModel(ideal_gas) => ideal_gas,
+        }
+    }
+}
+
+impl WithIdealGas for PcSaft {
+    fn with_ideal_gas(mut self, ideal_gas: IdealGasModel) -> EosResult<Self> {
+        ideal_gas.validate_components(self.components())?;
+        self.ideal_gas = IdealGasContributions::IdealGasModel(ideal_gas);
+        Ok(self)
+    }
+}
+
+// Auxiliary structure used to (de)serialize the equation of state.
+#[derive(Serialize, Deserialize)]
+struct PcSaftJSON {
+    parameters: ParameterSet<PcSaftRecord, JobackRecord, PcSaftBinaryRecord>,
+    options: PcSaftOptions,
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    ideal_gas: Option<IdealGasModel>,
+}
+
+impl Serialize for PcSaft {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let ideal_gas = match &self.ideal_gas {
+            IdealGasContributions::QSPR(_) => None,
+            IdealGasContributions::IdealGasModel(ideal_gas) => Some(ideal_gas.clone()),
+        };
+        PcSaftJSON {
+            parameters: self.parameters.parameter_set(),
+            options: self.options,
+            ideal_gas,
+        }
+        .serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for PcSaft {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let eos = PcSaftJSON::deserialize(deserializer)?;
+        let parameters =
+            PcSaftParameters::from_parameter_set(eos.parameters).map_err(D::Error::custom)?;
+        let pcsaft = Self::with_options(Arc::new(parameters), eos.options);
+        match eos.ideal_gas {
+            Some(ideal_gas) => pcsaft.with_ideal_gas(ideal_gas).map_err(D::Error::custom),
+            None => Ok(pcsaft),
         }
     }
 }
@@ -380,6 +456,56 @@ mod tests {
         );
     }
 
+    #[test]
+    fn with_ideal_gas() {
+        use feos_core::ideal_gas::{DipprRecord, IdealGasRecord};
+        let propane = IdealGasRecord::Dippr(DipprRecord::eq107([
+            0.5192e5, 1.9245e5, 1.6265e3, 1.168e5, 723.6,
+        ]));
+        let butane = IdealGasRecord::Dippr(DipprRecord::eq107([
+            0.7134e5, 2.43e5, 1.63e3, 1.5033e5, 730.42,
+        ]));
+        let ideal_gas = IdealGasModel::new(vec![propane, butane]);
+        let e = Arc::new(
+            PcSaft::new(propane_butane_parameters())
+                .with_ideal_gas(ideal_gas.clone())
+                .unwrap(),
+        );
+        let t = 300.0 * KELVIN;
+        let v = 1e-3 * METER.powi(3);
+        let n = arr1(&[1.0, 2.0]) * MOL;
+        let s = State::new_nvt(&e, t, v, &n).unwrap();
+        let reference = State::new_nvt(
+            &Arc::new(PcSaft::new(propane_butane_parameters())),
+            t,
+            v,
+            &n,
+        )
+        .unwrap();
+        assert_relative_eq!(
+            s.c_p(Contributions::IdealGas),
+            ideal_gas.c_p(t, &s.molefracs).unwrap(),
+            max_relative = 1e-10
+        );
+        assert_relative_eq!(
+            s.helmholtz_energy(Contributions::ResidualNvt),
+            reference.helmholtz_energy(Contributions::ResidualNvt),
+            max_relative = 1e-10
+        );
+
+        // the ideal gas contribution is kept for subsets
+        let e1 = Arc::new(e.subset(&[1]));
+        let s1 = State::new_nvt(&e1, t, v, &(arr1(&[1.0]) * MOL)).unwrap();
+        assert_relative_eq!(
+            s1.c_p(Contributions::IdealGas),
+            ideal_gas.subset(&[1]).c_p(t, &arr1(&[1.0])).unwrap(),
+            max_relative = 1e-10
+        );
+        assert!(PcSaft::new(propane_parameters())
+            .with_ideal_gas(ideal_gas)
+            .is_err());
+    }
+
     #[test]
     fn hard_sphere() {
         let hs = HardSphere::new(&propane_parameters());
@@ -537,6 +663,43 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn property_provider() -> EosResult<()> {
+        let e = Arc::new(PcSaft::new(propane_parameters()));
+        let t = 300.0 * KELVIN;
+        let p = BAR;
+        let x = arr1(&[1.0]);
+        let s = State::new_npt(&e, t, p, &(x.clone() * MOL), DensityInitialization::None)?;
+        let properties = e.properties(t, p, &x)?;
+        assert_relative_eq!(properties.density, s.mass_density(), max_relative = 1e-12);
+        assert_relative_eq!(
+            properties.enthalpy,
+            s.specific_enthalpy(Contributions::Total),
+            max_relative = 1e-12
+        );
+        assert_relative_eq!(
+            properties.c_p,
+            s.c_p(Contributions::Total) / s.total_molar_weight(),
+            max_relative = 1e-12
+        );
+        assert_relative_eq!(e.viscosity(t, p, &x)?, s.viscosity()?, max_relative = 1e-12);
+
+        let temperature = arr1(&[300.0, 250.0]) * KELVIN;
+        let pressure = arr1(&[1.0, 10.0]) * BAR;
+        let molefracs = ndarray::arr2(&[[1.0], [1.0]]);
+        let batch = e.properties_batch(&temperature, &pressure, &molefracs)?;
+        assert_eq!(batch.len(), 2);
+        assert_relative_eq!(batch[0].as_ref().unwrap().density, properties.density);
+        assert_relative_eq!(
+            batch[1].as_ref().unwrap().density,
+            e.density(250.0 * KELVIN, 10.0 * BAR, &x)?
+        );
+        assert!(e.density(t, p, &arr1(&[0.5, 0.5])).is_err());
+        let viscosity = e.viscosity_batch(&temperature, &pressure, &molefracs)?;
+        assert_relative_eq!(*viscosity[0].as_ref().unwrap(), s.viscosity()?);
+        Ok(())
+    }
+
     #[test]
     fn diffusion() -> EosResult<()> {
         let e = Arc::new(PcSaft::new(propane_parameters()));
@@ -559,4 +722,35 @@ mod tests {
         );
         Ok(())
     }
+
+    #[test]
+    fn serde_round_trip() -> EosResult<()> {
+        let e = Arc::new(PcSaft::new(propane_butane_parameters()));
+        let t = 300.0 * KELVIN;
+        let n = arr1(&[0.3, 0.7]) * MOL;
+        let s = State::new_npt(&e, t, BAR, &n, DensityInitialization::Liquid)?;
+        let json = serde_json::to_string(&s).unwrap();
+        let s_json: State<PcSaft> = serde_json::from_str(&json).unwrap();
+        assert_relative_eq!(
+            s.pressure(Contributions::Total),
+            s_json.pressure(Contributions::Total),
+            max_relative = 1e-12
+        );
+        assert_relative_eq!(
+            s.molar_enthalpy(Contributions::Total),
+            s_json.molar_enthalpy(Contributions::Total),
+            max_relative = 1e-12
+        );
+
+        let vle = PhaseEquilibrium::pure(&Arc::new(e.subset(&[0])), t, None, Default::default())?;
+        let json = serde_json::to_string(&vle).unwrap();
+        let vle_json: PhaseEquilibrium<PcSaft, 2> = serde_json::from_str(&json).unwrap();
+        assert_relative_eq!(
+            vle.liquid().density,
+            vle_json.liquid().density,
+            max_relative = 1e-12
+        );
+        assert!(serde_json::from_str::<PhaseEquilibrium<PcSaft, 3>>(&json).is_err());
+        Ok(())
+    }
 }
@@ -3,6 +3,7 @@ use crate::hard_sphere::HardSphereProperties;
 use feos_core::{HelmholtzEnergyDual, StateHD};
 use ndarray::prelude::*;
 use num_dual::DualNum;
+use serde::{Deserialize, Serialize};
 use std::f64::consts::{FRAC_PI_3, PI};
 use std::fmt;
 use std::sync::Arc;
@@ -80,6 +81,10 @@ pub const CDQ: [[f64; 2]; 3] = [
 
 pub const PI_SQ_43: f64 = 4.0 * PI * FRAC_PI_3;
 
+// Self-consistent renormalization of induced dipoles
+const MAX_ITER_POLARIZABLE: usize = 50;
+const TOL_POLARIZABLE: f64 = 1e-12;
+
 pub struct MeanSegmentNumbers {
     pub mij1: Array2<f64>,
     pub mij2: Array2<f64>,
@@ -94,11 +99,15 @@ pub enum Multipole {
 
 impl MeanSegmentNumbers {
     pub fn new(parameters: &PcSaftParameters, polarity: Multipole) -> Self {
-        let (npoles, comp) = match polarity {
-            Multipole::Dipole => (parameters.ndipole, &parameters.dipole_comp),
-            Multipole::Quadrupole => (parameters.nquadpole, &parameters.quadpole_comp),
+        let comp = match polarity {
+            Multipole::Dipole => &parameters.dipole_comp,
+            Multipole::Quadrupole => &parameters.quadpole_comp,
         };
+        Self::from_components(parameters, comp.as_slice().unwrap())
+    }
 
+    pub fn from_components(parameters: &PcSaftParameters, comp: &[usize]) -> Self {
+        let npoles = comp.len();
         let mut mij1 = Array2::zeros((npoles, npoles));
         let mut mij2 = Array2::zeros((npoles, npoles));
         let mut mijk1 = Array3::zeros((npoles, npoles, npoles));
@@ -233,6 +242,150 @@ impl fmt::Display for Dipole {
     }
 }
 
+/// Dipole contribution including induced dipoles of polarizable components.
+///
+/// The effective (squared) dipole moments are obtained from the mean-field
+/// renormalization
+/// $$\tilde\mu_i^2=\mu_i^2+\alpha_i^2\frac{8\pi}{3}\sum_j\frac{\rho_j\tilde\mu_j^2}{\sigma_{ij}^3}$$
+/// that is solved by successive substitution. The effective dipole moments
+/// replace the permanent ones in the second and third order terms and the
+/// dipole-induced dipole interaction is added to the second order term.
+pub struct PolarizableDipole {
+    pub parameters: Arc<PcSaftParameters>,
+    comp: Vec<usize>,
+    mean_segment_numbers: MeanSegmentNumbers,
+}
+
+impl PolarizableDipole {
+    pub fn new(parameters: &Arc<PcSaftParameters>) -> Self {
+        let comp: Vec<_> = (0..parameters.m.len())
+            .filter(|&i| parameters.mu2[i] > 0.0 || parameters.alpha[i] > 0.0)
+            .collect();
+        let mean_segment_numbers = MeanSegmentNumbers::from_components(parameters, &comp);
+        Self {
+            parameters: parameters.clone(),
+            comp,
+            mean_segment_numbers,
+        }
+    }
+
+    /// Squared effective dipole moments in units of K Å³.
+    fn effective_dipole_moments<D: DualNum<f64>>(&self, partial_density: &Array1<D>) -> Vec<D> {
+        let p = &self.parameters;
+        let mu2_k: Vec<_> = self
+            .comp
+            .iter()
+            .map(|&i| p.mu2[i] * p.m[i] * p.sigma[i].powi(3) * p.epsilon_k[i])
+            .collect();
+        let mut mu2_eff: Vec<D> = mu2_k.iter().map(|&mu2| D::from(mu2)).collect();
+        for _ in 0..MAX_ITER_POLARIZABLE {
+            let mu2_new: Vec<D> = self
+                .comp
+                .iter()
+                .zip(&mu2_k)
+                .map(|(&di, &mu2)| {
+                    let field: D = self
+                        .comp
+                        .iter()
+                        .zip(&mu2_eff)
+                        .map(|(&dj, &mu2_j)| {
+                            partial_density[dj] * mu2_j / p.sigma_ij[[di, dj]].powi(3)
+                        })
+                        .sum();
+                    field * (p.alpha[di].powi(2) * 8.0 * FRAC_PI_3) + mu2
+                })
+                .collect();
+            let converged = mu2_new
+                .iter()
+                .zip(&mu2_eff)
+                .all(|(new, old)| (new.re() - old.re()).abs() <= TOL_POLARIZABLE * new.re());
+            mu2_eff = mu2_new;
+            if converged {
+                break;
+            }
+        }
+        mu2_eff
+    }
+}
+
+impl<D: DualNum<f64>> HelmholtzEnergyDual<D> for PolarizableDipole {
+    fn helmholtz_energy(&self, state: &StateHD<D>) -> D {
+        let m = &self.mean_segment_numbers;
+        let p = &self.parameters;
+        let comp = &self.comp;
+        let n = comp.len();
+
+        let t_inv = state.temperature.inv();
+        let eps_ij_t = p.e_k_ij.mapv(|v| t_inv * v);
+        let sig_ij_3 = p.sigma_ij.mapv(|v| v.powi(3));
+        let rho = &state.partial_density;
+        let mu2_eff = self.effective_dipole_moments(rho);
+        let mu2_term: Vec<D> = comp
+            .iter()
+            .zip(&mu2_eff)
+            .map(|(&i, &mu2)| t_inv * mu2 / p.m[i])
+            .collect();
+
+        let r = p.hs_diameter(state.temperature) * 0.5;
+        let eta = (rho * &p.m * &r * &r * &r).sum() * 4.0 * FRAC_PI_3;
+        let eta2 = eta * eta;
+        let etas = [D::one(), eta, eta2, eta2 * eta, eta2 * eta2];
+
+        let mut phi2 = D::zero();
+        let mut phi3 = D::zero();
+        for i in 0..n {
+            let di = comp[i];
+            for j in i..n {
+                let dj = comp[j];
+                let c = if i == j { 1.0 } else { 2.0 };
+                let induction = t_inv * (mu2_eff[i] * p.alpha[dj] + mu2_eff[j] * p.alpha[di])
+                    / (p.m[di] * p.m[dj])
+                    * 1.5;
+                phi2 -= rho[di]
+                    * rho[dj]
+                    * (mu2_term[i] * mu2_term[j] + induction)
+                    * pair_integral_ij(
+                        m.mij1[[i, j]],
+                        m.mij2[[i, j]],
+                        &etas,
+                        &AD,
+                        &BD,
+                        eps_ij_t[[di, dj]],
+                    )
+                    / sig_ij_3[[di, dj]]
+                    * c;
+                for k in j..n {
+                    let dk = comp[k];
+                    let c = if i == k {
+                        1.0
+                    } else if i == j || j == k {
+                        3.0
+                    } else {
+                        6.0
+                    };
+                    phi3 -= rho[di] * rho[dj] * rho[dk] * mu2_term[i] * mu2_term[j] * mu2_term[k]
+                        / (p.sigma_ij[[di, dj]] * p.sigma_ij[[di, dk]] * p.sigma_ij[[dj, dk]])
+                        * triplet_integral_ijk(m.mijk1[[i, j, k]], m.mijk2[[i, j, k]], &etas, &CD)
+                        * c;
+                }
+            }
+        }
+        phi2 *= PI;
+        phi3 *= PI_SQ_43;
+        let mut result = phi2 * phi2 / (phi2 - phi3) * state.volume;
+        if result.re().is_nan() {
+            result = phi2 * state.volume
+        }
+        result
+    }
+}
+
+impl fmt::Display for PolarizableDipole {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Polarizable Dipole")
+    }
+}
+
 pub struct Quadrupole {
     pub parameters: Arc<PcSaftParameters>,
 }
@@ -312,7 +465,7 @@ impl fmt::Display for Quadrupole {
 }
 
 /// Different combination rules used in the dipole-quadrupole contribution.
-#[derive(Clone, Copy)]
+#[derive(Clone, Copy, Serialize, Deserialize)]
 #[cfg_attr(feature = "python", pyo3::pyclass)]
 pub enum DQVariants {
     DQ35,
@@ -475,6 +628,48 @@ mod tests {
         assert_relative_eq!(a, -1.4126308106201688, epsilon = 1e-10);
     }
 
+    #[test]
+    fn test_polarizable_dipole_without_polarizability() {
+        let parameters = Arc::new(dme_parameters());
+        let dp = Dipole {
+            parameters: parameters.clone(),
+        };
+        let pdp = PolarizableDipole::new(&parameters);
+        let s = StateHD::new(350.0, 1000.0, arr1(&[1.0]));
+        assert_relative_eq!(
+            pdp.helmholtz_energy(&s),
+            dp.helmholtz_energy(&s),
+            max_relative = 1e-12
+        );
+    }
+
+    #[test]
+    fn test_polarizable_dipole_mix() {
+        let p = dme_co2_parameters();
+        let mut pure_records = p.pure_records.clone();
+        pure_records[0].model_record.alpha = Some(5.2);
+        pure_records[1].model_record.alpha = Some(2.9);
+        let parameters = Arc::new(PcSaftParameters::from_records(
+            pure_records,
+            p.binary_records.clone(),
+        ));
+        let dp = Dipole {
+            parameters: parameters.clone(),
+        };
+        let pdp = PolarizableDipole::new(&parameters);
+        assert_eq!(pdp.comp, vec![0, 1]);
+
+        let s = StateHD::new(350.0, 100.0, arr1(&[1.0, 1.0]));
+        let mu2_eff = pdp.effective_dipole_moments(&s.partial_density);
+        let mu2 = parameters.mu2[0]
+            * parameters.m[0]
+            * parameters.sigma[0].powi(3)
+            * parameters.epsilon_k[0];
+        assert!(mu2_eff[0] > mu2);
+        assert!(mu2_eff[1] > 0.0);
+        assert!(pdp.helmholtz_energy(&s) < dp.helmholtz_energy(&s));
+    }
+
     #[test]
     fn test_quadrupolar_contribution() {
         let qp = Quadrupole {
@@ -0,0 +1,258 @@
+use super::dispersion::dispersion_helmholtz_energy_density;
+use super::hard_chain::hard_chain_helmholtz_energy_density;
+use super::PcSaft;
+use crate::hard_sphere::{bmcsl_helmholtz_energy_density, packing_fractions};
+use crate::pcsaft::parameters::segment_diameter;
+use crate::pcsaft::PcSaftParameters;
+use feos_core::parameter::Parameter;
+use feos_core::{EosError, EosResult, ParameterSensitivity, StateHD};
+use ndarray::{Array1, Array2};
+use num_dual::DualNum;
+
+impl PcSaftParameters {
+    /// The names of the model parameters for which sensitivities are calculated.
+    pub(crate) fn sensitivity_parameter_names(&self) -> Vec<String> {
+        let n = self.m.len();
+        let mut names: Vec<_> = ["m", "sigma", "epsilon_k"]
+            .iter()
+            .flat_map(|p| (0..n).map(move |i| format!("{}_{}", p, i)))
+            .collect();
+        for i in 0..n {
+            for j in i + 1..n {
+                names.push(format!("k_{}{}", i, j));
+            }
+        }
+        names
+    }
+
+    /// The values of the model parameters for which sensitivities are calculated.
+    pub(crate) fn sensitivity_parameter_values(&self) -> Array1<f64> {
+        let n = self.m.len();
+        let mut values: Vec<_> = self
+            .m
+            .iter()
+            .chain(self.sigma.iter())
+            .chain(self.epsilon_k.iter())
+            .copied()
+            .collect();
+        for i in 0..n {
+            for j in i + 1..n {
+                values.push(self.k_ij[[i, j]]);
+            }
+        }
+        Array1::from_vec(values)
+    }
+
+    /// The parameter set with the model parameters replaced by the given values
+    /// in the order of [sensitivity_parameter_names](Self::sensitivity_parameter_names).
+    pub(crate) fn with_sensitivity_parameter_values(&self, values: &[f64]) -> EosResult<Self> {
+        let n = self.m.len();
+        if values.len() != 3 * n + n * (n - 1) / 2 {
+            return Err(EosError::Error(format!(
+                "Expected {} parameters, got {}.",
+                3 * n + n * (n - 1) / 2,
+                values.len()
+            )));
+        }
+        let (pure_records, binary_records) = self.records();
+        let mut pure_records = pure_records.to_vec();
+        let mut binary_records = binary_records.clone();
+        for (i, record) in pure_records.iter_mut().enumerate() {
+            record.model_record.m = values[i];
+            record.model_record.sigma = values[n + i];
+            record.model_record.epsilon_k = values[2 * n + i];
+        }
+        let mut k_ij = values[3 * n..].iter();
+        for i in 0..n {
+            for j in i + 1..n {
+                let k = *k_ij.next().unwrap();
+                binary_records[(i, j)].k_ij = k;
+                binary_records[(j, i)].k_ij = k;
+            }
+        }
+        let parameters = Self::from_records_with_metadata(
+            pure_records,
+            binary_records,
+            self.binary_metadata.clone(),
+        );
+        Ok(if self.combining_rule == parameters.combining_rule {
+            parameters
+        } else {
+            parameters.with_combining_rule(self.combining_rule)
+        })
+    }
+}
+
+/// The parameters are ordered as $m_i$, $\sigma_i$, $\varepsilon_i/k_\mathrm{B}$
+/// for all components, followed by the binary interaction parameters $k_{ij}$
+/// with $i<j$.
+///
+/// The Helmholtz energy is evaluated with the same hard-sphere, hard-chain
+/// and dispersion terms as the equation of state, only the parameters that
+/// are precomputed there are combined from the dual parameters instead.
+/// Sensitivities are only available for non-polar and non-associating components.
+impl ParameterSensitivity for PcSaft {
+    fn parameter_names(&self) -> Vec<String> {
+        self.parameters.sensitivity_parameter_names()
+    }
+
+    fn parameter_values(&self) -> Array1<f64> {
+        self.parameters.sensitivity_parameter_values()
+    }
+
+    fn parametric_residual<D: DualNum<f64>>(
+        &self,
+        parameters: &[D],
+        state: &StateHD<D>,
+    ) -> EosResult<D> {
+        let p = &self.parameters;
+        if p.ndipole > 0 || p.nquadpole > 0 || !p.association.assoc_comp.is_empty() {
+            return Err(EosError::Error(String::from(
+                "Parameter sensitivities are only available for non-polar and non-associating components.",
+            )));
+        }
+        let n = p.m.len();
+        if parameters.len() != 3 * n + n * (n - 1) / 2 {
+            return Err(EosError::Error(format!(
+                "Expected {} parameters, got {}.",
+                3 * n + n * (n - 1) / 2,
+                parameters.len()
+            )));
+        }
+        let (m, sigma, epsilon_k) = (
+            &parameters[..n],
+            &parameters[n..2 * n],
+            &parameters[2 * n..3 * n],
+        );
+
+        // combining rules
+        let mut k_ij = parameters[3 * n..].iter();
+        let mut sigma_ij = Array2::from_elem((n, n), D::zero());
+        let mut epsilon_k_ij = Array2::from_elem((n, n), D::zero());
+        for i in 0..n {
+            for j in i..n {
+                let (s, e) = if i == j {
+                    (sigma[i], epsilon_k[i])
+                } else {
+                    let (s, e) = p
+                        .combining_rule
+                        .apply([sigma[i], sigma[j]], [epsilon_k[i], epsilon_k[j]]);
+                    (
+                        s * (1.0 - p.l_ij[[i, j]]),
+                        e * (-*k_ij.next().unwrap() + 1.0),
+                    )
+                };
+                epsilon_k_ij[[i, j]] = e;
+                epsilon_k_ij[[j, i]] = e;
+                sigma_ij[[i, j]] = s;
+                sigma_ij[[j, i]] = s;
+            }
+        }
+
+        // temperature dependent segment diameter and packing fractions
+        let t_inv = state.temperature.recip();
+        let rho = &state.partial_density;
+        let m = Array1::from_vec(m.to_vec());
+        let d = Array1::from_shape_fn(n, |i| segment_diameter(sigma[i], epsilon_k[i], t_inv));
+        let geometry_coefficients = [m.clone(), m.clone(), m.clone(), m.clone()];
+        let component_index = Array1::from_shape_fn(n, |i| i);
+        let zeta = packing_fractions(
+            &d,
+            &geometry_coefficients,
+            &component_index,
+            rho,
+            [0, 1, 2, 3],
+        );
+        let zeta_x = packing_fractions(
+            &d,
+            &geometry_coefficients,
+            &component_index,
+            &state.molefracs,
+            [2, 3],
+        );
+
+        // mixture densities, crosswise interactions of all segments on all chains
+        let mut rho1mix = D::zero();
+        let mut rho2mix = D::zero();
+        for i in 0..n {
+            for j in 0..n {
+                let eps_ij_t = epsilon_k_ij[[i, j]] * t_inv;
+                let c_ij = rho[i] * rho[j] * m[i] * m[j] * sigma_ij[[i, j]].powi(3) * eps_ij_t;
+                rho1mix += c_ij;
+                rho2mix += c_ij * eps_ij_t;
+            }
+        }
+        let m_mix = (&state.molefracs * &m).sum();
+
+        let a_hs = bmcsl_helmholtz_energy_density(zeta, zeta_x[0] / zeta_x[1]);
+        let a_hc = hard_chain_helmholtz_energy_density(&m, &d, rho, [zeta[2], zeta[3]]);
+        let a_disp = dispersion_helmholtz_energy_density(m_mix, zeta[3], rho1mix, rho2mix);
+        Ok((a_hs + a_hc + a_disp) * state.volume)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::pcsaft::parameters::utils::propane_butane_parameters;
+    use approx::assert_relative_eq;
+    use feos_core::{Contributions, EquationOfState, State};
+    use ndarray::arr1;
+    use quantity::si::*;
+    use std::sync::Arc;
+
+    #[test]
+    fn helmholtz_energy() -> EosResult<()> {
+        let eos = PcSaft::new(propane_butane_parameters());
+        let state = StateHD::new(300.0, 1000.0, arr1(&[2.0, 3.0]));
+        let parameters = eos.parameter_values();
+        assert_eq!(eos.parameter_names().len(), parameters.len());
+        assert_relative_eq!(
+            eos.parametric_residual(parameters.as_slice().unwrap(), &state)?,
+            eos.evaluate_residual(&state),
+            max_relative = 1e-12
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn with_parameter_values() -> EosResult<()> {
+        let parameters = propane_butane_parameters();
+        let mut values = parameters.sensitivity_parameter_values();
+        values[3] = 3.7;
+        values[6] = 0.02;
+        let perturbed = parameters.with_sensitivity_parameter_values(values.as_slice().unwrap())?;
+        assert_eq!(perturbed.sigma[1], 3.7);
+        assert_eq!(perturbed.k_ij[[1, 0]], 0.02);
+        assert_eq!(perturbed.sensitivity_parameter_values(), values);
+        assert!(parameters
+            .with_sensitivity_parameter_values(&values.as_slice().unwrap()[..6])
+            .is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn pressure_sensitivities() -> EosResult<()> {
+        let parameters = propane_butane_parameters();
+        let eos = Arc::new(PcSaft::new(parameters.clone()));
+        let t = 300.0 * KELVIN;
+        let v = 1e-3 * METER.powi(3);
+        let n = arr1(&[2.0, 3.0]) * MOL;
+        let dp = State::new_nvt(&eos, t, v, &n)?.pressure_sensitivities()?;
+
+        // finite difference w.r.t. the segment diameter of the first component
+        let h = 1e-6;
+        let pressure = |sigma: f64| -> EosResult<SINumber> {
+            let mut records = parameters.pure_records.clone();
+            records[0].model_record.sigma = sigma;
+            let parameters =
+                PcSaftParameters::from_records(records, parameters.binary_records.clone());
+            let eos = Arc::new(PcSaft::new(Arc::new(parameters)));
+            Ok(State::new_nvt(&eos, t, v, &n)?.pressure(Contributions::Total))
+        };
+        let sigma = parameters.sigma[0];
+        let dp_fd = (pressure(sigma + h)? - pressure(sigma - h)?) / (2.0 * h);
+        assert_relative_eq!(dp.get(2), dp_fd, max_relative = 1e-6);
+        Ok(())
+    }
+}
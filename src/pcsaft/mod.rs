@@ -8,11 +8,15 @@
 mod dft;
 mod eos;
 pub(crate) mod parameters;
+#[cfg(feature = "estimator")]
+mod regression;
 
 #[cfg(feature = "dft")]
 pub use dft::PcSaftFunctional;
 pub use eos::{DQVariants, PcSaft, PcSaftOptions};
 pub use parameters::{PcSaftBinaryRecord, PcSaftParameters, PcSaftRecord};
+#[cfg(feature = "estimator")]
+pub use regression::PcSaftFitParameter;
 
 #[cfg(feature = "python")]
 pub mod python;
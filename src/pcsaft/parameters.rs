@@ -1,9 +1,10 @@
-use crate::association::{AssociationParameters, AssociationRecord};
+use crate::association::{AssociationParameters, AssociationRecord, BinaryAssociationRecord};
 use crate::hard_sphere::{HardSphereProperties, MonomerShape};
 use conv::ValueInto;
 use feos_core::joback::JobackRecord;
 use feos_core::parameter::{
-    FromSegments, FromSegmentsBinary, Parameter, ParameterError, PureRecord,
+    CombiningRule, FromSegments, FromSegmentsBinary, Metadata, Parameter, ParameterError,
+    PureRecord,
 };
 use ndarray::{Array, Array1, Array2};
 use num_dual::DualNum;
@@ -12,6 +13,7 @@ use quantity::si::{JOULE, KB, KELVIN};
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 use std::fmt::Write;
+use std::sync::Arc;
 
 /// PC-SAFT pure-component parameters.
 #[derive(Serialize, Deserialize, Clone, Default)]
@@ -28,10 +30,17 @@ pub struct PcSaftRecord {
     /// Quadrupole moment in units of Debye
     #[serde(skip_serializing_if = "Option::is_none")]
     pub q: Option<f64>,
+    /// Polarizability volume in units of Angstrom³
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub alpha: Option<f64>,
     /// Association parameters
     #[serde(flatten)]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub association_record: Option<AssociationRecord>,
+    /// Additional association site types with individual association parameters
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub association_sites: Vec<AssociationRecord>,
     /// Entropy scaling coefficients for the viscosity
     #[serde(skip_serializing_if = "Option::is_none")]
     pub viscosity: Option<[f64; 4]>,
@@ -63,6 +72,10 @@ impl FromSegments<f64> for PcSaftRecord {
             .iter()
             .filter_map(|(s, n)| s.mu.map(|mu| mu * n))
             .reduce(|a, b| a + b);
+        let alpha = segments
+            .iter()
+            .filter_map(|(s, n)| s.alpha.map(|alpha| alpha * n))
+            .reduce(|a, b| a + b);
         let association_record = segments
             .iter()
             .filter_map(|(s, n)| {
@@ -72,13 +85,32 @@ impl FromSegments<f64> for PcSaftRecord {
                         record.epsilon_k_ab * n,
                         record.na.unwrap_or(1.0) * n,
                         record.nb.unwrap_or(1.0) * n,
+                        record.nc.unwrap_or(0.0) * n,
                     ]
                 })
             })
-            .reduce(|a, b| [a[0] + b[0], a[1] + b[1], a[2] + b[2], a[3] + b[3]])
-            .map(|[kappa_ab, epsilon_k_ab, na, nb]| {
-                AssociationRecord::new(kappa_ab, epsilon_k_ab, Some(na), Some(nb))
+            .reduce(|a, b| {
+                [
+                    a[0] + b[0],
+                    a[1] + b[1],
+                    a[2] + b[2],
+                    a[3] + b[3],
+                    a[4] + b[4],
+                ]
+            })
+            .map(|[kappa_ab, epsilon_k_ab, na, nb, nc]| {
+                AssociationRecord::new(
+                    kappa_ab,
+                    epsilon_k_ab,
+                    Some(na),
+                    Some(nb),
+                    (nc > 0.0).then_some(nc),
+                )
             });
+        let association_sites = segments
+            .iter()
+            .flat_map(|(s, n)| s.association_sites.iter().map(|a| a.scale_sites(*n)))
+            .collect();
 
         // entropy scaling
         let mut viscosity = if segments
@@ -140,7 +172,9 @@ impl FromSegments<f64> for PcSaftRecord {
             epsilon_k: epsilon_k / m,
             mu,
             q,
+            alpha,
             association_record,
+            association_sites,
             viscosity,
             diffusion,
             thermal_conductivity,
@@ -155,7 +189,9 @@ impl FromSegments<usize> for PcSaftRecord {
         let dipole_segments: usize = segments.iter().filter_map(|(s, n)| s.mu.map(|_| n)).sum();
         let assoc_segments: usize = segments
             .iter()
-            .filter_map(|(s, n)| s.association_record.map(|_| n))
+            .filter_map(|(s, n)| {
+                (s.association_record.is_some() || !s.association_sites.is_empty()).then_some(n)
+            })
             .sum();
         if quadpole_segments + dipole_segments + assoc_segments > 1 {
             return Err(ParameterError::IncompatibleParameters(format!(
@@ -182,9 +218,22 @@ impl std::fmt::Display for PcSaftRecord {
         if let Some(n) = &self.q {
             write!(f, ", q={}", n)?;
         }
+        if let Some(n) = &self.alpha {
+            write!(f, ", alpha={}", n)?;
+        }
         if let Some(n) = &self.association_record {
             write!(f, ", association_record={}", n)?;
         }
+        if !self.association_sites.is_empty() {
+            write!(f, ", association_sites=[")?;
+            for (i, a) in self.association_sites.iter().enumerate() {
+                if i > 0 {
+                    write!(f, ", ")?;
+                }
+                write!(f, "{}", a)?;
+            }
+            write!(f, "]")?;
+        }
         if let Some(n) = &self.viscosity {
             write!(f, ", viscosity={:?}", n)?;
         }
@@ -215,7 +264,7 @@ impl PcSaftRecord {
     ) -> PcSaftRecord {
         let association_record = match (kappa_ab, epsilon_k_ab) {
             (Some(kappa_ab), Some(epsilon_k_ab)) => {
-                Some(AssociationRecord::new(kappa_ab, epsilon_k_ab, na, nb))
+                Some(AssociationRecord::new(kappa_ab, epsilon_k_ab, na, nb, None))
             }
             (None, None) => None,
             _ => {
@@ -228,7 +277,9 @@ impl PcSaftRecord {
             epsilon_k,
             mu,
             q,
+            alpha: None,
             association_record,
+            association_sites: Vec::new(),
             viscosity,
             diffusion,
             thermal_conductivity,
@@ -236,14 +287,28 @@ impl PcSaftRecord {
     }
 }
 
+/// PC-SAFT binary interaction parameters.
 #[derive(Serialize, Deserialize, Clone, Default)]
 pub struct PcSaftBinaryRecord {
-    k_ij: f64,
+    /// correction to energy parameters
+    pub k_ij: f64,
+    /// correction to diameter
+    #[serde(default)]
+    #[serde(skip_serializing_if = "f64::is_zero")]
+    pub l_ij: f64,
+    /// Cross-association parameters
+    #[serde(flatten)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub association: Option<BinaryAssociationRecord>,
 }
 
 impl From<f64> for PcSaftBinaryRecord {
     fn from(k_ij: f64) -> Self {
-        Self { k_ij }
+        Self {
+            k_ij,
+            l_ij: 0.0,
+            association: None,
+        }
     }
 }
 
@@ -255,17 +320,43 @@ impl From<PcSaftBinaryRecord> for f64 {
 
 impl<T: Copy + ValueInto<f64>> FromSegmentsBinary<T> for PcSaftBinaryRecord {
     fn from_segments_binary(segments: &[(Self, T, T)]) -> Result<Self, ParameterError> {
-        let (k_ij, n) = segments.iter().fold((0.0, 0.0), |(k_ij, n), (br, n1, n2)| {
-            let nab = (*n1).value_into().unwrap() * (*n2).value_into().unwrap();
-            (k_ij + br.k_ij * nab, n + nab)
-        });
-        Ok(Self { k_ij: k_ij / n })
+        let (k_ij, l_ij, n) =
+            segments
+                .iter()
+                .fold((0.0, 0.0, 0.0), |(k_ij, l_ij, n), (br, n1, n2)| {
+                    let nab = (*n1).value_into().unwrap() * (*n2).value_into().unwrap();
+                    (k_ij + br.k_ij * nab, l_ij + br.l_ij * nab, n + nab)
+                });
+        let association = segments
+            .iter()
+            .filter_map(|(br, n1, n2)| {
+                br.association.map(|a| {
+                    let nab = (*n1).value_into().unwrap() * (*n2).value_into().unwrap();
+                    [a.kappa_ab * nab, a.epsilon_k_ab * nab, nab]
+                })
+            })
+            .reduce(|a, b| [a[0] + b[0], a[1] + b[1], a[2] + b[2]])
+            .map(|[kappa_ab, epsilon_k_ab, n]| {
+                BinaryAssociationRecord::new(kappa_ab / n, epsilon_k_ab / n)
+            });
+        Ok(Self {
+            k_ij: k_ij / n,
+            l_ij: l_ij / n,
+            association,
+        })
     }
 }
 
 impl std::fmt::Display for PcSaftBinaryRecord {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "PcSaftBinaryRecord(k_ij={})", self.k_ij)
+        write!(f, "PcSaftBinaryRecord(k_ij={}", self.k_ij)?;
+        if !self.l_ij.is_zero() {
+            write!(f, ", l_ij={}", self.l_ij)?;
+        }
+        if let Some(association) = &self.association {
+            write!(f, ", association={}", association)?;
+        }
+        write!(f, ")")
     }
 }
 
@@ -279,11 +370,16 @@ pub struct PcSaftParameters {
     pub q: Array1<f64>,
     pub mu2: Array1<f64>,
     pub q2: Array1<f64>,
+    pub alpha: Array1<f64>,
     pub association: AssociationParameters,
     pub k_ij: Array2<f64>,
+    pub l_ij: Array2<f64>,
     pub sigma_ij: Array2<f64>,
     pub epsilon_k_ij: Array2<f64>,
     pub e_k_ij: Array2<f64>,
+    pub m_sigma3_epsilon_k_ij: Array2<f64>,
+    pub m_sigma3_epsilon_k2_ij: Array2<f64>,
+    pub combining_rule: CombiningRule,
     pub ndipole: usize,
     pub nquadpole: usize,
     pub dipole_comp: Array1<usize>,
@@ -293,6 +389,7 @@ pub struct PcSaftParameters {
     pub thermal_conductivity: Option<Array2<f64>>,
     pub pure_records: Vec<PureRecord<PcSaftRecord, JobackRecord>>,
     pub binary_records: Array2<PcSaftBinaryRecord>,
+    pub binary_metadata: Array2<Option<Metadata>>,
     pub joback_records: Option<Vec<JobackRecord>>,
 }
 
@@ -313,6 +410,7 @@ impl Parameter for PcSaftParameters {
         let mut epsilon_k = Array::zeros(n);
         let mut mu = Array::zeros(n);
         let mut q = Array::zeros(n);
+        let mut alpha = Array::zeros(n);
         let mut association_records = Vec::with_capacity(n);
         let mut viscosity = Vec::with_capacity(n);
         let mut diffusion = Vec::with_capacity(n);
@@ -328,7 +426,14 @@ impl Parameter for PcSaftParameters {
             epsilon_k[i] = r.epsilon_k;
             mu[i] = r.mu.unwrap_or(0.0);
             q[i] = r.q.unwrap_or(0.0);
-            association_records.push(r.association_record);
+            alpha[i] = r.alpha.unwrap_or(0.0);
+            association_records.push(
+                r.association_record
+                    .iter()
+                    .chain(r.association_sites.iter())
+                    .copied()
+                    .collect::<Vec<_>>(),
+            );
             viscosity.push(r.viscosity);
             diffusion.push(r.diffusion);
             thermal_conductivity.push(r.thermal_conductivity);
@@ -354,9 +459,15 @@ impl Parameter for PcSaftParameters {
             .collect();
         let nquadpole = quadpole_comp.len();
 
-        let association = AssociationParameters::new(&association_records, &sigma, None);
+        let binary_association: Vec<_> = binary_records
+            .indexed_iter()
+            .filter_map(|((i, j), br)| br.association.map(|a| ([i, j], a)))
+            .collect();
+        let association =
+            AssociationParameters::new(&association_records, &sigma, &binary_association, None);
 
         let k_ij = binary_records.map(|br| br.k_ij);
+        let l_ij = binary_records.map(|br| br.l_ij);
         let mut epsilon_k_ij = Array::zeros((n, n));
         let mut sigma_ij = Array::zeros((n, n));
         let mut e_k_ij = Array::zeros((n, n));
@@ -364,10 +475,16 @@ impl Parameter for PcSaftParameters {
             for j in 0..n {
                 e_k_ij[[i, j]] = (epsilon_k[i] * epsilon_k[j]).sqrt();
                 epsilon_k_ij[[i, j]] = (1.0 - k_ij[[i, j]]) * e_k_ij[[i, j]];
-                sigma_ij[[i, j]] = 0.5 * (sigma[i] + sigma[j]);
+                sigma_ij[[i, j]] = (1.0 - l_ij[[i, j]]) * 0.5 * (sigma[i] + sigma[j]);
             }
         }
 
+        // coefficient matrices of the dispersion double sums
+        let m_sigma3_ij =
+            Array2::from_shape_fn((n, n), |(i, j)| m[i] * m[j] * sigma_ij[[i, j]].powi(3));
+        let m_sigma3_epsilon_k_ij = &m_sigma3_ij * &epsilon_k_ij;
+        let m_sigma3_epsilon_k2_ij = &m_sigma3_epsilon_k_ij * &epsilon_k_ij;
+
         let viscosity_coefficients = if viscosity.iter().any(|v| v.is_none()) {
             None
         } else {
@@ -413,11 +530,16 @@ impl Parameter for PcSaftParameters {
             q,
             mu2,
             q2,
+            alpha,
             association,
             k_ij,
+            l_ij,
             sigma_ij,
             epsilon_k_ij,
             e_k_ij,
+            m_sigma3_epsilon_k_ij,
+            m_sigma3_epsilon_k2_ij,
+            combining_rule: CombiningRule::LorentzBerthelot,
             ndipole,
             nquadpole,
             dipole_comp,
@@ -426,6 +548,7 @@ impl Parameter for PcSaftParameters {
             diffusion: diffusion_coefficients,
             thermal_conductivity: thermal_conductivity_coefficients,
             pure_records,
+            binary_metadata: Array2::from_elem(binary_records.raw_dim(), None),
             binary_records,
             joback_records,
         }
@@ -439,6 +562,20 @@ impl Parameter for PcSaftParameters {
     ) {
         (&self.pure_records, &self.binary_records)
     }
+
+    fn from_records_with_metadata(
+        pure_records: Vec<PureRecord<Self::Pure, Self::IdealGas>>,
+        binary_records: Array2<Self::Binary>,
+        binary_metadata: Array2<Option<Metadata>>,
+    ) -> Self {
+        let mut parameters = Self::from_records(pure_records, binary_records);
+        parameters.binary_metadata = binary_metadata;
+        parameters
+    }
+
+    fn binary_metadata(&self) -> Option<&Array2<Option<Metadata>>> {
+        Some(&self.binary_metadata)
+    }
 }
 
 impl HardSphereProperties for PcSaftParameters {
@@ -447,14 +584,58 @@ impl HardSphereProperties for PcSaftParameters {
     }
 
     fn hs_diameter<D: DualNum<f64>>(&self, temperature: D) -> Array1<D> {
-        let ti = temperature.recip() * -3.0;
+        let t_inv = temperature.recip();
         Array::from_shape_fn(self.sigma.len(), |i| {
-            -((ti * self.epsilon_k[i]).exp() * 0.12 - 1.0) * self.sigma[i]
+            segment_diameter(D::from(self.sigma[i]), D::from(self.epsilon_k[i]), t_inv)
         })
     }
 }
 
+/// The temperature dependent segment diameter
+/// $d=\sigma\left(1-0.12e^{-\frac{3\varepsilon}{kT}}\right)$
+/// for (possibly dual) parameters $\sigma$ and $\varepsilon/k$ and the inverse temperature.
+pub(crate) fn segment_diameter<D: DualNum<f64>>(sigma: D, epsilon_k: D, t_inv: D) -> D {
+    -((t_inv * epsilon_k * -3.0).exp() * 0.12 - 1.0) * sigma
+}
+
 impl PcSaftParameters {
+    /// Replace the combining rule for the cross diameters and energy parameters.
+    ///
+    /// The binary interaction parameters `k_ij` and `l_ij` are applied
+    /// as corrections to the combined parameters.
+    pub fn with_combining_rule(&self, combining_rule: CombiningRule) -> Self {
+        let mut parameters = Self::from_records_with_metadata(
+            self.pure_records.clone(),
+            self.binary_records.clone(),
+            self.binary_metadata.clone(),
+        );
+        let (sigma_ij, e_k_ij) = combining_rule.cross_parameters(&self.sigma, &self.epsilon_k);
+        let sigma_ij = (1.0 - &self.l_ij) * sigma_ij;
+        let epsilon_k_ij = (1.0 - &self.k_ij) * &e_k_ij;
+        let m_sigma3_ij = Array2::from_shape_fn(sigma_ij.raw_dim(), |(i, j)| {
+            self.m[i] * self.m[j] * sigma_ij[[i, j]].powi(3)
+        });
+        parameters.m_sigma3_epsilon_k_ij = &m_sigma3_ij * &epsilon_k_ij;
+        parameters.m_sigma3_epsilon_k2_ij = &parameters.m_sigma3_epsilon_k_ij * &epsilon_k_ij;
+        parameters.sigma_ij = sigma_ij;
+        parameters.epsilon_k_ij = epsilon_k_ij;
+        parameters.e_k_ij = e_k_ij;
+        parameters.combining_rule = combining_rule;
+        parameters
+    }
+
+    /// Rebuild shared parameters if they do not use the given combining rule.
+    pub(crate) fn apply_combining_rule(
+        parameters: Arc<Self>,
+        combining_rule: CombiningRule,
+    ) -> Arc<Self> {
+        if parameters.combining_rule == combining_rule {
+            parameters
+        } else {
+            Arc::new(parameters.with_combining_rule(combining_rule))
+        }
+    }
+
     pub fn to_markdown(&self) -> String {
         let mut output = String::new();
         let o = &mut output;
@@ -469,7 +650,7 @@ impl PcSaftParameters {
             let association = record
                 .model_record
                 .association_record
-                .unwrap_or_else(|| AssociationRecord::new(0.0, 0.0, None, None));
+                .unwrap_or_else(|| AssociationRecord::new(0.0, 0.0, None, None, None));
             write!(
                 o,
                 "\n|{}|{}|{}|{}|{}|{}|{}|{}|{}|{}|{}|",
@@ -511,10 +692,16 @@ impl std::fmt::Display for PcSaftParameters {
             write!(f, "\n\tepsilon_k_ab={}", self.association.epsilon_k_ab)?;
             write!(f, "\n\tna={}", self.association.na)?;
             write!(f, "\n\tnb={}", self.association.nb)?;
+            if !self.association.nc.iter().all(|n| n.is_zero()) {
+                write!(f, "\n\tnc={}", self.association.nc)?;
+            }
         }
         if !self.k_ij.iter().all(|k| k.is_zero()) {
             write!(f, "\n\tk_ij=\n{}", self.k_ij)?;
         }
+        if !self.l_ij.iter().all(|l| l.is_zero()) {
+            write!(f, "\n\tl_ij=\n{}", self.l_ij)?;
+        }
         write!(f, "\n)")
     }
 }
@@ -522,6 +709,7 @@ impl std::fmt::Display for PcSaftParameters {
 #[cfg(test)]
 pub mod utils {
     use super::*;
+    use approx::assert_relative_eq;
     use feos_core::joback::JobackRecord;
     use feos_core::parameter::{BinaryRecord, ChemicalRecord, SegmentRecord};
     use std::sync::Arc;
@@ -750,9 +938,7 @@ pub mod utils {
         let kij = [("CH3", "OH", -0.2), ("CH2", "OH", -0.1)];
         let binary_segment_records = kij
             .iter()
-            .map(|&(id1, id2, k_ij)| {
-                BinaryRecord::new(id1.into(), id2.into(), PcSaftBinaryRecord { k_ij })
-            })
+            .map(|&(id1, id2, k_ij)| BinaryRecord::new(id1.into(), id2.into(), k_ij.into()))
             .collect();
         let params = PcSaftParameters::from_segments(
             vec![propane, ethanol],
@@ -768,4 +954,108 @@ pub mod utils {
 
         Ok(())
     }
+
+    #[test]
+    pub fn test_lij() {
+        let br: PcSaftBinaryRecord =
+            serde_json::from_str(r#"{"k_ij": 0.01}"#).expect("Unable to parse json.");
+        assert_eq!(br.l_ij, 0.0);
+        let br: PcSaftBinaryRecord =
+            serde_json::from_str(r#"{"k_ij": 0.01, "l_ij": 0.02}"#).expect("Unable to parse json.");
+        assert_eq!(br.k_ij, 0.01);
+        assert_eq!(br.l_ij, 0.02);
+
+        let pure_records = propane_butane_parameters().pure_records.clone();
+        let params = PcSaftParameters::new_binary(pure_records, Some(br));
+        let sigma_ij = (1.0 - 0.02) * 0.5 * (params.sigma[0] + params.sigma[1]);
+        assert_eq!(params.l_ij[[0, 1]], 0.02);
+        assert_eq!(params.sigma_ij[[0, 1]], sigma_ij);
+        assert_eq!(params.sigma_ij[[1, 0]], sigma_ij);
+        assert_eq!(params.sigma_ij[[0, 0]], params.sigma[0]);
+    }
+
+    #[test]
+    pub fn test_combining_rule() {
+        let br = PcSaftBinaryRecord {
+            k_ij: 0.01,
+            l_ij: 0.02,
+            association: None,
+        };
+        let pure_records = propane_butane_parameters().pure_records.clone();
+        let params = PcSaftParameters::new_binary(pure_records, Some(br));
+        let params_lb = params.with_combining_rule(CombiningRule::LorentzBerthelot);
+        assert_relative_eq!(params_lb.sigma_ij, params.sigma_ij, max_relative = 1e-14);
+        assert_relative_eq!(
+            params_lb.epsilon_k_ij,
+            params.epsilon_k_ij,
+            max_relative = 1e-14
+        );
+
+        let params_gs = params.with_combining_rule(CombiningRule::GeometricSigma);
+        let sigma_ij = (1.0 - 0.02) * (params.sigma[0] * params.sigma[1]).sqrt();
+        assert_eq!(params_gs.combining_rule, CombiningRule::GeometricSigma);
+        assert_relative_eq!(params_gs.sigma_ij[[0, 1]], sigma_ij, max_relative = 1e-14);
+        assert_eq!(params_gs.sigma_ij[[1, 1]], params.sigma[1]);
+        assert_relative_eq!(
+            params_gs.epsilon_k_ij,
+            params.epsilon_k_ij,
+            max_relative = 1e-14
+        );
+    }
+
+    #[test]
+    pub fn test_json_round_trip() -> Result<(), ParameterError> {
+        let metadata = Metadata::new(Some("10.1021/ie0003887".into()), None, Some("test".into()));
+        let water = water_parameters().pure_records[0]
+            .clone()
+            .with_metadata(metadata.clone());
+        let propane = propane_parameters().pure_records[0].clone();
+        let binary = PcSaftBinaryRecord {
+            k_ij: 0.01,
+            l_ij: 0.02,
+            association: None,
+        };
+        let binary_metadata = Metadata::new(None, Some("2023-05-01".into()), Some("VLE".into()));
+        let params = PcSaftParameters::from_records_with_metadata(
+            vec![water, propane],
+            Array2::from_shape_fn([2, 2], |(i, j)| match i == j {
+                true => PcSaftBinaryRecord::default(),
+                false => binary.clone(),
+            }),
+            Array2::from_shape_fn([2, 2], |(i, j)| match i == j {
+                true => None,
+                false => Some(binary_metadata.clone()),
+            }),
+        );
+
+        let json = params.to_json_str()?;
+        let params_json = PcSaftParameters::from_json_str(&json)?;
+        assert_eq!(params.m, params_json.m);
+        assert_eq!(params.sigma, params_json.sigma);
+        assert_eq!(params.k_ij, params_json.k_ij);
+        assert_eq!(params.l_ij, params_json.l_ij);
+        assert_eq!(
+            params.association.kappa_ab,
+            params_json.association.kappa_ab
+        );
+        assert_eq!(
+            params.joback_records.is_some(),
+            params_json.joback_records.is_some()
+        );
+        assert_eq!(params_json.pure_records[0].metadata, Some(metadata));
+        assert_eq!(params_json.pure_records[1].metadata, None);
+        assert_eq!(
+            params_json.binary_metadata[[0, 1]],
+            Some(binary_metadata.clone())
+        );
+        assert_eq!(
+            params_json.binary_metadata[[1, 0]],
+            Some(binary_metadata.clone())
+        );
+        assert_eq!(params_json.binary_metadata[[0, 0]], None);
+        let subset = params_json.subset(&[1, 0]);
+        assert_eq!(subset.binary_metadata[[0, 1]], Some(binary_metadata));
+        assert_eq!(json, params_json.to_json_str()?);
+        Ok(())
+    }
 }
@@ -1,16 +1,17 @@
 use super::parameters::{PcSaftBinaryRecord, PcSaftParameters, PcSaftRecord};
 use super::DQVariants;
+use crate::association::{BinaryAssociationRecord, PyAssociationRecord};
 use feos_core::joback::JobackRecord;
 use feos_core::parameter::{
-    BinaryRecord, Identifier, IdentifierOption, Parameter, ParameterError, PureRecord,
-    SegmentRecord,
+    BinaryRecord, CombiningRule, Identifier, IdentifierOption, Parameter, ParameterError,
+    PureRecord, SegmentRecord,
 };
 use feos_core::python::joback::PyJobackRecord;
 use feos_core::python::parameter::*;
 use feos_core::*;
 use ndarray::Array2;
 use numpy::{PyArray2, PyReadonlyArray2, ToPyArray};
-use pyo3::exceptions::PyTypeError;
+use pyo3::exceptions::{PyTypeError, PyValueError};
 use pyo3::prelude::*;
 use std::convert::{TryFrom, TryInto};
 use std::sync::Arc;
@@ -18,7 +19,7 @@ use std::sync::Arc;
 /// Create a set of PC-Saft parameters from records.
 #[pyclass(name = "PcSaftRecord")]
 #[pyo3(
-    text_signature = "(m, sigma, epsilon_k, mu=None, q=None, kappa_ab=None, epsilon_k_ab=None, na=None, nb=None, viscosity=None, diffusion=None, thermal_conductivity=None)"
+    text_signature = "(m, sigma, epsilon_k, mu=None, q=None, kappa_ab=None, epsilon_k_ab=None, na=None, nb=None, viscosity=None, diffusion=None, thermal_conductivity=None, nc=None, association_sites=None, alpha=None)"
 )]
 #[derive(Clone)]
 pub struct PyPcSaftRecord(PcSaftRecord);
@@ -39,8 +40,11 @@ impl PyPcSaftRecord {
         viscosity: Option<[f64; 4]>,
         diffusion: Option<[f64; 5]>,
         thermal_conductivity: Option<[f64; 4]>,
+        nc: Option<f64>,
+        association_sites: Option<Vec<PyAssociationRecord>>,
+        alpha: Option<f64>,
     ) -> Self {
-        Self(PcSaftRecord::new(
+        let mut record = PcSaftRecord::new(
             m,
             sigma,
             epsilon_k,
@@ -53,7 +57,14 @@ impl PyPcSaftRecord {
             viscosity,
             diffusion,
             thermal_conductivity,
-        ))
+        );
+        if let Some(association_record) = record.association_record.as_mut() {
+            association_record.nc = nc;
+        }
+        record.association_sites = association_sites
+            .map_or_else(Vec::new, |sites| sites.into_iter().map(|s| s.0).collect());
+        record.alpha = alpha;
+        Self(record)
     }
 
     #[getter]
@@ -81,6 +92,11 @@ impl PyPcSaftRecord {
         self.0.q
     }
 
+    #[getter]
+    fn get_alpha(&self) -> Option<f64> {
+        self.0.alpha
+    }
+
     #[getter]
     fn get_kappa_ab(&self) -> Option<f64> {
         self.0.association_record.map(|a| a.kappa_ab)
@@ -101,6 +117,20 @@ impl PyPcSaftRecord {
         self.0.association_record.and_then(|a| a.nb)
     }
 
+    #[getter]
+    fn get_nc(&self) -> Option<f64> {
+        self.0.association_record.and_then(|a| a.nc)
+    }
+
+    #[getter]
+    fn get_association_sites(&self) -> Vec<PyAssociationRecord> {
+        self.0
+            .association_sites
+            .iter()
+            .map(|&a| PyAssociationRecord(a))
+            .collect()
+    }
+
     #[getter]
     fn get_viscosity(&self) -> Option<[f64; 4]> {
         self.0.viscosity
@@ -126,14 +156,90 @@ impl_json_handling!(PyPcSaftRecord);
 impl_pure_record!(PcSaftRecord, PyPcSaftRecord, JobackRecord, PyJobackRecord);
 impl_segment_record!(PcSaftRecord, PyPcSaftRecord, JobackRecord, PyJobackRecord);
 
+/// Create a record for the binary interaction parameters of PC-SAFT.
+///
+/// Parameters
+/// ----------
+/// k_ij : float, optional
+///     Correction to the energy parameter (default: 0).
+/// l_ij : float, optional
+///     Correction to the segment diameter (default: 0).
+/// kappa_ab : float, optional
+///     Cross-association volume parameter that replaces
+///     the combining rule.
+/// epsilon_k_ab : float, optional
+///     Cross-association energy parameter in units of Kelvin
+///     that replaces the combining rule.
+///
+/// Returns
+/// -------
+/// PcSaftBinaryRecord
 #[pyclass(name = "PcSaftBinaryRecord")]
-#[pyo3(
-    text_signature = "(pure_records, binary_records=None, substances=None, search_option='Name')"
-)]
+#[pyo3(text_signature = "(k_ij=None, l_ij=None, kappa_ab=None, epsilon_k_ab=None)")]
 #[derive(Clone)]
 pub struct PyPcSaftBinaryRecord(PcSaftBinaryRecord);
 impl_binary_record!(PcSaftBinaryRecord, PyPcSaftBinaryRecord);
 
+#[pymethods]
+impl PyPcSaftBinaryRecord {
+    #[new]
+    fn new(
+        k_ij: Option<f64>,
+        l_ij: Option<f64>,
+        kappa_ab: Option<f64>,
+        epsilon_k_ab: Option<f64>,
+    ) -> PyResult<Self> {
+        let association = match (kappa_ab, epsilon_k_ab) {
+            (Some(kappa_ab), Some(epsilon_k_ab)) => {
+                Some(BinaryAssociationRecord::new(kappa_ab, epsilon_k_ab))
+            }
+            (None, None) => None,
+            _ => return Err(PyErr::new::<PyValueError, _>(
+                "To model cross-association, both kappa_ab and epsilon_k_ab need to be specified.",
+            )),
+        };
+        Ok(Self(PcSaftBinaryRecord {
+            k_ij: k_ij.unwrap_or(0.0),
+            l_ij: l_ij.unwrap_or(0.0),
+            association,
+        }))
+    }
+
+    #[getter]
+    fn get_k_ij(&self) -> f64 {
+        self.0.k_ij
+    }
+
+    #[getter]
+    fn get_l_ij(&self) -> f64 {
+        self.0.l_ij
+    }
+
+    #[getter]
+    fn get_kappa_ab(&self) -> Option<f64> {
+        self.0.association.map(|a| a.kappa_ab)
+    }
+
+    #[getter]
+    fn get_epsilon_k_ab(&self) -> Option<f64> {
+        self.0.association.map(|a| a.epsilon_k_ab)
+    }
+
+    #[setter]
+    fn set_k_ij(&mut self, k_ij: f64) {
+        self.0.k_ij = k_ij
+    }
+
+    #[setter]
+    fn set_l_ij(&mut self, l_ij: f64) {
+        self.0.l_ij = l_ij
+    }
+
+    fn __repr__(&self) -> PyResult<String> {
+        Ok(self.0.to_string())
+    }
+}
+
 /// Create a set of PC-SAFT parameters from records.
 ///
 /// Parameters
@@ -146,7 +252,7 @@ impl_binary_record!(PcSaftBinaryRecord, PyPcSaftBinaryRecord);
 ///     The substances to use. Filters substances from `pure_records` according to
 ///     `search_option`.
 ///     When not provided, all entries of `pure_records` are used.
-/// search_option : {'Name', 'Cas', 'Inchi', 'IupacName', 'Formula', 'Smiles'}, optional, defaults to 'Name'.
+/// search_option : {'Name', 'Cas', 'Inchi', 'InchiKey', 'IupacName', 'Formula', 'Smiles'}, optional, defaults to 'Name'.
 ///     Identifier that is used to search substance.
 ///
 /// Returns
@@ -169,6 +275,11 @@ impl PyPcSaftParameters {
         self.0.k_ij.view().to_pyarray(py)
     }
 
+    #[getter]
+    fn get_l_ij<'py>(&self, py: Python<'py>) -> &'py PyArray2<f64> {
+        self.0.l_ij.view().to_pyarray(py)
+    }
+
     fn _repr_markdown_(&self) -> String {
         self.0.to_markdown()
     }
@@ -181,12 +292,16 @@ impl PyPcSaftParameters {
 #[pymodule]
 pub fn pcsaft(_py: Python<'_>, m: &PyModule) -> PyResult<()> {
     m.add_class::<PyIdentifier>()?;
+    m.add_class::<PyMetadata>()?;
     m.add_class::<IdentifierOption>()?;
+    m.add_class::<CombiningRule>()?;
     m.add_class::<PyChemicalRecord>()?;
     m.add_class::<PyJobackRecord>()?;
 
     m.add_class::<DQVariants>()?;
     m.add_class::<PyPcSaftRecord>()?;
+    m.add_class::<PyAssociationRecord>()?;
+    m.add_class::<PyPcSaftBinaryRecord>()?;
     m.add_class::<PyPureRecord>()?;
     m.add_class::<PySegmentRecord>()?;
     m.add_class::<PyBinaryRecord>()?;
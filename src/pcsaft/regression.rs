@@ -0,0 +1,288 @@
+use super::parameters::{PcSaftParameters, PcSaftRecord};
+use crate::association::AssociationRecord;
+use crate::estimator::{EstimatorError, RegressionParameters};
+use feos_core::parameter::Parameter;
+use std::fmt;
+use std::str::FromStr;
+
+/// The PC-SAFT parameters that can be adjusted in a regression.
+///
+/// The parameters are written as `name[i]` for pure component parameters
+/// and `k_ij[i,j]` for binary interaction parameters. The association
+/// parameters of additional association sites are written as
+/// `kappa_ab[i,s]`, where `s = 0` (or no index) denotes the association
+/// record and `s > 0` the entries of `association_sites`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum PcSaftFitParameter {
+    /// Segment number
+    M(usize),
+    /// Segment diameter in units of Angstrom
+    Sigma(usize),
+    /// Energetic parameter in units of Kelvin
+    EpsilonK(usize),
+    /// Dipole moment in units of Debye
+    Mu(usize),
+    /// Association volume parameter of a component and association site
+    KappaAB(usize, usize),
+    /// Association energy parameter of a component and association site in units of Kelvin
+    EpsilonKAB(usize, usize),
+    /// Binary interaction parameter
+    Kij(usize, usize),
+    /// Binary correction to the segment diameter
+    Lij(usize, usize),
+}
+
+impl fmt::Display for PcSaftFitParameter {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::M(i) => write!(f, "m[{}]", i),
+            Self::Sigma(i) => write!(f, "sigma[{}]", i),
+            Self::EpsilonK(i) => write!(f, "epsilon_k[{}]", i),
+            Self::Mu(i) => write!(f, "mu[{}]", i),
+            Self::KappaAB(i, 0) => write!(f, "kappa_ab[{}]", i),
+            Self::KappaAB(i, s) => write!(f, "kappa_ab[{},{}]", i, s),
+            Self::EpsilonKAB(i, 0) => write!(f, "epsilon_k_ab[{}]", i),
+            Self::EpsilonKAB(i, s) => write!(f, "epsilon_k_ab[{},{}]", i, s),
+            Self::Kij(i, j) => write!(f, "k_ij[{},{}]", i, j),
+            Self::Lij(i, j) => write!(f, "l_ij[{},{}]", i, j),
+        }
+    }
+}
+
+impl FromStr for PcSaftFitParameter {
+    type Err = EstimatorError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let error = || EstimatorError::InvalidParameter(s.into());
+        let (name, indices) = s
+            .trim()
+            .strip_suffix(']')
+            .and_then(|s| s.split_once('['))
+            .ok_or_else(error)?;
+        let indices = indices
+            .split(',')
+            .map(|i| i.trim().parse())
+            .collect::<Result<Vec<usize>, _>>()
+            .map_err(|_| error())?;
+        match (name.trim(), indices.as_slice()) {
+            ("m", &[i]) => Ok(Self::M(i)),
+            ("sigma", &[i]) => Ok(Self::Sigma(i)),
+            ("epsilon_k", &[i]) => Ok(Self::EpsilonK(i)),
+            ("mu", &[i]) => Ok(Self::Mu(i)),
+            ("kappa_ab", &[i]) => Ok(Self::KappaAB(i, 0)),
+            ("kappa_ab", &[i, s]) => Ok(Self::KappaAB(i, s)),
+            ("epsilon_k_ab", &[i]) => Ok(Self::EpsilonKAB(i, 0)),
+            ("epsilon_k_ab", &[i, s]) => Ok(Self::EpsilonKAB(i, s)),
+            ("k_ij", &[i, j]) if i != j => Ok(Self::Kij(i, j)),
+            ("l_ij", &[i, j]) if i != j => Ok(Self::Lij(i, j)),
+            _ => Err(error()),
+        }
+    }
+}
+
+impl PcSaftFitParameter {
+    fn component(&self) -> usize {
+        match *self {
+            Self::M(i)
+            | Self::Sigma(i)
+            | Self::EpsilonK(i)
+            | Self::Mu(i)
+            | Self::KappaAB(i, _)
+            | Self::EpsilonKAB(i, _) => i,
+            Self::Kij(i, j) | Self::Lij(i, j) => i.max(j),
+        }
+    }
+}
+
+fn no_association(name: &PcSaftFitParameter) -> EstimatorError {
+    EstimatorError::InvalidParameter(format!(
+        "{} (the component has no such association site)",
+        name
+    ))
+}
+
+/// The association record of site `s`: the association record of the
+/// component for `s = 0`, otherwise `association_sites[s - 1]`.
+fn association_record(
+    record: &PcSaftRecord,
+    site: usize,
+    name: &PcSaftFitParameter,
+) -> Result<AssociationRecord, EstimatorError> {
+    match site {
+        0 => record.association_record,
+        s => record.association_sites.get(s - 1).copied(),
+    }
+    .ok_or_else(|| no_association(name))
+}
+
+fn association_record_mut<'a>(
+    record: &'a mut PcSaftRecord,
+    site: usize,
+    name: &PcSaftFitParameter,
+) -> Result<&'a mut AssociationRecord, EstimatorError> {
+    match site {
+        0 => record.association_record.as_mut(),
+        s => record.association_sites.get_mut(s - 1),
+    }
+    .ok_or_else(|| no_association(name))
+}
+
+impl RegressionParameters for PcSaftParameters {
+    type Name = PcSaftFitParameter;
+
+    fn get_parameter(&self, name: &PcSaftFitParameter) -> Result<f64, EstimatorError> {
+        let (pure_records, binary_records) = self.records();
+        if name.component() >= pure_records.len() {
+            return Err(EstimatorError::InvalidParameter(name.to_string()));
+        }
+        let record = &pure_records[name.component()].model_record;
+        let association = |s| association_record(record, s, name);
+        Ok(match *name {
+            PcSaftFitParameter::M(_) => record.m,
+            PcSaftFitParameter::Sigma(_) => record.sigma,
+            PcSaftFitParameter::EpsilonK(_) => record.epsilon_k,
+            PcSaftFitParameter::Mu(_) => record.mu.unwrap_or(0.0),
+            PcSaftFitParameter::KappaAB(_, s) => association(s)?.kappa_ab,
+            PcSaftFitParameter::EpsilonKAB(_, s) => association(s)?.epsilon_k_ab,
+            PcSaftFitParameter::Kij(i, j) => binary_records[(i, j)].k_ij,
+            PcSaftFitParameter::Lij(i, j) => binary_records[(i, j)].l_ij,
+        })
+    }
+
+    fn set_parameters(
+        &self,
+        names: &[PcSaftFitParameter],
+        values: &[f64],
+    ) -> Result<Self, EstimatorError> {
+        let (pure_records, binary_records) = self.records();
+        let mut pure_records = pure_records.to_vec();
+        let mut binary_records = binary_records.clone();
+        for (name, &value) in names.iter().zip(values) {
+            if name.component() >= pure_records.len() {
+                return Err(EstimatorError::InvalidParameter(name.to_string()));
+            }
+            let record = &mut pure_records[name.component()].model_record;
+            match *name {
+                PcSaftFitParameter::M(_) => record.m = value,
+                PcSaftFitParameter::Sigma(_) => record.sigma = value,
+                PcSaftFitParameter::EpsilonK(_) => record.epsilon_k = value,
+                PcSaftFitParameter::Mu(_) => record.mu = Some(value),
+                PcSaftFitParameter::KappaAB(_, s) => {
+                    association_record_mut(record, s, name)?.kappa_ab = value
+                }
+                PcSaftFitParameter::EpsilonKAB(_, s) => {
+                    association_record_mut(record, s, name)?.epsilon_k_ab = value
+                }
+                PcSaftFitParameter::Kij(i, j) => {
+                    binary_records[(i, j)].k_ij = value;
+                    binary_records[(j, i)].k_ij = value;
+                }
+                PcSaftFitParameter::Lij(i, j) => {
+                    binary_records[(i, j)].l_ij = value;
+                    binary_records[(j, i)].l_ij = value;
+                }
+            }
+        }
+        // keep the binary metadata and the combining rule of the parameter set
+        let parameters = Self::from_records_with_metadata(
+            pure_records,
+            binary_records,
+            self.binary_metadata.clone(),
+        );
+        Ok(if self.combining_rule == parameters.combining_rule {
+            parameters
+        } else {
+            parameters.with_combining_rule(self.combining_rule)
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::pcsaft::parameters::utils::{propane_butane_parameters, water_parameters};
+    use feos_core::parameter::{CombiningRule, Metadata};
+    use ndarray::Array2;
+
+    #[test]
+    fn parse_parameters() -> Result<(), EstimatorError> {
+        for s in [
+            "m[0]",
+            "sigma[1]",
+            "epsilon_k_ab[0]",
+            "kappa_ab[1,2]",
+            "k_ij[0,1]",
+            "l_ij[1,0]",
+        ] {
+            assert_eq!(s.parse::<PcSaftFitParameter>()?.to_string(), s);
+        }
+        assert_eq!(
+            " k_ij[1, 0] ".parse::<PcSaftFitParameter>()?,
+            PcSaftFitParameter::Kij(1, 0)
+        );
+        assert!("k_ij[0]".parse::<PcSaftFitParameter>().is_err());
+        assert!("k_ij[1,1]".parse::<PcSaftFitParameter>().is_err());
+        assert!("lambda[0]".parse::<PcSaftFitParameter>().is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn set_parameters() -> Result<(), EstimatorError> {
+        let parameters = propane_butane_parameters();
+        let names = [PcSaftFitParameter::Sigma(1), PcSaftFitParameter::Kij(0, 1)];
+        let fitted = parameters.set_parameters(&names, &[4.0, 0.02])?;
+        assert_eq!(fitted.get_parameter(&names[0])?, 4.0);
+        assert_eq!(fitted.get_parameter(&PcSaftFitParameter::Kij(1, 0))?, 0.02);
+        assert_eq!(fitted.sigma[1], 4.0);
+        assert_eq!(fitted.k_ij[[0, 1]], 0.02);
+        assert!(parameters
+            .get_parameter(&PcSaftFitParameter::KappaAB(0, 0))
+            .is_err());
+        assert!(parameters
+            .get_parameter(&PcSaftFitParameter::Sigma(2))
+            .is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn set_parameters_keeps_metadata_and_combining_rule() -> Result<(), EstimatorError> {
+        let parameters = propane_butane_parameters();
+        let metadata = Metadata::new(None, None, Some("VLE".into()));
+        let parameters = PcSaftParameters::from_records_with_metadata(
+            parameters.pure_records.clone(),
+            parameters.binary_records.clone(),
+            Array2::from_elem((2, 2), Some(metadata.clone())),
+        )
+        .with_combining_rule(CombiningRule::GeometricSigma);
+        let fitted = parameters.set_parameters(&[PcSaftFitParameter::Sigma(0)], &[3.8])?;
+        assert_eq!(fitted.combining_rule, CombiningRule::GeometricSigma);
+        assert_eq!(fitted.binary_metadata[[0, 1]], Some(metadata));
+        let (sigma_ij, _) =
+            CombiningRule::GeometricSigma.cross_parameters(&fitted.sigma, &fitted.epsilon_k);
+        assert_eq!(fitted.sigma_ij, sigma_ij);
+        Ok(())
+    }
+
+    #[test]
+    fn set_association_sites() -> Result<(), EstimatorError> {
+        let mut record = water_parameters().pure_records[0].clone();
+        record.model_record.association_sites =
+            vec![AssociationRecord::new(0.01, 1000.0, Some(1.0), None, None)];
+        let parameters = PcSaftParameters::new_pure(record);
+        let names = [PcSaftFitParameter::EpsilonKAB(0, 1)];
+        let fitted = parameters.set_parameters(&names, &[1200.0])?;
+        assert_eq!(fitted.get_parameter(&names[0])?, 1200.0);
+        assert_eq!(
+            fitted.get_parameter(&PcSaftFitParameter::EpsilonKAB(0, 0))?,
+            2500.6706
+        );
+        assert_eq!(
+            fitted.pure_records[0].model_record.association_sites[0].epsilon_k_ab,
+            1200.0
+        );
+        assert!(parameters
+            .get_parameter(&PcSaftFitParameter::KappaAB(0, 2))
+            .is_err());
+        Ok(())
+    }
+}
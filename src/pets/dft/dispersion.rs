@@ -12,9 +12,9 @@ use std::fmt;
 use std::sync::Arc;
 
 /// psi Parameter for DFT (Heier2018)
-const PSI_DFT: f64 = 1.21;
+pub(super) const PSI_DFT: f64 = 1.21;
 /// psi Parameter for pDGT (not adjusted, yet)
-const PSI_PDGT: f64 = 1.21;
+pub(super) const PSI_PDGT: f64 = 1.21;
 
 #[derive(Clone)]
 pub struct AttractiveFunctional {
@@ -27,7 +27,7 @@ impl AttractiveFunctional {
     }
 }
 
-fn att_weight_functions<N: DualNum<f64> + ScalarOperand>(
+pub(super) fn att_weight_functions<N: DualNum<f64> + ScalarOperand>(
     p: &PetsParameters,
     psi: f64,
     temperature: N,
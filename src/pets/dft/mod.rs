@@ -1,21 +1,26 @@
 use super::eos::PetsOptions;
-use super::parameters::PetsParameters;
+use super::parameters::{PetsBinaryRecord, PetsParameters, PetsRecord};
 use crate::hard_sphere::{FMTContribution, FMTVersion};
 use dispersion::AttractiveFunctional;
-use feos_core::joback::Joback;
-use feos_core::parameter::Parameter;
-use feos_core::{IdealGasContribution, MolarWeight};
+use feos_core::ideal_gas::{IdealGasModel, WithIdealGas};
+use feos_core::joback::JobackRecord;
+use feos_core::parameter::{Parameter, ParameterSet};
+use feos_core::{EosResult, EquationOfState, IdealGasContribution, MolarWeight};
 use feos_dft::adsorption::FluidParameters;
 use feos_dft::solvation::PairPotential;
 use feos_dft::{FunctionalContribution, HelmholtzEnergyFunctional, MoleculeShape, DFT};
 use ndarray::{Array1, Array2};
 use num_dual::DualNum;
+use polar::DipoleFunctional;
 use pure_pets_functional::*;
 use quantity::si::*;
+use serde::de::Error;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
 use std::f64::consts::FRAC_PI_6;
 use std::sync::Arc;
 
 mod dispersion;
+mod polar;
 mod pure_pets_functional;
 
 /// PeTS Helmholtz energy functional.
@@ -25,7 +30,7 @@ pub struct PetsFunctional {
     fmt_version: FMTVersion,
     options: PetsOptions,
     contributions: Vec<Box<dyn FunctionalContribution>>,
-    joback: Joback,
+    ideal_gas: IdealGasModel,
 }
 
 impl PetsFunctional {
@@ -44,12 +49,56 @@ impl PetsFunctional {
     }
 
     /// PeTS functional with provided options for FMT and equation of state options.
+    ///
+    /// The functional consists of the hard-sphere and attractive contributions
+    /// of the PeTS reference fluid that are extended by additional contributions,
+    /// e.g., a dipolar contribution if dipole moments are specified.
     pub fn with_options(
         parameters: Arc<PetsParameters>,
         fmt_version: FMTVersion,
         pets_options: PetsOptions,
     ) -> DFT<Self> {
-        let mut contributions: Vec<Box<dyn FunctionalContribution>> = Vec::with_capacity(2);
+        let parameters =
+            PetsParameters::apply_combining_rule(parameters, pets_options.combining_rule);
+        let mut contributions = Self::reference_contributions(&parameters, fmt_version);
+        contributions.extend(Self::additional_contributions(&parameters));
+
+        let ideal_gas = parameters.joback_records.clone().map_or_else(
+            || IdealGasModel::default(parameters.sigma.len()),
+            IdealGasModel::joback,
+        );
+
+        Self {
+            parameters,
+            fmt_version,
+            options: pets_options,
+            contributions,
+            ideal_gas,
+        }
+        .into()
+    }
+
+    /// PeTS functional of a PeTS-like Stockmayer fluid with the given
+    /// dipole moments in units of Debye.
+    pub fn polar(
+        parameters: Arc<PetsParameters>,
+        mu: &Array1<f64>,
+        fmt_version: FMTVersion,
+        pets_options: PetsOptions,
+    ) -> DFT<Self> {
+        Self::with_options(
+            Arc::new(parameters.with_dipole_moments(mu)),
+            fmt_version,
+            pets_options,
+        )
+    }
+
+    /// Hard-sphere and attractive contributions of the PeTS fluid.
+    fn reference_contributions(
+        parameters: &Arc<PetsParameters>,
+        fmt_version: FMTVersion,
+    ) -> Vec<Box<dyn FunctionalContribution>> {
+        let mut contributions: Vec<Box<dyn FunctionalContribution>> = Vec::with_capacity(3);
 
         if matches!(
             fmt_version,
@@ -66,27 +115,28 @@ impl PetsFunctional {
             contributions.push(Box::new(att));
         } else {
             // Hard-sphere contribution mixtures
-            let hs = FMTContribution::new(&parameters, fmt_version);
+            let hs = FMTContribution::new(parameters, fmt_version);
             contributions.push(Box::new(hs));
 
             // Dispersion contribution mixtures
             let att = AttractiveFunctional::new(parameters.clone());
             contributions.push(Box::new(att));
         }
+        contributions
+    }
 
-        let joback = match &parameters.joback_records {
-            Some(joback_records) => Joback::new(joback_records.clone()),
-            None => Joback::default(parameters.sigma.len()),
-        };
+    /// Contributions that extend the PeTS reference fluid.
+    fn additional_contributions(
+        parameters: &Arc<PetsParameters>,
+    ) -> Vec<Box<dyn FunctionalContribution>> {
+        let mut contributions: Vec<Box<dyn FunctionalContribution>> = Vec::new();
 
-        Self {
-            parameters,
-            fmt_version,
-            options: pets_options,
-            contributions,
-            joback,
+        // Dipolar contribution
+        if parameters.ndipole > 0 {
+            let dipole = DipoleFunctional::new(parameters.clone());
+            contributions.push(Box::new(dipole));
         }
-        .into()
+        contributions
     }
 }
 
@@ -97,6 +147,8 @@ impl HelmholtzEnergyFunctional for PetsFunctional {
             self.fmt_version,
             self.options,
         )
+        .with_ideal_gas(self.ideal_gas.subset(component_list))
+        .unwrap()
     }
 
     fn molecule_shape(&self) -> MoleculeShape {
@@ -104,8 +156,10 @@ impl HelmholtzEnergyFunctional for PetsFunctional {
     }
 
     fn compute_max_density(&self, moles: &Array1<f64>) -> f64 {
-        self.options.max_eta * moles.sum()
-            / (FRAC_PI_6 * self.parameters.sigma.mapv(|v| v.powi(3)) * moles).sum()
+        self.options.max_density.apply(
+            self.options.max_eta * moles.sum()
+                / (FRAC_PI_6 * self.parameters.sigma.mapv(|v| v.powi(3)) * moles).sum(),
+        )
     }
 
     fn contributions(&self) -> &[Box<dyn FunctionalContribution>] {
@@ -113,7 +167,48 @@ impl HelmholtzEnergyFunctional for PetsFunctional {
     }
 
     fn ideal_gas(&self) -> &dyn IdealGasContribution {
-        &self.joback
+        &self.ideal_gas
+    }
+}
+
+impl WithIdealGas for PetsFunctional {
+    fn with_ideal_gas(mut self, ideal_gas: IdealGasModel) -> EosResult<Self> {
+        ideal_gas.validate_components(self.parameters.sigma.len())?;
+        self.ideal_gas = ideal_gas;
+        Ok(self)
+    }
+}
+
+// Auxiliary structure used to (de)serialize the Helmholtz energy functional.
+#[derive(Serialize, Deserialize)]
+struct PetsFunctionalJSON {
+    parameters: ParameterSet<PetsRecord, JobackRecord, PetsBinaryRecord>,
+    fmt_version: FMTVersion,
+    options: PetsOptions,
+    ideal_gas: IdealGasModel,
+}
+
+impl Serialize for PetsFunctional {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        PetsFunctionalJSON {
+            parameters: self.parameters.parameter_set(),
+            fmt_version: self.fmt_version,
+            options: self.options,
+            ideal_gas: self.ideal_gas.clone(),
+        }
+        .serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for PetsFunctional {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let func = PetsFunctionalJSON::deserialize(deserializer)?;
+        let parameters =
+            PetsParameters::from_parameter_set(func.parameters).map_err(D::Error::custom)?;
+        Self::with_options(Arc::new(parameters), func.fmt_version, func.options)
+            .into_inner()
+            .with_ideal_gas(func.ideal_gas)
+            .map_err(D::Error::custom)
     }
 }
 
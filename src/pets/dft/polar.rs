@@ -0,0 +1,51 @@
+use super::dispersion::{att_weight_functions, PSI_DFT, PSI_PDGT};
+use crate::hard_sphere::HardSphereProperties;
+use crate::pets::eos::polar::helmholtz_energy_density_dipole;
+use crate::pets::parameters::PetsParameters;
+use feos_core::EosError;
+use feos_dft::{FunctionalContributionDual, WeightFunctionInfo};
+use ndarray::*;
+use num_dual::DualNum;
+use std::fmt;
+use std::sync::Arc;
+
+/// Dipolar contribution of a PeTS-like Stockmayer fluid evaluated
+/// with the weighted densities of the attractive functional.
+#[derive(Clone)]
+pub struct DipoleFunctional {
+    parameters: Arc<PetsParameters>,
+}
+
+impl DipoleFunctional {
+    pub fn new(parameters: Arc<PetsParameters>) -> Self {
+        Self { parameters }
+    }
+}
+
+impl<N: DualNum<f64> + ScalarOperand> FunctionalContributionDual<N> for DipoleFunctional {
+    fn weight_functions(&self, temperature: N) -> WeightFunctionInfo<N> {
+        att_weight_functions(&self.parameters, PSI_DFT, temperature)
+    }
+
+    fn weight_functions_pdgt(&self, temperature: N) -> WeightFunctionInfo<N> {
+        att_weight_functions(&self.parameters, PSI_PDGT, temperature)
+    }
+
+    fn calculate_helmholtz_energy_density(
+        &self,
+        temperature: N,
+        density: ArrayView2<N>,
+    ) -> Result<Array1<N>, EosError> {
+        let d = self.parameters.hs_diameter(temperature);
+        Ok(density
+            .axis_iter(Axis(1))
+            .map(|rho| helmholtz_energy_density_dipole(&self.parameters, temperature, &d, rho))
+            .collect())
+    }
+}
+
+impl fmt::Display for DipoleFunctional {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Dipole functional")
+    }
+}
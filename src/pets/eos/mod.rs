@@ -1,40 +1,54 @@
-use super::parameters::PetsParameters;
+use super::parameters::{PetsBinaryRecord, PetsParameters, PetsRecord};
 use crate::hard_sphere::HardSphere;
-use feos_core::joback::Joback;
-use feos_core::parameter::Parameter;
+use feos_core::ideal_gas::{IdealGasModel, WithIdealGas};
+use feos_core::joback::JobackRecord;
+use feos_core::parameter::{CombiningRule, Parameter, ParameterSet};
 use feos_core::{
     Contributions, EntropyScaling, EosError, EosResult, EosUnit, EquationOfState, HelmholtzEnergy,
-    IdealGasContribution, MolarWeight, State,
+    IdealGasContribution, MaxDensity, MolarWeight, State,
 };
 use ndarray::Array1;
 use quantity::si::*;
+use serde::de::Error;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
 use std::f64::consts::{FRAC_PI_6, PI};
 use std::sync::Arc;
 
 pub(crate) mod dispersion;
+pub(crate) mod polar;
 mod qspr;
 use dispersion::Dispersion;
+use polar::Dipole;
 use qspr::QSPR;
 
 #[allow(clippy::upper_case_acronyms)]
 enum IdealGasContributions {
     QSPR(QSPR),
-    Joback(Joback),
+    IdealGasModel(IdealGasModel),
 }
 
 /// Configuration options for the PeTS equation of state and Helmholtz energy functional.
 ///
 /// The maximum packing fraction is used to infer initial values
 /// for routines that depend on starting values for the system density.
-#[derive(Copy, Clone)]
+#[derive(Copy, Clone, Serialize, Deserialize)]
 pub struct PetsOptions {
     /// maximum packing fraction
     pub max_eta: f64,
+    /// strategy for the calculation of the maximum density
+    pub max_density: MaxDensity,
+    /// combining rule for the cross diameters and energy parameters
+    #[serde(default)]
+    pub combining_rule: CombiningRule,
 }
 
 impl Default for PetsOptions {
     fn default() -> Self {
-        Self { max_eta: 0.5 }
+        Self {
+            max_eta: 0.5,
+            max_density: MaxDensity::Heuristic,
+            combining_rule: CombiningRule::LorentzBerthelot,
+        }
     }
 }
 
@@ -54,12 +68,18 @@ impl Pets {
 
     /// PeTS equation of state with provided options.
     pub fn with_options(parameters: Arc<PetsParameters>, options: PetsOptions) -> Self {
-        let contributions: Vec<Box<dyn HelmholtzEnergy>> = vec![
+        let parameters = PetsParameters::apply_combining_rule(parameters, options.combining_rule);
+        let mut contributions: Vec<Box<dyn HelmholtzEnergy>> = vec![
             Box::new(HardSphere::new(&parameters)),
             Box::new(Dispersion {
                 parameters: parameters.clone(),
             }),
         ];
+        if parameters.ndipole > 0 {
+            contributions.push(Box::new(Dipole {
+                parameters: parameters.clone(),
+            }));
+        }
 
         let joback_records = parameters.joback_records.clone();
 
@@ -69,7 +89,9 @@ impl Pets {
             contributions,
             ideal_gas: joback_records.map_or(
                 IdealGasContributions::QSPR(QSPR { parameters }),
-                |joback_records| IdealGasContributions::Joback(Joback::new(joback_records)),
+                |joback_records| {
+                    IdealGasContributions::IdealGasModel(IdealGasModel::joback(joback_records))
+                },
             ),
         }
     }
@@ -81,15 +103,21 @@ impl EquationOfState for Pets {
     }
 
     fn subset(&self, component_list: &[usize]) -> Self {
-        Self::with_options(
+        let mut eos = Self::with_options(
             Arc::new(self.parameters.subset(component_list)),
             self.options,
-        )
+        );
+        if let IdealGasContributions::IdealGasModel(ideal_gas) = &self.ideal_gas {
+            eos.ideal_gas = IdealGasContributions::IdealGasModel(ideal_gas.subset(component_list));
+        }
+        eos
     }
 
     fn compute_max_density(&self, moles: &Array1<f64>) -> f64 {
-        self.options.max_eta * moles.sum()
-            / (FRAC_PI_6 * self.parameters.sigma.mapv(|v| v.powi(3)) * moles).sum()
+        self.options.max_density.apply(
+            self.options.max_eta * moles.sum()
+                / (FRAC_PI_6 * self.parameters.sigma.mapv(|v| v.powi(3)) * moles).sum(),
+        )
     }
 
     fn residual(&self) -> &[Box<dyn HelmholtzEnergy>] {
@@ -99,7 +127,53 @@ impl EquationOfState for Pets {
     fn ideal_gas(&self) -> &dyn IdealGasContribution {
         match &self.ideal_gas {
             IdealGasContributions::QSPR(qspr) => qspr,
-            IdealGasContributions::Joback(joback) => joback,
+            IdealGasContributions::IdealGasModel(ideal_gas) => ideal_gas,
+        }
+    }
+}
+
+impl WithIdealGas for Pets {
+    fn with_ideal_gas(mut self, ideal_gas: IdealGasModel) -> EosResult<Self> {
+        ideal_gas.validate_components(self.components())?;
+        self.ideal_gas = IdealGasContributions::IdealGasModel(ideal_gas);
+        Ok(self)
+    }
+}
+
+// Auxiliary structure used to (de)serialize the equation of state.
+#[derive(Serialize, Deserialize)]
+struct PetsJSON {
+    parameters: ParameterSet<PetsRecord, JobackRecord, PetsBinaryRecord>,
+    options: PetsOptions,
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    ideal_gas: Option<IdealGasModel>,
+}
+
+impl Serialize for Pets {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let ideal_gas = match &self.ideal_gas {
+            IdealGasContributions::QSPR(_) => None,
+            IdealGasContributions::IdealGasModel(ideal_gas) => Some(ideal_gas.clone()),
+        };
+        PetsJSON {
+            parameters: self.parameters.parameter_set(),
+            options: self.options,
+            ideal_gas,
+        }
+        .serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for Pets {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let eos = PetsJSON::deserialize(deserializer)?;
+        let parameters =
+            PetsParameters::from_parameter_set(eos.parameters).map_err(D::Error::custom)?;
+        let pets = Self::with_options(Arc::new(parameters), eos.options);
+        match eos.ideal_gas {
+            Some(ideal_gas) => pets.with_ideal_gas(ideal_gas).map_err(D::Error::custom),
+            None => Ok(pets),
         }
     }
 }
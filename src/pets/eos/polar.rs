@@ -0,0 +1,136 @@
+use crate::hard_sphere::HardSphereProperties;
+use crate::pets::parameters::PetsParameters;
+use feos_core::{HelmholtzEnergyDual, StateHD};
+use ndarray::{Array1, ArrayView1};
+use num_dual::DualNum;
+use std::f64::consts::{FRAC_PI_3, PI};
+use std::fmt;
+use std::sync::Arc;
+
+// Dipole parameters of Gross and Vrabec (2006) for spherical molecules
+pub const AD: [f64; 5] = [
+    0.30435038064,
+    -0.13585877707,
+    1.44933285154,
+    0.35569769252,
+    -2.06533084541,
+];
+pub const BD: [f64; 5] = [0.21879385627, -1.18964307357, 1.16268885692, 0.0, 0.0];
+pub const CD: [f64; 4] = [-0.06467735252, 0.19758818347, -0.80875619458, 0.69028490492];
+
+const PI_SQ_43: f64 = 4.0 * PI * FRAC_PI_3;
+
+/// Helmholtz energy density of the dipolar contribution in units of $k_\mathrm{B}T/\mathrm{\AA}^3$
+/// for given partial densities and hard-sphere diameters.
+pub(crate) fn helmholtz_energy_density_dipole<D: DualNum<f64>>(
+    p: &PetsParameters,
+    temperature: D,
+    diameter: &Array1<D>,
+    rho: ArrayView1<D>,
+) -> D {
+    let t_inv = temperature.recip();
+    let eta = (0..rho.len())
+        .map(|i| rho[i] * diameter[i].powi(3))
+        .sum::<D>()
+        * FRAC_PI_3
+        * 0.5;
+    let mut etas = [D::one(); 5];
+    for i in 1..etas.len() {
+        etas[i] = etas[i - 1] * eta;
+    }
+    let j2 = |eps_ij_t: D| -> D {
+        (0..AD.len())
+            .map(|n| etas[n] * (eps_ij_t * BD[n] + AD[n]))
+            .sum()
+    };
+    let j3: D = (0..CD.len()).map(|n| etas[n] * CD[n]).sum();
+
+    let mu2_term: Vec<D> = p
+        .dipole_comp
+        .iter()
+        .map(|&i| t_inv * p.sigma[i].powi(3) * p.epsilon_k[i] * p.mu2[i])
+        .collect();
+
+    let mut phi2 = D::zero();
+    let mut phi3 = D::zero();
+    for i in 0..p.ndipole {
+        let di = p.dipole_comp[i];
+        for j in i..p.ndipole {
+            let dj = p.dipole_comp[j];
+            let c = if i == j { 1.0 } else { 2.0 };
+            phi2 -= rho[di] * rho[dj] * mu2_term[i] * mu2_term[j] * j2(t_inv * p.e_k_ij[[di, dj]])
+                / p.sigma_ij[[di, dj]].powi(3)
+                * c;
+            for k in j..p.ndipole {
+                let dk = p.dipole_comp[k];
+                let c = if i == k {
+                    1.0
+                } else if i == j || j == k {
+                    3.0
+                } else {
+                    6.0
+                };
+                phi3 -= rho[di] * rho[dj] * rho[dk] * mu2_term[i] * mu2_term[j] * mu2_term[k]
+                    / (p.sigma_ij[[di, dj]] * p.sigma_ij[[di, dk]] * p.sigma_ij[[dj, dk]])
+                    * j3
+                    * c;
+            }
+        }
+    }
+    phi2 *= PI;
+    phi3 *= PI_SQ_43;
+    let result = phi2 * phi2 / (phi2 - phi3);
+    if result.re().is_nan() {
+        phi2
+    } else {
+        result
+    }
+}
+
+/// Dipolar contribution of a PeTS-like Stockmayer fluid.
+///
+/// The contribution of Gross and Vrabec (2006) is used in its form
+/// for spherical molecules, i.e., with a segment number of 1.
+#[derive(Debug, Clone)]
+pub struct Dipole {
+    pub parameters: Arc<PetsParameters>,
+}
+
+impl<D: DualNum<f64>> HelmholtzEnergyDual<D> for Dipole {
+    fn helmholtz_energy(&self, state: &StateHD<D>) -> D {
+        let d = self.parameters.hs_diameter(state.temperature);
+        helmholtz_energy_density_dipole(
+            &self.parameters,
+            state.temperature,
+            &d,
+            state.partial_density.view(),
+        ) * state.volume
+    }
+}
+
+impl fmt::Display for Dipole {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Dipole")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::pets::parameters::utils::{argon_krypton_parameters, argon_parameters};
+    use approx::assert_relative_eq;
+    use ndarray::arr1;
+
+    #[test]
+    fn mix() {
+        let p1 = Arc::new(argon_parameters().with_dipole_moments(&arr1(&[1.5])));
+        let p12 = Arc::new(argon_krypton_parameters().with_dipole_moments(&arr1(&[1.5, 0.0])));
+        let (c1, c12) = (Dipole { parameters: p1 }, Dipole { parameters: p12 });
+        let t = 250.0;
+        let v = 1000.0;
+        let a1 = c1.helmholtz_energy(&StateHD::new(t, v, arr1(&[10.0])));
+        let a12 = c12.helmholtz_energy(&StateHD::new(t, v, arr1(&[10.0, 0.0])));
+        assert!(a1 < 0.0);
+        assert_relative_eq!(a1, a12, max_relative = 1e-14);
+    }
+}
@@ -1,12 +1,14 @@
 use crate::hard_sphere::{HardSphereProperties, MonomerShape};
 use feos_core::joback::JobackRecord;
-use feos_core::parameter::{Parameter, PureRecord};
+use feos_core::parameter::{CombiningRule, Metadata, Parameter, PureRecord};
 use ndarray::{Array, Array1, Array2};
 use num_dual::DualNum;
 use num_traits::Zero;
+use quantity::si::{JOULE, KB, KELVIN};
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 use std::fmt::Write;
+use std::sync::Arc;
 
 /// PeTS parameters for a pure substance.
 #[derive(Serialize, Deserialize, Debug, Clone, Default)]
@@ -15,6 +17,9 @@ pub struct PetsRecord {
     pub sigma: f64,
     /// Energetic parameter in units of Kelvin
     pub epsilon_k: f64,
+    /// Dipole moment in units of Debye
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub mu: Option<f64>,
     /// Entropy scaling parameters for viscosity
     #[serde(skip_serializing_if = "Option::is_none")]
     pub viscosity: Option<[f64; 4]>,
@@ -30,6 +35,9 @@ impl std::fmt::Display for PetsRecord {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "PetsRecord(sigma={}", self.sigma)?;
         write!(f, ", epsilon_k={}", self.epsilon_k)?;
+        if let Some(n) = &self.mu {
+            write!(f, ", mu={}", n)?;
+        }
         if let Some(n) = &self.viscosity {
             write!(f, ", viscosity={:?}", n)?;
         }
@@ -50,7 +58,7 @@ impl PetsRecord {
     ///
     /// ```
     /// use feos::pets::PetsRecord;
-    /// let record = PetsRecord::new(3.7, 120.0, None, None, None);
+    /// let record = PetsRecord::new(3.7, 120.0, None, None, None, None);
     /// ```
     pub fn new(
         sigma: f64,
@@ -58,10 +66,12 @@ impl PetsRecord {
         viscosity: Option<[f64; 4]>,
         diffusion: Option<[f64; 5]>,
         thermal_conductivity: Option<[f64; 4]>,
+        mu: Option<f64>,
     ) -> PetsRecord {
         PetsRecord {
             sigma,
             epsilon_k,
+            mu,
             viscosity,
             diffusion,
             thermal_conductivity,
@@ -104,6 +114,14 @@ pub struct PetsParameters {
     pub sigma: Array1<f64>,
     /// Lennard-Jones energy parameter in Kelvin
     pub epsilon_k: Array1<f64>,
+    /// dipole moment in units of Debye
+    pub mu: Array1<f64>,
+    /// reduced squared dipole moment
+    pub mu2: Array1<f64>,
+    /// number of dipolar components
+    pub ndipole: usize,
+    /// indices of dipolar components
+    pub dipole_comp: Array1<usize>,
     /// binary interaction parameter
     pub k_ij: Array2<f64>,
     /// diameter matrix
@@ -112,6 +130,9 @@ pub struct PetsParameters {
     pub epsilon_k_ij: Array2<f64>,
     /// energy parameter matrix not including k_ij
     pub e_k_ij: Array2<f64>,
+    /// combining rule for the cross diameters and energy parameters
+    #[serde(default)]
+    pub combining_rule: CombiningRule,
     /// viscosity parameters for entropy scaling
     pub viscosity: Option<Array2<f64>>,
     /// diffusion parameters for entropy scaling
@@ -124,6 +145,7 @@ pub struct PetsParameters {
     pub joback_records: Option<Vec<JobackRecord>>,
     /// records of all binary interaction parameters
     pub binary_records: Array2<PetsBinaryRecord>,
+    pub binary_metadata: Array2<Option<Metadata>>,
 }
 
 impl Parameter for PetsParameters {
@@ -140,6 +162,7 @@ impl Parameter for PetsParameters {
         let mut molarweight = Array::zeros(n);
         let mut sigma = Array::zeros(n);
         let mut epsilon_k = Array::zeros(n);
+        let mut mu = Array::zeros(n);
         let mut viscosity = Vec::with_capacity(n);
         let mut diffusion = Vec::with_capacity(n);
         let mut thermal_conductivity = Vec::with_capacity(n);
@@ -151,12 +174,23 @@ impl Parameter for PetsParameters {
             let r = &record.model_record;
             sigma[i] = r.sigma;
             epsilon_k[i] = r.epsilon_k;
+            mu[i] = r.mu.unwrap_or(0.0);
             viscosity.push(r.viscosity);
             diffusion.push(r.diffusion);
             thermal_conductivity.push(r.thermal_conductivity);
             molarweight[i] = record.molarweight;
         }
 
+        let mu2 = &mu * &mu / (&sigma * &sigma * &sigma * &epsilon_k)
+            * 1e-19
+            * (JOULE / KELVIN / KB).into_value().unwrap();
+        let dipole_comp: Array1<usize> = mu2
+            .iter()
+            .enumerate()
+            .filter_map(|(i, &mu2)| (mu2.abs() > 0.0).then_some(i))
+            .collect();
+        let ndipole = dipole_comp.len();
+
         let k_ij = binary_records.map(|br| br.k_ij);
         let mut epsilon_k_ij = Array::zeros((n, n));
         let mut sigma_ij = Array::zeros((n, n));
@@ -209,15 +243,21 @@ impl Parameter for PetsParameters {
             molarweight,
             sigma,
             epsilon_k,
+            mu,
+            mu2,
+            ndipole,
+            dipole_comp,
             k_ij,
             sigma_ij,
             epsilon_k_ij,
             e_k_ij,
+            combining_rule: CombiningRule::LorentzBerthelot,
             viscosity: viscosity_coefficients,
             diffusion: diffusion_coefficients,
             thermal_conductivity: thermal_conductivity_coefficients,
             pure_records,
             joback_records,
+            binary_metadata: Array2::from_elem(binary_records.raw_dim(), None),
             binary_records,
         }
     }
@@ -230,6 +270,20 @@ impl Parameter for PetsParameters {
     ) {
         (&self.pure_records, &self.binary_records)
     }
+
+    fn from_records_with_metadata(
+        pure_records: Vec<PureRecord<Self::Pure, Self::IdealGas>>,
+        binary_records: Array2<Self::Binary>,
+        binary_metadata: Array2<Option<Metadata>>,
+    ) -> Self {
+        let mut parameters = Self::from_records(pure_records, binary_records);
+        parameters.binary_metadata = binary_metadata;
+        parameters
+    }
+
+    fn binary_metadata(&self) -> Option<&Array2<Option<Metadata>>> {
+        Some(&self.binary_metadata)
+    }
 }
 
 impl HardSphereProperties for PetsParameters {
@@ -246,6 +300,53 @@ impl HardSphereProperties for PetsParameters {
 }
 
 impl PetsParameters {
+    /// Replace the dipole moments (in units of Debye) of all components.
+    pub fn with_dipole_moments(&self, mu: &Array1<f64>) -> Self {
+        let pure_records = self
+            .pure_records
+            .iter()
+            .zip(mu.iter())
+            .map(|(record, &mu)| {
+                let mut record = record.clone();
+                record.model_record.mu = Some(mu);
+                record
+            })
+            .collect();
+        Self::from_records_with_metadata(
+            pure_records,
+            self.binary_records.clone(),
+            self.binary_metadata.clone(),
+        )
+        .with_combining_rule(self.combining_rule)
+    }
+
+    /// Replace the combining rule for the cross diameters and energy parameters.
+    ///
+    /// The binary interaction parameters `k_ij` are applied as corrections
+    /// to the combined energy parameters.
+    pub fn with_combining_rule(&self, combining_rule: CombiningRule) -> Self {
+        let (sigma_ij, e_k_ij) = combining_rule.cross_parameters(&self.sigma, &self.epsilon_k);
+        Self {
+            sigma_ij,
+            epsilon_k_ij: (1.0 - &self.k_ij) * &e_k_ij,
+            e_k_ij,
+            combining_rule,
+            ..self.clone()
+        }
+    }
+
+    /// Rebuild shared parameters if they do not use the given combining rule.
+    pub(crate) fn apply_combining_rule(
+        parameters: Arc<Self>,
+        combining_rule: CombiningRule,
+    ) -> Arc<Self> {
+        if parameters.combining_rule == combining_rule {
+            parameters
+        } else {
+            Arc::new(parameters.with_combining_rule(combining_rule))
+        }
+    }
+
     pub fn to_markdown(&self) -> String {
         let mut output = String::new();
         let o = &mut output;
@@ -275,6 +376,9 @@ impl std::fmt::Display for PetsParameters {
         write!(f, "\n\tmolarweight={}", self.molarweight)?;
         write!(f, "\n\tsigma={}", self.sigma)?;
         write!(f, "\n\tepsilon_k={}", self.epsilon_k)?;
+        if !self.dipole_comp.is_empty() {
+            write!(f, "\n\tmu={}", self.mu)?;
+        }
         if !self.k_ij.iter().all(|k| k.is_zero()) {
             write!(f, "\n\tk_ij=\n{}", self.k_ij)?;
         }
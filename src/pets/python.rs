@@ -14,7 +14,7 @@ use std::sync::Arc;
 /// Create a set of PeTS parameters from records.
 #[pyclass(name = "PetsRecord")]
 #[pyo3(
-    text_signature = "(sigma, epsilon_k, viscosity=None, diffusion=None, thermal_conductivity=None)"
+    text_signature = "(sigma, epsilon_k, viscosity=None, diffusion=None, thermal_conductivity=None, mu=None)"
 )]
 #[derive(Clone)]
 pub struct PyPetsRecord(PetsRecord);
@@ -28,6 +28,7 @@ impl PyPetsRecord {
         viscosity: Option<[f64; 4]>,
         diffusion: Option<[f64; 5]>,
         thermal_conductivity: Option<[f64; 4]>,
+        mu: Option<f64>,
     ) -> Self {
         Self(PetsRecord::new(
             sigma,
@@ -35,6 +36,7 @@ impl PyPetsRecord {
             viscosity,
             diffusion,
             thermal_conductivity,
+            mu,
         ))
     }
 
@@ -48,6 +50,11 @@ impl PyPetsRecord {
         self.0.epsilon_k
     }
 
+    #[getter]
+    fn get_mu(&self) -> Option<f64> {
+        self.0.mu
+    }
+
     #[getter]
     fn get_viscosity(&self) -> Option<[f64; 4]> {
         self.0.viscosity
@@ -91,7 +98,7 @@ impl_binary_record!(PetsBinaryRecord, PyPetsBinaryRecord);
 ///     The substances to use. Filters substances from `pure_records` according to
 ///     `search_option`.
 ///     When not provided, all entries of `pure_records` are used.
-/// search_option : {'Name', 'Cas', 'Inchi', 'IupacName', 'Formula', 'Smiles'}, optional, defaults to 'Name'.
+/// search_option : {'Name', 'Cas', 'Inchi', 'InchiKey', 'IupacName', 'Formula', 'Smiles'}, optional, defaults to 'Name'.
 ///     Identifier that is used to search substance.
 #[pyclass(name = "PetsParameters")]
 #[pyo3(
@@ -120,11 +127,13 @@ impl PyPetsParameters {
     ///     entropy scaling parameters for self-diffusion.
     /// thermal_conductivity: List[List[float]], optional
     ///     entropy scaling parameters for thermal conductivity.
+    /// mu: List[float], optional
+    ///     dipole moments in units of Debye.
     /// Returns
     /// -------
     /// PetsParameters
     #[pyo3(
-        text_signature = "(sigma, epsilon_k, k_ij=None, molarweight=None, viscosity=None, diffusion=None, thermal_conductivity=None)"
+        text_signature = "(sigma, epsilon_k, k_ij=None, molarweight=None, viscosity=None, diffusion=None, thermal_conductivity=None, mu=None)"
     )]
     #[staticmethod]
     fn from_lists(
@@ -135,6 +144,7 @@ impl PyPetsParameters {
         viscosity: Option<Vec<[f64; 4]>>,
         diffusion: Option<Vec<[f64; 5]>>,
         thermal_conductivity: Option<Vec<[f64; 4]>>,
+        mu: Option<Vec<f64>>,
     ) -> PyResult<Self> {
         // Check if all inputs have the same length
         let n = sigma.len();
@@ -147,6 +157,7 @@ impl PyPetsParameters {
             viscosity.as_ref().map(|v| v.len()),
             diffusion.as_ref().map(|v| v.len()),
             thermal_conductivity.as_ref().map(|v| v.len()),
+            mu.as_ref().map(|v| v.len()),
         ]
         .iter()
         .filter_map(|&v| v)
@@ -175,6 +186,7 @@ impl PyPetsParameters {
                     viscosity.as_ref().map(|v| v[i]),
                     diffusion.as_ref().map(|v| v[i]),
                     thermal_conductivity.as_ref().map(|v| v[i]),
+                    mu.as_ref().map(|v| v[i]),
                 );
                 PureRecord::new(
                     identifier,
@@ -213,11 +225,13 @@ impl PyPetsParameters {
     ///     entropy scaling parameters for self-diffusion.
     /// thermal_conductivity: List[float], optional
     ///     entropy scaling parameters for thermal conductivity.
+    /// mu: float, optional
+    ///     dipole moment in units of Debye.
     /// Returns
     /// -------
     /// PetsParameters
     #[pyo3(
-        text_signature = "(sigma, epsilon_k, molarweight=None, viscosity=None, diffusion=None, thermal_conductivity=None)"
+        text_signature = "(sigma, epsilon_k, molarweight=None, viscosity=None, diffusion=None, thermal_conductivity=None, mu=None)"
     )]
     #[staticmethod]
     fn from_values(
@@ -227,6 +241,7 @@ impl PyPetsParameters {
         viscosity: Option<[f64; 4]>,
         diffusion: Option<[f64; 5]>,
         thermal_conductivity: Option<[f64; 4]>,
+        mu: Option<f64>,
     ) -> Self {
         let pure_record = PureRecord::new(
             Identifier::new(
@@ -238,7 +253,14 @@ impl PyPetsParameters {
                 None,
             ),
             molarweight.map_or(1.0, |v| v),
-            PetsRecord::new(sigma, epsilon_k, viscosity, diffusion, thermal_conductivity),
+            PetsRecord::new(
+                sigma,
+                epsilon_k,
+                viscosity,
+                diffusion,
+                thermal_conductivity,
+                mu,
+            ),
             None,
         );
         Self(Arc::new(PetsParameters::new_pure(pure_record)))
@@ -263,7 +285,9 @@ impl_parameter!(PetsParameters, PyPetsParameters);
 #[pymodule]
 pub fn pets(_py: Python<'_>, m: &PyModule) -> PyResult<()> {
     m.add_class::<PyIdentifier>()?;
+    m.add_class::<PyMetadata>()?;
     m.add_class::<IdentifierOption>()?;
+    m.add_class::<CombiningRule>()?;
     m.add_class::<PyChemicalRecord>()?;
     m.add_class::<PyJobackRecord>()?;
 
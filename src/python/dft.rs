@@ -5,13 +5,23 @@ use crate::estimator::*;
 use crate::gc_pcsaft::python::PyGcPcSaftFunctionalParameters;
 #[cfg(feature = "gc_pcsaft")]
 use crate::gc_pcsaft::{GcPcSaftFunctional, GcPcSaftOptions};
-use crate::hard_sphere::{FMTFunctional, FMTVersion};
+use crate::hard_sphere::{FMTFunctional, FMTVersion, SizeDistribution};
 #[cfg(feature = "estimator")]
 use crate::impl_estimator;
+#[cfg(all(feature = "estimator", feature = "pcsaft"))]
+use crate::impl_estimator_pcsaft;
+#[cfg(feature = "estimator")]
+use crate::impl_estimator_surface_tension;
+#[cfg(feature = "lennard_jones")]
+use crate::lennard_jones::python::{PyLennardJonesParameters, PyTholCoefficients};
+#[cfg(feature = "lennard_jones")]
+use crate::lennard_jones::{LennardJonesFunctional, LennardJonesOptions, TholCoefficients};
 #[cfg(feature = "pcsaft")]
 use crate::pcsaft::python::PyPcSaftParameters;
 #[cfg(feature = "pcsaft")]
 use crate::pcsaft::{DQVariants, PcSaftFunctional, PcSaftOptions};
+#[cfg(all(feature = "estimator", feature = "pcsaft"))]
+use crate::pcsaft::{PcSaftFitParameter, PcSaftParameters};
 #[cfg(feature = "pets")]
 use crate::pets::python::PyPetsParameters;
 #[cfg(feature = "pets")]
@@ -21,6 +31,14 @@ use crate::saftvrqmie::python::PySaftVRQMieParameters;
 #[cfg(feature = "saftvrqmie")]
 use crate::saftvrqmie::{FeynmanHibbsOrder, SaftVRQMieFunctional, SaftVRQMieOptions};
 
+use feos_core::ideal_gas::WithIdealGas;
+use feos_core::parameter::{CombiningRule, ParameterError};
+use feos_core::python::ideal_gas::{PyIdealGasModel, PyIdealGasRecord};
+#[cfg(feature = "estimator")]
+use feos_core::python::parameter::PyIdentifier;
+use feos_core::python::solid::PySolidModel;
+use feos_core::python::reduced_units::PyReducedUnits;
+use feos_core::python::solver_log::PySolverLog;
 use feos_core::*;
 use feos_dft::adsorption::*;
 use feos_dft::interface::*;
@@ -28,7 +46,7 @@ use feos_dft::python::*;
 use feos_dft::solvation::*;
 use feos_dft::*;
 use numpy::convert::ToPyArray;
-use numpy::{PyArray1, PyArray2, PyArray4};
+use numpy::{PyArray1, PyArray2, PyArray3, PyArray4};
 use pyo3::exceptions::{PyIndexError, PyValueError};
 use pyo3::prelude::*;
 #[cfg(feature = "estimator")]
@@ -60,6 +78,12 @@ impl PyFunctionalVariant {
     ///     Tolerance for convergence of cross association. Defaults to 1e-10.
     /// dq_variant : DQVariants, optional
     ///     Combination rule used in the dipole/quadrupole term. Defaults to 'DQVariants.DQ35'
+    /// max_density : SINumber, optional
+    ///     Upper limit for the estimate of the maximum density that is used
+    ///     to initialize liquid densities. Defaults to no limit.
+    /// combining_rule : CombiningRule, optional
+    ///     Combining rule for the cross diameters and energy parameters.
+    ///     Defaults to CombiningRule.LorentzBerthelot.
     ///
     /// Returns
     /// -------
@@ -67,8 +91,8 @@ impl PyFunctionalVariant {
     #[cfg(feature = "pcsaft")]
     #[staticmethod]
     #[pyo3(
-        signature = (parameters, fmt_version=FMTVersion::WhiteBear, max_eta=0.5, max_iter_cross_assoc=50, tol_cross_assoc=1e-10, dq_variant=DQVariants::DQ35),
-        text_signature = "(parameters, fmt_version, max_eta=0.5, max_iter_cross_assoc=50, tol_cross_assoc=1e-10, dq_variant)"
+        signature = (parameters, fmt_version=FMTVersion::WhiteBear, max_eta=0.5, max_iter_cross_assoc=50, tol_cross_assoc=1e-10, dq_variant=DQVariants::DQ35, max_density=None, combining_rule=CombiningRule::LorentzBerthelot),
+        text_signature = "(parameters, fmt_version, max_eta=0.5, max_iter_cross_assoc=50, tol_cross_assoc=1e-10, dq_variant, max_density=None, combining_rule)"
     )]
     fn pcsaft(
         parameters: PyPcSaftParameters,
@@ -77,16 +101,23 @@ impl PyFunctionalVariant {
         max_iter_cross_assoc: usize,
         tol_cross_assoc: f64,
         dq_variant: DQVariants,
-    ) -> Self {
+        max_density: Option<PySINumber>,
+        combining_rule: CombiningRule,
+    ) -> PyResult<Self> {
+        let max_density =
+            max_density.map_or(Ok(MaxDensity::Heuristic), |d| MaxDensity::capped(d.into()))?;
         let options = PcSaftOptions {
             max_eta,
+            max_density,
             max_iter_cross_assoc,
             tol_cross_assoc,
             dq_variant,
+            polarizable: false,
+            combining_rule,
         };
-        Self(Arc::new(
+        Ok(Self(Arc::new(
             PcSaftFunctional::with_options(parameters.0, fmt_version, options).into(),
-        ))
+        )))
     }
 
     /// (heterosegmented) group contribution PC-SAFT Helmholtz energy functional.
@@ -103,6 +134,9 @@ impl PyFunctionalVariant {
     ///     Maximum number of iterations for cross association. Defaults to 50.
     /// tol_cross_assoc : float
     ///     Tolerance for convergence of cross association. Defaults to 1e-10.
+    /// max_density : SINumber, optional
+    ///     Upper limit for the estimate of the maximum density that is used
+    ///     to initialize liquid densities. Defaults to no limit.
     ///
     /// Returns
     /// -------
@@ -110,8 +144,8 @@ impl PyFunctionalVariant {
     #[cfg(feature = "gc_pcsaft")]
     #[staticmethod]
     #[pyo3(
-        signature = (parameters, fmt_version=FMTVersion::WhiteBear, max_eta=0.5, max_iter_cross_assoc=50, tol_cross_assoc=1e-10),
-        text_signature = "(parameters, fmt_version, max_eta=0.5, max_iter_cross_assoc=50, tol_cross_assoc=1e-10)"
+        signature = (parameters, fmt_version=FMTVersion::WhiteBear, max_eta=0.5, max_iter_cross_assoc=50, tol_cross_assoc=1e-10, max_density=None),
+        text_signature = "(parameters, fmt_version, max_eta=0.5, max_iter_cross_assoc=50, tol_cross_assoc=1e-10, max_density=None)"
     )]
     fn gc_pcsaft(
         parameters: PyGcPcSaftFunctionalParameters,
@@ -119,15 +153,19 @@ impl PyFunctionalVariant {
         max_eta: f64,
         max_iter_cross_assoc: usize,
         tol_cross_assoc: f64,
-    ) -> Self {
+        max_density: Option<PySINumber>,
+    ) -> PyResult<Self> {
+        let max_density =
+            max_density.map_or(Ok(MaxDensity::Heuristic), |d| MaxDensity::capped(d.into()))?;
         let options = GcPcSaftOptions {
             max_eta,
+            max_density,
             max_iter_cross_assoc,
             tol_cross_assoc,
         };
-        Self(Arc::new(
+        Ok(Self(Arc::new(
             GcPcSaftFunctional::with_options(parameters.0, fmt_version, options).into(),
-        ))
+        )))
     }
 
     /// PeTS Helmholtz energy functional without simplifications
@@ -141,6 +179,12 @@ impl PyFunctionalVariant {
     ///     The specific variant of the FMT term. Defaults to FMTVersion.WhiteBear
     /// max_eta : float, optional
     ///     Maximum packing fraction. Defaults to 0.5.
+    /// max_density : SINumber, optional
+    ///     Upper limit for the estimate of the maximum density that is used
+    ///     to initialize liquid densities. Defaults to no limit.
+    /// combining_rule : CombiningRule, optional
+    ///     Combining rule for the cross diameters and energy parameters.
+    ///     Defaults to CombiningRule.LorentzBerthelot.
     ///
     /// Returns
     /// -------
@@ -148,14 +192,135 @@ impl PyFunctionalVariant {
     #[cfg(feature = "pets")]
     #[staticmethod]
     #[pyo3(
-        signature = (parameters, fmt_version=FMTVersion::WhiteBear, max_eta=0.5),
-        text_signature = "(parameters, fmt_version, max_eta=0.5)"
+        signature = (parameters, fmt_version=FMTVersion::WhiteBear, max_eta=0.5, max_density=None, combining_rule=CombiningRule::LorentzBerthelot),
+        text_signature = "(parameters, fmt_version, max_eta=0.5, max_density=None, combining_rule)"
     )]
-    fn pets(parameters: PyPetsParameters, fmt_version: FMTVersion, max_eta: f64) -> Self {
-        let options = PetsOptions { max_eta };
-        Self(Arc::new(
+    fn pets(
+        parameters: PyPetsParameters,
+        fmt_version: FMTVersion,
+        max_eta: f64,
+        max_density: Option<PySINumber>,
+        combining_rule: CombiningRule,
+    ) -> PyResult<Self> {
+        let max_density =
+            max_density.map_or(Ok(MaxDensity::Heuristic), |d| MaxDensity::capped(d.into()))?;
+        let options = PetsOptions {
+            max_eta,
+            max_density,
+            combining_rule,
+        };
+        Ok(Self(Arc::new(
             PetsFunctional::with_options(parameters.0, fmt_version, options).into(),
-        ))
+        )))
+    }
+
+    /// PeTS Helmholtz energy functional of a PeTS-like Stockmayer fluid,
+    /// i.e., extended by a dipolar contribution.
+    ///
+    /// Parameters
+    /// ----------
+    /// parameters: PetsParameters
+    ///     The set of PeTS parameters.
+    /// mu: List[float]
+    ///     The dipole moments of all components in units of Debye.
+    /// fmt_version: FMTVersion, optional
+    ///     The specific variant of the FMT term. Defaults to FMTVersion.WhiteBear
+    /// max_eta : float, optional
+    ///     Maximum packing fraction. Defaults to 0.5.
+    /// max_density : SINumber, optional
+    ///     Upper limit for the estimate of the maximum density that is used
+    ///     to initialize liquid densities. Defaults to no limit.
+    /// combining_rule : CombiningRule, optional
+    ///     Combining rule for the cross diameters and energy parameters.
+    ///     Defaults to CombiningRule.LorentzBerthelot.
+    ///
+    /// Returns
+    /// -------
+    /// HelmholtzEnergyFunctional
+    #[cfg(feature = "pets")]
+    #[staticmethod]
+    #[pyo3(
+        signature = (parameters, mu, fmt_version=FMTVersion::WhiteBear, max_eta=0.5, max_density=None, combining_rule=CombiningRule::LorentzBerthelot),
+        text_signature = "(parameters, mu, fmt_version, max_eta=0.5, max_density=None, combining_rule)"
+    )]
+    fn pets_polar(
+        parameters: PyPetsParameters,
+        mu: Vec<f64>,
+        fmt_version: FMTVersion,
+        max_eta: f64,
+        max_density: Option<PySINumber>,
+        combining_rule: CombiningRule,
+    ) -> PyResult<Self> {
+        if mu.len() != parameters.0.sigma.len() {
+            return Err(PyValueError::new_err(format!(
+                "Expected {} dipole moments, got {}.",
+                parameters.0.sigma.len(),
+                mu.len()
+            )));
+        }
+        let max_density =
+            max_density.map_or(Ok(MaxDensity::Heuristic), |d| MaxDensity::capped(d.into()))?;
+        let options = PetsOptions {
+            max_eta,
+            max_density,
+            combining_rule,
+        };
+        Ok(Self(Arc::new(
+            PetsFunctional::polar(parameters.0, &mu.into(), fmt_version, options).into(),
+        )))
+    }
+
+    /// Helmholtz energy functional for the Lennard-Jones fluid based on the
+    /// reference equation of state of Thol et al. (2016).
+    ///
+    /// Parameters
+    /// ----------
+    /// parameters : LennardJonesParameters
+    ///     The Lennard-Jones parameters of all components.
+    /// coefficients : TholCoefficients, optional
+    ///     The coefficients of the equation of state.
+    ///     Defaults to the full Lennard-Jones fluid.
+    /// fmt_version: FMTVersion, optional
+    ///     The specific variant of the FMT term. Defaults to FMTVersion.WhiteBear
+    /// max_eta : float, optional
+    ///     Maximum packing fraction. Defaults to 0.5.
+    /// max_density : SINumber, optional
+    ///     Upper limit for the estimate of the maximum density that is used
+    ///     to initialize liquid densities. Defaults to no limit.
+    /// combining_rule : CombiningRule, optional
+    ///     Combining rule for the cross diameters and energy parameters.
+    ///     Defaults to CombiningRule.LorentzBerthelot.
+    ///
+    /// Returns
+    /// -------
+    /// HelmholtzEnergyFunctional
+    #[cfg(feature = "lennard_jones")]
+    #[staticmethod]
+    #[pyo3(
+        signature = (parameters, coefficients=None, fmt_version=FMTVersion::WhiteBear, max_eta=0.5, max_density=None, combining_rule=CombiningRule::LorentzBerthelot),
+        text_signature = "(parameters, coefficients=None, fmt_version, max_eta=0.5, max_density=None, combining_rule)"
+    )]
+    fn lennard_jones(
+        parameters: PyLennardJonesParameters,
+        coefficients: Option<PyTholCoefficients>,
+        fmt_version: FMTVersion,
+        max_eta: f64,
+        max_density: Option<PySINumber>,
+        combining_rule: CombiningRule,
+    ) -> PyResult<Self> {
+        let max_density =
+            max_density.map_or(Ok(MaxDensity::Heuristic), |d| MaxDensity::capped(d.into()))?;
+        let options = LennardJonesOptions {
+            max_eta,
+            max_density,
+            combining_rule,
+        };
+        let coefficients =
+            coefficients.map_or_else(|| Arc::new(TholCoefficients::lennard_jones()), |c| c.0);
+        Ok(Self(Arc::new(
+            LennardJonesFunctional::with_options(parameters.0, coefficients, fmt_version, options)
+                .into(),
+        )))
     }
 
     /// Helmholtz energy functional for hard sphere systems.
@@ -177,8 +342,43 @@ impl PyFunctionalVariant {
         ))
     }
 
+    /// Helmholtz energy functional for polydisperse hard sphere systems.
+    ///
+    /// The continuous size distribution is discretized by quadrature
+    /// nodes (diameters) and weights. The functional has a single
+    /// component, whose weighted densities are assembled by quadrature
+    /// over the nodes.
+    ///
+    /// Parameters
+    /// ----------
+    /// diameters : numpy.ndarray[float]
+    ///     The quadrature nodes, i.e., the diameters of the hard spheres in Angstrom.
+    /// weights : numpy.ndarray[float]
+    ///     The quadrature weights.
+    /// fmt_version : FMTVersion
+    ///     The specific variant of the FMT term.
+    ///
+    /// Returns
+    /// -------
+    /// HelmholtzEnergyFunctional
+    #[staticmethod]
+    #[pyo3(text_signature = "(diameters, weights, fmt_version)")]
+    fn fmt_polydisperse(
+        diameters: &PyArray1<f64>,
+        weights: &PyArray1<f64>,
+        fmt_version: FMTVersion,
+    ) -> PyResult<Self> {
+        let distribution =
+            SizeDistribution::new(diameters.to_owned_array(), weights.to_owned_array())?;
+        Ok(Self(Arc::new(
+            FMTFunctional::polydisperse(&distribution, fmt_version).into(),
+        )))
+    }
+
     /// SAFT-VRQ Mie Helmholtz energy functional.
     ///
+    /// Association is not implemented in the functional.
+    ///
     /// Parameters
     /// ----------
     /// parameters : SaftVRQMieParameters
@@ -192,6 +392,12 @@ impl PyFunctionalVariant {
     ///     Currently, only the first order is implemented.
     /// inc_nonadd_term : bool, optional
     ///     Include non-additive correction to the hard-sphere reference. Defaults to True.
+    /// max_density : SINumber, optional
+    ///     Upper limit for the estimate of the maximum density that is used
+    ///     to initialize liquid densities. Defaults to no limit.
+    /// combining_rule : CombiningRule, optional
+    ///     Combining rule for the cross diameters and energy parameters.
+    ///     Defaults to CombiningRule.LorentzBerthelot.
     ///
     /// Returns
     /// -------
@@ -199,8 +405,8 @@ impl PyFunctionalVariant {
     #[cfg(feature = "saftvrqmie")]
     #[staticmethod]
     #[pyo3(
-        signature = (parameters, fmt_version=FMTVersion::WhiteBear, max_eta=0.5, fh_order=FeynmanHibbsOrder::FH1, inc_nonadd_term=true),
-        text_signature = "(parameters, fmt_version, max_eta=0.5, fh_order, inc_nonadd_term=True)"
+        signature = (parameters, fmt_version=FMTVersion::WhiteBear, max_eta=0.5, fh_order=FeynmanHibbsOrder::FH1, inc_nonadd_term=true, max_density=None, combining_rule=CombiningRule::LorentzBerthelot),
+        text_signature = "(parameters, fmt_version, max_eta=0.5, fh_order, inc_nonadd_term=True, max_density=None, combining_rule)"
     )]
     fn saftvrqmie(
         parameters: PySaftVRQMieParameters,
@@ -208,15 +414,121 @@ impl PyFunctionalVariant {
         max_eta: f64,
         fh_order: FeynmanHibbsOrder,
         inc_nonadd_term: bool,
-    ) -> Self {
+        max_density: Option<PySINumber>,
+        combining_rule: CombiningRule,
+    ) -> PyResult<Self> {
+        if !parameters.0.association.assoc_comp.is_empty() {
+            return Err(PyValueError::new_err(
+                "Association is not implemented in the SAFT-VRQ Mie Helmholtz energy functional.",
+            ));
+        }
+        let max_density =
+            max_density.map_or(Ok(MaxDensity::Heuristic), |d| MaxDensity::capped(d.into()))?;
         let options = SaftVRQMieOptions {
             max_eta,
+            max_density,
             fh_order,
             inc_nonadd_term,
+            combining_rule,
+            ..Default::default()
         };
-        Self(Arc::new(
+        Ok(Self(Arc::new(
             SaftVRQMieFunctional::with_options(parameters.0, fmt_version, options).into(),
-        ))
+        )))
+    }
+
+    /// Tabulate the pair potentials of all pairs of segments, e.g.,
+    /// to use them in molecular simulations.
+    ///
+    /// The tables are named `PAIR_i_j` (i <= j) with segment indices
+    /// starting at 1.
+    ///
+    /// Parameters
+    /// ----------
+    /// temperature : SINumber
+    ///     The temperature.
+    /// distance : SIArray1
+    ///     The positive and strictly increasing distances.
+    ///
+    /// Returns
+    /// -------
+    /// List[PotentialTable]
+    #[pyo3(text_signature = "($self, temperature, distance)")]
+    fn pair_potential_tables(
+        &self,
+        temperature: PySINumber,
+        distance: PySIArray1,
+    ) -> PyResult<Vec<PyPotentialTable>> {
+        Ok(self
+            .0
+            .pair_potential_tables(temperature.into(), &distance.into())?
+            .into_iter()
+            .map(PyPotentialTable)
+            .collect())
+    }
+
+    /// Tabulate the potential of a single planar wall for all segments,
+    /// e.g., to use it in molecular simulations.
+    ///
+    /// The distance is measured from the wall. The tables are named
+    /// `WALL_i` with segment indices starting at 1.
+    ///
+    /// Parameters
+    /// ----------
+    /// potential : ExternalPotential
+    ///     The external potential of the wall.
+    /// temperature : SINumber
+    ///     The temperature.
+    /// distance : SIArray1
+    ///     The positive and strictly increasing distances.
+    ///
+    /// Returns
+    /// -------
+    /// List[PotentialTable]
+    #[pyo3(text_signature = "($self, potential, temperature, distance)")]
+    fn wall_potential_tables(
+        &self,
+        potential: PyExternalPotential,
+        temperature: PySINumber,
+        distance: PySIArray1,
+    ) -> PyResult<Vec<PyPotentialTable>> {
+        Ok(potential
+            .0
+            .wall_potential_tables(&**self.0, temperature.into(), &distance.into())?
+            .into_iter()
+            .map(PyPotentialTable)
+            .collect())
+    }
+
+    /// Creates a Helmholtz energy functional from a json string created with `to_json_str`.
+    ///
+    /// Parameters
+    /// ----------
+    /// json : str
+    ///     The json string containing the Helmholtz energy functional.
+    ///
+    /// Returns
+    /// -------
+    /// HelmholtzEnergyFunctional
+    #[staticmethod]
+    #[pyo3(text_signature = "(json)")]
+    fn from_json_str(json: &str) -> Result<Self, ParameterError> {
+        Ok(Self(Arc::new(serde_json::from_str(json)?)))
+    }
+
+    /// Creates a json string containing the parameters, options
+    /// and ideal gas model of the Helmholtz energy functional.
+    ///
+    /// Returns
+    /// -------
+    /// str
+    fn to_json_str(&self) -> Result<String, ParameterError> {
+        Ok(serde_json::to_string(self.0.as_ref())?)
+    }
+
+    fn __reduce__(&self, py: Python<'_>) -> PyResult<(PyObject, (String,))> {
+        let from_json_str = py.get_type::<Self>().getattr("from_json_str")?;
+        Ok((from_json_str.into(), (self.to_json_str()?,)))
     }
 }
 
@@ -227,7 +539,7 @@ impl_state_molarweight!(DFT<FunctionalVariant>, PyFunctionalVariant);
 impl_phase_equilibrium!(DFT<FunctionalVariant>, PyFunctionalVariant);
 
 impl_planar_interface!(FunctionalVariant);
-impl_surface_tension_diagram!(FunctionalVariant);
+impl_surface_tension_diagram!(FunctionalVariant, PyFunctionalVariant);
 
 impl_pore!(FunctionalVariant, PyFunctionalVariant);
 impl_adsorption!(FunctionalVariant, PyFunctionalVariant);
@@ -237,6 +549,12 @@ impl_solvation_profile!(FunctionalVariant);
 
 #[cfg(feature = "estimator")]
 impl_estimator!(DFT<FunctionalVariant>, PyFunctionalVariant);
+#[cfg(feature = "estimator")]
+impl_estimator_surface_tension!(DFT<FunctionalVariant>, PyFunctionalVariant);
+#[cfg(all(feature = "estimator", feature = "pcsaft"))]
+impl_estimator_pcsaft!(DFT<FunctionalVariant>, PyFunctionalVariant, |p| {
+    PcSaftFunctional::new(p).into()
+});
 
 #[pymodule]
 pub fn dft(_py: Python<'_>, m: &PyModule) -> PyResult<()> {
@@ -248,19 +566,39 @@ pub fn dft(_py: Python<'_>, m: &PyModule) -> PyResult<()> {
     m.add_class::<PyStateVec>()?;
     m.add_class::<PyPhaseDiagram>()?;
     m.add_class::<PyPhaseEquilibrium>()?;
+    m.add_class::<PyIdealGasRecord>()?;
+    m.add_class::<PyIdealGasModel>()?;
+    m.add_class::<PySolidModel>()?;
+    m.add_class::<PySolverLog>()?;
+    m.add_class::<PyReducedUnits>()?;
     m.add_class::<FMTVersion>()?;
 
     m.add_class::<PyPlanarInterface>()?;
     m.add_class::<Geometry>()?;
     m.add_class::<PyPore1D>()?;
+    m.add_class::<PyWetting>()?;
+    m.add_class::<PyThinFilm>()?;
+    m.add_class::<PyPore2D>()?;
     m.add_class::<PyPore3D>()?;
     m.add_class::<PyPairCorrelation>()?;
+    m.add_class::<PyPairCorrelationMatrix>()?;
     m.add_class::<PyExternalPotential>()?;
     m.add_class::<PyAdsorption1D>()?;
+    m.add_class::<PyAdsorption2D>()?;
     m.add_class::<PyAdsorption3D>()?;
+    m.add_class::<PyAdsorptionHysteresis1D>()?;
+    m.add_class::<PyAdsorptionHysteresis2D>()?;
+    m.add_class::<PyAdsorptionHysteresis3D>()?;
+    m.add_class::<PyPureComponentIsotherm>()?;
+    m.add_class::<PyIdealAdsorbedSolution>()?;
+    m.add_class::<PyFixedBed>()?;
+    m.add_class::<PyWorkingCapacity>()?;
+    m.add_class::<PyBreakthroughCurve>()?;
     m.add_class::<PySurfaceTensionDiagram>()?;
     m.add_class::<PyDFTSolver>()?;
     m.add_class::<PySolvationProfile>()?;
+    m.add_class::<PyPotentialTable>()?;
+    m.add_class::<PySimulationProfile>()?;
 
     #[cfg(feature = "estimator")]
     m.add_wrapped(wrap_pymodule!(estimator_dft))?;
@@ -273,5 +611,10 @@ pub fn dft(_py: Python<'_>, m: &PyModule) -> PyResult<()> {
 pub fn estimator_dft(_py: Python<'_>, m: &PyModule) -> PyResult<()> {
     m.add_class::<PyDataSet>()?;
     m.add_class::<PyEstimator>()?;
-    m.add_class::<PyLoss>()
+    m.add_class::<PyThermoML>()?;
+    m.add_class::<PyLoss>()?;
+    m.add_class::<Optimizer>()?;
+    #[cfg(feature = "pcsaft")]
+    m.add_class::<PyPosterior>()?;
+    Ok(())
 }
@@ -9,10 +9,18 @@ use crate::gc_pcsaft::{GcPcSaft, GcPcSaftOptions};
 use crate::impl_estimator;
 #[cfg(all(feature = "estimator", feature = "pcsaft"))]
 use crate::impl_estimator_entropy_scaling;
+#[cfg(all(feature = "estimator", feature = "pcsaft"))]
+use crate::impl_estimator_pcsaft;
+#[cfg(feature = "lennard_jones")]
+use crate::lennard_jones::python::{PyLennardJonesParameters, PyTholCoefficients};
+#[cfg(feature = "lennard_jones")]
+use crate::lennard_jones::{LennardJones, LennardJonesOptions, TholCoefficients};
 #[cfg(feature = "pcsaft")]
 use crate::pcsaft::python::PyPcSaftParameters;
 #[cfg(feature = "pcsaft")]
 use crate::pcsaft::{DQVariants, PcSaft, PcSaftOptions};
+#[cfg(all(feature = "estimator", feature = "pcsaft"))]
+use crate::pcsaft::{PcSaftFitParameter, PcSaftParameters};
 #[cfg(feature = "pets")]
 use crate::pets::python::PyPetsParameters;
 #[cfg(feature = "pets")]
@@ -29,6 +37,15 @@ use crate::uvtheory::{Perturbation, UVTheory, UVTheoryOptions, VirialOrder};
 use feos_core::cubic::PengRobinson;
 use feos_core::python::cubic::PyPengRobinsonParameters;
 use feos_core::python::user_defined::PyEoSObj;
+use feos_core::ideal_gas::WithIdealGas;
+use feos_core::parameter::{CombiningRule, ParameterError};
+use feos_core::python::chemical_equilibrium::PyChemicalReactions;
+use feos_core::python::ideal_gas::{PyIdealGasModel, PyIdealGasRecord};
+use feos_core::python::solid::PySolidModel;
+use feos_core::python::reduced_units::PyReducedUnits;
+use feos_core::python::solver_log::PySolverLog;
+#[cfg(feature = "estimator")]
+use feos_core::python::parameter::PyIdentifier;
 use feos_core::*;
 use numpy::convert::ToPyArray;
 use numpy::{PyArray1, PyArray2};
@@ -62,6 +79,15 @@ impl PyEosVariant {
     ///     Tolerance for convergence of cross association. Defaults to 1e-10.
     /// dq_variant : DQVariants, optional
     ///     Combination rule used in the dipole/quadrupole term. Defaults to 'DQVariants.DQ35'
+    /// max_density : SINumber, optional
+    ///     Upper limit for the estimate of the maximum density that is used
+    ///     to initialize liquid densities. Defaults to no limit.
+    /// polarizable : bool, optional
+    ///     Account for induced dipoles of components with a polarizability.
+    ///     Defaults to False.
+    /// combining_rule : CombiningRule, optional
+    ///     Combining rule for the cross diameters and energy parameters.
+    ///     Defaults to CombiningRule.LorentzBerthelot.
     ///
     /// Returns
     /// -------
@@ -71,8 +97,8 @@ impl PyEosVariant {
     #[cfg(feature = "pcsaft")]
     #[staticmethod]
     #[pyo3(
-        signature = (parameters, max_eta=0.5, max_iter_cross_assoc=50, tol_cross_assoc=1e-10, dq_variant=DQVariants::DQ35),
-        text_signature = "(parameters, max_eta=0.5, max_iter_cross_assoc=50, tol_cross_assoc=1e-10, dq_variant)"
+        signature = (parameters, max_eta=0.5, max_iter_cross_assoc=50, tol_cross_assoc=1e-10, dq_variant=DQVariants::DQ35, max_density=None, polarizable=false, combining_rule=CombiningRule::LorentzBerthelot),
+        text_signature = "(parameters, max_eta=0.5, max_iter_cross_assoc=50, tol_cross_assoc=1e-10, dq_variant, max_density=None, polarizable=False, combining_rule)"
     )]
     pub fn pcsaft(
         parameters: PyPcSaftParameters,
@@ -80,17 +106,25 @@ impl PyEosVariant {
         max_iter_cross_assoc: usize,
         tol_cross_assoc: f64,
         dq_variant: DQVariants,
-    ) -> Self {
+        max_density: Option<PySINumber>,
+        polarizable: bool,
+        combining_rule: CombiningRule,
+    ) -> PyResult<Self> {
+        let max_density =
+            max_density.map_or(Ok(MaxDensity::Heuristic), |d| MaxDensity::capped(d.into()))?;
         let options = PcSaftOptions {
             max_eta,
+            max_density,
             max_iter_cross_assoc,
             tol_cross_assoc,
             dq_variant,
+            polarizable,
+            combining_rule,
         };
-        Self(Arc::new(EosVariant::PcSaft(PcSaft::with_options(
+        Ok(Self(Arc::new(EosVariant::PcSaft(PcSaft::with_options(
             parameters.0,
             options,
-        ))))
+        )))))
     }
 
     /// (heterosegmented) group contribution PC-SAFT equation of state.
@@ -105,6 +139,9 @@ impl PyEosVariant {
     ///     Maximum number of iterations for cross association. Defaults to 50.
     /// tol_cross_assoc : float
     ///     Tolerance for convergence of cross association. Defaults to 1e-10.
+    /// max_density : SINumber, optional
+    ///     Upper limit for the estimate of the maximum density that is used
+    ///     to initialize liquid densities. Defaults to no limit.
     ///
     /// Returns
     /// -------
@@ -114,23 +151,26 @@ impl PyEosVariant {
     #[cfg(feature = "gc_pcsaft")]
     #[staticmethod]
     #[pyo3(
-        signature = (parameters, max_eta=0.5, max_iter_cross_assoc=50, tol_cross_assoc=1e-10),
-        text_signature = "(parameters, max_eta=0.5, max_iter_cross_assoc=50, tol_cross_assoc=1e-10)"
+        signature = (parameters, max_eta=0.5, max_iter_cross_assoc=50, tol_cross_assoc=1e-10, max_density=None),
+        text_signature = "(parameters, max_eta=0.5, max_iter_cross_assoc=50, tol_cross_assoc=1e-10, max_density=None)"
     )]
     pub fn gc_pcsaft(
         parameters: PyGcPcSaftEosParameters,
         max_eta: f64,
         max_iter_cross_assoc: usize,
         tol_cross_assoc: f64,
-    ) -> Self {
+        max_density: Option<PySINumber>,
+    ) -> PyResult<Self> {
+        let max_density =
+            max_density.map_or(Ok(MaxDensity::Heuristic), |d| MaxDensity::capped(d.into()))?;
         let options = GcPcSaftOptions {
             max_eta,
+            max_density,
             max_iter_cross_assoc,
             tol_cross_assoc,
         };
-        Self(Arc::new(EosVariant::GcPcSaft(GcPcSaft::with_options(
-            parameters.0,
-            options,
+        Ok(Self(Arc::new(EosVariant::GcPcSaft(
+            GcPcSaft::with_options(parameters.0, options),
         ))))
     }
 
@@ -177,6 +217,12 @@ impl PyEosVariant {
     ///     The parameters of the PeTS equation of state to use.
     /// max_eta : float, optional
     ///     Maximum packing fraction. Defaults to 0.5.
+    /// max_density : SINumber, optional
+    ///     Upper limit for the estimate of the maximum density that is used
+    ///     to initialize liquid densities. Defaults to no limit.
+    /// combining_rule : CombiningRule, optional
+    ///     Combining rule for the cross diameters and energy parameters.
+    ///     Defaults to CombiningRule.LorentzBerthelot.
     ///
     /// Returns
     /// -------
@@ -185,13 +231,27 @@ impl PyEosVariant {
     ///     states.
     #[cfg(feature = "pets")]
     #[staticmethod]
-    #[pyo3(signature = (parameters, max_eta=0.5), text_signature = "(parameters, max_eta=0.5)")]
-    fn pets(parameters: PyPetsParameters, max_eta: f64) -> Self {
-        let options = PetsOptions { max_eta };
-        Self(Arc::new(EosVariant::Pets(Pets::with_options(
+    #[pyo3(
+        signature = (parameters, max_eta=0.5, max_density=None, combining_rule=CombiningRule::LorentzBerthelot),
+        text_signature = "(parameters, max_eta=0.5, max_density=None, combining_rule)"
+    )]
+    fn pets(
+        parameters: PyPetsParameters,
+        max_eta: f64,
+        max_density: Option<PySINumber>,
+        combining_rule: CombiningRule,
+    ) -> PyResult<Self> {
+        let max_density =
+            max_density.map_or(Ok(MaxDensity::Heuristic), |d| MaxDensity::capped(d.into()))?;
+        let options = PetsOptions {
+            max_eta,
+            max_density,
+            combining_rule,
+        };
+        Ok(Self(Arc::new(EosVariant::Pets(Pets::with_options(
             parameters.0,
             options,
-        ))))
+        )))))
     }
 
     /// UV-Theory equation of state.
@@ -207,6 +267,12 @@ impl PyEosVariant {
     /// virial_order : VirialOrder, optional
     ///     Highest order of virial coefficient to consider.
     ///     Defaults to second order (original uv-theory).
+    /// max_density : SINumber, optional
+    ///     Upper limit for the estimate of the maximum density that is used
+    ///     to initialize liquid densities. Defaults to no limit.
+    /// combining_rule : CombiningRule, optional
+    ///     Combining rule for the cross diameters and energy parameters.
+    ///     Defaults to CombiningRule.LorentzBerthelot.
     ///
     /// Returns
     /// -------
@@ -216,25 +282,81 @@ impl PyEosVariant {
     #[cfg(feature = "uvtheory")]
     #[staticmethod]
     #[pyo3(
-        signature = (parameters, max_eta=0.5, perturbation=Perturbation::WeeksChandlerAndersen, virial_order=VirialOrder::Second),    
-        text_signature = "(parameters, max_eta=0.5, perturbation, virial_order)"
+        signature = (parameters, max_eta=0.5, perturbation=Perturbation::WeeksChandlerAndersen, virial_order=VirialOrder::Second, max_density=None, combining_rule=CombiningRule::LorentzBerthelot),
+        text_signature = "(parameters, max_eta=0.5, perturbation, virial_order, max_density=None, combining_rule)"
     )]
     fn uvtheory(
         parameters: PyUVParameters,
         max_eta: f64,
         perturbation: Perturbation,
         virial_order: VirialOrder,
+        max_density: Option<PySINumber>,
+        combining_rule: CombiningRule,
     ) -> PyResult<Self> {
+        let max_density =
+            max_density.map_or(Ok(MaxDensity::Heuristic), |d| MaxDensity::capped(d.into()))?;
         let options = UVTheoryOptions {
             max_eta,
+            max_density,
             perturbation,
             virial_order,
+            combining_rule,
         };
         Ok(Self(Arc::new(EosVariant::UVTheory(
             UVTheory::with_options(parameters.0, options)?,
         ))))
     }
 
+    /// Lennard-Jones reference equation of state of Thol et al. (2016).
+    ///
+    /// Parameters
+    /// ----------
+    /// parameters : LennardJonesParameters
+    ///     The Lennard-Jones parameters of all components.
+    /// coefficients : TholCoefficients, optional
+    ///     The coefficients of the equation of state.
+    ///     Defaults to the full Lennard-Jones fluid.
+    /// max_eta : float, optional
+    ///     Maximum packing fraction. Defaults to 0.5.
+    /// max_density : SINumber, optional
+    ///     Upper limit for the estimate of the maximum density that is used
+    ///     to initialize liquid densities. Defaults to no limit.
+    /// combining_rule : CombiningRule, optional
+    ///     Combining rule for the cross diameters and energy parameters.
+    ///     Defaults to CombiningRule.LorentzBerthelot.
+    ///
+    /// Returns
+    /// -------
+    /// EquationOfState
+    ///     The Lennard-Jones equation of state that can be used to compute
+    ///     thermodynamic states.
+    #[cfg(feature = "lennard_jones")]
+    #[staticmethod]
+    #[pyo3(
+        signature = (parameters, coefficients=None, max_eta=0.5, max_density=None, combining_rule=CombiningRule::LorentzBerthelot),
+        text_signature = "(parameters, coefficients=None, max_eta=0.5, max_density=None, combining_rule)"
+    )]
+    fn lennard_jones(
+        parameters: PyLennardJonesParameters,
+        coefficients: Option<PyTholCoefficients>,
+        max_eta: f64,
+        max_density: Option<PySINumber>,
+        combining_rule: CombiningRule,
+    ) -> PyResult<Self> {
+        let max_density =
+            max_density.map_or(Ok(MaxDensity::Heuristic), |d| MaxDensity::capped(d.into()))?;
+        let options = LennardJonesOptions {
+            max_eta,
+            max_density,
+            combining_rule,
+        };
+        let coefficients =
+            coefficients.map_or_else(|| Arc::new(TholCoefficients::lennard_jones()), |c| c.0);
+        Ok(Self(Arc::new(EosVariant::LennardJones(
+            LennardJones::with_options(parameters.0, coefficients, options),
+        ))))
+    }
+
     /// SAFT-VRQ Mie equation of state.
     ///
     /// Parameters
@@ -248,6 +370,16 @@ impl PyEosVariant {
     ///     Currently, only the first order is implemented.
     /// inc_nonadd_term : bool, optional
     ///     Include non-additive correction to the hard-sphere reference. Defaults to True.
+    /// max_density : SINumber, optional
+    ///     Upper limit for the estimate of the maximum density that is used
+    ///     to initialize liquid densities. Defaults to no limit.
+    /// max_iter_cross_assoc : unsigned integer, optional
+    ///     Maximum number of iterations for cross association. Defaults to 50.
+    /// tol_cross_assoc : float
+    ///     Tolerance for convergence of cross association. Defaults to 1e-10.
+    /// combining_rule : CombiningRule, optional
+    ///     Combining rule for the cross diameters and energy parameters.
+    ///     Defaults to CombiningRule.LorentzBerthelot.
     ///
     /// Returns
     /// -------
@@ -257,25 +389,68 @@ impl PyEosVariant {
     #[cfg(feature = "saftvrqmie")]
     #[staticmethod]
     #[pyo3(
-        signature = (parameters, max_eta=0.5, fh_order=FeynmanHibbsOrder::FH1, inc_nonadd_term=true),
-        text_signature = "(parameters, max_eta=0.5, fh_order, inc_nonadd_term=True)"
+        signature = (parameters, max_eta=0.5, fh_order=FeynmanHibbsOrder::FH1, inc_nonadd_term=true, max_density=None, max_iter_cross_assoc=50, tol_cross_assoc=1e-10, combining_rule=CombiningRule::LorentzBerthelot),
+        text_signature = "(parameters, max_eta=0.5, fh_order, inc_nonadd_term=True, max_density=None, max_iter_cross_assoc=50, tol_cross_assoc=1e-10, combining_rule)"
     )]
     fn saftvrqmie(
         parameters: PySaftVRQMieParameters,
         max_eta: f64,
         fh_order: FeynmanHibbsOrder,
         inc_nonadd_term: bool,
-    ) -> Self {
+        max_density: Option<PySINumber>,
+        max_iter_cross_assoc: usize,
+        tol_cross_assoc: f64,
+        combining_rule: CombiningRule,
+    ) -> PyResult<Self> {
+        let max_density =
+            max_density.map_or(Ok(MaxDensity::Heuristic), |d| MaxDensity::capped(d.into()))?;
         let options = SaftVRQMieOptions {
             max_eta,
+            max_density,
             fh_order,
             inc_nonadd_term,
+            max_iter_cross_assoc,
+            tol_cross_assoc,
+            combining_rule,
         };
-        Self(Arc::new(EosVariant::SaftVRQMie(SaftVRQMie::with_options(
-            parameters.0,
-            options,
+        Ok(Self(Arc::new(EosVariant::SaftVRQMie(
+            SaftVRQMie::with_options(parameters.0, options),
         ))))
     }
+
+    /// Creates an equation of state from a json string created with `to_json_str`.
+    ///
+    /// Parameters
+    /// ----------
+    /// json : str
+    ///     The json string containing the equation of state.
+    ///
+    /// Returns
+    /// -------
+    /// EquationOfState
+    #[staticmethod]
+    #[pyo3(text_signature = "(json)")]
+    fn from_json_str(json: &str) -> Result<Self, ParameterError> {
+        Ok(Self(Arc::new(serde_json::from_str(json)?)))
+    }
+
+    /// Creates a json string containing the parameters, options
+    /// and ideal gas model of the equation of state.
+    ///
+    /// Equations of state that are implemented in Python
+    /// can not be serialized.
+    ///
+    /// Returns
+    /// -------
+    /// str
+    fn to_json_str(&self) -> Result<String, ParameterError> {
+        Ok(serde_json::to_string(self.0.as_ref())?)
+    }
+
+    fn __reduce__(&self, py: Python<'_>) -> PyResult<(PyObject, (String,))> {
+        let from_json_str = py.get_type::<Self>().getattr("from_json_str")?;
+        Ok((from_json_str.into(), (self.to_json_str()?,)))
+    }
 }
 
 impl_equation_of_state!(PyEosVariant);
@@ -285,11 +460,17 @@ impl_state_molarweight!(EosVariant, PyEosVariant);
 #[cfg(feature = "pcsaft")]
 impl_state_entropy_scaling!(EosVariant, PyEosVariant);
 impl_phase_equilibrium!(EosVariant, PyEosVariant);
+impl_stream!(EosVariant, PyEosVariant);
+impl_chemical_equilibrium!(EosVariant, PyEosVariant);
 
 #[cfg(feature = "estimator")]
 impl_estimator!(EosVariant, PyEosVariant);
 #[cfg(all(feature = "estimator", feature = "pcsaft"))]
 impl_estimator_entropy_scaling!(EosVariant, PyEosVariant);
+#[cfg(all(feature = "estimator", feature = "pcsaft"))]
+impl_estimator_pcsaft!(EosVariant, PyEosVariant, |p| EosVariant::PcSaft(
+    PcSaft::new(p)
+));
 
 #[pymodule]
 pub fn eos(_py: Python<'_>, m: &PyModule) -> PyResult<()> {
@@ -301,6 +482,15 @@ pub fn eos(_py: Python<'_>, m: &PyModule) -> PyResult<()> {
     m.add_class::<PyStateVec>()?;
     m.add_class::<PyPhaseDiagram>()?;
     m.add_class::<PyPhaseEquilibrium>()?;
+    m.add_class::<PyStream>()?;
+    m.add_class::<PyIsoline>()?;
+    m.add_class::<PyChemicalReactions>()?;
+    m.add_class::<PyChemicalEquilibrium>()?;
+    m.add_class::<PyIdealGasRecord>()?;
+    m.add_class::<PyIdealGasModel>()?;
+    m.add_class::<PySolidModel>()?;
+    m.add_class::<PySolverLog>()?;
+    m.add_class::<PyReducedUnits>()?;
 
     #[cfg(feature = "estimator")]
     m.add_wrapped(wrap_pymodule!(estimator_eos))?;
@@ -313,6 +503,10 @@ pub fn eos(_py: Python<'_>, m: &PyModule) -> PyResult<()> {
 pub fn estimator_eos(_py: Python<'_>, m: &PyModule) -> PyResult<()> {
     m.add_class::<PyDataSet>()?;
     m.add_class::<PyEstimator>()?;
+    m.add_class::<PyThermoML>()?;
     m.add_class::<PyLoss>()?;
+    m.add_class::<Optimizer>()?;
+    #[cfg(feature = "pcsaft")]
+    m.add_class::<PyPosterior>()?;
     m.add_class::<Phase>()
 }
@@ -1,5 +1,7 @@
 #[cfg(feature = "gc_pcsaft")]
 use crate::gc_pcsaft::python::gc_pcsaft as gc_pcsaft_module;
+#[cfg(feature = "lennard_jones")]
+use crate::lennard_jones::python::lennard_jones as lennard_jones_module;
 #[cfg(feature = "pcsaft")]
 use crate::pcsaft::python::pcsaft as pcsaft_module;
 #[cfg(feature = "pets")]
@@ -42,6 +44,8 @@ pub fn feos(py: Python<'_>, m: &PyModule) -> PyResult<()> {
     m.add_wrapped(wrap_pymodule!(uvtheory_module))?;
     #[cfg(feature = "saftvrqmie")]
     m.add_wrapped(wrap_pymodule!(saftvrqmie_module))?;
+    #[cfg(feature = "lennard_jones")]
+    m.add_wrapped(wrap_pymodule!(lennard_jones_module))?;
 
     set_path(py, m, "feos.si", "quantity")?;
     set_path(py, m, "feos.eos", "eos")?;
@@ -62,6 +66,8 @@ pub fn feos(py: Python<'_>, m: &PyModule) -> PyResult<()> {
     set_path(py, m, "feos.uvtheory", "uvtheory")?;
     #[cfg(feature = "saftvrqmie")]
     set_path(py, m, "feos.saftvrqmie", "saftvrqmie")?;
+    #[cfg(feature = "lennard_jones")]
+    set_path(py, m, "feos.lennard_jones", "lennard_jones")?;
 
     py.run(
         "\
@@ -78,12 +84,17 @@ quantity.SIArray4.__module__ = 'feos.si'
     Ok(())
 }
 
+/// Registers the submodule in `sys.modules` and sets the `__module__`
+/// attribute of its classes, so that objects can be pickled.
 fn set_path(py: Python<'_>, m: &PyModule, path: &str, module: &str) -> PyResult<()> {
     py.run(
         &format!(
             "\
 import sys
 sys.modules['{path}'] = {module}
+for _cls in vars({module}).values():
+    if isinstance(_cls, type) and _cls.__module__ == 'builtins':
+        _cls.__module__ = '{path}'
     "
         ),
         None,
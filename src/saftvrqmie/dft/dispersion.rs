@@ -56,20 +56,16 @@ impl<N: DualNum<f64> + ScalarOperand> FunctionalContributionDual<N> for Attracti
         let p = &self.parameters;
         let n = p.m.len();
 
-        // temperature dependent segment radius // calc & store this in struct
-        let s_eff_ij =
-            Array2::from_shape_fn((n, n), |(i, j)| p.calc_sigma_eff_ij(i, j, temperature));
+        // temperature dependent segment radius (cached in the parameters)
+        let s_eff_ij = p.sigma_eff_ij(temperature);
 
-        // temperature dependent segment radius // calc & store this in struct
-        let d_hs_ij = Array2::from_shape_fn((n, n), |(i, j)| {
-            p.hs_diameter_ij(i, j, temperature, s_eff_ij[[i, j]])
-        });
+        // temperature dependent segment radius (cached in the parameters)
+        let d_hs_ij = p.d_hs_ij(temperature);
 
-        // temperature dependent well depth // calc & store this in struct
-        let epsilon_k_eff_ij =
-            Array2::from_shape_fn((n, n), |(i, j)| p.calc_epsilon_k_eff_ij(i, j, temperature));
+        // temperature dependent well depth (cached in the parameters)
+        let epsilon_k_eff_ij = p.epsilon_k_eff_ij(temperature);
 
-        // temperature dependent well depth // calc & store this in struct
+        // temperature dependent well depth
         let dq_ij = Array2::from_shape_fn((n, n), |(i, j)| p.quantum_d_ij(i, j, temperature));
 
         // alphas .... // calc & store this in struct
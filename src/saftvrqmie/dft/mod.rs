@@ -1,17 +1,21 @@
-use crate::hard_sphere::{FMTContribution, FMTVersion, HardSphereProperties, MonomerShape};
+use crate::hard_sphere::{FMTContribution, FMTVersion};
 use crate::saftvrqmie::eos::SaftVRQMieOptions;
-use crate::saftvrqmie::parameters::SaftVRQMieParameters;
+use crate::saftvrqmie::parameters::{
+    SaftVRQMieBinaryRecord, SaftVRQMieParameters, SaftVRQMieRecord,
+};
 use dispersion::AttractiveFunctional;
-use feos_core::joback::Joback;
-use feos_core::parameter::Parameter;
-use feos_core::{IdealGasContribution, MolarWeight};
+use feos_core::ideal_gas::{IdealGasModel, WithIdealGas};
+use feos_core::joback::JobackRecord;
+use feos_core::parameter::{Parameter, ParameterSet};
+use feos_core::{EosResult, EquationOfState, IdealGasContribution, MolarWeight};
 use feos_dft::adsorption::FluidParameters;
 use feos_dft::solvation::PairPotential;
 use feos_dft::{FunctionalContribution, HelmholtzEnergyFunctional, MoleculeShape, DFT};
 use ndarray::{Array, Array1, Array2};
 use non_additive_hs::NonAddHardSphereFunctional;
-use num_dual::DualNum;
 use quantity::si::*;
+use serde::de::Error;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
 use std::f64::consts::FRAC_PI_6;
 use std::sync::Arc;
 
@@ -19,12 +23,16 @@ mod dispersion;
 mod non_additive_hs;
 
 /// SAFT-VRQ Mie Helmholtz energy functional.
+///
+/// Association is not implemented in the functional. Use the
+/// [SaftVRQMie](crate::saftvrqmie::SaftVRQMie) equation of state for
+/// associating components.
 pub struct SaftVRQMieFunctional {
     pub parameters: Arc<SaftVRQMieParameters>,
     fmt_version: FMTVersion,
     options: SaftVRQMieOptions,
     contributions: Vec<Box<dyn FunctionalContribution>>,
-    joback: Joback,
+    ideal_gas: IdealGasModel,
 }
 
 impl SaftVRQMieFunctional {
@@ -40,11 +48,20 @@ impl SaftVRQMieFunctional {
         Self::with_options(parameters, fmt_version, SaftVRQMieOptions::default())
     }
 
+    /// # Panics
+    ///
+    /// If any of the components is associating.
     pub fn with_options(
         parameters: Arc<SaftVRQMieParameters>,
         fmt_version: FMTVersion,
         saft_options: SaftVRQMieOptions,
     ) -> DFT<Self> {
+        assert!(
+            parameters.association.assoc_comp.is_empty(),
+            "Association is not implemented in the SAFT-VRQ Mie Helmholtz energy functional."
+        );
+        let parameters =
+            SaftVRQMieParameters::apply_combining_rule(parameters, saft_options.combining_rule);
         let mut contributions: Vec<Box<dyn FunctionalContribution>> = Vec::with_capacity(3);
 
         // Hard sphere contribution
@@ -61,17 +78,17 @@ impl SaftVRQMieFunctional {
         let att = AttractiveFunctional::new(parameters.clone());
         contributions.push(Box::new(att));
 
-        let joback = match &parameters.joback_records {
-            Some(joback_records) => Joback::new(joback_records.clone()),
-            None => Joback::default(parameters.m.len()),
-        };
+        let ideal_gas = parameters.joback_records.clone().map_or_else(
+            || IdealGasModel::default(parameters.m.len()),
+            IdealGasModel::joback,
+        );
 
         (Self {
             parameters,
             fmt_version,
             options: saft_options,
             contributions,
-            joback,
+            ideal_gas,
         })
         .into()
     }
@@ -84,12 +101,19 @@ impl HelmholtzEnergyFunctional for SaftVRQMieFunctional {
             self.fmt_version,
             self.options,
         )
+        .with_ideal_gas(self.ideal_gas.subset(component_list))
+        .unwrap()
     }
 
     fn compute_max_density(&self, moles: &Array1<f64>) -> f64 {
-        self.options.max_eta * moles.sum()
-            / (FRAC_PI_6 * &self.parameters.m * self.parameters.sigma.mapv(|v| v.powi(3)) * moles)
-                .sum()
+        self.options.max_density.apply(
+            self.options.max_eta * moles.sum()
+                / (FRAC_PI_6
+                    * &self.parameters.m
+                    * self.parameters.sigma.mapv(|v| v.powi(3))
+                    * moles)
+                    .sum(),
+        )
     }
 
     fn contributions(&self) -> &[Box<dyn FunctionalContribution>] {
@@ -97,7 +121,7 @@ impl HelmholtzEnergyFunctional for SaftVRQMieFunctional {
     }
 
     fn ideal_gas(&self) -> &dyn IdealGasContribution {
-        &self.joback
+        &self.ideal_gas
     }
 
     fn molecule_shape(&self) -> MoleculeShape {
@@ -105,19 +129,50 @@ impl HelmholtzEnergyFunctional for SaftVRQMieFunctional {
     }
 }
 
-impl MolarWeight for SaftVRQMieFunctional {
-    fn molar_weight(&self) -> SIArray1 {
-        self.parameters.molarweight.clone() * GRAM / MOL
+impl WithIdealGas for SaftVRQMieFunctional {
+    fn with_ideal_gas(mut self, ideal_gas: IdealGasModel) -> EosResult<Self> {
+        ideal_gas.validate_components(self.parameters.m.len())?;
+        self.ideal_gas = ideal_gas;
+        Ok(self)
+    }
+}
+
+// Auxiliary structure used to (de)serialize the Helmholtz energy functional.
+#[derive(Serialize, Deserialize)]
+struct SaftVRQMieFunctionalJSON {
+    parameters: ParameterSet<SaftVRQMieRecord, JobackRecord, SaftVRQMieBinaryRecord>,
+    fmt_version: FMTVersion,
+    options: SaftVRQMieOptions,
+    ideal_gas: IdealGasModel,
+}
+
+impl Serialize for SaftVRQMieFunctional {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        SaftVRQMieFunctionalJSON {
+            parameters: self.parameters.parameter_set(),
+            fmt_version: self.fmt_version,
+            options: self.options,
+            ideal_gas: self.ideal_gas.clone(),
+        }
+        .serialize(serializer)
     }
 }
 
-impl HardSphereProperties for SaftVRQMieParameters {
-    fn monomer_shape<N: DualNum<f64>>(&self, _: N) -> MonomerShape<N> {
-        MonomerShape::Spherical(self.m.len())
+impl<'de> Deserialize<'de> for SaftVRQMieFunctional {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let func = SaftVRQMieFunctionalJSON::deserialize(deserializer)?;
+        let parameters =
+            SaftVRQMieParameters::from_parameter_set(func.parameters).map_err(D::Error::custom)?;
+        Self::with_options(Arc::new(parameters), func.fmt_version, func.options)
+            .into_inner()
+            .with_ideal_gas(func.ideal_gas)
+            .map_err(D::Error::custom)
     }
+}
 
-    fn hs_diameter<D: DualNum<f64>>(&self, temperature: D) -> Array1<D> {
-        self.hs_diameter(temperature)
+impl MolarWeight for SaftVRQMieFunctional {
+    fn molar_weight(&self) -> SIArray1 {
+        self.parameters.molarweight.clone() * GRAM / MOL
     }
 }
 
@@ -138,3 +193,15 @@ impl PairPotential for SaftVRQMieFunctional {
         })
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::saftvrqmie::parameters::utils::water_fh1;
+
+    #[test]
+    #[should_panic(expected = "Association is not implemented")]
+    fn association() {
+        SaftVRQMieFunctional::new(water_fh1());
+    }
+}
@@ -116,14 +116,8 @@ where
         // auxiliary variables
         let n3i = n3.mapv(|n3| (-n3 + 1.0).recip());
 
-        // temperature dependent segment radius // calc & store this in struct
-        let s_eff_ij =
-            Array2::from_shape_fn((n, n), |(i, j)| p.calc_sigma_eff_ij(i, j, temperature));
-
-        // temperature dependent segment radius // calc & store this in struct
-        let d_hs_ij = Array2::from_shape_fn((n, n), |(i, j)| {
-            p.hs_diameter_ij(i, j, temperature, s_eff_ij[[i, j]])
-        });
+        // temperature dependent segment radius (cached in the parameters)
+        let d_hs_ij = p.d_hs_ij(temperature);
 
         // Additive hard-sphere diameter
         let d_hs_add_ij =
@@ -46,7 +46,7 @@ impl<D: DualNum<f64>> Alpha<D> {
         let mut alpha_ij: Array2<D> = Array2::zeros((nc, nc));
 
         for i in 0..nc {
-            for j in 0..nc {
+            for j in i..nc {
                 let sigma_ratio = D::one() * p.sigma_ij[[i, j]] / sigma_eff_ij[[i, j]];
                 let eps_ratio = D::one() * p.epsilon_k_ij[[i, j]] / epsilon_k_eff_ij[[i, j]];
                 let la = p.lambda_a_ij[[i, j]];
@@ -59,6 +59,7 @@ impl<D: DualNum<f64>> Alpha<D> {
                 let q1a = sigma_ratio_a * sigma_ratio.powi(2) * la * (la - 1.0) / (la - 1.0);
                 let q1r = sigma_ratio_r * sigma_ratio.powi(2) * lr * (lr - 1.0) / (lr - 1.0);
                 alpha_ij[[i, j]] = (dmt * (q1a - q1r) + ma - mr) * p.c_ij[[i, j]] * eps_ratio;
+                alpha_ij[[j, i]] = alpha_ij[[i, j]];
             }
         }
         Self { alpha_ij }
@@ -83,19 +84,13 @@ impl<D: DualNum<f64>> HelmholtzEnergyDual<D> for Dispersion {
         let p = &self.parameters;
         let rho = &state.partial_density;
         // temperature dependent segment radius
-        let s_eff_ij = Array2::from_shape_fn((n, n), |(i, j)| -> D {
-            p.calc_sigma_eff_ij(i, j, state.temperature)
-        });
+        let s_eff_ij = p.sigma_eff_ij(state.temperature);
 
         // temperature dependent segment radius
-        let d_hs_ij = Array2::from_shape_fn((n, n), |(i, j)| -> D {
-            p.hs_diameter_ij(i, j, state.temperature, s_eff_ij[[i, j]])
-        });
+        let d_hs_ij = p.d_hs_ij(state.temperature);
 
         // temperature dependent well depth
-        let epsilon_k_eff_ij = Array2::from_shape_fn((n, n), |(i, j)| -> D {
-            p.calc_epsilon_k_eff_ij(i, j, state.temperature)
-        });
+        let epsilon_k_eff_ij = p.epsilon_k_eff_ij(state.temperature);
 
         // temperature dependent well depth
         let dq_ij = Array2::from_shape_fn((n, n), |(i, j)| -> D {
@@ -114,8 +109,8 @@ impl<D: DualNum<f64>> HelmholtzEnergyDual<D> for Dispersion {
             rho_s += rho[i] * p.m[i];
         }
         // packing fractions
-        let zeta = zeta_saft_vrq_mie(&p.m, &x_s, &d_hs_ij, rho_s);
-        let zeta_bar = zeta_saft_vrq_mie(&p.m, &x_s, &s_eff_ij, rho_s);
+        let zeta = zeta_saft_vrq_mie(&x_s, &d_hs_ij, rho_s);
+        let zeta_bar = zeta_saft_vrq_mie(&x_s, &s_eff_ij, rho_s);
 
         // alphas ....
         let alpha = Alpha::new(p, &s_eff_ij, &epsilon_k_eff_ij, state.temperature);
@@ -124,7 +119,7 @@ impl<D: DualNum<f64>> HelmholtzEnergyDual<D> for Dispersion {
         let a2 = second_order_perturbation(
             p, &alpha, &x_s, zeta, zeta_bar, rho_s, &d_hs_ij, &s_eff_ij, &dq_ij,
         );
-        let a3 = third_order_perturbation(p, &alpha, &x_s, zeta_bar, &epsilon_k_eff_ij);
+        let a3 = third_order_perturbation(&alpha, &x_s, zeta_bar, &epsilon_k_eff_ij);
 
         let mut n_s = D::zero();
         for i in 0..n {
@@ -159,31 +154,36 @@ pub fn dispersion_energy_density<D: DualNum<f64>>(
     let x_s = Array1::from_shape_fn(n, |i| -> D { rho[i] * p.m[i] * rho_s.recip() });
 
     // packing fractions
-    let zeta = zeta_saft_vrq_mie(&p.m, &x_s, d_hs_ij, rho_s);
-    let zeta_bar = zeta_saft_vrq_mie(&p.m, &x_s, s_eff_ij, rho_s);
+    let zeta = zeta_saft_vrq_mie(&x_s, d_hs_ij, rho_s);
+    let zeta_bar = zeta_saft_vrq_mie(&x_s, s_eff_ij, rho_s);
 
     let a1 = first_order_perturbation(p, &x_s, zeta, rho_s, d_hs_ij, s_eff_ij, dq_ij);
     let a2 = second_order_perturbation(
         p, alpha, &x_s, zeta, zeta_bar, rho_s, d_hs_ij, s_eff_ij, dq_ij,
     );
-    let a3 = third_order_perturbation(p, alpha, &x_s, zeta_bar, epsilon_k_eff_ij);
+    let a3 = third_order_perturbation(alpha, &x_s, zeta_bar, epsilon_k_eff_ij);
 
     let inv_t = temperature.recip();
     rho_s * (a1 * inv_t + a2 * inv_t.powi(2) + a3 * inv_t.powi(3))
 }
 
-fn zeta_saft_vrq_mie<D: DualNum<f64>>(
-    m: &Array1<f64>,
-    x_s: &Array1<D>,
-    diameter: &Array2<D>,
-    rho_s: D,
-) -> D {
-    let mut zeta = D::zero();
-    for i in 0..m.len() {
-        for j in 0..m.len() {
-            zeta += x_s[i] * x_s[j] * diameter[[i, j]].powi(3);
+/// Double sum $\sum_i\sum_jx_ix_jf_{ij}$ of a symmetric pair function
+/// $f_{ij}=f_{ji}$ that is only evaluated for $j\geq i$.
+fn symmetric_double_sum<D: DualNum<f64>, F: Fn(usize, usize) -> D>(x: &Array1<D>, f: F) -> D {
+    let n = x.len();
+    let mut sum = D::zero();
+    for i in 0..n {
+        let mut sum_i = D::zero();
+        for j in i + 1..n {
+            sum_i += x[j] * f(i, j);
         }
+        sum += x[i] * (x[i] * f(i, i) + sum_i * 2.0);
     }
+    sum
+}
+
+fn zeta_saft_vrq_mie<D: DualNum<f64>>(x_s: &Array1<D>, diameter: &Array2<D>, rho_s: D) -> D {
+    let zeta = symmetric_double_sum(x_s, |i, j| diameter[[i, j]].powi(3));
     zeta * FRAC_PI_6 * rho_s
 }
 
@@ -196,31 +196,23 @@ fn first_order_perturbation<D: DualNum<f64>>(
     s_eff_ij: &Array2<D>,
     dq_ij: &Array2<D>,
 ) -> D {
-    let n = parameters.sigma.len();
-    let mut a1 = D::zero();
-    for i in 0..n {
-        for j in 0..n {
-            let x0 = d_hs_ij[[i, j]].recip() * parameters.sigma_ij[[i, j]];
-            let x0_eff = s_eff_ij[[i, j]] / d_hs_ij[[i, j]];
-            let dq_div_sigma_2 = dq_ij[[i, j]] / parameters.sigma_ij[[i, j]].powi(2);
-            a1 += x_s[i]
-                * x_s[j]
-                * FRAC_PI_6
-                * rho_s
-                * d_hs_ij[[i, j]].powi(3)
-                * first_order_perturbation_ij(
-                    parameters.lambda_a_ij[[i, j]],
-                    parameters.lambda_r_ij[[i, j]],
-                    parameters.epsilon_k_ij[[i, j]],
-                    zeta,
-                    x0,
-                    x0_eff,
-                    parameters.c_ij[[i, j]],
-                    dq_div_sigma_2,
-                )
-        }
-    }
-    a1
+    let a1 = symmetric_double_sum(x_s, |i, j| {
+        let x0 = d_hs_ij[[i, j]].recip() * parameters.sigma_ij[[i, j]];
+        let x0_eff = s_eff_ij[[i, j]] / d_hs_ij[[i, j]];
+        let dq_div_sigma_2 = dq_ij[[i, j]] / parameters.sigma_ij[[i, j]].powi(2);
+        d_hs_ij[[i, j]].powi(3)
+            * first_order_perturbation_ij(
+                parameters.lambda_a_ij[[i, j]],
+                parameters.lambda_r_ij[[i, j]],
+                parameters.epsilon_k_ij[[i, j]],
+                zeta,
+                x0,
+                x0_eff,
+                parameters.c_ij[[i, j]],
+                dq_div_sigma_2,
+            )
+    });
+    a1 * FRAC_PI_6 * rho_s
 }
 
 fn first_order_perturbation_ij<D: DualNum<f64>>(
@@ -312,40 +304,33 @@ fn second_order_perturbation<D: DualNum<f64>>(
     s_eff_ij: &Array2<D>,
     dq_ij: &Array2<D>,
 ) -> D {
-    let n = parameters.sigma.len();
-    let mut a2 = D::zero();
-
     // Calculate isothermal hard sphere compressibillity factor
     let k = (-zeta + 1.0).powi(4)
         / (zeta * 4.0 + zeta.powi(2) * 4.0 - zeta.powi(3) * 4.0 + zeta.powi(4) + 1.0);
 
-    for i in 0..n {
-        for j in 0..n {
-            let chi = alpha.f(0, i, j) * zeta_bar
-                + alpha.f(1, i, j) * zeta_bar.powi(5)
-                + alpha.f(2, i, j) * zeta_bar.powi(8);
-            let x0 = d_hs_ij[[i, j]].recip() * parameters.sigma_ij[[i, j]];
-            let x0_eff = s_eff_ij[[i, j]] / d_hs_ij[[i, j]];
-            let dq_div_sigma_2 = dq_ij[[i, j]] / parameters.sigma_ij[[i, j]].powi(2);
-            a2 += x_s[i]
-                * x_s[j]
-                * FRAC_PI_6
-                * rho_s
-                * d_hs_ij[[i, j]].powi(3)
-                * (chi + 1.0)
-                * second_order_perturbation_ij(
-                    parameters.lambda_a_ij[[i, j]],
-                    parameters.lambda_r_ij[[i, j]],
-                    parameters.epsilon_k_ij[[i, j]],
-                    zeta,
-                    x0,
-                    x0_eff,
-                    parameters.c_ij[[i, j]],
-                    dq_div_sigma_2,
-                )
-        }
-    }
-    a2 * k
+    let zeta_bar_5 = zeta_bar.powi(5);
+    let zeta_bar_8 = zeta_bar.powi(8);
+    let a2 = symmetric_double_sum(x_s, |i, j| {
+        let chi = alpha.f(0, i, j) * zeta_bar
+            + alpha.f(1, i, j) * zeta_bar_5
+            + alpha.f(2, i, j) * zeta_bar_8;
+        let x0 = d_hs_ij[[i, j]].recip() * parameters.sigma_ij[[i, j]];
+        let x0_eff = s_eff_ij[[i, j]] / d_hs_ij[[i, j]];
+        let dq_div_sigma_2 = dq_ij[[i, j]] / parameters.sigma_ij[[i, j]].powi(2);
+        d_hs_ij[[i, j]].powi(3)
+            * (chi + 1.0)
+            * second_order_perturbation_ij(
+                parameters.lambda_a_ij[[i, j]],
+                parameters.lambda_r_ij[[i, j]],
+                parameters.epsilon_k_ij[[i, j]],
+                zeta,
+                x0,
+                x0_eff,
+                parameters.c_ij[[i, j]],
+                dq_div_sigma_2,
+            )
+    });
+    a2 * FRAC_PI_6 * rho_s * k
 }
 
 #[inline]
@@ -399,22 +384,14 @@ fn second_order_perturbation_ij<D: DualNum<f64>>(
 }
 
 fn third_order_perturbation<D: DualNum<f64>>(
-    parameters: &SaftVRQMieParameters,
     alpha: &Alpha<D>,
     x_s: &Array1<D>,
     zeta_bar: D,
     epsilon_k_eff_ij: &Array2<D>,
 ) -> D {
-    let n = parameters.sigma.len();
-    let mut a3 = D::zero();
-    for i in 0..n {
-        for j in 0..n {
-            a3 += x_s[i]
-                * x_s[j]
-                * third_order_perturbation_ij(i, j, epsilon_k_eff_ij[[i, j]], alpha, zeta_bar)
-        }
-    }
-    a3
+    symmetric_double_sum(x_s, |i, j| {
+        third_order_perturbation_ij(i, j, epsilon_k_eff_ij[[i, j]], alpha, zeta_bar)
+    })
 }
 
 fn third_order_perturbation_ij<D: DualNum<f64>>(
@@ -579,6 +556,25 @@ mod tests {
         assert_relative_eq!(rel_err, 0.0, epsilon = 5e-7);
     }
 
+    #[test]
+    fn test_symmetric_double_sum() {
+        let x = arr1(&[0.2, 0.5, 0.3]);
+        let f = Array2::from_shape_fn((3, 3), |(i, j)| {
+            ((i + 1) * (j + 2) + (j + 1) * (i + 2)) as f64
+        });
+        let mut sum = 0.0;
+        for i in 0..3 {
+            for j in 0..3 {
+                sum += x[i] * x[j] * f[[i, j]];
+            }
+        }
+        assert_relative_eq!(
+            symmetric_double_sum(&x, |i, j| f[[i, j]]),
+            sum,
+            max_relative = 1e-14
+        );
+    }
+
     #[test]
     fn test_zeta_saft_vrq_mie() {
         let p = hydrogen_fh1();
@@ -608,8 +604,8 @@ mod tests {
             rho_s += state.partial_density[i] * p.m[i];
         }
         // packing fractions
-        let zeta = zeta_saft_vrq_mie(&p.m, &x_s, &d_hs_ij, rho_s);
-        let zeta_bar = zeta_saft_vrq_mie(&p.m, &x_s, &s_eff_ij, rho_s);
+        let zeta = zeta_saft_vrq_mie(&x_s, &d_hs_ij, rho_s);
+        let zeta_bar = zeta_saft_vrq_mie(&x_s, &s_eff_ij, rho_s);
         assert_relative_eq!(zeta.re(), 9.7717457994590765E-002, epsilon = 5e-9);
         assert_relative_eq!(zeta_bar.re(), 0.10864364645845238, epsilon = 5e-9);
     }
@@ -645,8 +641,8 @@ mod tests {
         }
 
         // packing fractions
-        let zeta = zeta_saft_vrq_mie(&p.m, &x_s, &d_hs_ij, rho_s);
-        let zeta_bar = zeta_saft_vrq_mie(&p.m, &x_s, &s_eff_ij, rho_s);
+        let zeta = zeta_saft_vrq_mie(&x_s, &d_hs_ij, rho_s);
+        let zeta_bar = zeta_saft_vrq_mie(&x_s, &s_eff_ij, rho_s);
 
         // temperature dependent well depth
         let epsilon_k_eff_ij = Array2::from_shape_fn((nc, nc), |(i, j)| {
@@ -664,7 +660,7 @@ mod tests {
         let a2 = second_order_perturbation(
             &p, &alpha, &x_s, zeta, zeta_bar, rho_s, &d_hs_ij, &s_eff_ij, &dq_ij,
         );
-        let a3 = third_order_perturbation(&p, &alpha, &x_s, zeta_bar, &epsilon_k_eff_ij);
+        let a3 = third_order_perturbation(&alpha, &x_s, zeta_bar, &epsilon_k_eff_ij);
 
         let rel_err_a1 = (a1.re() + 30.702499892515764) / 30.702499892515764;
         let rel_err_a2 = (a2.re() + 67.046957636607587) / 67.046957636607587;
@@ -63,10 +63,20 @@ const W_K21: [f64; 21] = [
 impl SaftVRQMieParameters {
     #[inline]
     pub fn hs_diameter<D: DualNum<f64>>(&self, temperature: D) -> Array1<D> {
-        Array1::from_shape_fn(self.m.len(), |i| -> D {
-            let sigma_eff = self.calc_sigma_eff_ij(i, i, temperature);
-            self.hs_diameter_ij(i, i, temperature, sigma_eff)
-        })
+        self.d_hs_ij(temperature).into_diag()
+    }
+
+    /// Hard-sphere diameters of all pairs of segments.
+    ///
+    /// The diameters are cached for the last temperature.
+    pub fn d_hs_ij<D: DualNum<f64>>(&self, temperature: D) -> Array2<D> {
+        self.temperature_cache
+            .get_or_insert_with("d_hs_ij", temperature, |t| {
+                let s_eff_ij = self.sigma_eff_ij(t);
+                Array2::from_shape_fn(s_eff_ij.raw_dim(), |(i, j)| {
+                    self.hs_diameter_ij(i, j, t, s_eff_ij[[i, j]])
+                })
+            })
     }
 
     #[inline]
@@ -119,9 +129,18 @@ impl SaftVRQMieParameters {
 
     #[inline]
     pub fn epsilon_k_eff<D: DualNum<f64>>(&self, temperature: D) -> Array1<D> {
-        Array1::from_shape_fn(self.m.len(), |i| -> D {
-            self.calc_epsilon_k_eff_ij(i, i, temperature)
-        })
+        self.epsilon_k_eff_ij(temperature).into_diag()
+    }
+
+    /// Effective well depths of all pairs of segments.
+    ///
+    /// The well depths are cached for the last temperature.
+    pub fn epsilon_k_eff_ij<D: DualNum<f64>>(&self, temperature: D) -> Array2<D> {
+        let n = self.m.len();
+        self.temperature_cache
+            .get_or_insert_with("epsilon_k_eff_ij", temperature, |t| {
+                Array2::from_shape_fn((n, n), |(i, j)| self.calc_epsilon_k_eff_ij(i, j, t))
+            })
     }
 
     pub fn calc_epsilon_k_eff_ij<D: DualNum<f64>>(&self, i: usize, j: usize, temperature: D) -> D {
@@ -142,9 +161,18 @@ impl SaftVRQMieParameters {
 
     #[inline]
     pub fn sigma_eff<D: DualNum<f64>>(&self, temperature: D) -> Array1<D> {
-        Array1::from_shape_fn(self.m.len(), |i| -> D {
-            self.calc_sigma_eff_ij(i, i, temperature)
-        })
+        self.sigma_eff_ij(temperature).into_diag()
+    }
+
+    /// Effective segment diameters of all pairs of segments.
+    ///
+    /// The diameters are cached for the last temperature.
+    pub fn sigma_eff_ij<D: DualNum<f64>>(&self, temperature: D) -> Array2<D> {
+        let n = self.m.len();
+        self.temperature_cache
+            .get_or_insert_with("sigma_eff_ij", temperature, |t| {
+                Array2::from_shape_fn((n, n), |(i, j)| self.calc_sigma_eff_ij(i, j, t))
+            })
     }
 
     pub fn calc_sigma_eff_ij<D: DualNum<f64>>(&self, i: usize, j: usize, temperature: D) -> D {
@@ -268,6 +296,7 @@ pub fn zeta_23<D: DualNum<f64>>(m: &Array1<f64>, molefracs: &Array1<D>, diameter
 mod tests {
     use super::*;
     use crate::saftvrqmie::parameters::utils::h2_ne_fh1;
+    use crate::saftvrqmie::parameters::utils::hydrogen_deuterium_fh1;
     use crate::saftvrqmie::parameters::utils::hydrogen_fh1;
     use approx::assert_relative_eq;
     use ndarray::arr1;
@@ -297,6 +326,19 @@ mod tests {
         assert!((sigma_eff.re() - 3.2540054024660556).abs() < 5.0e-7)
     }
 
+    #[test]
+    fn test_sigma_effective_isotopes() {
+        let parameters = hydrogen_deuterium_fh1();
+        let temperature = Dual2::from_re(26.7060).derive();
+        assert_eq!(parameters.sigma[0], parameters.sigma[1]);
+        assert_eq!(parameters.epsilon_k[0], parameters.epsilon_k[1]);
+        let sigma_eff_h2 = parameters.calc_sigma_eff_ij(0, 0, temperature);
+        let sigma_eff_d2 = parameters.calc_sigma_eff_ij(1, 1, temperature);
+        assert!((sigma_eff_h2.re() - 3.2540054024660556).abs() < 5.0e-7);
+        assert!(sigma_eff_d2.re() < sigma_eff_h2.re());
+        assert!(sigma_eff_d2.re() > parameters.sigma[1]);
+    }
+
     #[test]
     fn test_eps_div_k_effective() {
         let parameters = hydrogen_fh1();
@@ -326,6 +368,23 @@ mod tests {
         assert!((d_hs.v1[0] + 8.4528823966252661e-3).abs() < 1.0e-9);
     }
 
+    #[test]
+    fn test_cached_hs_diameter() {
+        let parameters = h2_ne_fh1();
+        let temperature = Dual2::from_re(26.7060).derive();
+        let d_hs = parameters.hs_diameter(Dual2::from_re(26.7060));
+        let d_hs_dual = parameters.hs_diameter(temperature);
+        for i in 0..2 {
+            let sigma_eff = parameters.calc_sigma_eff_ij(i, i, temperature);
+            let d_hs_ref = parameters.hs_diameter_ij(i, i, temperature, sigma_eff);
+            assert_relative_eq!(d_hs[i].re, d_hs_ref.re, max_relative = 1e-14);
+            assert_relative_eq!(d_hs_dual[i].re, d_hs_ref.re, max_relative = 1e-14);
+            assert_relative_eq!(d_hs_dual[i].v1[0], d_hs_ref.v1[0], max_relative = 1e-10);
+            assert_relative_eq!(d_hs_dual[i].v2[0], d_hs_ref.v2[0], max_relative = 1e-10);
+        }
+        assert_eq!(parameters.temperature_cache.statistics(), (1, 2));
+    }
+
     #[test]
     fn test_hs_helmholtz_energy() {
         let hs = HardSphere {
@@ -1,11 +1,16 @@
-use super::parameters::SaftVRQMieParameters;
-use feos_core::parameter::Parameter;
+use super::parameters::{SaftVRQMieBinaryRecord, SaftVRQMieParameters, SaftVRQMieRecord};
+use crate::association::Association;
+use feos_core::ideal_gas::{IdealGasModel, WithIdealGas};
+use feos_core::joback::JobackRecord;
+use feos_core::parameter::{CombiningRule, Parameter, ParameterSet};
 use feos_core::{
     Contributions, EntropyScaling, EosError, EosResult, EquationOfState, HelmholtzEnergy,
-    MolarWeight, State,
+    IdealGasContribution, MaxDensity, MolarWeight, State,
 };
 use ndarray::Array1;
 use quantity::si::*;
+use serde::de::Error;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
 use std::f64::consts::{FRAC_PI_6, PI};
 use std::sync::Arc;
 
@@ -17,25 +22,34 @@ use hard_sphere::HardSphere;
 use non_additive_hs::NonAddHardSphere;
 
 /// Customization options for the SAFT-VRQ Mie equation of state and functional.
-#[derive(Copy, Clone)]
+#[derive(Copy, Clone, Serialize, Deserialize)]
 pub struct SaftVRQMieOptions {
     pub max_eta: f64,
+    pub max_density: MaxDensity,
     pub fh_order: FeynmanHibbsOrder,
     pub inc_nonadd_term: bool,
+    pub max_iter_cross_assoc: usize,
+    pub tol_cross_assoc: f64,
+    #[serde(default)]
+    pub combining_rule: CombiningRule,
 }
 
 impl Default for SaftVRQMieOptions {
     fn default() -> Self {
         Self {
             max_eta: 0.5,
+            max_density: MaxDensity::Heuristic,
             fh_order: FeynmanHibbsOrder::FH1,
             inc_nonadd_term: true,
+            max_iter_cross_assoc: 50,
+            tol_cross_assoc: 1e-10,
+            combining_rule: CombiningRule::LorentzBerthelot,
         }
     }
 }
 
 /// Order of Feynman-Hibbs potential
-#[derive(Copy, Clone)]
+#[derive(Copy, Clone, Serialize, Deserialize)]
 #[cfg_attr(feature = "python", pyo3::pyclass)]
 pub enum FeynmanHibbsOrder {
     /// First order correction
@@ -48,10 +62,13 @@ pub enum FeynmanHibbsOrder {
 ///
 /// # Note
 /// Currently, only the first-order Feynman-Hibbs term is implemented.
+/// Association is only considered in the equation of state and not in
+/// the Helmholtz energy functional.
 pub struct SaftVRQMie {
     parameters: Arc<SaftVRQMieParameters>,
     options: SaftVRQMieOptions,
     contributions: Vec<Box<dyn HelmholtzEnergy>>,
+    ideal_gas: IdealGasModel,
 }
 
 impl SaftVRQMie {
@@ -64,7 +81,9 @@ impl SaftVRQMie {
             FeynmanHibbsOrder::FH1 => (),
             FeynmanHibbsOrder::FH2 => unimplemented!(),
         };
-        let mut contributions: Vec<Box<dyn HelmholtzEnergy>> = Vec::with_capacity(4);
+        let parameters =
+            SaftVRQMieParameters::apply_combining_rule(parameters, options.combining_rule);
+        let mut contributions: Vec<Box<dyn HelmholtzEnergy>> = Vec::with_capacity(5);
         contributions.push(Box::new(HardSphere {
             parameters: parameters.clone(),
         }));
@@ -76,11 +95,25 @@ impl SaftVRQMie {
                 parameters: parameters.clone(),
             }));
         }
+        if !parameters.association.assoc_comp.is_empty() {
+            contributions.push(Box::new(Association::new(
+                &parameters,
+                &parameters.association,
+                options.max_iter_cross_assoc,
+                options.tol_cross_assoc,
+            )));
+        }
+
+        let ideal_gas = parameters.joback_records.clone().map_or_else(
+            || IdealGasModel::default(parameters.m.len()),
+            IdealGasModel::joback,
+        );
 
         Self {
             parameters,
             options,
             contributions,
+            ideal_gas,
         }
     }
 }
@@ -91,21 +124,71 @@ impl EquationOfState for SaftVRQMie {
     }
 
     fn subset(&self, component_list: &[usize]) -> Self {
-        Self::with_options(
-            Arc::new(self.parameters.subset(component_list)),
-            self.options,
-        )
+        Self {
+            ideal_gas: self.ideal_gas.subset(component_list),
+            ..Self::with_options(
+                Arc::new(self.parameters.subset(component_list)),
+                self.options,
+            )
+        }
     }
 
     fn compute_max_density(&self, moles: &Array1<f64>) -> f64 {
-        self.options.max_eta * moles.sum()
-            / (FRAC_PI_6 * &self.parameters.m * self.parameters.sigma.mapv(|v| v.powi(3)) * moles)
-                .sum()
+        self.options.max_density.apply(
+            self.options.max_eta * moles.sum()
+                / (FRAC_PI_6
+                    * &self.parameters.m
+                    * self.parameters.sigma.mapv(|v| v.powi(3))
+                    * moles)
+                    .sum(),
+        )
     }
 
     fn residual(&self) -> &[Box<dyn HelmholtzEnergy>] {
         &self.contributions
     }
+
+    fn ideal_gas(&self) -> &dyn IdealGasContribution {
+        &self.ideal_gas
+    }
+}
+
+impl WithIdealGas for SaftVRQMie {
+    fn with_ideal_gas(mut self, ideal_gas: IdealGasModel) -> EosResult<Self> {
+        ideal_gas.validate_components(self.components())?;
+        self.ideal_gas = ideal_gas;
+        Ok(self)
+    }
+}
+
+// Auxiliary structure used to (de)serialize the equation of state.
+#[derive(Serialize, Deserialize)]
+struct SaftVRQMieJSON {
+    parameters: ParameterSet<SaftVRQMieRecord, JobackRecord, SaftVRQMieBinaryRecord>,
+    options: SaftVRQMieOptions,
+    ideal_gas: IdealGasModel,
+}
+
+impl Serialize for SaftVRQMie {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        SaftVRQMieJSON {
+            parameters: self.parameters.parameter_set(),
+            options: self.options,
+            ideal_gas: self.ideal_gas.clone(),
+        }
+        .serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for SaftVRQMie {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let eos = SaftVRQMieJSON::deserialize(deserializer)?;
+        let parameters =
+            SaftVRQMieParameters::from_parameter_set(eos.parameters).map_err(D::Error::custom)?;
+        Self::with_options(Arc::new(parameters), eos.options)
+            .with_ideal_gas(eos.ideal_gas)
+            .map_err(D::Error::custom)
+    }
 }
 
 impl MolarWeight for SaftVRQMie {
@@ -307,3 +390,61 @@ impl EntropyScaling for SaftVRQMie {
         Ok(a + b * s + c * (1.0 - s.exp()) + d * s.powi(2))
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::saftvrqmie::parameters::utils::{h2_water_fh1, hydrogen_fh1, water_fh1};
+    use approx::assert_relative_eq;
+    use feos_core::{HelmholtzEnergyDual, StateHD};
+    use ndarray::arr1;
+
+    #[test]
+    fn association() {
+        let parameters = water_fh1();
+        let assoc = Association::new(&parameters, &parameters.association, 50, 1e-10);
+        let cross_assoc =
+            Association::new_cross_association(&parameters, &parameters.association, 50, 1e-10);
+        let t = 350.0;
+        let v = 41.248289328513216;
+        let n = 1.23;
+        let s = StateHD::new(t, v, arr1(&[n]));
+        let a = assoc.helmholtz_energy(&s) / n;
+        let a_cross = cross_assoc.helmholtz_energy(&s) / n;
+        assert!(a < 0.0);
+        assert_relative_eq!(a, a_cross, epsilon = 1e-10);
+    }
+
+    #[test]
+    fn association_mixture() {
+        let water = water_fh1();
+        let mixture = h2_water_fh1();
+        assert_eq!(mixture.association.assoc_comp, arr1(&[1]));
+        assert!(hydrogen_fh1().association.assoc_comp.is_empty());
+
+        let a_pure = Association::new(&water, &water.association, 50, 1e-10);
+        let a_mix = Association::new_cross_association(&mixture, &mixture.association, 50, 1e-10);
+        let t = 350.0;
+        let v = 41.248289328513216;
+        let n = 1.23;
+        let s_pure = StateHD::new(t, v, arr1(&[n]));
+        let s_mix = StateHD::new(t, v, arr1(&[0.0, n]));
+        assert_relative_eq!(
+            a_pure.helmholtz_energy(&s_pure),
+            a_mix.helmholtz_energy(&s_mix),
+            epsilon = 1e-10
+        );
+
+        let eos = SaftVRQMie::new(mixture);
+        assert_eq!(eos.residual().len(), 4);
+    }
+
+    #[test]
+    fn binary_association_record() {
+        let json = r#"{"k_ij": 0.1, "kappa_ab": 0.01, "epsilon_k_ab": 1000.0}"#;
+        let record: SaftVRQMieBinaryRecord = serde_json::from_str(json).unwrap();
+        let association = record.association.unwrap();
+        assert_eq!(association.kappa_ab, 0.01);
+        assert_eq!(association.epsilon_k_ab, 1000.0);
+    }
+}
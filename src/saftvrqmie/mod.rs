@@ -13,11 +13,18 @@
 mod dft;
 mod eos;
 mod parameters;
+#[cfg(feature = "estimator")]
+mod potential_fit;
 
 #[cfg(feature = "dft")]
 pub use dft::SaftVRQMieFunctional;
 pub use eos::{FeynmanHibbsOrder, SaftVRQMie, SaftVRQMieOptions};
-pub use parameters::{SaftVRQMieBinaryRecord, SaftVRQMieParameters, SaftVRQMieRecord};
+pub use parameters::{
+    SaftVRQMieBinaryRecord, SaftVRQMieIsotopeRecord, SaftVRQMieIsotopeSet, SaftVRQMieParameters,
+    SaftVRQMieRecord,
+};
+#[cfg(feature = "estimator")]
+pub use potential_fit::PairPotentialFit;
 
 #[cfg(feature = "python")]
 pub mod python;
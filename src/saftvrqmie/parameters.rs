@@ -1,6 +1,13 @@
+use crate::association::{AssociationParameters, AssociationRecord, BinaryAssociationRecord};
+use crate::hard_sphere::{HardSphereProperties, MonomerShape};
+use feos_core::ideal_gas::{IdealGasModel, IdealGasRecord};
 use feos_core::joback::JobackRecord;
-use feos_core::parameter::{Parameter, ParameterError, PureRecord};
+use feos_core::parameter::{
+    CombiningRule, Identifier, IdentifierOption, Metadata, Parameter, ParameterError, PureRecord,
+};
+use feos_core::TemperatureCache;
 use ndarray::{Array, Array1, Array2};
+use num_dual::DualNum;
 use num_traits::Zero;
 use quantity::si::{SINumber, ANGSTROM, CALORIE, GRAM, KELVIN, KILO, KILOGRAM, MOL, NAV, RGAS};
 use serde::{Deserialize, Serialize};
@@ -8,7 +15,9 @@ use std::collections::HashMap;
 use std::convert::TryFrom;
 use std::fmt::Write;
 use std::fs::File;
-use std::io::BufWriter;
+use std::io::{BufReader, BufWriter};
+use std::path::Path;
+use std::sync::Arc;
 
 /// SAFT-VRQ Mie pure-component parameters.
 #[derive(Serialize, Deserialize, Clone, Default)]
@@ -23,6 +32,10 @@ pub struct SaftVRQMieRecord {
     pub lr: f64,
     /// Attractive Mie exponent
     pub la: f64,
+    /// Association parameters
+    #[serde(flatten)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub association_record: Option<AssociationRecord>,
     /// Entropy scaling coefficients for the viscosity
     #[serde(skip_serializing_if = "Option::is_none")]
     pub viscosity: Option<[f64; 4]>,
@@ -39,6 +52,11 @@ impl std::fmt::Display for SaftVRQMieRecord {
         write!(f, "SaftVRQMieRecord(m={}", self.m)?;
         write!(f, ", sigma={}", self.sigma)?;
         write!(f, ", epsilon_k={}", self.epsilon_k)?;
+        write!(f, ", lr={}", self.lr)?;
+        write!(f, ", la={}", self.la)?;
+        if let Some(n) = &self.association_record {
+            write!(f, ", association_record={}", n)?;
+        }
         if let Some(n) = &self.viscosity {
             write!(f, ", viscosity={:?}", n)?;
         }
@@ -59,16 +77,30 @@ impl SaftVRQMieRecord {
         epsilon_k: f64,
         lr: f64,
         la: f64,
+        kappa_ab: Option<f64>,
+        epsilon_k_ab: Option<f64>,
+        na: Option<f64>,
+        nb: Option<f64>,
         viscosity: Option<[f64; 4]>,
         diffusion: Option<[f64; 5]>,
         thermal_conductivity: Option<[f64; 4]>,
     ) -> SaftVRQMieRecord {
+        let association_record = match (kappa_ab, epsilon_k_ab) {
+            (Some(kappa_ab), Some(epsilon_k_ab)) => {
+                Some(AssociationRecord::new(kappa_ab, epsilon_k_ab, na, nb, None))
+            }
+            (None, None) => None,
+            _ => {
+                panic!("To model association, both kappa_ab and epsilon_k_ab need to be specified.")
+            }
+        };
         SaftVRQMieRecord {
             m,
             sigma,
             epsilon_k,
             lr,
             la,
+            association_record,
             viscosity,
             diffusion,
             thermal_conductivity,
@@ -82,7 +114,12 @@ pub struct SaftVRQMieBinaryRecord {
     /// correction to energy parameters
     pub k_ij: f64,
     /// correction to diameter
+    #[serde(default)]
     pub l_ij: f64,
+    /// Cross-association parameters
+    #[serde(flatten)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub association: Option<BinaryAssociationRecord>,
 }
 
 impl std::fmt::Display for SaftVRQMieBinaryRecord {
@@ -90,6 +127,9 @@ impl std::fmt::Display for SaftVRQMieBinaryRecord {
         write!(f, "SaftVRQMieBinaryParameters(")?;
         write!(f, "\n\tk_ij={}", self.k_ij)?;
         write!(f, "\n\tl_ij={}", self.l_ij)?;
+        if let Some(association) = &self.association {
+            write!(f, "\n\tassociation={}", association)?;
+        }
         write!(f, "\n)")
     }
 }
@@ -114,6 +154,143 @@ impl TryFrom<SaftVRQMieBinaryRecord> for f64 {
     }
 }
 
+/// Isotopologue or spin isomer of a component.
+///
+/// All isotopologues of a component share the SAFT-VRQ Mie parameters
+/// of the base record. They differ in their molar weight, which determines
+/// the quantum corrections, and in their ideal gas contribution. Spin isomers
+/// (e.g., ortho- and parahydrogen) share the molar weight and only differ in
+/// their ideal gas contribution.
+#[derive(Serialize, Deserialize, Clone)]
+pub struct SaftVRQMieIsotopeRecord {
+    pub identifier: Identifier,
+    pub molarweight: f64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ideal_gas_record: Option<IdealGasRecord>,
+}
+
+impl SaftVRQMieIsotopeRecord {
+    pub fn new(
+        identifier: Identifier,
+        molarweight: f64,
+        ideal_gas_record: Option<IdealGasRecord>,
+    ) -> Self {
+        Self {
+            identifier,
+            molarweight,
+            ideal_gas_record,
+        }
+    }
+}
+
+impl std::fmt::Display for SaftVRQMieIsotopeRecord {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "SaftVRQMieIsotopeRecord(")?;
+        write!(f, "\n\tidentifier={},", self.identifier)?;
+        write!(f, "\n\tmolarweight={},", self.molarweight)?;
+        if let Some(r) = self.ideal_gas_record.as_ref() {
+            write!(f, "\n\tideal_gas_record={},", r)?;
+        }
+        write!(f, "\n)")
+    }
+}
+
+/// SAFT-VRQ Mie parameters shared by the isotopologues and spin isomers of a component.
+#[derive(Serialize, Deserialize, Clone)]
+pub struct SaftVRQMieIsotopeSet {
+    pub model_record: SaftVRQMieRecord,
+    pub isotopes: Vec<SaftVRQMieIsotopeRecord>,
+}
+
+impl SaftVRQMieIsotopeSet {
+    pub fn new(model_record: SaftVRQMieRecord, isotopes: Vec<SaftVRQMieIsotopeRecord>) -> Self {
+        Self {
+            model_record,
+            isotopes,
+        }
+    }
+
+    /// Read an isotope set from a json file.
+    pub fn from_json<P: AsRef<Path>>(file: P) -> Result<Self, ParameterError> {
+        let reader = BufReader::new(File::open(file)?);
+        Ok(serde_json::from_reader(reader)?)
+    }
+
+    /// Return the isotopes in the order of `substances`.
+    fn select(
+        &self,
+        substances: &[&str],
+        search_option: IdentifierOption,
+    ) -> Result<Vec<&SaftVRQMieIsotopeRecord>, ParameterError> {
+        let mut missing = Vec::new();
+        let isotopes = substances
+            .iter()
+            .filter_map(|&s| {
+                let isotope = self
+                    .isotopes
+                    .iter()
+                    .find(|i| i.identifier.as_string(search_option).as_deref() == Some(s));
+                if isotope.is_none() {
+                    missing.push(s);
+                }
+                isotope
+            })
+            .collect();
+        if !missing.is_empty() {
+            return Err(ParameterError::ComponentsNotFound(format!("{:?}", missing)));
+        }
+        Ok(isotopes)
+    }
+
+    /// Create pure records for the given isotopes from the shared model record.
+    ///
+    /// Ideal gas records are only passed on to the pure records if
+    /// they are Joback records.
+    pub fn pure_records(
+        &self,
+        substances: &[&str],
+        search_option: IdentifierOption,
+    ) -> Result<Vec<PureRecord<SaftVRQMieRecord, JobackRecord>>, ParameterError> {
+        Ok(self
+            .select(substances, search_option)?
+            .into_iter()
+            .map(|i| {
+                let joback = match &i.ideal_gas_record {
+                    Some(IdealGasRecord::Joback(r)) => Some(r.clone()),
+                    _ => None,
+                };
+                PureRecord::new(
+                    i.identifier.clone(),
+                    i.molarweight,
+                    self.model_record.clone(),
+                    joback,
+                )
+            })
+            .collect())
+    }
+
+    /// Create the ideal gas contribution for the given isotopes.
+    ///
+    /// Isotopes without an ideal gas record are assigned a vanishing
+    /// ideal gas heat capacity.
+    pub fn ideal_gas(
+        &self,
+        substances: &[&str],
+        search_option: IdentifierOption,
+    ) -> Result<IdealGasModel, ParameterError> {
+        Ok(IdealGasModel::new(
+            self.select(substances, search_option)?
+                .into_iter()
+                .map(|i| {
+                    i.ideal_gas_record
+                        .clone()
+                        .unwrap_or_else(|| IdealGasRecord::Joback(JobackRecord::default()))
+                })
+                .collect(),
+        ))
+    }
+}
+
 /// Parameter set required for the SAFT-VRQ Mie equation of state and Helmholtz energy functional.
 pub struct SaftVRQMieParameters {
     pub molarweight: Array1<f64>,
@@ -131,12 +308,17 @@ pub struct SaftVRQMieParameters {
     pub lambda_r_ij: Array2<f64>,
     pub lambda_a_ij: Array2<f64>,
     pub mass_ij: Array2<f64>,
+    pub combining_rule: CombiningRule,
+    pub association: AssociationParameters,
     pub viscosity: Option<Array2<f64>>,
     pub diffusion: Option<Array2<f64>>,
     pub thermal_conductivity: Option<Array2<f64>>,
     pub pure_records: Vec<PureRecord<SaftVRQMieRecord, JobackRecord>>,
     pub binary_records: Array2<SaftVRQMieBinaryRecord>,
+    pub binary_metadata: Array2<Option<Metadata>>,
     pub joback_records: Option<Vec<JobackRecord>>,
+    /// Cache for the temperature-dependent effective diameters and well depths
+    pub(crate) temperature_cache: TemperatureCache,
 }
 
 impl Parameter for SaftVRQMieParameters {
@@ -156,6 +338,7 @@ impl Parameter for SaftVRQMieParameters {
         let mut epsilon_k = Array::zeros(n);
         let mut lr = Array::zeros(n);
         let mut la = Array::zeros(n);
+        let mut association_records = Vec::with_capacity(n);
         let mut viscosity = Vec::with_capacity(n);
         let mut diffusion = Vec::with_capacity(n);
         let mut thermal_conductivity = Vec::with_capacity(n);
@@ -170,12 +353,20 @@ impl Parameter for SaftVRQMieParameters {
             epsilon_k[i] = r.epsilon_k;
             lr[i] = r.lr;
             la[i] = r.la;
+            association_records.push(r.association_record.into_iter().collect::<Vec<_>>());
             viscosity.push(r.viscosity);
             diffusion.push(r.diffusion);
             thermal_conductivity.push(r.thermal_conductivity);
             molarweight[i] = record.molarweight;
         }
 
+        let binary_association: Vec<_> = binary_records
+            .indexed_iter()
+            .filter_map(|((i, j), br)| br.association.map(|a| ([i, j], a)))
+            .collect();
+        let association =
+            AssociationParameters::new(&association_records, &sigma, &binary_association, None);
+
         let k_ij = binary_records.map(|br| br.k_ij);
         let l_ij = binary_records.map(|br| br.l_ij);
         let mut epsilon_k_ij = Array::zeros((n, n));
@@ -256,12 +447,16 @@ impl Parameter for SaftVRQMieParameters {
             lambda_r_ij,
             lambda_a_ij,
             mass_ij,
+            combining_rule: CombiningRule::LorentzBerthelot,
+            association,
             viscosity: viscosity_coefficients,
             diffusion: diffusion_coefficients,
             thermal_conductivity: thermal_conductivity_coefficients,
             pure_records,
+            binary_metadata: Array2::from_elem(binary_records.raw_dim(), None),
             binary_records,
             joback_records,
+            temperature_cache: TemperatureCache::new(),
         }
     }
 
@@ -273,9 +468,91 @@ impl Parameter for SaftVRQMieParameters {
     ) {
         (&self.pure_records, &self.binary_records)
     }
+
+    fn from_records_with_metadata(
+        pure_records: Vec<PureRecord<Self::Pure, Self::IdealGas>>,
+        binary_records: Array2<Self::Binary>,
+        binary_metadata: Array2<Option<Metadata>>,
+    ) -> Self {
+        let mut parameters = Self::from_records(pure_records, binary_records);
+        parameters.binary_metadata = binary_metadata;
+        parameters
+    }
+
+    fn binary_metadata(&self) -> Option<&Array2<Option<Metadata>>> {
+        Some(&self.binary_metadata)
+    }
+}
+
+impl HardSphereProperties for SaftVRQMieParameters {
+    fn monomer_shape<N: DualNum<f64>>(&self, _: N) -> MonomerShape<N> {
+        MonomerShape::Spherical(self.m.len())
+    }
+
+    fn hs_diameter<D: DualNum<f64>>(&self, temperature: D) -> Array1<D> {
+        self.hs_diameter(temperature)
+    }
 }
 
 impl SaftVRQMieParameters {
+    /// Creates parameters for a mixture of isotopologues and spin isomers
+    /// that share the parameters of an isotope set.
+    ///
+    /// The binary interaction parameters between the isotopes vanish.
+    /// The ideal gas contributions of the isotopes are available from
+    /// [SaftVRQMieIsotopeSet::ideal_gas].
+    pub fn from_isotopes(
+        isotope_set: &SaftVRQMieIsotopeSet,
+        substances: &[&str],
+        search_option: IdentifierOption,
+    ) -> Result<Self, ParameterError> {
+        let pure_records = isotope_set.pure_records(substances, search_option)?;
+        let n = pure_records.len();
+        Ok(Self::from_records(
+            pure_records,
+            Array2::from_elem((n, n), SaftVRQMieBinaryRecord::default()),
+        ))
+    }
+
+    /// Replace the combining rule for the cross diameters and energy parameters.
+    ///
+    /// The binary interaction parameters `k_ij` and `l_ij` are applied as
+    /// corrections to the combined parameters. For the Lorentz-Berthelot rule,
+    /// the energy parameters are additionally weighted with $\sqrt{\sigma_i^3\sigma_j^3}/\sigma_{ij}^3$
+    /// as in the default combining rule of SAFT-VR Mie.
+    pub fn with_combining_rule(&self, combining_rule: CombiningRule) -> Self {
+        let mut parameters = Self::from_records_with_metadata(
+            self.pure_records.clone(),
+            self.binary_records.clone(),
+            self.binary_metadata.clone(),
+        );
+        let (sigma_ij, mut e_k_ij) = combining_rule.cross_parameters(&self.sigma, &self.epsilon_k);
+        let sigma_ij = (1.0 - &self.l_ij) * sigma_ij;
+        if combining_rule == CombiningRule::LorentzBerthelot {
+            let sigma3 = self.sigma.mapv(|s| s.powi(3));
+            e_k_ij.indexed_iter_mut().for_each(|((i, j), e)| {
+                *e *= (sigma3[i] * sigma3[j]).sqrt() / sigma_ij[[i, j]].powi(3)
+            });
+        }
+        parameters.epsilon_k_ij = (1.0 - &self.k_ij) * &e_k_ij;
+        parameters.sigma_ij = sigma_ij;
+        parameters.e_k_ij = e_k_ij;
+        parameters.combining_rule = combining_rule;
+        parameters
+    }
+
+    /// Rebuild shared parameters if they do not use the given combining rule.
+    pub(crate) fn apply_combining_rule(
+        parameters: Arc<Self>,
+        combining_rule: CombiningRule,
+    ) -> Arc<Self> {
+        if parameters.combining_rule == combining_rule {
+            parameters
+        } else {
+            Arc::new(parameters.with_combining_rule(combining_rule))
+        }
+    }
+
     pub fn to_markdown(&self) -> String {
         let mut output = String::new();
         let o = &mut output;
@@ -402,6 +679,13 @@ impl std::fmt::Display for SaftVRQMieParameters {
         if !self.k_ij.iter().all(|k| k.is_zero()) {
             write!(f, "\n\tk_ij=\n{}", self.k_ij)?;
         }
+        if !self.association.assoc_comp.is_empty() {
+            write!(f, "\n\tassociating={}", self.association.assoc_comp)?;
+            write!(f, "\n\tkappa_ab={}", self.association.kappa_ab)?;
+            write!(f, "\n\tepsilon_k_ab={}", self.association.epsilon_k_ab)?;
+            write!(f, "\n\tna={}", self.association.na)?;
+            write!(f, "\n\tnb={}", self.association.nb)?;
+        }
         write!(f, "\n)")
     }
 }
@@ -488,6 +772,47 @@ pub mod utils {
         Arc::new(SaftVRQMieParameters::new_pure(neon_record))
     }
 
+    pub fn hydrogen_deuterium_fh1() -> Arc<SaftVRQMieParameters> {
+        let isotope_json = r#"
+            {
+                "model_record": {
+                    "m": 1.0,
+                    "sigma": 3.0243,
+                    "epsilon_k": 26.706,
+                    "lr": 9.0,
+                    "la": 6.0
+                },
+                "isotopes": [
+                    {
+                        "identifier": {
+                            "cas": "1333-74-0",
+                            "name": "hydrogen",
+                            "formula": "H2"
+                        },
+                        "molarweight": 2.0157309551872
+                    },
+                    {
+                        "identifier": {
+                            "cas": "7782-39-0",
+                            "name": "deuterium",
+                            "formula": "D2"
+                        },
+                        "molarweight": 4.0282035557
+                    }
+                ]
+            }"#;
+        let isotope_set: SaftVRQMieIsotopeSet =
+            serde_json::from_str(isotope_json).expect("Unable to parse json.");
+        Arc::new(
+            SaftVRQMieParameters::from_isotopes(
+                &isotope_set,
+                &["hydrogen", "deuterium"],
+                IdentifierOption::Name,
+            )
+            .unwrap(),
+        )
+    }
+
     pub fn h2_ne_fh1() -> Arc<SaftVRQMieParameters> {
         let binary_json = r#"[
             {
@@ -534,7 +859,76 @@ pub mod utils {
             Some(SaftVRQMieBinaryRecord {
                 k_ij: 0.105,
                 l_ij: 0.0,
+                association: None,
             }),
         ))
     }
+
+    pub fn water_fh1() -> Arc<SaftVRQMieParameters> {
+        let water_json = r#"
+            {
+                "identifier": {
+                    "cas": "7732-18-5",
+                    "name": "water",
+                    "formula": "H2O"
+                },
+                "model_record": {
+                    "m": 1.0,
+                    "sigma": 3.0063,
+                    "epsilon_k": 266.68,
+                    "lr": 17.02,
+                    "la": 6.0,
+                    "kappa_ab": 0.0692,
+                    "epsilon_k_ab": 1985.4,
+                    "na": 2.0,
+                    "nb": 2.0
+                },
+                "molarweight": 18.015
+            }"#;
+        let water_record: PureRecord<SaftVRQMieRecord, JobackRecord> =
+            serde_json::from_str(water_json).expect("Unable to parse json.");
+        Arc::new(SaftVRQMieParameters::new_pure(water_record))
+    }
+
+    pub fn h2_water_fh1() -> Arc<SaftVRQMieParameters> {
+        let binary_json = r#"[
+            {
+                "identifier": {
+                    "cas": "1333-74-0",
+                    "name": "hydrogen",
+                    "formula": "H2"
+                },
+                "model_record": {
+                    "m": 1.0,
+                    "sigma": 3.0243,
+                    "epsilon_k": 26.706,
+                    "lr": 9.0,
+                    "la": 6.0
+                },
+                "molarweight": 2.0157309551872
+            },
+            {
+                "identifier": {
+                    "cas": "7732-18-5",
+                    "name": "water",
+                    "formula": "H2O"
+                },
+                "model_record": {
+                    "m": 1.0,
+                    "sigma": 3.0063,
+                    "epsilon_k": 266.68,
+                    "lr": 17.02,
+                    "la": 6.0,
+                    "kappa_ab": 0.0692,
+                    "epsilon_k_ab": 1985.4,
+                    "na": 2.0,
+                    "nb": 2.0
+                },
+                "molarweight": 18.015
+            }
+        ]"#;
+        let binary_record: Vec<PureRecord<SaftVRQMieRecord, JobackRecord>> =
+            serde_json::from_str(binary_json).expect("Unable to parse json.");
+        Arc::new(SaftVRQMieParameters::new_binary(binary_record, None))
+    }
 }
@@ -0,0 +1,236 @@
+//! Fit of Mie potentials to tabulated pair potentials.
+use super::parameters::{SaftVRQMieParameters, SaftVRQMieRecord};
+use crate::estimator::{levenberg_marquardt, EstimatorError};
+use feos_core::parameter::{Identifier, Parameter, PureRecord};
+use feos_core::SolverOptions;
+use ndarray::{s, Array1, Zip};
+use quantity::si::{SIArray1, SINumber, ANGSTROM, KB, KELVIN};
+use std::fmt;
+
+/// Only points with a potential below this multiple of the well depth are fitted.
+const MAX_POTENTIAL_FIT: f64 = 10.0;
+const BOUNDS_LR: (f64, f64) = (7.0, 100.0);
+const BOUNDS_LA: (f64, f64) = (4.0, 6.9);
+
+/// The result of the fit of a Mie potential to a tabulated pair potential.
+pub struct PairPotentialFit {
+    /// The fitted pure component parameters.
+    pub record: SaftVRQMieRecord,
+    /// Root mean squared deviation of the fitted potential in units of Kelvin.
+    pub rmsd: f64,
+    /// The number of iterations of the optimizer.
+    pub iterations: usize,
+    /// Whether the optimizer converged within the maximum number of iterations.
+    pub converged: bool,
+}
+
+impl fmt::Display for PairPotentialFit {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "PairPotentialFit(sigma={}, epsilon_k={}, lr={}, la={}, rmsd={}, iterations={}, converged={})",
+            self.record.sigma,
+            self.record.epsilon_k,
+            self.record.lr,
+            self.record.la,
+            self.rmsd,
+            self.iterations,
+            self.converged
+        )
+    }
+}
+
+impl SaftVRQMieRecord {
+    /// Fit the parameters of a Mie potential to a tabulated pair
+    /// potential, e.g., from ab initio calculations.
+    ///
+    /// If a `temperature` is given, the first-order Feynman-Hibbs
+    /// corrected Mie potential is fitted to the Feynman-Hibbs corrected
+    /// pair potential at that temperature. The derivatives of the pair
+    /// potential are approximated by finite differences, so that the
+    /// first and last point of the table are not used in this case.
+    ///
+    /// The attractive exponent is fixed to `la` if it is provided.
+    /// Only points with a potential below ten times the well depth
+    /// are considered in the fit.
+    pub fn fit_pair_potential(
+        distance: &SIArray1,
+        potential: &SIArray1,
+        molarweight: f64,
+        temperature: Option<SINumber>,
+        la: Option<f64>,
+        options: SolverOptions,
+    ) -> Result<PairPotentialFit, EstimatorError> {
+        let r = distance.to_reduced(ANGSTROM)?;
+        let u = potential.to_reduced(KB * KELVIN)?;
+        if r.len() != u.len() {
+            return Err(EstimatorError::IncompatibleInput);
+        }
+        if r.len() < 5 || r.windows(2).into_iter().any(|w| w[1] <= w[0]) {
+            return Err(EstimatorError::InvalidParameter(String::from(
+                "The pair potential requires at least 5 points at increasing distances.",
+            )));
+        }
+
+        // parameters of the Feynman-Hibbs correction
+        let parameters = |record: SaftVRQMieRecord| {
+            SaftVRQMieParameters::new_pure(PureRecord::new(
+                Identifier::default(),
+                molarweight,
+                record,
+                None,
+            ))
+        };
+        let t = match temperature {
+            Some(t) => t.to_reduced(KELVIN)?,
+            // the quantum correction vanishes in the classical limit
+            None => f64::INFINITY,
+        };
+        let mie = SaftVRQMieRecord::new(
+            1.0, 1.0, 1.0, 12.0, 6.0, None, None, None, None, None, None, None,
+        );
+        let d = parameters(mie).quantum_d_ij(0, 0, t);
+
+        // the (Feynman-Hibbs corrected) pair potential
+        let (r, u) = match temperature {
+            Some(_) => feynman_hibbs_correction(&r, &u, d),
+            None => (r, u),
+        };
+
+        // initial values from the minimum and the root of the potential
+        let (i_min, &u_min) = u
+            .iter()
+            .enumerate()
+            .min_by(|a, b| a.1.total_cmp(b.1))
+            .unwrap();
+        if u_min >= 0.0 {
+            return Err(EstimatorError::InvalidParameter(String::from(
+                "The pair potential has no attractive well.",
+            )));
+        }
+        let epsilon_k = -u_min;
+        let sigma = (1..=i_min)
+            .rev()
+            .find(|&i| u[i - 1] > 0.0)
+            .map_or(r[i_min] * 2f64.powf(-1.0 / 6.0), |i| {
+                r[i - 1] - u[i - 1] * (r[i] - r[i - 1]) / (u[i] - u[i - 1])
+            });
+
+        let (r, u): (Vec<_>, Vec<_>) = r
+            .iter()
+            .zip(u.iter())
+            .filter(|(_, &u)| u <= MAX_POTENTIAL_FIT * epsilon_k)
+            .map(|(&r, &u)| (r, u))
+            .unzip();
+        let (r, u) = (Array1::from(r), Array1::from(u));
+
+        let record = |x: &Array1<f64>| SaftVRQMieRecord {
+            m: 1.0,
+            sigma: x[0],
+            epsilon_k: x[1],
+            lr: x[2],
+            la: la.unwrap_or_else(|| x[3]),
+            ..Default::default()
+        };
+        let residuals = |x: &Array1<f64>| -> Result<Array1<f64>, EstimatorError> {
+            let p = parameters(record(x));
+            Ok(Zip::from(&r)
+                .and(&u)
+                .map_collect(|&r, &u| (p.qmie_potential_ij(0, 0, r, t)[0] - u) / epsilon_k))
+        };
+
+        let mut x0 = vec![sigma, epsilon_k, 12.0];
+        let mut bounds = vec![
+            (0.5 * sigma, 2.0 * sigma),
+            (1e-3 * epsilon_k, 1e3 * epsilon_k),
+            BOUNDS_LR,
+        ];
+        if la.is_none() {
+            x0.push(6.0);
+            bounds.push(BOUNDS_LA);
+        }
+        let (x, cost, iterations, converged) =
+            levenberg_marquardt(residuals, Array1::from(x0), &bounds, options, None)?;
+        Ok(PairPotentialFit {
+            record: record(&x),
+            rmsd: (cost / r.len() as f64).sqrt() * epsilon_k,
+            iterations,
+            converged,
+        })
+    }
+}
+
+/// Apply the first-order Feynman-Hibbs correction
+/// $u+D\left(u''+\frac{2}{r}u'\right)$ to a tabulated potential
+/// using finite differences on the (non-uniform) grid.
+///
+/// The first and last point of the table are discarded.
+fn feynman_hibbs_correction(
+    r: &Array1<f64>,
+    u: &Array1<f64>,
+    d: f64,
+) -> (Array1<f64>, Array1<f64>) {
+    let n = r.len();
+    let u_fh = Array1::from_shape_fn(n - 2, |k| {
+        let i = k + 1;
+        let h1 = r[i] - r[i - 1];
+        let h2 = r[i + 1] - r[i];
+        let du = -h2 / (h1 * (h1 + h2)) * u[i - 1]
+            + (h2 - h1) / (h1 * h2) * u[i]
+            + h1 / (h2 * (h1 + h2)) * u[i + 1];
+        let d2u =
+            2.0 * (u[i - 1] / (h1 * (h1 + h2)) - u[i] / (h1 * h2) + u[i + 1] / (h2 * (h1 + h2)));
+        u[i] + d * (d2u + 2.0 * du / r[i])
+    });
+    (r.slice(s![1..n - 1]).to_owned(), u_fh)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_relative_eq;
+
+    fn mie_potential(r: &Array1<f64>) -> SIArray1 {
+        let (sigma, epsilon_k, lr, la): (f64, f64, f64, f64) = (3.0, 30.0, 12.0, 6.0);
+        let c = lr / (lr - la) * (lr / la).powf(la / (lr - la));
+        r.mapv(|r| c * epsilon_k * ((sigma / r).powf(lr) - (sigma / r).powf(la))) * (KB * KELVIN)
+    }
+
+    #[test]
+    fn fit_mie_potential() -> Result<(), EstimatorError> {
+        let r = Array1::linspace(2.7, 10.0, 200);
+        let potential = mie_potential(&r);
+        let fit = SaftVRQMieRecord::fit_pair_potential(
+            &(r * ANGSTROM),
+            &potential,
+            20.0,
+            None,
+            Some(6.0),
+            SolverOptions::default(),
+        )?;
+        assert!(fit.converged);
+        assert_relative_eq!(fit.record.sigma, 3.0, max_relative = 1e-6);
+        assert_relative_eq!(fit.record.epsilon_k, 30.0, max_relative = 1e-6);
+        assert_relative_eq!(fit.record.lr, 12.0, max_relative = 1e-6);
+        assert!(fit.rmsd < 1e-6);
+        Ok(())
+    }
+
+    #[test]
+    fn fit_feynman_hibbs_potential() -> Result<(), EstimatorError> {
+        let r = Array1::linspace(2.7, 10.0, 2000);
+        let potential = mie_potential(&r);
+        let fit = SaftVRQMieRecord::fit_pair_potential(
+            &(r * ANGSTROM),
+            &potential,
+            20.0,
+            Some(30.0 * KELVIN),
+            Some(6.0),
+            SolverOptions::default(),
+        )?;
+        assert_relative_eq!(fit.record.sigma, 3.0, max_relative = 1e-4);
+        assert_relative_eq!(fit.record.epsilon_k, 30.0, max_relative = 1e-4);
+        assert_relative_eq!(fit.record.lr, 12.0, max_relative = 1e-3);
+        Ok(())
+    }
+}
@@ -1,19 +1,27 @@
 //! Python bindings for the SAFT-VRQ Mie equation of state.
+use crate::association::BinaryAssociationRecord;
 use crate::saftvrqmie::eos::FeynmanHibbsOrder;
 use crate::saftvrqmie::parameters::{
-    SaftVRQMieBinaryRecord, SaftVRQMieParameters, SaftVRQMieRecord,
+    SaftVRQMieBinaryRecord, SaftVRQMieIsotopeRecord, SaftVRQMieIsotopeSet, SaftVRQMieParameters,
+    SaftVRQMieRecord,
 };
+#[cfg(feature = "estimator")]
+use crate::saftvrqmie::potential_fit::PairPotentialFit;
 use feos_core::joback::JobackRecord;
 use feos_core::parameter::{
-    BinaryRecord, Identifier, IdentifierOption, Parameter, ParameterError, PureRecord,
+    BinaryRecord, CombiningRule, Identifier, IdentifierOption, Parameter, ParameterError,
+    PureRecord,
 };
+use feos_core::python::ideal_gas::{PyIdealGasModel, PyIdealGasRecord};
 use feos_core::python::joback::PyJobackRecord;
-use feos_core::python::parameter::PyIdentifier;
+use feos_core::python::parameter::{PyIdentifier, PyMetadata};
 use feos_core::*;
 use ndarray::Array2;
 use numpy::{PyArray2, PyReadonlyArray2, ToPyArray};
-use pyo3::exceptions::{PyIOError, PyTypeError};
+use pyo3::exceptions::{PyIOError, PyTypeError, PyValueError};
 use pyo3::prelude::*;
+#[cfg(feature = "estimator")]
+use quantity::python::PySIArray1;
 use quantity::python::PySINumber;
 use std::convert::{TryFrom, TryInto};
 use std::sync::Arc;
@@ -21,7 +29,7 @@ use std::sync::Arc;
 /// Create a set of Saft-VRQ Mie parameters from records.
 #[pyclass(name = "SaftVRQMieRecord")]
 #[pyo3(
-    text_signature = "(m, sigma, epsilon_k, viscosity=None, diffusion=None, thermal_conductivity=None)"
+    text_signature = "(m, sigma, epsilon_k, lr, la, viscosity=None, kappa_ab=None, epsilon_k_ab=None, na=None, nb=None)"
 )]
 #[derive(Clone)]
 pub struct PySaftVRQMieRecord(SaftVRQMieRecord);
@@ -36,9 +44,24 @@ impl PySaftVRQMieRecord {
         lr: f64,
         la: f64,
         viscosity: Option<[f64; 4]>,
+        kappa_ab: Option<f64>,
+        epsilon_k_ab: Option<f64>,
+        na: Option<f64>,
+        nb: Option<f64>,
     ) -> Self {
         Self(SaftVRQMieRecord::new(
-            m, sigma, epsilon_k, lr, la, viscosity, None, None,
+            m,
+            sigma,
+            epsilon_k,
+            lr,
+            la,
+            kappa_ab,
+            epsilon_k_ab,
+            na,
+            nb,
+            viscosity,
+            None,
+            None,
         ))
     }
 
@@ -67,6 +90,26 @@ impl PySaftVRQMieRecord {
         self.0.la
     }
 
+    #[getter]
+    fn get_kappa_ab(&self) -> Option<f64> {
+        self.0.association_record.map(|a| a.kappa_ab)
+    }
+
+    #[getter]
+    fn get_epsilon_k_ab(&self) -> Option<f64> {
+        self.0.association_record.map(|a| a.epsilon_k_ab)
+    }
+
+    #[getter]
+    fn get_na(&self) -> Option<f64> {
+        self.0.association_record.and_then(|a| a.na)
+    }
+
+    #[getter]
+    fn get_nb(&self) -> Option<f64> {
+        self.0.association_record.and_then(|a| a.nb)
+    }
+
     #[getter]
     fn get_viscosity(&self) -> Option<[f64; 4]> {
         self.0.viscosity
@@ -82,22 +125,144 @@ impl PySaftVRQMieRecord {
         self.0.thermal_conductivity
     }
 
+    /// Fit the parameters of a Mie potential to a tabulated pair
+    /// potential, e.g., from ab initio calculations.
+    ///
+    /// Parameters
+    /// ----------
+    /// distance : SIArray1
+    ///     The distances at which the pair potential is tabulated
+    ///     in increasing order.
+    /// potential : SIArray1
+    ///     The pair potential (energy per pair).
+    /// molarweight : float
+    ///     The molar weight in units of g/mol.
+    /// temperature : SINumber, optional
+    ///     If given, the Feynman-Hibbs corrected Mie potential is fitted
+    ///     to the Feynman-Hibbs corrected pair potential at this temperature.
+    /// la : float, optional
+    ///     Fixed attractive exponent. Fitted if not provided.
+    /// max_iter : int, optional
+    ///     The maximum number of iterations.
+    /// tol: float, optional
+    ///     The solution tolerance.
+    /// verbosity : Verbosity, optional
+    ///     The verbosity.
+    ///
+    /// Returns
+    /// -------
+    /// PairPotentialFit
+    #[cfg(feature = "estimator")]
+    #[staticmethod]
+    #[pyo3(
+        text_signature = "(distance, potential, molarweight, temperature=None, la=None, max_iter=None, tol=None, verbosity=None)"
+    )]
+    fn fit_pair_potential(
+        distance: PySIArray1,
+        potential: PySIArray1,
+        molarweight: f64,
+        temperature: Option<PySINumber>,
+        la: Option<f64>,
+        max_iter: Option<usize>,
+        tol: Option<f64>,
+        verbosity: Option<Verbosity>,
+    ) -> PyResult<PyPairPotentialFit> {
+        Ok(PyPairPotentialFit(SaftVRQMieRecord::fit_pair_potential(
+            &distance.into(),
+            &potential.into(),
+            molarweight,
+            temperature.map(|t| t.into()),
+            la,
+            (max_iter, tol, verbosity).into(),
+        )?))
+    }
+
+    fn __repr__(&self) -> PyResult<String> {
+        Ok(self.0.to_string())
+    }
+}
+
+/// The result of the fit of a Mie potential to a tabulated pair potential.
+#[cfg(feature = "estimator")]
+#[pyclass(name = "PairPotentialFit")]
+pub struct PyPairPotentialFit(PairPotentialFit);
+
+#[cfg(feature = "estimator")]
+#[pymethods]
+impl PyPairPotentialFit {
+    /// The fitted pure component parameters.
+    #[getter]
+    fn get_record(&self) -> PySaftVRQMieRecord {
+        PySaftVRQMieRecord(self.0.record.clone())
+    }
+
+    /// Root mean squared deviation of the fitted potential in units of Kelvin.
+    #[getter]
+    fn get_rmsd(&self) -> f64 {
+        self.0.rmsd
+    }
+
+    #[getter]
+    fn get_iterations(&self) -> usize {
+        self.0.iterations
+    }
+
+    #[getter]
+    fn get_converged(&self) -> bool {
+        self.0.converged
+    }
+
     fn __repr__(&self) -> PyResult<String> {
         Ok(self.0.to_string())
     }
 }
 
 /// Create a set of Saft-VRQ Mie parameters from records.
+///
+/// Parameters
+/// ----------
+/// k_ij : float
+///     Correction to the energy parameter.
+/// l_ij : float
+///     Correction to the segment diameter.
+/// kappa_ab : float, optional
+///     Cross-association volume parameter that replaces
+///     the combining rule.
+/// epsilon_k_ab : float, optional
+///     Cross-association energy parameter in units of Kelvin
+///     that replaces the combining rule.
+///
+/// Returns
+/// -------
+/// SaftVRQMieBinaryRecord
 #[pyclass(name = "SaftVRQMieBinaryRecord")]
-#[pyo3(text_signature = "(k_ij, l_ij)")]
+#[pyo3(text_signature = "(k_ij, l_ij, kappa_ab=None, epsilon_k_ab=None)")]
 #[derive(Clone)]
 pub struct PySaftVRQMieBinaryRecord(SaftVRQMieBinaryRecord);
 
 #[pymethods]
 impl PySaftVRQMieBinaryRecord {
     #[new]
-    fn new(k_ij: f64, l_ij: f64) -> Self {
-        Self(SaftVRQMieBinaryRecord { k_ij, l_ij })
+    fn new(
+        k_ij: f64,
+        l_ij: f64,
+        kappa_ab: Option<f64>,
+        epsilon_k_ab: Option<f64>,
+    ) -> PyResult<Self> {
+        let association = match (kappa_ab, epsilon_k_ab) {
+            (Some(kappa_ab), Some(epsilon_k_ab)) => {
+                Some(BinaryAssociationRecord::new(kappa_ab, epsilon_k_ab))
+            }
+            (None, None) => None,
+            _ => return Err(PyErr::new::<PyValueError, _>(
+                "To model cross-association, both kappa_ab and epsilon_k_ab need to be specified.",
+            )),
+        };
+        Ok(Self(SaftVRQMieBinaryRecord {
+            k_ij,
+            l_ij,
+            association,
+        }))
     }
 
     #[getter]
@@ -110,6 +275,16 @@ impl PySaftVRQMieBinaryRecord {
         self.0.l_ij
     }
 
+    #[getter]
+    fn get_kappa_ab(&self) -> Option<f64> {
+        self.0.association.map(|a| a.kappa_ab)
+    }
+
+    #[getter]
+    fn get_epsilon_k_ab(&self) -> Option<f64> {
+        self.0.association.map(|a| a.epsilon_k_ab)
+    }
+
     #[setter]
     fn set_k_ij(&mut self, k_ij: f64) {
         self.0.k_ij = k_ij
@@ -121,6 +296,171 @@ impl PySaftVRQMieBinaryRecord {
     }
 }
 
+/// Isotopologue or spin isomer of a component.
+///
+/// Parameters
+/// ----------
+/// identifier : Identifier
+///     The identifier of the isotope.
+/// molarweight : float
+///     The molar weight in units of g/mol.
+/// ideal_gas_record : IdealGasRecord, optional
+///     The ideal gas heat capacity model of the isotope.
+///
+/// Returns
+/// -------
+/// SaftVRQMieIsotopeRecord
+#[pyclass(name = "SaftVRQMieIsotopeRecord")]
+#[pyo3(text_signature = "(identifier, molarweight, ideal_gas_record=None)")]
+#[derive(Clone)]
+pub struct PySaftVRQMieIsotopeRecord(SaftVRQMieIsotopeRecord);
+
+#[pymethods]
+impl PySaftVRQMieIsotopeRecord {
+    #[new]
+    fn new(
+        identifier: PyIdentifier,
+        molarweight: f64,
+        ideal_gas_record: Option<PyIdealGasRecord>,
+    ) -> Self {
+        Self(SaftVRQMieIsotopeRecord::new(
+            identifier.0,
+            molarweight,
+            ideal_gas_record.map(|r| r.0),
+        ))
+    }
+
+    #[getter]
+    fn get_identifier(&self) -> PyIdentifier {
+        PyIdentifier(self.0.identifier.clone())
+    }
+
+    #[getter]
+    fn get_molarweight(&self) -> f64 {
+        self.0.molarweight
+    }
+
+    #[getter]
+    fn get_ideal_gas_record(&self) -> Option<PyIdealGasRecord> {
+        self.0.ideal_gas_record.clone().map(PyIdealGasRecord)
+    }
+
+    fn __repr__(&self) -> PyResult<String> {
+        Ok(self.0.to_string())
+    }
+}
+
+/// SAFT-VRQ Mie parameters shared by the isotopologues and spin
+/// isomers of a component.
+///
+/// Parameters
+/// ----------
+/// model_record : SaftVRQMieRecord
+///     The SAFT-VRQ Mie parameters of all isotopes.
+/// isotopes : List[SaftVRQMieIsotopeRecord]
+///     The isotopes.
+///
+/// Returns
+/// -------
+/// SaftVRQMieIsotopeSet
+#[pyclass(name = "SaftVRQMieIsotopeSet")]
+#[pyo3(text_signature = "(model_record, isotopes)")]
+#[derive(Clone)]
+pub struct PySaftVRQMieIsotopeSet(SaftVRQMieIsotopeSet);
+
+#[pymethods]
+impl PySaftVRQMieIsotopeSet {
+    #[new]
+    fn new(model_record: PySaftVRQMieRecord, isotopes: Vec<PySaftVRQMieIsotopeRecord>) -> Self {
+        Self(SaftVRQMieIsotopeSet::new(
+            model_record.0,
+            isotopes.into_iter().map(|i| i.0).collect(),
+        ))
+    }
+
+    /// Read an isotope set from a json file.
+    ///
+    /// Parameters
+    /// ----------
+    /// path : str
+    ///     Path to the json file.
+    ///
+    /// Returns
+    /// -------
+    /// SaftVRQMieIsotopeSet
+    #[staticmethod]
+    #[pyo3(text_signature = "(path)")]
+    fn from_json(path: &str) -> Result<Self, ParameterError> {
+        Ok(Self(SaftVRQMieIsotopeSet::from_json(path)?))
+    }
+
+    /// Create parameters for a mixture of isotopes.
+    ///
+    /// Parameters
+    /// ----------
+    /// substances : List[str]
+    ///     The isotopes to use.
+    /// search_option : IdentifierOption, optional, defaults to IdentifierOption.Name
+    ///     Identifier that is used to search the isotopes.
+    ///
+    /// Returns
+    /// -------
+    /// SaftVRQMieParameters
+    #[pyo3(
+        signature = (substances, search_option=IdentifierOption::Name),
+        text_signature = "($self, substances, search_option=None)"
+    )]
+    fn parameters(
+        &self,
+        substances: Vec<&str>,
+        search_option: IdentifierOption,
+    ) -> Result<PySaftVRQMieParameters, ParameterError> {
+        Ok(PySaftVRQMieParameters(Arc::new(
+            SaftVRQMieParameters::from_isotopes(&self.0, &substances, search_option)?,
+        )))
+    }
+
+    /// Create the ideal gas contribution for a mixture of isotopes.
+    ///
+    /// Parameters
+    /// ----------
+    /// substances : List[str]
+    ///     The isotopes to use.
+    /// search_option : IdentifierOption, optional, defaults to IdentifierOption.Name
+    ///     Identifier that is used to search the isotopes.
+    ///
+    /// Returns
+    /// -------
+    /// IdealGasModel
+    #[pyo3(
+        signature = (substances, search_option=IdentifierOption::Name),
+        text_signature = "($self, substances, search_option=None)"
+    )]
+    fn ideal_gas(
+        &self,
+        substances: Vec<&str>,
+        search_option: IdentifierOption,
+    ) -> Result<PyIdealGasModel, ParameterError> {
+        Ok(PyIdealGasModel(
+            self.0.ideal_gas(&substances, search_option)?,
+        ))
+    }
+
+    #[getter]
+    fn get_model_record(&self) -> PySaftVRQMieRecord {
+        PySaftVRQMieRecord(self.0.model_record.clone())
+    }
+
+    #[getter]
+    fn get_isotopes(&self) -> Vec<PySaftVRQMieIsotopeRecord> {
+        self.0
+            .isotopes
+            .iter()
+            .map(|i| PySaftVRQMieIsotopeRecord(i.clone()))
+            .collect()
+    }
+}
+
 /// Create a set of SAFT-VRQ Mie parameters from records.
 ///
 /// Parameters
@@ -133,7 +473,7 @@ impl PySaftVRQMieBinaryRecord {
 ///     The substances to use. Filters substances from `pure_records` according to
 ///     `search_option`.
 ///     When not provided, all entries of `pure_records` are used.
-/// search_option : {'Name', 'Cas', 'Inchi', 'IupacName', 'Formula', 'Smiles'}, optional, defaults to 'Name'.
+/// search_option : {'Name', 'Cas', 'Inchi', 'InchiKey', 'IupacName', 'Formula', 'Smiles'}, optional, defaults to 'Name'.
 ///     Identifier that is used to search substance.
 ///
 /// Returns
@@ -228,7 +568,9 @@ impl PySaftVRQMieParameters {
 #[pymodule]
 pub fn saftvrqmie(_py: Python<'_>, m: &PyModule) -> PyResult<()> {
     m.add_class::<PyIdentifier>()?;
+    m.add_class::<PyMetadata>()?;
     m.add_class::<IdentifierOption>()?;
+    m.add_class::<CombiningRule>()?;
     m.add_class::<PyJobackRecord>()?;
     m.add_class::<FeynmanHibbsOrder>()?;
 
@@ -236,5 +578,11 @@ pub fn saftvrqmie(_py: Python<'_>, m: &PyModule) -> PyResult<()> {
     m.add_class::<PyPureRecord>()?;
     m.add_class::<PyBinaryRecord>()?;
     m.add_class::<PySaftVRQMieParameters>()?;
+    m.add_class::<PySaftVRQMieIsotopeRecord>()?;
+    m.add_class::<PySaftVRQMieIsotopeSet>()?;
+    #[cfg(feature = "estimator")]
+    m.add_class::<PyPairPotentialFit>()?;
+    m.add_class::<PyIdealGasRecord>()?;
+    m.add_class::<PyIdealGasModel>()?;
     Ok(())
 }
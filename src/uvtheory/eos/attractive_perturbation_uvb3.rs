@@ -4,7 +4,7 @@ use super::hard_sphere_wca::{
 };
 use crate::uvtheory::parameters::*;
 use feos_core::{HelmholtzEnergyDual, StateHD};
-use ndarray::Array1;
+use ndarray::{Array1, Array2};
 use num_dual::DualNum;
 use std::{f64::consts::PI, fmt, sync::Arc};
 
@@ -163,31 +163,39 @@ fn residual_virial_coefficient<D: DualNum<f64>>(p: &UVParameters, x: &Array1<D>,
     }
     delta_b2bar
 }
+/// Residual third virial coefficient of the mixture.
+///
+/// The cross coefficients of three different components are approximated
+/// by the geometric mean of the binary coefficients:
+/// $\Delta B_{3,ijk}=\left(\Delta B_{3,ij}\Delta B_{3,ik}\Delta B_{3,jk}\right)^{1/3}$
 fn residual_third_virial_coefficient<D: DualNum<f64>>(
     p: &UVParameters,
     x: &Array1<D>,
     t: D,
     d: &Array1<D>,
 ) -> D {
-    let mut delta_b3bar = D::zero();
-
-    for i in 0..p.ncomponents {
-        let xi = x[i];
+    let n = p.ncomponents;
+    let delta_b3_ij = Array2::from_shape_fn((n, n), |(i, j)| {
+        let t_ij = t / p.eps_k_ij[[i, j]];
+        let rep_ij = p.rep_ij[[i, j]];
+        let att_ij = p.att_ij[[i, j]];
+        let q_ij = dimensionless_diameter_q_wca(t_ij, D::from(rep_ij), D::from(att_ij));
+        let rm_ij = (rep_ij / att_ij).powd((rep_ij - att_ij).recip());
+        let d_ij = (d[i] / p.sigma[i] + d[j] / p.sigma[j]) * 0.5;
+        delta_b3(t_ij, rm_ij, rep_ij, att_ij, d_ij, q_ij) * p.sigma_ij[[i, j]].powi(6)
+    });
 
-        for j in 0..p.ncomponents {
-            let t_ij = t / p.eps_k_ij[[i, j]];
-            let rep_ij = p.rep_ij[[i, j]];
-            let att_ij = p.att_ij[[i, j]];
-            let q_ij = dimensionless_diameter_q_wca(t_ij, D::from(rep_ij), D::from(att_ij));
-
-            // No mixing rule defined for B3 yet! The implemented rule is just taken from B2 and not correct!
-            let rm_ij = (rep_ij / att_ij).powd((rep_ij - att_ij).recip());
-            let d_ij = (d[i] / p.sigma[i] + d[j] / p.sigma[j]) * 0.5;
-            // Recheck mixing rule!
-            delta_b3bar += xi
-                * x[j]
-                * p.sigma_ij[[i, j]].powi(6)
-                * delta_b3(t_ij, rm_ij, rep_ij, att_ij, d_ij, q_ij);
+    let mut delta_b3bar = D::zero();
+    for i in 0..n {
+        for j in 0..n {
+            for k in 0..n {
+                let b_ijk = if i == j && j == k {
+                    delta_b3_ij[[i, i]]
+                } else {
+                    (delta_b3_ij[[i, j]] * delta_b3_ij[[i, k]] * delta_b3_ij[[j, k]]).cbrt()
+                };
+                delta_b3bar += x[i] * x[j] * x[k] * b_ijk;
+            }
         }
     }
     delta_b3bar
@@ -1,9 +1,16 @@
 #![allow(clippy::excessive_precision)]
 #![allow(clippy::needless_range_loop)]
 
-use super::parameters::UVParameters;
-use feos_core::{parameter::Parameter, EosError, EosResult, EquationOfState, HelmholtzEnergy};
+use super::parameters::{NoRecord, UVBinaryRecord, UVParameters, UVRecord};
+use feos_core::ideal_gas::{IdealGasModel, WithIdealGas};
+use feos_core::{
+    parameter::{CombiningRule, Parameter, ParameterSet},
+    DefaultIdealGasContribution, EosError, EosResult, EquationOfState, HelmholtzEnergy,
+    IdealGasContribution, MaxDensity,
+};
 use ndarray::Array1;
+use serde::de::Error;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
 use std::f64::consts::FRAC_PI_6;
 use std::sync::Arc;
 
@@ -26,7 +33,7 @@ use reference_perturbation_uvb3::ReferencePerturbationUVB3;
 use reference_perturbation_wca::ReferencePerturbationWCA;
 
 /// Type of perturbation.
-#[derive(Clone)]
+#[derive(Clone, Serialize, Deserialize)]
 #[cfg_attr(feature = "python", pyo3::pyclass)]
 pub enum Perturbation {
     BarkerHenderson,
@@ -34,7 +41,7 @@ pub enum Perturbation {
 }
 
 /// Order of the highest virial coefficient included in the model.
-#[derive(Clone)]
+#[derive(Clone, Serialize, Deserialize)]
 #[cfg_attr(feature = "python", pyo3::pyclass)]
 pub enum VirialOrder {
     Second,
@@ -42,19 +49,24 @@ pub enum VirialOrder {
 }
 
 /// Configuration options for uv-theory
-#[derive(Clone)]
+#[derive(Clone, Serialize, Deserialize)]
 pub struct UVTheoryOptions {
     pub max_eta: f64,
+    pub max_density: MaxDensity,
     pub perturbation: Perturbation,
     pub virial_order: VirialOrder,
+    #[serde(default)]
+    pub combining_rule: CombiningRule,
 }
 
 impl Default for UVTheoryOptions {
     fn default() -> Self {
         Self {
             max_eta: 0.5,
+            max_density: MaxDensity::Heuristic,
             perturbation: Perturbation::WeeksChandlerAndersen,
             virial_order: VirialOrder::Second,
+            combining_rule: CombiningRule::LorentzBerthelot,
         }
     }
 }
@@ -64,6 +76,7 @@ pub struct UVTheory {
     parameters: Arc<UVParameters>,
     options: UVTheoryOptions,
     contributions: Vec<Box<dyn HelmholtzEnergy>>,
+    ideal_gas: Option<IdealGasModel>,
 }
 
 impl UVTheory {
@@ -77,6 +90,7 @@ impl UVTheory {
         parameters: Arc<UVParameters>,
         options: UVTheoryOptions,
     ) -> EosResult<Self> {
+        let parameters = UVParameters::apply_combining_rule(parameters, options.combining_rule);
         let mut contributions: Vec<Box<dyn HelmholtzEnergy>> = Vec::with_capacity(3);
 
         match options.perturbation {
@@ -113,13 +127,7 @@ impl UVTheory {
                         }));
                     }
                     VirialOrder::Third => {
-                        if parameters.sigma.len() > 1 {
-                            return Err(EosError::Error(
-                                "Third virial coefficient is not implemented for mixtures!"
-                                    .to_string(),
-                            ));
-                        }
-                        if parameters.att[0] != 6.0 {
+                        if parameters.att.iter().any(|&att| att != 6.0) {
                             return Err(EosError::Error(
                                 "Third virial coefficient is not implemented for attractive exponents other than 6!"
                                     .to_string(),
@@ -140,6 +148,7 @@ impl UVTheory {
             parameters,
             options,
             contributions,
+            ideal_gas: None,
         })
     }
 }
@@ -150,21 +159,79 @@ impl EquationOfState for UVTheory {
     }
 
     fn subset(&self, component_list: &[usize]) -> Self {
-        Self::with_options(
-            Arc::new(self.parameters.subset(component_list)),
-            self.options.clone(),
-        )
-        .expect("Not defined for mixture")
+        Self {
+            ideal_gas: self
+                .ideal_gas
+                .as_ref()
+                .map(|ideal_gas| ideal_gas.subset(component_list)),
+            ..Self::with_options(
+                Arc::new(self.parameters.subset(component_list)),
+                self.options.clone(),
+            )
+            .expect("Not defined for mixture")
+        }
     }
 
     fn compute_max_density(&self, moles: &Array1<f64>) -> f64 {
-        self.options.max_eta * moles.sum()
-            / (FRAC_PI_6 * self.parameters.sigma.mapv(|v| v.powi(3)) * moles).sum()
+        self.options.max_density.apply(
+            self.options.max_eta * moles.sum()
+                / (FRAC_PI_6 * self.parameters.sigma.mapv(|v| v.powi(3)) * moles).sum(),
+        )
     }
 
     fn residual(&self) -> &[Box<dyn HelmholtzEnergy>] {
         &self.contributions
     }
+
+    fn ideal_gas(&self) -> &dyn IdealGasContribution {
+        match &self.ideal_gas {
+            Some(ideal_gas) => ideal_gas,
+            None => &DefaultIdealGasContribution,
+        }
+    }
+}
+
+impl WithIdealGas for UVTheory {
+    fn with_ideal_gas(mut self, ideal_gas: IdealGasModel) -> EosResult<Self> {
+        ideal_gas.validate_components(self.components())?;
+        self.ideal_gas = Some(ideal_gas);
+        Ok(self)
+    }
+}
+
+// Auxiliary structure used to (de)serialize the equation of state.
+#[derive(Serialize, Deserialize)]
+struct UVTheoryJSON {
+    parameters: ParameterSet<UVRecord, NoRecord, UVBinaryRecord>,
+    options: UVTheoryOptions,
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    ideal_gas: Option<IdealGasModel>,
+}
+
+impl Serialize for UVTheory {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        UVTheoryJSON {
+            parameters: self.parameters.parameter_set(),
+            options: self.options.clone(),
+            ideal_gas: self.ideal_gas.clone(),
+        }
+        .serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for UVTheory {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let eos = UVTheoryJSON::deserialize(deserializer)?;
+        let parameters =
+            UVParameters::from_parameter_set(eos.parameters).map_err(D::Error::custom)?;
+        let uvtheory =
+            Self::with_options(Arc::new(parameters), eos.options).map_err(D::Error::custom)?;
+        match eos.ideal_gas {
+            Some(ideal_gas) => uvtheory.with_ideal_gas(ideal_gas).map_err(D::Error::custom),
+            None => Ok(uvtheory),
+        }
+    }
 }
 
 #[cfg(test)]
@@ -209,8 +276,10 @@ mod test {
         let parameters = UVParameters::new_simple(rep, att, sig, eps_k);
         let options = UVTheoryOptions {
             max_eta: 0.5,
+            max_density: MaxDensity::Heuristic,
             perturbation: Perturbation::BarkerHenderson,
             virial_order: VirialOrder::Second,
+            combining_rule: CombiningRule::LorentzBerthelot,
         };
         let eos = Arc::new(UVTheory::with_options(Arc::new(parameters), options)?);
 
@@ -239,8 +308,10 @@ mod test {
         let parameters = UVParameters::new_simple(rep, att, sig, eps_k);
         let options = UVTheoryOptions {
             max_eta: 0.5,
+            max_density: MaxDensity::Heuristic,
             perturbation: Perturbation::WeeksChandlerAndersen,
             virial_order: VirialOrder::Third,
+            combining_rule: CombiningRule::LorentzBerthelot,
         };
         let eos = Arc::new(UVTheory::with_options(Arc::new(parameters), options)?);
 
@@ -259,6 +330,40 @@ mod test {
         Ok(())
     }
 
+    #[test]
+    fn helmholtz_energy_mixtures_uvb3() -> EosResult<()> {
+        // Mixture of equal components --> result must be the same as for pure fluid
+        let eps_k = 150.03;
+        let sig = 3.7039;
+        let p = test_parameters_mixture(
+            arr1(&[12.0, 12.0]),
+            arr1(&[6.0, 6.0]),
+            arr1(&[sig, sig]),
+            arr1(&[eps_k, eps_k]),
+        );
+        let options = UVTheoryOptions {
+            max_eta: 0.5,
+            max_density: MaxDensity::Heuristic,
+            perturbation: Perturbation::WeeksChandlerAndersen,
+            virial_order: VirialOrder::Third,
+            combining_rule: CombiningRule::LorentzBerthelot,
+        };
+        let eos = Arc::new(UVTheory::with_options(Arc::new(p), options)?);
+
+        let reduced_temperature = 4.0;
+        let reduced_density = 0.5;
+        let temperature = reduced_temperature * eps_k * KELVIN;
+        let moles = arr1(&[1.7, 0.3]) * MOL;
+        let volume = (sig * ANGSTROM).powi(3) / reduced_density * NAV * 2.0 * MOL;
+        let s = State::new_nvt(&eos, temperature, volume, &moles).unwrap();
+        let a = s
+            .molar_helmholtz_energy(Contributions::ResidualNvt)
+            .to_reduced(RGAS * temperature)
+            .unwrap();
+        assert_relative_eq!(a, 0.37659379124271003, max_relative = 1e-10);
+        Ok(())
+    }
+
     #[test]
     fn helmholtz_energy_mixtures_bh() -> EosResult<()> {
         // Mixture of equal components --> result must be the same as for pure fluid ///
@@ -293,8 +398,10 @@ mod test {
         // EoS
         let options = UVTheoryOptions {
             max_eta: 0.5,
+            max_density: MaxDensity::Heuristic,
             perturbation: Perturbation::BarkerHenderson,
             virial_order: VirialOrder::Second,
+            combining_rule: CombiningRule::LorentzBerthelot,
         };
 
         let eos_bh = Arc::new(UVTheory::with_options(Arc::new(uv_parameters), options)?);
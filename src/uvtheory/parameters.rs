@@ -1,14 +1,16 @@
 use feos_core::parameter::Identifier;
-use feos_core::parameter::{Parameter, PureRecord};
+use feos_core::parameter::{CombiningRule, Metadata, Parameter, PureRecord};
 use lazy_static::lazy_static;
 use ndarray::concatenate;
 use ndarray::prelude::*;
 use ndarray::Array2;
 use num_dual::DualNum;
+use num_traits::Zero;
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 use std::fmt;
 use std::fmt::Write;
+use std::sync::Arc;
 
 #[derive(Debug, Clone, Serialize, Deserialize, Default)]
 pub struct NoRecord;
@@ -22,10 +24,14 @@ impl fmt::Display for NoRecord {
 /// uv-theory parameters for a pure substance
 #[derive(Debug, Clone, Serialize, Deserialize, Default)]
 pub struct UVRecord {
-    rep: f64,
-    att: f64,
-    sigma: f64,
-    epsilon_k: f64,
+    /// Repulsive Mie exponent
+    pub rep: f64,
+    /// Attractive Mie exponent
+    pub att: f64,
+    /// Mie diameter in units of Angstrom
+    pub sigma: f64,
+    /// Mie energy parameter in units of Kelvin
+    pub epsilon_k: f64,
 }
 
 impl UVRecord {
@@ -53,12 +59,22 @@ impl std::fmt::Display for UVRecord {
 /// Binary interaction parameters
 #[derive(Serialize, Deserialize, Clone, Default, Debug)]
 pub struct UVBinaryRecord {
+    /// Correction to the energy parameter
     pub k_ij: f64,
+    /// Correction to the Mie diameter
+    #[serde(default)]
+    pub l_ij: f64,
+}
+
+impl UVBinaryRecord {
+    pub fn new(k_ij: f64, l_ij: f64) -> Self {
+        Self { k_ij, l_ij }
+    }
 }
 
 impl From<f64> for UVBinaryRecord {
     fn from(k_ij: f64) -> Self {
-        Self { k_ij }
+        Self { k_ij, l_ij: 0.0 }
     }
 }
 
@@ -70,7 +86,11 @@ impl From<UVBinaryRecord> for f64 {
 
 impl std::fmt::Display for UVBinaryRecord {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "UVBinaryRecord(k_ij={})", self.k_ij)
+        write!(f, "UVBinaryRecord(k_ij={}", self.k_ij)?;
+        if !self.l_ij.is_zero() {
+            write!(f, ", l_ij={}", self.l_ij)?;
+        }
+        write!(f, ")")
     }
 }
 
@@ -112,14 +132,18 @@ pub struct UVParameters {
     pub epsilon_k: Array1<f64>,
     pub molarweight: Array1<f64>,
     pub k_ij: Array2<f64>,
+    pub l_ij: Array2<f64>,
     pub rep_ij: Array2<f64>,
     pub att_ij: Array2<f64>,
     pub sigma_ij: Array2<f64>,
     pub eps_k_ij: Array2<f64>,
+    #[serde(default)]
+    pub combining_rule: CombiningRule,
     pub cd_bh_pure: Vec<Array1<f64>>,
     pub cd_bh_binary: Array2<Array1<f64>>,
     pub pure_records: Vec<PureRecord<UVRecord, NoRecord>>,
     pub binary_records: Array2<UVBinaryRecord>,
+    pub binary_metadata: Array2<Option<Metadata>>,
 }
 
 impl Parameter for UVParameters {
@@ -156,6 +180,7 @@ impl Parameter for UVParameters {
         let mut sigma_ij = Array2::zeros((n, n));
         let mut eps_k_ij = Array2::zeros((n, n));
         let k_ij = binary_records.map(|br| br.k_ij);
+        let l_ij = binary_records.map(|br| br.l_ij);
 
         for i in 0..n {
             rep_ij[[i, i]] = rep[i];
@@ -167,7 +192,7 @@ impl Parameter for UVParameters {
                 rep_ij[[j, i]] = rep_ij[[i, j]];
                 att_ij[[i, j]] = (att[i] * att[j]).sqrt();
                 att_ij[[j, i]] = att_ij[[i, j]];
-                sigma_ij[[i, j]] = 0.5 * (sigma[i] + sigma[j]);
+                sigma_ij[[i, j]] = (1.0 - l_ij[[i, j]]) * 0.5 * (sigma[i] + sigma[j]);
                 sigma_ij[[j, i]] = sigma_ij[[i, j]];
                 eps_k_ij[[i, j]] = (1.0 - k_ij[[i, j]]) * (epsilon_k[i] * epsilon_k[j]).sqrt();
                 eps_k_ij[[j, i]] = eps_k_ij[[i, j]];
@@ -187,13 +212,16 @@ impl Parameter for UVParameters {
             epsilon_k,
             molarweight,
             k_ij,
+            l_ij,
             rep_ij,
             att_ij,
             sigma_ij,
             eps_k_ij,
+            combining_rule: CombiningRule::LorentzBerthelot,
             cd_bh_pure,
             cd_bh_binary,
             pure_records,
+            binary_metadata: Array2::from_elem(binary_records.raw_dim(), None),
             binary_records,
         }
     }
@@ -201,6 +229,20 @@ impl Parameter for UVParameters {
     fn records(&self) -> (&[PureRecord<UVRecord, NoRecord>], &Array2<UVBinaryRecord>) {
         (&self.pure_records, &self.binary_records)
     }
+
+    fn from_records_with_metadata(
+        pure_records: Vec<PureRecord<Self::Pure, Self::IdealGas>>,
+        binary_records: Array2<Self::Binary>,
+        binary_metadata: Array2<Option<Metadata>>,
+    ) -> Self {
+        let mut parameters = Self::from_records(pure_records, binary_records);
+        parameters.binary_metadata = binary_metadata;
+        parameters
+    }
+
+    fn binary_metadata(&self) -> Option<&Array2<Option<Metadata>>> {
+        Some(&self.binary_metadata)
+    }
 }
 
 impl UVParameters {
@@ -211,6 +253,32 @@ impl UVParameters {
         Self::new_pure(pure_record)
     }
 
+    /// Replace the combining rule for the cross diameters and energy parameters.
+    ///
+    /// The binary interaction parameters `k_ij` and `l_ij` are applied
+    /// as corrections to the combined parameters.
+    pub fn with_combining_rule(&self, combining_rule: CombiningRule) -> Self {
+        let (sigma_ij, eps_k_ij) = combining_rule.cross_parameters(&self.sigma, &self.epsilon_k);
+        Self {
+            sigma_ij: (1.0 - &self.l_ij) * sigma_ij,
+            eps_k_ij: (1.0 - &self.k_ij) * eps_k_ij,
+            combining_rule,
+            ..self.clone()
+        }
+    }
+
+    /// Rebuild shared parameters if they do not use the given combining rule.
+    pub(crate) fn apply_combining_rule(
+        parameters: Arc<Self>,
+        combining_rule: CombiningRule,
+    ) -> Arc<Self> {
+        if parameters.combining_rule == combining_rule {
+            parameters
+        } else {
+            Arc::new(parameters.with_combining_rule(combining_rule))
+        }
+    }
+
     /// Markdown representation of parameters.
     pub fn to_markdown(&self) -> String {
         let mut output = String::new();
@@ -1,7 +1,8 @@
 use super::parameters::{NoRecord, UVBinaryRecord, UVParameters, UVRecord};
 use super::{Perturbation, VirialOrder};
 use feos_core::parameter::{
-    BinaryRecord, Identifier, IdentifierOption, Parameter, ParameterError, PureRecord,
+    BinaryRecord, CombiningRule, Identifier, IdentifierOption, Parameter, ParameterError,
+    PureRecord,
 };
 use feos_core::python::parameter::*;
 use feos_core::*;
@@ -30,6 +31,26 @@ impl PyUVRecord {
         Self(UVRecord::new(rep, att, sigma, epsilon_k))
     }
 
+    #[getter]
+    fn get_rep(&self) -> f64 {
+        self.0.rep
+    }
+
+    #[getter]
+    fn get_att(&self) -> f64 {
+        self.0.att
+    }
+
+    #[getter]
+    fn get_sigma(&self) -> f64 {
+        self.0.sigma
+    }
+
+    #[getter]
+    fn get_epsilon_k(&self) -> f64 {
+        self.0.epsilon_k
+    }
+
     fn __repr__(&self) -> PyResult<String> {
         Ok(self.0.to_string())
     }
@@ -37,11 +58,59 @@ impl PyUVRecord {
 
 impl_json_handling!(PyUVRecord);
 
+/// Create a binary record for uv-theory.
+///
+/// Parameters
+/// ----------
+/// k_ij : float, optional
+///     Correction to the energy parameter (default: 0).
+/// l_ij : float, optional
+///     Correction to the Mie diameter (default: 0).
+///
+/// Returns
+/// -------
+/// UVBinaryRecord
 #[pyclass(name = "UVBinaryRecord")]
+#[pyo3(text_signature = "(k_ij=None, l_ij=None)")]
 #[derive(Clone)]
 pub struct PyUVBinaryRecord(UVBinaryRecord);
 impl_binary_record!(UVBinaryRecord, PyUVBinaryRecord);
 
+#[pymethods]
+impl PyUVBinaryRecord {
+    #[new]
+    fn new(k_ij: Option<f64>, l_ij: Option<f64>) -> Self {
+        Self(UVBinaryRecord::new(
+            k_ij.unwrap_or(0.0),
+            l_ij.unwrap_or(0.0),
+        ))
+    }
+
+    #[getter]
+    fn get_k_ij(&self) -> f64 {
+        self.0.k_ij
+    }
+
+    #[getter]
+    fn get_l_ij(&self) -> f64 {
+        self.0.l_ij
+    }
+
+    #[setter]
+    fn set_k_ij(&mut self, k_ij: f64) {
+        self.0.k_ij = k_ij
+    }
+
+    #[setter]
+    fn set_l_ij(&mut self, l_ij: f64) {
+        self.0.l_ij = l_ij
+    }
+
+    fn __repr__(&self) -> PyResult<String> {
+        Ok(self.0.to_string())
+    }
+}
+
 /// Create a set of UV Theory parameters from records.
 ///
 /// Parameters
@@ -75,13 +144,24 @@ impl PyUVParameters {
     ///     Mie diameter in units of Angstrom
     /// epsilon_k : List[float]
     ///     Mie energy parameter in units of Kelvin
+    /// molarweight : List[float], optional
+    ///     molar weights in units of g/mol (default: 1)
+    /// k_ij : numpy.ndarray[float], optional
+    ///     matrix of binary interaction parameters for the energy parameter
     ///
     /// Returns
     /// -------
     /// UVParameters
-    #[pyo3(text_signature = "(rep, att, sigma, epsilon_k)")]
+    #[pyo3(text_signature = "(rep, att, sigma, epsilon_k, molarweight=None, k_ij=None)")]
     #[staticmethod]
-    fn from_lists(rep: Vec<f64>, att: Vec<f64>, sigma: Vec<f64>, epsilon_k: Vec<f64>) -> Self {
+    fn from_lists(
+        rep: Vec<f64>,
+        att: Vec<f64>,
+        sigma: Vec<f64>,
+        epsilon_k: Vec<f64>,
+        molarweight: Option<Vec<f64>>,
+        k_ij: Option<&PyArray2<f64>>,
+    ) -> Self {
         let n = rep.len();
         let pure_records = (0..n)
             .map(|i| {
@@ -94,10 +174,14 @@ impl PyUVParameters {
                     None,
                 );
                 let model_record = UVRecord::new(rep[i], att[i], sigma[i], epsilon_k[i]);
-                PureRecord::new(identifier, 1.0, model_record, None)
+                let mw = molarweight.as_ref().map_or(1.0, |mw| mw[i]);
+                PureRecord::new(identifier, mw, model_record, None)
             })
             .collect();
-        let binary = Array2::from_shape_fn((n, n), |(_, _)| UVBinaryRecord { k_ij: 0.0 });
+        let binary = match k_ij {
+            Some(k_ij) => k_ij.to_owned_array().mapv(UVBinaryRecord::from),
+            None => Array2::from_elem((n, n), UVBinaryRecord::default()),
+        };
         Self(Arc::new(UVParameters::from_records(pure_records, binary)))
     }
 
@@ -136,7 +220,9 @@ impl_parameter!(UVParameters, PyUVParameters);
 #[pymodule]
 pub fn uvtheory(_py: Python<'_>, m: &PyModule) -> PyResult<()> {
     m.add_class::<PyIdentifier>()?;
+    m.add_class::<PyMetadata>()?;
     m.add_class::<IdentifierOption>()?;
+    m.add_class::<CombiningRule>()?;
     m.add_class::<PyChemicalRecord>()?;
 
     m.add_class::<Perturbation>()?;
@@ -144,6 +230,7 @@ pub fn uvtheory(_py: Python<'_>, m: &PyModule) -> PyResult<()> {
     m.add_class::<PyUVRecord>()?;
     m.add_class::<PyPureRecord>()?;
     m.add_class::<PyBinaryRecord>()?;
+    m.add_class::<PyUVBinaryRecord>()?;
     m.add_class::<PyUVParameters>()?;
     Ok(())
 }
@@ -288,7 +288,72 @@ fn test_dft_newton() -> Result<(), Box<dyn Error>> {
     let vle = PhaseEquilibrium::pure(&func, t, None, Default::default())?;
     let solver = DFTSolver::new(Some(Verbosity::Iter))
         .picard_iteration(None, Some(10), None, None)
-        .newton(None, None, None, None);
+        .newton(None, None, None, None, None);
     PlanarInterface::from_tanh(&vle, points, w, tc, false)?.solve(Some(&solver))?;
     Ok(())
 }
+
+#[test]
+fn test_bulk_rings() -> Result<(), Box<dyn Error>> {
+    let eos_parameters = GcPcSaftEosParameters::from_json_segments(
+        &["methylcyclohexane"],
+        "parameters/pcsaft/gc_substances.json",
+        "parameters/pcsaft/sauer2014_hetero.json",
+        None,
+        IdentifierOption::Name,
+    )?;
+    let func_parameters = GcPcSaftFunctionalParameters::from_json_segments(
+        &["methylcyclohexane"],
+        "parameters/pcsaft/gc_substances.json",
+        "parameters/pcsaft/sauer2014_hetero.json",
+        None,
+        IdentifierOption::Name,
+    )?;
+    assert_eq!(func_parameters.ring_closures, vec![[5, 0]]);
+    let eos = Arc::new(GcPcSaft::new(Arc::new(eos_parameters)));
+    let func = Arc::new(GcPcSaftFunctional::new(Arc::new(func_parameters)));
+
+    let t = 300.0 * KELVIN;
+    let v = 0.002 * METER.powi(3);
+    let n = arr1(&[1.5]) * MOL;
+    let state_eos = State::new_nvt(&eos, t, v, &n)?;
+    let state_func = State::new_nvt(&func, t, v, &n)?;
+    let p_eos = state_eos.pressure_contributions();
+    let p_func = state_func.pressure_contributions();
+    for (eos, func) in p_eos.iter().zip(p_func.iter()) {
+        assert_relative_eq!(eos.1, func.1, max_relative = 1e-14);
+    }
+    Ok(())
+}
+
+#[test]
+fn test_dft_rings() -> Result<(), Box<dyn Error>> {
+    use feos_core::Contributions;
+    use feos_dft::HelmholtzEnergyFunctional;
+
+    let parameters = GcPcSaftFunctionalParameters::from_json_segments(
+        &["cyclohexane"],
+        "parameters/pcsaft/gc_substances.json",
+        "parameters/pcsaft/sauer2014_hetero.json",
+        None,
+        IdentifierOption::Name,
+    )?;
+    let func = Arc::new(GcPcSaftFunctional::new(Arc::new(parameters)));
+    assert_eq!(func.parameters.bonds.edge_count(), 6);
+    assert_eq!(func.bond_lengths(300.0).edge_count(), 5);
+
+    // the grand potential density in the bulk phases has to be consistent
+    // with the pressure, which requires the ring closure to be excluded
+    // from the ideal chain contribution
+    let t = 300.0 * KELVIN;
+    let vle = PhaseEquilibrium::pure(&func, t, None, Default::default())?;
+    let interface =
+        PlanarInterface::from_tanh(&vle, 1024, 100.0 * ANGSTROM, 600.0 * KELVIN, false)?
+            .solve(None)?;
+    let omega = interface.profile.grand_potential_density()?;
+    let p = vle.vapor().pressure(Contributions::Total);
+    assert_relative_eq!(omega.get(0), -p, max_relative = 1e-5);
+    assert_relative_eq!(omega.get(omega.len() - 1), -p, max_relative = 1e-5);
+    assert!(interface.surface_tension.unwrap() > 0.0 * NEWTON / METER);
+    Ok(())
+}
@@ -0,0 +1,59 @@
+use approx::assert_relative_eq;
+use feos::pcsaft::{PcSaft, PcSaftParameters};
+use feos_core::parameter::{IdentifierOption, Parameter};
+use feos_core::{Contributions, PhaseDiagram, PhaseEquilibrium, SolverOptions};
+use ndarray::arr1;
+use quantity::si::*;
+use std::error::Error;
+use std::sync::Arc;
+
+#[test]
+fn test_azeotrope() -> Result<(), Box<dyn Error>> {
+    // a pseudo-binary mixture of propane with strong positive deviations
+    let propane = PcSaftParameters::from_json(
+        vec!["propane"],
+        "tests/pcsaft/test_parameters.json",
+        None,
+        IdentifierOption::Name,
+    )?
+    .pure_records[0]
+        .clone();
+    let mut component = propane.clone();
+    component.model_record.epsilon_k *= 1.01;
+    let e = Arc::new(PcSaft::new(Arc::new(PcSaftParameters::new_binary(
+        vec![propane, component],
+        Some(0.1.into()),
+    ))));
+    let t = 250.0 * KELVIN;
+    let options = (SolverOptions::default(), SolverOptions::default());
+
+    let azeotrope = PhaseEquilibrium::azeotrope(&e, t, None, SolverOptions::default(), options)?;
+    assert_relative_eq!(
+        azeotrope.liquid().molefracs,
+        azeotrope.vapor().molefracs,
+        epsilon = 1e-8
+    );
+    let p_az = azeotrope.vapor().pressure(Contributions::Total);
+    for vle in PhaseEquilibrium::vle_pure_comps(&e, t).iter().flatten() {
+        assert!(vle.vapor().pressure(Contributions::Total) < p_az);
+    }
+
+    let diagram = PhaseDiagram::binary_vle(&e, t, Some(21), None, options)?;
+    let azeotropes = diagram.azeotropes();
+    assert_eq!(azeotropes.len(), 1);
+    assert_relative_eq!(
+        azeotropes[0].liquid().molefracs,
+        azeotrope.liquid().molefracs,
+        epsilon = 1e-6
+    );
+
+    let temperatures = SIArray1::linspace(240.0 * KELVIN, 260.0 * KELVIN, 3)?;
+    let line = PhaseDiagram::azeotrope_line(&e, &temperatures, SolverOptions::default(), options)?;
+    assert_eq!(line.states.len(), 3);
+    assert_relative_eq!(
+        line.states[1].liquid().molefracs,
+        azeotrope.liquid().molefracs,
+        epsilon = 1e-6
+    );
+    Ok(())
+}
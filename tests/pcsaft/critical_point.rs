@@ -47,3 +47,26 @@ fn test_critical_point_mix() -> Result<(), Box<dyn Error>> {
     );
     Ok(())
 }
+
+#[test]
+fn test_critical_point_global() -> Result<(), Box<dyn Error>> {
+    let params = PcSaftParameters::from_json(
+        vec!["propane", "butane"],
+        "tests/pcsaft/test_parameters.json",
+        None,
+        IdentifierOption::Name,
+    )?;
+    let saft = Arc::new(PcSaft::new(Arc::new(params)));
+    let moles = arr1(&[1.5, 1.5]) * MOL;
+    for homotopy in [false, true] {
+        let cp = State::critical_point_global(&saft, Some(&moles), homotopy, Default::default())?;
+        assert_relative_eq!(cp.temperature, 407.93481 * KELVIN, max_relative = 1e-8);
+        assert_relative_eq!(
+            cp.density,
+            4265.50745 * MOL / METER.powi(3),
+            max_relative = 1e-6
+        );
+        assert!(cp.solver_log().unwrap().converged);
+    }
+    Ok(())
+}
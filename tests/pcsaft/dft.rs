@@ -5,8 +5,11 @@ use feos::hard_sphere::FMTVersion;
 use feos::pcsaft::{PcSaft, PcSaftFunctional, PcSaftParameters};
 use feos_core::parameter::{IdentifierOption, Parameter};
 use feos_core::{Contributions, PhaseEquilibrium, State, Verbosity};
-use feos_dft::interface::PlanarInterface;
-use feos_dft::DFTSolver;
+use feos_dft::adsorption::{
+    FixedBed, IdealAdsorbedSolution, LangmuirIsotherm, PureComponentIsotherm, TabulatedIsotherm,
+};
+use feos_dft::interface::{ParametricFunctional, PlanarInterface};
+use feos_dft::{DFTSolver, DFT};
 use ndarray::{arr1, Axis};
 use quantity::si::*;
 use std::error::Error;
@@ -87,6 +90,53 @@ fn test_bulk_implementations() -> Result<(), Box<dyn Error>> {
     Ok(())
 }
 
+#[test]
+fn test_tensor_fmt() -> Result<(), Box<dyn Error>> {
+    let params = Arc::new(PcSaftParameters::from_json(
+        vec!["propane"],
+        "tests/pcsaft/test_parameters.json",
+        None,
+        IdentifierOption::Name,
+    )?);
+    let eos = Arc::new(PcSaft::new(params.clone()));
+    let func_wb = Arc::new(PcSaftFunctional::new_full(
+        params.clone(),
+        FMTVersion::WhiteBear,
+    ));
+    let func_tensor = Arc::new(PcSaftFunctional::new_full(
+        params,
+        FMTVersion::TensorWhiteBear,
+    ));
+
+    // the tensorial weighted density reduces to the bulk
+    let t = 200.0 * KELVIN;
+    let v = 0.002 * METER.powi(3);
+    let n = arr1(&[1.5]) * MOL;
+    let state = State::new_nvt(&eos, t, v, &n)?;
+    let state_tensor = State::new_nvt(&func_tensor, t, v, &n)?;
+    assert_relative_eq!(
+        state.pressure(Contributions::Total),
+        state_tensor.pressure(Contributions::Total),
+        max_relative = 1e-12,
+    );
+
+    // vapor-liquid interfaces are hardly affected by the tensorial weighted density
+    let w = 150.0 * ANGSTROM;
+    let points = 2048;
+    let tc = State::critical_point(&func_wb, None, None, Default::default())?.temperature;
+    let vle_wb = PhaseEquilibrium::pure(&func_wb, t, None, Default::default())?;
+    let vle_tensor = PhaseEquilibrium::pure(&func_tensor, t, None, Default::default())?;
+    let profile_wb = PlanarInterface::from_tanh(&vle_wb, points, w, tc, false)?.solve(None)?;
+    let profile_tensor =
+        PlanarInterface::from_tanh(&vle_tensor, points, w, tc, false)?.solve(None)?;
+    assert_relative_eq!(
+        profile_wb.surface_tension.unwrap(),
+        profile_tensor.surface_tension.unwrap(),
+        max_relative = 1e-2,
+    );
+    Ok(())
+}
+
 #[test]
 #[allow(non_snake_case)]
 fn test_dft_propane() -> Result<(), Box<dyn Error>> {
@@ -227,13 +277,49 @@ fn test_dft_propane_newton() -> Result<(), Box<dyn Error>> {
     let points = 512;
     let tc = State::critical_point(&func, None, None, Default::default())?.temperature;
     let vle = PhaseEquilibrium::pure(&func, t, None, Default::default())?;
-    let solver = DFTSolver::new(Some(Verbosity::Iter)).newton(None, None, None, None);
+    let solver = DFTSolver::new(Some(Verbosity::Iter)).newton(None, None, None, None, None);
     PlanarInterface::from_tanh(&vle, points, w, tc, false)?.solve(Some(&solver))?;
-    let solver = DFTSolver::new(Some(Verbosity::Iter)).newton(Some(true), None, None, None);
+    let solver = DFTSolver::new(Some(Verbosity::Iter)).newton(Some(true), None, None, None, None);
     PlanarInterface::from_tanh(&vle, points, w, tc, false)?.solve(Some(&solver))?;
     Ok(())
 }
 
+#[test]
+fn test_dft_propane_picard_newton() -> Result<(), Box<dyn Error>> {
+    let params = Arc::new(PcSaftParameters::from_json(
+        vec!["propane"],
+        "tests/pcsaft/test_parameters.json",
+        None,
+        IdentifierOption::Name,
+    )?);
+    let func = Arc::new(PcSaftFunctional::new(params));
+    let t = 200.0 * KELVIN;
+    let w = 150.0 * ANGSTROM;
+    let points = 512;
+    let tc = State::critical_point(&func, None, None, Default::default())?.temperature;
+    let vle = PhaseEquilibrium::pure(&func, t, None, Default::default())?;
+    let interface = PlanarInterface::from_tanh(&vle, points, w, tc, false)?.solve(None)?;
+    let solver = DFTSolver::new(Some(Verbosity::Iter)).picard_newton(
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(true),
+    );
+    let interface_newton =
+        PlanarInterface::from_tanh(&vle, points, w, tc, false)?.solve(Some(&solver))?;
+    assert_relative_eq!(
+        interface.surface_tension.unwrap(),
+        interface_newton.surface_tension.unwrap(),
+        max_relative = 1e-8
+    );
+    let log = interface_newton.profile.solver_log.unwrap();
+    assert!(log.solver().contains(&"Picard iteration"));
+    assert!(log.solver().contains(&"Newton"));
+    Ok(())
+}
+
 #[test]
 #[allow(non_snake_case)]
 fn test_dft_water() -> Result<(), Box<dyn Error>> {
@@ -379,3 +465,1041 @@ fn test_entropy_bulk_values() -> Result<(), Box<dyn Error>> {
     );
     Ok(())
 }
+
+#[test]
+fn test_surface_tension_decomposition() -> Result<(), Box<dyn Error>> {
+    let params = Arc::new(PcSaftParameters::from_json(
+        vec!["propane"],
+        "tests/pcsaft/test_parameters.json",
+        None,
+        IdentifierOption::Name,
+    )?);
+    let func = Arc::new(PcSaftFunctional::new(params));
+    let t = 200.0 * KELVIN;
+    let w = 150.0 * ANGSTROM;
+    let points = 2048;
+    let tc = State::critical_point(&func, None, None, Default::default())?.temperature;
+    let vle = PhaseEquilibrium::pure(&func, t, None, Default::default())?;
+    let interface = PlanarInterface::from_tanh(&vle, points, w, tc, false)?.solve(None)?;
+    let surface_tension = interface.surface_tension.unwrap();
+
+    // the contributions are integrated independently of the grand potential
+    let (energy, entropy) = interface.surface_tension_energy_entropy()?;
+    assert_relative_eq!(energy + entropy, surface_tension, max_relative = 1e-6);
+    assert!(energy > surface_tension);
+    assert!(entropy < 0.0 * NEWTON / METER);
+
+    // the chemical potential term vanishes at the equimolar surface of a pure component
+    let contributions = interface.surface_tension_contributions()?;
+    let (_, chemical_potential, _) = contributions.last().unwrap();
+    assert!(chemical_potential.to_reduced(surface_tension)?.abs() < 1e-3);
+    Ok(())
+}
+
+#[test]
+fn test_interface_thermodynamic_properties() -> Result<(), Box<dyn Error>> {
+    let params = Arc::new(PcSaftParameters::from_json(
+        vec!["propane"],
+        "tests/pcsaft/test_parameters.json",
+        None,
+        IdentifierOption::Name,
+    )?);
+    let func = Arc::new(PcSaftFunctional::new(params));
+    let t = 200.0 * KELVIN;
+    let w = 150.0 * ANGSTROM;
+    let points = 2048;
+    let tc = State::critical_point(&func, None, None, Default::default())?.temperature;
+    let interface = |t: SINumber| -> Result<_, Box<dyn Error>> {
+        let vle = PhaseEquilibrium::pure(&func, t, None, Default::default())?;
+        Ok(PlanarInterface::from_tanh(&vle, points, w, tc, false)?.solve(None)?)
+    };
+    let profile = interface(t)?;
+    let h = profile.enthalpy_density()?;
+    let s_i = profile.partial_molar_entropy()?;
+    let h_i = profile.partial_molar_enthalpy()?;
+
+    // bulk limits on both ends of the interface
+    for (phase, z) in [(profile.vle.liquid(), 0), (profile.vle.vapor(), points - 1)] {
+        assert_relative_eq!(
+            h.get(z),
+            phase.enthalpy(Contributions::Total) / phase.volume,
+            max_relative = 1e-7,
+        );
+        let mu = phase.chemical_potential(Contributions::Total);
+        let s_bulk = phase.partial_molar_entropy(Contributions::Total)
+            - phase.partial_molar_volume(Contributions::Total) * phase.dp_dt(Contributions::Total);
+        assert_relative_eq!(s_i.get((0, z)), s_bulk.get(0), max_relative = 1e-7);
+        assert_relative_eq!(
+            h_i.get((0, z)),
+            mu.get(0) + t * s_i.get((0, z)),
+            max_relative = 1e-7,
+        );
+    }
+
+    // the surface excess entropy and enthalpy at the equimolar surface
+    let s_sigma = profile.interfacial_excess_entropy()?;
+    let h_sigma = profile.interfacial_excess_enthalpy()?;
+    assert_relative_eq!(h_sigma, t * s_sigma, max_relative = 1e-8);
+    let (_, entropy) = profile.surface_tension_energy_entropy()?;
+    assert_relative_eq!(entropy, -t * s_sigma, max_relative = 1e-8);
+
+    // Gibbs adsorption equation for a pure component: s^sigma = -dgamma/dT
+    let dt = 0.1 * KELVIN;
+    let gamma_p = interface(t + dt)?.surface_tension.unwrap();
+    let gamma_m = interface(t - dt)?.surface_tension.unwrap();
+    assert_relative_eq!(
+        s_sigma,
+        -(gamma_p - gamma_m) / (2.0 * dt),
+        max_relative = 1e-3
+    );
+    Ok(())
+}
+
+#[test]
+fn test_surface_tension_sensitivities() -> Result<(), Box<dyn Error>> {
+    let params = Arc::new(PcSaftParameters::from_json(
+        vec!["propane"],
+        "tests/pcsaft/test_parameters.json",
+        None,
+        IdentifierOption::Name,
+    )?);
+    let func = Arc::new(PcSaftFunctional::new(params));
+    let t = 200.0 * KELVIN;
+    let w = 150.0 * ANGSTROM;
+    let points = 2048;
+    let tc = State::critical_point(&func, None, None, Default::default())?.temperature;
+    let interface = |func: &Arc<DFT<PcSaftFunctional>>| -> Result<_, Box<dyn Error>> {
+        let vle = PhaseEquilibrium::pure(func, t, None, Default::default())?;
+        Ok(PlanarInterface::from_tanh(&vle, points, w, tc, false)?.solve(None)?)
+    };
+    let sensitivities = interface(&func)?.surface_tension_sensitivities()?;
+    assert_eq!(func.parameter_names(), ["m_0", "sigma_0", "epsilon_k_0"]);
+
+    // central differences of the surface tensions of the solved interfaces
+    let parameters = func.parameter_values();
+    for (k, &theta) in parameters.iter().enumerate() {
+        let h = 1e-4 * theta;
+        let mut p = parameters.to_vec();
+        p[k] = theta + h;
+        let gamma_p = interface(&Arc::new(func.with_parameter_values(&p)?))?.surface_tension;
+        p[k] = theta - h;
+        let gamma_m = interface(&Arc::new(func.with_parameter_values(&p)?))?.surface_tension;
+        assert_relative_eq!(
+            sensitivities.get(k),
+            (gamma_p.unwrap() - gamma_m.unwrap()) / (2.0 * h),
+            max_relative = 1e-3
+        );
+    }
+    Ok(())
+}
+
+#[test]
+fn test_iast_langmuir() -> Result<(), Box<dyn Error>> {
+    // for equal saturation capacities, IAST reduces to the extended Langmuir isotherm
+    let n_s = 5.0 * MOL / KILOGRAM;
+    let b = [1.0 / BAR, 0.1 / BAR];
+    let iast = IdealAdsorbedSolution::new(vec![
+        Arc::new(LangmuirIsotherm::new(n_s, b[0])),
+        Arc::new(LangmuirIsotherm::new(n_s, b[1])),
+    ]);
+    let p = 2.0 * BAR;
+    let y = arr1(&[0.3, 0.7]);
+    let n = iast.adsorption(p, &y, Default::default())?;
+    let denominator = 1.0 + (b[0] * p * y[0] + b[1] * p * y[1]).into_value()?;
+    for i in 0..2 {
+        assert_relative_eq!(
+            n.get(i),
+            n_s * (b[i] * p * y[i]).into_value()? / denominator,
+            max_relative = 1e-8
+        );
+    }
+
+    // tabulated isotherms reproduce the results of the analytic isotherms
+    let pressure = SIArray1::linspace(0.01 * BAR, 100.0 * BAR, 10000)?;
+    let isotherms: Vec<Arc<dyn PureComponentIsotherm>> = b
+        .iter()
+        .map(|&b| {
+            let isotherm = LangmuirIsotherm::new(n_s, b);
+            let adsorption = pressure
+                .into_iter()
+                .map(|p| isotherm.adsorption(p).unwrap())
+                .collect();
+            Arc::new(TabulatedIsotherm::new(pressure.clone(), adsorption).unwrap()) as _
+        })
+        .collect();
+    let n_tab = IdealAdsorbedSolution::new(isotherms).adsorption(p, &y, Default::default())?;
+    for i in 0..2 {
+        assert_relative_eq!(n_tab.get(i), n.get(i), max_relative = 1e-4);
+    }
+    Ok(())
+}
+
+#[test]
+fn test_breakthrough_curve() -> Result<(), Box<dyn Error>> {
+    let temperature = 300.0 * KELVIN;
+    let isotherm = LangmuirIsotherm::new(3.0 * MOL / KILOGRAM, 1.0 / BAR);
+    let langmuir = IdealAdsorbedSolution::new(vec![Arc::new(isotherm)]);
+    let length = 0.1 * METER;
+    let porosity = 0.4;
+    let bed_density = 500.0 * KILOGRAM / METER.powi(3);
+    let velocity = 0.1 * METER / SECOND;
+    let bed = FixedBed::new(
+        langmuir,
+        temperature,
+        length,
+        porosity,
+        bed_density,
+        velocity,
+        1e-5 * METER.powi(2) / SECOND,
+        arr1(&[0.1]) / SECOND,
+    )?;
+    let pressure = BAR;
+    let y = arr1(&[0.1]);
+    let curve =
+        bed.breakthrough_curve(pressure, &y, 300.0 * SECOND, 20, 301, Default::default())?;
+
+    // the mass balance of the column determines the stoichiometric time
+    let c_feed = curve.feed_concentration.get(0);
+    let q_feed = isotherm.adsorption(pressure * y[0])?;
+    let t_stoichiometric = (length / velocity
+        * (1.0 + (bed_density * q_feed / (porosity * c_feed)).into_value()?))
+    .to_reduced(SECOND)?;
+    let relative = curve.relative_concentration()?;
+    let t_mass_balance: f64 = relative
+        .row(0)
+        .windows(2)
+        .into_iter()
+        .map(|c| 0.5 * (2.0 - c[0] - c[1]))
+        .sum();
+    assert_relative_eq!(t_mass_balance, t_stoichiometric, max_relative = 1e-2);
+
+    let t_breakthrough = curve
+        .breakthrough_time(0, 0.5)?
+        .unwrap()
+        .to_reduced(SECOND)?;
+    assert_relative_eq!(t_breakthrough, t_stoichiometric, max_relative = 1e-1);
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "rayon")]
+fn test_pore3d_from_cif() -> Result<(), Box<dyn Error>> {
+    use feos_dft::adsorption::{Pore3D, PoreSpecification};
+    use ndarray::arr2;
+    use std::collections::HashMap;
+
+    let cif = "data_test
+_cell_length_a 10.0(1)
+_cell_length_b 10.0
+_cell_length_c 12.0
+_cell_angle_alpha 90
+_cell_angle_beta 90
+_cell_angle_gamma 90.00
+loop_
+_symmetry_equiv_pos_as_xyz
+'x, y, z'
+'x+1/2, y+1/2, z+1/2'
+loop_
+_atom_site_label
+_atom_site_type_symbol
+_atom_site_fract_x
+_atom_site_fract_y
+_atom_site_fract_z
+C1 C 0.0 0.0 0.0
+C2 C 0.5 0.5 0.5 # equivalent to C1
+O1 O 0.25 0.0 0.0
+";
+    let file = std::env::temp_dir().join("feos_test_pore3d.cif");
+    std::fs::write(&file, cif)?;
+    let force_field = HashMap::from([
+        (String::from("C"), (3.4, 28.0)),
+        (String::from("O"), (3.0, 60.0)),
+    ]);
+    let pore_cif = Pore3D::from_cif(&file, &force_field, [10, 10, 12], None, None)?;
+    std::fs::remove_file(&file)?;
+
+    let coordinates = arr2(&[
+        [0.0, 5.0, 2.5, 7.5],
+        [0.0, 5.0, 0.0, 5.0],
+        [0.0, 6.0, 0.0, 6.0],
+    ]) * ANGSTROM;
+    let pore = Pore3D::new(
+        [10.0 * ANGSTROM, 10.0 * ANGSTROM, 12.0 * ANGSTROM],
+        [10, 10, 12],
+        coordinates,
+        arr1(&[3.4, 3.4, 3.0, 3.0]),
+        arr1(&[28.0, 28.0, 60.0, 60.0]),
+        None,
+        None,
+    );
+    assert_relative_eq!(
+        pore_cif.pore_volume()?,
+        pore.pore_volume()?,
+        max_relative = 1e-10
+    );
+    Ok(())
+}
+
+#[test]
+fn test_enriched_initialization() -> Result<(), Box<dyn Error>> {
+    let params = Arc::new(PcSaftParameters::from_json(
+        vec!["propane", "hexane"],
+        "tests/pcsaft/test_parameters.json",
+        None,
+        IdentifierOption::Name,
+    )?);
+    let func = Arc::new(PcSaftFunctional::new(params));
+    let t = 300.0 * KELVIN;
+    let w = 150.0 * ANGSTROM;
+    let points = 2048;
+    let tc = 450.0 * KELVIN;
+    let vle = PhaseEquilibrium::bubble_point(
+        &func,
+        t,
+        &arr1(&[0.3, 0.7]),
+        None,
+        None,
+        Default::default(),
+    )?;
+    let interface = PlanarInterface::from_tanh(&vle, points, w, tc, false)?.solve(None)?;
+    let interface_enriched =
+        PlanarInterface::from_tanh_enriched(&vle, points, w, tc, false)?.solve(None)?;
+    assert_relative_eq!(
+        interface.surface_tension.unwrap(),
+        interface_enriched.surface_tension.unwrap(),
+        max_relative = 1e-6
+    );
+    Ok(())
+}
+
+#[test]
+fn test_adsorption_composition_derivative() -> Result<(), Box<dyn Error>> {
+    use feos_core::StateBuilder;
+    use feos_dft::adsorption::{ExternalPotential, Pore1D, PoreSpecification};
+    use feos_dft::Geometry;
+
+    let params = Arc::new(PcSaftParameters::from_json(
+        vec!["propane", "butane"],
+        "tests/pcsaft/test_parameters.json",
+        None,
+        IdentifierOption::Name,
+    )?);
+    let func = Arc::new(PcSaftFunctional::new(params));
+    let pore = Pore1D::new(
+        Geometry::Cartesian,
+        20.0 * ANGSTROM,
+        ExternalPotential::LJ93 {
+            sigma_ss: 3.4,
+            epsilon_k_ss: 28.0,
+            rho_s: 0.08,
+        },
+        None,
+        None,
+    );
+    let t = 300.0 * KELVIN;
+    let p = 0.2 * BAR;
+    let moles = |x: &[f64]| -> Result<SIArray1, Box<dyn Error>> {
+        let bulk = StateBuilder::new(&func)
+            .temperature(t)
+            .pressure(p)
+            .molefracs(&arr1(x))
+            .vapor()
+            .build()?;
+        Ok(pore
+            .initialize(&bulk, None, None)?
+            .solve(None)?
+            .profile
+            .moles())
+    };
+
+    let bulk = StateBuilder::new(&func)
+        .temperature(t)
+        .pressure(p)
+        .molefracs(&arr1(&[0.4, 0.6]))
+        .vapor()
+        .build()?;
+    let dn_dx = pore
+        .initialize(&bulk, None, None)?
+        .solve(None)?
+        .profile
+        .dn_dx()?;
+
+    // central differences with a change in composition that sums up to 0
+    let h = 1e-4;
+    let dn = (moles(&[0.4 + h, 0.6 - h])? - moles(&[0.4 - h, 0.6 + h])?) / (2.0 * h);
+    for i in 0..2 {
+        assert_relative_eq!(
+            dn.get(i),
+            dn_dx.get((i, 0)) - dn_dx.get((i, 1)),
+            max_relative = 1e-4
+        );
+    }
+    Ok(())
+}
+
+#[test]
+fn test_isosteric_heat() -> Result<(), Box<dyn Error>> {
+    use feos_core::StateBuilder;
+    use feos_dft::adsorption::{ExternalPotential, Pore1D, PoreSpecification};
+    use feos_dft::Geometry;
+
+    let params = Arc::new(PcSaftParameters::from_json(
+        vec!["propane"],
+        "tests/pcsaft/test_parameters.json",
+        None,
+        IdentifierOption::Name,
+    )?);
+    let func = Arc::new(PcSaftFunctional::new(params));
+    let pore = Pore1D::new(
+        Geometry::Cartesian,
+        20.0 * ANGSTROM,
+        ExternalPotential::LJ93 {
+            sigma_ss: 3.4,
+            epsilon_k_ss: 28.0,
+            rho_s: 0.08,
+        },
+        None,
+        None,
+    );
+    let t = 300.0 * KELVIN;
+    let p = 0.2 * BAR;
+    let moles = |t, p| -> Result<SINumber, Box<dyn Error>> {
+        let bulk = StateBuilder::new(&func)
+            .temperature(t)
+            .pressure(p)
+            .vapor()
+            .build()?;
+        Ok(pore
+            .initialize(&bulk, None, None)?
+            .solve(None)?
+            .profile
+            .total_moles())
+    };
+
+    let bulk = StateBuilder::new(&func)
+        .temperature(t)
+        .pressure(p)
+        .vapor()
+        .build()?;
+    let q_st = pore
+        .initialize(&bulk, None, None)?
+        .solve(None)?
+        .isosteric_heat()?;
+
+    // central differences at constant pressure and temperature, respectively
+    let dn_dt = (moles(t + 1e-3 * KELVIN, p)? - moles(t - 1e-3 * KELVIN, p)?) / (2e-3 * KELVIN);
+    let dn_dp = (moles(t, p * 1.0001)? - moles(t, p * 0.9999)?) / (2e-4 * p);
+    assert_relative_eq!(
+        q_st.get(0),
+        -RGAS * t * t * dn_dt / (p * dn_dp),
+        max_relative = 1e-4
+    );
+    Ok(())
+}
+
+#[test]
+fn test_tabulated_external_potential() -> Result<(), Box<dyn Error>> {
+    use feos_core::StateBuilder;
+    use feos_dft::adsorption::{ExternalPotential, Pore1D, PoreSpecification};
+    use feos_dft::Geometry;
+    use ndarray::{Array1, Array2};
+
+    let params = Arc::new(PcSaftParameters::from_json(
+        vec!["propane"],
+        "tests/pcsaft/test_parameters.json",
+        None,
+        IdentifierOption::Name,
+    )?);
+    let func = Arc::new(PcSaftFunctional::new(params));
+    let t = 300.0 * KELVIN;
+    let bulk = StateBuilder::new(&func)
+        .temperature(t)
+        .pressure(BAR)
+        .build()?;
+
+    // tabulate the LJ93 potential on a fine grid
+    let lj93 = ExternalPotential::LJ93 {
+        sigma_ss: 3.4,
+        epsilon_k_ss: 28.0,
+        rho_s: 0.08,
+    };
+    let distance = Array1::linspace(1.0, 40.0, 20000);
+    let fluid: &PcSaftFunctional = &func;
+    let potential = lj93.calculate_cartesian_potential(&distance, fluid, t.to_reduced(KELVIN)?);
+    let tabulated = ExternalPotential::Tabulated {
+        distance,
+        potential,
+    };
+
+    let pore_size = 25.0 * ANGSTROM;
+    let pore = Pore1D::new(Geometry::Cartesian, pore_size, lj93, None, None);
+    let pore_tabulated = Pore1D::new(Geometry::Cartesian, pore_size, tabulated, None, None);
+    let v = pore
+        .initialize(&bulk, None, None)?
+        .profile
+        .external_potential;
+    let v_tabulated = pore_tabulated
+        .initialize(&bulk, None, None)?
+        .profile
+        .external_potential;
+    for (v, v_tabulated) in v.iter().zip(v_tabulated.iter()) {
+        assert_relative_eq!((-v).exp(), (-v_tabulated).exp(), epsilon = 1e-4);
+    }
+
+    // in cylindrical pores, the distance is also measured from the wall
+    let linear = ExternalPotential::Tabulated {
+        distance: Array1::linspace(0.0, 100.0, 2),
+        potential: Array2::from_shape_vec((1, 2), vec![0.0, 100.0])?,
+    };
+    let pore = Pore1D::new(Geometry::Cylindrical, pore_size, linear, None, None);
+    let profile = pore.initialize(&bulk, None, None)?.profile;
+    let r = profile.grid.grids()[0];
+    let r_pore = pore_size.to_reduced(ANGSTROM)?;
+    for (v, r) in profile.external_potential.row(0).iter().zip(r.iter()) {
+        assert_relative_eq!(v * t.to_reduced(KELVIN)?, r_pore - r, epsilon = 1e-8);
+    }
+
+    // the table has to contain a row for every segment
+    let invalid = ExternalPotential::Tabulated {
+        distance: Array1::linspace(0.0, 100.0, 2),
+        potential: Array2::zeros((2, 2)),
+    };
+    let pore = Pore1D::new(Geometry::Cartesian, pore_size, invalid, None, None);
+    assert!(pore.initialize(&bulk, None, None).is_err());
+    Ok(())
+}
+
+#[test]
+fn test_pore2d_slit() -> Result<(), Box<dyn Error>> {
+    use feos_core::StateBuilder;
+    use feos_dft::adsorption::{ExternalPotential, Pore1D, Pore2D, PoreSpecification};
+    use feos_dft::Geometry;
+
+    let params = Arc::new(PcSaftParameters::from_json(
+        vec!["propane"],
+        "tests/pcsaft/test_parameters.json",
+        None,
+        IdentifierOption::Name,
+    )?);
+    let func = Arc::new(PcSaftFunctional::new(params));
+    let bulk = StateBuilder::new(&func)
+        .temperature(300.0 * KELVIN)
+        .pressure(BAR)
+        .build()?;
+    let wall = |epsilon_k_ss| ExternalPotential::LJ93 {
+        sigma_ss: 3.4,
+        epsilon_k_ss,
+        rho_s: 0.08,
+    };
+    let pore_size = 20.0 * ANGSTROM;
+    let period = 10.0 * ANGSTROM;
+
+    // a homogeneous slit in 2D has to reproduce the 1D results
+    let pore_1d = Pore1D::new(Geometry::Cartesian, pore_size, wall(28.0), Some(256), None);
+    let moles_1d = pore_1d
+        .initialize(&bulk, None, None)?
+        .solve(None)?
+        .profile
+        .moles();
+    let pore_2d = Pore2D::new([pore_size, period], [256, 8], wall(28.0), None, None, None);
+    let moles_2d = pore_2d
+        .initialize(&bulk, None, None)?
+        .solve(None)?
+        .profile
+        .moles();
+    assert_relative_eq!(
+        moles_2d.get(0) / period,
+        moles_1d.get(0),
+        max_relative = 1e-6
+    );
+
+    // a more attractive stripe on the walls increases the adsorbed amount
+    let pattern = Some((wall(56.0), 0.5 * period));
+    let pore_patterned = Pore2D::new(
+        [pore_size, period],
+        [256, 16],
+        wall(28.0),
+        None,
+        pattern,
+        None,
+    );
+    let moles_patterned = pore_patterned
+        .initialize(&bulk, None, None)?
+        .solve(None)?
+        .profile
+        .moles();
+    assert!(moles_patterned.get(0) > moles_2d.get(0));
+    Ok(())
+}
+
+#[test]
+fn test_checkpoint() -> Result<(), Box<dyn Error>> {
+    use feos_dft::DFTCheckpoint;
+
+    let params = Arc::new(PcSaftParameters::from_json(
+        vec!["propane"],
+        "tests/pcsaft/test_parameters.json",
+        None,
+        IdentifierOption::Name,
+    )?);
+    let func = Arc::new(PcSaftFunctional::new(params));
+    let t = 200.0 * KELVIN;
+    let w = 150.0 * ANGSTROM;
+    let points = 512;
+    let tc = State::critical_point(&func, None, None, Default::default())?.temperature;
+    let vle = PhaseEquilibrium::pure(&func, t, None, Default::default())?;
+    let interface = PlanarInterface::from_tanh(&vle, points, w, tc, false)?.solve(None)?;
+
+    // write the converged profile and read it again
+    let file = std::env::temp_dir().join("feos_dft_checkpoint.json");
+    interface.profile.checkpoint()?.to_file(&file)?;
+    let checkpoint = DFTCheckpoint::from_file(&file)?;
+    std::fs::remove_file(&file)?;
+    assert_eq!(
+        checkpoint.residual.len(),
+        interface
+            .profile
+            .solver_log
+            .as_ref()
+            .unwrap()
+            .residual()
+            .len()
+    );
+
+    // resuming from the converged profile requires fewer iterations
+    let mut resumed = PlanarInterface::from_tanh(&vle, points, w, tc, false)?;
+    resumed.profile.load_checkpoint(&checkpoint)?;
+    let resumed = resumed.solve(None)?;
+    assert_relative_eq!(
+        interface.surface_tension.unwrap(),
+        resumed.surface_tension.unwrap(),
+        max_relative = 1e-8
+    );
+    assert!(resumed.profile.solver_log.unwrap().residual().len() < checkpoint.residual.len());
+
+    // the grid has to match
+    let mut other = PlanarInterface::from_tanh(&vle, points / 2, w, tc, false)?;
+    assert!(other.profile.load_checkpoint(&checkpoint).is_err());
+
+    // the checkpoint has to be in equilibrium with the bulk state of the profile
+    let mut shifted = checkpoint.clone();
+    shifted.chemical_potential += 1.0;
+    let mut other = PlanarInterface::from_tanh(&vle, points, w, tc, false)?;
+    assert!(other.profile.load_checkpoint(&shifted).is_err());
+
+    // the history of an interrupted Anderson mixing is stored and used to resume
+    let solver = DFTSolver::new(None).anderson_mixing(Some(true), Some(20), None, None, None);
+    let mut interrupted = PlanarInterface::from_tanh(&vle, points, w, tc, false)?;
+    interrupted.solve_inplace(Some(&solver), true)?;
+    let checkpoint = interrupted.profile.checkpoint()?;
+    let history = checkpoint.anderson_history.as_ref().unwrap();
+    assert!(history.log);
+    assert_eq!(history.density.len(), 20);
+    assert_eq!(history.residual.len(), 20);
+    let mut resumed = PlanarInterface::from_tanh(&vle, points, w, tc, false)?;
+    resumed.profile.load_checkpoint(&checkpoint)?;
+    let resumed = resumed.solve(None)?;
+    assert_relative_eq!(
+        interface.surface_tension.unwrap(),
+        resumed.surface_tension.unwrap(),
+        max_relative = 1e-8
+    );
+    Ok(())
+}
+
+#[test]
+fn test_pair_correlation_matrix() -> Result<(), Box<dyn Error>> {
+    use feos_core::StateBuilder;
+    use feos_dft::solvation::{PairCorrelation, PairCorrelationMatrix};
+
+    let params = Arc::new(PcSaftParameters::from_json(
+        vec!["propane", "butane"],
+        "tests/pcsaft/test_parameters.json",
+        None,
+        IdentifierOption::Name,
+    )?);
+    let func = Arc::new(PcSaftFunctional::new(params));
+    let bulk = StateBuilder::new(&func)
+        .temperature(250.0 * KELVIN)
+        .pressure(BAR)
+        .molefracs(&arr1(&[0.5, 0.5]))
+        .liquid()
+        .build()?;
+    let n_grid = 1024;
+    let width = 20.0 * ANGSTROM;
+    let matrix = PairCorrelationMatrix::new(&bulk, n_grid, width)?.solve(None)?;
+    let g = matrix.pair_correlation_function().unwrap();
+    assert_eq!(g.shape(), &[2, 2, n_grid]);
+
+    // every row corresponds to a single test particle calculation
+    let g_1 = PairCorrelation::new(&bulk, 1, n_grid, width)?
+        .solve(None)?
+        .pair_correlation_function
+        .unwrap();
+    for j in 0..2 {
+        for k in 0..n_grid {
+            assert_relative_eq!(g[(1, j, k)], g_1[(j, k)], max_relative = 1e-10);
+        }
+        // g_ij approaches 1 far from the test particle
+        assert_relative_eq!(g[(0, j, n_grid - 1)], 1.0, epsilon = 1e-2);
+    }
+
+    // partial structure factors are symmetric
+    let k = arr1(&[0.0, 0.5, 1.0, 2.0]) * ANGSTROM.powi(-1);
+    let s = matrix.structure_factor(&k)?;
+    assert_eq!(s.shape(), &[2, 2, 4]);
+    for l in 0..4 {
+        assert_relative_eq!(s[(0, 1, l)], s[(1, 0, l)], max_relative = 1e-12);
+        assert!(s[(0, 0, l)].is_finite() && s[(1, 1, l)].is_finite());
+    }
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "rayon")]
+fn test_partition_coefficient() -> Result<(), Box<dyn Error>> {
+    use feos_dft::solvation::SolvationProfile;
+    use ndarray::{arr2, Array1};
+
+    let params = Arc::new(PcSaftParameters::from_json(
+        vec!["propane", "butane"],
+        "tests/pcsaft/test_parameters.json",
+        None,
+        IdentifierOption::Name,
+    )?);
+    let func = Arc::new(PcSaftFunctional::new(params));
+    let t = 250.0 * KELVIN;
+    let solvation = |x: f64| -> Result<_, Box<dyn Error>> {
+        let bulk = feos_core::StateBuilder::new(&func)
+            .temperature(t)
+            .pressure(BAR)
+            .molefracs(&arr1(&[x, 1.0 - x]))
+            .liquid()
+            .build()?;
+        Ok(SolvationProfile::new(
+            &bulk,
+            [24, 24, 24],
+            arr2(&[[0.0], [0.0], [0.0]]) * ANGSTROM,
+            Array1::from_elem(1, 3.5),
+            Array1::from_elem(1, 150.0),
+            Some([24.0 * ANGSTROM; 3]),
+            None,
+            None,
+        )?
+        .solve(None)?)
+    };
+    let propane = solvation(0.9)?;
+    let butane = solvation(0.1)?;
+
+    // consistency of the thermodynamic cycle
+    let k_ab = propane.partition_coefficient(&butane)?;
+    let k_ba = butane.partition_coefficient(&propane)?;
+    assert_relative_eq!(k_ab * k_ba, 1.0, max_relative = 1e-12);
+    assert_relative_eq!(
+        propane.partition_coefficient(&propane)?,
+        1.0,
+        max_relative = 1e-12
+    );
+    let ratio = propane.henry_constant()? / butane.henry_constant()?;
+    let density_ratio = propane.profile.bulk.density / butane.profile.bulk.density;
+    assert_relative_eq!(
+        ratio.into_value()?,
+        density_ratio.into_value()? / k_ab,
+        max_relative = 1e-10
+    );
+
+    // the standard state correction is independent of the solvent
+    let dg_propane = propane.standard_solvation_gibbs_energy(None, None)?
+        - propane.solvation_free_energy.unwrap();
+    let dg_butane =
+        butane.standard_solvation_gibbs_energy(None, None)? - butane.solvation_free_energy.unwrap();
+    assert_relative_eq!(
+        dg_propane.to_reduced(JOULE / MOL)?,
+        dg_butane.to_reduced(JOULE / MOL)?,
+        max_relative = 1e-10
+    );
+    Ok(())
+}
+
+#[test]
+fn test_binary_surface_tension_diagram() -> Result<(), Box<dyn Error>> {
+    use feos_core::PhaseDiagram;
+    use feos_dft::interface::SurfaceTensionDiagram;
+
+    let params = Arc::new(PcSaftParameters::from_json(
+        vec!["propane", "butane"],
+        "tests/pcsaft/test_parameters.json",
+        None,
+        IdentifierOption::Name,
+    )?);
+    let func = Arc::new(PcSaftFunctional::new(params));
+    let t = 250.0 * KELVIN;
+    let npoints = 5;
+    let n_grid = 512;
+    let mut diagram = SurfaceTensionDiagram::binary_isotherm(
+        &func,
+        t,
+        Some(npoints),
+        Some(n_grid),
+        None,
+        None,
+        None,
+        None,
+    )?;
+    assert_eq!(diagram.profiles.len(), npoints);
+
+    // same results as the sequential calculation
+    let dia = PhaseDiagram::binary_vle(&func, t, Some(npoints), None, Default::default())?;
+    let mut reference =
+        SurfaceTensionDiagram::new(&dia.states, None, Some(n_grid), None, None, None, None);
+    let st = diagram.surface_tension();
+    let st_ref = reference.surface_tension();
+    for i in 0..npoints {
+        assert_relative_eq!(st.get(i), st_ref.get(i), max_relative = 1e-8);
+    }
+
+    // relative adsorption and enrichment for every state point
+    assert_eq!(diagram.relative_adsorption().len(), npoints);
+    assert_eq!(diagram.interfacial_enrichment().len(), npoints);
+    Ok(())
+}
+
+#[test]
+fn test_wetting() -> Result<(), Box<dyn Error>> {
+    use feos_dft::adsorption::{ExternalPotential, Pore1D, Wetting};
+    use feos_dft::Geometry;
+
+    let params = Arc::new(PcSaftParameters::from_json(
+        vec!["propane"],
+        "tests/pcsaft/test_parameters.json",
+        None,
+        IdentifierOption::Name,
+    )?);
+    let func = Arc::new(PcSaftFunctional::new(params));
+    let vle = [200.0, 230.0, 260.0]
+        .iter()
+        .map(|&t| PhaseEquilibrium::pure(&func, t * KELVIN, None, Default::default()))
+        .collect::<Result<Vec<_>, _>>()?;
+    let pore = Pore1D::new(
+        Geometry::Cartesian,
+        60.0 * ANGSTROM,
+        ExternalPotential::LJ93 {
+            sigma_ss: 3.4,
+            epsilon_k_ss: 28.0,
+            rho_s: 0.08,
+        },
+        Some(1024),
+        None,
+    );
+    let wetting = Wetting::new(&vle, &pore, Some(512), None)?;
+
+    // Young's equation
+    let cos_theta = wetting.cos_theta();
+    let contact_angle = wetting.contact_angle();
+    for i in 0..vle.len() {
+        let gamma_lv = wetting.liquid_vapor_tension.get(i);
+        let gamma_sl = wetting.solid_liquid_tension.get(i);
+        let gamma_sv = wetting.solid_vapor_tension.get(i);
+        assert_relative_eq!(
+            gamma_sv - gamma_sl,
+            gamma_lv * cos_theta[i],
+            max_relative = 1e-10
+        );
+        assert!((0.0..=180.0).contains(&contact_angle[i]));
+    }
+
+    // planar walls only
+    let pore = Pore1D::new(
+        Geometry::Cylindrical,
+        30.0 * ANGSTROM,
+        ExternalPotential::HardWall { sigma_ss: 1.0 },
+        None,
+        None,
+    );
+    assert!(Wetting::new(&vle, &pore, Some(512), None).is_err());
+    Ok(())
+}
+
+#[test]
+fn test_adsorption_hysteresis() -> Result<(), Box<dyn Error>> {
+    use feos_dft::adsorption::{AdsorptionHysteresis1D, ExternalPotential, Pore1D};
+    use feos_dft::Geometry;
+
+    let params = Arc::new(PcSaftParameters::from_json(
+        vec!["propane"],
+        "tests/pcsaft/test_parameters.json",
+        None,
+        IdentifierOption::Name,
+    )?);
+    let func = Arc::new(PcSaftFunctional::new(params));
+    let t = 230.0 * KELVIN;
+    let p_sat = PhaseEquilibrium::pure(&func, t, None, Default::default())?
+        .vapor()
+        .pressure(Contributions::Total);
+    let pore = Pore1D::new(
+        Geometry::Cartesian,
+        25.0 * ANGSTROM,
+        ExternalPotential::LJ93 {
+            sigma_ss: 3.4,
+            epsilon_k_ss: 100.0,
+            rho_s: 0.08,
+        },
+        None,
+        None,
+    );
+    let pressure = SIArray1::linspace(0.02 * p_sat, p_sat, 50)?;
+    let hysteresis = AdsorptionHysteresis1D::new(&func, t, &pressure, &pore, None, None)?;
+
+    // the equilibrium transition lies between the evaporation and the condensation
+    let (p_low, p_high) = hysteresis.hysteresis_loops()[0];
+    let p_eq = hysteresis.equilibrium_pressures()[0];
+    let (_, p_des) = hysteresis.desorption_jumps()[0];
+    let (p_ads, _) = hysteresis.adsorption_jumps()[0];
+    assert!(p_low <= p_eq && p_eq <= p_high);
+    assert!(p_des <= p_eq && p_eq <= p_ads);
+
+    // the equilibrium isotherm follows the branch with the lower grand potential
+    let omega_ads = hysteresis.adsorption.grand_potential();
+    let omega_des = hysteresis.desorption.grand_potential();
+    let equilibrium = hysteresis.into_equilibrium_isotherm();
+    let omega_eq = equilibrium.grand_potential();
+    for i in 0..pressure.len() {
+        let omega_min = if omega_ads.get(i) < omega_des.get(i) {
+            omega_ads.get(i)
+        } else {
+            omega_des.get(i)
+        };
+        assert_relative_eq!(omega_eq.get(i), omega_min);
+    }
+    Ok(())
+}
+
+#[test]
+fn test_thin_film() -> Result<(), Box<dyn Error>> {
+    use feos_dft::adsorption::{ExternalPotential, Pore1D, ThinFilm};
+    use feos_dft::Geometry;
+
+    let params = Arc::new(PcSaftParameters::from_json(
+        vec!["propane"],
+        "tests/pcsaft/test_parameters.json",
+        None,
+        IdentifierOption::Name,
+    )?);
+    let func = Arc::new(PcSaftFunctional::new(params));
+    let vle = PhaseEquilibrium::pure(&func, 230.0 * KELVIN, None, Default::default())?;
+    let pore = Pore1D::new(
+        Geometry::Cartesian,
+        80.0 * ANGSTROM,
+        ExternalPotential::LJ93 {
+            sigma_ss: 3.4,
+            epsilon_k_ss: 28.0,
+            rho_s: 0.08,
+        },
+        Some(1024),
+        None,
+    );
+    let p_sat = vle.vapor().pressure(Contributions::Total);
+    let pressure = SIArray1::linspace(0.5 * p_sat, 0.9 * p_sat, 5)?;
+    let film = ThinFilm::new(&vle, &pore, &pressure, None)?;
+
+    // films grow and the disjoining pressure decreases towards saturation
+    let l = film.film_thickness.to_reduced(ANGSTROM)?;
+    let pi = film.disjoining_pressure.to_reduced(BAR)?;
+    for i in 1..pressure.len() {
+        assert!(l[i] > l[i - 1]);
+        assert!(pi[i] < pi[i - 1]);
+        assert!(pi[i] > 0.0);
+    }
+
+    // interpolation reproduces the calculated points
+    let l_eq = film
+        .equilibrium_film_thickness(film.disjoining_pressure.get(2))
+        .unwrap();
+    assert_relative_eq!(l_eq, film.film_thickness.get(2), max_relative = 1e-10);
+
+    // the vapor reservoir has to be undersaturated
+    let pressure = SIArray1::linspace(0.5 * p_sat, 1.1 * p_sat, 5)?;
+    assert!(ThinFilm::new(&vle, &pore, &pressure, None).is_err());
+    Ok(())
+}
+
+#[test]
+fn test_simulation_tables() -> Result<(), Box<dyn Error>> {
+    use feos_dft::adsorption::ExternalPotential;
+    use feos_dft::simulation::{PotentialTable, SimulationProfile};
+    use ndarray::Array1;
+
+    let params = Arc::new(PcSaftParameters::from_json(
+        vec!["propane"],
+        "tests/pcsaft/test_parameters.json",
+        None,
+        IdentifierOption::Name,
+    )?);
+    let (sigma, epsilon_k) = (params.sigma[0], params.epsilon_k[0]);
+    let func = Arc::new(PcSaftFunctional::new(params));
+    let t = 300.0 * KELVIN;
+    let distance = Array1::linspace(3.0, 12.0, 91) * ANGSTROM;
+
+    let tables = func.pair_potential_tables(t, &distance)?;
+    assert_eq!(tables.len(), 1);
+    assert_eq!(tables[0].keyword, "PAIR_1_1");
+    for ((&r, &u), &f) in tables[0]
+        .distance
+        .iter()
+        .zip(&tables[0].energy)
+        .zip(&tables[0].force)
+    {
+        let s6 = (sigma / r).powi(6);
+        assert_relative_eq!(u, 4.0 * epsilon_k * s6 * (s6 - 1.0), max_relative = 1e-12);
+        assert_relative_eq!(
+            f,
+            24.0 * epsilon_k * s6 * (2.0 * s6 - 1.0) / r,
+            max_relative = 1e-6,
+            epsilon = 1e-6
+        );
+    }
+
+    let wall = ExternalPotential::LJ93 {
+        sigma_ss: 3.4,
+        epsilon_k_ss: 28.0,
+        rho_s: 0.08,
+    };
+    let wall_tables = wall.wall_potential_tables(&**func, t, &distance)?;
+    assert_eq!(wall_tables[0].keyword, "WALL_1");
+    let hard_wall = ExternalPotential::HardWall { sigma_ss: 3.4 };
+    assert!(hard_wall
+        .wall_potential_tables(&**func, t, &distance)
+        .is_err());
+
+    let file = std::env::temp_dir().join("feos_test_potential.table");
+    PotentialTable::to_lammps_file(&[tables, wall_tables].concat(), &file)?;
+    let content = std::fs::read_to_string(&file)?;
+    std::fs::remove_file(&file)?;
+    assert!(content.contains("\nPAIR_1_1\nN 91\n"));
+    assert!(content.contains("\nWALL_1\nN 91\n"));
+    assert_eq!(content.lines().count(), 1 + 2 * (4 + 91));
+
+    let chunks = "# Chunk-averaged data for fix 1 and group all
+# Timestep Number-of-chunks Total-count
+# Chunk Coord1 Ncount density/number
+1000 3 30
+  1 0.5 10 0.01
+  2 1.5 10 0.02
+  3 2.5 10 0.03
+2000 3 30
+  1 0.5 10 0.03
+  2 1.5 10 0.04
+  3 2.5 10 0.05
+";
+    let file = std::env::temp_dir().join("feos_test_profile.dat");
+    std::fs::write(&file, chunks)?;
+    let profile = SimulationProfile::from_lammps_chunk(&file)?;
+    std::fs::remove_file(&file)?;
+    let unit = 1.0 / (NAV * ANGSTROM.powi(3));
+    assert_relative_eq!(profile.density.get(1), 0.03 * unit, max_relative = 1e-12);
+    let density = profile.interpolate(&(arr1(&[0.0, 1.0, 2.0]) * ANGSTROM))?;
+    assert!(density.get(0).to_reduced(unit)?.is_nan());
+    assert_relative_eq!(density.get(1), 0.025 * unit, max_relative = 1e-12);
+    assert_relative_eq!(density.get(2), 0.035 * unit, max_relative = 1e-12);
+    Ok(())
+}
@@ -1,6 +1,11 @@
+mod azeotrope;
 mod critical_point;
 mod dft;
+mod parameter_scan;
+mod phase_diagram;
+mod process;
 mod properties;
+mod solid;
 mod stability_analysis;
 mod state_creation_mixture;
 mod state_creation_pure;
@@ -0,0 +1,54 @@
+use approx::assert_relative_eq;
+use feos::pcsaft::{PcSaft, PcSaftParameters};
+use feos_core::parameter::{IdentifierOption, Parameter};
+use feos_core::State;
+use quantity::si::*;
+use std::error::Error;
+use std::sync::Arc;
+
+#[test]
+fn test_parameter_scan_pure() -> Result<(), Box<dyn Error>> {
+    let params = PcSaftParameters::from_json(
+        vec!["propane"],
+        "tests/pcsaft/test_parameters.json",
+        None,
+        IdentifierOption::Name,
+    )?;
+    let saft = Arc::new(PcSaft::new(Arc::new(params)));
+    let scan = State::parameter_scan(
+        &saft,
+        250.0 * KELVIN,
+        450.0 * KELVIN,
+        5,
+        20,
+        Default::default(),
+    )?;
+    assert_eq!(scan.critical_points.len(), 1);
+    assert_relative_eq!(
+        scan.critical_points[0].temperature,
+        375.12441 * KELVIN,
+        max_relative = 1e-8
+    );
+    Ok(())
+}
+
+#[test]
+fn test_parameter_scan_mixture() -> Result<(), Box<dyn Error>> {
+    let params = PcSaftParameters::from_json(
+        vec!["propane", "butane"],
+        "tests/pcsaft/test_parameters.json",
+        None,
+        IdentifierOption::Name,
+    )?;
+    let saft = Arc::new(PcSaft::new(Arc::new(params)));
+    let scan = State::parameter_scan(
+        &saft,
+        250.0 * KELVIN,
+        450.0 * KELVIN,
+        5,
+        20,
+        Default::default(),
+    );
+    assert!(scan.is_err());
+    Ok(())
+}
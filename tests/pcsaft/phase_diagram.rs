@@ -0,0 +1,110 @@
+#![cfg(feature = "rayon")]
+use approx::assert_relative_eq;
+use feos::pcsaft::{PcSaft, PcSaftParameters};
+use feos_core::parameter::{IdentifierOption, Parameter};
+use feos_core::{Contributions, PhaseDiagram};
+use ndarray::arr1;
+use quantity::si::*;
+use rayon::ThreadPoolBuilder;
+use std::error::Error;
+use std::sync::Arc;
+
+fn assert_phase_diagrams_eq(serial: &PhaseDiagram<PcSaft, 2>, parallel: &PhaseDiagram<PcSaft, 2>) {
+    assert_eq!(serial.states.len(), parallel.states.len());
+    for (s, p) in serial.states.iter().zip(parallel.states.iter()) {
+        assert_relative_eq!(
+            s.vapor().temperature,
+            p.vapor().temperature,
+            max_relative = 1e-6
+        );
+        assert_relative_eq!(
+            s.vapor().pressure(Contributions::Total),
+            p.vapor().pressure(Contributions::Total),
+            max_relative = 1e-6
+        );
+        assert_relative_eq!(s.liquid().molefracs, p.liquid().molefracs, epsilon = 1e-6);
+        assert_relative_eq!(s.vapor().molefracs, p.vapor().molefracs, epsilon = 1e-6);
+    }
+}
+
+#[test]
+fn test_par_binary_vle() -> Result<(), Box<dyn Error>> {
+    let params = PcSaftParameters::from_json(
+        vec!["propane", "butane"],
+        "tests/pcsaft/test_parameters.json",
+        None,
+        IdentifierOption::Name,
+    )?;
+    let eos = Arc::new(PcSaft::new(Arc::new(params)));
+    let options = (Default::default(), Default::default());
+    for tp in [250.0 * KELVIN, 5.0 * BAR] {
+        let serial = PhaseDiagram::binary_vle(&eos, tp, Some(21), None, options)?;
+        let thread_pool = ThreadPoolBuilder::new().num_threads(3).build()?;
+        let parallel =
+            PhaseDiagram::par_binary_vle(&eos, tp, Some(21), None, 4, thread_pool, options)?;
+        assert_phase_diagrams_eq(&serial, &parallel);
+    }
+    Ok(())
+}
+
+#[test]
+fn test_par_lle() -> Result<(), Box<dyn Error>> {
+    let params = PcSaftParameters::from_json(
+        vec!["water_np", "hexane"],
+        "tests/pcsaft/test_parameters.json",
+        None,
+        IdentifierOption::Name,
+    )?;
+    let eos = Arc::new(PcSaft::new(Arc::new(params)));
+    let feed = arr1(&[0.5, 0.5]) * MOL;
+    let (t_min, t_max) = (300.0 * KELVIN, 340.0 * KELVIN);
+    let serial = PhaseDiagram::lle(&eos, 10.0 * BAR, &feed, t_min, t_max, Some(9))?;
+    let thread_pool = ThreadPoolBuilder::new().num_threads(3).build()?;
+    let parallel = PhaseDiagram::par_lle(
+        &eos,
+        10.0 * BAR,
+        &feed,
+        t_min,
+        t_max,
+        Some(9),
+        2,
+        thread_pool,
+    )?;
+    assert!(!serial.states.is_empty());
+    assert_phase_diagrams_eq(&serial, &parallel);
+    Ok(())
+}
+
+#[test]
+fn test_par_binary_vlle() -> Result<(), Box<dyn Error>> {
+    let params = PcSaftParameters::from_json(
+        vec!["water_np", "hexane"],
+        "tests/pcsaft/test_parameters.json",
+        None,
+        IdentifierOption::Name,
+    )?;
+    let eos = Arc::new(PcSaft::new(Arc::new(params)));
+    let options = (Default::default(), Default::default());
+    let p = BAR;
+    let x_lle = (0.999, 0.001);
+    let t_lim = Some(300.0 * KELVIN);
+    let serial =
+        PhaseDiagram::binary_vlle(&eos, p, x_lle, t_lim, None, Some(11), Some(5), options)?;
+    let thread_pool = ThreadPoolBuilder::new().num_threads(3).build()?;
+    let parallel = PhaseDiagram::par_binary_vlle(
+        &eos,
+        p,
+        x_lle,
+        t_lim,
+        None,
+        Some(11),
+        Some(5),
+        2,
+        thread_pool,
+        options,
+    )?;
+    assert_phase_diagrams_eq(&serial.vle1, &parallel.vle1);
+    assert_phase_diagrams_eq(&serial.vle2, &parallel.vle2);
+    assert_phase_diagrams_eq(&serial.lle.unwrap(), &parallel.lle.unwrap());
+    Ok(())
+}
@@ -0,0 +1,109 @@
+use approx::assert_relative_eq;
+use feos::pcsaft::{PcSaft, PcSaftParameters};
+use feos_core::parameter::{IdentifierOption, Parameter};
+use feos_core::{Contributions, Efficiency, Isoline, PhaseEquilibrium, SolverOptions, Stream};
+use ndarray::arr1;
+use quantity::si::*;
+use std::error::Error;
+use std::sync::Arc;
+
+#[test]
+fn test_stream_process_units() -> Result<(), Box<dyn Error>> {
+    let params = PcSaftParameters::from_json(
+        vec!["propane"],
+        "tests/pcsaft/test_parameters.json",
+        None,
+        IdentifierOption::Name,
+    )?;
+    let e = Arc::new(PcSaft::new(Arc::new(params)));
+    let x = arr1(&[1.0]);
+    let options = SolverOptions::default();
+
+    // throttling of a saturated liquid yields a two-phase stream
+    let liquid = Stream::new_tp(&e, 300.0 * KELVIN, 20.0 * BAR, &x, options)?;
+    assert!(liquid.vapor_fraction().is_none());
+    let throttled = liquid.valve(2.0 * BAR, options)?;
+    let vle = PhaseEquilibrium::pure(&e, 2.0 * BAR, None, options)?;
+    assert!(throttled.vapor_fraction().unwrap() > 0.0);
+    assert_relative_eq!(
+        throttled.molar_enthalpy(),
+        liquid.molar_enthalpy(),
+        max_relative = 1e-10
+    );
+    assert_relative_eq!(
+        throttled.temperature,
+        vle.vapor().temperature,
+        max_relative = 1e-10
+    );
+
+    // an inefficient compressor requires more work
+    let vapor = Stream::new_tp(&e, 300.0 * KELVIN, BAR, &x, options)?;
+    let (isentropic, w_s) = vapor.compressor(5.0 * BAR, Efficiency::Isentropic(1.0), options)?;
+    let (real, w) = vapor.compressor(5.0 * BAR, Efficiency::Isentropic(0.8), options)?;
+    assert_relative_eq!(
+        isentropic.molar_entropy(),
+        vapor.molar_entropy(),
+        max_relative = 1e-8
+    );
+    assert_relative_eq!(w, w_s / 0.8, max_relative = 1e-6);
+    assert!(real.temperature > isentropic.temperature);
+
+    // the heater duty reproduces the outlet stream
+    let (heated, q) = vapor.heater(350.0 * KELVIN, options)?;
+    let heated2 = vapor.heater_duty(q, options)?;
+    assert_relative_eq!(heated2.temperature, heated.temperature, max_relative = 1e-6);
+    Ok(())
+}
+
+#[test]
+fn test_stream_ph_mixture() -> Result<(), Box<dyn Error>> {
+    let params = PcSaftParameters::from_json(
+        vec!["propane", "butane"],
+        "tests/pcsaft/test_parameters.json",
+        None,
+        IdentifierOption::Name,
+    )?;
+    let e = Arc::new(PcSaft::new(Arc::new(params)));
+    let x = arr1(&[0.5, 0.5]);
+    let options = SolverOptions::default();
+    let stream = Stream::new_tp(&e, 280.0 * KELVIN, 3.0 * BAR, &x, options)?;
+    assert!(stream.vapor_fraction().is_some());
+    let stream_ph = Stream::new_ph(&e, 3.0 * BAR, stream.molar_enthalpy(), &x, None, options)?;
+    assert_relative_eq!(
+        stream_ph.temperature,
+        stream.temperature,
+        max_relative = 1e-6
+    );
+    Ok(())
+}
+
+#[test]
+fn test_isobar_saturation() -> Result<(), Box<dyn Error>> {
+    let params = PcSaftParameters::from_json(
+        vec!["propane"],
+        "tests/pcsaft/test_parameters.json",
+        None,
+        IdentifierOption::Name,
+    )?;
+    let e = Arc::new(PcSaft::new(Arc::new(params)));
+    let t = SIArray1::linspace(200.0 * KELVIN, 300.0 * KELVIN, 11)?;
+    let x = arr1(&[1.0]);
+    let isobar = Isoline::isobar(&e, BAR, &t, &x, SolverOptions::default())?;
+    let vle = PhaseEquilibrium::pure(&e, BAR, None, SolverOptions::default())?;
+    assert_eq!(isobar.streams.len(), 13);
+    let h = isobar.molar_enthalpy();
+    for i in 1..h.len() {
+        assert!(h.get(i) > h.get(i - 1));
+    }
+    let beta = isobar.vapor_fraction();
+    let i = beta.iter().position(|b| !b.is_nan()).unwrap();
+    assert_eq!(beta[i], 0.0);
+    assert_eq!(beta[i + 1], 1.0);
+    assert_relative_eq!(
+        h.get(i + 1) - h.get(i),
+        vle.vapor().molar_enthalpy(Contributions::Total)
+            - vle.liquid().molar_enthalpy(Contributions::Total),
+        max_relative = 1e-10
+    );
+    Ok(())
+}
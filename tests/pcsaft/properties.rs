@@ -1,7 +1,10 @@
 use approx::assert_relative_eq;
 use feos::pcsaft::{PcSaft, PcSaftParameters};
 use feos_core::parameter::{IdentifierOption, Parameter};
-use feos_core::{EquationOfState, StateBuilder};
+use feos_core::{
+    Contributions, DensityInitialization, EquationOfState, PhaseEquilibrium, SolverOptions, State,
+    StateBuilder, StateVec,
+};
 use ndarray::*;
 use quantity::si::*;
 use std::error::Error;
@@ -53,3 +56,98 @@ fn test_virial_is_not_nan() -> Result<(), Box<dyn Error>> {
     assert!(!virial_b.is_nan());
     Ok(())
 }
+
+#[test]
+fn test_state_vec_npt() -> Result<(), Box<dyn Error>> {
+    let params = PcSaftParameters::from_json(
+        vec!["propane"],
+        "tests/pcsaft/test_parameters.json",
+        None,
+        IdentifierOption::Name,
+    )?;
+    let e = Arc::new(PcSaft::new(Arc::new(params)));
+    let t = arr1(&[250.0, 300.0, 350.0]) * KELVIN;
+    let p = arr1(&[1.0, 2.0, 3.0]) * BAR;
+    let n = arr1(&[1.0]) * MOL;
+    let states = State::new_npt_vec(&e, &t, &p, &n, DensityInitialization::None)?;
+    let state_vec: StateVec<_> = states.iter().collect();
+    let rho = state_vec.density();
+    let c_p = state_vec.c_p();
+    for (i, s) in states.iter().enumerate() {
+        let s_ref = State::new_npt(&e, t.get(i), p.get(i), &n, DensityInitialization::None)?;
+        assert_relative_eq!(rho.get(i), s_ref.density, max_relative = 1e-12);
+        assert_relative_eq!(
+            c_p.get(i),
+            s_ref.c_p(Contributions::Total),
+            max_relative = 1e-12
+        );
+        assert_relative_eq!(
+            s.pressure(Contributions::Total),
+            p.get(i),
+            max_relative = 1e-8
+        );
+    }
+    let p2 = arr1(&[1.0, 2.0]) * BAR;
+    assert!(State::new_npt_vec(&e, &t, &p2, &n, DensityInitialization::None).is_err());
+    Ok(())
+}
+
+#[test]
+fn test_infinite_dilution() -> Result<(), Box<dyn Error>> {
+    let params = PcSaftParameters::from_json(
+        vec!["propane", "butane"],
+        "tests/pcsaft/test_parameters.json",
+        None,
+        IdentifierOption::Name,
+    )?;
+    let e = Arc::new(PcSaft::new(Arc::new(params)));
+    let t = 250.0 * KELVIN;
+    let p = 5.0 * BAR;
+    let solvent = State::new_npt(
+        &e,
+        t,
+        p,
+        &(arr1(&[0.0, 1.0]) * MOL),
+        DensityInitialization::Liquid,
+    )?;
+    let dilute = State::new_npt(
+        &e,
+        t,
+        p,
+        &(arr1(&[1e-7, 1.0 - 1e-7]) * MOL),
+        DensityInitialization::Liquid,
+    )?;
+    assert_relative_eq!(
+        dilute.henry_constant(0)?,
+        solvent.henry_constant(0)?,
+        max_relative = 1e-12
+    );
+    assert_relative_eq!(
+        solvent.henry_constant(0)?,
+        dilute.ln_phi()[0].exp() * p,
+        max_relative = 1e-5
+    );
+    assert_relative_eq!(
+        solvent.partial_molar_volume_infinite_dilution(0)?,
+        dilute.partial_molar_volume(Contributions::Total).get(0),
+        max_relative = 1e-5
+    );
+    assert_relative_eq!(
+        solvent.ln_activity_coefficient_infinite_dilution(0)?,
+        dilute.ln_symmetric_activity_coefficient()?[0],
+        epsilon = 1e-5
+    );
+
+    let vle = PhaseEquilibrium::pure(&Arc::new(e.subset(&[1])), t, None, SolverOptions::default())?;
+    let saturated = State::new_npt(
+        &e,
+        t,
+        vle.liquid().pressure(Contributions::Total),
+        &(arr1(&[0.0, 1.0]) * MOL),
+        DensityInitialization::Liquid,
+    )?;
+    let h =
+        PhaseEquilibrium::henry_constant(&e, t, 0, &arr1(&[0.0, 1.0]), SolverOptions::default())?;
+    assert_relative_eq!(h, saturated.henry_constant(0)?, max_relative = 1e-8);
+    Ok(())
+}
@@ -0,0 +1,70 @@
+use approx::assert_relative_eq;
+use feos::pcsaft::{PcSaft, PcSaftParameters};
+use feos_core::parameter::{IdentifierOption, Parameter};
+use feos_core::{Contributions, PhaseDiagram, PhaseEquilibrium, SolidModel, SolverOptions};
+use quantity::si::*;
+use std::error::Error;
+use std::sync::Arc;
+
+#[test]
+fn test_solid_phase() -> Result<(), Box<dyn Error>> {
+    let params = PcSaftParameters::from_json(
+        vec!["propane"],
+        "tests/pcsaft/test_parameters.json",
+        None,
+        IdentifierOption::Name,
+    )?;
+    let e = Arc::new(PcSaft::new(Arc::new(params)));
+    let options = SolverOptions::default();
+    let t_t = 150.0 * KELVIN;
+    let vle = PhaseEquilibrium::pure(&e, t_t, None, options)?;
+    let p_t = vle.vapor().pressure(Contributions::Total);
+    let v_s = 0.9 / vle.liquid().density;
+    let solid = SolidModel::new(t_t, 3500.0 * JOULE / MOL, v_s, None);
+
+    // sublimation pressure from the Clausius-Clapeyron equation
+    let t = 145.0 * KELVIN;
+    let vapor = solid.sublimation_point(&e, t, None, options)?;
+    let h_sub = vle.vapor().molar_enthalpy(Contributions::Total)
+        - vle.liquid().molar_enthalpy(Contributions::Total)
+        + solid.melting_enthalpy;
+    let p_sub = p_t * (-(h_sub / RGAS * (1.0 / t - 1.0 / t_t)).into_value()?).exp();
+    assert!(vapor.pressure(Contributions::Total) < p_t);
+    assert_relative_eq!(
+        vapor.pressure(Contributions::Total),
+        p_sub,
+        max_relative = 1e-2
+    );
+
+    // the melting pressure increases for a solid that is denser than the liquid
+    let liquid = solid.melting_point(&e, 152.0 * KELVIN, None, options)?;
+    assert!(liquid.pressure(Contributions::Total) > p_t);
+    assert!(liquid.density > vle.liquid().density);
+
+    let dia = PhaseDiagram::pure_solid(
+        &e,
+        &solid,
+        130.0 * KELVIN,
+        155.0 * KELVIN,
+        11,
+        None,
+        options,
+    )?;
+    assert_eq!(dia.sublimation.len(), 11);
+    assert_eq!(dia.melting.len(), 11);
+    assert_relative_eq!(
+        dia.sublimation
+            .last()
+            .unwrap()
+            .pressure(Contributions::Total),
+        p_t,
+        max_relative = 1e-8
+    );
+    assert_relative_eq!(
+        dia.melting[0].pressure(Contributions::Total),
+        p_t,
+        max_relative = 1e-8
+    );
+    assert_relative_eq!(dia.vle.states[0].vapor().temperature, t_t);
+    Ok(())
+}
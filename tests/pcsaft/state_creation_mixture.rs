@@ -123,3 +123,87 @@ fn temperature_pressure_molefracs() -> Result<(), Box<dyn Error>> {
     );
     Ok(())
 }
+
+#[test]
+fn temperature_mass_density_massfracs() -> Result<(), Box<dyn Error>> {
+    let saft = Arc::new(PcSaft::new(propane_butane_parameters()?));
+    let temperature = 300.0 * KELVIN;
+    let w = arr1(&[0.3, 0.7]);
+    let mass_density = 2.0 * KILOGRAM / METER.powi(3);
+    let state = StateBuilder::new(&saft)
+        .temperature(temperature)
+        .mass_density(mass_density)
+        .massfracs(&w)
+        .build()?;
+    Zip::from(&state.massfracs())
+        .and(&w)
+        .for_each(|&l, &r| assert_relative_eq!(l, r, max_relative = 1e-10));
+    assert_relative_eq!(state.mass_density(), mass_density, max_relative = 1e-10);
+
+    let specific_enthalpy = state.specific_enthalpy(Contributions::Total);
+    let state = StateBuilder::new(&saft)
+        .pressure(state.pressure(Contributions::Total))
+        .specific_enthalpy(specific_enthalpy)
+        .massfracs(&w)
+        .initial_temperature(290.0 * KELVIN)
+        .build()?;
+    assert_relative_eq!(state.temperature, temperature, max_relative = 1e-8);
+    assert_relative_eq!(
+        state.specific_enthalpy(Contributions::Total),
+        specific_enthalpy,
+        max_relative = 1e-8
+    );
+    Ok(())
+}
+
+#[test]
+fn pressure_temperature_flows() -> Result<(), Box<dyn Error>> {
+    let saft = Arc::new(PcSaft::new(propane_butane_parameters()?));
+    let temperature = 300.0 * KELVIN;
+    let pressure = BAR;
+    let mass_flow = arr1(&[0.3, 0.7]) * KILOGRAM / SECOND;
+    let state = StateBuilder::new(&saft)
+        .temperature(temperature)
+        .pressure(pressure)
+        .mass_flow(&mass_flow)
+        .build()?;
+    assert_relative_eq!(
+        state.mass_flow().to_reduced(KILOGRAM / SECOND)?,
+        arr1(&[0.3, 0.7]),
+        max_relative = 1e-10
+    );
+    assert_relative_eq!(
+        state.total_mass_flow(),
+        KILOGRAM / SECOND,
+        max_relative = 1e-10
+    );
+
+    let volume_flow = state.volume_flow();
+    let w = state.massfracs();
+    let state = StateBuilder::new(&saft)
+        .temperature(temperature)
+        .volume_flow(volume_flow)
+        .total_mass_flow(KILOGRAM / SECOND)
+        .massfracs(&w)
+        .build()?;
+    assert_relative_eq!(
+        state.pressure(Contributions::Total),
+        pressure,
+        max_relative = 1e-8
+    );
+    assert_relative_eq!(
+        state.mass_flow().to_reduced(KILOGRAM / SECOND)?,
+        arr1(&[0.3, 0.7]),
+        max_relative = 1e-10
+    );
+
+    let molar_flow = state.molar_flow();
+    assert!(StateBuilder::new(&saft)
+        .temperature(temperature)
+        .pressure(pressure)
+        .molar_flow(&molar_flow)
+        .mass_flow(&mass_flow)
+        .build()
+        .is_err());
+    Ok(())
+}
@@ -1,9 +1,11 @@
 use approx::assert_relative_eq;
-use feos::pcsaft::{PcSaft, PcSaftParameters};
+use feos::pcsaft::{PcSaft, PcSaftOptions, PcSaftParameters};
 use feos_core::parameter::{IdentifierOption, Parameter, ParameterError};
 use feos_core::{
-    Contributions, DensityInitialization, EquationOfState, PhaseEquilibrium, State, StateBuilder,
+    Contributions, DensityInitialization, EquationOfState, MaxDensity, PhaseEquilibrium, State,
+    StateBuilder,
 };
+use ndarray::arr1;
 use quantity::si::*;
 use std::error::Error;
 use std::sync::Arc;
@@ -422,3 +424,32 @@ fn test_consistency() -> Result<(), Box<dyn Error>> {
     }
     Ok(())
 }
+
+#[test]
+fn max_density_strategy() -> Result<(), Box<dyn Error>> {
+    let moles = arr1(&[1.0]);
+    let heuristic = PcSaft::new(propane_parameters()?).compute_max_density(&moles);
+
+    let cap = 0.5 * heuristic * SIUnit::reference_density();
+    let options = PcSaftOptions {
+        max_density: MaxDensity::capped(cap)?,
+        ..Default::default()
+    };
+    let saft = PcSaft::with_options(propane_parameters()?, options);
+    assert_relative_eq!(saft.compute_max_density(&moles), 0.5 * heuristic);
+
+    let options = PcSaftOptions {
+        max_density: MaxDensity::capped(2.0 * cap)?,
+        ..Default::default()
+    };
+    let saft = PcSaft::with_options(propane_parameters()?, options);
+    assert_relative_eq!(saft.compute_max_density(&moles), heuristic);
+
+    let options = PcSaftOptions {
+        max_density: MaxDensity::fixed(3.0 * cap)?,
+        ..Default::default()
+    };
+    let saft = PcSaft::with_options(propane_parameters()?, options);
+    assert_relative_eq!(saft.compute_max_density(&moles), 1.5 * heuristic);
+    Ok(())
+}
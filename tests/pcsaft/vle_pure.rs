@@ -1,7 +1,8 @@
 use approx::assert_relative_eq;
 use feos::pcsaft::{PcSaft, PcSaftParameters};
 use feos_core::parameter::{IdentifierOption, Parameter};
-use feos_core::{Contributions, PhaseEquilibrium};
+use feos_core::{Contributions, DensityInitialization, PhaseEquilibrium, State};
+use ndarray::arr1;
 use quantity::si::*;
 use std::error::Error;
 use std::sync::Arc;
@@ -75,3 +76,33 @@ fn vle_pure_pressure() -> Result<(), Box<dyn Error>> {
     }
     Ok(())
 }
+
+#[test]
+fn vle_pure_solver_log() -> Result<(), Box<dyn Error>> {
+    let params = PcSaftParameters::from_json(
+        vec!["propane"],
+        "tests/pcsaft/test_parameters.json",
+        None,
+        IdentifierOption::Name,
+    )?;
+    let saft = Arc::new(PcSaft::new(Arc::new(params)));
+    let vle = PhaseEquilibrium::pure(&saft, 250.0 * KELVIN, None, Default::default())?;
+    let log = vle.solver_log().unwrap();
+    assert_eq!(log.solver, "pure_t");
+    assert!(log.converged);
+    assert!(!log.is_empty());
+    assert!(log.residual()[log.len() - 1] < 1e-12);
+
+    let state = State::new_npt(
+        &saft,
+        250.0 * KELVIN,
+        10.0 * BAR,
+        &(arr1(&[1.0]) * MOL),
+        DensityInitialization::Liquid,
+    )?;
+    let log = state.solver_log().unwrap();
+    assert_eq!(log.solver, "density_iteration");
+    assert!(log.converged);
+    assert!(log.step_size().iter().all(|s| s.is_finite()));
+    Ok(())
+}